@@ -0,0 +1,193 @@
+//! What the fake portal serves: one event, its teams, referees, schedule and coin flips.
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+/// The event the fake portal serves, with its ids in the portal's full form (`events/1234-A`)
+#[derive(Debug, Clone)]
+pub struct PortalData {
+    pub event_id: String,
+    pub event_name: String,
+    pub event_slug: String,
+    pub starts_on: String,
+    pub ends_on: String,
+    pub teams: Vec<Team>,
+    pub referees: Vec<Referee>,
+    /// Served as-is by the privileged schedule call, so a test can hand it any shape
+    pub schedule: Value,
+    /// Served as-is by the coin-flip call. A posted result is written into the flip it names.
+    pub coin_flips: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Team {
+    /// The full team id, `teams/1234-A`
+    pub id: String,
+    pub name: String,
+    pub roster: Vec<RosterEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub roster_name: String,
+    pub cap_number: Option<u8>,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Referee {
+    pub user_id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub roster_name: String,
+}
+
+impl PortalData {
+    /// The event `docs/third-party-stub/stub_site.py` serves: one court, two games sharing one
+    /// timing rule, and two teams whose rosters carry the awkward `roles` cases (a playing coach,
+    /// and a coach with a cap number who must not reach the grid).
+    pub fn example() -> Self {
+        let member = |name: &str, cap: u8, roles: &[&str]| RosterEntry {
+            roster_name: name.to_string(),
+            cap_number: Some(cap),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+        };
+
+        let black_sheep = vec![
+            member("Alice", 1, &["Player", "Captain"]),
+            member("Bailey", 2, &["Player"]),
+            member("Casey", 3, &["Player"]),
+            member("Drew", 4, &["Player"]),
+            member("Emerson", 5, &["Player"]),
+            member("Finley", 6, &["Player"]),
+        ];
+        let white_knights = vec![
+            member("Ashley", 1, &["Player", "Captain"]),
+            member("Blair", 2, &["Player"]),
+            member("Cameron", 3, &["Player"]),
+            member("Dakota", 4, &["Player"]),
+            member("Elliot", 5, &["Player"]),
+            member("Frankie", 6, &["Player", "Coach"]),
+            member("Gabriel", 7, &["Coach"]),
+        ];
+
+        Self {
+            event_id: "events/1234-A".to_string(),
+            event_name: "Example Open 2026".to_string(),
+            event_slug: "example-open-2026".to_string(),
+            starts_on: "2026-08-08T09:00:00Z".to_string(),
+            ends_on: "2026-08-08T18:00:00Z".to_string(),
+            teams: vec![
+                Team {
+                    id: "teams/1234-A".to_string(),
+                    name: "Black Sheep".to_string(),
+                    roster: black_sheep,
+                },
+                Team {
+                    id: "teams/5678-B".to_string(),
+                    name: "White Knights".to_string(),
+                    roster: white_knights,
+                },
+            ],
+            referees: vec![Referee {
+                user_id: "user-abc123".to_string(),
+                username: "reef_ref".to_string(),
+                name: None,
+                roster_name: "Casey".to_string(),
+            }],
+            schedule: json!({
+                "eventId": "events/1234-A",
+                "games": {
+                    "1": {
+                        "number": "1",
+                        "dark": { "teamId": "teams/1234-A" },
+                        "light": { "teamId": "teams/5678-B" },
+                        "startsOn": "2026-08-08T09:00:00Z",
+                        "court": "A",
+                        "timingRule": { "name": "RR" },
+                        "refereeAssignments": [
+                            { "role": "Head Referee", "userId": "user-abc123" }
+                        ],
+                        "description": "Round robin opener"
+                    },
+                    "2": {
+                        "number": "2",
+                        "dark": { "teamId": "teams/5678-B" },
+                        "light": { "teamId": "teams/1234-A" },
+                        "startsOn": "2026-08-08T10:00:00Z",
+                        "court": "A",
+                        "timingRule": { "name": "RR" }
+                    }
+                },
+                "nonGameEntries": [],
+                "groups": [],
+                "timingRules": [{
+                    "name": "RR",
+                    "teamTimeoutCount": 1,
+                    "teamTimeoutsCountedPerHalf": true,
+                    "overtimeAllowed": true,
+                    "suddenDeathAllowed": true,
+                    "last2minStopTime": false,
+                    "halfPlayDuration": 900,
+                    "halfTimeDuration": 180,
+                    "teamTimeoutDuration": 60,
+                    "overtimeHalfPlayDuration": 300,
+                    "overtimeHalfTimeDuration": 180,
+                    "preOvertimeBreak": 180,
+                    "preSuddenDeathDuration": 60,
+                    "minimumBreak": 240
+                }]
+            }),
+            coin_flips: json!({
+                "groups": [],
+                "games": [{
+                    "identifier": "coin-flip-1",
+                    "tiedTeams": [
+                        { "teamId": "teams/1234-A" },
+                        { "teamId": "teams/5678-B" }
+                    ],
+                    "result": null
+                }]
+            }),
+        }
+    }
+
+    /// The short form of the event id, as it appears in URL paths
+    pub fn event_partial(&self) -> &str {
+        self.event_id
+            .strip_prefix("events/")
+            .unwrap_or(&self.event_id)
+    }
+
+    pub(crate) fn event_json(&self) -> Value {
+        json!({
+            "id": self.event_id,
+            "name": self.event_name,
+            "slug": self.event_slug,
+            "dateRange": { "startsOn": self.starts_on, "endsOn": self.ends_on },
+        })
+    }
+
+    pub(crate) fn referee_json(referee: &Referee) -> Value {
+        json!({
+            "user": {
+                "id": referee.user_id,
+                "name": referee.name,
+                "username": referee.username,
+            },
+            "rosterName": referee.roster_name,
+        })
+    }
+
+    /// The referees the schedule assigns to a game, in assignment order
+    pub(crate) fn game_referees(&self, game_number: &str) -> Vec<&Referee> {
+        self.schedule["games"][game_number]["refereeAssignments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|assignment| assignment["userId"].as_str())
+            .filter_map(|id| self.referees.iter().find(|r| r.user_id == id))
+            .collect()
+    }
+}
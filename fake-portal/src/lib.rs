@@ -0,0 +1,315 @@
+//! An in-process stand-in for the UWH Portal, for integration tests.
+//!
+//! `FakePortal::start` serves every call `uwh_common::uwhportal::UwhPortalClient` makes on a
+//! loopback port, with its own runtime on its own thread, so a test drives it the same way
+//! whether the test itself is sync or async. The data it serves is `PortalData::example()` —
+//! the same event as `docs/third-party-stub/stub_site.py` — unless the test hands it other data.
+//!
+//! Unlike the Python stub, it enforces the link negotiation and the bearer token, because here
+//! the point is to exercise refbox's reaction to being refused. A test scripts the rest of the
+//! refusals with `fail_next` / `fail_always` (server errors, slow responses, expired tokens and
+//! rate limits) and afterwards reads back what was sent with `requests`, `posted_scores`,
+//! `posted_stats` and `coin_flip_results`.
+
+mod data;
+mod routes;
+
+pub use data::{PortalData, Referee, RosterEntry, Team};
+
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    net::{Ipv4Addr, SocketAddr, TcpListener},
+    sync::{Arc, Mutex, MutexGuard},
+    thread::JoinHandle,
+    time::Duration,
+};
+use tokio::sync::oneshot;
+
+/// The access key the portal accepts until `FakePortal::expire_tokens` is first called
+pub const ACCESS_KEY: &str = "fake-portal-access-key";
+
+/// The only account `POST /api/authentication` accepts
+pub const EMAIL: &str = "referee@example.com";
+pub const PASSWORD: &str = "correct horse";
+
+/// One of the calls the portal serves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// `POST /api/events/{event}/access-keys/ref-box`
+    LinkRefbox,
+    /// `POST /api/authentication`
+    Login,
+    /// `GET /api/events/{event}/access-keys/verify`
+    VerifyToken,
+    /// `GET /api/events`
+    EventList,
+    /// `GET /api/events/{event}/teams`
+    EventTeams,
+    /// `GET /api/events/{event}/schedule/privileged`
+    Schedule,
+    /// `GET /api/events/{event}/referees`
+    Referees,
+    /// `GET /api/events/{event}/participants`
+    Participants,
+    /// `GET /api/admin/events/game-referees`
+    GameReferees,
+    /// `GET /api/admin/get-event-team`
+    TeamRoster,
+    /// `GET /api/events/{slug}/schedule/coin-flips`
+    CoinFlips,
+    /// `POST /api/events/{slug}/schedule/coin-flips`
+    SetCoinFlip,
+    /// `POST /api/events/{event}/schedule/games/{game}/scores`
+    PostScores,
+    /// `POST /api/admin/events/stats`
+    PostStats,
+}
+
+/// How a scripted call goes wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// Answer with this status and an error body, without handling the call
+    Status(u16),
+    /// Hold the response this long, then handle the call as normal. Longer than the client's
+    /// timeout, this is a portal that cannot be reached.
+    Delay(Duration),
+    /// Refuse the token with a `401`, whether or not it is the current one
+    ExpiredToken,
+    /// Answer `429` with a `Retry-After` header
+    RateLimited { retry_after: Duration },
+}
+
+/// A call as the portal received it, whether or not it was answered successfully
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub endpoint: Endpoint,
+    pub path: String,
+    pub query: Option<String>,
+    pub authorization: Option<String>,
+    pub body: String,
+}
+
+/// A score the portal accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostedScores {
+    /// The short event id from the URL path
+    pub event: String,
+    pub game_number: String,
+    pub dark: u8,
+    pub light: u8,
+    pub force: bool,
+}
+
+/// A set of game stats the portal accepted
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostedStats {
+    /// The full event id from the query string
+    pub event_id: String,
+    pub game_number: String,
+    pub events: Vec<Value>,
+}
+
+#[derive(Debug, Default)]
+struct Script {
+    next: VecDeque<Failure>,
+    always: Option<Failure>,
+}
+
+#[derive(Debug)]
+pub(crate) struct State {
+    data: PortalData,
+    token: String,
+    /// How many times the key has been retired
+    generation: u32,
+    /// The code an admin would have shown for a pending link, if one is pending
+    pending_link: Option<String>,
+    scripts: HashMap<Endpoint, Script>,
+    requests: Vec<Request>,
+    posted_scores: Vec<PostedScores>,
+    posted_stats: Vec<PostedStats>,
+    coin_flip_results: Vec<Value>,
+}
+
+impl State {
+    fn take_failure(&mut self, endpoint: Endpoint) -> Option<Failure> {
+        let script = self.scripts.get_mut(&endpoint)?;
+        script.next.pop_front().or(script.always)
+    }
+
+    pub(crate) fn token_is_current(&self, authorization: Option<&str>) -> bool {
+        authorization.and_then(|h| h.strip_prefix("Bearer ")) == Some(self.token.as_str())
+    }
+}
+
+pub(crate) type Shared = Arc<Mutex<State>>;
+
+pub(crate) fn lock(state: &Shared) -> MutexGuard<'_, State> {
+    // A handler that panicked part-way leaves nothing half-written that matters to a test, and
+    // the panic itself is already reported by the failing request.
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A running fake portal. Shut down when dropped.
+pub struct FakePortal {
+    addr: SocketAddr,
+    state: Shared,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakePortal {
+    /// Serve `PortalData::example()` on a free loopback port
+    pub fn start() -> io::Result<Self> {
+        Self::start_with(PortalData::example())
+    }
+
+    pub fn start_with(data: PortalData) -> io::Result<Self> {
+        // Bound here rather than on the server thread, so a test never races the first request
+        // against the bind.
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(State {
+            data,
+            token: ACCESS_KEY.to_string(),
+            generation: 0,
+            pending_link: None,
+            scripts: HashMap::new(),
+            requests: Vec::new(),
+            posted_scores: Vec::new(),
+            posted_stats: Vec::new(),
+            coin_flip_results: Vec::new(),
+        }));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let app = routes::router(state.clone());
+        let (shutdown, shutdown_rx) = oneshot::channel();
+
+        let thread = std::thread::Builder::new()
+            .name(format!("fake-portal-{}", addr.port()))
+            .spawn(move || {
+                runtime.block_on(async move {
+                    let listener = match tokio::net::TcpListener::from_std(listener) {
+                        Ok(listener) => listener,
+                        Err(e) => panic!("fake portal could not adopt its listener: {e}"),
+                    };
+                    // Dropping the server when told to stop, rather than shutting it down
+                    // gracefully, means a request held by `Failure::Delay` cannot keep the
+                    // test waiting.
+                    tokio::select! {
+                        result = axum::serve(listener, app) => {
+                            if let Err(e) = result {
+                                panic!("fake portal stopped serving: {e}");
+                            }
+                        }
+                        _ = shutdown_rx => {}
+                    }
+                });
+            })?;
+
+        Ok(Self {
+            addr,
+            state,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        })
+    }
+
+    /// The base URL to hand to the client, without a trailing slash
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// The key the portal currently accepts
+    pub fn access_key(&self) -> String {
+        lock(&self.state).token.clone()
+    }
+
+    pub fn data(&self) -> PortalData {
+        lock(&self.state).data.clone()
+    }
+
+    /// Let the next link request with this code succeed, as if an admin had started pairing a
+    /// refbox and read the code out. Without it, linking answers `NoPendingLink`.
+    pub fn expect_link(&self, code: u32) {
+        lock(&self.state).pending_link = Some(code.to_string());
+    }
+
+    /// Retire the current key for a new one. Every call that needs a token is refused until the
+    /// client links or logs in again and is handed the new key.
+    pub fn expire_tokens(&self) {
+        let mut state = lock(&self.state);
+        state.generation += 1;
+        state.token = format!("{ACCESS_KEY}-{}", state.generation);
+    }
+
+    /// Make the next call to `endpoint` fail this way. Calls queue up: scripting two failures
+    /// fails the next two calls.
+    pub fn fail_next(&self, endpoint: Endpoint, failure: Failure) {
+        lock(&self.state)
+            .scripts
+            .entry(endpoint)
+            .or_default()
+            .next
+            .push_back(failure);
+    }
+
+    /// Make every call to `endpoint` fail this way, once any `fail_next` failures are used up
+    pub fn fail_always(&self, endpoint: Endpoint, failure: Failure) {
+        lock(&self.state)
+            .scripts
+            .entry(endpoint)
+            .or_default()
+            .always = Some(failure);
+    }
+
+    /// Let every call through again
+    pub fn clear_failures(&self) {
+        lock(&self.state).scripts.clear();
+    }
+
+    /// Every call received so far, oldest first
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.state).requests.clone()
+    }
+
+    /// How many calls to `endpoint` have been received
+    pub fn count(&self, endpoint: Endpoint) -> usize {
+        lock(&self.state)
+            .requests
+            .iter()
+            .filter(|r| r.endpoint == endpoint)
+            .count()
+    }
+
+    pub fn posted_scores(&self) -> Vec<PostedScores> {
+        lock(&self.state).posted_scores.clone()
+    }
+
+    pub fn posted_stats(&self) -> Vec<PostedStats> {
+        lock(&self.state).posted_stats.clone()
+    }
+
+    /// The coin-flip results posted so far, as sent
+    pub fn coin_flip_results(&self) -> Vec<Value> {
+        lock(&self.state).coin_flip_results.clone()
+    }
+}
+
+impl Drop for FakePortal {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
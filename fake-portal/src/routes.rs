@@ -0,0 +1,421 @@
+//! The HTTP side: one handler per call, each behind the layer that records it and applies any
+//! scripted failure.
+
+use crate::{Endpoint, Failure, PortalData, PostedScores, PostedStats, Request, Shared, lock};
+use axum::{
+    Json, Router,
+    body::{Body, Bytes, to_bytes},
+    extract::{Path, Query, Request as HttpRequest, State},
+    http::{
+        HeaderMap, StatusCode,
+        header::{AUTHORIZATION, RETRY_AFTER},
+    },
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{MethodRouter, get, post},
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+type Params = Query<HashMap<String, String>>;
+
+pub(crate) fn router(state: Shared) -> Router {
+    let at = |endpoint: Endpoint, route: MethodRouter<Shared>| {
+        route.layer(middleware::from_fn_with_state(
+            (state.clone(), endpoint),
+            intercept,
+        ))
+    };
+
+    Router::new()
+        .route(
+            "/api/events/{event}/access-keys/ref-box",
+            at(Endpoint::LinkRefbox, post(link_refbox)),
+        )
+        .route("/api/authentication", at(Endpoint::Login, post(login)))
+        .route(
+            "/api/events/{event}/access-keys/verify",
+            at(Endpoint::VerifyToken, get(verify_token)),
+        )
+        .route("/api/events", at(Endpoint::EventList, get(event_list)))
+        .route(
+            "/api/events/{event}/teams",
+            at(Endpoint::EventTeams, get(event_teams)),
+        )
+        .route(
+            "/api/events/{event}/schedule/privileged",
+            at(Endpoint::Schedule, get(schedule)),
+        )
+        .route(
+            "/api/events/{event}/referees",
+            at(Endpoint::Referees, get(referees)),
+        )
+        .route(
+            "/api/events/{event}/participants",
+            at(Endpoint::Participants, get(participants)),
+        )
+        .route(
+            "/api/admin/events/game-referees",
+            at(Endpoint::GameReferees, get(game_referees)),
+        )
+        .route(
+            "/api/admin/get-event-team",
+            at(Endpoint::TeamRoster, get(team_roster)),
+        )
+        // The coin-flip calls take the event's slug rather than its id, but the path segment
+        // keeps the same name as its neighbours because the router will not mix the two.
+        .route(
+            "/api/events/{event}/schedule/coin-flips",
+            at(Endpoint::CoinFlips, get(coin_flips))
+                .merge(at(Endpoint::SetCoinFlip, post(set_coin_flip))),
+        )
+        .route(
+            "/api/events/{event}/schedule/games/{game}/scores",
+            at(Endpoint::PostScores, post(post_scores)),
+        )
+        .route(
+            "/api/admin/events/stats",
+            at(Endpoint::PostStats, post(post_stats)),
+        )
+        .with_state(state)
+}
+
+/// Record the call, then either answer it with the failure scripted for its endpoint or pass it
+/// on to the handler
+async fn intercept(
+    State((state, endpoint)): State<(Shared, Endpoint)>,
+    request: HttpRequest,
+    next: Next,
+) -> Response {
+    let (parts, body) = request.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => return error(StatusCode::BAD_REQUEST, &format!("unreadable body: {e}")),
+    };
+
+    let failure = {
+        let mut state = lock(&state);
+        state.requests.push(Request {
+            endpoint,
+            path: parts.uri.path().to_string(),
+            query: parts.uri.query().map(str::to_string),
+            authorization: parts
+                .headers
+                .get(AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        });
+        state.take_failure(endpoint)
+    };
+
+    match failure {
+        None => {}
+        Some(Failure::Delay(delay)) => tokio::time::sleep(delay).await,
+        Some(Failure::Status(code)) => {
+            let status = StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            return error(status, &format!("scripted failure: {status}"));
+        }
+        Some(Failure::ExpiredToken) => return unauthorized(),
+        Some(Failure::RateLimited { retry_after }) => {
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, retry_after.as_secs().to_string())],
+                Json(json!({ "error": "too many requests" })),
+            )
+                .into_response();
+        }
+    }
+
+    next.run(HttpRequest::from_parts(parts, Body::from(body)))
+        .await
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+fn unauthorized() -> Response {
+    error(StatusCode::UNAUTHORIZED, "unauthorized")
+}
+
+fn unknown_event(event: &str) -> Response {
+    error(StatusCode::NOT_FOUND, &format!("unknown event {event}"))
+}
+
+fn authorization(headers: &HeaderMap) -> Option<&str> {
+    headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok())
+}
+
+async fn link_refbox(
+    State(state): State<Shared>,
+    Path(event): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut state = lock(&state);
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    let reason = match (&state.pending_link, body["code"].as_str()) {
+        (None, _) => "NoPendingLink",
+        (Some(expected), Some(code)) if expected == code => {
+            state.pending_link = None;
+            return Json(json!({ "accessKey": state.token })).into_response();
+        }
+        (Some(_), _) => "InvalidCode",
+    };
+    (StatusCode::BAD_REQUEST, Json(json!({ "reason": reason }))).into_response()
+}
+
+async fn login(State(state): State<Shared>, Json(body): Json<Value>) -> Response {
+    let state = lock(&state);
+    if body["email"] == crate::EMAIL && body["password"] == crate::PASSWORD {
+        Json(json!({ "accessToken": state.token })).into_response()
+    } else {
+        unauthorized()
+    }
+}
+
+async fn verify_token(
+    State(state): State<Shared>,
+    Path(event): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    StatusCode::OK.into_response()
+}
+
+async fn event_list(State(state): State<Shared>) -> Response {
+    let state = lock(&state);
+    Json(json!({ "totalCount": 1, "items": [state.data.event_json()] })).into_response()
+}
+
+async fn event_teams(State(state): State<Shared>, Path(event): Path<String>) -> Response {
+    let state = lock(&state);
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    let teams: Vec<Value> = state
+        .data
+        .teams
+        .iter()
+        .map(|team| json!({ "team": team }))
+        .collect();
+    Json(json!({ "teams": teams })).into_response()
+}
+
+async fn schedule(
+    State(state): State<Shared>,
+    Path(event): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    Json(state.data.schedule.clone()).into_response()
+}
+
+async fn referees(State(state): State<Shared>, Path(event): Path<String>) -> Response {
+    let state = lock(&state);
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    let dedicated: Vec<Value> = state
+        .data
+        .referees
+        .iter()
+        .map(PortalData::referee_json)
+        .collect();
+    Json(json!({
+        "tournamentReferee": null,
+        "referees": { "dedicated": dedicated, "hybrid": [], "timeOrScoreKeeper": [] },
+    }))
+    .into_response()
+}
+
+async fn participants(
+    State(state): State<Shared>,
+    Path(event): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    let participants: Vec<Value> = state
+        .data
+        .referees
+        .iter()
+        .map(PortalData::referee_json)
+        .collect();
+    Json(participants).into_response()
+}
+
+async fn game_referees(State(state): State<Shared>, Query(params): Params) -> Response {
+    let state = lock(&state);
+    let event_id = params
+        .get("eventId")
+        .map(String::as_str)
+        .unwrap_or_default();
+    if event_id != state.data.event_id {
+        return unknown_event(event_id);
+    }
+    let game_number = params
+        .get("gameNumber")
+        .map(String::as_str)
+        .unwrap_or_default();
+    let referees: Vec<Value> = state
+        .data
+        .game_referees(game_number)
+        .into_iter()
+        .map(PortalData::referee_json)
+        .collect();
+    Json(json!({ "referees": referees })).into_response()
+}
+
+async fn team_roster(State(state): State<Shared>, Query(params): Params) -> Response {
+    let state = lock(&state);
+    let team_id = params.get("teamId").map(String::as_str).unwrap_or_default();
+    match state.data.teams.iter().find(|team| team.id == team_id) {
+        Some(team) => Json(json!({ "roster": team.roster })).into_response(),
+        None => error(StatusCode::NOT_FOUND, &format!("unknown teamId {team_id}")),
+    }
+}
+
+async fn coin_flips(
+    State(state): State<Shared>,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if slug != state.data.event_slug {
+        return unknown_event(&slug);
+    }
+    Json(state.data.coin_flips.clone()).into_response()
+}
+
+async fn set_coin_flip(
+    State(state): State<Shared>,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+    Json(model): Json<Value>,
+) -> Response {
+    let mut state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if slug != state.data.event_slug {
+        return unknown_event(&slug);
+    }
+
+    let identifier = model["CoinFlipIdentifier"].as_str().unwrap_or_default();
+    let Some(flip) = coin_flip_mut(&mut state.data.coin_flips, identifier) else {
+        return error(
+            StatusCode::NOT_FOUND,
+            &format!("unknown coin flip {identifier}"),
+        );
+    };
+    flip["result"] = json!({
+        "kind": model["Kind"],
+        "team": { "teamId": model["TeamIdOrPendingAssignmentName"] },
+    });
+
+    state.coin_flip_results.push(model);
+    StatusCode::OK.into_response()
+}
+
+/// The coin flip with this identifier, whether it settles a game or a group
+fn coin_flip_mut<'a>(coin_flips: &'a mut Value, identifier: &str) -> Option<&'a mut Value> {
+    let is_it = |flip: &Value| flip["identifier"] == identifier;
+    if coin_flips["games"]
+        .as_array()
+        .is_some_and(|games| games.iter().any(is_it))
+    {
+        return coin_flips["games"]
+            .as_array_mut()?
+            .iter_mut()
+            .find(|flip| is_it(flip));
+    }
+    coin_flips["groups"]
+        .as_array_mut()?
+        .iter_mut()
+        .flat_map(|group| group["coinFlips"].as_array_mut().into_iter().flatten())
+        .find(|flip| is_it(flip))
+}
+
+async fn post_scores(
+    State(state): State<Shared>,
+    Path((event, game_number)): Path<(String, String)>,
+    Query(params): Params,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    if event != state.data.event_partial() {
+        return unknown_event(&event);
+    }
+    let score = |side: &str| {
+        body[side]["value"]
+            .as_u64()
+            .and_then(|value| u8::try_from(value).ok())
+    };
+    let (Some(dark), Some(light)) = (score("dark"), score("light")) else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "scores must be dark.value and light.value",
+        );
+    };
+    // Like the Python stub, a game number the schedule does not know is accepted anyway.
+    state.posted_scores.push(PostedScores {
+        event,
+        game_number,
+        dark,
+        light,
+        force: params.get("force").is_some_and(|force| force == "true"),
+    });
+    StatusCode::OK.into_response()
+}
+
+async fn post_stats(
+    State(state): State<Shared>,
+    Query(params): Params,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let mut state = lock(&state);
+    if !state.token_is_current(authorization(&headers)) {
+        return unauthorized();
+    }
+    let event_id = params.get("eventId").cloned().unwrap_or_default();
+    if event_id != state.data.event_id {
+        return unknown_event(&event_id);
+    }
+    let Ok(events) = serde_json::from_slice::<Vec<Value>>(&body) else {
+        return error(StatusCode::BAD_REQUEST, "stats must be a JSON array");
+    };
+    state.posted_stats.push(PostedStats {
+        event_id,
+        game_number: params.get("gameNumber").cloned().unwrap_or_default(),
+        events,
+    });
+    StatusCode::OK.into_response()
+}
@@ -290,13 +290,23 @@ impl DisplayState {
             Some(TimeoutSnapshot::Ref(_)) | Some(TimeoutSnapshot::PenaltyShot(_))
         );
 
-        let minutes = (data.snapshot.secs_in_period / 60)
-            .try_into()
-            .unwrap_or(u8::MAX);
-        let seconds = if minutes > 99 {
-            u8::MAX
+        // During a shootout the time digits show the shootout tally, each team on its own side
+        let (minutes, seconds) = if let Some(tally) = data.snapshot.shootout_scores() {
+            if white_on_right {
+                (tally.black.min(99), tally.white.min(99))
+            } else {
+                (tally.white.min(99), tally.black.min(99))
+            }
         } else {
-            (data.snapshot.secs_in_period % 60) as u8
+            let minutes = (data.snapshot.secs_in_period / 60)
+                .try_into()
+                .unwrap_or(u8::MAX);
+            let seconds = if minutes > 99 {
+                u8::MAX
+            } else {
+                (data.snapshot.secs_in_period % 60) as u8
+            };
+            (minutes, seconds)
         };
 
         // If we are not in a team timeout, show the game time. If we are in a team timeout, show the seconds
@@ -340,6 +350,7 @@ impl DisplayState {
                 | GamePeriod::OvertimeSecondHalf
                 | GamePeriod::PreSuddenDeath
                 | GamePeriod::SuddenDeath
+                | GamePeriod::PenaltyShootout
         );
         let sdn_dth = matches!(
             data.snapshot.current_period,
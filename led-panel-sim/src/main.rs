@@ -252,6 +252,26 @@ fn main() {
                 },
             },
         },
+        TestCase {
+            name: "PenaltyShootout, B3, W2, B14, W14".to_string(),
+            transmitted_data: TransmittedData {
+                white_on_right: false,
+                flash: false,
+                beep_test: false,
+                brightness: Brightness::Low,
+                snapshot: GameSnapshotNoHeap {
+                    current_period: GamePeriod::PenaltyShootout,
+                    secs_in_period: u16::from_be_bytes([3, 2]),
+                    timeout: None,
+                    scores: BlackWhiteBundle {
+                        black: 14,
+                        white: 14,
+                    },
+                    penalties: Default::default(),
+                    is_old_game: false,
+                },
+            },
+        },
         TestCase {
             name: "BetweenGames, T99, B15, W14".to_string(),
             transmitted_data: TransmittedData {
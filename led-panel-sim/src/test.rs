@@ -299,6 +299,41 @@ fn test_time() {
     );
 }
 
+#[test]
+fn test_shootout_tally() {
+    let mut data = empty_data();
+    data.snapshot.current_period = GamePeriod::PenaltyShootout;
+    // Black 3, White 2
+    data.snapshot.secs_in_period = u16::from_be_bytes([3, 2]);
+
+    let (state, _brightness) = DisplayState::from_transmitted_data(&data);
+    assert_eq!(
+        state,
+        DisplayState {
+            time_m_ones: Digit::TWO,
+            time_s_ones: Digit::THREE,
+            time_s_tens: Digit::ZERO,
+            overtime: true,
+            ..EMPTY_STATE
+        }
+    );
+
+    data.white_on_right = true;
+    let (state, _brightness) = DisplayState::from_transmitted_data(&data);
+    assert_eq!(
+        state,
+        DisplayState {
+            time_m_ones: Digit::THREE,
+            time_s_ones: Digit::TWO,
+            time_s_tens: Digit::ZERO,
+            overtime: true,
+            white_on_left: false,
+            white_on_right: true,
+            ..EMPTY_STATE
+        }
+    );
+}
+
 #[test]
 fn test_timeouts() {
     let mut data = empty_data();
@@ -630,6 +665,16 @@ fn test_game_periods() {
             ..EMPTY_STATE
         }
     );
+
+    data.snapshot.current_period = GamePeriod::PenaltyShootout;
+    let (state, _brightness) = DisplayState::from_transmitted_data(&data);
+    assert_eq!(
+        state,
+        DisplayState {
+            overtime: true,
+            ..EMPTY_STATE
+        }
+    );
 }
 
 #[test]
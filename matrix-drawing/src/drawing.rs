@@ -70,7 +70,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
             | GamePeriod::PreOvertime
             | GamePeriod::OvertimeHalfTime
             | GamePeriod::PreSuddenDeath => YELLOW,
-            GamePeriod::SuddenDeath => RED,
+            GamePeriod::SuddenDeath | GamePeriod::PenaltyShootout => RED,
         },
     };
 
@@ -81,11 +81,24 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
         _ => RED,
     };
 
+    // During a shootout the time panels show the shootout tally in place of the clock
+    let time_text = if let Some(tally) = state.shootout_scores() {
+        let mut tally_string = ArrayString::<5>::new();
+        if white_on_right {
+            write!(&mut tally_string, "{}-{}", tally.black, tally.white).unwrap();
+        } else {
+            write!(&mut tally_string, "{}-{}", tally.white, tally.black).unwrap();
+        }
+        tally_string
+    } else {
+        secs_to_time_string(state.secs_in_period)
+    };
+
     // EVERYTHING TO BE DISPLAYED ON THE CENTER 2 TIME PANELS
     if let Some(timeout) = state.timeout {
         // There is currently a timeout
         Text::with_text_style(
-            &secs_to_time_string(state.secs_in_period),
+            &time_text,
             Point::new(152, 33),
             MonoTextStyle::new(&FONT_14X31, game_color),
             CENTERED,
@@ -106,6 +119,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
                 GamePeriod::OvertimeSecondHalf => "OT 2ND\nHALF",
                 GamePeriod::PreSuddenDeath => "PRE SD\nBREAK",
                 GamePeriod::SuddenDeath => "SUDDEN\nDEATH",
+                GamePeriod::PenaltyShootout => "SHOOT\nOUT",
             }
         };
 
@@ -181,7 +195,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
         };
     } else {
         Text::with_text_style(
-            &secs_to_time_string(state.secs_in_period),
+            &time_text,
             Point::new(127, 18),
             MonoTextStyle::new(&FONT_20X46, game_color),
             CENTERED,
@@ -201,6 +215,7 @@ pub fn draw_panels<D: DrawTarget<Color = Rgb888>>(
                 GamePeriod::OvertimeSecondHalf => "O/T 2ND HALF",
                 GamePeriod::PreSuddenDeath => "PRE-SUDDEN DEATH",
                 GamePeriod::SuddenDeath => "SUDDEN DEATH",
+                GamePeriod::PenaltyShootout => "PENALTY SHOOTOUT",
             }
         };
 
@@ -138,12 +138,12 @@ mod test {
         data.snapshot.penalties.black.push(PenaltySnapshot {
             player_number: 1,
             time: PenaltyTime::Seconds(48),
-            infraction: Infraction::Unknown, // infraction is not encoded, so the test will fail with any other value
+            infraction: Infraction::UNKNOWN, // infraction is not encoded, so the test will fail with any other value
         });
         data.snapshot.penalties.white.push(PenaltySnapshot {
             player_number: 12,
             time: PenaltyTime::Seconds(96),
-            infraction: Infraction::Unknown, // infraction is not encoded, so the test will fail with any other value
+            infraction: Infraction::UNKNOWN, // infraction is not encoded, so the test will fail with any other value
         });
 
         test_data(&mut data)?;
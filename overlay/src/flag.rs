@@ -18,10 +18,24 @@ const FLAG_HEIGHT: f32 = 70f32;
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Type {
     Goal(UWHColor, bool),
+    /// A goal that was disallowed after it was given
+    NoGoal(UWHColor, bool),
     /// Third enum value is used to keep track of whether the flag was visited in last sync. Unvisited flags need to be deleted.
     Penalty(UWHColor, PenaltyTime, bool),
 }
 
+impl Type {
+    const fn color(&self) -> UWHColor {
+        match *self {
+            Type::Goal(color, _) | Type::NoGoal(color, _) | Type::Penalty(color, _, _) => color,
+        }
+    }
+
+    const fn is_penalty(&self) -> bool {
+        matches!(self, Type::Penalty(..))
+    }
+}
+
 struct Textures {
     black_goal: Texture,
     white_goal: Texture,
@@ -64,7 +78,9 @@ impl Renderer {
     pub fn add_flag(&mut self, mut flag: Flag, game_state: &crate::State) {
         flag.vertical_position = self.active_flags.len() as u32;
         flag.player_name = match flag.flag_type {
-            Type::Penalty(UWHColor::Black, _, _) | Type::Goal(UWHColor::Black, _) => game_state
+            Type::Penalty(UWHColor::Black, _, _)
+            | Type::Goal(UWHColor::Black, _)
+            | Type::NoGoal(UWHColor::Black, _) => game_state
                 .black
                 .get_players()
                 .find(|player| player.number.unwrap() == flag.player_number)
@@ -107,58 +123,53 @@ impl Renderer {
     }
 
     fn synchronize_goals(&mut self, game_state: &crate::State) {
-        // mark all goal flags as unvisited
+        self.synchronize_callout(game_state.snapshot.recent_goal, Type::Goal, game_state);
+        self.synchronize_callout(
+            game_state.snapshot.recent_disallowed_goal,
+            Type::NoGoal,
+            game_state,
+        );
+    }
+
+    /// Keeps the flags of one kind of goal callout (`Type::Goal` or `Type::NoGoal`) in step with
+    /// the snapshot, fading out the ones it no longer has.
+    fn synchronize_callout(
+        &mut self,
+        callout: Option<(UWHColor, u8)>,
+        kind: fn(UWHColor, bool) -> Type,
+        game_state: &crate::State,
+    ) {
+        let is_kind = |flag_type: &Type| {
+            std::mem::discriminant(flag_type)
+                == std::mem::discriminant(&kind(flag_type.color(), false))
+        };
+
+        // mark all flags of this kind as unvisited
         for flag in &mut self.active_flags {
-            if let Flag {
-                flag_type: Type::Goal(_, is_visited),
-                ..
-            } = flag
-            {
-                *is_visited = false;
+            if is_kind(&flag.flag_type) {
+                flag.flag_type = kind(flag.flag_type.color(), false);
             }
         }
 
-        if let Some(goal) = game_state.snapshot.recent_goal {
-            // find the position of the flag in the local list, or else create new
-            let flag_pos = self
-                .active_flags
-                .iter()
-                .position(|flag| {
-                    matches!(flag.flag_type, Type::Goal(color, _) if color == goal.0 )
-                        && flag.player_number == goal.1
-                })
-                .unwrap_or_else(|| {
-                    self.add_flag(
-                        Flag::new(String::new(), goal.1, Type::Goal(goal.0, true)),
-                        game_state,
-                    );
-                    self.active_flags.len() - 1
-                });
-
-            // get the goal flag in the snapshot and mark it as visited
-            match self.active_flags.get_mut(flag_pos).unwrap() {
-                Flag {
-                    flag_type: Type::Goal(_, is_visited),
-                    ..
-                } => {
-                    *is_visited = true;
-                }
-                _ => unreachable!(),
+        if let Some((color, player_number)) = callout {
+            // find the flag in the local list and mark it as visited, or else create new
+            match self.active_flags.iter_mut().find(|flag| {
+                flag.flag_type == kind(color, false) && flag.player_number == player_number
+            }) {
+                Some(flag) => flag.flag_type = kind(color, true),
+                None => self.add_flag(
+                    Flag::new(String::new(), player_number, kind(color, true)),
+                    game_state,
+                ),
             }
         }
-        // move out all deleted goal flags to be faded to the inactive flag section
-        self.active_flags
-            .iter()
-            .filter_map(|x| {
-                if let Type::Goal(_, false) = x.flag_type {
-                    Some(x.clone())
-                } else {
-                    None
-                }
-            })
-            .for_each(|x| self.inactive_flags.push(x));
-        self.active_flags
-            .retain(|x| !matches!(x.flag_type, Type::Goal(_, false)));
+
+        // move out all deleted flags to be faded to the inactive flag section
+        let (deleted, active) = std::mem::take(&mut self.active_flags)
+            .into_iter()
+            .partition(|flag| flag.flag_type == kind(flag.flag_type.color(), false));
+        self.active_flags = active;
+        self.inactive_flags.extend::<Vec<_>>(deleted);
     }
 
     /// Used to synchronise penalty info from snapshot with the local penalty list.
@@ -261,26 +272,12 @@ impl Renderer {
                         std::cmp::Ordering::Less
                     }
                 }
-            } else if let (
+            } else if !a.flag_type.is_penalty() && !b.flag_type.is_penalty() {
                 // if both flags are goal flags, keep same ordering
-                Flag {
-                    flag_type: Type::Goal(_, _),
-                    ..
-                },
-                Flag {
-                    flag_type: Type::Goal(_, _),
-                    ..
-                },
-            ) = (a, b)
-            {
                 std::cmp::Ordering::Equal
             } else {
                 // if one is a goal flag and the other a penalty flag, put the penalty flag on top
-                if let Flag {
-                    flag_type: Type::Goal(_, _),
-                    ..
-                } = a
-                {
+                if !a.flag_type.is_penalty() {
                     std::cmp::Ordering::Greater
                 } else {
                     std::cmp::Ordering::Less
@@ -312,11 +309,9 @@ impl Renderer {
                 )
                     .interpolate_linear(flag.movement_animation_counter)
             };
-            let color = match flag.flag_type {
-                Type::Goal(color, _) | Type::Penalty(color, _, _) => color,
-            };
+            let color = flag.flag_type.color();
             let tex = match flag.flag_type {
-                Type::Goal(_, _) => {
+                Type::Goal(_, _) | Type::NoGoal(_, _) => {
                     if color == UWHColor::White {
                         &self.textures.white_goal
                     } else {
@@ -395,6 +390,31 @@ impl Renderer {
                         },
                     );
                 }
+                Type::NoGoal(color, _) => {
+                    let (x_off, text) =
+                        fit_text(94f32, "NO GOAL", 24, &self.textures.font, Justify::Center);
+                    draw_text_ex(
+                        text.as_str(),
+                        35f32 + x_off,
+                        (idx as f32).mul_add(FLAG_HEIGHT, BASE_HEIGHT) + movement_offset + 33f32,
+                        TextParams {
+                            font: Some(&self.textures.font),
+                            font_size: 24,
+                            color: if color == UWHColor::Black {
+                                Color {
+                                    a: alpha_offset,
+                                    ..WHITE
+                                }
+                            } else {
+                                Color {
+                                    a: alpha_offset,
+                                    ..BLACK
+                                }
+                            },
+                            ..Default::default()
+                        },
+                    );
+                }
                 Type::Penalty(color, timeout, _) => {
                     let text = match timeout {
                         PenaltyTime::Seconds(s) => {
@@ -444,13 +464,11 @@ impl Renderer {
             }
         }
         for flag in &mut self.inactive_flags {
-            let color = match flag.flag_type {
-                Type::Goal(color, _) | Type::Penalty(color, _, _) => color,
-            };
+            let color = flag.flag_type.color();
             flag.alpha_animation_counter -= 1f32 / 60f32;
             let alpha_offset = (0f32, 1f32).interpolate_linear(flag.alpha_animation_counter);
             let tex = match flag.flag_type {
-                Type::Goal(_, _) => {
+                Type::Goal(_, _) | Type::NoGoal(_, _) => {
                     if color == UWHColor::White {
                         &self.textures.white_goal
                     } else {
@@ -528,6 +546,31 @@ impl Renderer {
                         },
                     );
                 }
+                Type::NoGoal(_, _) => {
+                    let (x_off, text) =
+                        fit_text(94f32, "NO GOAL", 24, &self.textures.font, Justify::Center);
+                    draw_text_ex(
+                        text.as_str(),
+                        35f32 + x_off,
+                        (flag.vertical_position as f32).mul_add(FLAG_HEIGHT, BASE_HEIGHT) + 33f32,
+                        TextParams {
+                            font: Some(&self.textures.font),
+                            font_size: 24,
+                            color: if color == UWHColor::Black {
+                                Color {
+                                    a: alpha_offset,
+                                    ..WHITE
+                                }
+                            } else {
+                                Color {
+                                    a: alpha_offset,
+                                    ..BLACK
+                                }
+                            },
+                            ..Default::default()
+                        },
+                    );
+                }
                 Type::Penalty(_, timeout, _) => {
                     let text = match timeout {
                         PenaltyTime::Seconds(s) => {
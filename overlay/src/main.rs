@@ -80,6 +80,22 @@ fn texture_from_image_result(image: network::Image) -> Result<Texture, Box<dyn s
 }
 
 impl State {
+    /// Teams the portal can't name, because it can't be reached or hasn't filled in a knockout
+    /// slot yet, fall back to the names the refbox worked out
+    fn fill_team_names(&mut self) {
+        let names = &self.snapshot.team_names;
+        for (team, name, placeholder) in [
+            (&mut self.black, &names.black, BLACK_TEAM_NAME),
+            (&mut self.white, &names.white, WHITE_TEAM_NAME),
+        ] {
+            if !team.from_portal {
+                team.team_name = name
+                    .as_deref()
+                    .map_or(placeholder.to_string(), |name| name.trim().to_uppercase());
+            }
+        }
+    }
+
     fn update_state(&mut self, received_update: StateUpdate) {
         match received_update {
             StateUpdate::Snapshot(snapshot) => {
@@ -98,7 +114,12 @@ impl State {
                         self.half_play_duration = None;
                     }
                 }
+                if self.snapshot.game_number() != snapshot.game_number() {
+                    self.black.from_portal = false;
+                    self.white.from_portal = false;
+                }
                 self.snapshot = snapshot;
+                self.fill_team_names();
             }
             StateUpdate::GameData(game_data) => {
                 if let Some(ref event_id) = self.snapshot.event_id {
@@ -107,6 +128,7 @@ impl State {
                     {
                         self.black = TeamInfo::from(game_data.black);
                         self.white = TeamInfo::from(game_data.white);
+                        self.fill_team_names();
                         self.start_time = game_data.start_time;
                         self.referees = game_data.referees.into_iter().map(Member::from).collect();
                         self.pool = game_data.pool;
@@ -162,6 +184,8 @@ pub struct TeamInfo {
     pub team_name: String,
     pub members: Vec<Member>,
     pub flag: Option<Texture>,
+    /// The portal knew which team this is
+    from_portal: bool,
 }
 
 impl From<TeamInfoRaw> for TeamInfo {
@@ -176,6 +200,7 @@ impl From<TeamInfoRaw> for TeamInfo {
                 (None, None) => a.name.cmp(&b.name),
             });
         Self {
+            from_portal: !team_info_raw.team_name.is_empty(),
             team_name: team_info_raw.team_name,
             members: team_info_raw
                 .members
@@ -193,6 +218,7 @@ impl TeamInfo {
             team_name: name.to_string(),
             members: Vec::new(),
             flag: None,
+            from_portal: false,
         }
     }
 
@@ -326,7 +352,8 @@ async fn main() {
             | GamePeriod::OvertimeSecondHalf
             | GamePeriod::PreOvertime
             | GamePeriod::PreSuddenDeath
-            | GamePeriod::SuddenDeath => {
+            | GamePeriod::SuddenDeath
+            | GamePeriod::PenaltyShootout => {
                 renderer.overtime_and_sudden_death_display(&local_state);
                 flag_renderer.draw();
             }
@@ -5,10 +5,13 @@ use crate::{
 };
 use coarsetime::Instant;
 use macroquad::prelude::*;
-use uwh_common::game_snapshot::{GamePeriod, TimeoutSnapshot};
+use uwh_common::{
+    color::Color as UwhColor,
+    game_snapshot::{GamePeriod, TimeoutSnapshot},
+};
 
 impl PageRenderer {
-    /// Display during overtime. Has no animations
+    /// Display during overtime, sudden death and the penalty shootout. Has no animations
     pub fn overtime_and_sudden_death_display(&mut self, state: &State) {
         let mut time = Instant::now()
             .duration_since(self.animation_register2)
@@ -217,19 +220,36 @@ impl PageRenderer {
         draw_texture_both!(self.assets.time_and_game_state, 367f32, 18f32, WHITE);
         let min = state.snapshot.secs_in_period / 60;
         let secs = state.snapshot.secs_in_period % 60;
-        let text = format!(
-            "{}:{}",
-            if min < 10 {
-                format!("0{min}")
-            } else {
-                format!("{min}")
-            },
-            if secs < 10 {
-                format!("0{secs}")
-            } else {
-                format!("{secs}")
-            }
-        );
+        // The shootout has no clock, so its tally takes the clock's place
+        let text = if let Some(shootout) = state
+            .snapshot
+            .shootout
+            .as_ref()
+            .filter(|_| state.snapshot.current_period == GamePeriod::PenaltyShootout)
+        {
+            let tally = shootout.tally();
+            format!("{} - {}", tally.white, tally.black)
+        } else {
+            format!(
+                "{}:{}",
+                if min < 10 {
+                    format!("0{min}")
+                } else {
+                    format!("{min}")
+                },
+                if secs < 10 {
+                    format!("0{secs}")
+                } else {
+                    format!("{secs}")
+                }
+            )
+        };
+        let gold_period = [
+            GamePeriod::SuddenDeath,
+            GamePeriod::PreSuddenDeath,
+            GamePeriod::PenaltyShootout,
+        ]
+        .contains(&state.snapshot.current_period);
         let (x_off, text) = fit_text(180f32, &text, 50, &self.assets.font, Justify::Center);
         draw_text_ex(
             text.as_str(),
@@ -238,13 +258,7 @@ impl PageRenderer {
             TextParams {
                 font: Some(&self.assets.font),
                 font_size: 50,
-                color: if [GamePeriod::SuddenDeath, GamePeriod::PreSuddenDeath]
-                    .contains(&state.snapshot.current_period)
-                {
-                    GOLD
-                } else {
-                    RED
-                },
+                color: if gold_period { GOLD } else { RED },
                 ..Default::default()
             },
         );
@@ -254,6 +268,7 @@ impl PageRenderer {
             GamePeriod::OvertimeHalfTime => "OVERTIME HALF TIME",
             GamePeriod::SuddenDeath => "SUDDEN DEATH",
             GamePeriod::PreSuddenDeath => "PRE SUDDEN DEATH",
+            GamePeriod::PenaltyShootout => "PENALTY SHOOTOUT",
             _ => "PRE OVERTIME",
         };
         let (x_off, text) = fit_text(200f32, ot_text, 20, &self.assets.font, Justify::Center);
@@ -264,16 +279,20 @@ impl PageRenderer {
             TextParams {
                 font: Some(&self.assets.font),
                 font_size: 20,
-                color: if [GamePeriod::SuddenDeath, GamePeriod::PreSuddenDeath]
-                    .contains(&state.snapshot.current_period)
-                {
-                    GOLD
-                } else {
-                    RED
-                },
+                color: if gold_period { GOLD } else { RED },
                 ..Default::default()
             },
         );
+        if let Some(shootout) = &state.snapshot.shootout {
+            // One marker per attempt beside each team's bar: green scored, red missed
+            for (color, y) in [(UwhColor::White, 57f32), (UwhColor::Black, 93f32)] {
+                for (i, attempt) in shootout.attempts[color].iter().enumerate() {
+                    let x = 640f32 + 22f32 * i as f32;
+                    draw_circle(x, y, 8f32, if attempt.scored { GREEN } else { RED });
+                    draw_circle(x + 1920f32, y, 8f32, WHITE);
+                }
+            }
+        }
         if let Some(flag) = &state.white.flag {
             draw_texture_both_ex!(
                 flag,
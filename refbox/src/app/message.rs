@@ -3,15 +3,17 @@ use crate::{
     config::{BeepTestPreset, GameSource},
     portal_manager::{ItemId, PortalEvent},
     sound_controller::{BuzzerSound, RemoteId},
-    tournament_manager::{TournamentManager, penalty::PenaltyKind},
+    tournament_manager::{
+        TimelineEdit, TournamentManager, incident::IncidentCategory, penalty::PenaltyKind,
+    },
 };
 use std::sync::{Arc, Mutex};
 use tokio::{sync::mpsc::Sender, time::Duration};
 use uwh_common::{
     color::Color as GameColor,
-    game_snapshot::{GameSnapshot, Infraction},
+    game_snapshot::{GameSnapshot, GoalDisallowReason, Infraction},
     uwhportal::{
-        PortalTokenResponse,
+        PortalTokenResponse, RosterPlayer,
         schedule::{Event, EventId, Schedule, TeamId, TeamList},
     },
 };
@@ -38,6 +40,23 @@ pub enum Message {
     ScoreEditComplete {
         canceled: bool,
     },
+    DisallowGoal,
+    ExportResults,
+    /// Open the player statistics page, or switch it between the top scorers and the
+    /// discipline table
+    ShowPlayerStats {
+        discipline: bool,
+    },
+    ExportPlayerStats,
+    ShowTimeline,
+    ShowAuditTrail,
+    /// Open the editor for an entry on the timeline page
+    EditTimelineEntry(TimelineEdit),
+    SelectGoalToDisallow(GameColor, usize),
+    ChangeDisallowReason(GoalDisallowReason),
+    DisallowGoalComplete {
+        canceled: bool,
+    },
     PenaltyOverview,
     WarningOverview,
     FoulOverview,
@@ -80,6 +99,45 @@ pub enum Message {
     AddScoreComplete {
         canceled: bool,
     },
+    /// Operator tapped START SHOOTOUT in sudden death. Opens
+    /// `ConfirmationKind::StartShootout`.
+    StartShootout,
+    /// Operator tapped CATCH UP between games. Opens `ConfirmationKind::CatchUpPlan`.
+    PlanCatchUp,
+    /// Records the attempt on `KeypadPage::ShootoutAttempt`. `scored` is
+    /// ignored when `canceled` is set.
+    ShootoutAttemptComplete {
+        canceled: bool,
+        scored: bool,
+    },
+    RemoveLastShootoutAttempt,
+    /// Toggles whether `color`'s captain lodged a protest, on the result
+    /// sign-off page.
+    ToggleProtest(GameColor),
+    /// Closes `KeypadPage::ResultSignOff`, keeping the number entered
+    /// unless `canceled`.
+    SignOffEntryComplete {
+        canceled: bool,
+    },
+    /// Attaches the captains' sign-off to the ended game's result, then
+    /// archives and sends it.
+    ResultSignOffDone,
+    /// Opens `AppState::IncidentReport`, timing the report from now.
+    ReportIncident,
+    ChangeIncidentCategory(IncidentCategory),
+    /// The team involved in the incident being reported, if only one was.
+    ChangeIncidentTeam(Option<GameColor>),
+    /// Every keystroke in the incident report's text field.
+    IncidentDescriptionChanged(String),
+    /// Closes `KeypadPage::IncidentPlayer`, keeping the number entered
+    /// unless `canceled`.
+    IncidentPlayerComplete {
+        canceled: bool,
+    },
+    /// Files the incident report being written unless `canceled`.
+    IncidentReportComplete {
+        canceled: bool,
+    },
     ShowGameDetails,
     /// Operator tapped the power button on the game-info page. Opens the
     /// Pi power page (`AppState::PowerPage`).
@@ -183,6 +241,10 @@ pub enum Message {
     PenaltyShot(bool),
     EndTimeout,
     CancelTimeout,
+    /// Reverse the most recent goal, penalty, foul, team timeout or clock
+    /// start/stop. Repeatable, so several taps step further back.
+    Undo,
+    Redo,
     ConfirmScores(GameSnapshot),
     ScoreConfirmation {
         correct: bool,
@@ -190,10 +252,10 @@ pub enum Message {
     AutoConfirmScores(GameSnapshot),
     RecvEventList(Vec<Event>),
     RecvTeamsList(EventId, TeamList),
-    /// A team's roster arrived from the portal, reduced to the cap numbers on
-    /// it. Players with no cap number are dropped at the fetch — there is
-    /// nothing to tap for them.
-    RecvTeamRoster(TeamId, Vec<u8>),
+    /// A team's roster arrived from the portal. The names are kept for the
+    /// player statistics; the number grid only uses the players with a usable
+    /// cap number.
+    RecvTeamRoster(TeamId, Vec<RosterPlayer>),
     RecvSchedule(EventId, Schedule),
     RecvPortalToken(PortalTokenResponse),
     /// Result of a portal token-validity check for a specific event. Carries
@@ -347,6 +409,7 @@ impl Message {
             | Self::ToggleBoolParameter(_)
             | Self::SelectGameSource(_)
             | Self::CustomSiteUrlChanged(_)
+            | Self::IncidentDescriptionChanged(_)
             | Self::CycleParameter(_)
             | Self::RecvEventList(_)
             | Self::RecvTeamsList(_, _)
@@ -360,6 +423,8 @@ impl Message {
             | Self::PortalRefreshFinished
             | Self::BeepTestTick
             | Self::NoAction
+            | Self::Undo
+            | Self::Redo
             | Self::OpenNewDisplay => true,
 
             Self::EditTime
@@ -368,6 +433,16 @@ impl Message {
             | Self::EditScores
             | Self::AddNewScore(_)
             | Self::ScoreEditComplete { .. }
+            | Self::DisallowGoal
+            | Self::ExportResults
+            | Self::ShowPlayerStats { .. }
+            | Self::ExportPlayerStats
+            | Self::ShowTimeline
+            | Self::ShowAuditTrail
+            | Self::EditTimelineEntry(_)
+            | Self::SelectGoalToDisallow(_, _)
+            | Self::ChangeDisallowReason(_)
+            | Self::DisallowGoalComplete { .. }
             | Self::PenaltyOverview
             | Self::WarningOverview
             | Self::FoulOverview
@@ -382,6 +457,18 @@ impl Message {
             | Self::KeypadPage(_)
             | Self::ChangeColor(_)
             | Self::AddScoreComplete { .. }
+            | Self::StartShootout
+            | Self::PlanCatchUp
+            | Self::ShootoutAttemptComplete { .. }
+            | Self::RemoveLastShootoutAttempt
+            | Self::ToggleProtest(_)
+            | Self::SignOffEntryComplete { .. }
+            | Self::ResultSignOffDone
+            | Self::ReportIncident
+            | Self::ChangeIncidentCategory(_)
+            | Self::ChangeIncidentTeam(_)
+            | Self::IncidentPlayerComplete { .. }
+            | Self::IncidentReportComplete { .. }
             | Self::ShowGameDetails
             | Self::OpenPortalDetailPage
             | Self::ClosePortalDetailPage
@@ -485,7 +572,17 @@ impl PartialEq for Message {
         match (self, other) {
             (Self::EditTime, Self::EditTime)
             | (Self::StartPlayNow, Self::StartPlayNow)
+            | (Self::StartShootout, Self::StartShootout)
+            | (Self::PlanCatchUp, Self::PlanCatchUp)
+            | (Self::RemoveLastShootoutAttempt, Self::RemoveLastShootoutAttempt)
+            | (Self::ResultSignOffDone, Self::ResultSignOffDone)
+            | (Self::ReportIncident, Self::ReportIncident)
             | (Self::EditScores, Self::EditScores)
+            | (Self::DisallowGoal, Self::DisallowGoal)
+            | (Self::ExportResults, Self::ExportResults)
+            | (Self::ExportPlayerStats, Self::ExportPlayerStats)
+            | (Self::ShowTimeline, Self::ShowTimeline)
+            | (Self::ShowAuditTrail, Self::ShowAuditTrail)
             | (Self::PenaltyOverview, Self::PenaltyOverview)
             | (Self::WarningOverview, Self::WarningOverview)
             | (Self::FoulOverview, Self::FoulOverview)
@@ -508,6 +605,8 @@ impl PartialEq for Message {
             | (Self::UpdateAudioOutput, Self::UpdateAudioOutput)
             | (Self::EndTimeout, Self::EndTimeout)
             | (Self::CancelTimeout, Self::CancelTimeout)
+            | (Self::Undo, Self::Undo)
+            | (Self::Redo, Self::Redo)
             | (Self::StopClock, Self::StopClock)
             | (Self::StartClock, Self::StartClock)
             | (Self::BeepTestStart, Self::BeepTestStart)
@@ -582,6 +681,18 @@ impl PartialEq for Message {
             (Self::ScoreEditComplete { canceled: a }, Self::ScoreEditComplete { canceled: b }) => {
                 a == b
             }
+            (Self::SelectGoalToDisallow(a, b), Self::SelectGoalToDisallow(c, d)) => {
+                a == c && b == d
+            }
+            (Self::ChangeDisallowReason(a), Self::ChangeDisallowReason(b)) => a == b,
+            (Self::EditTimelineEntry(a), Self::EditTimelineEntry(b)) => a == b,
+            (Self::ShowPlayerStats { discipline: a }, Self::ShowPlayerStats { discipline: b }) => {
+                a == b
+            }
+            (
+                Self::DisallowGoalComplete { canceled: a },
+                Self::DisallowGoalComplete { canceled: b },
+            ) => a == b,
             (Self::Scroll { which: a, up: b }, Self::Scroll { which: c, up: d }) => {
                 a == c && b == d
             }
@@ -640,6 +751,32 @@ impl PartialEq for Message {
             (Self::AddScoreComplete { canceled: a }, Self::AddScoreComplete { canceled: b }) => {
                 a == b
             }
+            (
+                Self::ShootoutAttemptComplete {
+                    canceled: a,
+                    scored: b,
+                },
+                Self::ShootoutAttemptComplete {
+                    canceled: c,
+                    scored: d,
+                },
+            ) => a == c && b == d,
+            (Self::ToggleProtest(a), Self::ToggleProtest(b)) => a == b,
+            (
+                Self::SignOffEntryComplete { canceled: a },
+                Self::SignOffEntryComplete { canceled: b },
+            ) => a == b,
+            (Self::ChangeIncidentCategory(a), Self::ChangeIncidentCategory(b)) => a == b,
+            (Self::ChangeIncidentTeam(a), Self::ChangeIncidentTeam(b)) => a == b,
+            (Self::IncidentDescriptionChanged(a), Self::IncidentDescriptionChanged(b)) => a == b,
+            (
+                Self::IncidentPlayerComplete { canceled: a },
+                Self::IncidentPlayerComplete { canceled: b },
+            ) => a == b,
+            (
+                Self::IncidentReportComplete { canceled: a },
+                Self::IncidentReportComplete { canceled: b },
+            ) => a == b,
             (Self::ConfirmScores(a), Self::ConfirmScores(b)) => a == b,
             (Self::ScoreConfirmation { correct: a }, Self::ScoreConfirmation { correct: b }) => {
                 a == b
@@ -705,6 +842,16 @@ impl PartialEq for Message {
             | (Self::AddNewScore(_), _)
             | (Self::ChangeScore { .. }, _)
             | (Self::ScoreEditComplete { .. }, _)
+            | (Self::DisallowGoal, _)
+            | (Self::ExportResults, _)
+            | (Self::ShowPlayerStats { .. }, _)
+            | (Self::ExportPlayerStats, _)
+            | (Self::ShowTimeline, _)
+            | (Self::ShowAuditTrail, _)
+            | (Self::EditTimelineEntry(_), _)
+            | (Self::SelectGoalToDisallow(_, _), _)
+            | (Self::ChangeDisallowReason(_), _)
+            | (Self::DisallowGoalComplete { .. }, _)
             | (Self::PenaltyOverview, _)
             | (Self::WarningOverview, _)
             | (Self::FoulOverview, _)
@@ -722,6 +869,19 @@ impl PartialEq for Message {
             | (Self::SelectPlayerNumber(_), _)
             | (Self::ChangeColor(_), _)
             | (Self::AddScoreComplete { .. }, _)
+            | (Self::StartShootout, _)
+            | (Self::PlanCatchUp, _)
+            | (Self::ShootoutAttemptComplete { .. }, _)
+            | (Self::RemoveLastShootoutAttempt, _)
+            | (Self::ToggleProtest(_), _)
+            | (Self::SignOffEntryComplete { .. }, _)
+            | (Self::ResultSignOffDone, _)
+            | (Self::ReportIncident, _)
+            | (Self::ChangeIncidentCategory(_), _)
+            | (Self::ChangeIncidentTeam(_), _)
+            | (Self::IncidentDescriptionChanged(_), _)
+            | (Self::IncidentPlayerComplete { .. }, _)
+            | (Self::IncidentReportComplete { .. }, _)
             | (Self::ShowGameDetails, _)
             | (Self::OpenNewDisplay, _)
             | (Self::OpenPortalDetailPage, _)
@@ -766,6 +926,8 @@ impl PartialEq for Message {
             | (Self::PenaltyShot(_), _)
             | (Self::EndTimeout, _)
             | (Self::CancelTimeout, _)
+            | (Self::Undo, _)
+            | (Self::Redo, _)
             | (Self::ConfirmScores(_), _)
             | (Self::ScoreConfirmation { .. }, _)
             | (Self::AutoConfirmScores(_), _)
@@ -890,6 +1052,7 @@ pub enum LengthParameter {
     OvertimeHalf,
     OvertimeHalfTime,
     PreSuddenDeath,
+    StopTimeWindow,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -903,6 +1066,8 @@ pub enum ListableParameter {
 pub enum BoolGameParameter {
     OvertimeAllowed,
     SuddenDeathAllowed,
+    PenaltyShootoutAllowed,
+    StopTimeAllowed,
     // Emitted by the 2 Halves / 1 Period selector in the Half Length parameter
     // editor (surfaced per the former ADR-009 Task 14 TODO). Toggles the staged
     // `single_half` choice held in AppState::ParameterEditor.
@@ -922,6 +1087,7 @@ pub enum BoolGameParameter {
     TeamWarning,
     TimeoutsCountedPerHalf,
     ConfirmScore,
+    ConfirmResult,
     AudibleCountdown,
     ManualAlarmEnabled,
 }
@@ -935,6 +1101,8 @@ pub enum CyclingParameter {
     Mode,
     Brightness,
     FrontDisplayLayout,
+    ScoresheetStyle,
+    PenaltyShootoutRounds,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -944,6 +1112,9 @@ pub enum ScrollOption {
     Equal,
     GameParameter,
     PortalDetail,
+    PlayerStats,
+    Timeline,
+    AuditTrail,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -979,6 +1150,16 @@ pub enum KeypadPage {
         ret_to_overview: bool,
     },
     PortalLogin(u32, bool),
+    /// The shooter's cap number for a penalty shootout attempt. The team can
+    /// only be changed before the first attempt, when either may shoot first.
+    ShootoutAttempt {
+        color: GameColor,
+    },
+    /// The cap number, or other identifying number, of the captain signing
+    /// off the result for a team. `0` leaves the team unsigned.
+    ResultSignOff(GameColor),
+    /// The player involved in the incident being reported. `0` names no one.
+    IncidentPlayer(GameColor),
 }
 
 impl KeypadPage {
@@ -987,9 +1168,11 @@ impl KeypadPage {
             Self::AddScore { .. }
             | Self::Penalty(_, _, _, _)
             | Self::FoulAdd { .. }
-            | Self::WarningAdd { .. } => 99,
+            | Self::WarningAdd { .. }
+            | Self::ShootoutAttempt { .. }
+            | Self::IncidentPlayer(_) => 99,
             Self::TeamTimeouts(_, _) => 999,
-            Self::GameNumber => 9999,
+            Self::GameNumber | Self::ResultSignOff(_) => 9999,
             Self::PortalLogin(_, _) => 999_999,
         }
     }
@@ -999,11 +1182,14 @@ impl KeypadPage {
             Self::AddScore { .. }
             | Self::Penalty(_, _, _, _)
             | Self::FoulAdd { .. }
-            | Self::WarningAdd { .. } => fl!("player-number"),
+            | Self::WarningAdd { .. }
+            | Self::ShootoutAttempt { .. }
+            | Self::IncidentPlayer(_) => fl!("player-number"),
             Self::GameNumber => fl!("game-number"),
             Self::TeamTimeouts(_, true) => fl!("num-tos-per-half"),
             Self::TeamTimeouts(_, false) => fl!("num-tos-per-game"),
             Self::PortalLogin(_, _) => fl!("portal-login-code"),
+            Self::ResultSignOff(_) => fl!("cap-number-or-id"),
         }
     }
 }
@@ -1032,6 +1218,15 @@ pub enum ConfirmationOption {
     // Offered by ConfirmationKind::PortalTenantSwitch — restarts the app on the
     // new Mode/portal. Raised by apply_app_options (Task 9); handled in Task 8.
     RestartAndApply,
+    // Offered by ConfirmationKind::ResumeGame — restores the journaled game.
+    ResumeGame,
+    // Offered by ConfirmationKind::StartShootout — ends sudden death.
+    StartShootout,
+    // Offered by ConfirmationKind::CatchUpPlan — accepts the plan shown.
+    AcceptPlan,
+    // Offered by ConfirmationKind::CatchUpPlan — re-plans with or without
+    // shortened half-times.
+    ShortenHalfTimes(bool),
 }
 
 /// The three actions offered on the operator power page.
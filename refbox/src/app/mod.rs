@@ -2,11 +2,27 @@ use self::infraction::InfractionDetails;
 use super::{APP_NAME, fl};
 use crate::{
     beep_test::{cadence::TournamentManager as BeepTestManager, snapshot::BeepTestSnapshot},
-    config::{Config, CustomSite, GameSource, Mode, RemoteSource},
+    config::{Config, CustomSite, GameSource, Mode, RemoteSource, ScoresheetStyle},
     penalty_editor::*,
-    portal_manager::{ItemId, PortalEvent, PortalManager, SelectedEventId, UwhPortalIo},
+    portal_manager::{
+        ItemId, PortalEvent, PortalIndicatorState, PortalManager, SelectedEventId, UwhPortalIo,
+    },
+    results_archive::{self, ArchivedGame, ArchivedReferee, ResultsArchive},
+    rule_profile::RuleProfile,
+    schedule_file::{self, ScheduleFile},
+    scoresheet,
     sound_controller::*,
-    tournament_manager::{penalty::*, *},
+    suspensions::{self, SuspensionLedger},
+    tournament_manager::{
+        action::Action,
+        audit::{AuditChange, AuditClocks, AuditEntry, AuditFoul, AuditPenalty, AuditSource},
+        catch_up::{self, CatchUpPlan},
+        incident::IncidentCategory,
+        journal::{self, GameJournal, WallAnchor},
+        penalty::*,
+        session::SessionRecorder,
+        *,
+    },
 };
 use futures_lite::Stream;
 use iced::{
@@ -38,10 +54,16 @@ use uwh_common::{
     color::Color,
     config::Game as GameConfig,
     drawing_support::*,
-    game_snapshot::{GamePeriod, GameSnapshot, Infraction, TimeoutSnapshot},
+    game_data::{self, SharedSource, SingleEventSite},
+    game_snapshot::{GamePeriod, GameSnapshot, GoalDisallowReason, Infraction, TimeoutSnapshot},
     uwhportal::{
-        PortalTokenResponse, RosterPlayer, UwhPortalClient, check_access_key,
-        schedule::{DateRange, Event, EventId, GameNumber, Schedule, TeamId},
+        PortalTokenResponse, RosterPlayer, UwhPortalClient,
+        bracket::{Bracket, GameResults},
+        check_access_key,
+        player_stats::PlayerStats,
+        schedule::{
+            Event, EventId, GameNumber, Schedule, ScheduledTeam, TeamId, TeamRefAssignment,
+        },
     },
 };
 
@@ -64,6 +86,9 @@ use theme::*;
 pub mod update_sender;
 use update_sender::*;
 
+pub mod status_api;
+use status_api::{GameInfo, Games, StatusApi, StatusApiConfig};
+
 pub(crate) mod languages;
 use languages::*;
 
@@ -75,6 +100,8 @@ const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 /// How long the operator must hold a used-up team timeout button to revive
 /// (give back) one team timeout. Long enough to confirm the hold was intentional.
 const TIMEOUT_REVIVE_HOLD_DURATION: Duration = Duration::from_secs(3);
+/// The most rounds the SHOOTOUT ROUNDS button cycles up to before going back to one
+const MAX_SHOOTOUT_ROUNDS: u16 = 5;
 
 /// Which phase an in-progress timeout-revive long-press is in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -146,6 +173,8 @@ pub struct RefBoxApp {
     /// (mirrors `maybe_play_sound`'s use of `self.snapshot`).
     beep_test_snapshot: BeepTestSnapshot,
     config: Config,
+    /// The rule profile chosen at startup; the tournament manager holds a copy
+    rules: RuleProfile,
     edited_settings: Option<EditableSettings>,
     page_entry_snapshot: Option<PageEntrySnapshot>,
     snapshot: GameSnapshot,
@@ -159,6 +188,14 @@ pub struct RefBoxApp {
     time_edit_old: (Duration, Option<Duration>),
     last_message: Message,
     update_sender: UpdateSender,
+    /// The read-only HTTP status API, when `--status-port` was given
+    status_api: Option<StatusApi>,
+    /// Set when the schedule, the event's teams or the results behind the status API changed,
+    /// so the next `publish_status` republishes everything and not just the snapshot
+    status_stale: bool,
+    /// The portal queue version last published to the status API, see
+    /// [`PortalManager::queue_version`]
+    status_queue_version: Option<(u64, usize)>,
     uwhportal_client: Option<Arc<Mutex<UwhPortalClient>>>,
     /// Shared handle the background portal task reads to learn the
     /// currently-selected event for its periodic `verify_token` probe.
@@ -175,16 +212,22 @@ pub struct RefBoxApp {
     /// portal only — a custom site derives TLS from the scheme that was typed.
     require_https: bool,
     source: GameSource,
+    /// What the `File` source last loaded, folder and all. `None` until one has
+    /// loaded, and again after a load fails, so the Game page can say so.
+    schedule_file: Option<Arc<ScheduleFile>>,
     events: Option<BTreeMap<EventId, Event>>,
     schedule: Option<Schedule>,
+    /// `schedule` with its slots filled in from the games played on this refbox, see
+    /// [`Self::refresh_resolved_schedule`]
+    resolved_schedule: Option<Schedule>,
     /// The running game's copy of both teams' cap numbers, taken at kickoff so
     /// a mid-game REFRESH cannot move the grid under the operator's hand. Empty
     /// vectors mean "no usable roster" and the number pad is shown.
     game_rosters: BlackWhiteBundle<Vec<u8>>,
-    /// Cap numbers for every team the portal has told us about, keyed by portal
+    /// Rosters for every team the portal has told us about, keyed by portal
     /// team id. Session-only: never written to disk, so a restart with no
     /// network falls back to the number pad until a fetch succeeds.
-    team_rosters: BTreeMap<TeamId, Vec<u8>>,
+    team_rosters: BTreeMap<TeamId, Vec<RosterPlayer>>,
     current_event_id: Option<EventId>,
     current_court: Option<String>,
     /// One-shot: the game number to re-select once the schedule arrives during
@@ -227,6 +270,13 @@ pub struct RefBoxApp {
     alarm_delay_token: u64,
     /// The in-progress timeout-revive long-press, if any (`None` = no hold active).
     timeout_revive: Option<ReviveHold>,
+    /// A game result held back until the captains have signed it off, when
+    /// `config.confirm_result` is set. Kept outside `AppState::ResultSignOff` so it survives a
+    /// trip to the keypad.
+    sign_off: Option<PendingSignOff>,
+    /// The incident report being written on `AppState::IncidentReport`. Kept outside the state
+    /// for the same reason as `sign_off`.
+    incident: Option<IncidentDetails>,
     /// Monotonic source of revive-timer tokens (never reset; guards stale timers).
     timeout_revive_token: u64,
     portal_manager: PortalManager,
@@ -254,6 +304,22 @@ pub struct RefBoxApp {
     /// Version of the on-disk backup (`refbox-v*.bak`), if one exists. Shown on
     /// the Revert button so the operator sees which version they'd roll back to.
     update_backup_version: Option<crate::updater::version::Version>,
+    /// Maps the game clock's `Instant`s to wall-clock time for the game
+    /// journal. Fixed for the life of the process so an unchanged game always
+    /// journals identically.
+    journal_anchor: WallAnchor,
+    /// The journal last written to disk, used to skip rewrites when nothing
+    /// about the game changed.
+    last_journal: Option<GameJournal>,
+    /// A journal found at startup that is waiting on the operator's
+    /// resume-or-start-fresh choice. Journaling is paused until it is answered
+    /// so the idle startup game cannot overwrite it.
+    pending_resume: Option<GameJournal>,
+    /// Players sitting out games for a total dismissal, across every event
+    /// this refbox has run games for
+    suspensions: SuspensionLedger,
+    /// Every game this refbox has finished, kept for export
+    results: ResultsArchive,
     /// Debug-only one-shot: when `UWH_PORTAL_SCRAMBLE_TOKEN` is set in a
     /// debug build, this starts `true` and is cleared the first time
     /// `set_current_event_id` is called with `Some(_)`. At that point
@@ -271,10 +337,12 @@ pub struct RefBoxApp {
 #[derive(Debug)]
 pub struct RefBoxAppFlags {
     pub config: Config,
+    pub rules: RuleProfile,
     pub config_dir: std::path::PathBuf,
     pub serial_ports: Vec<SerialPortBuilder>,
     pub binary_port: u16,
     pub json_port: u16,
+    pub websocket_port: u16,
     pub sim_children: Vec<Child>,
     pub sim_spawn_config: crate::SimSpawnConfig,
     pub require_https: bool,
@@ -287,6 +355,18 @@ pub struct RefBoxAppFlags {
     /// on this boot. Makes `new()` land on the Updates page with a one-time
     /// rollback notice instead of the normal main screen.
     pub show_rolled_back: bool,
+    /// Where to write a session file of everything done to the game, if anywhere
+    pub record_session: Option<std::path::PathBuf>,
+    /// Where to serve the read-only HTTP status API, if anywhere
+    pub status_api: Option<StatusApiConfig>,
+}
+
+/// The result of an ended game, and the captains' sign-off entered for it so far
+#[derive(Debug, Clone)]
+struct PendingSignOff {
+    game_number: GameNumber,
+    scores: BlackWhiteBundle<u8>,
+    sign_offs: BlackWhiteBundle<TeamSignOff>,
 }
 
 #[derive(Debug, Clone)]
@@ -297,6 +377,14 @@ enum AppState {
         scores: BlackWhiteBundle<u8>,
         is_confirmation: bool,
     },
+    /// `selected` is the goal picked so far, as a side and that side's index
+    /// into `GameSnapshot::goals`. `indices` are the scroll offsets of the two
+    /// goal lists.
+    DisallowGoal {
+        selected: Option<(Color, usize)>,
+        reason: GoalDisallowReason,
+        indices: BlackWhiteBundle<usize>,
+    },
     PenaltyOverview(BlackWhiteBundle<usize>),
     WarningOverview(BlackWhiteBundle<usize>),
     FoulOverview(OptColorBundle<usize>),
@@ -314,11 +402,33 @@ enum AppState {
     ParameterList(ListableParameter, usize),
     ConfirmationPage(ConfirmationKind),
     ConfirmScores(BlackWhiteBundle<u8>),
+    /// Each captain signs off the result of the game that just ended, or lodges a protest. What
+    /// they have entered so far is in `RefBoxApp::sign_off`.
+    ResultSignOff,
+    /// A referee files an incident report against the game in progress. What they have written
+    /// so far is in `RefBoxApp::incident`.
+    IncidentReport,
     /// `scroll_index` is the current scroll offset into the detail-row
     /// list (see `make_scroll_list` in `shared_elements.rs`).
     PortalDetailPage {
         scroll_index: usize,
     },
+    /// The event's player statistics: its discipline table when `discipline` is set, otherwise
+    /// its top scorers. `scroll_index` is the scroll offset into the list.
+    PlayerStatsPage {
+        discipline: bool,
+        scroll_index: usize,
+    },
+    /// Every event of the current game, most recent first. `scroll_index` is the scroll offset
+    /// into the list.
+    TimelinePage {
+        scroll_index: usize,
+    },
+    /// The changes made by hand to the score, clocks and penalties of the current game, most
+    /// recent first. `scroll_index` is the scroll offset into the list.
+    AuditTrailPage {
+        scroll_index: usize,
+    },
     /// Shown when the operator taps a red stuck row on the detail page.
     /// `discard_armed` is the two-tap confirmation state for the
     /// DISCARD button; it starts false and flips to true on the first
@@ -397,6 +507,24 @@ enum ConfirmationKind {
     /// ACCESS TOKEN row lives only on the Game config page, so there is one
     /// place to return to.
     LinkLockedByGame,
+    /// A game was in progress when the refbox last stopped. Offers to resume it
+    /// from the game journal or to start fresh (ADR 013, Option B).
+    ResumeGame {
+        game_number: GameNumber,
+        scores: BlackWhiteBundle<u8>,
+    },
+    /// The operator asked to settle a tied sudden death with a penalty
+    /// shootout. Sudden death can't be returned to, so it is confirmed first.
+    StartShootout,
+    /// The operator asked how to get back on schedule. Shows the plan and
+    /// offers to re-plan with shortened half-times. Only a plan that shortens
+    /// half-times can be accepted; without that it's just a forecast.
+    CatchUpPlan {
+        plan: CatchUpPlan,
+        shorten_half_times: bool,
+    },
+    /// The results archive was exported, to the directory carried here
+    ResultsExported(String),
 }
 
 /// Which of the two kinds of site an address belongs to. Decides which saved
@@ -434,7 +562,9 @@ struct SiteTarget {
 ///
 /// `Manual` returns `None` deliberately: with manual games nothing is fetched,
 /// but results already queued must keep going to the site they were queued for
-/// rather than following the operator back to the built-in portal.
+/// rather than following the operator back to the built-in portal. `File`
+/// fetches nothing either, and returns `None` for the same reason: the results
+/// it queues wait for whichever site the refbox was last pointed at.
 ///
 /// `Custom` returns `None` when the saved address is empty or unusable, which
 /// leaves the client where it is instead of pointing it at nothing.
@@ -445,7 +575,7 @@ fn site_target(
     portal_require_https: bool,
 ) -> Option<SiteTarget> {
     match source {
-        GameSource::Manual => None,
+        GameSource::Manual | GameSource::File => None,
         GameSource::Portal => Some(portal_target(mode, portal_require_https)),
         GameSource::Custom => {
             custom_site::parse_custom_site(&custom_site.url)
@@ -586,6 +716,8 @@ pub(crate) enum PageEntrySnapshot {
         confirm_score: bool,
         hide_time: bool,
         audible_countdown: bool,
+        scoresheet_style: ScoresheetStyle,
+        confirm_result: bool,
     },
     Display {
         white_on_right: bool,
@@ -643,6 +775,8 @@ impl PageEntrySnapshot {
                 confirm_score,
                 hide_time,
                 audible_countdown,
+                scoresheet_style,
+                confirm_result,
             } => {
                 edited.source = source;
                 edited.current_event_id = current_event_id;
@@ -655,6 +789,8 @@ impl PageEntrySnapshot {
                 edited.confirm_score = confirm_score;
                 edited.hide_time = hide_time;
                 edited.audible_countdown = audible_countdown;
+                edited.scoresheet_style = scoresheet_style;
+                edited.confirm_result = confirm_result;
             }
             PageEntrySnapshot::Display {
                 white_on_right,
@@ -740,10 +876,18 @@ impl RefBoxApp {
         }
 
         new_snapshot.event_id = self.current_event_id.clone();
+        new_snapshot.team_names = self.scheduled_team_names(&new_snapshot.game_number);
 
         self.maybe_play_sound(&new_snapshot);
+        self.persist_journal();
+        let timing_rule = self
+            .schedule
+            .as_ref()
+            .and_then(|schedule| schedule.get_game_timing(&new_snapshot.game_number))
+            .cloned();
         if let Err(e) = self.update_sender.send_snapshot(
             new_snapshot.clone(),
+            timing_rule,
             self.config.hardware.white_on_right,
             self.config.hardware.brightness,
         ) {
@@ -783,7 +927,8 @@ impl RefBoxApp {
                     | GamePeriod::SecondHalf
                     | GamePeriod::OvertimeFirstHalf
                     | GamePeriod::OvertimeSecondHalf
-                    | GamePeriod::SuddenDeath => false,
+                    | GamePeriod::SuddenDeath
+                    | GamePeriod::PenaltyShootout => false,
                 };
 
                 let (end_starts_play, end_stops_play) = match new_snapshot.current_period {
@@ -796,7 +941,7 @@ impl RefBoxApp {
                     | GamePeriod::PreOvertime
                     | GamePeriod::OvertimeHalfTime
                     | GamePeriod::PreSuddenDeath => (true, false),
-                    GamePeriod::SuddenDeath => (false, false),
+                    GamePeriod::SuddenDeath | GamePeriod::PenaltyShootout => (false, false),
                 };
 
                 let is_buzz_period = end_starts_play && self.config.sound.auto_sound_start_play
@@ -879,15 +1024,45 @@ impl RefBoxApp {
         self.beep_test_has_run = false;
     }
 
+    /// Where the data for `source` comes from: the loaded schedule folder for
+    /// `File`, and otherwise the site the client points at. `Manual` fetches
+    /// nothing of its own and leaves the site where it is, as `site_target`
+    /// does, so whatever is already underway finishes against it. A custom
+    /// site stands for the one event its address names.
+    fn data_source(&self, source: GameSource) -> Option<SharedSource> {
+        match source {
+            GameSource::File => self.schedule_file.clone().map(|file| file as SharedSource),
+            GameSource::Manual | GameSource::Portal | GameSource::Custom => {
+                let client = self.uwhportal_client.clone()?;
+                match self.current_site.kind {
+                    SiteKind::Portal => Some(client),
+                    SiteKind::Custom => {
+                        match custom_site::parse_custom_site(&self.current_site.address) {
+                            Ok(parsed) => {
+                                Some(Arc::new(SingleEventSite::new(parsed.event_id, client)))
+                            }
+                            // Never built from an unusable address, see `site_target`
+                            Err(_) => Some(client),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// The source for the source being shown: the editor's choice while it is
+    /// open, since that is what its pickers are filled from.
+    fn fetch_source(&self) -> Option<SharedSource> {
+        let source = self
+            .edited_settings
+            .as_ref()
+            .map_or(self.source, |edits| edits.source);
+        self.data_source(source)
+    }
+
     fn request_event_list(&self) -> Task<Message> {
-        if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: the `UwhPortalClient` is only mutated by
-            // `set_token`/`clear_token`, neither of which panics, so the
-            // mutex is never poisoned in practice.
-            let request = client
-                .lock()
-                .unwrap()
-                .get_event_list(self.list_all_events, true);
+        if let Some(source) = self.fetch_source() {
+            let request = source.list_events(self.list_all_events, true);
             Task::future(async move {
                 match request.await {
                     Ok(events) => {
@@ -937,9 +1112,8 @@ impl RefBoxApp {
     }
 
     fn request_teams_list(&self, event_id: EventId) -> Task<Message> {
-        if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: see `request_event_list` above.
-            let request = client.lock().unwrap().get_event_teams(&event_id);
+        if let Some(source) = self.fetch_source() {
+            let request = source.teams(&event_id);
             Task::future(async move {
                 match request.await {
                     Ok(teams) => {
@@ -958,19 +1132,17 @@ impl RefBoxApp {
     }
 
     fn request_team_roster(&self, team_id: TeamId) -> Task<Message> {
-        if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: see `request_event_list` above.
-            let request = client.lock().unwrap().get_team_roster(&team_id);
+        if let Some(source) = self.fetch_source() {
+            let request = source.roster(&team_id);
             Task::future(async move {
                 match request.await {
                     Ok(players) => {
-                        let numbers = usable_cap_numbers(&players);
                         info!(
                             "Got roster for team {}: {} numbered players",
                             team_id.full(),
-                            numbers.len()
+                            usable_cap_numbers(&players).len()
                         );
-                        Message::RecvTeamRoster(team_id, numbers)
+                        Message::RecvTeamRoster(team_id, players)
                     }
                     Err(e) => {
                         // A failure must leave whatever is cached untouched, so
@@ -986,14 +1158,11 @@ impl RefBoxApp {
     }
 
     fn request_schedule(&self, event_id: EventId) -> Task<Message> {
-        if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: see `request_event_list` above.
-            let guard = client.lock().unwrap();
-            let schedule_req = guard.get_event_schedule_privileged(&event_id);
-            let names_req = guard.get_event_referee_name_map_from_referees(&event_id);
-            drop(guard);
+        if let Some(source) = self.fetch_source() {
+            let schedule_req = source.schedule(&event_id);
+            let names_req = source.referee_names(&event_id);
             Task::future(async move {
-                let mut schedule = match schedule_req.await {
+                let schedule = match schedule_req.await {
                     Ok(s) => s,
                     Err(e) => {
                         error!("Failed to get schedule: {e}");
@@ -1010,17 +1179,7 @@ impl RefBoxApp {
                         Default::default()
                     }
                 };
-                for game in schedule.games.values_mut() {
-                    if let Some(assignments) = &mut game.referee_assignments {
-                        for assignment in assignments.iter_mut() {
-                            if let Some(uid) = &assignment.user_id {
-                                if let Some(name) = name_map.get(uid) {
-                                    assignment.display_name = Some(name.clone());
-                                }
-                            }
-                        }
-                    }
-                }
+                let schedule = game_data::with_referee_names(schedule, &name_map);
                 info!("Got schedule");
                 Message::RecvSchedule(event_id, schedule)
             })
@@ -1031,7 +1190,9 @@ impl RefBoxApp {
 
     fn request_uwhportal_token(&self, event_id: &EventId, code: u32) -> Task<Message> {
         if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: see `request_event_list` above.
+            // why this cannot panic: the `UwhPortalClient` is only mutated by
+            // `set_token`/`clear_token`, neither of which panics, so the
+            // mutex is never poisoned in practice.
             let request = client.lock().unwrap().login_to_portal(event_id, code);
             let portal_name = portal_name_for_mode(self.config.mode);
             Task::future(async move {
@@ -1051,25 +1212,26 @@ impl RefBoxApp {
         }
     }
 
-    /// Whether games come from a remote site at all, as opposed to being
-    /// entered by hand. Most callers only need this question; the few that must
-    /// tell the official Portal from a third-party site match on `source`.
+    /// Whether games come from a schedule at all — a remote site or a schedule
+    /// file — as opposed to being entered by hand. Most callers only need this
+    /// question; the few that must tell the sources apart match on `source`.
     fn uses_remote(&self) -> bool {
         !matches!(self.source, GameSource::Manual)
     }
 
     /// Commit an applied source: the live field, the saved field so a relaunch
-    /// comes back on the same source, and — for a real remote — the one to
+    /// comes back on the same source, and — for anything but MANUAL — the one to
     /// return to when MANUAL is switched off again.
     ///
-    /// Only a real remote is remembered: applying MANUAL leaves the previous
-    /// choice standing, which is the whole point of keeping it separately.
+    /// MANUAL itself is never remembered: applying it leaves the previous choice
+    /// standing, which is the whole point of keeping it separately.
     fn commit_source(&mut self, source: GameSource) {
         self.source = source;
         self.config.source = source;
         match source {
             GameSource::Portal => self.config.remembered_remote = RemoteSource::Portal,
             GameSource::Custom => self.config.remembered_remote = RemoteSource::Custom,
+            GameSource::File => self.config.remembered_remote = RemoteSource::File,
             GameSource::Manual => {}
         }
     }
@@ -1228,23 +1390,11 @@ impl RefBoxApp {
         let event_changed = self.current_event_id.as_ref() != Some(&event_id);
 
         let events = self.events.get_or_insert_with(BTreeMap::new);
-        events.entry(event_id.clone()).or_insert_with(|| Event {
-            id: event_id.clone(),
-            // Only ever shown in the event picker, which a custom site never
-            // opens. The id keeps it recognisable in a log rather than blank.
-            name: event_id.partial().to_string(),
-            slug: String::new(),
-            // A custom site serves no event-level date range, and the only
-            // reader sorts the event picker — unreachable here. Both ends are
-            // set to now rather than invented.
-            date_range: DateRange {
-                start: time::OffsetDateTime::now_utc(),
-                end: time::OffsetDateTime::now_utc(),
-            },
-            teams: None,
-            schedule: None,
-            courts: None,
-        });
+        // The name is only ever shown in the event picker, which a custom site
+        // never opens, and the date range only sorts it.
+        events
+            .entry(event_id.clone())
+            .or_insert_with(|| game_data::placeholder_event(&event_id));
 
         // Route through set_current_event_id so portal_event_id stays in sync
         // for the background health check (ADR 011 amendment 2026-04-23).
@@ -1258,7 +1408,7 @@ impl RefBoxApp {
         // which needs `current_court` to be `None`.
         if event_changed {
             self.current_court = None;
-            self.schedule = None;
+            self.set_schedule(None);
         }
         if let Some(ref mut edits) = self.edited_settings {
             if event_changed {
@@ -1275,9 +1425,73 @@ impl RefBoxApp {
         ])
     }
 
+    /// Load the schedule folder and adopt its event, for the FILE source.
+    ///
+    /// Everything the portal path would fetch is put where the fetch would have
+    /// put it: the events-map entry with the file's teams, the rosters in the
+    /// roster cache, and the schedule handed to `RecvSchedule` as if it had just
+    /// arrived. From there next-game selection, timing rules and referee names
+    /// all take the portal path unchanged.
+    ///
+    /// With the editor open the event is only staged, like an event picked from
+    /// the portal's list, and APPLY commits it. With it closed — at startup, or
+    /// on REFRESH — there is nothing to stage, so it is committed directly, as
+    /// `adopt_custom_event` does.
+    ///
+    /// Returns `None` when no folder was found or it would not load. The reason
+    /// is logged, and `schedule_folder` is cleared so the Game page shows it.
+    fn adopt_schedule_file(&mut self) -> Option<Task<Message>> {
+        self.schedule_file = None;
+        let Some(folder) =
+            schedule_file::find_folder(self.config.schedule_folder.as_deref(), &self.config_dir)
+        else {
+            error!(
+                "No schedule folder configured, and no {} folder found",
+                schedule_file::FOLDER_NAME
+            );
+            return None;
+        };
+        let file = match schedule_file::load(&folder) {
+            Ok(file) => Arc::new(file),
+            Err(e) => {
+                error!("Failed to load the schedule folder {folder:?}: {e}");
+                return None;
+            }
+        };
+        let event_id = file.event.id.clone();
+
+        self.team_rosters
+            .extend(file.rosters.iter().map(|(id, r)| (id.clone(), r.clone())));
+        // Replaced rather than only created when absent: loading again is how an
+        // edited file is picked up.
+        self.events
+            .get_or_insert_with(BTreeMap::new)
+            .insert(event_id.clone(), file.event.clone());
+
+        if let Some(ref mut edits) = self.edited_settings {
+            if edits.current_event_id.as_ref() != Some(&event_id) {
+                edits.select_event(event_id.clone());
+            }
+        } else if self.current_event_id.as_ref() != Some(&event_id) {
+            self.set_current_event_id(Some(event_id.clone()));
+            // See `adopt_custom_event` for why a different event clears these
+            self.current_court = None;
+            self.set_schedule(None);
+        }
+
+        info!(
+            "Loaded event {} from the schedule folder {:?}",
+            event_id.full(),
+            file.folder
+        );
+        let schedule = file.schedule.clone();
+        self.schedule_file = Some(file);
+        Some(Task::done(Message::RecvSchedule(event_id, schedule)))
+    }
+
     fn check_uwhportal_auth(&self, event_id: &EventId) -> Task<Message> {
         if let Some(client) = &self.uwhportal_client {
-            // why this cannot panic: see `request_event_list` above.
+            // why this cannot panic: see `request_uwhportal_token` above.
             let has_token = client.lock().unwrap().has_token();
             if !has_token {
                 // Never ask a site to vouch for a credential we do not hold.
@@ -1287,7 +1501,7 @@ impl RefBoxApp {
                 // instead, without sending the request.
                 return Task::done(Message::RecvTokenValid(event_id.clone(), false));
             }
-            // why this cannot panic: see `request_event_list` above.
+            // why this cannot panic: see `request_uwhportal_token` above.
             let request = client.lock().unwrap().verify_token(event_id);
             // Tag the result with the event it was checked for so the handler
             // can drop a late reply for a previously-selected event.
@@ -1322,9 +1536,9 @@ impl RefBoxApp {
         if let Some(schedule) = &self.schedule {
             if let Some(game) = schedule.games.get(game_num) {
                 for (color, team) in [(Color::Black, &game.dark), (Color::White, &game.light)] {
-                    if let Some(numbers) = team.assigned().and_then(|id| self.team_rosters.get(id))
+                    if let Some(players) = team.assigned().and_then(|id| self.team_rosters.get(id))
                     {
-                        out[color] = numbers.clone();
+                        out[color] = usable_cap_numbers(players);
                     }
                 }
             }
@@ -1341,6 +1555,20 @@ impl RefBoxApp {
 
         let mut tasks: Vec<Task<Message>> = Vec::new();
 
+        // A result the captains never finished signing off is not held back past the next
+        // kickoff: it goes with whatever they entered
+        if self.sign_off.is_some() {
+            warn!("Next game started before the result was signed off, recording it as it stands");
+            tasks.push(self.finish_sign_off());
+            if matches!(
+                self.app_state,
+                AppState::ResultSignOff | AppState::KeypadPage(KeypadPage::ResultSignOff(_), _)
+            ) {
+                self.app_state = AppState::MainPage;
+                trace!("AppState changed to {:?}", self.app_state);
+            }
+        }
+
         if self.uses_remote() {
             debug!("Searching for next game info after game {new_game_num}");
             if let (Some(schedule), Some(pool)) = (&self.schedule, &self.current_court) {
@@ -1367,7 +1595,8 @@ impl RefBoxApp {
                         timing,
                         start_time: Some(next_game.start_time),
                     };
-                    tm.set_next_game(info);
+                    tm.apply(Action::SetNextGame(Box::new(info)), Instant::now())
+                        .unwrap();
                     Some(next_game.number.clone())
                 } else {
                     error!("Couldn't find a next game");
@@ -1396,6 +1625,11 @@ impl RefBoxApp {
     fn roster_refresh_tasks(&self, game_num: &GameNumber) -> Vec<Task<Message>> {
         let mut tasks = Vec::new();
 
+        // A schedule file's rosters came with it; there is nothing to re-pull
+        if self.source == GameSource::File {
+            return tasks;
+        }
+
         if let Some(schedule) = &self.schedule {
             if let Some(game) = schedule.games.get(game_num) {
                 for team in [&game.dark, &game.light] {
@@ -1410,32 +1644,94 @@ impl RefBoxApp {
     }
 
     fn handle_game_end(&mut self, game_number: &GameNumber) -> Task<Message> {
-        let mut tasks = vec![];
+        if self.config.confirm_result {
+            let scores = {
+                // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
+                let tm = self.tm.lock().unwrap();
+                tm.last_game_info()
+                    .filter(|info| {
+                        recorded_result_matches_ended_game(Some(&info.game_number), game_number)
+                    })
+                    .map(|info| info.scores)
+            };
+            // The result is archived and sent once the captains are done with it
+            if let Some(scores) = scores {
+                self.sign_off = Some(PendingSignOff {
+                    game_number: game_number.clone(),
+                    scores,
+                    sign_offs: Default::default(),
+                });
+                self.app_state = AppState::ResultSignOff;
+                trace!("AppState changed to {:?}", self.app_state);
+                return Task::none();
+            }
+        }
+        self.record_result(game_number)
+    }
+
+    /// Attach the captains' sign-off to the result waiting on it, then archive and send it
+    fn finish_sign_off(&mut self) -> Task<Message> {
+        let Some(pending) = self.sign_off.take() else {
+            return Task::none();
+        };
+        info!(
+            "Result of game {} signed off: {:?}",
+            pending.game_number, pending.sign_offs
+        );
+        {
+            // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
+            let mut tm = self.tm.lock().unwrap();
+            if let Err(e) = tm.sign_off_result(pending.sign_offs, Instant::now()) {
+                error!("Failed to record the result sign-off: {e}");
+            }
+        }
+        self.record_result(&pending.game_number)
+    }
+
+    /// Archive the ended game's result and, with a remote game source, send it to the portal
+    fn record_result(&mut self, game_number: &GameNumber) -> Task<Message> {
+        let mut tasks = vec![self.archive_result(game_number)];
         if self.uses_remote() {
             // Copy everything needed out from under the lock: the recorded result's own
             // game number, its scores, and its stats JSON.
             let recorded = {
                 // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
                 let tm = self.tm.lock().unwrap();
-                tm.last_game_info()
-                    .map(|info| (info.game_number.clone(), info.scores, info.stats.as_json()))
+                tm.last_game_info().map(|info| {
+                    (
+                        info.game_number.clone(),
+                        info.scores,
+                        info.stats.as_json(),
+                        info.stats.total_dismissals(),
+                        info.stats.protest_lodged(),
+                    )
+                })
             };
 
             match recorded {
-                Some((recorded_game, scores, stats))
+                Some((recorded_game, scores, stats, dismissals, protest))
                     if recorded_result_matches_ended_game(Some(&recorded_game), game_number) =>
                 {
                     info!("Game ended, scores: {scores:?} stats were: {stats:?}");
 
+                    if let Some(event_id) = self.current_event_id.clone() {
+                        self.record_suspensions(&event_id, game_number, &dismissals);
+                    }
+
                     if let Some(ref event_id) = self.current_event_id {
                         let event_id_str = event_id.full().to_string();
-                        tasks.push(self.request_schedule(event_id.clone()));
+                        // A schedule file has no newer copy to fetch. Its
+                        // knockout slots fill in from the results archive.
+                        if self.source != GameSource::File {
+                            tasks.push(self.request_schedule(event_id.clone()));
+                        }
                         if let Err(e) = self.portal_manager.enqueue_game_end(
                             event_id_str,
                             game_number.to_string(),
                             scores.black,
                             scores.white,
                             stats,
+                            protest,
                         ) {
                             error!("portal_manager.enqueue_game_end failed: {e}");
                         }
@@ -1443,7 +1739,7 @@ impl RefBoxApp {
                         error!("Missing current event id to handle game end");
                     }
                 }
-                Some((recorded_game, _, _)) => {
+                Some((recorded_game, ..)) => {
                     warn!(
                         "Clock left game {game_number} without a result being recorded for it \
                          (the newest recorded result belongs to game {recorded_game}); nothing \
@@ -1462,6 +1758,422 @@ impl RefBoxApp {
         Task::batch(tasks)
     }
 
+    /// Keep a finished game's result on the refbox, whether or not it is also sent to a portal,
+    /// and write its completed scoresheet if one is wanted. The returned task prints the sheet to
+    /// PDF.
+    fn archive_result(&mut self, game_number: &GameNumber) -> Task<Message> {
+        let (scores, stats, sheet_lines, sign_offs, incidents, started_at, ended_at, config) = {
+            // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
+            let tm = self.tm.lock().unwrap();
+            let Some(info) = tm.last_game_info() else {
+                return Task::none();
+            };
+            if !recorded_result_matches_ended_game(Some(&info.game_number), game_number) {
+                return Task::none();
+            }
+            (
+                info.scores,
+                info.stats.as_json(),
+                info.stats.sheet_lines(),
+                info.stats.sign_offs(),
+                info.stats.incidents().to_vec(),
+                info.stats.start_timestamp(),
+                info.stats.end_timestamp(),
+                tm.config().clone(),
+            )
+        };
+
+        let (game, timing_rule) = self
+            .schedule
+            .as_ref()
+            .map(|schedule| schedule.get_game_and_timing(game_number))
+            .unwrap_or_default();
+        let team_name = |id: Option<&TeamId>| self.team_name(id?);
+
+        let mut referees: Vec<_> = game
+            .and_then(|game| game.referee_assignments.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(|assignment| {
+                let name = assignment
+                    .display_name
+                    .clone()
+                    .or_else(|| team_name(assignment.team_id.as_ref()))
+                    .or_else(|| assignment.user_id.clone())?;
+                Some(ArchivedReferee {
+                    role: assignment.role.clone(),
+                    name,
+                })
+            })
+            .collect();
+        if let Some(teams) = self
+            .schedule
+            .as_ref()
+            .and_then(|schedule| schedule.referees_by_game_number.as_ref()?.get(game_number))
+        {
+            let team_roles: [(&str, &Option<TeamRefAssignment>); 3] = [
+                ("Time/Score Keeper", &teams.time_or_score_keeper),
+                ("Time/Score Helper", &teams.time_or_score_helper),
+                ("Referees", &teams.referees),
+            ];
+            referees.extend(team_roles.into_iter().filter_map(|(role, assignment)| {
+                let team = assignment.as_ref()?.team.as_ref()?;
+                let name = team.name.clone().or_else(|| team_name(team.id.as_ref()))?;
+                Some(ArchivedReferee {
+                    role: role.to_string(),
+                    name,
+                })
+            }));
+        }
+
+        // The event day is the one in the schedule's time zone, so a late game doesn't land on
+        // the next day in UTC
+        let offset = game
+            .map(|game| game.start_time.offset())
+            .or_else(|| time::UtcOffset::current_local_offset().ok())
+            .unwrap_or(time::UtcOffset::UTC);
+        let ended_at = ended_at
+            .unwrap_or_else(time::OffsetDateTime::now_utc)
+            .to_offset(offset);
+
+        let archived = ArchivedGame {
+            event_id: self.current_event_id.clone(),
+            game_number: game_number.clone(),
+            day: ended_at.date(),
+            started_at: started_at.map(|t| t.to_offset(offset)),
+            ended_at,
+            teams: self.scheduled_team_names(game_number),
+            team_ids: self.scheduled_teams(game_number),
+            scores,
+            timing_rule: timing_rule.map(|rule| rule.name.clone()),
+            config,
+            referees,
+            stats: serde_json::from_str(&stats).unwrap_or_default(),
+            incidents,
+        };
+        let task = self.write_scoresheet(&archived, &sheet_lines, sign_offs);
+        self.results.record(archived);
+        self.refresh_resolved_schedule();
+        self.publish_results();
+        if let Err(e) = results_archive::save(&self.config_dir, &self.results) {
+            error!("Failed to write results.json: {e}");
+        }
+        task
+    }
+
+    fn write_scoresheet(
+        &self,
+        game: &ArchivedGame,
+        lines: &[SheetLine],
+        sign_offs: Option<BlackWhiteBundle<TeamSignOff>>,
+    ) -> Task<Message> {
+        if self.config.scoresheet_style == ScoresheetStyle::Off {
+            return Task::none();
+        }
+        let names = |team: &Option<TeamId>| -> BTreeMap<u8, String> {
+            team.as_ref()
+                .and_then(|id| self.team_rosters.get(id))
+                .into_iter()
+                .flatten()
+                .filter_map(|player| Some((player.number?, player.name.clone())))
+                .collect()
+        };
+        let event_name = self
+            .current_event_id
+            .as_ref()
+            .and_then(|id| self.events.as_ref()?.get(id))
+            .map(|event| event.name.as_str());
+        let completed = scoresheet::CompletedGame {
+            event_name,
+            court: self.current_court.as_deref(),
+            game,
+            lines,
+            players: BlackWhiteBundle {
+                black: names(&game.team_ids.black),
+                white: names(&game.team_ids.white),
+            },
+            sign_offs,
+            rules: &self.rules,
+        };
+        match scoresheet::save(
+            &scoresheet::dir(&self.config_dir),
+            self.config.scoresheet_style,
+            &completed,
+        ) {
+            Ok(path) => Task::future(async move {
+                let _ = tokio::task::spawn_blocking(move || scoresheet::print_pdf(&path)).await;
+                Message::NoAction
+            }),
+            Err(e) => {
+                error!("Failed to write the scoresheet: {e}");
+                Task::none()
+            }
+        }
+    }
+
+    /// Export every game of the most recent event day, to a USB stick if one is plugged in
+    fn export_results(&mut self) {
+        let Some(day) = self.results.latest_day() else {
+            return;
+        };
+        let dir = results_archive::default_export_dir(&self.config_dir);
+        self.app_state = match self.results.export_day(day, &dir) {
+            Ok(_) => AppState::ConfirmationPage(ConfirmationKind::ResultsExported(
+                dir.display().to_string(),
+            )),
+            Err(e) => {
+                let err_string = format!(
+                    "An error occurred while exporting the results to {}.\n\n\
+                    Error Message:\n{e}",
+                    dir.display()
+                );
+                error!("{err_string}");
+                AppState::ConfirmationPage(ConfirmationKind::Error(err_string))
+            }
+        };
+        trace!("AppState changed to {:?}", self.app_state);
+    }
+
+    /// Player statistics for the current event, or for the event of the last archived game when
+    /// there is no current event, with names from the rosters fetched this session
+    fn player_stats(&self) -> Option<(EventId, PlayerStats)> {
+        let event_id = self
+            .current_event_id
+            .as_ref()
+            .or_else(|| self.results.latest_event())?;
+        let mut stats = self.results.player_stats(event_id);
+        stats.add_names(&self.team_rosters);
+        Some((event_id.clone(), stats))
+    }
+
+    /// Export the player statistics, to a USB stick if one is plugged in
+    fn export_player_stats(&mut self) {
+        let Some((event_id, stats)) = self.player_stats() else {
+            return;
+        };
+        let dir = results_archive::default_export_dir(&self.config_dir);
+        self.app_state = match results_archive::export_player_stats(&stats, &event_id, &dir) {
+            Ok(_) => AppState::ConfirmationPage(ConfirmationKind::ResultsExported(
+                dir.display().to_string(),
+            )),
+            Err(e) => {
+                let err_string = format!(
+                    "An error occurred while exporting the player statistics to {}.\n\n\
+                    Error Message:\n{e}",
+                    dir.display()
+                );
+                error!("{err_string}");
+                AppState::ConfirmationPage(ConfirmationKind::Error(err_string))
+            }
+        };
+        trace!("AppState changed to {:?}", self.app_state);
+    }
+
+    /// What the portal health tile shows. The indicator is dormant whenever Using-UWH-Portal is
+    /// off OR no event is linked: `Some` only when the feature is on AND an event is linked. See
+    /// ADR 011 amendments 2026-04-23 (event-linked gate) and 2026-05-16 (using-uwh-portal gate).
+    fn portal_indicator(&self) -> Option<PortalIndicatorState> {
+        if !self.uses_remote() {
+            return None;
+        }
+        self.current_event_id.as_ref().map(|_| {
+            let mut state = self.portal_manager.indicator_state();
+            // The committed source, not the one staged in the editor:
+            // the tile reports the live connection, so choosing CUSTOM
+            // must not change the emblem until APPLY.
+            state.site_is_custom = self.source == GameSource::Custom;
+            state
+        })
+    }
+
+    /// The current event's name for a portal team
+    fn team_name(&self, id: &TeamId) -> Option<String> {
+        let event_id = self.current_event_id.as_ref()?;
+        let teams = self.events.as_ref()?.get(event_id)?.teams.as_ref()?;
+        teams.get(id).cloned()
+    }
+
+    /// The final scores of the current event's games that were played on this refbox
+    fn local_results(&self) -> GameResults {
+        self.current_event_id
+            .as_ref()
+            .map(|id| self.results.game_results(id))
+            .unwrap_or_default()
+    }
+
+    /// Replaces the current schedule and re-resolves it
+    fn set_schedule(&mut self, schedule: Option<Schedule>) {
+        self.schedule = schedule;
+        self.refresh_resolved_schedule();
+    }
+
+    /// Fills in the current schedule's winner-of, loser-of and seeded-by slots from the games
+    /// played on this refbox, so knockout games show their teams without the portal. Slots that
+    /// the local results don't decide stay as they are. The result is cached, so this must be
+    /// called whenever the schedule, the current event or the results archive changes.
+    fn refresh_resolved_schedule(&mut self) {
+        self.resolved_schedule = self.schedule.as_ref().map(|schedule| {
+            let results = self.local_results();
+            if results.is_empty() {
+                schedule.clone()
+            } else {
+                Bracket::new(schedule, &results).resolved_schedule()
+            }
+        });
+        self.status_stale = true;
+    }
+
+    /// The current schedule, resolved as in [`Self::refresh_resolved_schedule`]
+    fn resolved_schedule(&self) -> Option<&Schedule> {
+        self.resolved_schedule.as_ref()
+    }
+
+    /// The teams playing `game_number` in the current schedule, by color, resolved as in
+    /// [`Self::refresh_resolved_schedule`]
+    fn scheduled_game_teams(
+        &self,
+        game_number: &GameNumber,
+    ) -> BlackWhiteBundle<Option<ScheduledTeam>> {
+        let Some(game) = self
+            .resolved_schedule()
+            .and_then(|schedule| schedule.games.get(game_number))
+        else {
+            return BlackWhiteBundle::default();
+        };
+        BlackWhiteBundle {
+            black: Some(game.dark.clone()),
+            white: Some(game.light.clone()),
+        }
+    }
+
+    /// The portal teams playing `game_number` in the current schedule, by color
+    fn scheduled_teams(&self, game_number: &GameNumber) -> BlackWhiteBundle<Option<TeamId>> {
+        let teams = self.scheduled_game_teams(game_number);
+        BlackWhiteBundle {
+            black: teams.black.and_then(|team| team.assigned().cloned()),
+            white: teams.white.and_then(|team| team.assigned().cloned()),
+        }
+    }
+
+    /// The names of the teams playing `game_number` in the current schedule, by color
+    fn scheduled_team_names(&self, game_number: &GameNumber) -> BlackWhiteBundle<Option<String>> {
+        let teams = self.scheduled_game_teams(game_number);
+        let name = |team: Option<ScheduledTeam>| {
+            let team = team?;
+            team.assigned()
+                .and_then(|id| self.team_name(id))
+                .or_else(|| team.pending().map(str::to_string))
+        };
+        BlackWhiteBundle {
+            black: name(teams.black),
+            white: name(teams.white),
+        }
+    }
+
+    /// `game_number` as the schedule has it, for the status API
+    fn game_info(&self, game_number: &GameNumber) -> GameInfo {
+        let game = self
+            .resolved_schedule()
+            .and_then(|schedule| schedule.games.get(game_number));
+        GameInfo {
+            number: game_number.clone(),
+            teams: self.scheduled_team_names(game_number),
+            start_time: game.map(|game| game.start_time),
+            court: game.map(|game| game.court.clone()),
+            timing_rule: game.map(|game| game.timing_rule.clone()),
+        }
+    }
+
+    /// Bring the status API up to date with everything but the results archive, which only
+    /// changes when a game is recorded and is published then. Runs after every message, but only
+    /// does any work when the snapshot or the portal queue changed, or `status_stale` is set.
+    fn publish_status(&mut self) {
+        let Some(api) = self.status_api.as_ref() else {
+            return;
+        };
+        let indicator = self.portal_indicator();
+        let queue_version = Some(self.portal_manager.queue_version());
+        let queue_changed = queue_version != self.status_queue_version;
+        let stale = std::mem::take(&mut self.status_stale);
+        api.update(|status| {
+            if !stale
+                && !queue_changed
+                && status.snapshot == self.snapshot
+                && status.portal.indicator == indicator
+            {
+                return;
+            }
+            if stale
+                || status.games.current.number != self.snapshot.game_number
+                || status.games.next.number != self.snapshot.next_game_number
+            {
+                status.games = Games {
+                    current: self.game_info(&self.snapshot.game_number),
+                    next: self.game_info(&self.snapshot.next_game_number),
+                };
+            }
+            status.snapshot.clone_from(&self.snapshot);
+            status.portal.indicator = indicator;
+            if queue_changed {
+                status.portal.queue = self.portal_manager.queued_results();
+            }
+            if !stale {
+                return;
+            }
+            status.schedule.clone_from(&self.resolved_schedule);
+            match self.current_event_id.as_ref().and_then(|id| {
+                self.events
+                    .as_ref()
+                    .and_then(|events| events.get(id)?.teams.as_ref())
+            }) {
+                Some(teams) => status.teams.clone_from(teams),
+                None => status.teams.clear(),
+            }
+        });
+        self.status_queue_version = queue_version;
+    }
+
+    fn publish_results(&self) {
+        if let Some(api) = self.status_api.as_ref() {
+            api.update(|status| status.results.clone_from(&self.results));
+        }
+    }
+
+    /// Players who may not play in the upcoming game, with the games they have left to sit out.
+    /// Only known between games, for games from a portal schedule.
+    fn upcoming_suspensions(&self) -> Vec<(Color, u8, u16)> {
+        let Some(event_id) = self.current_event_id.as_ref() else {
+            return Vec::new();
+        };
+        if !self.uses_remote() || self.snapshot.current_period != GamePeriod::BetweenGames {
+            return Vec::new();
+        }
+        let teams = self.scheduled_teams(&self.snapshot.next_game_number);
+        self.suspensions
+            .for_teams(event_id, &teams)
+            .into_iter()
+            .map(|(color, s)| (color, s.cap_number, s.games_remaining))
+            .collect()
+    }
+
+    /// Take a finished game into the suspension ledger and save it if it changed
+    fn record_suspensions(
+        &mut self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        dismissals: &[(Color, u8)],
+    ) {
+        let teams = self.scheduled_teams(game_number);
+        let games = self.rules.penalties.dismissal_suspension_games;
+        if self
+            .suspensions
+            .record_game(event_id, game_number, &teams, dismissals, games)
+            && let Err(e) = suspensions::save(&self.config_dir, &self.suspensions)
+        {
+            error!("Failed to write suspensions.json: {e}");
+        }
+    }
+
     /// Update `current_event_id` and mirror the new value into the
     /// `portal_event_id` shared handle so the background portal-health
     /// task sees it on its next tick. Every per-page apply that writes
@@ -1472,6 +2184,7 @@ impl RefBoxApp {
         #[cfg(debug_assertions)]
         let new_is_some = new.is_some();
         self.current_event_id = new.clone();
+        self.refresh_resolved_schedule();
         // why this cannot panic: the guarded data is a plain `Option`
         // and no writer panics while holding the guard; a poisoned
         // mutex just returns the previous value, which we then
@@ -1531,6 +2244,38 @@ impl RefBoxApp {
         }
     }
 
+    /// Write the game journal if the game has changed since it was last
+    /// written, or remove it once no game is in progress. Errors are logged,
+    /// never fatal: a failed write only means a crash could not be recovered.
+    fn persist_journal(&mut self) {
+        if self.pending_resume.is_some() {
+            return;
+        }
+        let tm = self.tm.lock().unwrap();
+        if tm.current_period() == GamePeriod::BetweenGames {
+            drop(tm);
+            if self.last_journal.take().is_some() {
+                if let Err(e) = journal::delete(&self.config_dir) {
+                    error!("Failed to delete game_journal.json: {e}");
+                }
+            }
+            return;
+        }
+        let new_journal = tm.journal(&self.journal_anchor, time::OffsetDateTime::now_utc());
+        drop(tm);
+        if self
+            .last_journal
+            .as_ref()
+            .is_some_and(|last| last.same_state(&new_journal))
+        {
+            return;
+        }
+        if let Err(e) = journal::save(&self.config_dir, &new_journal) {
+            error!("Failed to write game_journal.json: {e}");
+        }
+        self.last_journal = Some(new_journal);
+    }
+
     fn apply_app_options(&mut self) -> Option<ConfirmationKind> {
         let edited = self.edited_settings.as_ref()?;
         // Snapshot the fields we need so the immutable borrow on
@@ -1566,7 +2311,7 @@ impl RefBoxApp {
         // sync (ADR 011 amendment 2026-04-23 dormant-until-linked).
         self.set_current_event_id(event_id);
         self.current_court = current_court;
-        self.schedule = schedule;
+        self.set_schedule(schedule);
         self.config.mode = mode;
         // `hide_time` is mirrored to the update server, which the pure helper
         // cannot do — so notify here, and only when it actually changed.
@@ -1630,7 +2375,7 @@ impl RefBoxApp {
         // Route through set_current_event_id so portal_event_id stays in sync (ADR 011).
         self.set_current_event_id(None);
         self.current_court = None;
-        self.schedule = None;
+        self.set_schedule(None);
         // The Using-UWH-Portal setting is now off, which the player-grid design
         // spec lists as an unconditional number-pad condition. `game_rosters` is
         // otherwise only written at kickoff, so without this the grid would keep
@@ -1684,9 +2429,10 @@ impl RefBoxApp {
             // Between games: commit the clean manual slate directly.
             // Safety: BetweenGames checked above; set_config only errors when a game is
             // in progress, so this path is unreachable.
-            tm.set_config(edited.config.clone()).unwrap();
             let now = Instant::now();
-            tm.reset_to_manual_break(now);
+            tm.apply(Action::SetConfig(Box::new(edited.config.clone())), now)
+                .unwrap();
+            tm.apply(Action::ResetToManualBreak, now).unwrap();
             // Snapshot the config we need before dropping the `edited` borrow so we can
             // call `&mut self` methods below (mirrors the existing config-change branch at
             // ~line 1049).
@@ -1702,7 +2448,7 @@ impl RefBoxApp {
                 .as_ref()
                 .and_then(|schedule| schedule.get_game_timing(&edited.game_number))
                 .cloned()
-                .map(|tr| tr.into())
+                .map(|tr| tr.game_config(&edited.config))
                 .unwrap_or_else(|| tm.config().clone())
         } else {
             edited.config.clone()
@@ -1713,7 +2459,9 @@ impl RefBoxApp {
                 return Some(ConfirmationKind::GameConfigChangedFromApply(new_config));
             }
             // Safety: precondition checked above (period != BetweenGames / next-game info just set); error path is unreachable in this control flow.
-            tm.set_config(new_config.clone()).unwrap();
+            let now = Instant::now();
+            tm.apply(Action::SetConfig(Box::new(new_config.clone())), now)
+                .unwrap();
 
             let (game, timing) = edited
                 .schedule
@@ -1722,17 +2470,19 @@ impl RefBoxApp {
                 .unwrap_or((None, None));
             let start_time = game.map(|g| g.start_time);
 
-            tm.set_next_game(NextGameInfo {
+            let next_game_info = NextGameInfo {
                 number: edited.game_number.clone(),
                 timing: timing.cloned(),
                 start_time,
-            });
+            };
+            tm.apply(Action::SetNextGame(Box::new(next_game_info)), now)
+                .unwrap();
 
             if edited.uses_remote() {
                 // Safety: precondition checked above (period != BetweenGames / next-game info just set); error path is unreachable in this control flow.
-                tm.apply_next_game_start(Instant::now()).unwrap();
+                tm.apply(Action::ApplyNextGameStart, now).unwrap();
             } else {
-                tm.clear_scheduled_game_start();
+                tm.apply(Action::ClearScheduledGameStart, now).unwrap();
             }
 
             std::mem::drop(tm);
@@ -1750,7 +2500,7 @@ impl RefBoxApp {
             // sync (ADR 011 amendment 2026-04-23 dormant-until-linked).
             self.set_current_event_id(event_id);
             self.current_court = current_court;
-            self.schedule = schedule;
+            self.set_schedule(schedule);
             return None;
         }
 
@@ -1777,11 +2527,13 @@ impl RefBoxApp {
                 }
             };
 
-            tm.set_next_game(next_game_info);
+            let now = Instant::now();
+            tm.apply(Action::SetNextGame(Box::new(next_game_info)), now)
+                .unwrap();
 
             if edited.uses_remote() {
                 // Safety: precondition checked above (period != BetweenGames / next-game info just set); error path is unreachable in this control flow.
-                tm.apply_next_game_start(Instant::now()).unwrap();
+                tm.apply(Action::ApplyNextGameStart, now).unwrap();
             }
         }
 
@@ -1798,7 +2550,7 @@ impl RefBoxApp {
         // for the background health check (ADR 011 amendment 2026-04-23).
         self.set_current_event_id(event_id);
         self.current_court = current_court;
-        self.schedule = schedule;
+        self.set_schedule(schedule);
 
         None
     }
@@ -1852,16 +2604,28 @@ impl RefBoxApp {
                 AppState::EditGameConfig(landing)
             }
             ConfirmationOption::GoBack => AppState::EditGameConfig(ConfigPage::Game),
+            ConfirmationOption::ResumeGame => {
+                unreachable!("ResumeGame is only offered by ResumeGame pages")
+            }
+            ConfirmationOption::StartShootout => {
+                unreachable!("StartShootout is only offered by StartShootout pages")
+            }
+            ConfirmationOption::AcceptPlan | ConfirmationOption::ShortenHalfTimes(_) => {
+                unreachable!(
+                    "AcceptPlan and ShortenHalfTimes are only offered by CatchUpPlan pages"
+                )
+            }
             ConfirmationOption::EndGameAndApply => {
                 // Safety: *FromApply confirmations are only raised while edited_settings is Some; the invariant is enforced by apply_game_options.
                 let edited = self.edited_settings.as_ref().unwrap();
                 // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
-                tm.reset_game(now);
+                tm.apply(Action::ResetGame, now).unwrap();
                 if let Some(ref config) = new_config {
                     // Safety: precondition checked above (period != BetweenGames / next-game info just set); error path is unreachable in this control flow.
-                    tm.set_config(config.clone()).unwrap();
+                    tm.apply(Action::SetConfig(Box::new(config.clone())), now)
+                        .unwrap();
                 }
 
                 let (game, timing) = edited
@@ -1871,17 +2635,19 @@ impl RefBoxApp {
                     .unwrap_or((None, None));
                 let start_time = game.map(|g| g.start_time);
 
-                tm.set_next_game(NextGameInfo {
+                let next_game_info = NextGameInfo {
                     number: edited.game_number.clone(),
                     timing: timing.cloned(),
                     start_time,
-                });
+                };
+                tm.apply(Action::SetNextGame(Box::new(next_game_info)), now)
+                    .unwrap();
 
                 if edited.uses_remote() {
                     // Safety: precondition checked above (period != BetweenGames / next-game info just set); error path is unreachable in this control flow.
-                    tm.apply_next_game_start(now).unwrap();
+                    tm.apply(Action::ApplyNextGameStart, now).unwrap();
                 } else {
-                    tm.clear_scheduled_game_start();
+                    tm.apply(Action::ClearScheduledGameStart, now).unwrap();
                 }
 
                 std::mem::drop(tm);
@@ -1900,9 +2666,11 @@ impl RefBoxApp {
                 let edited = self.edited_settings.as_ref().unwrap();
                 // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
                 let mut tm = self.tm.lock().unwrap();
-                tm.set_game_number(&edited.game_number);
+                let now = Instant::now();
+                tm.apply(Action::SetGameNumber(edited.game_number.clone()), now)
+                    .unwrap();
                 // Safety: snapshot generation only fails before the tournament manager is initialised, which happens in RefBoxApp::new().
-                let new_snapshot = tm.generate_snapshot(Instant::now()).unwrap();
+                let new_snapshot = tm.generate_snapshot(now).unwrap();
                 std::mem::drop(tm);
                 self.page_entry_snapshot = None;
                 self.persist_config();
@@ -2028,6 +2796,145 @@ impl RefBoxApp {
         task
     }
 
+    /// Handles operator responses to `ConfirmationKind::ResumeGame`.
+    ///
+    /// - `ResumeGame` — load the journaled game into the tournament manager, with
+    ///   any running clock advanced by the time the refbox was down.
+    /// - `DiscardChanges` — start fresh and delete the journal, so the offer is
+    ///   not repeated on the next start.
+    fn resume_game_confirmation(&mut self, selection: ConfirmationOption) -> Task<Message> {
+        let Some(saved) = self.pending_resume.take() else {
+            self.app_state = AppState::MainPage;
+            return Task::none();
+        };
+        let mut task = Task::none();
+        match selection {
+            ConfirmationOption::ResumeGame => {
+                let now = Instant::now();
+                let mut tm = self.tm.lock().unwrap();
+                tm.apply(Action::RestoreJournal(Box::new(saved)), now)
+                    .unwrap();
+                self.config.game = tm.config().clone();
+                let new_snapshot = tm.generate_snapshot(now).unwrap();
+                std::mem::drop(tm);
+                task = self.apply_snapshot(new_snapshot);
+            }
+            ConfirmationOption::DiscardChanges => {
+                info!("Operator chose not to resume game {}", saved.game_number());
+                if let Err(e) = journal::delete(&self.config_dir) {
+                    error!("Failed to delete game_journal.json: {e}");
+                }
+            }
+            ConfirmationOption::GoBack
+            | ConfirmationOption::EndGameAndApply
+            | ConfirmationOption::KeepGameAndApply
+            | ConfirmationOption::RestartAndApply
+            | ConfirmationOption::StartShootout
+            | ConfirmationOption::AcceptPlan
+            | ConfirmationOption::ShortenHalfTimes(_) => {
+                unreachable!("ResumeGame pages only offer ResumeGame and DiscardChanges")
+            }
+        }
+        self.app_state = self.last_app_state.clone();
+        trace!("AppState changed to {:?}", self.app_state);
+        task
+    }
+
+    /// Handles operator responses to `ConfirmationKind::StartShootout`.
+    fn start_shootout_confirmation(&mut self, selection: ConfirmationOption) -> Task<Message> {
+        let mut task = Task::none();
+        match selection {
+            ConfirmationOption::StartShootout => {
+                let now = Instant::now();
+                let mut tm = self.tm.lock().unwrap();
+                if let Err(e) = tm.apply(Action::StartShootout, now) {
+                    error!("Failed to start penalty shootout: {e}");
+                }
+                let new_snapshot = tm.generate_snapshot(now).unwrap();
+                std::mem::drop(tm);
+                task = self.apply_snapshot(new_snapshot);
+            }
+            ConfirmationOption::GoBack => {}
+            ConfirmationOption::DiscardChanges
+            | ConfirmationOption::EndGameAndApply
+            | ConfirmationOption::KeepGameAndApply
+            | ConfirmationOption::RestartAndApply
+            | ConfirmationOption::ResumeGame
+            | ConfirmationOption::AcceptPlan
+            | ConfirmationOption::ShortenHalfTimes(_) => {
+                unreachable!("StartShootout pages only offer StartShootout and GoBack")
+            }
+        }
+        self.app_state = AppState::MainPage;
+        trace!("AppState changed to {:?}", self.app_state);
+        task
+    }
+
+    /// The catch-up plan for the rest of the current court's games, starting
+    /// with the next one. Only available between games with a portal schedule.
+    fn plan_catch_up(&self, shorten_half_times: bool) -> Option<CatchUpPlan> {
+        let schedule = self.schedule.as_ref()?;
+        let tm = self.tm.lock().unwrap();
+        if !self.uses_remote() || tm.current_period() != GamePeriod::BetweenGames {
+            return None;
+        }
+        let late_by = tm.behind_schedule(Instant::now());
+        catch_up::plan_catch_up(
+            schedule,
+            &self.snapshot.next_game_number,
+            late_by,
+            shorten_half_times,
+        )
+    }
+
+    /// Handles operator responses to `ConfirmationKind::CatchUpPlan`.
+    fn catch_up_confirmation(&mut self, selection: ConfirmationOption) -> Task<Message> {
+        let AppState::ConfirmationPage(ConfirmationKind::CatchUpPlan { plan, .. }) =
+            &self.app_state
+        else {
+            unreachable!("only dispatched from CatchUpPlan pages")
+        };
+        match selection {
+            ConfirmationOption::AcceptPlan => {
+                let plan = Some(plan.clone());
+                let mut tm = self.tm.lock().unwrap();
+                if let Err(e) = tm.apply(Action::SetCatchUpPlan(plan), Instant::now()) {
+                    let err_string = format!(
+                        "The catch-up plan was not accepted.\n\n\
+                        Error Message:\n{e}"
+                    );
+                    error!("{err_string}");
+                    self.app_state =
+                        AppState::ConfirmationPage(ConfirmationKind::Error(err_string));
+                    trace!("AppState changed to {:?}", self.app_state);
+                    return Task::none();
+                }
+            }
+            ConfirmationOption::ShortenHalfTimes(shorten_half_times) => {
+                if let Some(plan) = self.plan_catch_up(shorten_half_times) {
+                    self.app_state = AppState::ConfirmationPage(ConfirmationKind::CatchUpPlan {
+                        plan,
+                        shorten_half_times,
+                    });
+                    trace!("AppState changed to {:?}", self.app_state);
+                    return Task::none();
+                }
+            }
+            ConfirmationOption::GoBack => {}
+            ConfirmationOption::DiscardChanges
+            | ConfirmationOption::EndGameAndApply
+            | ConfirmationOption::KeepGameAndApply
+            | ConfirmationOption::RestartAndApply
+            | ConfirmationOption::ResumeGame
+            | ConfirmationOption::StartShootout => {
+                unreachable!("CatchUpPlan pages only offer AcceptPlan, ShortenHalfTimes and GoBack")
+            }
+        }
+        self.app_state = AppState::MainPage;
+        trace!("AppState changed to {:?}", self.app_state);
+        Task::none()
+    }
+
     /// Handles operator responses to `ConfirmationKind::SwitchToManualFromApply`.
     ///
     /// This confirmation is raised when the operator turns the portal toggle OFF while
@@ -2062,16 +2969,17 @@ impl RefBoxApp {
                     // panicked; the refbox treats that as fatal (matches the 20+
                     // identical sites in this file).
                     let mut tm = self.tm.lock().unwrap();
-                    tm.reset_game(now);
+                    tm.apply(Action::ResetGame, now).unwrap();
                     // `set_config` must run BEFORE `reset_to_manual_break` because
                     // `reset_to_manual_break` reads `self.config.nominal_break` to
                     // set the break clock.  After `reset_game` the period is
                     // `BetweenGames`, so `set_config` cannot error.
-                    tm.set_config(manual_config.clone()).unwrap();
+                    tm.apply(Action::SetConfig(Box::new(manual_config.clone())), now)
+                        .unwrap();
                     // Overrides reset_game's minimum-break clock with the nominal
                     // break; also resets the game number to "0", clears the
                     // next-game / grid, and starts the break counting down.
-                    tm.reset_to_manual_break(now);
+                    tm.apply(Action::ResetToManualBreak, now).unwrap();
                 }
                 self.clear_portal_selections_to_manual(manual_config);
                 // Clear the page-entry snapshot like the sibling apply arms so a
@@ -2097,7 +3005,8 @@ impl RefBoxApp {
                 {
                     // Safety: Mutex poison — same rationale as the EndGameAndApply arm.
                     let mut tm = self.tm.lock().unwrap();
-                    tm.clear_portal_next_game();
+                    tm.apply(Action::ClearPortalNextGame, Instant::now())
+                        .unwrap();
                 }
                 self.clear_portal_selections_to_manual(manual_config);
                 // Clear the page-entry snapshot like the sibling apply arms so a
@@ -2119,6 +3028,17 @@ impl RefBoxApp {
                 AppState::EditGameConfig(ConfigPage::Main)
             }
             ConfirmationOption::GoBack => AppState::EditGameConfig(ConfigPage::Game),
+            ConfirmationOption::ResumeGame => {
+                unreachable!("ResumeGame is only offered by ResumeGame pages")
+            }
+            ConfirmationOption::StartShootout => {
+                unreachable!("StartShootout is only offered by StartShootout pages")
+            }
+            ConfirmationOption::AcceptPlan | ConfirmationOption::ShortenHalfTimes(_) => {
+                unreachable!(
+                    "AcceptPlan and ShortenHalfTimes are only offered by CatchUpPlan pages"
+                )
+            }
             ConfirmationOption::RestartAndApply => {
                 unreachable!("RestartAndApply is only offered by PortalTenantSwitch pages")
             }
@@ -2156,6 +3076,8 @@ impl RefBoxApp {
                 confirm_score: edited.confirm_score,
                 hide_time: edited.hide_time,
                 audible_countdown: edited.audible_countdown,
+                scoresheet_style: edited.scoresheet_style,
+                confirm_result: edited.confirm_result,
             },
             ConfigPage::Display => PageEntrySnapshot::Display {
                 white_on_right: edited.white_on_right,
@@ -2248,6 +3170,8 @@ impl RefBoxApp {
             show_behind_schedule_time: self.config.show_behind_schedule_time,
             confirm_score: self.config.confirm_score,
             audible_countdown: self.config.audible_countdown,
+            scoresheet_style: self.config.scoresheet_style,
+            confirm_result: self.config.confirm_result,
             pending_language: None,
             original_language: None,
             beep_test_levels: None,
@@ -2309,10 +3233,12 @@ impl RefBoxApp {
     pub(super) fn new(flags: RefBoxAppFlags) -> (Self, Task<Message>) {
         let RefBoxAppFlags {
             config,
+            rules,
             config_dir,
             serial_ports,
             binary_port,
             json_port,
+            websocket_port,
             sim_children,
             sim_spawn_config,
             require_https,
@@ -2322,13 +3248,23 @@ impl RefBoxApp {
             install_path,
             restart_argv,
             show_rolled_back,
+            record_session,
+            status_api,
         } = flags;
 
         // Paint in the saved display mode from the first frame.
         crate::app::theme::set_display_mode(config.display_mode);
 
+        let now = Instant::now();
         let mut tm = TournamentManager::new(config.game.clone());
-        tm.start_clock(Instant::now());
+        tm.set_rules(rules.clone()).unwrap();
+        if let Some(dir) = &record_session {
+            match SessionRecorder::create(dir, &config.game, &rules, now) {
+                Ok(recorder) => tm.start_recording(recorder),
+                Err(e) => error!("Failed to start recording the session in {dir:?}: {e}"),
+            }
+        }
+        tm.apply(Action::StartClock, now).unwrap();
 
         // In BeepTest mode, also build a cadence engine. `None` for the
         // ordinary Hockey/Rugby modes — the game `tm` above remains the
@@ -2360,6 +3296,10 @@ impl RefBoxApp {
                 );
                 GameSource::Manual
             }
+        } else if config.source == GameSource::File {
+            // Provisional: the folder is loaded once the app exists, and a
+            // folder that will not load drops back to manual games there.
+            GameSource::File
         } else {
             GameSource::Manual
         };
@@ -2400,6 +3340,7 @@ impl RefBoxApp {
             serial_ports,
             binary_port,
             json_port,
+            websocket_port,
             config.hide_time,
             config.mode == Mode::BeepTest,
             if has_led_panel || config.mode == Mode::BeepTest {
@@ -2508,6 +3449,7 @@ impl RefBoxApp {
             beep_test_tm,
             beep_test_snapshot: BeepTestSnapshot::default(),
             config,
+            rules,
             edited_settings: Default::default(),
             page_entry_snapshot: None,
             snapshot,
@@ -2516,13 +3458,18 @@ impl RefBoxApp {
             time_edit_old: (Duration::ZERO, None),
             last_message: Message::NoAction,
             update_sender,
+            status_api: status_api.map(StatusApi::new),
+            status_stale: true,
+            status_queue_version: None,
             uwhportal_client,
             portal_event_id,
             current_site,
             require_https,
             source: startup_source,
+            schedule_file: None,
             events: None,
             schedule: None,
+            resolved_schedule: None,
             game_rosters: BlackWhiteBundle {
                 black: Vec::new(),
                 white: Vec::new(),
@@ -2545,6 +3492,8 @@ impl RefBoxApp {
             spacebar_held: false,
             alarm_delay_token: 0,
             timeout_revive: None,
+            sign_off: None,
+            incident: None,
             timeout_revive_token: 0,
             portal_manager,
             portal_event_rx,
@@ -2553,10 +3502,53 @@ impl RefBoxApp {
             restart_argv,
             pending_update: None,
             update_backup_version: None,
+            journal_anchor: WallAnchor::now(),
+            last_journal: None,
+            pending_resume: None,
+            suspensions: SuspensionLedger::default(),
+            results: ResultsArchive::default(),
             #[cfg(debug_assertions)]
             scramble_token_pending,
         };
 
+        match suspensions::load_or_default(&new.config_dir) {
+            Ok(ledger) => new.suspensions = ledger,
+            Err(e) => error!("Failed to read suspensions.json: {e}"),
+        }
+
+        match results_archive::load_or_default(&new.config_dir) {
+            Ok(archive) => new.results = archive,
+            Err(e) => error!("Failed to read results.json: {e}"),
+        }
+        new.refresh_resolved_schedule();
+        new.publish_results();
+
+        // A game that was under way when the refbox stopped (crash, power
+        // loss) is offered back to the operator rather than restored silently:
+        // they may have already moved on. Unless the startup auto-revert has
+        // the Updates page to show, the first screen asks. See ADR 013.
+        match journal::load_or_none(&new.config_dir) {
+            Ok(Some(saved)) if saved.is_resumable(time::OffsetDateTime::now_utc()) => {
+                if show_rolled_back {
+                    info!("Game journal present but an update was rolled back; not offering it");
+                } else {
+                    info!(
+                        "Found a journal of game {} written at {}; offering to resume",
+                        saved.game_number(),
+                        saved.saved_at
+                    );
+                    new.app_state = AppState::ConfirmationPage(ConfirmationKind::ResumeGame {
+                        game_number: saved.game_number().clone(),
+                        scores: saved.scores(),
+                    });
+                    new.pending_resume = Some(saved);
+                }
+            }
+            Ok(Some(_)) => info!("Game journal present but stale or between games; ignoring"),
+            Ok(None) => {}
+            Err(e) => error!("Failed to read game_journal.json: {e}"),
+        }
+
         // Restore a recent portal link so a relaunch (language change, self-update)
         // or a short shutdown comes back recognized instead of dormant. A stale or
         // cross-portal note is simply not restored (the app starts dormant) but the
@@ -2571,10 +3563,40 @@ impl RefBoxApp {
         // Skipped entirely when a custom site was restored above: the note
         // records a *portal* link, and applying it would silently move the
         // operator off the site they configured.
+        //
+        // A schedule file is loaded here, ahead of the note, because the note
+        // can only restore its court and game once the file's event is known.
+        let file_task = if new.source == GameSource::File {
+            new.adopt_schedule_file().unwrap_or_else(|| {
+                error!("Saved schedule folder is not usable; starting with manual games");
+                new.source = GameSource::Manual;
+                Task::none()
+            })
+        } else {
+            Task::none()
+        };
         match crate::portal_manager::link_session::load_or_none(&new.config_dir) {
             Ok(Some(_)) if new.source == GameSource::Custom => {
                 info!("Custom site restored from config; portal link note ignored");
             }
+            // Only the court and game are taken from the note, and only for the
+            // event the file holds: the note must never turn a schedule file
+            // into a portal link, including when the file failed to load.
+            Ok(Some(note)) if new.config.source == GameSource::File => {
+                if new.source == GameSource::File
+                    && new.current_event_id.as_ref() == Some(&note.event_id)
+                    && decide_restore(&note, time::OffsetDateTime::now_utc(), new.config.mode)
+                {
+                    info!(
+                        "Restoring court {:?} and game {:?} for the schedule file",
+                        note.court, note.game_number
+                    );
+                    new.current_court = note.court.clone();
+                    new.pending_restore_game = note.game_number.clone();
+                } else {
+                    info!("Link note is not for the schedule file's event; ignored");
+                }
+            }
             Ok(Some(note)) => {
                 if decide_restore(&note, time::OffsetDateTime::now_utc(), new.config.mode) {
                     info!(
@@ -2616,6 +3638,8 @@ impl RefBoxApp {
         // what brings its schedule and teams back after a restart.
         if new.source == GameSource::Custom {
             startup_tasks.push(new.adopt_custom_event());
+        } else if new.source == GameSource::File {
+            startup_tasks.push(file_task);
         } else if new.uses_remote() {
             startup_tasks.push(new.request_event_list());
         }
@@ -2646,6 +3670,12 @@ impl RefBoxApp {
     }
 
     pub(super) fn update(&mut self, message: Message) -> Task<Message> {
+        let task = self.handle_message(message);
+        self.publish_status();
+        task
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         trace!("Handling message: {message:?}");
 
         if !message.is_repeatable() && (message == self.last_message) {
@@ -2662,7 +3692,7 @@ impl RefBoxApp {
                 let now = Instant::now();
                 let mut tm = self.tm.lock().unwrap();
                 let was_running = tm.clock_is_running();
-                tm.stop_clock(now).unwrap();
+                tm.apply(Action::StopClock, now).unwrap();
                 let game_time = tm.game_clock_time(now).unwrap();
                 let timeout_time = tm.timeout_clock_time(now);
                 self.time_edit_old = (game_time, timeout_time);
@@ -2713,14 +3743,14 @@ impl RefBoxApp {
                     let mut tm = self.tm.lock().unwrap();
                     let now = Instant::now();
                     if !canceled {
-                        tm.set_game_clock_time(game_time).unwrap();
+                        tm.apply(Action::SetGameClock(game_time), now).unwrap();
                         if let Some(time) = timeout_time {
-                            tm.set_timeout_clock_time(time).unwrap();
+                            tm.apply(Action::SetTimeoutClock(time), now).unwrap();
                         }
                     }
                     if was_running {
-                        tm.start_clock(now);
-                        tm.update(now).unwrap();
+                        tm.apply(Action::StartClock, now).unwrap();
+                        tm.apply(Action::Update, now).unwrap();
                     }
                     let snapshot = tm.generate_snapshot(now).unwrap();
                     drop(tm);
@@ -2735,7 +3765,7 @@ impl RefBoxApp {
             Message::StartPlayNow => {
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
-                tm.start_play_now(now).unwrap();
+                tm.apply(Action::StartPlayNow, now).unwrap();
                 let snapshot = tm.generate_snapshot(now).unwrap();
                 std::mem::drop(tm);
                 self.apply_snapshot(snapshot)
@@ -2769,11 +3799,11 @@ impl RefBoxApp {
                         let mut scores = tm.get_scores();
                         scores[color] = scores[color].saturating_add(1);
 
-                        tm.pause_for_confirm(now).unwrap();
+                        tm.apply(Action::PauseForConfirm, now).unwrap();
                         self.app_state = AppState::ConfirmScores(scores);
                         Task::none()
                     } else {
-                        tm.add_score(color, 0, now);
+                        tm.apply(Action::AddScore(color, 0), now).unwrap();
                         let snapshot = tm.generate_snapshot(now).unwrap(); // TODO: Remove this unwrap
                         std::mem::drop(tm);
                         let task = self.apply_snapshot(snapshot);
@@ -2807,38 +3837,108 @@ impl RefBoxApp {
                     is_confirmation,
                 } = self.app_state
                 {
-                    if is_confirmation {
-                        tm.set_scores(scores, now);
-                        tm.end_confirm_pause(now).unwrap();
-                        tm.start_clock(now);
-
-                        // Update `tm` after game ends to get into Between Games
-                        now += Duration::from_millis(2);
-                        tm.update(now).unwrap();
-                        AppState::MainPage
-                    } else if !canceled {
-                        if tm.current_period() == GamePeriod::SuddenDeath
-                            && (scores.black != scores.white)
-                        {
-                            tm.pause_for_confirm(now).unwrap();
-                            AppState::ConfirmScores(scores)
-                        } else {
-                            tm.set_scores(scores, now);
-                            AppState::MainPage
+                    if is_confirmation {
+                        tm.apply(Action::SetScores(scores), now).unwrap();
+                        tm.apply(Action::EndConfirmPause, now).unwrap();
+                        tm.apply(Action::StartClock, now).unwrap();
+
+                        // Update `tm` after game ends to get into Between Games
+                        now += Duration::from_millis(2);
+                        tm.apply(Action::Update, now).unwrap();
+                        AppState::MainPage
+                    } else if !canceled {
+                        if tm.current_period() == GamePeriod::SuddenDeath
+                            && (scores.black != scores.white)
+                        {
+                            tm.apply(Action::PauseForConfirm, now).unwrap();
+                            AppState::ConfirmScores(scores)
+                        } else {
+                            tm.apply(Action::SetScores(scores), now).unwrap();
+                            AppState::MainPage
+                        }
+                    } else {
+                        AppState::MainPage
+                    }
+                } else {
+                    unreachable!()
+                };
+
+                let snapshot = tm.generate_snapshot(now).unwrap(); // `now` is in the past!
+                std::mem::drop(tm);
+                tasks.push(self.apply_snapshot(snapshot));
+
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::batch(tasks)
+            }
+            Message::DisallowGoal => {
+                // Any staged score edits are dropped, disallowing a goal
+                // changes the score itself
+                self.app_state = AppState::DisallowGoal {
+                    selected: None,
+                    reason: GoalDisallowReason::Foul,
+                    indices: BlackWhiteBundle { black: 0, white: 0 },
+                };
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::SelectGoalToDisallow(color, goal) => {
+                if let AppState::DisallowGoal {
+                    ref mut selected, ..
+                } = self.app_state
+                {
+                    *selected = Some((color, goal));
+                } else {
+                    unreachable!()
+                }
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::ChangeDisallowReason(new_reason) => {
+                if let AppState::DisallowGoal { ref mut reason, .. } = self.app_state {
+                    *reason = new_reason;
+                } else {
+                    unreachable!()
+                }
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::DisallowGoalComplete { canceled } => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+
+                self.app_state = if let AppState::DisallowGoal {
+                    selected, reason, ..
+                } = self.app_state
+                {
+                    match selected {
+                        Some((color, goal)) if !canceled => {
+                            match tm.apply(Action::DisallowGoal(color, goal, reason), now) {
+                                Ok(()) => AppState::MainPage,
+                                Err(e) => {
+                                    let err_string = format!(
+                                        "An error occurred while disallowing the goal.\n\n\
+                                        Error Message:\n{e}"
+                                    );
+                                    error!("{err_string}");
+                                    AppState::ConfirmationPage(ConfirmationKind::Error(err_string))
+                                }
+                            }
                         }
-                    } else {
-                        AppState::MainPage
+                        _ => AppState::ScoreEdit {
+                            scores: tm.get_scores(),
+                            is_confirmation: false,
+                        },
                     }
                 } else {
                     unreachable!()
                 };
 
-                let snapshot = tm.generate_snapshot(now).unwrap(); // `now` is in the past!
+                let snapshot = tm.generate_snapshot(now).unwrap();
                 std::mem::drop(tm);
-                tasks.push(self.apply_snapshot(snapshot));
+                let task = self.apply_snapshot(snapshot);
 
                 trace!("AppState changed to {:?}", self.app_state);
-                Task::batch(tasks)
+                task
             }
             Message::PenaltyOverview => {
                 if let Err(e) = self.pen_edit.start_session() {
@@ -2877,13 +3977,19 @@ impl RefBoxApp {
             Message::Scroll { which, up } => {
                 match self.app_state {
                     AppState::PenaltyOverview(ref mut indices)
-                    | AppState::WarningOverview(ref mut indices) => {
+                    | AppState::WarningOverview(ref mut indices)
+                    | AppState::DisallowGoal {
+                        ref mut indices, ..
+                    } => {
                         let idx = match which {
                             ScrollOption::Black => &mut indices.black,
                             ScrollOption::White => &mut indices.white,
                             ScrollOption::GameParameter
                             | ScrollOption::Equal
-                            | ScrollOption::PortalDetail => unreachable!(),
+                            | ScrollOption::PortalDetail
+                            | ScrollOption::PlayerStats
+                            | ScrollOption::Timeline
+                            | ScrollOption::AuditTrail => unreachable!(),
                         };
                         if up {
                             *idx = idx.saturating_sub(1);
@@ -2896,9 +4002,11 @@ impl RefBoxApp {
                             ScrollOption::Black => &mut indices.black,
                             ScrollOption::Equal => &mut indices.equal,
                             ScrollOption::White => &mut indices.white,
-                            ScrollOption::GameParameter | ScrollOption::PortalDetail => {
-                                unreachable!()
-                            }
+                            ScrollOption::GameParameter
+                            | ScrollOption::PortalDetail
+                            | ScrollOption::PlayerStats
+                            | ScrollOption::Timeline
+                            | ScrollOption::AuditTrail => unreachable!(),
                         };
                         if up {
                             *idx = idx.saturating_sub(1);
@@ -2924,6 +4032,37 @@ impl RefBoxApp {
                             *scroll_index = scroll_index.saturating_add(1);
                         }
                     }
+                    AppState::PlayerStatsPage {
+                        ref mut scroll_index,
+                        ..
+                    } => {
+                        debug_assert_eq!(which, ScrollOption::PlayerStats);
+                        if up {
+                            *scroll_index = scroll_index.saturating_sub(1);
+                        } else {
+                            *scroll_index = scroll_index.saturating_add(1);
+                        }
+                    }
+                    AppState::TimelinePage {
+                        ref mut scroll_index,
+                    } => {
+                        debug_assert_eq!(which, ScrollOption::Timeline);
+                        if up {
+                            *scroll_index = scroll_index.saturating_sub(1);
+                        } else {
+                            *scroll_index = scroll_index.saturating_add(1);
+                        }
+                    }
+                    AppState::AuditTrailPage {
+                        ref mut scroll_index,
+                    } => {
+                        debug_assert_eq!(which, ScrollOption::AuditTrail);
+                        if up {
+                            *scroll_index = scroll_index.saturating_sub(1);
+                        } else {
+                            *scroll_index = scroll_index.saturating_add(1);
+                        }
+                    }
                     _ => {
                         unreachable!();
                     }
@@ -3206,7 +4345,8 @@ impl RefBoxApp {
                     KeypadPage::AddScore { .. }
                     | KeypadPage::Penalty(None, _, _, _)
                     | KeypadPage::FoulAdd { origin: None, .. }
-                    | KeypadPage::WarningAdd { origin: None, .. } => 0,
+                    | KeypadPage::WarningAdd { origin: None, .. }
+                    | KeypadPage::ShootoutAttempt { .. } => 0,
                     KeypadPage::Penalty(Some((color, index)), _, _, _) => {
                         self.pen_edit.get_item(color, index).unwrap().player_number as u32
                     }
@@ -3263,6 +4403,16 @@ impl RefBoxApp {
                         *id = self.uwhportal_client.as_ref().unwrap().lock().unwrap().id();
                         0
                     }
+                    KeypadPage::ResultSignOff(color) => self
+                        .sign_off
+                        .as_ref()
+                        .and_then(|pending| pending.sign_offs[color].signed_by)
+                        .unwrap_or(0),
+                    KeypadPage::IncidentPlayer(_) => self
+                        .incident
+                        .as_ref()
+                        .and_then(|incident| incident.player_number)
+                        .map_or(0, u32::from),
                 };
                 self.app_state = AppState::KeypadPage(page, init_val);
                 trace!("AppState changed to {:?}", self.app_state);
@@ -3338,6 +4488,10 @@ impl RefBoxApp {
                     | AppState::KeypadPage(
                         KeypadPage::WarningAdd { ref mut color, .. },
                         ref mut player_num,
+                    )
+                    | AppState::KeypadPage(
+                        KeypadPage::ShootoutAttempt { ref mut color },
+                        ref mut player_num,
                     ) => {
                         *color = new_color.expect("Invalid color value");
                         // A number chosen for one team means nothing on the
@@ -3371,10 +4525,11 @@ impl RefBoxApp {
                             let mut scores = tm.get_scores();
                             scores[color] = scores[color].saturating_add(1);
 
-                            tm.pause_for_confirm(now).unwrap();
+                            tm.apply(Action::PauseForConfirm, now).unwrap();
                             AppState::ConfirmScores(scores)
                         } else {
-                            tm.add_score(color, player.try_into().unwrap(), now);
+                            let player = player.try_into().unwrap();
+                            tm.apply(Action::AddScore(color, player), now).unwrap();
                             AppState::MainPage
                         };
                         let snapshot = tm.generate_snapshot(now).unwrap();
@@ -3392,11 +4547,262 @@ impl RefBoxApp {
                 trace!("AppState changed to {:?}", self.app_state);
                 task
             }
+            Message::StartShootout => {
+                self.app_state = AppState::ConfirmationPage(ConfirmationKind::StartShootout);
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::PlanCatchUp => {
+                if let Some(plan) = self.plan_catch_up(false) {
+                    self.app_state = AppState::ConfirmationPage(ConfirmationKind::CatchUpPlan {
+                        plan,
+                        shorten_half_times: false,
+                    });
+                    trace!("AppState changed to {:?}", self.app_state);
+                } else {
+                    warn!("Could not plan a catch-up: no schedule, or not between games");
+                }
+                Task::none()
+            }
+            Message::ShootoutAttemptComplete { canceled, scored } => {
+                let mut task = Task::none();
+                if !canceled {
+                    if let AppState::KeypadPage(KeypadPage::ShootoutAttempt { color }, player) =
+                        self.app_state
+                    {
+                        let mut tm = self.tm.lock().unwrap();
+                        let now = Instant::now();
+                        let player = player.try_into().unwrap();
+                        if let Err(e) =
+                            tm.apply(Action::AddShootoutAttempt(color, player, scored), now)
+                        {
+                            error!("Failed to record shootout attempt: {e}");
+                        }
+                        let snapshot = tm.generate_snapshot(now).unwrap();
+                        std::mem::drop(tm);
+                        task = self.apply_snapshot(snapshot);
+                    } else {
+                        unreachable!()
+                    }
+                }
+                self.app_state = AppState::MainPage;
+                trace!("AppState changed to {:?}", self.app_state);
+                task
+            }
+            Message::RemoveLastShootoutAttempt => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                if let Err(e) = tm.apply(Action::RemoveLastShootoutAttempt, now) {
+                    warn!("Failed to remove shootout attempt: {e}");
+                }
+                let snapshot = tm.generate_snapshot(now).unwrap();
+                std::mem::drop(tm);
+                self.apply_snapshot(snapshot)
+            }
+            Message::ToggleProtest(color) => {
+                if let Some(pending) = self.sign_off.as_mut() {
+                    pending.sign_offs[color].protest ^= true;
+                }
+                Task::none()
+            }
+            Message::SignOffEntryComplete { canceled } => {
+                if !canceled {
+                    if let AppState::KeypadPage(KeypadPage::ResultSignOff(color), number) =
+                        self.app_state
+                    {
+                        if let Some(pending) = self.sign_off.as_mut() {
+                            pending.sign_offs[color].signed_by = (number != 0).then_some(number);
+                        }
+                    } else {
+                        unreachable!()
+                    }
+                }
+                self.app_state = AppState::ResultSignOff;
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::ResultSignOffDone => {
+                let task = self.finish_sign_off();
+                self.app_state = AppState::MainPage;
+                trace!("AppState changed to {:?}", self.app_state);
+                task
+            }
+            Message::ReportIncident => {
+                let now = Instant::now();
+                let tm = self.tm.lock().unwrap();
+                let Some(start_time) = tm.game_clock_time(now) else {
+                    return Task::none();
+                };
+                self.incident = Some(IncidentDetails {
+                    category: IncidentCategory::default(),
+                    color: None,
+                    player_number: None,
+                    start_period: tm.current_period(),
+                    start_time,
+                    start_instant: now,
+                    description: String::new(),
+                });
+                drop(tm);
+                self.app_state = AppState::IncidentReport;
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::ChangeIncidentCategory(category) => {
+                if let Some(incident) = self.incident.as_mut() {
+                    incident.category = category;
+                }
+                Task::none()
+            }
+            Message::ChangeIncidentTeam(color) => {
+                if let Some(incident) = self.incident.as_mut()
+                    && incident.color != color
+                {
+                    incident.color = color;
+                    // A cap number only means something on the team it was entered for
+                    incident.player_number = None;
+                }
+                Task::none()
+            }
+            Message::IncidentDescriptionChanged(description) => {
+                if let Some(incident) = self.incident.as_mut() {
+                    incident.description = description;
+                }
+                Task::none()
+            }
+            Message::IncidentPlayerComplete { canceled } => {
+                if !canceled {
+                    if let AppState::KeypadPage(KeypadPage::IncidentPlayer(_), number) =
+                        self.app_state
+                    {
+                        if let Some(incident) = self.incident.as_mut() {
+                            incident.player_number =
+                                u8::try_from(number).ok().filter(|&number| number != 0);
+                        }
+                    } else {
+                        unreachable!()
+                    }
+                }
+                self.app_state = AppState::IncidentReport;
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::IncidentReportComplete { canceled } => {
+                let incident = self.incident.take();
+                self.app_state = AppState::TimelinePage { scroll_index: 0 };
+                if !canceled && let Some(incident) = incident {
+                    // Safety: Mutex poison only occurs if another thread already panicked; the refbox treats that as fatal (matches the 20+ identical sites in this file).
+                    if let Err(e) = self.tm.lock().unwrap().add_incident(incident) {
+                        let err_string = format!(
+                            "An error occurred while filing the incident report.\n\n\
+                            Error Message:\n{e}"
+                        );
+                        error!("{err_string}");
+                        self.app_state =
+                            AppState::ConfirmationPage(ConfirmationKind::Error(err_string));
+                    }
+                }
+                // Journal the report right away, rather than on the next tick
+                let snapshot = self
+                    .tm
+                    .lock()
+                    .unwrap()
+                    .generate_snapshot(Instant::now())
+                    .unwrap();
+                let task = self.apply_snapshot(snapshot);
+                trace!("AppState changed to {:?}", self.app_state);
+                task
+            }
             Message::ShowGameDetails => {
                 self.app_state = AppState::GameDetailsPage(false);
                 trace!("AppState changed to {:?}", self.app_state);
                 Task::none()
             }
+            Message::ExportResults => {
+                self.export_results();
+                Task::none()
+            }
+            Message::ShowPlayerStats { discipline } => {
+                self.app_state = AppState::PlayerStatsPage {
+                    discipline,
+                    scroll_index: 0,
+                };
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::ExportPlayerStats => {
+                self.export_player_stats();
+                Task::none()
+            }
+            Message::ShowTimeline => {
+                self.app_state = AppState::TimelinePage { scroll_index: 0 };
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::ShowAuditTrail => {
+                self.app_state = AppState::AuditTrailPage { scroll_index: 0 };
+                trace!("AppState changed to {:?}", self.app_state);
+                Task::none()
+            }
+            Message::EditTimelineEntry(edit) => {
+                // Each entry opens the way it would from its own page, so finishing the edit
+                // leaves the operator where they would be after editing it from there. An entry
+                // that has left its list since the page was drawn opens nothing.
+                let tm = self.tm.lock().unwrap();
+                let steps = match edit {
+                    TimelineEdit::Goal(color, goal) => {
+                        vec![
+                            Message::DisallowGoal,
+                            Message::SelectGoalToDisallow(color, goal),
+                        ]
+                    }
+                    TimelineEdit::Penalty(color, index) => tm.get_penalties()[color]
+                        .get(index)
+                        .map(|penalty| {
+                            vec![
+                                Message::PenaltyOverview,
+                                Message::KeypadPage(KeypadPage::Penalty(
+                                    Some((color, index)),
+                                    color,
+                                    penalty.kind,
+                                    penalty.infraction,
+                                )),
+                            ]
+                        })
+                        .unwrap_or_default(),
+                    TimelineEdit::Warning(color, index) => tm.get_warnings()[color]
+                        .get(index)
+                        .map(|warning| {
+                            vec![
+                                Message::WarningOverview,
+                                Message::KeypadPage(KeypadPage::WarningAdd {
+                                    origin: Some((color, index)),
+                                    color,
+                                    infraction: warning.infraction,
+                                    team_warning: warning.player_number.is_none(),
+                                    ret_to_overview: true,
+                                }),
+                            ]
+                        })
+                        .unwrap_or_default(),
+                    TimelineEdit::Foul(color, index) => tm.get_fouls()[color]
+                        .get(index)
+                        .map(|foul| {
+                            vec![
+                                Message::FoulOverview,
+                                Message::KeypadPage(KeypadPage::FoulAdd {
+                                    origin: Some((color, index)),
+                                    color,
+                                    infraction: foul.infraction,
+                                    ret_to_overview: true,
+                                }),
+                            ]
+                        })
+                        .unwrap_or_default(),
+                    TimelineEdit::GameClock => vec![Message::EditTime],
+                };
+                drop(tm);
+                Task::batch(steps.into_iter().map(|step| self.update(step)))
+            }
             Message::OpenPowerPage => {
                 self.app_state = AppState::PowerPage;
                 trace!("AppState changed to {:?}", self.app_state);
@@ -3550,6 +4956,9 @@ impl RefBoxApp {
                     // Stats-pending row: fire one stats attempt. No
                     // background loop, no escalation.
                     self.portal_manager.request_stats_retry(&id);
+                } else if self.portal_manager.is_under_review(&id) {
+                    // The protest is settled: the result the portal has is final.
+                    self.portal_manager.mark_final(id);
                 } else {
                     // Young score-pending row tapped — force an immediate retry.
                     if let Err(e) = self.portal_manager.force_immediate_retry(&id) {
@@ -3603,6 +5012,12 @@ impl RefBoxApp {
                 }
                 Task::none()
             }
+            Message::RequestPortalRefresh if self.source == GameSource::File => {
+                // REFRESH reads the folder again, which is how a schedule edited
+                // on another machine gets onto the refbox. A folder that no
+                // longer loads leaves the schedule already in use untouched.
+                self.adopt_schedule_file().unwrap_or_else(Task::none)
+            }
             Message::RequestPortalRefresh => {
                 // Only spin the REFRESH button when there is actually an event
                 // to refresh AND a client to fetch it with; otherwise nothing
@@ -4231,6 +5646,7 @@ impl RefBoxApp {
                     LengthParameter::OvertimeHalf => config.ot_half_play_duration,
                     LengthParameter::OvertimeHalfTime => config.ot_half_time_duration,
                     LengthParameter::PreSuddenDeath => config.pre_sudden_death_duration,
+                    LengthParameter::StopTimeWindow => config.stop_time_window,
                 };
                 self.app_state = AppState::ParameterEditor(param, dur, single_half);
                 trace!("AppState changed to {:?}", self.app_state);
@@ -4308,6 +5724,9 @@ impl RefBoxApp {
                                 LengthParameter::PreSuddenDeath => {
                                     edited_settings.config.pre_sudden_death_duration = dur
                                 }
+                                LengthParameter::StopTimeWindow => {
+                                    edited_settings.config.stop_time_window = dur
+                                }
                             }
                         }
                         AppState::KeypadPage(KeypadPage::GameNumber, num) => {
@@ -4524,6 +5943,12 @@ impl RefBoxApp {
                             BoolGameParameter::SuddenDeathAllowed => {
                                 edited_settings.config.sudden_death_allowed ^= true
                             }
+                            BoolGameParameter::PenaltyShootoutAllowed => {
+                                edited_settings.config.penalty_shootout_allowed ^= true
+                            }
+                            BoolGameParameter::StopTimeAllowed => {
+                                edited_settings.config.stop_time_allowed ^= true
+                            }
                             BoolGameParameter::WhiteOnRight => {
                                 edited_settings.white_on_right ^= true
                             }
@@ -4558,6 +5983,9 @@ impl RefBoxApp {
                             BoolGameParameter::ConfirmScore => {
                                 edited_settings.confirm_score ^= true
                             }
+                            BoolGameParameter::ConfirmResult => {
+                                edited_settings.confirm_result ^= true
+                            }
                             BoolGameParameter::AudibleCountdown => {
                                 edited_settings.audible_countdown ^= true
                             }
@@ -4589,6 +6017,7 @@ impl RefBoxApp {
                 // `edited_settings` before it can be drawn.
                 let edited_settings = self.edited_settings.as_mut().unwrap();
                 let was_using = edited_settings.uses_remote();
+                let was_file = edited_settings.source == GameSource::File;
                 edited_settings.source = new_source;
 
                 // `remembered_remote` is deliberately NOT updated here. It is
@@ -4602,7 +6031,11 @@ impl RefBoxApp {
                 // `clear_for_remote_switch`) and kick off the event-list fetch
                 // immediately so the picker has data ready when the operator
                 // navigates to it.
-                if !was_using && edited_settings.uses_remote() {
+                //
+                // Moving between a schedule file and a site is a blank slate
+                // too: the file's event is not one the site's pickers know.
+                let is_file = new_source == GameSource::File;
+                if (!was_using || was_file != is_file) && edited_settings.uses_remote() {
                     edited_settings.clear_for_remote_switch();
                     // Only the portal has a list to fetch. A custom site names
                     // its event in the URL and adopts it when the source is
@@ -4621,6 +6054,11 @@ impl RefBoxApp {
 
                 if trigger_event_list_fetch {
                     self.request_event_list()
+                } else if is_file {
+                    // The file is read every time FILE is pressed, not only on
+                    // the way in, so a stick plugged in late — or a corrected
+                    // file — is picked up without leaving the page.
+                    self.adopt_schedule_file().unwrap_or_else(Task::none)
                 } else {
                     Task::none()
                 }
@@ -4637,6 +6075,11 @@ impl RefBoxApp {
                     CyclingParameter::Mode => settings.mode.cycle(),
                     CyclingParameter::Brightness => settings.brightness.cycle(),
                     CyclingParameter::FrontDisplayLayout => settings.front_display_layout.cycle(),
+                    CyclingParameter::ScoresheetStyle => settings.scoresheet_style.cycle(),
+                    CyclingParameter::PenaltyShootoutRounds => {
+                        let rounds = &mut settings.config.penalty_shootout_rounds;
+                        *rounds = *rounds % MAX_SHOOTOUT_ROUNDS + 1;
+                    }
                 }
                 Task::none()
             }
@@ -4755,6 +6198,27 @@ impl RefBoxApp {
                     return Task::none();
                 }
 
+                if matches!(
+                    self.app_state,
+                    AppState::ConfirmationPage(ConfirmationKind::ResumeGame { .. })
+                ) {
+                    return self.resume_game_confirmation(selection);
+                }
+
+                if matches!(
+                    self.app_state,
+                    AppState::ConfirmationPage(ConfirmationKind::StartShootout)
+                ) {
+                    return self.start_shootout_confirmation(selection);
+                }
+
+                if matches!(
+                    self.app_state,
+                    AppState::ConfirmationPage(ConfirmationKind::CatchUpPlan { .. })
+                ) {
+                    return self.catch_up_confirmation(selection);
+                }
+
                 // The link refusal carries no page: the ACCESS TOKEN row exists
                 // only on the Game config page, so that is where it returns.
                 if matches!(
@@ -4780,7 +6244,8 @@ impl RefBoxApp {
                 }
 
                 // After ADR 009 Task 13 retired the global apply path, only
-                // `ConfirmationKind::Error` (which offers DiscardChanges) and
+                // `ConfirmationKind::Error` / `ConfirmationKind::ResultsExported`
+                // (which offer DiscardChanges) and
                 // `ConfirmationKind::UwhPortalLinkFailed` /
                 // `ConfirmationKind::UwhPortalKeyUnusable` (which offer GoBack)
                 // reach this match. The Game-related and PortalTenantSwitch
@@ -4810,6 +6275,24 @@ impl RefBoxApp {
                              which are dispatched above to apply_game_confirmation."
                         )
                     }
+                    ConfirmationOption::ResumeGame => {
+                        unreachable!(
+                            "ResumeGame is only offered by ResumeGame pages, which are \
+                             dispatched above to resume_game_confirmation."
+                        )
+                    }
+                    ConfirmationOption::StartShootout => {
+                        unreachable!(
+                            "StartShootout is only offered by StartShootout pages, which are \
+                             dispatched above to start_shootout_confirmation."
+                        )
+                    }
+                    ConfirmationOption::AcceptPlan | ConfirmationOption::ShortenHalfTimes(_) => {
+                        unreachable!(
+                            "AcceptPlan and ShortenHalfTimes are only offered by CatchUpPlan \
+                             pages, which are dispatched above to catch_up_confirmation."
+                        )
+                    }
                 };
                 trace!("AppState changed to {:?}", self.app_state);
                 Task::none()
@@ -4825,9 +6308,10 @@ impl RefBoxApp {
                     let now = Instant::now();
                     // Safe: end_confirm_pause's only Err is NotPaused, which can't occur here —
                     // Message::ConfirmScores is only dispatched while a confirm-pause is active.
-                    tm.end_confirm_pause(now).unwrap();
-                    tm.start_clock(now);
-                    tm.update(now + Duration::from_millis(2)).unwrap(); // Need to update after game ends
+                    tm.apply(Action::EndConfirmPause, now).unwrap();
+                    tm.apply(Action::StartClock, now).unwrap();
+                    tm.apply(Action::Update, now + Duration::from_millis(2))
+                        .unwrap(); // Need to update after game ends
                     self.app_state = AppState::MainPage;
                     trace!("AppState changed to {:?}", self.app_state);
                 }
@@ -4840,10 +6324,10 @@ impl RefBoxApp {
                         let now = Instant::now();
                         let mut tm = self.tm.lock().unwrap();
 
-                        tm.set_scores(scores, now);
+                        tm.apply(Action::SetScores(scores), now).unwrap();
                         // Safe: end_confirm_pause's only Err is NotPaused, which can't occur here —
                         // Message::ScoreConfirmation is only dispatched while a confirm-pause is active.
-                        tm.end_confirm_pause(now).unwrap();
+                        tm.apply(Action::EndConfirmPause, now).unwrap();
                         AppState::MainPage
                     } else {
                         AppState::ScoreEdit {
@@ -4861,10 +6345,11 @@ impl RefBoxApp {
             Message::AutoConfirmScores(snapshot) => {
                 info!("Autoconfirming");
 
-                let task = self.apply_snapshot(snapshot);
-
+                // Set first: the game ends with this snapshot, which may open the result sign-off
                 self.app_state = AppState::MainPage;
 
+                let task = self.apply_snapshot(snapshot);
+
                 trace!("AppState changed to {:?}", self.app_state);
                 task
             }
@@ -4872,9 +6357,9 @@ impl RefBoxApp {
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
                 if switch {
-                    tm.switch_to_team_timeout(color).unwrap();
+                    tm.apply(Action::SwitchToTeamTimeout(color), now).unwrap();
                 } else {
-                    tm.start_team_timeout(color, now).unwrap();
+                    tm.apply(Action::StartTeamTimeout(color), now).unwrap();
                 }
                 if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
@@ -4887,9 +6372,9 @@ impl RefBoxApp {
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
                 if switch {
-                    tm.switch_to_ref_timeout(now).unwrap();
+                    tm.apply(Action::SwitchToRefTimeout, now).unwrap();
                 } else {
-                    tm.start_ref_timeout(now).unwrap();
+                    tm.apply(Action::StartRefTimeout, now).unwrap();
                 }
                 if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
@@ -4903,14 +6388,14 @@ impl RefBoxApp {
                 let now = Instant::now();
                 if switch {
                     if self.config.mode == Mode::Rugby {
-                        tm.switch_to_rugby_penalty_shot(now).unwrap();
+                        tm.apply(Action::SwitchToRugbyPenaltyShot, now).unwrap();
                     } else {
-                        tm.switch_to_penalty_shot().unwrap();
+                        tm.apply(Action::SwitchToPenaltyShot, now).unwrap();
                     }
                 } else if self.config.mode == Mode::Rugby {
-                    tm.start_rugby_penalty_shot(now).unwrap();
+                    tm.apply(Action::StartRugbyPenaltyShot, now).unwrap();
                 } else {
-                    tm.start_penalty_shot(now).unwrap();
+                    tm.apply(Action::StartPenaltyShot, now).unwrap();
                 }
                 if let AppState::TimeEdit(_, _, ref mut time) = self.app_state {
                     *time = Some(tm.timeout_clock_time(now).unwrap());
@@ -4922,16 +6407,11 @@ impl RefBoxApp {
             Message::EndTimeout => {
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
+                // A game-ending timeout arms the end-of-game confirm pause so the confirm screen
+                // (below) can finish the game cleanly; a bare halt would leave
+                // `end_confirm_pause` with no pause to end, panicking on confirm (R6).
                 let would_end = tm.timeout_end_would_end_game(now).unwrap();
-                if would_end {
-                    // Arm the end-of-game confirm pause so the confirm screen (below) can finish
-                    // the game cleanly; a bare halt would leave `end_confirm_pause` with no pause
-                    // to end, panicking on confirm (R6).
-                    tm.end_game_ending_timeout(now).unwrap();
-                } else {
-                    tm.end_timeout(now).unwrap();
-                    tm.update(now).unwrap();
-                }
+                tm.apply(Action::EndTimeout, now).unwrap();
                 let snapshot = tm.generate_snapshot(now).unwrap();
                 std::mem::drop(tm);
                 let task = self.apply_snapshot(snapshot);
@@ -4955,8 +6435,26 @@ impl RefBoxApp {
                 // Unlike EndTimeout, no `timeout_end_would_end_game` check is needed:
                 // a team timeout only happens mid-half with the game clock stopped, so
                 // cancelling it can never end the game — it just resumes play.
-                tm.cancel_team_timeout(now).unwrap();
-                tm.update(now).unwrap();
+                tm.apply(Action::CancelTeamTimeout, now).unwrap();
+                let snapshot = tm.generate_snapshot(now).unwrap();
+                std::mem::drop(tm);
+                self.apply_snapshot(snapshot)
+            }
+            Message::Undo | Message::Redo => {
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                let action = if message == Message::Undo {
+                    Action::Undo
+                } else {
+                    Action::Redo
+                };
+                // The buttons are only live while there is something to undo
+                // or redo, so an error here means the game changed under the
+                // action; the history has been dropped and the button greys out.
+                if let Err(e) = tm.apply(action, now) {
+                    warn!("{message:?} failed: {e}");
+                    tm.apply(Action::Update, now).unwrap();
+                }
                 let snapshot = tm.generate_snapshot(now).unwrap();
                 std::mem::drop(tm);
                 self.apply_snapshot(snapshot)
@@ -4968,6 +6466,7 @@ impl RefBoxApp {
                     tasks.push(self.request_teams_list(event.id.clone()));
                 }
                 self.events = Some(e_map);
+                self.status_stale = true;
                 // Startup link restore: now that the event list is populated,
                 // fetch the schedule for the restored event so RecvSchedule can
                 // re-select the remembered game and start its scheduled countdown.
@@ -4991,6 +6490,7 @@ impl RefBoxApp {
                 if let Some(ref mut events) = self.events {
                     if let Some(event) = events.get_mut(&event_id) {
                         event.teams = Some(teams);
+                        self.status_stale = true;
                     } else {
                         error!(
                             "Received teams for event_id {}, it is not in the event list",
@@ -5005,8 +6505,8 @@ impl RefBoxApp {
                 }
                 Task::none()
             }
-            Message::RecvTeamRoster(team_id, numbers) => {
-                self.team_rosters.insert(team_id, numbers);
+            Message::RecvTeamRoster(team_id, players) => {
+                self.team_rosters.insert(team_id, players);
                 Task::none()
             }
             Message::RecvSchedule(event_id, mut schedule) => {
@@ -5081,7 +6581,7 @@ impl RefBoxApp {
                         }
                         if let Some(ref id) = self.current_event_id {
                             if *id == event_id {
-                                self.schedule = Some(schedule);
+                                self.set_schedule(Some(schedule));
                                 if self.edited_settings.is_none() {
                                     let mut tm = self.tm.lock().unwrap();
                                     if tm.current_period() == GamePeriod::BetweenGames {
@@ -5101,20 +6601,25 @@ impl RefBoxApp {
                                             info!(
                                                 "Setting upcoming game info from received schedule: {game:?}"
                                             );
-                                            tm.set_next_game(NextGameInfo {
+                                            let now = Instant::now();
+                                            let next_game_info = NextGameInfo {
                                                 number: game.number.clone(),
                                                 timing: Some(timing.clone()),
                                                 start_time: Some(game.start_time),
-                                            });
+                                            };
+                                            tm.apply(
+                                                Action::SetNextGame(Box::new(next_game_info)),
+                                                now,
+                                            )
+                                            .unwrap();
                                             if restore_num.is_some() {
                                                 // Start the live countdown to the
                                                 // scheduled start so a restored session
                                                 // is ready to go (same path the normal
                                                 // between-games transition uses).
-                                                let now = Instant::now();
                                                 // why this cannot panic: BetweenGames was
                                                 // just checked and next_game was just set.
-                                                tm.apply_next_game_start(now).unwrap();
+                                                tm.apply(Action::ApplyNextGameStart, now).unwrap();
                                                 let new_game_config = tm.config().clone();
                                                 let snapshot = tm.generate_snapshot(now).unwrap();
                                                 std::mem::drop(tm);
@@ -5230,11 +6735,15 @@ impl RefBoxApp {
                 Task::none()
             }
             Message::StartClock => {
-                self.tm.lock().unwrap().start_clock(Instant::now());
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                tm.apply(Action::StartClock, now).unwrap();
                 Task::none()
             }
             Message::StopClock => {
-                self.tm.lock().unwrap().stop_clock(Instant::now()).unwrap();
+                let mut tm = self.tm.lock().unwrap();
+                let now = Instant::now();
+                tm.apply(Action::StopClock, now).unwrap();
                 Task::none()
             }
             Message::TimeUpdaterStarted(tx) => {
@@ -5371,7 +6880,7 @@ impl RefBoxApp {
                 }
                 let mut tm = self.tm.lock().unwrap();
                 let now = Instant::now();
-                if tm.revive_team_timeout(color).is_err() {
+                if tm.apply(Action::ReviveTeamTimeout(color), now).is_err() {
                     // State moved on during the hold (e.g. half ended); nothing to do.
                     std::mem::drop(tm);
                     self.timeout_revive = None;
@@ -5473,6 +6982,7 @@ impl RefBoxApp {
                 };
                 if let Err(e) = self.update_sender.send_snapshot(
                     game_snap,
+                    None,
                     // Beep test has no sides control: lap count always on the left.
                     false,
                     self.config.hardware.brightness,
@@ -5531,6 +7041,8 @@ impl RefBoxApp {
                     show_behind_schedule_time: self.config.show_behind_schedule_time,
                     confirm_score: self.config.confirm_score,
                     audible_countdown: self.config.audible_countdown,
+                    scoresheet_style: self.config.scoresheet_style,
+                    confirm_result: self.config.confirm_result,
                     pending_language: Some(current_language),
                     original_language: Some(current_language),
                     beep_test_levels: None,
@@ -5582,6 +7094,8 @@ impl RefBoxApp {
                     show_behind_schedule_time: self.config.show_behind_schedule_time,
                     confirm_score: self.config.confirm_score,
                     audible_countdown: self.config.audible_countdown,
+                    scoresheet_style: self.config.scoresheet_style,
+                    confirm_result: self.config.confirm_result,
                     pending_language: Some(current_language),
                     original_language: Some(current_language),
                     beep_test_levels: None,
@@ -5672,6 +7186,8 @@ impl RefBoxApp {
                     show_behind_schedule_time: self.config.show_behind_schedule_time,
                     confirm_score: self.config.confirm_score,
                     audible_countdown: self.config.audible_countdown,
+                    scoresheet_style: self.config.scoresheet_style,
+                    confirm_result: self.config.confirm_result,
                     pending_language: Some(current_language),
                     original_language: Some(current_language),
                     beep_test_levels: None,
@@ -5770,6 +7286,8 @@ impl RefBoxApp {
                     show_behind_schedule_time: self.config.show_behind_schedule_time,
                     confirm_score: self.config.confirm_score,
                     audible_countdown: self.config.audible_countdown,
+                    scoresheet_style: self.config.scoresheet_style,
+                    confirm_result: self.config.confirm_result,
                     pending_language: Some(current_language),
                     original_language: Some(current_language),
                     beep_test_levels: Some(self.config.beep_test.levels.clone()),
@@ -5987,25 +7505,16 @@ impl RefBoxApp {
                     .as_ref()
                     .and_then(|events| events.get(id).and_then(|event| event.teams.as_ref()))
             }),
-            // The portal health indicator is dormant whenever Using-UWH-Portal
-            // is off OR no event is linked. `Some` only when the feature is on
-            // AND an event is linked; otherwise `None` and the time banner
-            // falls back to the pre-feature layout. See ADR 011 amendments
-            // 2026-04-23 (event-linked gate) and 2026-05-16 (using-uwh-portal gate).
-            portal_indicator: if self.uses_remote() {
-                self.current_event_id.as_ref().map(|_| {
-                    let mut state = self.portal_manager.indicator_state();
-                    // The committed source, not the one staged in the editor:
-                    // the tile reports the live connection, so choosing CUSTOM
-                    // must not change the emblem until APPLY.
-                    state.site_is_custom = self.source == GameSource::Custom;
-                    state
-                })
-            } else {
-                None
-            },
+            // `None` while the indicator is dormant (see `portal_indicator`),
+            // and the time banner falls back to the pre-feature layout.
+            portal_indicator: self.portal_indicator(),
             has_led_panel: self.has_led_panel,
+            rules: &self.rules,
             committed_site_url: &self.config.custom_site.url,
+            schedule_folder: self
+                .schedule_file
+                .as_ref()
+                .map(|file| file.folder.as_path()),
         };
 
         let mut main_view = column![match self.app_state {
@@ -6031,11 +7540,16 @@ impl RefBoxApp {
                 } else {
                     std::time::Duration::ZERO
                 };
+                let can_catch_up = self.uses_remote()
+                    && self.schedule.is_some()
+                    && self.tm.lock().unwrap().behind_schedule(Instant::now())
+                        > std::time::Duration::ZERO;
+                let schedule = self.resolved_schedule();
                 build_main_view(
                     data,
                     game_config,
                     self.uses_remote(),
-                    self.schedule.as_ref(),
+                    schedule,
                     self.config.track_fouls_and_warnings,
                     self.config.sound.sound_enabled && self.config.sound.manual_alarm_enabled,
                     self.mouse_alarm_held || self.spacebar_held,
@@ -6045,6 +7559,8 @@ impl RefBoxApp {
                         .unwrap()
                         .last_game_info()
                         .map(|i| (i.game_number.clone(), i.scores)),
+                    self.upcoming_suspensions(),
+                    can_catch_up,
                 )
             }
             AppState::TimeEdit(_, time, timeout_time) => build_time_edit_view(
@@ -6064,6 +7580,11 @@ impl RefBoxApp {
                 self.snapshot.conf_pause_time,
                 self.snapshot.scores,
             ),
+            AppState::DisallowGoal {
+                selected,
+                reason,
+                indices,
+            } => build_disallow_goal_page(data, selected, reason, indices),
             AppState::PenaltyOverview(indices) => build_penalty_overview_page(
                 data,
                 self.pen_edit.get_printable_lists(Instant::now()).unwrap(),
@@ -6088,18 +7609,21 @@ impl RefBoxApp {
                     self.edited_settings.as_ref().map(|e| e.game_number.clone()),
                     &self.game_rosters,
                 ),
-            AppState::GameDetailsPage(is_refreshing) => build_game_info_page(
-                data,
-                &self.config.game,
-                self.uses_remote(),
-                is_refreshing,
-                self.schedule.as_ref(),
-                self.tm
-                    .lock()
-                    .unwrap()
-                    .last_game_info()
-                    .map(|i| (i.game_number.clone(), i.scores)),
-            ),
+            AppState::GameDetailsPage(is_refreshing) => {
+                let schedule = self.resolved_schedule();
+                let tm = self.tm.lock().unwrap();
+                build_game_info_page(
+                    data,
+                    &self.config.game,
+                    self.uses_remote(),
+                    is_refreshing,
+                    schedule,
+                    tm.last_game_info()
+                        .map(|i| (i.game_number.clone(), i.scores)),
+                    (tm.can_undo(), tm.can_redo()),
+                    !self.results.is_empty(),
+                )
+            }
             AppState::WarningsSummaryPage => build_warnings_summary_page(data),
             AppState::PowerPage => build_power_page(data),
             AppState::EditGameConfig(page) => build_game_config_edit_page(
@@ -6134,6 +7658,50 @@ impl RefBoxApp {
             }
             AppState::ConfirmScores(scores) =>
                 build_score_confirmation_page(data, scores, self.snapshot.conf_pause_time),
+            AppState::ResultSignOff => {
+                // Invariant: `sign_off` is set whenever this page is opened, and only taken on
+                // the way out of it.
+                let pending = self
+                    .sign_off
+                    .as_ref()
+                    .expect("sign_off must be Some when AppState is ResultSignOff");
+                build_result_sign_off_page(
+                    data,
+                    &pending.game_number,
+                    pending.scores,
+                    pending.sign_offs,
+                )
+            }
+            AppState::IncidentReport => {
+                // Invariant: `incident` is set whenever this page is opened, and only taken on
+                // the way out of it.
+                let incident = self
+                    .incident
+                    .as_ref()
+                    .expect("incident must be Some when AppState is IncidentReport");
+                build_incident_report_page(data, incident)
+            }
+            AppState::PlayerStatsPage {
+                discipline,
+                scroll_index,
+            } => {
+                let stats = self.player_stats();
+                build_player_stats_page(
+                    data,
+                    stats.as_ref().map(|(_, stats)| stats),
+                    discipline,
+                    scroll_index,
+                )
+            }
+            AppState::TimelinePage { scroll_index } => {
+                let timeline = self.tm.lock().unwrap().timeline();
+                build_timeline_page(data, &timeline, scroll_index)
+            }
+            AppState::AuditTrailPage { scroll_index } => {
+                let mut trail = self.tm.lock().unwrap().audit_trail();
+                trail.reverse();
+                build_audit_trail_page(data, &trail, scroll_index)
+            }
             AppState::PortalDetailPage { scroll_index } =>
                 build_portal_detail_page(
                     data,
@@ -6289,6 +7857,7 @@ impl RefBoxApp {
                 is_confirmation, ..
             } if is_confirmation => {}
             AppState::ConfirmScores(_) => {}
+            AppState::ResultSignOff => {}
             // The power page has its own Back button and no game controls.
             AppState::PowerPage => {}
             // BeepTest mode has its own bottom action row; the timeout ribbon
@@ -6462,8 +8031,8 @@ mod usable_cap_numbers_tests {
     }
 }
 
-/// Copy the six plain `Config` toggles owned by the App Options page out of the
-/// staged edits. Returns `true` when `hide_time` changed, which the live Apply
+/// Copy the six plain `Config` toggles and the scoresheet style owned by the App Options page
+/// out of the staged edits. Returns `true` when `hide_time` changed, which the live Apply
 /// path has to report to the update server.
 ///
 /// A free function shared by both commit paths, rather than a method or two
@@ -6482,6 +8051,8 @@ fn commit_app_toggles(config: &mut Config, edited: &EditableSettings) -> bool {
     config.show_behind_schedule_time = edited.show_behind_schedule_time;
     config.confirm_score = edited.confirm_score;
     config.audible_countdown = edited.audible_countdown;
+    config.scoresheet_style = edited.scoresheet_style;
+    config.confirm_result = edited.confirm_result;
     let hide_time_changed = config.hide_time != edited.hide_time;
     config.hide_time = edited.hide_time;
     hide_time_changed
@@ -6501,6 +8072,8 @@ mod commit_app_toggles_tests {
             confirm_score: false,
             audible_countdown: true,
             hide_time: true,
+            scoresheet_style: ScoresheetStyle::Detailed,
+            confirm_result: true,
             ..Default::default()
         }
     }
@@ -6517,9 +8090,12 @@ mod commit_app_toggles_tests {
         assert!(!config.confirm_score);
         assert!(config.audible_countdown);
         assert!(config.hide_time);
+        assert_eq!(config.scoresheet_style, ScoresheetStyle::Detailed);
+        assert!(config.confirm_result);
     }
 
     #[test]
+    #[allow(clippy::field_reassign_with_default)]
     fn all_six_toggles_are_copied_the_other_way() {
         // Guards against a hardcoded assignment rather than a copy: the same six
         // fields driven back in the opposite direction. All six start `true` so
@@ -6534,6 +8110,8 @@ mod commit_app_toggles_tests {
         config.confirm_score = true;
         config.audible_countdown = true;
         config.hide_time = true;
+        config.scoresheet_style = ScoresheetStyle::Simple;
+        config.confirm_result = true;
         let edited = EditableSettings::default();
 
         commit_app_toggles(&mut config, &edited);
@@ -6550,6 +8128,8 @@ mod commit_app_toggles_tests {
         assert_eq!(config.confirm_score, edited.confirm_score);
         assert_eq!(config.audible_countdown, edited.audible_countdown);
         assert_eq!(config.hide_time, edited.hide_time);
+        assert_eq!(config.scoresheet_style, edited.scoresheet_style);
+        assert_eq!(config.confirm_result, edited.confirm_result);
     }
 
     #[test]
@@ -6617,6 +8197,8 @@ mod commit_app_toggles_tests {
         expected.confirm_score = edited.confirm_score;
         expected.audible_countdown = edited.audible_countdown;
         expected.hide_time = edited.hide_time;
+        expected.scoresheet_style = edited.scoresheet_style;
+        expected.confirm_result = edited.confirm_result;
 
         commit_app_toggles(&mut config, &edited);
 
@@ -0,0 +1,361 @@
+//! Read-only HTTP status API.
+//!
+//! Tools on the pool deck that would rather not speak the JSON-over-TCP stream on `--json-port`
+//! (an announcer's laptop, the results desk, a stream PC) can poll this instead. It serves, as
+//! JSON under `/api/v1`:
+//!
+//! - `snapshot`: the current `GameSnapshot`
+//! - `games`: the current and next game, with their teams, start time, court and timing rule
+//! - `schedule`: the loaded schedule, with the knockout and seeded slots that the results on this
+//!   refbox decide filled in, `404` when there is none
+//! - `teams`: the current event's team names by team id
+//! - `portal`: the portal indicator and every result still queued for the portal
+//! - `results`: the local results archive
+//!
+//! Nothing here changes anything on the refbox. It is off unless `--status-port` is given, and
+//! listens on `--status-bind` (loopback by default). With a key in the `UWH_REFBOX_STATUS_KEY`
+//! environment variable, every request must carry the key, as `Authorization: Bearer <key>` or as
+//! a `key` query parameter. The key is not a command line flag so that it stays out of `ps`.
+
+use super::update_sender::{TCP_BIND_RETRY_BUDGET, TCP_BIND_RETRY_INITIAL, bind_with_retry};
+use crate::{
+    portal_manager::{PortalIndicatorState, QueuedResult},
+    results_archive::ResultsArchive,
+};
+use axum::{
+    Json, Router,
+    extract::{Query, Request, State},
+    http::{
+        StatusCode,
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+    },
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    net::IpAddr,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use time::OffsetDateTime;
+use tokio::{io, net::TcpListener, task::JoinHandle};
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    game_snapshot::GameSnapshot,
+    uwhportal::schedule::{GameNumber, Schedule, TeamList},
+};
+
+/// The environment variable the status API's key is read from
+pub const KEY_VAR: &str = "UWH_REFBOX_STATUS_KEY";
+
+/// Where the status API listens, and the key it asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusApiConfig {
+    pub bind: IpAddr,
+    pub port: u16,
+    pub key: Option<String>,
+}
+
+/// Everything the status API serves. The app keeps it current; the API only reads it.
+#[derive(Debug, Clone, Default)]
+pub struct StatusData {
+    pub snapshot: GameSnapshot,
+    pub games: Games,
+    pub schedule: Option<Schedule>,
+    pub teams: TeamList,
+    pub portal: PortalStatus,
+    pub results: ResultsArchive,
+}
+
+/// The game being played, or the last one when between games, and the one after it
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Games {
+    pub current: GameInfo,
+    pub next: GameInfo,
+}
+
+/// One game as the schedule has it. Only the number is known for a game that is not on one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GameInfo {
+    pub number: GameNumber,
+    pub teams: BlackWhiteBundle<Option<String>>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub start_time: Option<OffsetDateTime>,
+    pub court: Option<String>,
+    pub timing_rule: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PortalStatus {
+    /// `None` while the indicator is dormant: no portal in use, or no event linked
+    pub indicator: Option<PortalIndicatorState>,
+    pub queue: Vec<QueuedResult>,
+}
+
+type SharedStatus = Arc<Mutex<StatusData>>;
+
+/// Take the lock even if a panicking holder poisoned it. An `update` that panicked may have
+/// changed some fields and not others, but each field is still whole, and the app's next update
+/// brings the rest up to date.
+fn lock(status: &SharedStatus) -> MutexGuard<'_, StatusData> {
+    status.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[derive(Debug)]
+pub struct StatusApi {
+    status: SharedStatus,
+    join: JoinHandle<()>,
+}
+
+impl StatusApi {
+    pub fn new(config: StatusApiConfig) -> Self {
+        let status = SharedStatus::default();
+        let join = tokio::task::spawn(listen(config, status.clone()));
+        Self { status, join }
+    }
+
+    /// Change what the API serves
+    pub fn update(&self, change: impl FnOnce(&mut StatusData)) {
+        change(&mut lock(&self.status));
+    }
+}
+
+impl Drop for StatusApi {
+    fn drop(&mut self) {
+        self.join.abort();
+    }
+}
+
+async fn listen(config: StatusApiConfig, status: SharedStatus) {
+    let bind = config.bind.to_string();
+    let Some(listener) = bind_with_retry(
+        (&bind, config.port),
+        "status API",
+        TCP_BIND_RETRY_BUDGET,
+        TCP_BIND_RETRY_INITIAL,
+    )
+    .await
+    else {
+        return;
+    };
+    info!(
+        "Serving the status API on {bind} port {} ({})",
+        config.port,
+        if config.key.is_some() {
+            "key required"
+        } else {
+            "no key"
+        }
+    );
+    if let Err(e) = serve(listener, status, config.key).await {
+        error!("The status API stopped: {e}");
+    }
+}
+
+async fn serve(listener: TcpListener, status: SharedStatus, key: Option<String>) -> io::Result<()> {
+    axum::serve(listener, router(status, key)).await
+}
+
+fn router(status: SharedStatus, key: Option<String>) -> Router {
+    let api = Router::new()
+        .route("/snapshot", get(snapshot))
+        .route("/games", get(games))
+        .route("/schedule", get(schedule))
+        .route("/teams", get(teams))
+        .route("/portal", get(portal))
+        .route("/results", get(results))
+        .with_state(status);
+    let api = match key {
+        Some(key) => api.layer(middleware::from_fn_with_state(
+            Arc::<str>::from(key),
+            require_key,
+        )),
+        None => api,
+    };
+    Router::new().nest("/api/v1", api)
+}
+
+async fn snapshot(State(status): State<SharedStatus>) -> Json<GameSnapshot> {
+    Json(lock(&status).snapshot.clone())
+}
+
+async fn games(State(status): State<SharedStatus>) -> Json<Games> {
+    Json(lock(&status).games.clone())
+}
+
+async fn schedule(State(status): State<SharedStatus>) -> Result<Json<Schedule>, StatusCode> {
+    lock(&status)
+        .schedule
+        .clone()
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn teams(State(status): State<SharedStatus>) -> Json<TeamList> {
+    Json(lock(&status).teams.clone())
+}
+
+async fn portal(State(status): State<SharedStatus>) -> Json<PortalStatus> {
+    Json(lock(&status).portal.clone())
+}
+
+async fn results(State(status): State<SharedStatus>) -> Json<ResultsArchive> {
+    Json(lock(&status).results.clone())
+}
+
+#[derive(Deserialize)]
+struct KeyQuery {
+    key: Option<String>,
+}
+
+async fn require_key(State(key): State<Arc<str>>, request: Request, next: Next) -> Response {
+    let bearer = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::to_string);
+    let given = bearer.or_else(|| {
+        Query::<KeyQuery>::try_from_uri(request.uri())
+            .ok()
+            .and_then(|q| q.0.key)
+    });
+    if given.is_some_and(|given| key_matches(&given, &key)) {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, [(WWW_AUTHENTICATE, "Bearer")]).into_response()
+    }
+}
+
+/// Compare without stopping at the first difference, so the time taken says nothing about how
+/// much of a guess was right
+fn key_matches(given: &str, key: &str) -> bool {
+    given.len() == key.len()
+        && given
+            .bytes()
+            .zip(key.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uwh_common::game_snapshot::GamePeriod;
+
+    async fn start(status: SharedStatus, key: Option<&str>) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let url = format!("http://{}/api/v1", listener.local_addr().unwrap());
+        tokio::spawn(serve(listener, status, key.map(str::to_string)));
+        url
+    }
+
+    #[tokio::test]
+    async fn serves_what_the_app_last_published() {
+        let status = SharedStatus::default();
+        let url = start(status.clone(), None).await;
+        let client = reqwest::Client::new();
+
+        lock(&status).snapshot = GameSnapshot {
+            current_period: GamePeriod::FirstHalf,
+            game_number: "7".to_string(),
+            scores: BlackWhiteBundle { black: 2, white: 1 },
+            ..Default::default()
+        };
+        lock(&status).games.next = GameInfo {
+            number: "8".to_string(),
+            teams: BlackWhiteBundle {
+                black: Some("Sharks".to_string()),
+                white: None,
+            },
+            ..Default::default()
+        };
+
+        let snapshot: serde_json::Value = client
+            .get(format!("{url}/snapshot"))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(snapshot["game_number"], "7");
+        assert_eq!(snapshot["scores"]["black"], 2);
+
+        let games: serde_json::Value = client
+            .get(format!("{url}/games"))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(games["next"]["number"], "8");
+        assert_eq!(games["next"]["teams"]["black"], "Sharks");
+
+        let portal: serde_json::Value = client
+            .get(format!("{url}/portal"))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert!(portal["indicator"].is_null());
+        assert_eq!(portal["queue"], serde_json::json!([]));
+
+        let response = client.get(format!("{url}/schedule")).send().await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn nothing_can_be_changed() {
+        let url = start(SharedStatus::default(), None).await;
+        let response = reqwest::Client::new()
+            .post(format!("{url}/snapshot"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn a_key_is_needed_once_one_is_set() {
+        let url = start(SharedStatus::default(), Some("pool-deck")).await;
+        let client = reqwest::Client::new();
+
+        let missing = client.get(format!("{url}/teams")).send().await.unwrap();
+        assert_eq!(missing.status(), reqwest::StatusCode::UNAUTHORIZED);
+        let wrong = client
+            .get(format!("{url}/teams"))
+            .bearer_auth("pool-dock")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(wrong.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+        let header = client
+            .get(format!("{url}/teams"))
+            .bearer_auth("pool-deck")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(header.status(), reqwest::StatusCode::OK);
+        let query = client
+            .get(format!("{url}/teams?key=pool-deck"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(query.status(), reqwest::StatusCode::OK);
+    }
+
+    #[test]
+    fn keys_match_only_exactly() {
+        assert!(key_matches("abc", "abc"));
+        assert!(!key_matches("abd", "abc"));
+        assert!(!key_matches("ab", "abc"));
+        assert!(!key_matches("", "abc"));
+    }
+}
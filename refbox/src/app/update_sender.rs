@@ -1,6 +1,16 @@
+use axum::{
+    Router,
+    extract::{
+        State, WebSocketUpgrade,
+        ws::{Message as WsMessage, WebSocket},
+    },
+    response::{Html, IntoResponse},
+    routing::get,
+};
 use futures_lite::future::FutureExt;
 use log::*;
 use matrix_drawing::transmitted_data::{Brightness, TransmittedData};
+use serde::Serialize;
 use std::{
     collections::HashMap,
     fmt::Debug,
@@ -19,7 +29,10 @@ use tokio::{
     time::{Duration, Instant, sleep, sleep_until, timeout},
 };
 use tokio_serial::{SerialPortBuilder, SerialPortBuilderExt, SerialStream};
-use uwh_common::game_snapshot::{EncodingError, GamePeriod, GameSnapshot, GameSnapshotNoHeap};
+use uwh_common::{
+    game_snapshot::{EncodingError, GamePeriod, GameSnapshot, GameSnapshotNoHeap},
+    uwhportal::schedule::TimingRule,
+};
 
 /// Time budget for retrying a *transient* serial-open failure, plus the initial
 /// backoff step (doubled each attempt, capped by the budget). This bounds when a
@@ -105,6 +118,7 @@ impl UpdateSender {
         initial: Vec<SerialPortBuilder>,
         binary_port: u16,
         json_port: u16,
+        websocket_port: u16,
         hide_time: bool,
         beep_test: bool,
         initial_layout: crate::sim_frame::FrontDisplayLayout,
@@ -116,7 +130,12 @@ impl UpdateSender {
         let server_join =
             task::spawn(Server::new(rx, initial, hide_time, beep_test, initial_layout).run_loop());
 
-        let listener_join = task::spawn(listener_loop(tx.clone(), binary_port, json_port));
+        let listener_join = task::spawn(listener_loop(
+            tx.clone(),
+            binary_port,
+            json_port,
+            websocket_port,
+        ));
 
         Self {
             tx,
@@ -125,23 +144,26 @@ impl UpdateSender {
         }
     }
 
+    /// `timing_rule` is the schedule's rule for the game, which only the WebSocket clients are sent
     pub fn send_snapshot(
         &self,
         snapshot: GameSnapshot,
+        timing_rule: Option<TimingRule>,
         white_on_right: bool,
         brightness: Brightness,
     ) -> Result<(), TrySendError<Box<GameSnapshot>>> {
         self.tx
             .try_send(ServerMessage::NewSnapshot(
                 Box::new(snapshot),
+                timing_rule.map(Box::new),
                 white_on_right,
                 brightness,
             ))
             .map_err(|e| match e {
-                TrySendError::Full(ServerMessage::NewSnapshot(snapshot, _, _)) => {
+                TrySendError::Full(ServerMessage::NewSnapshot(snapshot, _, _, _)) => {
                     TrySendError::Full(snapshot)
                 }
-                TrySendError::Closed(ServerMessage::NewSnapshot(snapshot, _, _)) => {
+                TrySendError::Closed(ServerMessage::NewSnapshot(snapshot, _, _, _)) => {
                     TrySendError::Closed(snapshot)
                 }
                 _ => unreachable!(),
@@ -200,6 +222,10 @@ enum WorkerError {
     ChannelClosed,
     #[error("The sender sent an illegal first message")]
     IllegalMessage,
+    #[error("The client closed the connection")]
+    ClientClosed,
+    #[error(transparent)]
+    WebSocketError(#[from] axum::Error),
     #[error(transparent)]
     EncodingError(#[from] EncodingError),
     #[error(transparent)]
@@ -226,9 +252,39 @@ async fn worker_loop<T: AsyncWrite + Debug + Unpin + Send>(
     }
 }
 
+/// `worker_loop` for a WebSocket client. The socket is read as well as written, so that a ping is
+/// answered and a client that closes the connection is noticed between snapshots.
+async fn websocket_worker_loop(
+    mut rx: mpsc::Receiver<String>,
+    mut socket: WebSocket,
+) -> Result<(), WorkerError> {
+    loop {
+        select! {
+            data = rx.recv() => {
+                let data = data.ok_or(WorkerError::ChannelClosed)?;
+                match timeout(TIMEOUT, socket.send(WsMessage::Text(data.into()))).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => {
+                        error!("Send to WebSocket client failed: {e:?}");
+                        Err(e)?;
+                    }
+                    Err(_) => {
+                        warn!("Send to WebSocket client timed out");
+                    }
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(WsMessage::Close(_))) | None => return Err(WorkerError::ClientClosed),
+                Some(Ok(_)) => {}
+                Some(Err(e)) => Err(e)?,
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 enum SerialWorkerMessage {
-    NewSnapshot(GameSnapshotNoHeap, bool, Brightness),
+    NewSnapshot(Box<GameSnapshotNoHeap>, bool, Brightness),
     TriggerFlash,
 }
 
@@ -248,7 +304,7 @@ async fn serial_worker_loop(
     };
 
     let mut data = TransmittedData {
-        snapshot,
+        snapshot: *snapshot,
         flash: false,
         beep_test,
         brightness,
@@ -281,7 +337,7 @@ async fn serial_worker_loop(
             recv = rx.recv() => {
                 match recv {
                     Some(SerialWorkerMessage::NewSnapshot(snapshot, white_on_right, brightness)) => {
-                        data.snapshot = snapshot;
+                        data.snapshot = *snapshot;
                         data.white_on_right = white_on_right;
                         data.brightness = brightness;
                         bytes = data.encode()?;
@@ -307,6 +363,7 @@ pub enum SendType {
 enum WorkerTx {
     Binary(mpsc::Sender<Vec<u8>>),
     Json(mpsc::Sender<Vec<u8>>),
+    WebSocket(mpsc::Sender<String>),
     Serial(mpsc::Sender<SerialWorkerMessage>),
 }
 
@@ -331,6 +388,13 @@ impl WorkerHandle {
         }
     }
 
+    fn new_websocket(tx: mpsc::Sender<String>, join: JoinHandle<Result<(), WorkerError>>) -> Self {
+        WorkerHandle {
+            tx: WorkerTx::WebSocket(tx),
+            join,
+        }
+    }
+
     fn new_serial(
         tx: mpsc::Sender<SerialWorkerMessage>,
         join: JoinHandle<Result<(), WorkerError>>,
@@ -349,6 +413,10 @@ impl WorkerHandle {
         matches!(self.tx, WorkerTx::Json(_))
     }
 
+    fn is_websocket(&self) -> bool {
+        matches!(self.tx, WorkerTx::WebSocket(_))
+    }
+
     fn is_serial(&self) -> bool {
         matches!(self.tx, WorkerTx::Serial(_))
     }
@@ -357,6 +425,7 @@ impl WorkerHandle {
         &self,
         binary: &[u8],
         json: &[u8],
+        websocket: &str,
         snapshot: &GameSnapshotNoHeap,
         white_on_right: bool,
         brightness: Brightness,
@@ -364,9 +433,12 @@ impl WorkerHandle {
         match self.tx {
             WorkerTx::Binary(ref tx) => tx.try_send(Vec::from(binary)).map_err(error_formatter),
             WorkerTx::Json(ref tx) => tx.try_send(Vec::from(json)).map_err(error_formatter),
+            WorkerTx::WebSocket(ref tx) => {
+                tx.try_send(websocket.to_string()).map_err(error_formatter)
+            }
             WorkerTx::Serial(ref tx) => tx
                 .try_send(SerialWorkerMessage::NewSnapshot(
-                    snapshot.clone(),
+                    Box::new(snapshot.clone()),
                     white_on_right,
                     brightness,
                 ))
@@ -388,10 +460,19 @@ fn error_formatter<T: Debug>(old: TrySendError<T>) -> TrySendError<String> {
     }
 }
 
+/// What each WebSocket client is sent, one message per snapshot. The team names are in the
+/// snapshot.
+#[derive(Debug, Serialize)]
+struct WebSocketUpdate<'a> {
+    snapshot: &'a GameSnapshot,
+    timing_rule: Option<&'a TimingRule>,
+}
+
 #[derive(Debug)]
 pub enum ServerMessage {
     NewConnection(SendType, TcpStream),
-    NewSnapshot(Box<GameSnapshot>, bool, Brightness),
+    NewWebSocket(Box<WebSocket>),
+    NewSnapshot(Box<GameSnapshot>, Option<Box<TimingRule>>, bool, Brightness),
     TriggerFlash,
     Stop,
     SetHideTime(bool),
@@ -405,12 +486,17 @@ struct Server {
     rx: mpsc::Receiver<ServerMessage>,
     has_binary: bool,
     has_json: bool,
+    has_websocket: bool,
     snapshot: GameSnapshotNoHeap,
+    /// The last snapshot in full, with its timing rule, kept while any WebSocket client is
+    /// connected so that another one can be sent it as soon as it connects
+    latest: Option<(GameSnapshot, Option<Box<TimingRule>>)>,
     white_on_right: bool,
     brightness: Brightness,
     flash: bool,
     binary: Vec<u8>,
     json: Vec<u8>,
+    websocket: String,
     hide_time: bool,
     beep_test: bool,
     layout: crate::sim_frame::FrontDisplayLayout,
@@ -430,12 +516,15 @@ impl Server {
             rx,
             has_binary: false,
             has_json: false,
+            has_websocket: false,
             snapshot: Default::default(),
+            latest: None,
             white_on_right: false,
             brightness: Brightness::Low,
             flash: false,
             binary: Vec::new(),
             json: Vec::new(),
+            websocket: String::new(),
             hide_time,
             beep_test,
             layout: initial_layout,
@@ -474,6 +563,23 @@ impl Server {
         new_id
     }
 
+    fn add_websocket_sender(&mut self, socket: WebSocket) -> usize {
+        let (tx, rx) = mpsc::channel(WORKER_CHANNEL_LEN);
+        let join = task::spawn(websocket_worker_loop(rx, socket));
+
+        let new_id = self.next_id;
+        self.senders
+            .insert(new_id, WorkerHandle::new_websocket(tx, join));
+        self.next_id += 1;
+
+        if !self.has_websocket {
+            self.has_websocket = true;
+            self.encode_websocket();
+        }
+
+        new_id
+    }
+
     fn add_serial_sender(&mut self, sender: SerialStream) {
         let (tx, rx) = mpsc::channel(WORKER_CHANNEL_LEN);
         let join = task::spawn(serial_worker_loop(rx, sender, self.beep_test));
@@ -488,14 +594,22 @@ impl Server {
     fn check_types(&mut self) {
         self.has_binary = self.senders.iter().any(|(_, handle)| handle.is_binary());
         self.has_json = self.senders.iter().any(|(_, handle)| handle.is_json());
+        self.has_websocket = self.senders.iter().any(|(_, handle)| handle.is_websocket());
+        if !self.has_websocket {
+            self.latest = None;
+        }
     }
 
-    fn encode(&mut self, new_snapshot: GameSnapshot) {
+    fn encode(&mut self, new_snapshot: GameSnapshot, timing_rule: Option<Box<TimingRule>>) {
         self.json = if self.has_json {
             (serde_json::to_string(&new_snapshot).unwrap() + "\n").into_bytes()
         } else {
             Vec::new()
         };
+        if self.has_websocket {
+            self.latest = Some((new_snapshot.clone(), timing_rule));
+        }
+        self.encode_websocket();
 
         let next_time = new_snapshot.next_period_len_secs.unwrap_or(0) as u16;
 
@@ -520,13 +634,27 @@ impl Server {
                 | GamePeriod::OvertimeFirstHalf
                 | GamePeriod::OvertimeSecondHalf
                 | GamePeriod::SecondHalf
-                | GamePeriod::SuddenDeath => {}
+                | GamePeriod::SuddenDeath
+                | GamePeriod::PenaltyShootout => {}
             }
         }
 
         self.encode_flash();
     }
 
+    fn encode_websocket(&mut self) {
+        self.websocket = match &self.latest {
+            Some((snapshot, timing_rule)) if self.has_websocket => {
+                serde_json::to_string(&WebSocketUpdate {
+                    snapshot,
+                    timing_rule: timing_rule.as_deref(),
+                })
+                .unwrap()
+            }
+            _ => String::new(),
+        };
+    }
+
     fn encode_flash(&mut self) {
         self.binary = if self.has_binary {
             Vec::from(
@@ -562,6 +690,7 @@ impl Server {
             if let Err(e) = handle.send(
                 &self.binary,
                 &self.json,
+                &self.websocket,
                 &self.snapshot,
                 self.white_on_right,
                 self.brightness,
@@ -618,6 +747,7 @@ impl Server {
                                     if let Err(e) = handle.send(
                                         &self.binary,
                                         &self.json,
+                                        &self.websocket,
                                         &self.snapshot,
                                         self.white_on_right,
                                         self.brightness,
@@ -629,10 +759,32 @@ impl Server {
                                 }
                             }
                         }
-                        Some(ServerMessage::NewSnapshot(snapshot, white_on_right, brightness)) => {
+                        Some(ServerMessage::NewWebSocket(socket)) => {
+                            // The full snapshot is only kept while a scoreboard is connected, so
+                            // the first one waits for the next snapshot and later ones are sent
+                            // the last one straight away.
+                            let new_id = self.add_websocket_sender(*socket);
+                            if !self.websocket.is_empty()
+                                && let Some(handle) = self.senders.get(&new_id)
+                            {
+                                if let Err(e) = handle.send(
+                                    &self.binary,
+                                    &self.json,
+                                    &self.websocket,
+                                    &self.snapshot,
+                                    self.white_on_right,
+                                    self.brightness,
+                                ) {
+                                    error!(
+                                        "Error replaying latest snapshot to new client {new_id}: {e:?}"
+                                    );
+                                }
+                            }
+                        }
+                        Some(ServerMessage::NewSnapshot(snapshot, timing_rule, white_on_right, brightness)) => {
                             self.white_on_right = white_on_right;
                             self.brightness = brightness;
-                            self.encode(*snapshot);
+                            self.encode(*snapshot, timing_rule);
                             self.send_to_workers(false);
                         }
                         Some(ServerMessage::TriggerFlash) => {
@@ -682,8 +834,8 @@ impl Drop for Server {
 /// runs to completion, so worst-case wall time is up to one extra backoff beyond
 /// the budget.
 /// 🔧 PI: confirm/tune on the spare Pi during the 5×-restart test.
-const TCP_BIND_RETRY_BUDGET: Duration = Duration::from_millis(2000);
-const TCP_BIND_RETRY_INITIAL: Duration = Duration::from_millis(100);
+pub(super) const TCP_BIND_RETRY_BUDGET: Duration = Duration::from_millis(2000);
+pub(super) const TCP_BIND_RETRY_INITIAL: Duration = Duration::from_millis(100);
 
 /// A bind failure is worth retrying only when the address is momentarily still
 /// in use (e.g. held by the exiting process during a restart).
@@ -694,7 +846,7 @@ fn is_transient_bind_error(e: &io::Error) -> bool {
 /// Bind a TCP listener, retrying an `AddrInUse` failure within `budget` (with
 /// exponential backoff from `initial`) before giving up and returning `None`.
 /// Any non-transient error gives up immediately. Never panics.
-async fn bind_with_retry(
+pub(super) async fn bind_with_retry(
     addr: (&str, u16),
     label: &str,
     budget: Duration,
@@ -719,8 +871,15 @@ async fn bind_with_retry(
     }
 }
 
-async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_port: u16) {
-    info!("Starting Listeners for JSON (port {json_port}) and binary (port {binary_port})");
+async fn listener_loop(
+    tx: mpsc::Sender<ServerMessage>,
+    binary_port: u16,
+    json_port: u16,
+    websocket_port: u16,
+) {
+    info!(
+        "Starting Listeners for JSON (port {json_port}), binary (port {binary_port}) and WebSocket (port {websocket_port})"
+    );
     let binary_listener_v6 = bind_with_retry(
         ("::", binary_port),
         "binary",
@@ -741,8 +900,70 @@ async fn listener_loop(tx: mpsc::Sender<ServerMessage>, binary_port: u16, json_p
     let binary_listener_v4 = TcpListener::bind(("0.0.0.0", binary_port)).await.ok();
     let json_listener_v4 = TcpListener::bind(("0.0.0.0", json_port)).await.ok();
 
+    let websocket_listener_v6 = bind_with_retry(
+        ("::", websocket_port),
+        "WebSocket",
+        TCP_BIND_RETRY_BUDGET,
+        TCP_BIND_RETRY_INITIAL,
+    )
+    .await;
+    let websocket_listener_v4 = TcpListener::bind(("0.0.0.0", websocket_port)).await.ok();
+
     info!("Listeners started");
 
+    // The WebSocket listeners are served from this task too, so aborting it stops them all
+    tokio::join!(
+        serve_websocket(websocket_listener_v6, tx.clone()),
+        serve_websocket(websocket_listener_v4, tx.clone()),
+        accept_loop(
+            tx,
+            binary_listener_v6,
+            json_listener_v6,
+            binary_listener_v4,
+            json_listener_v4,
+        ),
+    );
+}
+
+/// The page served at `/` on the WebSocket port, a scoreboard for any browser on the network
+const SCOREBOARD_HTML: &str = include_str!("../../resources/scoreboard.html");
+
+async fn serve_websocket(listener: Option<TcpListener>, tx: mpsc::Sender<ServerMessage>) {
+    let Some(listener) = listener else {
+        return;
+    };
+    let router = Router::new()
+        .route("/", get(|| async { Html(SCOREBOARD_HTML) }))
+        .route("/ws", get(websocket_upgrade))
+        .with_state(tx);
+    if let Err(e) = axum::serve(listener, router).await {
+        error!("The WebSocket listener stopped: {e}");
+    }
+}
+
+async fn websocket_upgrade(
+    State(tx): State<mpsc::Sender<ServerMessage>>,
+    upgrade: WebSocketUpgrade,
+) -> impl IntoResponse {
+    upgrade.on_upgrade(async move |socket| {
+        info!("New WebSocket connection");
+        if tx
+            .send(ServerMessage::NewWebSocket(Box::new(socket)))
+            .await
+            .is_err()
+        {
+            error!("New WebSocket connection failed: the server has stopped");
+        }
+    })
+}
+
+async fn accept_loop(
+    tx: mpsc::Sender<ServerMessage>,
+    binary_listener_v6: Option<TcpListener>,
+    json_listener_v6: Option<TcpListener>,
+    binary_listener_v4: Option<TcpListener>,
+    json_listener_v4: Option<TcpListener>,
+) {
     loop {
         type ListenResult = std::io::Result<(TcpStream, SocketAddr)>;
 
@@ -818,6 +1039,7 @@ mod test {
 
     const BINARY_PORT: u16 = 12345;
     const JSON_PORT: u16 = 12346;
+    const WEBSOCKET_PORT: u16 = 12349;
     const MAX_CONN_FAILS: usize = 20;
 
     #[tokio::test]
@@ -826,6 +1048,7 @@ mod test {
             vec![],
             BINARY_PORT,
             JSON_PORT,
+            WEBSOCKET_PORT,
             false,
             false,
             FrontDisplayLayout::Default,
@@ -902,45 +1125,45 @@ mod test {
                     PenaltySnapshot {
                         time: PenaltyTime::Seconds(57),
                         player_number: 3,
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         time: PenaltyTime::Seconds(117),
                         player_number: 6,
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         time: PenaltyTime::Seconds(297),
                         player_number: 12,
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         time: PenaltyTime::TotalDismissal,
                         player_number: 15,
-                        infraction: Infraction::FreeArm,
+                        infraction: Infraction::FREE_ARM,
                     },
                 ],
             },
             warnings: BlackWhiteBundle {
                 black: vec![
                     InfractionSnapshot {
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                         player_number: Some(3),
                     },
                     InfractionSnapshot {
-                        infraction: Infraction::OutOfBounds,
+                        infraction: Infraction::OUT_OF_BOUNDS,
                         player_number: Some(6),
                     },
                 ],
                 white: vec![
                     InfractionSnapshot {
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                         player_number: Some(12),
                     },
                     InfractionSnapshot {
-                        infraction: Infraction::StickInfringement,
+                        infraction: Infraction::STICK_INFRINGEMENT,
                         player_number: None,
                     },
                 ],
@@ -948,31 +1171,31 @@ mod test {
             fouls: OptColorBundle {
                 black: vec![
                     InfractionSnapshot {
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                         player_number: Some(3),
                     },
                     InfractionSnapshot {
-                        infraction: Infraction::OutOfBounds,
+                        infraction: Infraction::OUT_OF_BOUNDS,
                         player_number: Some(6),
                     },
                 ],
                 white: vec![
                     InfractionSnapshot {
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                         player_number: Some(12),
                     },
                     InfractionSnapshot {
-                        infraction: Infraction::StickInfringement,
+                        infraction: Infraction::STICK_INFRINGEMENT,
                         player_number: None,
                     },
                 ],
                 equal: vec![
                     InfractionSnapshot {
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                         player_number: None,
                     },
                     InfractionSnapshot {
-                        infraction: Infraction::StickInfringement,
+                        infraction: Infraction::STICK_INFRINGEMENT,
                         player_number: None,
                     },
                 ],
@@ -981,8 +1204,12 @@ mod test {
             game_number: "26".to_string(),
             next_game_number: "28".to_string(),
             event_id: Some(EventId::from_partial("1-A")),
+            team_names: Default::default(),
             recent_goal: None,
+            goals: Default::default(),
+            recent_disallowed_goal: None,
             next_period_len_secs: Some(180),
+            shootout: None,
             conf_pause_time: None,
         };
 
@@ -1004,7 +1231,7 @@ mod test {
         );
 
         update_sender
-            .send_snapshot(snapshot, white_on_right, brightness)
+            .send_snapshot(snapshot, None, white_on_right, brightness)
             .unwrap();
 
         let expected_binary_bytes = binary_expected.len();
@@ -1041,11 +1268,13 @@ mod test {
     async fn binary_port_emits_beep_test_flag_when_constructed_in_beep_test_mode() {
         const BT_BINARY_PORT: u16 = 12347;
         const BT_JSON_PORT: u16 = 12348;
+        const BT_WEBSOCKET_PORT: u16 = 12350;
 
         let update_sender = UpdateSender::new(
             vec![],
             BT_BINARY_PORT,
             BT_JSON_PORT,
+            BT_WEBSOCKET_PORT,
             false,
             /* beep_test */ true,
             FrontDisplayLayout::Default,
@@ -1112,8 +1341,12 @@ mod test {
             game_number: "1".to_string(),
             next_game_number: "2".to_string(),
             event_id: None,
+            team_names: Default::default(),
             recent_goal: None,
+            goals: Default::default(),
+            recent_disallowed_goal: None,
             next_period_len_secs: None,
+            shootout: None,
             conf_pause_time: None,
         };
 
@@ -1133,7 +1366,7 @@ mod test {
         );
 
         update_sender
-            .send_snapshot(snapshot, false, Brightness::Low)
+            .send_snapshot(snapshot, None, false, Brightness::Low)
             .unwrap();
 
         let expected_binary_bytes = binary_expected.len();
@@ -1153,6 +1386,115 @@ mod test {
         assert_eq!(binary_expected, binary_result);
     }
 
+    #[tokio::test]
+    async fn websocket_clients_get_the_snapshot_with_names_and_timing_rule() {
+        use futures_lite::StreamExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        const WS_BINARY_PORT: u16 = 12351;
+        const WS_JSON_PORT: u16 = 12352;
+        const WS_PORT: u16 = 12353;
+
+        let update_sender = UpdateSender::new(
+            vec![],
+            WS_BINARY_PORT,
+            WS_JSON_PORT,
+            WS_PORT,
+            false,
+            false,
+            FrontDisplayLayout::Default,
+        );
+
+        let connect = || async {
+            let mut fail_count = 0;
+            loop {
+                match tokio_tungstenite::connect_async(format!("ws://localhost:{WS_PORT}/ws")).await
+                {
+                    Ok((socket, _)) => break socket,
+                    Err(e) => {
+                        assert_le!(fail_count, MAX_CONN_FAILS, "{e:?}");
+                        fail_count += 1;
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                    }
+                }
+            }
+        };
+        let read_update = |message: Message| {
+            let Message::Text(update) = message else {
+                panic!("Expected a text message, got {message:?}");
+            };
+            serde_json::from_str::<serde_json::Value>(&update).unwrap()
+        };
+
+        let snapshot = GameSnapshot {
+            current_period: GamePeriod::SecondHalf,
+            secs_in_period: 95,
+            scores: BlackWhiteBundle { black: 3, white: 2 },
+            game_number: "12".to_string(),
+            team_names: BlackWhiteBundle {
+                black: Some("Sharks".to_string()),
+                white: Some("Eels".to_string()),
+            },
+            ..Default::default()
+        };
+        let timing_rule = TimingRule {
+            name: "RR".to_string(),
+            team_timeout_count: 1,
+            team_timeouts_counted_per_half: false,
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            last_2_min_stop_time: false,
+            half_play_duration: Duration::from_secs(600),
+            half_time_duration: Duration::from_secs(180),
+            team_timeout_duration: Duration::from_secs(60),
+            ot_half_play_duration: Duration::ZERO,
+            ot_half_time_duration: Duration::ZERO,
+            pre_overtime_break: Duration::ZERO,
+            pre_sudden_death_duration: Duration::ZERO,
+            minimum_break: Duration::from_secs(120),
+            game_block: None,
+        };
+
+        // Nothing is kept for a scoreboard before one connects, so the first one gets the next
+        // snapshot. Keep sending until the server has registered it.
+        let mut first = connect().await;
+        let update = loop {
+            update_sender
+                .send_snapshot(
+                    snapshot.clone(),
+                    Some(timing_rule.clone()),
+                    false,
+                    Brightness::Low,
+                )
+                .unwrap();
+            if let Ok(message) =
+                tokio::time::timeout(Duration::from_millis(100), first.next()).await
+            {
+                break read_update(message.unwrap().unwrap());
+            }
+        };
+        assert_eq!(update["snapshot"]["secs_in_period"], 95);
+        assert_eq!(update["snapshot"]["scores"]["black"], 3);
+        assert_eq!(update["snapshot"]["team_names"]["black"], "Sharks");
+        assert_eq!(update["snapshot"]["team_names"]["white"], "Eels");
+        assert!(update.get("team_names").is_none());
+        assert_eq!(update["timing_rule"]["name"], "RR");
+
+        // A scoreboard joining one already connected is sent the last snapshot straight away
+        let mut second = connect().await;
+        let update = read_update(second.next().await.unwrap().unwrap());
+        assert_eq!(update["snapshot"]["game_number"], "12");
+        assert_eq!(update["timing_rule"]["name"], "RR");
+
+        let page = reqwest::get(format!("http://localhost:{WS_PORT}/"))
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(page, SCOREBOARD_HTML);
+    }
+
     #[test]
     fn error_formatter_preserves_full_vs_closed_variant() {
         // Treating Full as Closed tears down a live panel-sim TCP
@@ -1179,10 +1521,10 @@ mod test {
             ..Default::default()
         };
         // 9s left -> hidden: shows the 900s (15:00) upcoming period length.
-        server.encode(snap(9));
+        server.encode(snap(9), None);
         assert_eq!(server.snapshot.secs_in_period, 900);
         // Exactly 10s left -> still the live countdown (threshold is `< 10`).
-        server.encode(snap(10));
+        server.encode(snap(10), None);
         assert_eq!(server.snapshot.secs_in_period, 10);
     }
 
@@ -1192,12 +1534,15 @@ mod test {
         // shown, even in the final seconds of a pre-game break.
         let (_tx, rx) = mpsc::channel(1);
         let mut server = Server::new(rx, vec![], false, false, FrontDisplayLayout::Default);
-        server.encode(GameSnapshot {
-            current_period: GamePeriod::BetweenGames,
-            secs_in_period: 3,
-            next_period_len_secs: Some(900),
-            ..Default::default()
-        });
+        server.encode(
+            GameSnapshot {
+                current_period: GamePeriod::BetweenGames,
+                secs_in_period: 3,
+                next_period_len_secs: Some(900),
+                ..Default::default()
+            },
+            None,
+        );
         assert_eq!(server.snapshot.secs_in_period, 3);
     }
 
@@ -0,0 +1,217 @@
+use super::*;
+use collect_array::CollectArrayResult;
+use iced::{
+    Element, Length,
+    alignment::{Horizontal, Vertical},
+    widget::{column, container, horizontal_space, row, text},
+};
+use uwh_common::color::Color as GameColor;
+
+const AUDIT_TRAIL_LIST_LEN: usize = 4;
+
+/// Render the changes made by hand to the current game, most recent first, each with what it was
+/// changed from and to
+pub(in super::super) fn build_audit_trail_page<'a>(
+    data: ViewData<'_, '_>,
+    trail: &[AuditEntry],
+    scroll_index: usize,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        ..
+    } = data;
+
+    let title = text(fl!("edit-history"))
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .size(MEDIUM_TEXT);
+
+    let num_items = trail.len();
+    let scroll_index = scroll_index.min(num_items.saturating_sub(AUDIT_TRAIL_LIST_LEN));
+    let mut no_entries = (num_items == 0).then(|| fl!("no-edits"));
+
+    let rows: CollectArrayResult<_, AUDIT_TRAIL_LIST_LEN> = trail
+        .iter()
+        .skip(scroll_index)
+        .map(Some)
+        .chain([None].into_iter().cycle())
+        .take(AUDIT_TRAIL_LIST_LEN)
+        .map(|entry| match entry {
+            Some(entry) => make_audit_row(entry),
+            None => container(text(no_entries.take().unwrap_or_default()).size(SMALL_PLUS_TEXT))
+                .center(Length::Fill)
+                .height(Length::Fixed(MIN_BUTTON_SIZE))
+                .style(disabled_container)
+                .into(),
+        })
+        .collect();
+
+    let list = make_scroll_list(
+        rows.unwrap(),
+        num_items,
+        scroll_index,
+        title,
+        ScrollOption::AuditTrail,
+        light_gray_container,
+    )
+    .height(Length::Fill)
+    .width(Length::FillPortion(5));
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            false,
+            mode,
+            clock_running,
+            portal_indicator,
+            None,
+        ),
+        list,
+        row![
+            make_button(fl!("back"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::ShowTimeline),
+            horizontal_space(),
+            horizontal_space(),
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+fn make_audit_row<'a>(entry: &AuditEntry) -> Element<'a, Message> {
+    let when = format!(
+        "{} {}",
+        period_text(entry.game_period),
+        time_string(Duration::from_secs_f32(entry.period_time))
+    );
+
+    let clock = |secs: &f32| time_string(Duration::from_secs_f32(*secs));
+    let (what, from, to, color) = match &entry.change {
+        AuditChange::Score { from, to } => (
+            if entry.source == AuditSource::Confirm {
+                fl!("audit-change", change = "score-confirmed")
+            } else {
+                fl!("audit-change", change = "score-edited")
+            },
+            format!("{} - {}", from.black, from.white),
+            format!("{} - {}", to.black, to.white),
+            None,
+        ),
+        AuditChange::GameClock { from, to } => (
+            fl!("audit-change", change = "game-clock"),
+            clock(from),
+            clock(to),
+            None,
+        ),
+        AuditChange::TimeoutClock { from, to } => (
+            fl!("audit-change", change = "timeout-clock"),
+            clock(from),
+            clock(to),
+            None,
+        ),
+        AuditChange::Penalty { from, to } => (
+            match (from, to) {
+                (None, _) => fl!("audit-change", change = "penalty-added"),
+                (Some(_), Some(_)) => fl!("audit-change", change = "penalty-edited"),
+                (Some(_), None) => fl!("audit-change", change = "penalty-deleted"),
+            },
+            from.as_ref().map(penalty_text).unwrap_or_default(),
+            to.as_ref().map(penalty_text).unwrap_or_default(),
+            from.or(*to).map(|penalty| penalty.color),
+        ),
+        AuditChange::Foul { from, to } => (
+            if to.is_some() {
+                fl!("audit-change", change = "foul-added")
+            } else {
+                fl!("audit-change", change = "foul-deleted")
+            },
+            from.as_ref().map(foul_text).unwrap_or_default(),
+            to.as_ref().map(foul_text).unwrap_or_default(),
+            from.or(*to).and_then(|foul| foul.color),
+        ),
+        AuditChange::Clocks { from, to } => (
+            fl!("audit-change", change = "clocks-set"),
+            clocks_text(from),
+            clocks_text(to),
+            None,
+        ),
+        AuditChange::GoalDisallowed {
+            color, from, to, ..
+        } => (
+            fl!("audit-change", change = "goal-disallowed"),
+            format!("{} - {}", from.black, from.white),
+            format!("{} - {}", to.black, to.white),
+            Some(*color),
+        ),
+    };
+    let what = match entry.source {
+        AuditSource::Undo => format!("{what} ({})", fl!("undo")),
+        AuditSource::Redo => format!("{what} ({})", fl!("redo")),
+        AuditSource::EditPage | AuditSource::Confirm | AuditSource::DisallowedGoal => what,
+    };
+
+    container(
+        row![
+            text(when).width(Length::FillPortion(3)),
+            text(what).width(Length::FillPortion(3)),
+            text(format!("{from} \u{2192} {to}")).width(Length::FillPortion(4)),
+        ]
+        .spacing(SPACING)
+        .align_y(Vertical::Center),
+    )
+    .center_y(Length::Fixed(MIN_BUTTON_SIZE))
+    .padding(PADDING)
+    .width(Length::Fill)
+    .style(match color {
+        Some(GameColor::Black) => black_container,
+        Some(GameColor::White) => white_container,
+        None => gray_container,
+    })
+    .into()
+}
+
+fn penalty_text(penalty: &AuditPenalty) -> String {
+    let team = match penalty.color {
+        GameColor::Black => fl!("dark-team-name-caps"),
+        GameColor::White => fl!("light-team-name-caps"),
+    };
+    format!(
+        "{team} #{} {}",
+        penalty.player_number,
+        fl!("penalty-kind", kind = penalty.kind.fluent())
+    )
+}
+
+fn foul_text(foul: &AuditFoul) -> String {
+    let team = match foul.color {
+        Some(GameColor::Black) => fl!("dark-team-name-caps"),
+        Some(GameColor::White) => fl!("light-team-name-caps"),
+        None => fl!("no-team"),
+    };
+    match foul.player_number {
+        Some(number) => format!("{team} #{number} {}", fl!("timeline-foul")),
+        None => format!("{team} {}", fl!("timeline-foul")),
+    }
+}
+
+fn clocks_text(clocks: &AuditClocks) -> String {
+    let clock = time_string(Duration::from_secs_f32(clocks.game_clock));
+    match clocks.timeout {
+        Some(TimeoutKind::Team(_)) => format!("{clock} {}", fl!("timeline-team-timeout")),
+        Some(TimeoutKind::Ref) => format!("{clock} {}", fl!("timeline-ref-timeout")),
+        Some(TimeoutKind::PenaltyShot) => format!("{clock} {}", fl!("timeline-penalty-shot")),
+        None if clocks.running => clock,
+        None => format!("{clock} {}", fl!("audit-clock-stopped")),
+    }
+}
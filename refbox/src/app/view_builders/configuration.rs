@@ -1,7 +1,7 @@
 use super::{ViewData, fl, message::*, shared_elements::*, theme::*};
 use crate::app::PageEntrySnapshot;
 use crate::app::languages::Language;
-use crate::config::{CustomSite, GameSource, Level, Mode, RemoteSource};
+use crate::config::{CustomSite, GameSource, Level, Mode, RemoteSource, ScoresheetStyle};
 use crate::portal_manager::PortalIndicatorState;
 use crate::sim_frame::FrontDisplayLayout;
 use crate::sound_controller::*;
@@ -15,7 +15,7 @@ use iced::{
     },
 };
 use matrix_drawing::transmitted_data::Brightness;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, path::Path};
 use tokio::time::Duration;
 use uwh_common::{
     config::Game as GameConfig,
@@ -24,9 +24,9 @@ use uwh_common::{
 };
 
 impl EditableSettings {
-    /// Whether games come from a remote site at all, as opposed to being
-    /// entered by hand. Most callers only need this question; only the few
-    /// that must tell the official Portal from a third-party site match on
+    /// Whether games come from a schedule at all — a remote site or a schedule
+    /// file — as opposed to being entered by hand. Most callers only need this
+    /// question; only the few that must tell the sources apart match on
     /// `source` directly.
     pub fn uses_remote(&self) -> bool {
         !matches!(self.source, GameSource::Manual)
@@ -60,6 +60,8 @@ pub(in super::super) struct EditableSettings {
     pub show_behind_schedule_time: bool,
     pub confirm_score: bool,
     pub audible_countdown: bool,
+    pub scoresheet_style: ScoresheetStyle,
+    pub confirm_result: bool,
     pub pending_language: Option<Language>,
     pub original_language: Option<Language>,
     /// Staged copy of `config.beep_test.levels` used by the BeepTest
@@ -205,6 +207,17 @@ impl Cyclable for Mode {
     }
 }
 
+impl Cyclable for ScoresheetStyle {
+    fn next(&self) -> Self {
+        match self {
+            Self::Off => Self::Detailed,
+            Self::Detailed => Self::Simple,
+            Self::Simple => Self::CmasOfficial,
+            Self::CmasOfficial => Self::Off,
+        }
+    }
+}
+
 impl Cyclable for Brightness {
     fn next(&self) -> Self {
         match self {
@@ -266,6 +279,8 @@ pub(in super::super) fn page_has_changes(
                 confirm_score,
                 hide_time,
                 audible_countdown,
+                scoresheet_style,
+                confirm_result,
             },
         ) => {
             edited.source != *source
@@ -279,6 +294,8 @@ pub(in super::super) fn page_has_changes(
                 || edited.confirm_score != *confirm_score
                 || edited.hide_time != *hide_time
                 || edited.audible_countdown != *audible_countdown
+                || edited.scoresheet_style != *scoresheet_style
+                || edited.confirm_result != *confirm_result
         }
         (
             ConfigPage::Display,
@@ -331,6 +348,7 @@ pub(in super::super) fn build_game_config_edit_page<'a>(
         portal_indicator,
         has_led_panel,
         committed_site_url,
+        schedule_folder,
         ..
     } = data;
 
@@ -348,6 +366,7 @@ pub(in super::super) fn build_game_config_edit_page<'a>(
         ),
         ConfigPage::Game => make_event_config_page(
             committed_site_url,
+            schedule_folder,
             snapshot,
             settings,
             events,
@@ -642,9 +661,11 @@ fn game_block_validity(cfg: &GameConfig) -> GameBlockValidity {
 #[allow(clippy::too_many_arguments)]
 /// `committed_site_url` is the custom site address as committed — see
 /// `ViewData::committed_site_url` for why the SITE row shows that rather than
-/// the address currently typed into the editor.
+/// the address currently typed into the editor. `schedule_folder` is where the
+/// FILE source's event was loaded from.
 fn make_event_config_page<'a>(
     committed_site_url: &str,
+    schedule_folder: Option<&Path>,
     snapshot: &GameSnapshot,
     settings: &EditableSettings,
     events: Option<&BTreeMap<EventId, Event>>,
@@ -672,8 +693,9 @@ fn make_event_config_page<'a>(
     // otherwise be offered courts and games we were never authorised to have.
     // EVENT is deliberately exempt: it has to be selectable before a token can
     // be obtained at all. `None` (still checking) does not grey anything —
-    // only an outright rejection does.
-    let token_rejected = settings.uwhportal_token_valid == Some(false);
+    // only an outright rejection does. A schedule file needs no token at all.
+    let token_rejected =
+        settings.uwhportal_token_valid == Some(false) && *source != GameSource::File;
 
     // Game-number picker — placed in the centre cell of the action row
     // (Cancel | Game | Apply) in both portal modes per ADR-009 Task 14 layout.
@@ -725,6 +747,7 @@ fn make_event_config_page<'a>(
             match remembered_remote {
                 RemoteSource::Portal => GameSource::Portal,
                 RemoteSource::Custom => GameSource::Custom,
+                RemoteSource::File => GameSource::File,
             }
         })),
     );
@@ -748,8 +771,8 @@ fn make_event_config_page<'a>(
     .height(Length::Fill);
 
     if uses_remote {
-        // Portal mode ON: row 1 = UWH Portal + 2 blanks; rows 2–4 = full-width
-        // Event / Token / Court single-button rows.
+        // Portal mode ON: row 1 = MANUAL GAMES + source buttons; rows 2–4 =
+        // full-width Event / Token / Court single-button rows.
         let event_label = if let Some(events) = events {
             if let Some(event_id) = current_event_id {
                 match events.get(event_id) {
@@ -847,9 +870,9 @@ fn make_event_config_page<'a>(
         .style(light_gray_button)
         .on_press_maybe(auth_state_message);
 
-        // Row 1's second and third cells are blank in remote mode, so the two
-        // source buttons cost no layout change. The active one is marked with
-        // the existing selected-button style rather than a new treatment.
+        // Row 1 holds MANUAL GAMES and the three source buttons. The active one
+        // is marked with the existing selected-button style rather than a new
+        // treatment.
         let portal_source_btn = button(
             text(fl!("source-portal", portal = portal_name_for_mode(mode)))
                 .size(MEDIUM_TEXT)
@@ -884,16 +907,59 @@ fn make_event_config_page<'a>(
         })
         .on_press(Message::SelectGameSource(GameSource::Custom));
 
-        col = col
+        // Pressing FILE again reads the folder again, so it stays live while
+        // selected rather than acting as a plain indicator.
+        let file_source_btn = button(
+            text(fl!("source-file"))
+                .size(MEDIUM_TEXT)
+                .align_x(Horizontal::Center)
+                .align_y(Vertical::Center)
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(if settings.source == GameSource::File {
+            light_gray_selected_button
+        } else {
+            light_gray_button
+        })
+        .on_press(Message::SelectGameSource(GameSource::File));
+
+        col = col.push(
+            row![
+                manual_games_btn,
+                portal_source_btn,
+                custom_source_btn,
+                file_source_btn
+            ]
+            .spacing(SPACING)
+            .height(Length::Fill),
+        );
+
+        col = if *source == GameSource::File {
+            // A schedule file names its own event and needs no token, so the
+            // SCHEDULE FILE row takes the EVENT row's slot and the event, shown
+            // but not pickable, takes the token's.
+            let shown = schedule_folder
+                .map_or_else(|| fl!("not-found"), |folder| folder.display().to_string());
+            col.push(
+                make_long_value_button(
+                    fl!("schedule-file"),
+                    shown,
+                    Some(Message::SelectGameSource(GameSource::File)),
+                )
+                .height(Length::Fill),
+            )
             .push(
-                row![manual_games_btn, portal_source_btn, custom_source_btn]
-                    .spacing(SPACING)
+                make_value_button(fl!("event"), event_label, (true, true), None)
                     .height(Length::Fill),
             )
+        } else {
             // Under CUSTOM the event is named inside the URL, so there is
             // nothing to pick: the SITE row takes the EVENT row's slot and the
             // page stays at four rows either way.
-            .push(if *source == GameSource::Custom {
+            col.push(if *source == GameSource::Custom {
                 // The committed address, never the one being typed: an address
                 // that has not been applied is not the address in use, and
                 // showing it here would hide exactly that difference.
@@ -916,10 +982,11 @@ fn make_event_config_page<'a>(
                     .height(Length::Fill)
             })
             .push(auth_state_button)
-            .push(
-                make_value_button(fl!("court"), pool_label, (true, true), pool_btn_msg)
-                    .height(Length::Fill),
-            );
+        };
+        col = col.push(
+            make_value_button(fl!("court"), pool_label, (true, true), pool_btn_msg)
+                .height(Length::Fill),
+        );
     } else {
         // Portal mode OFF: 4 data rows × 3 cells each.
         col = col
@@ -1060,6 +1127,49 @@ fn make_event_config_page<'a>(
             );
     }
 
+    // The portal's timing rules have no shootout or stop-time settings, so this row is the
+    // refbox's own in both modes and a portal game takes it from here.
+    col = col.push(
+        row![
+            make_value_button(
+                fl!("penalty-shootout-allowed"),
+                bool_string(config.penalty_shootout_allowed),
+                (false, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::PenaltyShootoutAllowed,
+                )),
+            ),
+            make_value_button(
+                fl!("shootout-rounds"),
+                config.penalty_shootout_rounds.to_string(),
+                (false, true),
+                config
+                    .penalty_shootout_allowed
+                    .then_some(Message::CycleParameter(
+                        CyclingParameter::PenaltyShootoutRounds,
+                    )),
+            ),
+            make_value_button(
+                fl!("stop-time-allowed"),
+                bool_string(config.stop_time_allowed),
+                (false, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::StopTimeAllowed,
+                )),
+            ),
+            make_value_button(
+                fl!("stop-time-window"),
+                time_string(config.stop_time_window),
+                (false, true),
+                config
+                    .stop_time_allowed
+                    .then_some(Message::EditParameter(LengthParameter::StopTimeWindow)),
+            ),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+    );
+
     // Action row: Cancel | Game-number picker | Apply.
     // Apply is blocked when the portal state is incomplete, so a click on Apply
     // can't reach a wasteful "fix something and try again" dialog.
@@ -1115,6 +1225,8 @@ fn make_app_config_page<'a>(
         confirm_score,
         hide_time,
         audible_countdown,
+        scoresheet_style,
+        confirm_result,
         ..
     } = settings;
 
@@ -1199,6 +1311,24 @@ fn make_app_config_page<'a>(
                     BoolGameParameter::ShowBehindScheduleTime,
                 )),
             ),
+            make_value_button(
+                fl!("completed-scoresheet"),
+                scoresheet_style.to_string(),
+                (false, true),
+                Some(Message::CycleParameter(CyclingParameter::ScoresheetStyle)),
+            ),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+        row![
+            make_value_button(
+                fl!("captains-sign-off-result"),
+                bool_string(*confirm_result),
+                (false, true),
+                Some(Message::ToggleBoolParameter(
+                    BoolGameParameter::ConfirmResult,
+                )),
+            ),
             horizontal_space(),
         ]
         .spacing(SPACING)
@@ -1713,6 +1843,7 @@ pub(in super::super) fn build_game_parameter_editor<'a>(
         LengthParameter::OvertimeHalf => fl!("ot-half-len"),
         LengthParameter::OvertimeHalfTime => fl!("ot-half-tm-len"),
         LengthParameter::PreSuddenDeath => fl!("pre-sd-break"),
+        LengthParameter::StopTimeWindow => fl!("stop-time-window-len"),
     };
 
     // Live Game Block validation: build a staged copy of the config with the
@@ -1849,6 +1980,7 @@ pub(in super::super) fn build_game_parameter_editor<'a>(
         LengthParameter::OvertimeHalf => config.ot_half_play_duration,
         LengthParameter::OvertimeHalfTime => config.ot_half_time_duration,
         LengthParameter::PreSuddenDeath => config.pre_sudden_death_duration,
+        LengthParameter::StopTimeWindow => config.stop_time_window,
     };
     let has_changes =
         param_edit_has_changes(length, old_length, param, single_half, config.single_half);
@@ -1913,6 +2045,9 @@ pub(in super::super) fn build_parameter_help_page<'a>(
             (fl!("ot-half-tm-len"), fl!("len-of-overtime-halftime"))
         }
         LengthParameter::PreSuddenDeath => (fl!("pre-sd-break"), fl!("pre-sd-len")),
+        LengthParameter::StopTimeWindow => {
+            (fl!("stop-time-window-len"), fl!("stop-time-window-help"))
+        }
     };
     let body = body.replace('\n', " ");
 
@@ -2716,6 +2851,8 @@ mod tests {
             confirm_score: false,
             hide_time: false,
             audible_countdown: false,
+            scoresheet_style: ScoresheetStyle::Off,
+            confirm_result: false,
         };
         let edited = EditableSettings {
             hide_time: true,
@@ -2738,6 +2875,8 @@ mod tests {
             confirm_score: false,
             hide_time: false,
             audible_countdown: false,
+            scoresheet_style: ScoresheetStyle::Off,
+            confirm_result: false,
         };
         let edited = EditableSettings {
             audible_countdown: true,
@@ -2746,6 +2885,30 @@ mod tests {
         assert!(page_has_changes(ConfigPage::App, &edited, Some(&snap)));
     }
 
+    #[test]
+    fn app_detects_scoresheet_style_change() {
+        let snap = PageEntrySnapshot::App {
+            source: GameSource::Manual,
+            current_event_id: None,
+            current_court: None,
+            schedule: None,
+            mode: Mode::Hockey6V6,
+            collect_scorer_cap_num: false,
+            track_fouls_and_warnings: false,
+            show_behind_schedule_time: false,
+            confirm_score: false,
+            hide_time: false,
+            audible_countdown: false,
+            scoresheet_style: ScoresheetStyle::Off,
+            confirm_result: false,
+        };
+        let mut edited = EditableSettings::default();
+        assert!(!page_has_changes(ConfigPage::App, &edited, Some(&snap)));
+        edited.scoresheet_style.cycle();
+        assert_eq!(edited.scoresheet_style, ScoresheetStyle::Detailed);
+        assert!(page_has_changes(ConfigPage::App, &edited, Some(&snap)));
+    }
+
     // ---------------------------------------------------------------------
     // Invariant 1: per-page snapshot capture-and-revert (B3.10, B3.33)
     //
@@ -2873,6 +3036,8 @@ mod tests {
             confirm_score: edited.confirm_score,
             hide_time: false,
             audible_countdown: false,
+            scoresheet_style: ScoresheetStyle::Off,
+            confirm_result: false,
         };
 
         edited.source = GameSource::Manual;
@@ -27,6 +27,7 @@ pub(in super::super) fn build_confirmation_page<'a>(
         }
         ConfirmationKind::GameNumberChangedFromApply => fl!("apply-this-game-number-change"),
         ConfirmationKind::Error(string) => string.clone(),
+        ConfirmationKind::ResultsExported(dir) => fl!("results-exported", dir = dir.clone()),
         ConfirmationKind::UwhPortalIncompleteFromApply => {
             fl!("portal-enabled", portal = portal_name_for_mode(mode))
         }
@@ -57,6 +58,17 @@ pub(in super::super) fn build_confirmation_page<'a>(
         ConfirmationKind::SiteLockedByGame(_) => fl!("source-locked-game"),
         ConfirmationKind::SiteLockedByQueue(_) => fl!("source-locked-queue"),
         ConfirmationKind::LinkLockedByGame => fl!("link-locked-game"),
+        ConfirmationKind::ResumeGame {
+            game_number,
+            scores,
+        } => fl!(
+            "resume-saved-game",
+            game_number = game_number.clone(),
+            score_black = scores.black,
+            score_white = scores.white
+        ),
+        ConfirmationKind::StartShootout => fl!("start-shootout-confirm"),
+        ConfirmationKind::CatchUpPlan { plan, .. } => catch_up_plan_text(plan),
     };
 
     type ButtonStyleFn = fn(&Theme, Status) -> Style;
@@ -101,7 +113,7 @@ pub(in super::super) fn build_confirmation_page<'a>(
                 ConfirmationOption::EndGameAndApply,
             ),
         ],
-        ConfirmationKind::Error(_) => {
+        ConfirmationKind::Error(_) | ConfirmationKind::ResultsExported(_) => {
             vec![(fl!("ok"), green_button, ConfirmationOption::DiscardChanges)]
         }
         ConfirmationKind::UwhPortalIncompleteFromApply => vec![
@@ -164,6 +176,55 @@ pub(in super::super) fn build_confirmation_page<'a>(
                 ConfirmationOption::GoBack,
             )]
         }
+        ConfirmationKind::ResumeGame { .. } => vec![
+            (
+                fl!("resume-game"),
+                green_button,
+                ConfirmationOption::ResumeGame,
+            ),
+            (
+                fl!("start-fresh"),
+                red_button,
+                ConfirmationOption::DiscardChanges,
+            ),
+        ],
+        ConfirmationKind::StartShootout => vec![
+            (
+                fl!("start-shootout"),
+                green_button,
+                ConfirmationOption::StartShootout,
+            ),
+            (fl!("cancel"), red_button, ConfirmationOption::GoBack),
+        ],
+        ConfirmationKind::CatchUpPlan {
+            plan,
+            shorten_half_times,
+        } => {
+            let mut buttons: Vec<(_, ButtonStyleFn, _)> = vec![];
+            // Breaks shrink on their own, so a plan that only cuts breaks is just information
+            if plan.shortens_half_times() {
+                buttons.push((
+                    fl!("accept-plan"),
+                    green_button,
+                    ConfirmationOption::AcceptPlan,
+                ));
+            }
+            buttons.push(if *shorten_half_times {
+                (
+                    fl!("keep-half-times"),
+                    orange_button,
+                    ConfirmationOption::ShortenHalfTimes(false),
+                )
+            } else {
+                (
+                    fl!("shorten-half-times"),
+                    orange_button,
+                    ConfirmationOption::ShortenHalfTimes(true),
+                )
+            });
+            buttons.push((fl!("cancel"), red_button, ConfirmationOption::GoBack));
+            buttons
+        }
     };
 
     let buttons = buttons.into_iter().map(|(text, style, option)| {
@@ -210,6 +271,49 @@ pub(in super::super) fn build_confirmation_page<'a>(
     .into()
 }
 
+/// The most games listed on the catch-up page, so a plan that never catches up still fits
+const MAX_CATCH_UP_LINES: usize = 6;
+
+/// One line per game still starting late, then when the court is back on schedule
+fn catch_up_plan_text(plan: &CatchUpPlan) -> String {
+    let mut lines: Vec<String> = plan
+        .games
+        .iter()
+        .take(MAX_CATCH_UP_LINES)
+        .map(|game| {
+            let cut = time_string(game.break_cut);
+            let late = time_string(game.late_by);
+            if let Some(half_time) = game.half_time {
+                fl!(
+                    "catch-up-plan-game-half-time",
+                    game = game.number.clone(),
+                    cut = cut,
+                    late = late,
+                    half_time = time_string(half_time)
+                )
+            } else {
+                fl!(
+                    "catch-up-plan-game",
+                    game = game.number.clone(),
+                    cut = cut,
+                    late = late
+                )
+            }
+        })
+        .collect();
+    if plan.games.len() > MAX_CATCH_UP_LINES {
+        lines.push("...".to_string());
+    }
+
+    lines.push(if let Some(ref game) = plan.on_schedule_by {
+        fl!("catch-up-plan-on-time", game = game.clone())
+    } else {
+        fl!("catch-up-plan-late", late = time_string(plan.remaining))
+    });
+
+    lines.join("\n")
+}
+
 pub(in super::super) fn build_score_confirmation_page<'a>(
     data: ViewData<'_, '_>,
     scores: BlackWhiteBundle<u8>,
@@ -0,0 +1,220 @@
+use super::*;
+use collect_array::CollectArrayResult;
+use iced::{
+    Element, Length,
+    alignment::{Horizontal, Vertical},
+    widget::{Container, Space, button, column, horizontal_space, row, text},
+};
+use uwh_common::{
+    color::Color as GameColor,
+    game_snapshot::{GoalDisallowReason, GoalSnapshot},
+};
+
+pub(in super::super) fn build_disallow_goal_page<'a>(
+    data: ViewData<'_, '_>,
+    selected: Option<(GameColor, usize)>,
+    reason: GoalDisallowReason,
+    indices: BlackWhiteBundle<usize>,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        ..
+    } = data;
+
+    let mut reason_row = row![].spacing(SPACING);
+    for option in [
+        GoalDisallowReason::Foul,
+        GoalDisallowReason::Infraction,
+        GoalDisallowReason::ScorerCorrection,
+    ] {
+        reason_row = reason_row.push(
+            make_smaller_button(fl!("disallow-reason", reason = reason_fluent(option)))
+                .style(if option == reason {
+                    orange_selected_button
+                } else {
+                    orange_button
+                })
+                .width(Length::Fill)
+                .on_press(Message::ChangeDisallowReason(option)),
+        );
+    }
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            false,
+            mode,
+            clock_running,
+            portal_indicator,
+            None
+        ),
+        row![
+            make_goal_list(
+                &snapshot.goals.black,
+                indices.black,
+                GameColor::Black,
+                selected
+            ),
+            make_goal_list(
+                &snapshot.goals.white,
+                indices.white,
+                GameColor::White,
+                selected
+            ),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+        reason_row,
+        row![
+            make_button(cancel_or_back_label(selected.is_some()))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::DisallowGoalComplete { canceled: true }),
+            horizontal_space(),
+            make_button(fl!("apply"))
+                .style(green_button)
+                .width(Length::Fill)
+                .on_press_maybe(
+                    selected.map(|_| Message::DisallowGoalComplete { canceled: false })
+                ),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+/// The `disallow-reason` selector for each reason
+pub(super) fn reason_fluent(reason: GoalDisallowReason) -> &'static str {
+    match reason {
+        GoalDisallowReason::Foul => "foul",
+        GoalDisallowReason::Infraction => "infraction",
+        GoalDisallowReason::ScorerCorrection => "scorer-correction",
+    }
+}
+
+/// Team goals are recorded against player 0
+pub(super) fn goal_player_fluent(goal: &GoalSnapshot) -> String {
+    match goal.player_number {
+        0 => String::from("none"),
+        n => n.to_string(),
+    }
+}
+
+fn make_goal_list<'a>(
+    goals: &[GoalSnapshot],
+    index: usize,
+    color: GameColor,
+    selected: Option<(GameColor, usize)>,
+) -> Container<'a, Message> {
+    const GOAL_LIST_LEN: usize = 3;
+
+    let color_text = match color {
+        GameColor::Black => fl!("black-goals"),
+        GameColor::White => fl!("white-goals"),
+    };
+
+    let title = text(color_text)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center);
+
+    let buttons: CollectArrayResult<_, GOAL_LIST_LEN> = goals
+        .iter()
+        .enumerate()
+        .skip(index)
+        .map(Some)
+        .chain([None].into_iter().cycle())
+        .take(GOAL_LIST_LEN)
+        .map(|goal| {
+            if let Some((i, goal)) = goal {
+                let printable = if let Some(reason) = goal.disallowed {
+                    fl!(
+                        "disallowed-goal-entry",
+                        player_number = goal_player_fluent(goal),
+                        reason = fl!("disallow-reason", reason = reason_fluent(reason))
+                    )
+                } else {
+                    fl!(
+                        "goal-entry",
+                        player_number = goal_player_fluent(goal),
+                        period = period_text(goal.period),
+                        time = time_string(Duration::from_secs(goal.secs_in_period.into()))
+                    )
+                };
+
+                let mut text = text(printable)
+                    .align_y(Vertical::Center)
+                    .align_x(Horizontal::Left)
+                    .width(Length::Fill);
+
+                if goal.disallowed.is_some() {
+                    text = text.style(red_text);
+                } else if selected == Some((color, i)) {
+                    text = text.style(orange_text);
+                }
+
+                // A goal can only be disallowed once
+                button(text)
+                    .padding(PADDING)
+                    .height(Length::Fixed(MIN_BUTTON_SIZE))
+                    .width(Length::Fill)
+                    .style(gray_button)
+                    .on_press_maybe(
+                        goal.disallowed
+                            .is_none()
+                            .then_some(Message::SelectGoalToDisallow(color, i)),
+                    )
+                    .into()
+            } else {
+                button(Space::with_width(Length::Shrink))
+                    .height(Length::Fixed(MIN_BUTTON_SIZE))
+                    .width(Length::Fill)
+                    .style(gray_button)
+                    .into()
+            }
+        })
+        .collect();
+
+    let cont_style = match color {
+        GameColor::Black => black_container,
+        GameColor::White => white_container,
+    };
+
+    let scroll_option = match color {
+        GameColor::Black => ScrollOption::Black,
+        GameColor::White => ScrollOption::White,
+    };
+
+    make_scroll_list(
+        buttons.unwrap(),
+        goals.len(),
+        index,
+        title,
+        scroll_option,
+        cont_style,
+    )
+}
+
+/// The name of `period` as the goal lists and the timeline show it
+pub(super) fn period_text(period: GamePeriod) -> String {
+    match period {
+        GamePeriod::BetweenGames => fl!("next-game"),
+        GamePeriod::FirstHalf => fl!("first-half"),
+        GamePeriod::HalfTime => fl!("half-time"),
+        GamePeriod::SecondHalf => fl!("second-half"),
+        GamePeriod::PreOvertime => fl!("pre-ot-break-abreviated"),
+        GamePeriod::OvertimeFirstHalf => fl!("ot-first-half"),
+        GamePeriod::OvertimeHalfTime => fl!("ot-half-time"),
+        GamePeriod::OvertimeSecondHalf => fl!("ot-2nd-half"),
+        GamePeriod::PreSuddenDeath => fl!("pre-sd-break"),
+        GamePeriod::SuddenDeath => fl!("sudden-death"),
+        GamePeriod::PenaltyShootout => fl!("penalty-shootout"),
+    }
+}
@@ -18,6 +18,7 @@ pub(in super::super) fn build_foul_overview_page<'a>(
         mode,
         clock_running,
         portal_indicator,
+        rules,
         ..
     } = data;
 
@@ -44,17 +45,20 @@ pub(in super::super) fn build_foul_overview_page<'a>(
             make_foul_list(
                 warnings.black.into_iter().rev().collect(),
                 indices.black,
-                Some(GameColor::Black)
+                Some(GameColor::Black),
+                rules,
             ),
             make_foul_list(
                 warnings.equal.into_iter().rev().collect(),
                 indices.equal,
-                None
+                None,
+                rules,
             ),
             make_foul_list(
                 warnings.white.into_iter().rev().collect(),
                 indices.white,
-                Some(GameColor::White)
+                Some(GameColor::White),
+                rules,
             )
         ]
         .spacing(SPACING)
@@ -70,7 +74,7 @@ pub(in super::super) fn build_foul_overview_page<'a>(
                 .on_press(Message::KeypadPage(KeypadPage::FoulAdd {
                     origin: None,
                     color: None,
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     ret_to_overview: true,
                 })),
             make_button(fl!("apply"))
@@ -91,6 +95,7 @@ fn make_foul_list<'a>(
     fouls: Vec<PrintableInfractionSummary>,
     index: usize,
     color: Option<GameColor>,
+    rules: &RuleProfile,
 ) -> Container<'a, Message> {
     const FOUL_LIST_LEN: usize = 3;
 
@@ -123,7 +128,7 @@ fn make_foul_list<'a>(
                         .player_number
                         .map(|n| n.to_string())
                         .unwrap_or_else(|| String::from("none")),
-                    infraction = inf_short_name(details.infraction)
+                    infraction = inf_short_name(rules, details.infraction)
                 );
 
                 let mut text = text(printable)
@@ -158,7 +163,7 @@ fn make_foul_list<'a>(
                     .on_press(Message::KeypadPage(KeypadPage::FoulAdd {
                         origin: None,
                         color,
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                         ret_to_overview: true,
                     }))
                     .into()
@@ -8,6 +8,7 @@ use uwh_common::{
     bundles::BlackWhiteBundle, config::Game as GameConfig, uwhportal::schedule::Schedule,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub(in super::super) fn build_game_info_page<'a>(
     data: ViewData<'_, '_>,
     config: &GameConfig,
@@ -15,6 +16,8 @@ pub(in super::super) fn build_game_info_page<'a>(
     is_refreshing: bool,
     schedule: Option<&Schedule>,
     last_game: Option<(GameNumber, BlackWhiteBundle<u8>)>,
+    (can_undo, can_redo): (bool, bool),
+    can_export: bool,
 ) -> Element<'a, Message> {
     let ViewData {
         snapshot,
@@ -87,6 +90,32 @@ pub(in super::super) fn build_game_info_page<'a>(
             None
         ),
         table_button,
+        row![
+            make_button(fl!("undo"))
+                .style(orange_button)
+                .width(Length::Fill)
+                .on_press_maybe(can_undo.then_some(Message::Undo)),
+            make_button(fl!("redo"))
+                .style(orange_button)
+                .width(Length::Fill)
+                .on_press_maybe(can_redo.then_some(Message::Redo)),
+            make_button(fl!("timeline"))
+                .style(blue_button)
+                .width(Length::Fill)
+                .on_press(Message::ShowTimeline),
+            make_button(fl!("player-stats"))
+                .style(blue_button)
+                .width(Length::Fill)
+                .on_press_maybe(
+                    can_export.then_some(Message::ShowPlayerStats { discipline: false })
+                ),
+            make_button(fl!("export-results"))
+                .style(blue_button)
+                .width(Length::Fill)
+                .on_press_maybe(can_export.then_some(Message::ExportResults)),
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill),
         row![
             make_button(fl!("back"))
                 .style(red_button)
@@ -107,12 +107,14 @@ pub(in super::super) fn game_info_rows(
     ));
 
     // --- Settings grid (belongs to the current game) ---
-    // Six fixed rows in fixed left/right slots so the layout never reorders.
+    // Eight fixed rows in fixed left/right slots so the layout never reorders.
     // Settings that don't apply to this game's config are shown greyed, not hidden.
     let single = config.single_half;
     let no_ot = !config.overtime_allowed;
     let no_sd = !config.sudden_death_allowed;
     let no_to = config.num_team_timeouts_allowed == 0;
+    let no_so = !config.penalty_shootout_allowed;
+    let no_st = !config.stop_time_allowed;
 
     // Half Length / Game Length (left label depends on single-period) | Half-Time.
     let half_left = if single {
@@ -183,6 +185,25 @@ pub(in super::super) fn game_info_rows(
             stop_clock_value(schedule, current_game_num),
         ),
     });
+    rows.push(Row::SettingPair {
+        left: SettingCell::active(
+            fl!("gi-penalty-shootout"),
+            bool_string(config.penalty_shootout_allowed),
+        ),
+        right: SettingCell::maybe(
+            fl!("gi-shootout-rounds"),
+            config.penalty_shootout_rounds.to_string(),
+            no_so,
+        ),
+    });
+    rows.push(Row::SettingPair {
+        left: SettingCell::active(fl!("gi-stop-time"), bool_string(config.stop_time_allowed)),
+        right: SettingCell::maybe(
+            fl!("gi-stop-time-window"),
+            time_string(config.stop_time_window),
+            no_st,
+        ),
+    });
 
     // Context block BEFORE the current block, between games only: the last game that
     // actually finished. Number AND score both come from the recorded result — pairing the
@@ -716,8 +737,8 @@ mod tests {
         let snapshot = GameSnapshot::default();
         let rows = game_info_rows(&snapshot, &cfg_all_on(), false, None, None, None);
         let pairs = setting_pairs(&rows);
-        // Six fixed rows, paired exactly as in the mockup.
-        assert_eq!(pairs.len(), 6);
+        // Eight fixed rows, paired exactly as in the mockup.
+        assert_eq!(pairs.len(), 8);
         assert_eq!(
             pairs[0],
             (fl!("gi-half-length"), fl!("gi-half-time-length"))
@@ -742,6 +763,41 @@ mod tests {
                 fl!("gi-stop-clock-last-2")
             )
         );
+        assert_eq!(
+            pairs[6],
+            (fl!("gi-penalty-shootout"), fl!("gi-shootout-rounds"))
+        );
+        assert_eq!(pairs[7], (fl!("gi-stop-time"), fl!("gi-stop-time-window")));
+    }
+
+    #[test]
+    fn stop_time_off_grays_its_window() {
+        let snapshot = GameSnapshot::default();
+        let rows = game_info_rows(&snapshot, &cfg_all_on(), false, None, None, None);
+        assert_eq!(cell_grayed(&rows, &fl!("gi-stop-time-window")), Some(true));
+
+        let config = GameConfig {
+            stop_time_allowed: true,
+            ..cfg_all_on()
+        };
+        let rows = game_info_rows(&snapshot, &config, false, None, None, None);
+        assert_eq!(cell_grayed(&rows, &fl!("gi-stop-time-window")), Some(false));
+        assert_eq!(cell_grayed(&rows, &fl!("gi-stop-time")), Some(false));
+    }
+
+    #[test]
+    fn shootout_off_grays_its_rounds() {
+        let snapshot = GameSnapshot::default();
+        let rows = game_info_rows(&snapshot, &cfg_all_on(), false, None, None, None);
+        assert_eq!(cell_grayed(&rows, &fl!("gi-shootout-rounds")), Some(true));
+
+        let config = GameConfig {
+            penalty_shootout_allowed: true,
+            ..cfg_all_on()
+        };
+        let rows = game_info_rows(&snapshot, &config, false, None, None, None);
+        assert_eq!(cell_grayed(&rows, &fl!("gi-shootout-rounds")), Some(false));
+        assert_eq!(cell_grayed(&rows, &fl!("gi-penalty-shootout")), Some(false));
     }
 
     #[test]
@@ -807,7 +863,7 @@ mod tests {
     }
 
     #[test]
-    fn settings_keep_six_fixed_rows_when_mostly_off() {
+    fn settings_keep_fixed_rows_when_mostly_off() {
         // The user's reported bug: with OT/SD off and 0 timeouts, rows must NOT
         // reflow into wrong pairs — every slot stays fixed (just greyed).
         let snapshot = GameSnapshot::default();
@@ -820,7 +876,7 @@ mod tests {
         };
         let rows = game_info_rows(&snapshot, &config, false, None, None, None);
         let pairs = setting_pairs(&rows);
-        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs.len(), 8);
         assert!(pairs.contains(&(fl!("gi-overtime"), fl!("gi-sudden-death"))));
         assert!(pairs.contains(&(fl!("gi-timeouts"), fl!("gi-timeout-duration"))));
     }
@@ -0,0 +1,131 @@
+use super::*;
+use iced::{
+    Element, Length, Theme,
+    widget::{
+        button::{Status, Style},
+        column, horizontal_space, row, text_input, vertical_space,
+    },
+};
+use uwh_common::color::Color as GameColor;
+
+type StyleFn = fn(&Theme, Status) -> Style;
+
+/// File an incident report against the game in progress: what kind of incident it was, the team
+/// and player involved if any, and the referee's own account of it. The report is timed from when
+/// this page was opened.
+pub(in super::super) fn build_incident_report_page<'a>(
+    data: ViewData<'_, '_>,
+    incident: &IncidentDetails,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        ..
+    } = data;
+
+    let mut category_row = row![].spacing(SPACING);
+    for category in IncidentCategory::ALL {
+        category_row = category_row.push(
+            make_smaller_button(fl!(
+                "incident-category",
+                category = category_fluent(category)
+            ))
+            .style(if category == incident.category {
+                orange_selected_button
+            } else {
+                orange_button
+            })
+            .width(Length::Fill)
+            .on_press(Message::ChangeIncidentCategory(category)),
+        );
+    }
+
+    let (black_style, white_style, neither_style): (StyleFn, StyleFn, StyleFn) =
+        match incident.color {
+            Some(GameColor::Black) => (black_selected_button, white_button, blue_button),
+            Some(GameColor::White) => (black_button, white_selected_button, blue_button),
+            None => (black_button, white_button, blue_selected_button),
+        };
+
+    let player = make_value_button(
+        fl!("incident-player"),
+        incident
+            .player_number
+            .map_or_else(|| fl!("none"), |number| number.to_string()),
+        (false, true),
+        incident
+            .color
+            .map(|color| Message::KeypadPage(KeypadPage::IncidentPlayer(color))),
+    )
+    .style(light_gray_button);
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            false,
+            mode,
+            clock_running,
+            portal_indicator,
+            None
+        ),
+        centered_text(fl!(
+            "incident-time",
+            period = period_text(incident.start_period),
+            time = time_string(incident.start_time)
+        )),
+        category_row,
+        row![
+            make_button(fl!("dark-team-name-caps"))
+                .style(black_style)
+                .width(Length::Fill)
+                .on_press(Message::ChangeIncidentTeam(Some(GameColor::Black))),
+            make_button(fl!("no-team"))
+                .style(neither_style)
+                .width(Length::Fill)
+                .on_press(Message::ChangeIncidentTeam(None)),
+            make_button(fl!("light-team-name-caps"))
+                .style(white_style)
+                .width(Length::Fill)
+                .on_press(Message::ChangeIncidentTeam(Some(GameColor::White))),
+            player,
+        ]
+        .spacing(SPACING),
+        text_input(&fl!("incident-description"), &incident.description)
+            .on_input(Message::IncidentDescriptionChanged)
+            .padding(PADDING)
+            .size(MEDIUM_TEXT)
+            .width(Length::Fill),
+        vertical_space(),
+        row![
+            make_button(fl!("cancel"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::IncidentReportComplete { canceled: true }),
+            horizontal_space(),
+            make_button(fl!("done"))
+                .style(green_button)
+                .width(Length::Fill)
+                .on_press_maybe(
+                    (!incident.description.trim().is_empty())
+                        .then_some(Message::IncidentReportComplete { canceled: false })
+                ),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+/// The `incident-category` selector for each category
+fn category_fluent(category: IncidentCategory) -> &'static str {
+    match category {
+        IncidentCategory::Injury => "injury",
+        IncidentCategory::EquipmentFailure => "equipment-failure",
+        IncidentCategory::Misconduct => "misconduct",
+        IncidentCategory::Other => "other",
+    }
+}
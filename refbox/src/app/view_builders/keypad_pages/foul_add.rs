@@ -18,6 +18,7 @@ pub(super) fn make_foul_add_page<'a>(
     foul: Infraction,
     ret_to_overview: bool,
     player_num: u32,
+    rules: &RuleProfile,
 ) -> Element<'a, Message> {
     let (black_style, white_style, equal_style): (StyleFn, StyleFn, StyleFn) = match color {
         Some(GameColor::Black) => (black_selected_button, white_button, blue_button),
@@ -79,7 +80,7 @@ pub(super) fn make_foul_add_page<'a>(
         ]
         .spacing(SPACING),
         Space::with_height(SPACING),
-        make_penalty_dropdown(foul, true),
+        make_penalty_dropdown(rules, foul, true),
         vertical_space(),
         exit_row,
     ]
@@ -91,7 +92,7 @@ pub(super) fn make_foul_add_page<'a>(
 /// also needs a player number. An "equal" foul (`color == None`) has no player,
 /// so it needs only the infraction.
 fn foul_add_can_commit(infraction: Infraction, color: Option<GameColor>, player_num: u32) -> bool {
-    !matches!(infraction, Infraction::Unknown) && (color.is_none() || player_num > 0)
+    !matches!(infraction, Infraction::UNKNOWN) && (color.is_none() || player_num > 0)
 }
 
 #[cfg(test)]
@@ -101,23 +102,23 @@ mod tests {
     #[test]
     fn foul_needs_infraction() {
         // Equal foul, infraction unset → blocked.
-        assert!(!foul_add_can_commit(Infraction::Unknown, None, 0));
+        assert!(!foul_add_can_commit(Infraction::UNKNOWN, None, 0));
     }
 
     #[test]
     fn foul_equal_with_infraction_ok_without_number() {
-        assert!(foul_add_can_commit(Infraction::StickInfringement, None, 0));
+        assert!(foul_add_can_commit(Infraction::STICK_INFRINGEMENT, None, 0));
     }
 
     #[test]
     fn foul_individual_needs_number() {
         assert!(!foul_add_can_commit(
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
             Some(GameColor::Black),
             0
         ));
         assert!(foul_add_can_commit(
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
             Some(GameColor::Black),
             5
         ));
@@ -126,7 +127,7 @@ mod tests {
     #[test]
     fn foul_individual_with_number_still_needs_infraction() {
         assert!(!foul_add_can_commit(
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
             Some(GameColor::Black),
             5
         ));
@@ -41,6 +41,15 @@ use player_grid::*;
 mod portal_login;
 use portal_login::*;
 
+mod shootout_attempt;
+use shootout_attempt::*;
+
+mod result_sign_off;
+use result_sign_off::*;
+
+mod incident_player;
+use incident_player::*;
+
 pub(in super::super) fn build_keypad_page<'a>(
     data: ViewData<'_, '_>,
     page: KeypadPage,
@@ -55,6 +64,7 @@ pub(in super::super) fn build_keypad_page<'a>(
         source,
         clock_running,
         portal_indicator,
+        rules,
         ..
     } = data;
 
@@ -150,6 +160,7 @@ pub(in super::super) fn build_keypad_page<'a>(
                         track_fouls_and_warnings,
                         foul,
                         player_num,
+                        rules,
                     )
                 }
                 KeypadPage::GameNumber =>
@@ -162,7 +173,14 @@ pub(in super::super) fn build_keypad_page<'a>(
                     color,
                     infraction,
                     ret_to_overview,
-                } => make_foul_add_page(origin, color, infraction, ret_to_overview, player_num),
+                } => make_foul_add_page(
+                    origin,
+                    color,
+                    infraction,
+                    ret_to_overview,
+                    player_num,
+                    rules,
+                ),
                 KeypadPage::WarningAdd {
                     origin,
                     color,
@@ -176,10 +194,15 @@ pub(in super::super) fn build_keypad_page<'a>(
                     team_warning,
                     ret_to_overview,
                     player_num,
+                    rules,
                 ),
                 KeypadPage::PortalLogin(id, requested) => {
                     make_portal_login_page(id, requested, mode, source)
                 }
+                KeypadPage::ShootoutAttempt { color } =>
+                    make_shootout_attempt_page(color, snapshot.shootout.as_ref(), player_num),
+                KeypadPage::ResultSignOff(color) => make_result_sign_off_page(color),
+                KeypadPage::IncidentPlayer(color) => make_incident_player_page(color),
             }
         ]
         .spacing(SPACING)
@@ -205,7 +228,8 @@ enum PanelRole {
     /// Asks which player, but this entry belongs to no player — an "equal"
     /// foul, or a team warning. The panel is greyed out.
     TeamEntry,
-    /// Not about a player at all: game number, timeouts per half, portal login.
+    /// Not about a player at all: game number, timeouts per half, portal login,
+    /// a captain's sign-off.
     /// Free digit entry, always enabled, panel sized to its contents.
     NotPlayer,
 }
@@ -260,7 +284,9 @@ fn panel_role(page: &KeypadPage) -> PanelRole {
                 PanelRole::Player(*color)
             }
         }
-        KeypadPage::Penalty(_, color, _, _) => PanelRole::Player(*color),
+        KeypadPage::Penalty(_, color, _, _)
+        | KeypadPage::ShootoutAttempt { color }
+        | KeypadPage::IncidentPlayer(color) => PanelRole::Player(*color),
         KeypadPage::FoulAdd { color, .. } => match color {
             Some(color) => PanelRole::Player(*color),
             None => PanelRole::TeamEntry,
@@ -276,9 +302,10 @@ fn panel_role(page: &KeypadPage) -> PanelRole {
                 PanelRole::Player(*color)
             }
         }
-        KeypadPage::GameNumber | KeypadPage::TeamTimeouts(_, _) | KeypadPage::PortalLogin(_, _) => {
-            PanelRole::NotPlayer
-        }
+        KeypadPage::GameNumber
+        | KeypadPage::TeamTimeouts(_, _)
+        | KeypadPage::PortalLogin(_, _)
+        | KeypadPage::ResultSignOff(_) => PanelRole::NotPlayer,
     }
 }
 
@@ -454,8 +481,8 @@ mod tests {
                 KeypadPage::Penalty(
                     None,
                     GameColor::White,
-                    PenaltyKind::OneMinute,
-                    Infraction::Unknown,
+                    PenaltyKind::ONE_MINUTE,
+                    Infraction::UNKNOWN,
                 ),
                 PanelRole::Player(GameColor::White),
             ),
@@ -464,7 +491,7 @@ mod tests {
                 KeypadPage::FoulAdd {
                     origin: None,
                     color: Some(GameColor::Black),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     ret_to_overview: false,
                 },
                 PanelRole::Player(GameColor::Black),
@@ -473,7 +500,7 @@ mod tests {
                 KeypadPage::FoulAdd {
                     origin: None,
                     color: None,
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     ret_to_overview: false,
                 },
                 PanelRole::TeamEntry,
@@ -484,7 +511,7 @@ mod tests {
                 KeypadPage::WarningAdd {
                     origin: None,
                     color: GameColor::White,
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     team_warning: false,
                     ret_to_overview: false,
                 },
@@ -494,7 +521,7 @@ mod tests {
                 KeypadPage::WarningAdd {
                     origin: None,
                     color: GameColor::White,
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     team_warning: true,
                     ret_to_overview: false,
                 },
@@ -506,6 +533,20 @@ mod tests {
                 PanelRole::NotPlayer,
             ),
             (KeypadPage::PortalLogin(0, false), PanelRole::NotPlayer),
+            (
+                KeypadPage::ShootoutAttempt {
+                    color: GameColor::White,
+                },
+                PanelRole::Player(GameColor::White),
+            ),
+            (
+                KeypadPage::ResultSignOff(GameColor::Black),
+                PanelRole::NotPlayer,
+            ),
+            (
+                KeypadPage::IncidentPlayer(GameColor::White),
+                PanelRole::Player(GameColor::White),
+            ),
         ];
 
         for (page, expected) in cases {
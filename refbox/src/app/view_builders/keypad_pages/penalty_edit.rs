@@ -1,4 +1,5 @@
 use super::*;
+use crate::rule_profile::MAX_PENALTY_LENGTHS;
 use iced::{
     Length, Theme,
     widget::{
@@ -10,6 +11,7 @@ use uwh_common::color::Color as GameColor;
 
 type StyleFn = fn(&Theme, Status) -> Style;
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn make_penalty_edit_page<'a>(
     origin: Option<(GameColor, usize)>,
     color: GameColor,
@@ -18,67 +20,25 @@ pub(super) fn make_penalty_edit_page<'a>(
     track_fouls_and_warnings: bool,
     infraction: Infraction,
     player_num: u32,
+    rules: &RuleProfile,
 ) -> Element<'a, Message> {
     let (black_style, white_style): (StyleFn, StyleFn) = match color {
         GameColor::Black => (black_selected_button, white_button),
         GameColor::White => (black_button, white_selected_button),
     };
 
-    let (green, yellow, orange) = match mode {
-        Mode::Hockey6V6 => (
-            PenaltyKind::OneMinute,
-            PenaltyKind::TwoMinute,
-            PenaltyKind::FiveMinute,
-        ),
-
-        Mode::Hockey3V3 => (
-            PenaltyKind::ThirtySecond,
-            PenaltyKind::OneMinute,
-            PenaltyKind::TwoMinute,
-        ),
-
-        Mode::Rugby => (
-            PenaltyKind::TwoMinute,
-            PenaltyKind::FourMinute,
-            PenaltyKind::FiveMinute,
-        ),
-
-        Mode::BeepTest => unreachable!("BeepTest mode does not edit penalties"),
+    // From least to most severe, so a profile with fewer lengths uses the milder colours
+    let timed_styles: [(StyleFn, StyleFn); MAX_PENALTY_LENGTHS] = [
+        (green_button, green_selected_button),
+        (yellow_button, yellow_selected_button),
+        (orange_button, orange_selected_button),
+    ];
+    let td_style: StyleFn = if kind == PenaltyKind::TotalDismissal {
+        red_selected_button
+    } else {
+        red_button
     };
 
-    let (green_style, yellow_style, orange_style, td_style): (StyleFn, StyleFn, StyleFn, StyleFn) =
-        if kind == green {
-            (
-                green_selected_button,
-                yellow_button,
-                orange_button,
-                red_button,
-            )
-        } else if kind == yellow {
-            (
-                green_button,
-                yellow_selected_button,
-                orange_button,
-                red_button,
-            )
-        } else if kind == orange {
-            (
-                green_button,
-                yellow_button,
-                orange_selected_button,
-                red_button,
-            )
-        } else if kind == PenaltyKind::TotalDismissal {
-            (
-                green_button,
-                yellow_button,
-                orange_button,
-                red_selected_button,
-            )
-        } else {
-            (green_button, yellow_button, orange_button, red_button)
-        };
-
     let mut exit_row = row![
         make_smaller_button(fl!("cancel"))
             .style(red_button)
@@ -115,10 +75,6 @@ pub(super) fn make_penalty_edit_page<'a>(
             ),
     );
 
-    let green_label = fl!("penalty-kind", kind = green.fluent());
-    let yellow_label = fl!("penalty-kind", kind = yellow.fluent());
-    let orange_label = fl!("penalty-kind", kind = orange.fluent());
-
     let mut content = column![
         row![
             make_smaller_button(fl!("dark-team-name-caps"))
@@ -134,28 +90,31 @@ pub(super) fn make_penalty_edit_page<'a>(
     content = content.push(vertical_space());
 
     if track_fouls_and_warnings {
-        content = content.push(make_penalty_dropdown(infraction, false));
+        content = content.push(make_penalty_dropdown(rules, infraction, false));
     }
 
     content = content.push(vertical_space());
 
-    content = content.push(
-        row![
-            make_smaller_button(green_label)
-                .style(green_style)
-                .on_press(Message::ChangeKind(green)),
-            make_smaller_button(yellow_label)
-                .style(yellow_style)
-                .on_press(Message::ChangeKind(yellow)),
-            make_smaller_button(orange_label)
-                .style(orange_style)
-                .on_press(Message::ChangeKind(orange)),
-            make_smaller_button(fl!("total-dismissal"))
-                .style(td_style)
-                .on_press(Message::ChangeKind(PenaltyKind::TotalDismissal)),
-        ]
-        .spacing(SPACING),
+    let mut kind_row = row![].spacing(SPACING);
+    for (timed_kind, (style, selected_style)) in
+        rules.penalties.kinds(mode).into_iter().zip(timed_styles)
+    {
+        kind_row = kind_row.push(
+            make_smaller_button(fl!("penalty-kind", kind = timed_kind.fluent()))
+                .style(if kind == timed_kind {
+                    selected_style
+                } else {
+                    style
+                })
+                .on_press(Message::ChangeKind(timed_kind)),
+        );
+    }
+    kind_row = kind_row.push(
+        make_smaller_button(fl!("total-dismissal"))
+            .style(td_style)
+            .on_press(Message::ChangeKind(PenaltyKind::TotalDismissal)),
     );
+    content = content.push(kind_row);
 
     content = content.push(vertical_space());
 
@@ -175,7 +134,7 @@ pub(super) fn make_penalty_edit_page<'a>(
 /// so those pages do still require one (`foul_add_can_commit`,
 /// `warning_add_can_commit`). An infraction-less penalty is not a new state
 /// either: it is what this page has always produced with tracking off, and
-/// `Infraction::Unknown` is the enum's default, handled throughout.
+/// `Infraction::UNKNOWN` is the default, handled throughout.
 ///
 /// The team, infraction, and tracking flag stay in the signature — the whole of
 /// the page's state that could plausibly gate saving — even though the rule
@@ -194,7 +153,6 @@ fn penalty_edit_can_commit(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use enum_iterator::all;
 
     #[test]
     fn penalty_gate_depends_only_on_the_player_number() {
@@ -203,7 +161,7 @@ mod tests {
         // fouls-and-warnings toggle: a player number is sufficient on its own,
         // and its absence is the only thing that blocks saving.
         for color in [GameColor::Black, GameColor::White] {
-            for infraction in all::<Infraction>() {
+            for infraction in RuleProfile::default().infraction_choices() {
                 for tracking in [false, true] {
                     assert!(
                         penalty_edit_can_commit(color, infraction, tracking, 5),
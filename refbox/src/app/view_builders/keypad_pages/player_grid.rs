@@ -113,6 +113,7 @@ pub(super) fn show_grid(numbers: &[u8], mode: Mode, current: u32) -> bool {
 }
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
     use crate::app::theme::{BORDER_WIDTH, DisplayMode, black, blue, set_display_mode, white};
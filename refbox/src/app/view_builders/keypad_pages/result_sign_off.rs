@@ -0,0 +1,31 @@
+use super::*;
+use iced::{
+    Length,
+    widget::{column, row, text, vertical_space},
+};
+
+pub(super) fn make_result_sign_off_page<'a>(color: GameColor) -> Element<'a, Message> {
+    let team = match color {
+        GameColor::Black => fl!("dark-team-name-caps"),
+        GameColor::White => fl!("light-team-name-caps"),
+    };
+
+    column![
+        text(team).size(MEDIUM_TEXT),
+        text(fl!("sign-off-entry-hint")).size(SMALL_PLUS_TEXT),
+        vertical_space(),
+        row![
+            make_button(fl!("cancel"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::SignOffEntryComplete { canceled: true }),
+            make_button(fl!("done"))
+                .style(green_button)
+                .width(Length::Fill)
+                .on_press(Message::SignOffEntryComplete { canceled: false }),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .into()
+}
@@ -0,0 +1,67 @@
+use super::*;
+use iced::{
+    Length, Theme,
+    widget::{
+        button::{Status, Style},
+        column, row, text, vertical_space,
+    },
+};
+use uwh_common::{color::Color as GameColor, game_snapshot::ShootoutSnapshot};
+
+type StyleFn = fn(&Theme, Status) -> Style;
+
+pub(super) fn make_shootout_attempt_page<'a>(
+    color: GameColor,
+    shootout: Option<&ShootoutSnapshot>,
+    player_num: u32,
+) -> Element<'a, Message> {
+    let (black_style, white_style): (StyleFn, StyleFn) = match color {
+        GameColor::Black => (black_selected_button, white_button),
+        GameColor::White => (black_button, white_selected_button),
+    };
+
+    // Once the first attempt is taken the teams alternate, so the team is fixed
+    let can_change_color = shootout.is_none_or(|s| s.next_shooter.is_none());
+    let round = shootout.map_or(0, |s| s.attempts[color].len()) + 1;
+
+    let mut black_btn = make_button(fl!("dark-team-name-caps")).style(black_style);
+    let mut white_btn = make_button(fl!("light-team-name-caps")).style(white_style);
+    if can_change_color {
+        black_btn = black_btn.on_press(Message::ChangeColor(Some(GameColor::Black)));
+        white_btn = white_btn.on_press(Message::ChangeColor(Some(GameColor::White)));
+    }
+
+    let can_commit = player_num > 0;
+
+    column![
+        row![black_btn, white_btn].spacing(SPACING),
+        text(fl!("shootout-round", round = round)).size(MEDIUM_TEXT),
+        vertical_space(),
+        row![
+            make_button(fl!("cancel"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::ShootoutAttemptComplete {
+                    canceled: true,
+                    scored: false,
+                }),
+            make_button(fl!("shot-missed"))
+                .style(orange_button)
+                .width(Length::Fill)
+                .on_press_maybe(can_commit.then_some(Message::ShootoutAttemptComplete {
+                    canceled: false,
+                    scored: false,
+                })),
+            make_button(fl!("shot-scored"))
+                .style(green_button)
+                .width(Length::Fill)
+                .on_press_maybe(can_commit.then_some(Message::ShootoutAttemptComplete {
+                    canceled: false,
+                    scored: true,
+                })),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .into()
+}
@@ -18,6 +18,7 @@ pub(super) fn make_warning_add_page<'a>(
     team_warning: bool,
     ret_to_overview: bool,
     player_num: u32,
+    rules: &RuleProfile,
 ) -> Element<'a, Message> {
     let (black_style, white_style): (StyleFn, StyleFn) = match color {
         GameColor::Black => (black_selected_button, white_button),
@@ -85,7 +86,7 @@ pub(super) fn make_warning_add_page<'a>(
         ]
         .spacing(SPACING),
         Space::with_height(SPACING),
-        make_penalty_dropdown(foul, true),
+        make_penalty_dropdown(rules, foul, true),
         vertical_space(),
         exit_row,
     ]
@@ -96,7 +97,7 @@ pub(super) fn make_warning_add_page<'a>(
 /// be selected, and an individual warning also needs a player number. A team
 /// warning (`team_warning == true`) has no player, so it needs only the infraction.
 fn warning_add_can_commit(infraction: Infraction, team_warning: bool, player_num: u32) -> bool {
-    !matches!(infraction, Infraction::Unknown) && (team_warning || player_num > 0)
+    !matches!(infraction, Infraction::UNKNOWN) && (team_warning || player_num > 0)
 }
 
 #[cfg(test)]
@@ -105,13 +106,13 @@ mod tests {
 
     #[test]
     fn warning_needs_infraction() {
-        assert!(!warning_add_can_commit(Infraction::Unknown, true, 0));
+        assert!(!warning_add_can_commit(Infraction::UNKNOWN, true, 0));
     }
 
     #[test]
     fn warning_team_with_infraction_ok_without_number() {
         assert!(warning_add_can_commit(
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
             true,
             0
         ));
@@ -120,12 +121,12 @@ mod tests {
     #[test]
     fn warning_individual_needs_number() {
         assert!(!warning_add_can_commit(
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
             false,
             0
         ));
         assert!(warning_add_can_commit(
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
             false,
             7
         ));
@@ -133,6 +134,6 @@ mod tests {
 
     #[test]
     fn warning_individual_with_number_still_needs_infraction() {
-        assert!(!warning_add_can_commit(Infraction::Unknown, false, 7));
+        assert!(!warning_add_can_commit(Infraction::UNKNOWN, false, 7));
     }
 }
@@ -10,7 +10,7 @@ use uwh_common::{
     bundles::BlackWhiteBundle,
     color::Color as GameColor,
     config::Game as GameConfig,
-    game_snapshot::{GamePeriod, GameSnapshot, PenaltyTime, TimeoutSnapshot},
+    game_snapshot::{GamePeriod, GameSnapshot, PenaltyTime, ShootoutSnapshot, TimeoutSnapshot},
     uwhportal::schedule::Schedule,
 };
 
@@ -26,6 +26,8 @@ pub(in super::super) fn build_main_view<'a>(
     alarm_held: bool,
     behind_schedule: std::time::Duration,
     last_game: Option<(GameNumber, BlackWhiteBundle<u8>)>,
+    suspended: Vec<(GameColor, u8, u16)>,
+    can_catch_up: bool,
 ) -> Element<'a, Message> {
     let ViewData {
         snapshot,
@@ -33,6 +35,7 @@ pub(in super::super) fn build_main_view<'a>(
         clock_running,
         teams,
         portal_indicator,
+        rules,
         ..
     } = data;
 
@@ -63,7 +66,7 @@ pub(in super::super) fn build_main_view<'a>(
             .on_press(Message::KeypadPage(KeypadPage::WarningAdd {
                 origin: None,
                 color: GameColor::Black,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
                 team_warning: false,
                 ret_to_overview: false,
             }))
@@ -76,7 +79,7 @@ pub(in super::super) fn build_main_view<'a>(
             .on_press(Message::KeypadPage(KeypadPage::FoulAdd {
                 origin: None,
                 color: None,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
                 ret_to_overview: false,
             }))
     };
@@ -131,7 +134,20 @@ pub(in super::super) fn build_main_view<'a>(
                     start_warning_row = start_warning_row.push(make_warn_button())
                 }
 
-                center_col = center_col.push(start_warning_row)
+                center_col = center_col.push(start_warning_row);
+
+                if !suspended.is_empty() {
+                    center_col = center_col.push(make_suspension_warning(&suspended));
+                }
+
+                if can_catch_up && snapshot.current_period == GamePeriod::BetweenGames {
+                    center_col = center_col.push(
+                        make_button(fl!("catch-up"))
+                            .style(orange_button)
+                            .width(Length::Fill)
+                            .on_press(Message::PlanCatchUp),
+                    );
+                }
             }
             GamePeriod::FirstHalf
             | GamePeriod::SecondHalf
@@ -142,6 +158,45 @@ pub(in super::super) fn build_main_view<'a>(
                     center_col = center_col
                         .push(row![make_foul_button(), make_warn_button()].spacing(SPACING))
                 }
+                // Recorded whistles stop the clock on their own, the rest need this button
+                if game_config.in_stop_time_window(
+                    snapshot.current_period,
+                    std::time::Duration::from_secs(snapshot.secs_in_period.into()),
+                ) {
+                    center_col = center_col.push(if clock_running {
+                        make_button(fl!("stop-clock"))
+                            .style(red_button)
+                            .width(Length::Fill)
+                            .on_press(Message::StopClock)
+                    } else {
+                        make_button(fl!("restart-clock"))
+                            .style(green_button)
+                            .width(Length::Fill)
+                            .on_press(Message::StartClock)
+                    });
+                }
+                if snapshot.current_period == GamePeriod::SuddenDeath
+                    && game_config.penalty_shootout_allowed
+                {
+                    center_col = center_col.push(
+                        make_button(fl!("start-shootout"))
+                            .style(orange_button)
+                            .width(Length::Fill)
+                            .on_press(Message::StartShootout),
+                    );
+                }
+            }
+            GamePeriod::PenaltyShootout => {
+                let shootout = snapshot.shootout.as_ref();
+                center_col = center_col.push(make_shootout_panel(shootout));
+                let has_attempts = shootout
+                    .is_some_and(|s| s.attempts.iter().any(|(_, attempts)| !attempts.is_empty()));
+                center_col = center_col.push(
+                    make_button(fl!("remove-last-attempt"))
+                        .style(orange_button)
+                        .width(Length::Fill)
+                        .on_press_maybe(has_attempts.then_some(Message::RemoveLastShootoutAttempt)),
+                );
             }
         };
     }
@@ -225,7 +280,10 @@ pub(in super::super) fn build_main_view<'a>(
                             .iter()
                             .rev()
                             .take(10)
-                            .map(|warning| make_warning_container(warning, Some(color)).into())
+                            .map(
+                                |warning| make_warning_container(warning, Some(color), rules)
+                                    .into()
+                            )
                     )
                     .spacing(1)
                     .width(Length::Fill)
@@ -329,7 +387,10 @@ pub(in super::super) fn build_main_view<'a>(
                                 .iter()
                                 .rev()
                                 .take(10)
-                                .map(|warning| make_warning_container(warning, Some(color)).into())
+                                .map(
+                                    |warning| make_warning_container(warning, Some(color), rules)
+                                        .into()
+                                )
                         )
                         .spacing(1)
                         .width(Length::Fill)
@@ -437,7 +498,27 @@ pub(in super::super) fn build_main_view<'a>(
         make_multi_label_button((fl!("light-score-line-1"), fl!("light-score-line-2")))
             .style(white_button);
 
-    if snapshot.current_period != GamePeriod::BetweenGames {
+    if snapshot.current_period == GamePeriod::PenaltyShootout {
+        // The score buttons record shootout attempts instead, and only for the team due to shoot
+        let may_shoot = |color| {
+            snapshot.shootout.as_ref().is_none_or(|s| {
+                s.winner.is_none() && s.next_shooter.is_none_or(|next| next == color)
+            })
+        };
+        let attempt_msg = |color| {
+            may_shoot(color).then_some(Message::KeypadPage(KeypadPage::ShootoutAttempt { color }))
+        };
+        black_score_btn = black_score_btn.on_press(Message::EditScores);
+        black_new_score_btn =
+            make_multi_label_button((fl!("shootout-line-1"), fl!("shootout-line-2")))
+                .style(black_button)
+                .on_press_maybe(attempt_msg(GameColor::Black));
+        white_score_btn = white_score_btn.on_press(Message::EditScores);
+        white_new_score_btn =
+            make_multi_label_button((fl!("shootout-line-1"), fl!("shootout-line-2")))
+                .style(white_button)
+                .on_press_maybe(attempt_msg(GameColor::White));
+    } else if snapshot.current_period != GamePeriod::BetweenGames {
         black_score_btn = black_score_btn.on_press(Message::EditScores);
         black_new_score_btn = black_new_score_btn.on_press(Message::AddNewScore(GameColor::Black));
         white_score_btn = white_score_btn.on_press(Message::EditScores);
@@ -487,3 +568,82 @@ pub(in super::super) fn build_main_view<'a>(
     .height(Length::Fill)
     .into()
 }
+
+/// Warns that players due to sit out the upcoming game for a total dismissal are on its teams
+fn make_suspension_warning<'a>(suspended: &[(GameColor, u8, u16)]) -> Element<'a, Message> {
+    let lines = suspended.iter().map(|(color, number, games)| {
+        let team = match color {
+            GameColor::Black => fl!("dark-team-name-caps"),
+            GameColor::White => fl!("light-team-name-caps"),
+        };
+        text(fl!(
+            "suspended-player",
+            team = team,
+            number = number,
+            games = games
+        ))
+        .size(SMALL_TEXT)
+        .into()
+    });
+
+    container(
+        column![
+            text(fl!("suspended-players"))
+                .size(SMALL_PLUS_TEXT)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill),
+            column(lines),
+        ]
+        .spacing(SPACING),
+    )
+    .padding(PADDING)
+    .style(red_container)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Each team's shootout attempts in order, newest at the bottom
+fn make_shootout_panel<'a>(shootout: Option<&ShootoutSnapshot>) -> Element<'a, Message> {
+    let attempt_lines = |color: GameColor| {
+        let lines = shootout.map_or(Vec::new(), |s| {
+            s.attempts[color]
+                .iter()
+                .enumerate()
+                .map(|(i, attempt)| {
+                    let result = if attempt.scored {
+                        fl!("shootout-goal")
+                    } else {
+                        fl!("shootout-miss")
+                    };
+                    format!("{}. #{} {result}", i + 1, attempt.player_number)
+                })
+                .collect()
+        });
+        column(
+            lines
+                .into_iter()
+                .map(|line| text(line).size(SMALL_TEXT).into()),
+        )
+        .width(Length::Fill)
+    };
+
+    container(
+        column![
+            text(fl!("penalty-shootout"))
+                .size(SMALL_PLUS_TEXT)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill),
+            row![
+                attempt_lines(GameColor::Black),
+                attempt_lines(GameColor::White)
+            ]
+            .spacing(SPACING),
+        ]
+        .spacing(SPACING),
+    )
+    .padding(PADDING)
+    .style(light_gray_container)
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
@@ -19,6 +19,9 @@ pub(super) use game_info::*;
 
 pub mod game_info_table;
 
+pub mod disallow_goal;
+pub(super) use disallow_goal::*;
+
 pub mod warnings_fouls_summary;
 pub(super) use warnings_fouls_summary::*;
 
@@ -37,9 +40,18 @@ pub(super) use portal_attention_action::*;
 pub mod power;
 pub(super) use power::*;
 
+pub mod result_sign_off;
+pub(super) use result_sign_off::*;
+
+pub mod incident_report;
+pub(super) use incident_report::*;
+
 pub mod portal_detail;
 pub(super) use portal_detail::*;
 
+pub mod player_stats;
+pub(super) use player_stats::*;
+
 pub mod warnings;
 pub(super) use warnings::*;
 
@@ -56,3 +68,9 @@ pub(super) use shared_elements::*;
 
 pub mod time_edit;
 pub(super) use time_edit::*;
+
+pub mod timeline;
+pub(super) use timeline::*;
+
+pub mod audit_trail;
+pub(super) use audit_trail::*;
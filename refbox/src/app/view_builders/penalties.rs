@@ -18,15 +18,14 @@ pub(in super::super) fn build_penalty_overview_page<'a>(
         mode,
         clock_running,
         portal_indicator,
+        rules,
         ..
     } = data;
 
-    let default_pen_len = match mode {
-        Mode::Hockey3V3 => PenaltyKind::ThirtySecond,
-        Mode::Hockey6V6 => PenaltyKind::OneMinute,
-        Mode::Rugby => PenaltyKind::TwoMinute,
-        Mode::BeepTest => unreachable!("BeepTest mode does not edit penalties"),
-    };
+    if mode == Mode::BeepTest {
+        unreachable!("BeepTest mode does not edit penalties");
+    }
+    let default_pen_len = rules.penalties.default_kind(mode);
 
     let has_changes = any_pending_change(
         penalties
@@ -74,7 +73,7 @@ pub(in super::super) fn build_penalty_overview_page<'a>(
                     None,
                     GameColor::Black,
                     default_pen_len,
-                    Infraction::Unknown,
+                    Infraction::UNKNOWN,
                 ))),
             make_button(fl!("apply"))
                 .style(green_button)
@@ -159,7 +158,7 @@ fn make_penalty_list<'a>(
                         None,
                         color,
                         default_pen_len,
-                        Infraction::Unknown,
+                        Infraction::UNKNOWN,
                     )))
                     .into()
             }
@@ -0,0 +1,166 @@
+use super::*;
+use collect_array::CollectArrayResult;
+use iced::{
+    Element, Length,
+    alignment::{Horizontal, Vertical},
+    widget::{column, container, row, text},
+};
+use uwh_common::uwhportal::player_stats::{PlayerStats, PlayerTotals};
+
+/// Rows visible at once before the scroll arrows become active, as on the portal detail page
+const PLAYER_STATS_LIST_LEN: usize = 4;
+
+/// Render the player statistics page: the event's top scorers, or its discipline table, with
+/// BACK, a button to switch tables and EXPORT along the bottom. `stats` is `None` when no event
+/// has been played yet.
+pub(in super::super) fn build_player_stats_page<'a>(
+    data: ViewData<'_, '_>,
+    stats: Option<&PlayerStats>,
+    discipline: bool,
+    scroll_index: usize,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        ..
+    } = data;
+
+    let title = text(if discipline {
+        fl!("discipline")
+    } else {
+        fl!("top-scorers")
+    })
+    .height(Length::Fill)
+    .width(Length::Fill)
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Center)
+    .size(MEDIUM_TEXT);
+
+    let rows: Vec<(String, String)> = match stats {
+        Some(stats) if discipline => stats
+            .discipline()
+            .into_iter()
+            .map(|p| {
+                let label = fl!(
+                    "player-stats-discipline",
+                    dismissals = p.total_dismissals,
+                    penalties = p.penalties,
+                    time = p.penalty_time(),
+                    fouls = p.fouls
+                );
+                (player_label(p), label)
+            })
+            .collect(),
+        Some(stats) => {
+            let scorers = stats.top_scorers();
+            // Players level on goals share a rank
+            scorers
+                .iter()
+                .map(|p| {
+                    let rank = 1 + scorers.iter().filter(|q| q.goals > p.goals).count();
+                    (
+                        format!("{rank}. {}", player_label(p)),
+                        fl!("player-stats-goals", goals = p.goals),
+                    )
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+    let num_items = rows.len();
+    let scroll_index = scroll_index.min(num_items.saturating_sub(PLAYER_STATS_LIST_LEN));
+
+    let empty_row = |label: Option<String>| -> Element<'a, Message> {
+        container(text(label.unwrap_or_default()).size(SMALL_PLUS_TEXT))
+            .center(Length::Fill)
+            .height(Length::Fixed(MIN_BUTTON_SIZE))
+            .style(disabled_container)
+            .into()
+    };
+    let mut no_stats = (num_items == 0).then(|| fl!("no-player-stats"));
+
+    let row_items: CollectArrayResult<_, PLAYER_STATS_LIST_LEN> = rows
+        .into_iter()
+        .skip(scroll_index)
+        .map(Some)
+        .chain([None].into_iter().cycle())
+        .take(PLAYER_STATS_LIST_LEN)
+        .map(|slot| match slot {
+            Some((player, value)) => container(
+                row![
+                    text(player).size(SMALL_PLUS_TEXT).width(Length::Fill),
+                    text(value).size(SMALL_PLUS_TEXT),
+                ]
+                .spacing(SPACING)
+                .align_y(Vertical::Center),
+            )
+            .center_y(Length::Fixed(MIN_BUTTON_SIZE))
+            .padding(PADDING)
+            .width(Length::Fill)
+            .style(white_container)
+            .into(),
+            None => empty_row(no_stats.take()),
+        })
+        .collect();
+
+    let list = make_scroll_list(
+        row_items.unwrap(),
+        num_items,
+        scroll_index,
+        title,
+        ScrollOption::PlayerStats,
+        light_gray_container,
+    )
+    .height(Length::Fill)
+    .width(Length::FillPortion(5));
+
+    let other_table = make_button(if discipline {
+        fl!("top-scorers")
+    } else {
+        fl!("discipline")
+    })
+    .style(blue_button)
+    .width(Length::Fill)
+    .on_press(Message::ShowPlayerStats {
+        discipline: !discipline,
+    });
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            false,
+            mode,
+            clock_running,
+            portal_indicator,
+            None,
+        ),
+        list,
+        row![
+            make_button(fl!("back"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::ShowGameDetails),
+            other_table,
+            make_button(fl!("export"))
+                .style(blue_button)
+                .width(Length::Fill)
+                .on_press_maybe(
+                    stats
+                        .is_some_and(|s| !s.is_empty())
+                        .then_some(Message::ExportPlayerStats)
+                ),
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+fn player_label(player: &PlayerTotals) -> String {
+    format!("{} - {}", player.player_display(), player.team_display())
+}
@@ -20,8 +20,9 @@ const PORTAL_DETAIL_LIST_LEN: usize = 4;
 /// Rows produced by `PortalManager::detail_rows()` come in
 /// fixed order: the startup-failure row first, then the token-expired row
 /// (only one of the two can occur), then stuck items (oldest first), then
-/// young pending items (oldest first), then recent successes (newest
-/// first, capped at RECENT_SUCCESS_CAP).
+/// young pending items (oldest first), then stats-pending and protested
+/// results under review, then recent successes (newest first, capped at
+/// RECENT_SUCCESS_CAP).
 ///
 /// `can_retry` is false when the portal subsystem failed to start. RETRY ALL
 /// greys out in that state: with no background task it cannot retry anything,
@@ -197,6 +198,16 @@ fn render_row<'a>(r: DetailRow) -> Element<'a, Message> {
         .width(Length::Fill)
         .height(Length::Fixed(MIN_BUTTON_SIZE))
         .into(),
+        DetailRow::UnderReview { id, game_number } => button(row_text_centered(fl!(
+            "portal-row-under-review",
+            game = game_number
+        )))
+        .on_press(Message::PortalRowTapped(id))
+        .style(orange_button)
+        .padding(PADDING)
+        .width(Length::Fill)
+        .height(Length::Fixed(MIN_BUTTON_SIZE))
+        .into(),
         DetailRow::RecentSuccess {
             game_number,
             submitted_mins_ago,
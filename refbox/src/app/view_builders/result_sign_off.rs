@@ -0,0 +1,116 @@
+use super::*;
+use iced::{
+    Alignment, Element, Length,
+    alignment::Horizontal,
+    widget::{column, container, horizontal_space, row, text},
+};
+use uwh_common::color::Color as GameColor;
+
+/// Ask each captain to sign off the result of the game that just ended, with their cap number or
+/// another number identifying them, or to lodge a protest against it. DONE records whatever has been entered; a team left
+/// blank is recorded as unsigned.
+pub(in super::super) fn build_result_sign_off_page<'a>(
+    data: ViewData<'_, '_>,
+    game_number: &str,
+    scores: BlackWhiteBundle<u8>,
+    sign_offs: BlackWhiteBundle<TeamSignOff>,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        ..
+    } = data;
+
+    let header = container(
+        text(fl!(
+            "sign-off-result",
+            game = game_number,
+            score_black = scores.black,
+            score_white = scores.white
+        ))
+        .align_x(Horizontal::Center)
+        .width(Length::Fill),
+    )
+    .style(light_gray_container)
+    .padding(PADDING)
+    .width(Length::Fill);
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            true,
+            mode,
+            clock_running,
+            portal_indicator,
+            None
+        ),
+        header,
+        row![
+            make_team_sign_off(GameColor::Black, sign_offs.black),
+            make_team_sign_off(GameColor::White, sign_offs.white),
+        ]
+        .spacing(SPACING)
+        .height(Length::Fill),
+        row![
+            horizontal_space(),
+            horizontal_space(),
+            make_button(fl!("done"))
+                .style(green_button)
+                .width(Length::Fill)
+                .on_press(Message::ResultSignOffDone),
+        ]
+        .spacing(SPACING),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+fn make_team_sign_off<'a>(color: GameColor, sign_off: TeamSignOff) -> Element<'a, Message> {
+    let team = match color {
+        GameColor::Black => fl!("dark-team-name-caps"),
+        GameColor::White => fl!("light-team-name-caps"),
+    };
+
+    column![
+        container(text(team).size(MEDIUM_TEXT))
+            .center_x(Length::Fill)
+            .padding(PADDING)
+            .style(match color {
+                GameColor::Black => black_container,
+                GameColor::White => white_container,
+            }),
+        make_value_button(
+            fl!("captain-signed"),
+            sign_off
+                .signed_by
+                .map_or_else(|| fl!("not-signed"), |number| number.to_string()),
+            (false, true),
+            Some(Message::KeypadPage(KeypadPage::ResultSignOff(color))),
+        )
+        .style(match color {
+            GameColor::Black => black_button,
+            GameColor::White => white_button,
+        })
+        .height(Length::Fill),
+        make_value_button(
+            fl!("protest-lodged"),
+            bool_string(sign_off.protest),
+            (false, true),
+            Some(Message::ToggleProtest(color)),
+        )
+        .style(if sign_off.protest {
+            orange_button
+        } else {
+            light_gray_button
+        })
+        .height(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .width(Length::Fill)
+    .into()
+}
@@ -148,6 +148,27 @@ pub(in super::super) fn build_score_edit_view<'a>(
         cancel_or_back_label(score_edit_has_changes(scores, old_scores))
     };
 
+    // Disallowing a goal is its own page, reached from edit mode only. It is
+    // offered while there is a goal left to disallow and the period still
+    // allows it (see `TournamentManager::disallow_goal`).
+    let middle_btn: Element<'a, Message> = if is_confirmation {
+        horizontal_space().into()
+    } else {
+        let can_disallow = !matches!(
+            snapshot.current_period,
+            GamePeriod::BetweenGames | GamePeriod::SuddenDeath | GamePeriod::PenaltyShootout
+        ) && snapshot
+            .goals
+            .black
+            .iter()
+            .chain(snapshot.goals.white.iter())
+            .any(|goal| goal.disallowed.is_none());
+        make_button(fl!("disallow-goal"))
+            .style(orange_button)
+            .on_press_maybe(can_disallow.then_some(Message::DisallowGoal))
+            .into()
+    };
+
     main_col
         .push(
             row![
@@ -166,6 +187,8 @@ pub(in super::super) fn build_score_edit_view<'a>(
                     .on_press_maybe(cancel_btn_msg)
                     .style(red_button),
                 horizontal_space(),
+                middle_btn,
+                horizontal_space(),
                 confirm_btn,
             ]
             .spacing(SPACING),
@@ -2,7 +2,6 @@ use super::fit_text::{fit_text, fit_text_lines};
 use super::*;
 use crate::app::RevivePhase;
 use crate::portal_manager::{HealthState, PortalIndicatorState};
-use enum_iterator::all;
 use iced::{
     Alignment, Background, Border, Length, Theme,
     alignment::{Horizontal, Vertical},
@@ -603,10 +602,12 @@ pub(super) fn make_game_time_button<'a>(
                     | GamePeriod::SecondHalf
                     | GamePeriod::OvertimeFirstHalf
                     | GamePeriod::OvertimeSecondHalf
-                    | GamePeriod::SuddenDeath => false,
+                    | GamePeriod::SuddenDeath
+                    | GamePeriod::PenaltyShootout => false,
                 };
 
                 snapshot.current_period != GamePeriod::SuddenDeath
+                    && snapshot.current_period != GamePeriod::PenaltyShootout
                     && ((snapshot.secs_in_period <= 10
                         && (snapshot.secs_in_period % 2 == 0)
                         && (snapshot.secs_in_period != 0))
@@ -627,6 +628,7 @@ pub(super) fn make_game_time_button<'a>(
             GamePeriod::OvertimeSecondHalf => (fl!("overtime-second-half"), green_text),
             GamePeriod::PreSuddenDeath => (fl!("pre-sudden-death-break"), yellow_text),
             GamePeriod::SuddenDeath => (fl!("sudden-death"), green_text),
+            GamePeriod::PenaltyShootout => (fl!("penalty-shootout"), green_text),
         };
 
         if make_red {
@@ -807,7 +809,14 @@ pub(super) fn make_game_time_button<'a>(
         }
     });
 
-    let time_text = secs_to_long_time_string(snapshot.secs_in_period);
+    // The shootout has no clock, so its tally takes the clock's place
+    let time_text = match (snapshot.current_period, &snapshot.shootout) {
+        (GamePeriod::PenaltyShootout, Some(shootout)) => {
+            let tally = shootout.tally();
+            format!("{} - {}", tally.black, tally.white)
+        }
+        _ => secs_to_long_time_string(snapshot.secs_in_period).to_string(),
+    };
 
     let time_text = time_text.trim().to_owned();
 
@@ -1397,16 +1406,17 @@ where
 }
 
 pub(super) fn make_penalty_dropdown<'a>(
+    rules: &RuleProfile,
     infraction: Infraction,
     display_infraction_name: bool,
 ) -> Element<'a, Message> {
     const ROW_LEN: usize = 6;
-    let foul_buttons = all::<Infraction>().map(|button_infraction| {
+    let foul_buttons = rules.infraction_choices().map(|button_infraction| {
         // The Unknown infraction's icon is a black "?" PNG, invisible on the
         // black High-Contrast tile. In High Contrast only, render a themed white
         // "?" instead so the Unknown option follows the display mode; Light and
         // Dark keep the original image.
-        let inner: Element<'a, Message> = if button_infraction == Infraction::Unknown
+        let inner: Element<'a, Message> = if button_infraction == Infraction::UNKNOWN
             && display_mode() == DisplayMode::HighContrast
         {
             container(text("?").size(LARGE_TEXT).style(white_text))
@@ -1415,9 +1425,11 @@ pub(super) fn make_penalty_dropdown<'a>(
                 .into()
         } else {
             container(
-                Image::new(image::Handle::from_bytes(button_infraction.get_image()))
-                    .width(Length::Fill)
-                    .height(Length::Fixed(MIN_BUTTON_SIZE)),
+                Image::new(image::Handle::from_bytes(
+                    rules.icon(button_infraction).to_vec(),
+                ))
+                .width(Length::Fill)
+                .height(Length::Fixed(MIN_BUTTON_SIZE)),
             )
             .style(transparent_container)
             .into()
@@ -1435,7 +1447,7 @@ pub(super) fn make_penalty_dropdown<'a>(
     });
 
     let name: Container<'_, Message> = container(
-        row![text(infraction_bar_label(infraction))]
+        row![text(infraction_bar_label(rules, infraction))]
             .spacing(0)
             .align_y(Alignment::Center),
     )
@@ -1474,6 +1486,7 @@ pub(super) fn make_penalty_dropdown<'a>(
 pub fn make_warning_container<'a>(
     warning: &InfractionSnapshot,
     color: Option<GameColor>,
+    rules: &RuleProfile,
 ) -> Container<'a, Message> {
     const WIDTH: u16 = 220;
     const HEIGHT: u16 = 23;
@@ -1487,7 +1500,7 @@ pub fn make_warning_container<'a>(
     container(if color.is_some() {
         row![
             Space::with_width(PADDING),
-            text(inf_short_name(warning.infraction)).size(SMALL_TEXT),
+            text(inf_short_name(rules, warning.infraction)).size(SMALL_TEXT),
             horizontal_space(),
             text(who).size(SMALL_TEXT),
             Space::with_width(PADDING),
@@ -1495,7 +1508,7 @@ pub fn make_warning_container<'a>(
     } else {
         row![
             horizontal_space(),
-            text(inf_short_name(warning.infraction)).size(SMALL_TEXT),
+            text(inf_short_name(rules, warning.infraction)).size(SMALL_TEXT),
             horizontal_space(),
         ]
     })
@@ -1509,20 +1522,25 @@ pub fn make_warning_container<'a>(
     .padding(0)
 }
 
-pub fn inf_short_name(inf: Infraction) -> String {
+/// The name `rules` gives `inf`, or the translated name of a built-in infraction
+pub fn inf_short_name(rules: &RuleProfile, inf: Infraction) -> String {
+    if let Some(name) = rules.short_name(inf) {
+        return name.to_string();
+    }
     match inf {
-        Infraction::Unknown => fl!("unknown"),
-        Infraction::StickInfringement => fl!("stick-foul"),
-        Infraction::IllegalAdvancement => fl!("illegal-advance"),
-        Infraction::IllegalSubstitution => fl!("sub-foul"),
-        Infraction::IllegallyStoppingThePuck => fl!("illegal-stoppage"),
-        Infraction::OutOfBounds => fl!("out-of-bounds"),
-        Infraction::GrabbingTheBarrier => fl!("grabbing-the-wall"),
-        Infraction::Obstruction => fl!("obstruction"),
-        Infraction::DelayOfGame => fl!("delay-of-game"),
-        Infraction::UnsportsmanlikeConduct => fl!("unsportsmanlike"),
-        Infraction::FreeArm => fl!("free-arm"),
-        Infraction::FalseStart => fl!("false-start"),
+        Infraction::UNKNOWN => fl!("unknown"),
+        Infraction::STICK_INFRINGEMENT => fl!("stick-foul"),
+        Infraction::ILLEGAL_ADVANCEMENT => fl!("illegal-advance"),
+        Infraction::ILLEGAL_SUBSTITUTION => fl!("sub-foul"),
+        Infraction::ILLEGALLY_STOPPING_THE_PUCK => fl!("illegal-stoppage"),
+        Infraction::OUT_OF_BOUNDS => fl!("out-of-bounds"),
+        Infraction::GRABBING_THE_BARRIER => fl!("grabbing-the-wall"),
+        Infraction::OBSTRUCTION => fl!("obstruction"),
+        Infraction::DELAY_OF_GAME => fl!("delay-of-game"),
+        Infraction::UNSPORTSMANLIKE_CONDUCT => fl!("unsportsmanlike"),
+        Infraction::FREE_ARM => fl!("free-arm"),
+        Infraction::FALSE_START => fl!("false-start"),
+        _ => inf.id().to_string(),
     }
 }
 
@@ -1535,11 +1553,11 @@ pub fn inf_short_name(inf: Infraction) -> String {
 /// infraction (fouls-and-warnings tracking off). The prompt is substituted into
 /// the same `infraction` template as a real name so the localized prefix always
 /// matches the populated state.
-fn infraction_bar_label(infraction: Infraction) -> String {
-    let value = if infraction == Infraction::Unknown {
+fn infraction_bar_label(rules: &RuleProfile, infraction: Infraction) -> String {
+    let value = if infraction == Infraction::UNKNOWN {
         fl!("select-infraction")
     } else {
-        inf_short_name(infraction)
+        inf_short_name(rules, infraction)
     };
     fl!("infraction", infraction = value)
 }
@@ -1609,27 +1627,40 @@ mod tests {
         // Nothing picked yet → the bar prompts for a choice instead of naming
         // "Unknown" as though it were one. Compared against the same fl! keys so
         // the assertion holds regardless of which locale the loader resolves to.
+        let rules = RuleProfile::default();
         assert_eq!(
-            infraction_bar_label(Infraction::Unknown),
+            infraction_bar_label(&rules, Infraction::UNKNOWN),
             fl!("infraction", infraction = fl!("select-infraction"))
         );
         // Would have passed before this fix, and must not again.
         assert_ne!(
-            infraction_bar_label(Infraction::Unknown),
+            infraction_bar_label(&rules, Infraction::UNKNOWN),
             fl!("infraction", infraction = fl!("unknown"))
         );
     }
 
     #[test]
     fn infraction_bar_names_a_chosen_infraction() {
-        for inf in all::<Infraction>().filter(|i| *i != Infraction::Unknown) {
+        let rules = RuleProfile::default();
+        for inf in Infraction::BUILT_IN {
             assert_eq!(
-                infraction_bar_label(inf),
-                fl!("infraction", infraction = inf_short_name(inf))
+                infraction_bar_label(&rules, inf),
+                fl!("infraction", infraction = inf_short_name(&rules, inf))
             );
         }
     }
 
+    #[test]
+    fn short_names_come_from_the_profile_passed_in() {
+        let built_in = RuleProfile::default();
+        let mut renamed = built_in.clone();
+        renamed.infractions[1].short_name = Some("Stick".to_string());
+        let inf = renamed.infractions[1].id;
+
+        assert_eq!(inf_short_name(&renamed, inf), "Stick");
+        assert_ne!(inf_short_name(&built_in, inf), "Stick");
+    }
+
     #[test]
     fn make_scroll_list_handles_index_past_end_of_shrunken_list() {
         // Regression for the portal-detail crash (H7): a list can shrink under
@@ -0,0 +1,202 @@
+use super::*;
+use collect_array::CollectArrayResult;
+use iced::{
+    Element, Length,
+    alignment::{Horizontal, Vertical},
+    widget::{button, column, container, row, text},
+};
+use uwh_common::color::Color as GameColor;
+
+const TIMELINE_LIST_LEN: usize = 4;
+
+/// Render the timeline of the current game, most recent first. Entries that can still be changed
+/// are buttons that open their editor; the rest are shown in plain boxes.
+pub(in super::super) fn build_timeline_page<'a>(
+    data: ViewData<'_, '_>,
+    timeline: &[TimelineEntry],
+    scroll_index: usize,
+) -> Element<'a, Message> {
+    let ViewData {
+        snapshot,
+        mode,
+        clock_running,
+        portal_indicator,
+        rules,
+        ..
+    } = data;
+
+    let title = text(fl!("timeline"))
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .size(MEDIUM_TEXT);
+
+    let num_items = timeline.len();
+    let scroll_index = scroll_index.min(num_items.saturating_sub(TIMELINE_LIST_LEN));
+    let mut no_entries = (num_items == 0).then(|| fl!("no-timeline-entries"));
+
+    let rows: CollectArrayResult<_, TIMELINE_LIST_LEN> = timeline
+        .iter()
+        .skip(scroll_index)
+        .map(Some)
+        .chain([None].into_iter().cycle())
+        .take(TIMELINE_LIST_LEN)
+        .map(|entry| match entry {
+            Some(entry) => make_timeline_row(entry, rules),
+            None => container(text(no_entries.take().unwrap_or_default()).size(SMALL_PLUS_TEXT))
+                .center(Length::Fill)
+                .height(Length::Fixed(MIN_BUTTON_SIZE))
+                .style(disabled_container)
+                .into(),
+        })
+        .collect();
+
+    let list = make_scroll_list(
+        rows.unwrap(),
+        num_items,
+        scroll_index,
+        title,
+        ScrollOption::Timeline,
+        light_gray_container,
+    )
+    .height(Length::Fill)
+    .width(Length::FillPortion(5));
+
+    column![
+        make_game_time_button(
+            snapshot,
+            false,
+            false,
+            mode,
+            clock_running,
+            portal_indicator,
+            None,
+        ),
+        list,
+        row![
+            make_button(fl!("back"))
+                .style(red_button)
+                .width(Length::Fill)
+                .on_press(Message::ShowGameDetails),
+            make_button(fl!("edit-history"))
+                .style(blue_button)
+                .width(Length::Fill)
+                .on_press(Message::ShowAuditTrail),
+            make_button(fl!("report-incident"))
+                .style(orange_button)
+                .width(Length::Fill)
+                .on_press_maybe(
+                    (snapshot.current_period != GamePeriod::BetweenGames)
+                        .then_some(Message::ReportIncident)
+                ),
+        ]
+        .spacing(SPACING)
+        .width(Length::Fill),
+    ]
+    .spacing(SPACING)
+    .height(Length::Fill)
+    .into()
+}
+
+fn make_timeline_row<'a>(entry: &TimelineEntry, rules: &RuleProfile) -> Element<'a, Message> {
+    let line = &entry.line;
+
+    let when = match line.kind {
+        SheetLineKind::ShootoutAttempt { .. } => period_text(line.period),
+        _ => format!(
+            "{} {}",
+            period_text(line.period),
+            time_string(line.period_time)
+        ),
+    };
+
+    let (what, detail) = match line.kind {
+        SheetLineKind::Goal { disallowed: None } => (fl!("timeline-goal"), String::new()),
+        SheetLineKind::Goal {
+            disallowed: Some(reason),
+        } => (
+            fl!("timeline-disallowed-goal"),
+            fl!("disallow-reason", reason = reason_fluent(reason)),
+        ),
+        SheetLineKind::Penalty {
+            duration,
+            infraction,
+        } => {
+            let kind = duration.map_or(PenaltyKind::TotalDismissal, |d| {
+                PenaltyKind::Timed(d.as_secs().try_into().unwrap_or(u16::MAX))
+            });
+            (
+                fl!("timeline-penalty", kind = kind.fluent()),
+                infraction
+                    .map(|i| inf_short_name(rules, i))
+                    .unwrap_or_default(),
+            )
+        }
+        SheetLineKind::Warning(infraction) => {
+            (fl!("timeline-warning"), inf_short_name(rules, infraction))
+        }
+        SheetLineKind::Foul(infraction) => {
+            (fl!("timeline-foul"), inf_short_name(rules, infraction))
+        }
+        SheetLineKind::Timeout(TimeoutKind::Team(_)) => {
+            (fl!("timeline-team-timeout"), String::new())
+        }
+        SheetLineKind::Timeout(TimeoutKind::Ref) => (fl!("timeline-ref-timeout"), String::new()),
+        SheetLineKind::Timeout(TimeoutKind::PenaltyShot) => {
+            (fl!("timeline-penalty-shot"), String::new())
+        }
+        SheetLineKind::ShootoutAttempt { round, scored } => (
+            fl!("shootout-round", round = round),
+            if scored {
+                fl!("shootout-goal")
+            } else {
+                fl!("shootout-miss")
+            },
+        ),
+        SheetLineKind::PeriodStart => (fl!("timeline-period-start"), String::new()),
+    };
+
+    // Team goals are recorded against player 0, team warnings and fouls against no player
+    let who = match (&line.kind, line.player_number) {
+        (SheetLineKind::Goal { .. }, Some(0)) => fl!("team-warning-abreviation"),
+        (SheetLineKind::Warning(_) | SheetLineKind::Foul(_), None) if line.color.is_some() => {
+            fl!("team-warning-abreviation")
+        }
+        (_, Some(number)) => format!("#{number}"),
+        (_, None) => String::new(),
+    };
+
+    let content = row![
+        text(when).width(Length::FillPortion(3)),
+        text(what).width(Length::FillPortion(3)),
+        text(detail).width(Length::FillPortion(3)),
+        text(who).width(Length::FillPortion(1)),
+    ]
+    .spacing(SPACING)
+    .align_y(Vertical::Center);
+
+    match entry.edit {
+        Some(edit) => button(content)
+            .padding(PADDING)
+            .height(Length::Fixed(MIN_BUTTON_SIZE))
+            .width(Length::Fill)
+            .style(match line.color {
+                Some(GameColor::Black) => black_button,
+                Some(GameColor::White) => white_button,
+                None => gray_button,
+            })
+            .on_press(Message::EditTimelineEntry(edit))
+            .into(),
+        None => container(content)
+            .center_y(Length::Fixed(MIN_BUTTON_SIZE))
+            .padding(PADDING)
+            .width(Length::Fill)
+            .style(match line.color {
+                Some(GameColor::Black) => black_container,
+                Some(GameColor::White) => white_container,
+                None => gray_container,
+            })
+            .into(),
+    }
+}
@@ -18,6 +18,7 @@ pub(in super::super) fn build_warning_overview_page<'a>(
         mode,
         clock_running,
         portal_indicator,
+        rules,
         ..
     } = data;
 
@@ -43,12 +44,14 @@ pub(in super::super) fn build_warning_overview_page<'a>(
             make_warning_list(
                 warnings.black.into_iter().rev().collect(),
                 indices.black,
-                GameColor::Black
+                GameColor::Black,
+                rules,
             ),
             make_warning_list(
                 warnings.white.into_iter().rev().collect(),
                 indices.white,
-                GameColor::White
+                GameColor::White,
+                rules,
             )
         ]
         .spacing(SPACING)
@@ -64,7 +67,7 @@ pub(in super::super) fn build_warning_overview_page<'a>(
                 .on_press(Message::KeypadPage(KeypadPage::WarningAdd {
                     origin: None,
                     color: GameColor::Black,
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                     team_warning: false,
                     ret_to_overview: true,
                 })),
@@ -86,6 +89,7 @@ fn make_warning_list<'a>(
     warnings: Vec<PrintableInfractionSummary>,
     index: usize,
     color: GameColor,
+    rules: &RuleProfile,
 ) -> Container<'a, Message> {
     const WARNING_LIST_LEN: usize = 3;
 
@@ -117,7 +121,7 @@ fn make_warning_list<'a>(
                         .player_number
                         .map(|n| n.to_string())
                         .unwrap_or_else(|| String::from("none")),
-                    infraction = inf_short_name(details.infraction)
+                    infraction = inf_short_name(rules, details.infraction)
                 );
 
                 let mut text = text(printable)
@@ -153,7 +157,7 @@ fn make_warning_list<'a>(
                     .on_press(Message::KeypadPage(KeypadPage::WarningAdd {
                         origin: None,
                         color,
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                         team_warning: false,
                         ret_to_overview: true,
                     }))
@@ -3,8 +3,9 @@ use iced::widget::scrollable;
 use iced::{
     Element, Length,
     alignment::{Horizontal, Vertical},
-    widget::{column, container, row, text},
+    widget::{Container, Space, column, container, horizontal_space, row, text},
 };
+use uwh_common::{color::Color as GameColor, game_snapshot::GoalSnapshot};
 
 pub(in super::super) fn build_warnings_summary_page<'a>(
     data: ViewData<'_, '_>,
@@ -14,6 +15,7 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
         mode,
         clock_running,
         portal_indicator,
+        rules,
         ..
     } = data;
 
@@ -25,10 +27,12 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
             .width(Length::Fill),
         scrollable(
             row(snapshot.warnings.iter().map(|(color, warns)| column(
-                warns
-                    .iter()
-                    .rev()
-                    .map(|warning| make_warning_container(warning, Some(color)).into())
+                warns.iter().rev().map(|warning| make_warning_container(
+                    warning,
+                    Some(color),
+                    rules
+                )
+                .into())
             )
             .spacing(SPACING)
             .width(Length::Fill)
@@ -53,7 +57,7 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
                         fouls
                             .iter()
                             .rev()
-                            .map(|fouls| make_warning_container(fouls, color).into()),
+                            .map(|fouls| make_warning_container(fouls, color, rules).into()),
                     )
                     .into(),
                 ),
@@ -78,13 +82,45 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
     .width(Length::Fill)
     .height(Length::Fill);
 
-    let warnings_and_fouls_row = row![
+    let mut warnings_and_fouls_row = row![
         fouls_container.width(Length::Fill),
         warnings_container.width(Length::Fill)
     ]
     .spacing(SPACING)
     .width(Length::Fill);
 
+    // Disallowed goals only take up room on the page once there are some
+    if snapshot
+        .goals
+        .iter()
+        .any(|(_, goals)| goals.iter().any(|goal| goal.disallowed.is_some()))
+    {
+        warnings_and_fouls_row = warnings_and_fouls_row.push(
+            container(column![
+                text(fl!("disallowed-goals"))
+                    .size(SMALL_PLUS_TEXT)
+                    .align_y(Vertical::Top)
+                    .align_x(Horizontal::Center)
+                    .width(Length::Fill),
+                scrollable(
+                    column(snapshot.goals.iter().flat_map(|(color, goals)| {
+                        goals
+                            .iter()
+                            .filter(|goal| goal.disallowed.is_some())
+                            .map(move |goal| make_disallowed_goal_container(goal, color).into())
+                    }))
+                    .spacing(SPACING)
+                    .width(Length::Fill)
+                    .padding(PADDING)
+                )
+                .style(scrollable_style)
+            ])
+            .style(light_gray_container)
+            .width(Length::Fill)
+            .height(Length::Fill),
+        );
+    }
+
     column![
         make_game_time_button(
             snapshot,
@@ -117,3 +153,35 @@ pub(in super::super) fn build_warnings_summary_page<'a>(
     .height(Length::Fill)
     .into()
 }
+
+fn make_disallowed_goal_container<'a>(
+    goal: &GoalSnapshot,
+    color: GameColor,
+) -> Container<'a, Message> {
+    const WIDTH: u16 = 220;
+    const HEIGHT: u16 = 23;
+
+    let who = match goal.player_number {
+        0 => fl!("team-warning-abreviation"),
+        num => format!("#{num}"),
+    };
+    let reason = goal
+        .disallowed
+        .map(|reason| fl!("disallow-reason", reason = reason_fluent(reason)))
+        .unwrap_or_default();
+
+    container(row![
+        Space::with_width(PADDING),
+        text(reason).size(SMALL_TEXT),
+        horizontal_space(),
+        text(who).size(SMALL_TEXT),
+        Space::with_width(PADDING),
+    ])
+    .width(WIDTH)
+    .height(HEIGHT)
+    .style(match color {
+        GameColor::Black => black_container,
+        GameColor::White => white_container,
+    })
+    .padding(0)
+}
@@ -1,6 +1,8 @@
 use super::Mode;
 use crate::config::GameSource;
 use crate::portal_manager::PortalIndicatorState;
+use crate::rule_profile::RuleProfile;
+use std::path::Path;
 use uwh_common::{game_snapshot::GameSnapshot, uwhportal::schedule::TeamList};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,10 +26,15 @@ pub(super) struct ViewData<'a, 'b> {
     /// panel connected). Used to gray out controls that only make sense
     /// without a real panel — currently just "Open New Display".
     pub(super) has_led_panel: bool,
+    /// The rule profile in use, for the penalty lengths and infraction names
+    pub(super) rules: &'a RuleProfile,
     /// The custom site address as committed, which is the one the refbox
     /// actually talks to. The SITE row shows this rather than whatever is
     /// currently typed into the editor, so an address that has not been
     /// applied — or whose APPLY was refused — can never be mistaken for the
     /// address in use.
     pub(super) committed_site_url: &'a str,
+    /// The folder the schedule file source last loaded its event from, `None`
+    /// when none has loaded.
+    pub(super) schedule_folder: Option<&'a Path>,
 }
@@ -7,7 +7,7 @@ use macro_attr_2018::macro_attr;
 use matrix_drawing::transmitted_data::Brightness;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{fmt::Display, path::PathBuf};
 use toml::Table;
 pub use uwh_common::config::Game;
 
@@ -352,6 +352,21 @@ pub struct Config {
     pub display_mode: crate::app::theme::DisplayMode,
     #[serde(default)]
     pub front_display_layout: crate::sim_frame::FrontDisplayLayout,
+    /// A rule profile TOML file, relative to the config directory. The built-in rules are used
+    /// when this is unset.
+    #[serde(default)]
+    pub rule_profile: Option<PathBuf>,
+    /// The schedule folder for the `File` game source, relative to the config directory. When
+    /// unset, a `refbox-schedule` folder is looked for on a USB stick and then in the config
+    /// directory.
+    #[serde(default)]
+    pub schedule_folder: Option<PathBuf>,
+    /// The completed scoresheet written after each game, if any
+    #[serde(default)]
+    pub scoresheet_style: ScoresheetStyle,
+    /// Whether each team's captain signs off the result, or lodges a protest, when a game ends
+    #[serde(default)]
+    pub confirm_result: bool,
 }
 
 impl Config {
@@ -375,6 +390,10 @@ impl Config {
             mut language,
             mut display_mode,
             mut front_display_layout,
+            mut rule_profile,
+            mut schedule_folder,
+            mut scoresheet_style,
+            mut confirm_result,
         } = Default::default();
 
         if let Some(old_mode) = old.get("mode") {
@@ -445,6 +464,10 @@ impl Config {
         get_serde_value(old, "language", &mut language);
         get_serde_value(old, "display_mode", &mut display_mode);
         get_serde_value(old, "front_display_layout", &mut front_display_layout);
+        get_serde_value(old, "rule_profile", &mut rule_profile);
+        get_serde_value(old, "schedule_folder", &mut schedule_folder);
+        get_serde_value(old, "scoresheet_style", &mut scoresheet_style);
+        get_boolean_value(old, "confirm_result", &mut confirm_result);
 
         Self {
             mode,
@@ -465,6 +488,10 @@ impl Config {
             language,
             display_mode,
             front_display_layout,
+            rule_profile,
+            schedule_folder,
+            scoresheet_style,
+            confirm_result,
         }
     }
 }
@@ -492,9 +519,35 @@ impl Display for Mode {
     }
 }
 
+macro_attr! {
+    /// Whether a completed scoresheet is written after each game, and laid out like which of
+    /// schedule-processor's blank sheets
+    #[derive(Debug, Clone, Copy, Derivative, PartialEq, Eq, Serialize, Deserialize, EnumFromStr!)]
+    #[derivative(Default)]
+    pub enum ScoresheetStyle {
+        #[derivative(Default)]
+        Off,
+        Detailed,
+        Simple,
+        CmasOfficial,
+    }
+}
+
+impl Display for ScoresheetStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Off => f.write_str(&fl!("off")),
+            Self::Detailed => f.write_str(&fl!("scoresheet-detailed")),
+            Self::Simple => f.write_str(&fl!("scoresheet-simple")),
+            Self::CmasOfficial => f.write_str(&fl!("scoresheet-cmas-official")),
+        }
+    }
+}
+
 macro_attr! {
     /// Where this refbox gets its games. `Manual` means the operator enters
-    /// everything by hand; the other two are remote sources.
+    /// everything by hand; `Portal` and `Custom` are remote sources, and `File`
+    /// is a schedule folder on disk or a USB stick, for events with no network.
     #[derive(Debug, Clone, Copy, Derivative, PartialEq, Eq, Serialize, Deserialize, EnumFromStr!)]
     #[derivative(Default)]
     pub enum GameSource {
@@ -502,19 +555,21 @@ macro_attr! {
         Manual,
         Portal,
         Custom,
+        File,
     }
 }
 
 macro_attr! {
     /// Which remote source to return to when leaving `Manual`. Kept separately
     /// from `GameSource` so that switching to manual and back does not lose the
-    /// operator's choice of remote.
+    /// operator's choice of remote. A schedule file counts as one here.
     #[derive(Debug, Clone, Copy, Derivative, PartialEq, Eq, Serialize, Deserialize, EnumFromStr!)]
     #[derivative(Default)]
     pub enum RemoteSource {
         #[derivative(Default)]
         Portal,
         Custom,
+        File,
     }
 }
 
@@ -941,7 +996,12 @@ mod test {
 
     #[test]
     fn config_source_round_trips() {
-        for source in [GameSource::Manual, GameSource::Portal, GameSource::Custom] {
+        for source in [
+            GameSource::Manual,
+            GameSource::Portal,
+            GameSource::Custom,
+            GameSource::File,
+        ] {
             let config = Config {
                 source,
                 ..Default::default()
@@ -954,7 +1014,11 @@ mod test {
 
     #[test]
     fn config_remembered_remote_round_trips() {
-        for remote in [RemoteSource::Portal, RemoteSource::Custom] {
+        for remote in [
+            RemoteSource::Portal,
+            RemoteSource::Custom,
+            RemoteSource::File,
+        ] {
             let config = Config {
                 remembered_remote: remote,
                 ..Default::default()
@@ -1042,6 +1106,40 @@ mod test {
         assert_eq!(config.custom_site.token, "custom-token");
     }
 
+    #[test]
+    fn config_rule_profile_round_trips_and_migrates() {
+        let parsed: Config = toml::from_str(&toml::to_string(&Config::default()).unwrap()).unwrap();
+        assert_eq!(parsed.rule_profile, None);
+
+        let config = Config {
+            rule_profile: Some(PathBuf::from("rules/national.toml")),
+            ..Default::default()
+        };
+        let serialized = toml::to_string(&config).unwrap();
+        let deser: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(deser, config);
+
+        let old: Table = toml::from_str(&serialized).unwrap();
+        assert_eq!(Config::migrate(&old).rule_profile, config.rule_profile);
+    }
+
+    #[test]
+    fn config_schedule_folder_round_trips_and_migrates() {
+        let config = Config {
+            source: GameSource::File,
+            schedule_folder: Some(PathBuf::from("/media/refbox/STICK/spring-cup")),
+            ..Default::default()
+        };
+        let serialized = toml::to_string(&config).unwrap();
+        let deser: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(deser, config);
+
+        let old: Table = toml::from_str(&serialized).unwrap();
+        let migrated = Config::migrate(&old);
+        assert_eq!(migrated.source, GameSource::File);
+        assert_eq!(migrated.schedule_folder, config.schedule_folder);
+    }
+
     #[test]
     fn config_missing_display_mode_defaults_to_light() {
         // A config TOML written before this field existed must still load.
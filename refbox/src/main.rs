@@ -27,9 +27,11 @@ use once_cell::sync::Lazy;
 use rust_embed::RustEmbed;
 use std::{
     borrow::Cow,
+    net::IpAddr,
     path::PathBuf,
     process::{Command, Stdio},
     sync::Arc,
+    time::Duration,
 };
 use tokio_serial::{DataBits, FlowControl, Parity, StopBits};
 use unic_langid::LanguageIdentifier;
@@ -39,9 +41,15 @@ mod app_icon;
 mod beep_test;
 mod penalty_editor;
 mod portal_manager;
+mod results_archive;
+mod rule_profile;
+mod schedule_file;
+mod scoresheet;
 mod sim_app;
 mod sim_frame;
 mod sound_controller;
+mod state_file;
+mod suspensions;
 mod tournament_manager;
 #[cfg(test)]
 mod translation_consistency;
@@ -112,6 +120,11 @@ macro_rules! fl {
     }};
 }
 
+/// Parses a path that has to be valid UTF-8, because it is passed on to a restarted app
+fn utf8_path(path: &str) -> Result<PathBuf, std::convert::Infallible> {
+    Ok(PathBuf::from(path))
+}
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Cli {
@@ -143,6 +156,18 @@ struct Cli {
     /// Port to listen on for TCP connections with a JSON send type
     json_port: u16,
 
+    #[clap(long, default_value = "8002")]
+    /// Port to serve the WebSocket snapshot stream and the browser scoreboard on
+    websocket_port: u16,
+
+    #[clap(long, value_name = "PORT")]
+    /// Port to serve a read-only HTTP status API on, off when not given
+    status_port: Option<u16>,
+
+    #[clap(long, default_value = "127.0.0.1", value_name = "ADDR")]
+    /// Interface to serve the status API on, `0.0.0.0` or `::` for every interface
+    status_bind: IpAddr,
+
     #[clap(long, default_missing_value = "/dev/ttyUSB0")]
     /// Serial Port to send snapshots to
     serial_port: Option<String>,
@@ -194,6 +219,31 @@ struct Cli {
     /// Probe that the binary starts on this machine, then exit 0. Used as the
     /// post-download smoke test before committing to a new binary.
     self_check: bool,
+
+    #[clap(long, value_name = "DIR", value_parser = utf8_path)]
+    /// Record everything done to the game to a new session file in DIR, for replaying later
+    record_session: Option<PathBuf>,
+
+    #[clap(long, value_name = "FILE")]
+    /// Print the golden trace of a recorded session file, then exit
+    replay_session: Option<PathBuf>,
+
+    #[clap(
+        long,
+        default_value = "0",
+        value_name = "SECS",
+        requires = "replay_session"
+    )]
+    /// How long to keep replaying after the last action in the session file
+    replay_run_on: u64,
+
+    #[clap(long, value_name = "DIR")]
+    /// Export the archived results of an event day to JSON and CSV files in DIR, then exit
+    export_results: Option<PathBuf>,
+
+    #[clap(long, value_name = "YYYY-MM-DD", requires = "export_results")]
+    /// The day to export, default is the most recent day with a finished game
+    export_day: Option<String>,
 }
 
 /// All arguments needed to launch a panel-simulator child process. Built once
@@ -268,6 +318,9 @@ pub(crate) fn build_restart_argv(args: &Cli) -> Vec<String> {
         fullscreen,
         binary_port,
         json_port,
+        websocket_port,
+        status_port,
+        status_bind,
         serial_port,
         baud_rate,
         allow_http,
@@ -277,11 +330,16 @@ pub(crate) fn build_restart_argv(args: &Cli) -> Vec<String> {
         num_old_logs,
         simulate_sunlight_display,
         force_power_controls,
+        record_session,
         // --- Deliberately NOT replayed (see this fn's doc comment) ---
         language: _,         // a restart is often triggered BY a language change
         is_simulator: _,     // this relaunches the MAIN app, never a sim child
         capture_previews: _, // dev-only; replaying it would exit immediately
         self_check: _,       // a smoke-test probe, never replayed into a real restart
+        replay_session: _,   // replaying it would exit immediately
+        replay_run_on: _,    // only used with --replay-session
+        export_results: _,   // exports and exits immediately
+        export_day: _,       // only used with --export-results
     } = args;
 
     let mut argv: Vec<String> = Vec::new();
@@ -305,6 +363,14 @@ pub(crate) fn build_restart_argv(args: &Cli) -> Vec<String> {
     argv.push(binary_port.to_string());
     argv.push("--json-port".to_string());
     argv.push(json_port.to_string());
+    argv.push("--websocket-port".to_string());
+    argv.push(websocket_port.to_string());
+    if let Some(port) = status_port {
+        argv.push("--status-port".to_string());
+        argv.push(port.to_string());
+        argv.push("--status-bind".to_string());
+        argv.push(status_bind.to_string());
+    }
     if let Some(port) = serial_port {
         argv.push("--serial-port".to_string());
         argv.push(port.clone());
@@ -332,6 +398,12 @@ pub(crate) fn build_restart_argv(args: &Cli) -> Vec<String> {
     if *force_power_controls {
         argv.push("--force-power-controls".to_string());
     }
+    if let Some(dir) = record_session {
+        // The relaunched app starts a new session file in the same directory. A non-UTF-8
+        // path is rejected when the arguments are parsed.
+        argv.push("--record-session".to_string());
+        argv.push(dir.to_str().unwrap().to_string());
+    }
 
     argv
 }
@@ -359,6 +431,14 @@ fn respawn(exe: std::path::PathBuf, argv: &[String]) {
     }
 }
 
+/// The directory the config file is in, where everything else the refbox keeps is stored too
+fn config_dir_of(config_path: &std::path::Path) -> PathBuf {
+    config_path
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .unwrap_or_else(std::env::temp_dir)
+}
+
 fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let args = Cli::parse();
 
@@ -468,6 +548,39 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    if let Some(path) = &args.replay_session {
+        info!("Replaying the session in {path:?}");
+        let session = tournament_manager::session::load(path)?;
+        let trace =
+            tournament_manager::golden::replay(&session, Duration::from_secs(args.replay_run_on))?;
+        for line in trace {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    if let Some(dir) = &args.export_results {
+        let config_path = confy::get_configuration_file_path(APP_NAME, None)?;
+        let archive = results_archive::load_or_default(&config_dir_of(&config_path))?;
+        let day = match &args.export_day {
+            Some(day) => time::Date::parse(
+                day,
+                time::macros::format_description!("[year]-[month]-[day]"),
+            )?,
+            None => match archive.latest_day() {
+                Some(day) => day,
+                None => {
+                    println!("No finished games have been archived yet");
+                    return Ok(());
+                }
+            },
+        };
+        for path in archive.export_day(day, dir)? {
+            println!("{}", path.display());
+        }
+        return Ok(());
+    }
+
     if args.is_simulator {
         let flags = sim_app::SimRefBoxAppFlags {
             tcp_port: args.binary_port,
@@ -603,10 +716,27 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     // The portal retry queue lives next to the config file. `config_path` is
     // the file itself (see above where it was loaded), so its parent is the
     // directory we want.
-    let config_dir = config_path
-        .parent()
-        .map(std::path::Path::to_path_buf)
-        .unwrap_or_else(std::env::temp_dir);
+    let config_dir = config_dir_of(&config_path);
+
+    // The rule profile is loaded once here and handed to the app, which passes it on to the
+    // tournament manager along with the rest of the game settings
+    let rules = match &config.rule_profile {
+        Some(profile_path) => {
+            let profile_path = config_dir.join(profile_path);
+            match rule_profile::RuleProfile::load(&profile_path) {
+                Ok(rules) => {
+                    info!("Using rule profile {:?} from {profile_path:?}", rules.name);
+                    rules
+                }
+                Err(e) => {
+                    error!("Failed to load rule profile {profile_path:?}: {e}");
+                    warn!("Using the built-in rules");
+                    rule_profile::RuleProfile::default()
+                }
+            }
+        }
+        None => rule_profile::RuleProfile::default(),
+    };
 
     // Canonical path of the running binary, captured BEFORE any self-update swap.
     // The updater swaps the new binary in at this path and main() respawns it.
@@ -658,10 +788,12 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let mode = config.mode;
     let flags = app::RefBoxAppFlags {
         config,
+        rules,
         config_dir,
         serial_ports,
         binary_port: args.binary_port,
         json_port: args.json_port,
+        websocket_port: args.websocket_port,
         sim_children: child.into_iter().collect(),
         sim_spawn_config,
         require_https: !args.allow_http,
@@ -671,6 +803,14 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         install_path: install_path.clone(),
         restart_argv: restart_argv.clone(),
         show_rolled_back,
+        record_session: args.record_session.clone(),
+        status_api: args
+            .status_port
+            .map(|port| app::status_api::StatusApiConfig {
+                bind: args.status_bind,
+                port,
+                key: std::env::var(app::status_api::KEY_VAR).ok(),
+            }),
     };
 
     // Roboto covers Latin scripts. The CJK subset covers Japanese, Korean, and Chinese
@@ -808,10 +948,58 @@ mod restart_argv_tests {
         assert!(!argv_from(&[]).contains(&"--log-location".to_string()));
     }
 
+    #[test]
+    fn replays_the_status_api_only_when_it_is_on() {
+        let argv = argv_from(&["--status-port", "8080", "--status-bind", "0.0.0.0"]);
+        for arg in ["--status-port", "8080", "--status-bind", "0.0.0.0"] {
+            assert!(
+                argv.contains(&arg.to_string()),
+                "{arg} missing from {argv:?}"
+            );
+        }
+        let argv = argv_from(&[]);
+        assert!(!argv.contains(&"--status-port".to_string()));
+        assert!(!argv.contains(&"--status-bind".to_string()));
+    }
+
+    #[test]
+    fn replays_the_websocket_port() {
+        let argv = argv_from(&["--websocket-port", "9002"]);
+        let at = argv.iter().position(|a| a == "--websocket-port").unwrap();
+        assert_eq!(argv[at + 1], "9002");
+    }
+
     #[test]
     fn never_replays_self_check() {
         assert!(!argv_from(&["--self-check"]).contains(&"--self-check".to_string()));
     }
+
+    #[test]
+    fn keeps_recording_but_never_replays_a_session_file() {
+        let argv = argv_from(&["--record-session", "/tmp/sessions"]);
+        assert!(argv.contains(&"--record-session".to_string()));
+        assert!(argv.contains(&"/tmp/sessions".to_string()));
+        let argv = argv_from(&[
+            "--replay-session",
+            "/tmp/sessions/session.jsonl",
+            "--replay-run-on",
+            "30",
+        ]);
+        assert!(!argv.contains(&"--replay-session".to_string()));
+        assert!(!argv.contains(&"--replay-run-on".to_string()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_a_session_directory_that_is_not_utf8() {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+        let argv = [
+            OsString::from("refbox"),
+            OsString::from("--record-session"),
+            OsString::from_vec(b"/tmp/\xff".to_vec()),
+        ];
+        assert!(Cli::try_parse_from(argv).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::tournament_manager::{
     TournamentManager, TournamentManagerError,
+    action::Action,
     infraction::InfractionDetails,
     penalty::{Penalty, PenaltyKind, PenaltyTimePrintable},
 };
@@ -110,12 +111,14 @@ pub(super) trait Editable<C: ColorIndex>: Debug + PartialEq + Eq + Clone {
         index: usize,
         new_color: C,
         new_item: Self,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError>;
 
     fn delete_in_tm(
         tm: &mut TmGuard,
         color: C,
         index: usize,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError>;
 
     fn add_to_tm(
@@ -363,8 +366,10 @@ where
 
         for (origin, pen, new_color, action) in modified_pens.into_iter().rev() {
             match action {
-                Action::Edit => T::edit_in_tm(&mut tm, origin.color, origin.index, new_color, pen)?,
-                Action::Delete => T::delete_in_tm(&mut tm, origin.color, origin.index)?,
+                Action::Edit => {
+                    T::edit_in_tm(&mut tm, origin.color, origin.index, new_color, pen, now)?
+                }
+                Action::Delete => T::delete_in_tm(&mut tm, origin.color, origin.index, now)?,
             }
         }
 
@@ -498,14 +503,18 @@ impl Editable<Color> for Penalty {
         index: usize,
         new_color: Color,
         new_item: Self,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.edit_penalty(
-            old_color,
-            index,
-            new_color,
-            new_item.player_number,
-            new_item.kind,
-            new_item.infraction,
+        tm.apply(
+            Action::EditPenalty {
+                old_color,
+                index,
+                new_color,
+                player_number: new_item.player_number,
+                kind: new_item.kind,
+                infraction: new_item.infraction,
+            },
+            now,
         )
     }
 
@@ -513,8 +522,9 @@ impl Editable<Color> for Penalty {
         tm: &mut TmGuard,
         color: Color,
         index: usize,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.delete_penalty(color, index)
+        tm.apply(Action::DeletePenalty(color, index), now)
     }
 
     fn add_to_tm(
@@ -525,7 +535,10 @@ impl Editable<Color> for Penalty {
         now: Instant,
         infraction: Infraction,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.start_penalty(color, player_number, kind, now, infraction)
+        tm.apply(
+            Action::StartPenalty(color, player_number, kind, infraction),
+            now,
+        )
     }
 
     fn limit_list_len(
@@ -534,7 +547,13 @@ impl Editable<Color> for Penalty {
         limit: usize,
         now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.limit_pen_list_len(color, limit, now)
+        // The action drops what finished penalties it can and doesn't fail when that isn't
+        // enough, which is reported here instead
+        tm.apply(Action::LimitPenalties(color, limit), now)?;
+        if tm.get_penalties()[color].len() > limit {
+            return Err(TournamentManagerError::TooManyPenalties(limit));
+        }
+        Ok(())
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -597,13 +616,17 @@ impl Editable<Color> for InfractionDetails {
         index: usize,
         new_color: Color,
         new_item: Self,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.edit_warning(
-            old_color,
-            index,
-            new_color,
-            new_item.player_number,
-            new_item.infraction,
+        tm.apply(
+            Action::EditWarning {
+                old_color,
+                index,
+                new_color,
+                player_number: new_item.player_number,
+                infraction: new_item.infraction,
+            },
+            now,
         )
     }
 
@@ -611,8 +634,9 @@ impl Editable<Color> for InfractionDetails {
         tm: &mut TmGuard,
         color: Color,
         index: usize,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.delete_warning(color, index)
+        tm.apply(Action::DeleteWarning(color, index), now)
     }
 
     fn add_to_tm(
@@ -623,7 +647,7 @@ impl Editable<Color> for InfractionDetails {
         now: Instant,
         infraction: Infraction,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.add_warning(color, player_number, infraction, now)
+        tm.apply(Action::AddWarning(color, player_number, infraction), now)
     }
 
     fn limit_list_len(
@@ -692,13 +716,17 @@ impl Editable<Option<Color>> for InfractionDetails {
         index: usize,
         new_color: Option<Color>,
         new_item: Self,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.edit_foul(
-            old_color,
-            index,
-            new_color,
-            new_item.player_number,
-            new_item.infraction,
+        tm.apply(
+            Action::EditFoul {
+                old_color,
+                index,
+                new_color,
+                player_number: new_item.player_number,
+                infraction: new_item.infraction,
+            },
+            now,
         )
     }
 
@@ -706,8 +734,9 @@ impl Editable<Option<Color>> for InfractionDetails {
         tm: &mut TmGuard,
         color: Option<Color>,
         index: usize,
+        now: Instant,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.delete_foul(color, index)
+        tm.apply(Action::DeleteFoul(color, index), now)
     }
 
     fn add_to_tm(
@@ -718,7 +747,7 @@ impl Editable<Option<Color>> for InfractionDetails {
         now: Instant,
         infraction: Infraction,
     ) -> std::result::Result<(), TournamentManagerError> {
-        tm.add_foul(color, player_number, infraction, now)
+        tm.apply(Action::AddFoul(color, player_number, infraction), now)
     }
 
     fn limit_list_len(
@@ -850,25 +879,30 @@ mod test {
         let mut pen_edit = ListEditor::<Penalty, Color>::new(tm.clone());
 
         let b_pen = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 3,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let w_pen = Penalty {
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             player_number: 13,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         assert_eq!(
-            pen_edit.add_item(Color::Black, 4, PenaltyKind::OneMinute, Infraction::Unknown),
+            pen_edit.add_item(
+                Color::Black,
+                4,
+                PenaltyKind::ONE_MINUTE,
+                Infraction::UNKNOWN
+            ),
             Err(PenaltyEditorError::NotInSession)
         );
 
@@ -933,21 +967,21 @@ mod test {
         now += Duration::from_secs(5);
 
         let b_pen_0 = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 7,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let w_pen_0 = Penalty {
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             player_number: 4,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let b_pen_1 = Penalty {
@@ -956,16 +990,16 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let w_pen_1 = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 6,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         tm.start_penalty(
@@ -1070,9 +1104,9 @@ mod test {
             .black
             .push(EditableItem::Edited(b_origin(1), b_pen_1.clone()));
         pen_edit.items.black.push(EditableItem::New(
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             9,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         ));
 
         pen_edit
@@ -1080,9 +1114,9 @@ mod test {
             .white
             .push(EditableItem::Edited(w_origin(1), w_pen_1.clone()));
         pen_edit.items.white.push(EditableItem::New(
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             3,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         ));
 
         pen_edit.delete_item(Color::Black, 1).unwrap();
@@ -1142,30 +1176,30 @@ mod test {
         now += Duration::from_secs(5);
 
         let b_pen_0 = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 7,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let b_pen_0_ed = Penalty {
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             ..b_pen_0
         };
 
         let w_pen_0 = Penalty {
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             player_number: 4,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let w_pen_0_ed = Penalty {
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             ..w_pen_0
         };
 
@@ -1175,53 +1209,53 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let b_pen_1_ed = Penalty {
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             ..b_pen_1
         };
 
         let w_pen_1 = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 6,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         let w_pen_1_ed = Penalty {
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             ..w_pen_1
         };
 
         let b_pen_2 = Penalty {
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             player_number: 1,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::GrabbingTheBarrier,
+            infraction: Infraction::GRABBING_THE_BARRIER,
         };
 
         let b_pen_2_ed = Penalty {
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             ..b_pen_2
         };
 
         let w_pen_2 = Penalty {
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             player_number: 8,
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalAdvancement,
+            infraction: Infraction::ILLEGAL_ADVANCEMENT,
         };
 
         let w_pen_2_ed = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 9,
             ..w_pen_2
         };
@@ -1268,8 +1302,8 @@ mod test {
                 0,
                 Color::Black,
                 2,
-                PenaltyKind::OneMinute,
-                Infraction::Unknown
+                PenaltyKind::ONE_MINUTE,
+                Infraction::UNKNOWN
             ),
             Err(PenaltyEditorError::NotInSession)
         );
@@ -1385,12 +1419,12 @@ mod test {
         pen_edit.items.black.push(EditableItem::New(
             PenaltyKind::TotalDismissal,
             15,
-            Infraction::IllegalSubstitution,
+            Infraction::ILLEGAL_SUBSTITUTION,
         ));
         pen_edit.items.white.push(EditableItem::New(
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             2,
-            Infraction::IllegallyStoppingThePuck,
+            Infraction::ILLEGALLY_STOPPING_THE_PUCK,
         ));
 
         pen_edit
@@ -1421,7 +1455,7 @@ mod test {
                 Color::Black,
                 14,
                 PenaltyKind::TotalDismissal,
-                Infraction::IllegalSubstitution,
+                Infraction::ILLEGAL_SUBSTITUTION,
             )
             .unwrap();
         pen_edit
@@ -1430,8 +1464,8 @@ mod test {
                 3,
                 Color::White,
                 3,
-                PenaltyKind::FiveMinute,
-                Infraction::IllegallyStoppingThePuck,
+                PenaltyKind::FIVE_MINUTE,
+                Infraction::ILLEGALLY_STOPPING_THE_PUCK,
             )
             .unwrap();
 
@@ -1444,7 +1478,7 @@ mod test {
                 EditableItem::New(
                     PenaltyKind::TotalDismissal,
                     14,
-                    Infraction::IllegalSubstitution
+                    Infraction::ILLEGAL_SUBSTITUTION
                 )
             ]
         );
@@ -1455,9 +1489,9 @@ mod test {
                 EditableItem::Edited(b_origin(1), b_pen_1_ed.clone()),
                 EditableItem::Edited(w_origin(2), w_pen_2_ed),
                 EditableItem::New(
-                    PenaltyKind::FiveMinute,
+                    PenaltyKind::FIVE_MINUTE,
                     3,
-                    Infraction::IllegallyStoppingThePuck
+                    Infraction::ILLEGALLY_STOPPING_THE_PUCK
                 )
             ]
         );
@@ -1499,7 +1533,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let w_warn = InfractionDetails {
@@ -1507,11 +1541,11 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         assert_eq!(
-            warn_edit.add_item(Color::Black, Some(4), (), Infraction::Unknown),
+            warn_edit.add_item(Color::Black, Some(4), (), Infraction::UNKNOWN),
             Err(PenaltyEditorError::NotInSession)
         );
 
@@ -1568,7 +1602,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let w_warn_0 = InfractionDetails {
@@ -1576,7 +1610,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let b_warn_1 = InfractionDetails {
@@ -1584,7 +1618,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let w_warn_1 = InfractionDetails {
@@ -1592,7 +1626,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         tm.add_warning(
@@ -1689,7 +1723,7 @@ mod test {
         warn_edit
             .items
             .black
-            .push(EditableItem::New((), Some(9), Infraction::Unknown));
+            .push(EditableItem::New((), Some(9), Infraction::UNKNOWN));
         warn_edit
             .items
             .white
@@ -1697,7 +1731,7 @@ mod test {
         warn_edit
             .items
             .white
-            .push(EditableItem::New((), Some(3), Infraction::Unknown));
+            .push(EditableItem::New((), Some(3), Infraction::UNKNOWN));
 
         warn_edit.delete_item(Color::Black, 1).unwrap();
         warn_edit.delete_item(Color::Black, 2).unwrap();
@@ -1760,7 +1794,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let b_warn_0_ed = InfractionDetails {
@@ -1773,7 +1807,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let w_warn_0_ed = InfractionDetails {
@@ -1786,7 +1820,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let b_warn_1_ed = InfractionDetails {
@@ -1799,7 +1833,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         let w_warn_1_ed = InfractionDetails {
@@ -1812,7 +1846,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::GrabbingTheBarrier,
+            infraction: Infraction::GRABBING_THE_BARRIER,
         };
 
         let b_warn_2_ed = InfractionDetails {
@@ -1825,7 +1859,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalAdvancement,
+            infraction: Infraction::ILLEGAL_ADVANCEMENT,
         };
 
         let w_warn_2_ed = InfractionDetails {
@@ -1872,7 +1906,7 @@ mod test {
                 Color::Black,
                 Some(2),
                 (),
-                Infraction::Unknown
+                Infraction::UNKNOWN
             ),
             Err(PenaltyEditorError::NotInSession)
         );
@@ -1988,11 +2022,11 @@ mod test {
         warn_edit
             .items
             .black
-            .push(EditableItem::New((), Some(15), Infraction::Unknown));
+            .push(EditableItem::New((), Some(15), Infraction::UNKNOWN));
         warn_edit
             .items
             .white
-            .push(EditableItem::New((), Some(3), Infraction::Unknown));
+            .push(EditableItem::New((), Some(3), Infraction::UNKNOWN));
 
         warn_edit
             .edit_item(
@@ -2022,7 +2056,7 @@ mod test {
                 Color::Black,
                 Some(14),
                 (),
-                Infraction::IllegalSubstitution,
+                Infraction::ILLEGAL_SUBSTITUTION,
             )
             .unwrap();
         warn_edit
@@ -2032,7 +2066,7 @@ mod test {
                 Color::White,
                 Some(3),
                 (),
-                Infraction::IllegallyStoppingThePuck,
+                Infraction::ILLEGALLY_STOPPING_THE_PUCK,
             )
             .unwrap();
 
@@ -2042,7 +2076,7 @@ mod test {
                 EditableItem::Edited(b_origin(0), b_warn_0_ed.clone()),
                 EditableItem::Edited(w_origin(1), w_warn_1_ed.clone()),
                 EditableItem::Edited(b_origin(2), b_warn_2_ed),
-                EditableItem::New((), Some(14), Infraction::IllegalSubstitution)
+                EditableItem::New((), Some(14), Infraction::ILLEGAL_SUBSTITUTION)
             ]
         );
         assert_eq!(
@@ -2051,7 +2085,7 @@ mod test {
                 EditableItem::Edited(w_origin(0), w_warn_0_ed.clone()),
                 EditableItem::Edited(b_origin(1), b_warn_1_ed.clone()),
                 EditableItem::Edited(w_origin(2), w_warn_2_ed),
-                EditableItem::New((), Some(3), Infraction::IllegallyStoppingThePuck)
+                EditableItem::New((), Some(3), Infraction::ILLEGALLY_STOPPING_THE_PUCK)
             ]
         );
 
@@ -2074,14 +2108,15 @@ mod test {
         // Test applying changes with a warning that has no player number
         warn_edit.start_session().unwrap();
 
-        warn_edit
-            .items
-            .black
-            .push(EditableItem::New((), None, Infraction::IllegalSubstitution));
+        warn_edit.items.black.push(EditableItem::New(
+            (),
+            None,
+            Infraction::ILLEGAL_SUBSTITUTION,
+        ));
         warn_edit.items.white.push(EditableItem::New(
             (),
             None,
-            Infraction::IllegallyStoppingThePuck,
+            Infraction::ILLEGALLY_STOPPING_THE_PUCK,
         ));
 
         now += Duration::from_secs(20);
@@ -2097,7 +2132,7 @@ mod test {
                         start_period: GamePeriod::FirstHalf,
                         start_time: Duration::from_secs(855),
                         start_instant: now,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     }
                 ],
                 white: vec![
@@ -2108,7 +2143,7 @@ mod test {
                         start_period: GamePeriod::FirstHalf,
                         start_time: Duration::from_secs(855),
                         start_instant: now,
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     }
                 ],
             }
@@ -2135,7 +2170,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let e_foul = InfractionDetails {
@@ -2143,7 +2178,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::IllegalSubstitution,
+            infraction: Infraction::ILLEGAL_SUBSTITUTION,
         };
 
         let w_foul = InfractionDetails {
@@ -2151,11 +2186,11 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(880),
             start_instant: apply_time,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         assert_eq!(
-            foul_edit.add_item(Some(Color::Black), Some(4), (), Infraction::Unknown),
+            foul_edit.add_item(Some(Color::Black), Some(4), (), Infraction::UNKNOWN),
             Err(PenaltyEditorError::NotInSession)
         );
 
@@ -2235,7 +2270,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let e_foul_0 = InfractionDetails {
@@ -2243,7 +2278,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalSubstitution,
+            infraction: Infraction::ILLEGAL_SUBSTITUTION,
         };
 
         let w_foul_0 = InfractionDetails {
@@ -2251,7 +2286,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let b_foul_1 = InfractionDetails {
@@ -2259,7 +2294,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let e_foul_1 = InfractionDetails {
@@ -2267,7 +2302,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalAdvancement,
+            infraction: Infraction::ILLEGAL_ADVANCEMENT,
         };
 
         let w_foul_1 = InfractionDetails {
@@ -2275,7 +2310,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         tm.add_foul(
@@ -2402,7 +2437,7 @@ mod test {
         foul_edit
             .items
             .black
-            .push(EditableItem::New((), Some(9), Infraction::Unknown));
+            .push(EditableItem::New((), Some(9), Infraction::UNKNOWN));
         foul_edit
             .items
             .equal
@@ -2410,7 +2445,7 @@ mod test {
         foul_edit
             .items
             .equal
-            .push(EditableItem::New((), Some(3), Infraction::Unknown));
+            .push(EditableItem::New((), Some(3), Infraction::UNKNOWN));
         foul_edit
             .items
             .white
@@ -2418,7 +2453,7 @@ mod test {
         foul_edit
             .items
             .white
-            .push(EditableItem::New((), Some(5), Infraction::Unknown));
+            .push(EditableItem::New((), Some(5), Infraction::UNKNOWN));
 
         foul_edit.delete_item(Some(Color::Black), 1).unwrap();
         foul_edit.delete_item(Some(Color::Black), 2).unwrap();
@@ -2502,7 +2537,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         let b_foul_0_ed = InfractionDetails {
@@ -2515,7 +2550,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalSubstitution,
+            infraction: Infraction::ILLEGAL_SUBSTITUTION,
         };
 
         let e_foul_0_ed = InfractionDetails {
@@ -2523,7 +2558,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let w_foul_0 = InfractionDetails {
@@ -2531,7 +2566,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::DelayOfGame,
+            infraction: Infraction::DELAY_OF_GAME,
         };
 
         let w_foul_0_ed = InfractionDetails {
@@ -2544,7 +2579,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FalseStart,
+            infraction: Infraction::FALSE_START,
         };
 
         let b_foul_1_ed = InfractionDetails {
@@ -2557,7 +2592,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::IllegalAdvancement,
+            infraction: Infraction::ILLEGAL_ADVANCEMENT,
         };
 
         let e_foul_1_ed = InfractionDetails {
@@ -2565,7 +2600,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         let w_foul_1 = InfractionDetails {
@@ -2573,7 +2608,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(895),
             start_instant: now,
-            infraction: Infraction::FreeArm,
+            infraction: Infraction::FREE_ARM,
         };
 
         let w_foul_1_ed = InfractionDetails {
@@ -2624,7 +2659,7 @@ mod test {
                 Some(Color::Black),
                 Some(2),
                 (),
-                Infraction::Unknown
+                Infraction::UNKNOWN
             ),
             Err(PenaltyEditorError::NotInSession)
         );
@@ -2788,15 +2823,16 @@ mod test {
         foul_edit
             .items
             .black
-            .push(EditableItem::New((), Some(15), Infraction::Unknown));
-        foul_edit
-            .items
-            .equal
-            .push(EditableItem::New((), None, Infraction::IllegalSubstitution));
+            .push(EditableItem::New((), Some(15), Infraction::UNKNOWN));
+        foul_edit.items.equal.push(EditableItem::New(
+            (),
+            None,
+            Infraction::ILLEGAL_SUBSTITUTION,
+        ));
         foul_edit
             .items
             .white
-            .push(EditableItem::New((), Some(3), Infraction::Unknown));
+            .push(EditableItem::New((), Some(3), Infraction::UNKNOWN));
 
         foul_edit
             .edit_item(
@@ -2836,7 +2872,7 @@ mod test {
                 Some(Color::Black),
                 Some(14),
                 (),
-                Infraction::IllegalSubstitution,
+                Infraction::ILLEGAL_SUBSTITUTION,
             )
             .unwrap();
         foul_edit
@@ -2846,7 +2882,7 @@ mod test {
                 None,
                 None,
                 (),
-                Infraction::IllegallyStoppingThePuck,
+                Infraction::ILLEGALLY_STOPPING_THE_PUCK,
             )
             .unwrap();
         foul_edit
@@ -2856,7 +2892,7 @@ mod test {
                 Some(Color::White),
                 Some(3),
                 (),
-                Infraction::IllegallyStoppingThePuck,
+                Infraction::ILLEGALLY_STOPPING_THE_PUCK,
             )
             .unwrap();
 
@@ -2866,7 +2902,7 @@ mod test {
                 EditableItem::Edited(b_o_origin(0), b_foul_0_ed.clone()),
                 EditableItem::Edited(w_o_origin(1), w_foul_1_ed.clone()),
                 EditableItem::Edited(b_o_origin(2), b_foul_0_ed.clone()),
-                EditableItem::New((), Some(14), Infraction::IllegalSubstitution)
+                EditableItem::New((), Some(14), Infraction::ILLEGAL_SUBSTITUTION)
             ]
         );
         assert_eq!(
@@ -2875,7 +2911,7 @@ mod test {
                 EditableItem::Edited(e_o_origin(0), e_foul_0_ed.clone()),
                 EditableItem::Edited(b_o_origin(1), b_foul_1_ed.clone()),
                 EditableItem::Edited(e_o_origin(2), e_foul_0_ed.clone()),
-                EditableItem::New((), None, Infraction::IllegallyStoppingThePuck)
+                EditableItem::New((), None, Infraction::ILLEGALLY_STOPPING_THE_PUCK)
             ]
         );
         assert_eq!(
@@ -2884,7 +2920,7 @@ mod test {
                 EditableItem::Edited(w_o_origin(0), w_foul_0_ed.clone()),
                 EditableItem::Edited(e_o_origin(1), e_foul_1_ed.clone()),
                 EditableItem::Edited(w_o_origin(2), w_foul_0_ed.clone()),
-                EditableItem::New((), Some(3), Infraction::IllegallyStoppingThePuck)
+                EditableItem::New((), Some(3), Infraction::ILLEGALLY_STOPPING_THE_PUCK)
             ]
         );
 
@@ -2911,18 +2947,21 @@ mod test {
         // Test applying changes with a foul that has no player number
         foul_edit.start_session().unwrap();
 
-        foul_edit
-            .items
-            .black
-            .push(EditableItem::New((), None, Infraction::IllegalSubstitution));
-        foul_edit
-            .items
-            .equal
-            .push(EditableItem::New((), None, Infraction::IllegalSubstitution));
-        foul_edit
-            .items
-            .white
-            .push(EditableItem::New((), None, Infraction::IllegalSubstitution));
+        foul_edit.items.black.push(EditableItem::New(
+            (),
+            None,
+            Infraction::ILLEGAL_SUBSTITUTION,
+        ));
+        foul_edit.items.equal.push(EditableItem::New(
+            (),
+            None,
+            Infraction::ILLEGAL_SUBSTITUTION,
+        ));
+        foul_edit.items.white.push(EditableItem::New(
+            (),
+            None,
+            Infraction::ILLEGAL_SUBSTITUTION,
+        ));
 
         now += Duration::from_secs(20);
         foul_edit.apply_changes(now).unwrap();
@@ -2938,7 +2977,7 @@ mod test {
                         start_period: GamePeriod::FirstHalf,
                         start_time: Duration::from_secs(855),
                         start_instant: now,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     }
                 ],
                 equal: vec![
@@ -2949,7 +2988,7 @@ mod test {
                         start_period: GamePeriod::FirstHalf,
                         start_time: Duration::from_secs(855),
                         start_instant: now,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     }
                 ],
                 white: vec![
@@ -2960,7 +2999,7 @@ mod test {
                         start_period: GamePeriod::FirstHalf,
                         start_time: Duration::from_secs(855),
                         start_instant: now,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     }
                 ],
             }
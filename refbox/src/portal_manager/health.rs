@@ -100,12 +100,19 @@ pub trait PortalTaskIo {
 
 #[derive(Debug)]
 pub enum PortalCallError {
-    /// The portal responded, but with a non-success status. From the
-    /// current uwh-common API we cannot tell a conflict (409), token
-    /// rejection (401), or server error (5xx) apart — they all collapse to
-    /// this one variant. See ADR 011 amendment (2026-04-21) for the
-    /// rationale.
+    /// The portal responded and refused the request for a reason that is
+    /// not the login: a conflict, a payload it will not take, an unknown
+    /// event, or a body the client could not read.
     Failed(String),
+    /// The portal refused the access key (`401`), or the key does not cover
+    /// the call (`403`). Logging in again is what fixes this one, so it is
+    /// the only failure that reports the token as bad.
+    TokenRejected(String),
+    /// The portal answered but could not serve the request right now: a
+    /// server error (`5xx`) or a rate limit (`429`) that outlasted the
+    /// client's own retries. Like `Unreachable`, this says nothing about the
+    /// login.
+    Unavailable(String),
     /// The portal could not be reached at all: the HTTP exchange never
     /// completed (DNS, connect, timeout, TLS, or a dropped response). The
     /// login may be perfectly valid — this is a connectivity problem, not a
@@ -135,6 +142,8 @@ impl std::fmt::Display for PortalCallError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Failed(msg) => write!(f, "portal call failed: {msg}"),
+            Self::TokenRejected(msg) => write!(f, "portal refused the login: {msg}"),
+            Self::Unavailable(msg) => write!(f, "portal unavailable: {msg}"),
             Self::Unreachable(msg) => write!(f, "portal unreachable: {msg}"),
             Self::NotSent(msg) => write!(f, "portal request was never sent: {msg}"),
         }
@@ -171,7 +180,10 @@ async fn run_task(
                 let now = OffsetDateTime::now_utc();
                 for idx in 0..queue_snapshot.items.len() {
                     let item = &queue_snapshot.items[idx];
-                    if item.score_sent || !is_item_retry_eligible(item, now) {
+                    if item.score_sent
+                        || item.review_sent
+                        || !is_item_retry_eligible(item, now)
+                    {
                         continue;
                     }
                     if attempt_item(&io, item, &event_tx).await {
@@ -254,10 +266,22 @@ async fn run_task(
                             current_health = HealthState::Red;
                             let _ = event_tx.send(PortalEvent::TokenUnreachable).await;
                         }
-                        Err(PortalCallError::Failed(_)) => {
-                            // The portal responded but rejected the request (or
-                            // returned a server error). Treat as a token
-                            // problem so the operator is prompted to re-login.
+                        Err(PortalCallError::Unavailable(e)) => {
+                            // The portal is up but cannot serve the check, so
+                            // degrade as an outage does. A busy or broken
+                            // portal is no reason to push a re-login, since a
+                            // fresh login would be refused by it just the same.
+                            // Logged on every check, for the same reason as the
+                            // unreachable arm.
+                            log::warn!("portal health check found the portal unavailable: {e}");
+                            current_health = HealthState::Red;
+                            not_sent_logged = false;
+                            let _ = event_tx.send(PortalEvent::TokenUnreachable).await;
+                        }
+                        Err(PortalCallError::TokenRejected(_) | PortalCallError::Failed(_)) => {
+                            // The portal responded but refused the check. Treat
+                            // as a token problem so the operator is prompted to
+                            // re-login.
                             current_health = HealthState::Red;
                             not_sent_logged = false;
                             let _ = event_tx.send(PortalEvent::TokenStatus(false)).await;
@@ -403,6 +427,8 @@ mod tests {
             last_attempt_at: None,
             force: false,
             score_sent: false,
+            under_review: false,
+            review_sent: false,
         }
     }
 
@@ -600,6 +626,46 @@ mod tests {
         drop(handle);
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn unavailable_verify_emits_token_unreachable_not_token_status() {
+        // A portal answering 503 or 429 is up but busy; the login is not in
+        // question, so this must degrade like an outage and never read as
+        // "login expired".
+        let io = FakeIo {
+            verify_results: Mutex::new(vec![Err(PortalCallError::Unavailable(
+                "server error (503): ".into(),
+            ))]),
+            verify_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
+            scores_results: Mutex::new(vec![]),
+            scores_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
+            stats_results: Mutex::new(vec![]),
+            stats_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
+        };
+        let mut handle = spawn(io);
+
+        tokio::task::yield_now().await;
+        tokio::time::advance(Duration::from_secs(3)).await;
+        tokio::task::yield_now().await;
+        tokio::time::advance(Duration::from_millis(500)).await;
+        tokio::task::yield_now().await;
+        tokio::task::yield_now().await;
+
+        let events = drain_events(&mut handle.event_rx);
+        assert!(
+            events
+                .iter()
+                .any(|ev| matches!(ev, PortalEvent::TokenUnreachable)),
+            "unavailable verify must emit TokenUnreachable, got {events:?}"
+        );
+        assert!(
+            !events
+                .iter()
+                .any(|ev| matches!(ev, PortalEvent::TokenStatus(_))),
+            "unavailable verify must NOT emit TokenStatus, got {events:?}"
+        );
+        drop(handle);
+    }
+
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn unsent_verify_emits_token_unreachable_not_token_status() {
         // A verify_token the refbox refused to send is not evidence the login
@@ -648,7 +714,7 @@ mod tests {
         // surface as TokenStatus(false) so the operator is prompted to
         // re-login — and must NOT be confused with an outage.
         let io = FakeIo {
-            verify_results: Mutex::new(vec![Err(PortalCallError::Failed("401".into()))]),
+            verify_results: Mutex::new(vec![Err(PortalCallError::TokenRejected("401".into()))]),
             verify_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
             scores_results: Mutex::new(vec![]),
             scores_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
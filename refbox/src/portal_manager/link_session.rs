@@ -6,16 +6,13 @@
 //! dormant. See
 //! `docs/superpowers/specs/2026-06-22-portal-link-restore-across-restart-design.md`.
 //!
-//! Mirrors the atomic-write + tolerant-load pattern of `queue.rs`.
+//! Kept as a [`state_file`].
 
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::io::Write;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use time::OffsetDateTime;
-use time::macros::format_description;
 
-use crate::config::Mode;
+use crate::{config::Mode, state_file};
 use uwh_common::uwhportal::schedule::{EventId, GameNumber};
 
 /// How recent the last session must be to auto-restore the link on startup,
@@ -25,8 +22,7 @@ use uwh_common::uwhportal::schedule::{EventId, GameNumber};
 /// wrong boot clock never discards a recent link.
 pub const FRESHNESS_WINDOW: time::Duration = time::Duration::hours(120);
 
-const FILE_NAME: &str = "portal_link.json";
-const TMP_FILE_NAME: &str = "portal_link.json.tmp";
+const FILE_STEM: &str = "portal_link";
 
 /// The remembered live portal link, persisted next to `portal_queue.json`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -44,72 +40,26 @@ impl LinkSessionFile {
     pub const CURRENT_VERSION: u32 = 1;
 }
 
-fn file_path(dir: &Path) -> PathBuf {
-    dir.join(FILE_NAME)
-}
-
-fn tmp_path(dir: &Path) -> PathBuf {
-    dir.join(TMP_FILE_NAME)
-}
-
 /// Load the note. Missing → `None`. Present but unparseable or of an
-/// unknown version → rename to `portal_link.corrupt.<ts>.json`, log, and
-/// return `None`. Never blocks startup.
+/// unknown version → renamed aside and `None`, see [`state_file::load`].
+/// Never blocks startup.
 pub fn load_or_none(dir: &Path) -> std::io::Result<Option<LinkSessionFile>> {
-    let path = file_path(dir);
-    if !path.exists() {
-        return Ok(None);
-    }
-    let bytes = fs::read(&path)?;
-    match serde_json::from_slice::<LinkSessionFile>(&bytes) {
-        Ok(note) if note.version == LinkSessionFile::CURRENT_VERSION => Ok(Some(note)),
-        Ok(note) => {
-            log::error!(
-                "portal_link.json has unknown version {}; renaming and ignoring",
-                note.version
-            );
-            rename_corrupt(&path)?;
-            Ok(None)
-        }
-        Err(e) => {
-            log::error!("portal_link.json failed to parse ({e}); renaming and ignoring");
-            rename_corrupt(&path)?;
-            Ok(None)
-        }
-    }
-}
-
-fn rename_corrupt(path: &Path) -> std::io::Result<()> {
-    // Format: YYYYMMDDTHHMMSSZ, e.g. "20260622T142203Z".
-    let fmt = format_description!("[year][month][day]T[hour][minute][second]Z");
-    let ts = OffsetDateTime::now_utc()
-        .format(&fmt)
-        .unwrap_or_else(|_| "unknown-time".to_string());
-    let mut new_path = path.to_path_buf();
-    new_path.set_file_name(format!("portal_link.corrupt.{ts}.json"));
-    fs::rename(path, &new_path)
+    state_file::load(
+        dir,
+        FILE_STEM,
+        LinkSessionFile::CURRENT_VERSION,
+        |note: &LinkSessionFile| note.version,
+    )
 }
 
-/// Atomically write the note: temp file → fsync → rename over target.
+/// Atomically write the note.
 pub fn save(dir: &Path, note: &LinkSessionFile) -> std::io::Result<()> {
-    let tmp = tmp_path(dir);
-    {
-        let mut f = fs::File::create(&tmp)?;
-        serde_json::to_writer(&f, note).map_err(std::io::Error::other)?;
-        f.flush()?;
-        f.sync_all()?;
-    }
-    fs::rename(&tmp, file_path(dir))?;
-    Ok(())
+    state_file::save(dir, FILE_STEM, note)
 }
 
 /// Remove the note. A missing file is treated as success.
 pub fn delete(dir: &Path) -> std::io::Result<()> {
-    match fs::remove_file(file_path(dir)) {
-        Ok(()) => Ok(()),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
-        Err(e) => Err(e),
-    }
+    state_file::delete(dir, FILE_STEM)
 }
 
 /// True iff `now` is within `window` of `last_active` (and not before it).
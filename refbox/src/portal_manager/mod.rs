@@ -13,6 +13,10 @@ use std::collections::VecDeque;
 use std::time::Instant;
 use time::{Duration as TimeDuration, OffsetDateTime};
 use tokio::sync::mpsc;
+use uwh_common::{
+    game_data::{GameDataSource, SourceError},
+    uwhportal::PortalError,
+};
 
 use crate::portal_manager::queue::{QueueFile, QueuedItem};
 
@@ -107,32 +111,49 @@ impl UwhPortalIo {
     }
 }
 
-/// Classify a portal-client error. A `reqwest` transport error means the
-/// HTTP exchange never completed (DNS, connect, timeout, TLS, or a dropped
-/// response): the portal is unreachable, which is a connectivity problem,
-/// NOT a token rejection. A non-OK HTTP response surfaces as uwh-common's
-/// own error type instead and is mapped to `Failed` (could be a 401 token
-/// rejection, a 409 conflict, or a 5xx). Keeping the two apart lets the
-/// indicator avoid mislabelling an ordinary wifi drop as "login expired".
-/// The retry/cadence logic treats both the same (retry later); only the
-/// `verify_token` token-status path acts on the distinction. See ADR 011
-/// amendment (2026-04-21).
-fn classify_error(e: Box<dyn std::error::Error>) -> health::PortalCallError {
-    if let Some(req_err) = e.downcast_ref::<reqwest::Error>() {
-        if let Some(detail) = never_sent(req_err) {
-            return health::PortalCallError::NotSent(detail);
+/// Classify a portal-client error. A transport failure or timeout means the
+/// HTTP exchange never completed: the portal is unreachable, which is a
+/// connectivity problem, NOT a token rejection. A `401` or `403` is the token,
+/// and a `5xx` or `429` the portal saying it cannot serve right now. Anything
+/// else the portal answered is `Failed` (a conflict, a refused payload, an
+/// unknown event). Keeping these apart lets the indicator avoid mislabelling an
+/// ordinary wifi drop or a busy portal as "login expired". The retry/cadence
+/// logic treats them all the same (retry later); only the `verify_token`
+/// token-status path acts on the distinction. See ADR 011 amendments
+/// (2026-04-21 and 2026-10-17).
+fn classify_error(e: PortalError) -> health::PortalCallError {
+    match e {
+        PortalError::NotSent(ref req_err) => health::PortalCallError::NotSent(never_sent(req_err)),
+        PortalError::Network(_) | PortalError::Timeout(_) => {
+            health::PortalCallError::Unreachable(e.to_string())
         }
-        health::PortalCallError::Unreachable(e.to_string())
-    } else {
-        health::PortalCallError::Failed(e.to_string())
+        PortalError::AuthExpired(_) | PortalError::Forbidden(_) => {
+            health::PortalCallError::TokenRejected(e.to_string())
+        }
+        PortalError::ServerError { .. } | PortalError::RateLimited { .. } => {
+            health::PortalCallError::Unavailable(e.to_string())
+        }
+        PortalError::NotFound(_)
+        | PortalError::SchemaMismatch(_)
+        | PortalError::Rejected { .. } => health::PortalCallError::Failed(e.to_string()),
+    }
+}
+
+/// `classify_error` for a call made through `GameDataSource`. Only the portal
+/// client is ever behind this one, so anything else is a plain failure.
+fn classify_source_error(e: SourceError) -> health::PortalCallError {
+    match e {
+        SourceError::Portal(e) => classify_error(e),
+        e => health::PortalCallError::Failed(e.to_string()),
     }
 }
 
-/// Why the request never left the machine, or `None` if it did leave.
+/// Why the request never left the machine.
 ///
 /// A builder-kind `reqwest` error is raised inside `Client::execute` before
-/// any socket is opened. `https_only` refusing a plain-http address is the
-/// case this exists for, but the same kind covers an address with no host and
+/// any socket is opened, and only that kind reaches here. `https_only`
+/// refusing a plain-http address is the case this exists for, but the same
+/// kind covers an address with no host and
 /// a scheme that is not http or https. They are treated together on purpose:
 /// what matters downstream is that nothing reached the network, which is what
 /// separates all of them from a real transport failure.
@@ -142,18 +163,15 @@ fn classify_error(e: Box<dyn std::error::Error>) -> health::PortalCallError {
 /// may since have been repointed at another site — and claiming it would let
 /// the log tell an operator who already passed `--allow-http` to pass
 /// `--allow-http`. The launch-time check owns that advice.
-fn never_sent(e: &reqwest::Error) -> Option<String> {
-    if !e.is_builder() {
-        return None;
-    }
+fn never_sent(e: &reqwest::Error) -> String {
     // An address malformed enough to fail parsing carries no URL to quote, so
     // that case falls back to the error's own text. Keying off the kind alone
     // is what keeps the class intact: the request did not go out either way.
-    Some(match e.url() {
+    match e.url() {
         Some(url) if url.scheme() != "https" => format!("{url} is not an https address"),
         Some(url) => format!("{url} could not be sent as written"),
         None => format!("the address could not be used ({e})"),
-    })
+    }
 }
 
 /// Parse an `event_id` string (from a `QueuedItem`) into an `EventId`.
@@ -232,37 +250,25 @@ impl health::PortalTaskIo for UwhPortalIo {
             black: item.black_score,
             white: item.white_score,
         };
-        let game_number = item.id.game_number.clone();
-        let force = item.force;
-        let fut = {
-            // why this cannot panic: see `verify_token` above.
-            let guard = self
-                .client
-                .lock()
-                .unwrap_or_else(|poisoned| poisoned.into_inner());
-            guard.post_game_scores(&event_id, &game_number, scores, force)
-        };
-        fut.await.map_err(classify_error)
+        // The lock is taken only while the request is built, and a poisoned
+        // one is used regardless, as in `verify_token` above.
+        self.client
+            .submit_scores(&event_id, &item.id.game_number, scores, item.force)
+            .await
+            .map_err(classify_source_error)
     }
 
     async fn post_stats(&self, item: &QueuedItem) -> Result<(), health::PortalCallError> {
         let event_id = parse_event_id(&item.id.event_id)?;
-        let game_number = item.id.game_number.clone();
-        let stats = item.stats.clone();
-        let fut = {
-            // why this cannot panic: see `verify_token` above.
-            let guard = self
-                .client
-                .lock()
-                .unwrap_or_else(|poisoned| poisoned.into_inner());
-            guard.post_game_stats(&event_id, &game_number, stats)
-        };
-        fut.await.map_err(classify_error)
+        self.client
+            .submit_stats(&event_id, &item.id.game_number, item.stats.clone())
+            .await
+            .map_err(classify_source_error)
     }
 }
 
 /// Overall health state of the portal connection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum HealthState {
     /// Last exchange succeeded; queue is empty; token is valid.
     Green,
@@ -273,7 +279,7 @@ pub enum HealthState {
 }
 
 /// Combined state consumed by the time-banner helper.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct PortalIndicatorState {
     pub health: HealthState,
     /// True when the saved login token is known to be expired/rejected.
@@ -308,6 +314,25 @@ pub struct ItemId {
     pub game_number: String,
 }
 
+/// One game result still on the queue, as the status API reports it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueuedResult {
+    #[serde(flatten)]
+    pub id: ItemId,
+    pub black_score: u8,
+    pub white_score: u8,
+    #[serde(with = "time::serde::rfc3339")]
+    pub queued_at: OffsetDateTime,
+    pub attempts: u32,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_attempt_at: Option<OffsetDateTime>,
+    /// The score is in and only the stats are outstanding
+    pub score_sent: bool,
+    /// Unresolved for long enough that it waits on the operator
+    pub stuck: bool,
+    pub under_review: bool,
+}
+
 /// Event emitted by the portal manager's background task for the iced
 /// Subscription to convert into a `Message`.
 #[derive(Debug, Clone)]
@@ -383,6 +408,10 @@ pub enum DetailRow {
     /// stats attempt. No attempt counter: the row is one-shot, so a
     /// counter would wrongly imply background retrying.
     StatsPending { id: ItemId, game_number: String },
+    /// A protested game the portal has accepted, kept for review. Not
+    /// retried and never escalates; tapping marks the result final once
+    /// the protest is settled.
+    UnderReview { id: ItemId, game_number: String },
     /// A recently-completed submission, shown as an informational
     /// green strip. Not tappable.
     RecentSuccess {
@@ -408,7 +437,8 @@ pub const STUCK_THRESHOLD: TimeDuration = TimeDuration::minutes(30);
 pub fn is_item_stuck(item: &QueuedItem, now: OffsetDateTime) -> bool {
     // Stats-pending items (score already accepted) never go stuck: a
     // missing stat must not nag the operator or escalate to red.
-    !item.score_sent && (now - item.queued_at) >= STUCK_THRESHOLD
+    // Nor do results the portal already has that wait on a review.
+    !item.score_sent && !item.review_sent && (now - item.queued_at) >= STUCK_THRESHOLD
 }
 
 /// How long an unresolved item may sit in the queue before it is
@@ -460,6 +490,9 @@ pub struct PortalManager {
     /// newest at the front, capped at `RECENT_SUCCESS_CAP`. Used only
     /// for the detail-page strip; not persisted across restarts.
     recent_successes: VecDeque<RecentSuccess>,
+    /// Bumped by every queue mutation (see `push_queue_snapshot`), so a
+    /// caller can tell the queue changed without copying it
+    queue_generation: u64,
     /// Where this session may write the queue — or `None` when it may not.
     ///
     /// A `QueueStore` can only be obtained by successfully reading a directory
@@ -489,6 +522,7 @@ impl PortalManager {
             indicator_state: PortalIndicatorState::default(),
             command_tx: tx,
             recent_successes: VecDeque::new(),
+            queue_generation: 0,
             // No write target: these managers exist for indicator and
             // row-ordering assertions, none of which persists. A test that
             // needs a save to succeed builds the manager over its own
@@ -565,6 +599,43 @@ impl PortalManager {
         !self.queue.items.is_empty()
     }
 
+    /// Changes whenever what `queued_results` lists does: the queue
+    /// generation, and how many items have gone stuck since, which happens
+    /// with the passing of time and no mutation at all
+    pub fn queue_version(&self) -> (u64, usize) {
+        let now = OffsetDateTime::now_utc();
+        let stuck = self
+            .queue
+            .items
+            .iter()
+            .filter(|it| is_item_stuck(it, now))
+            .count();
+        (self.queue_generation, stuck)
+    }
+
+    /// Every result still on the queue, oldest first
+    pub fn queued_results(&self) -> Vec<QueuedResult> {
+        let now = OffsetDateTime::now_utc();
+        let mut results: Vec<_> = self
+            .queue
+            .items
+            .iter()
+            .map(|it| QueuedResult {
+                id: it.id.clone(),
+                black_score: it.black_score,
+                white_score: it.white_score,
+                queued_at: it.queued_at,
+                attempts: it.attempts,
+                last_attempt_at: it.last_attempt_at,
+                score_sent: it.score_sent,
+                stuck: is_item_stuck(it, now),
+                under_review: it.under_review,
+            })
+            .collect();
+        results.sort_by_key(|r| r.queued_at);
+        results
+    }
+
     /// Recompute the cached indicator state from current inputs.
     /// Called from the iced UI layer:
     /// - on every pure UI-layer tick so the 30-minute stuck-item
@@ -640,7 +711,10 @@ impl PortalManager {
     }
 
     fn has_score_pending_items(&self) -> bool {
-        self.queue.items.iter().any(|it| !it.score_sent)
+        self.queue
+            .items
+            .iter()
+            .any(|it| !it.score_sent && !it.review_sent)
     }
 
     fn needs_attention(&self) -> bool {
@@ -715,6 +789,7 @@ impl PortalManager {
             indicator_state: PortalIndicatorState::default(),
             command_tx,
             recent_successes: VecDeque::new(),
+            queue_generation: 0,
             store: Some(store),
         };
         // Clear any items that already exceeded the expiry limit before this
@@ -798,6 +873,7 @@ impl PortalManager {
             indicator_state: PortalIndicatorState::default(),
             command_tx,
             recent_successes: VecDeque::new(),
+            queue_generation: 0,
             store,
         };
         m.recompute_indicator();
@@ -832,9 +908,17 @@ impl PortalManager {
         self.find(id).is_some_and(|it| it.score_sent)
     }
 
+    /// True iff the queued item exists and is a protested result the
+    /// portal has accepted, waiting on the operator to mark it final.
+    pub fn is_under_review(&self, id: &ItemId) -> bool {
+        self.find(id).is_some_and(|it| it.review_sent)
+    }
+
     /// Send the current queue snapshot to the background task. Called
-    /// after every queue mutation so the task's view stays fresh.
-    fn push_queue_snapshot(&self) {
+    /// after every queue mutation so the task's view stays fresh, which
+    /// also makes it the place the queue generation is bumped.
+    fn push_queue_snapshot(&mut self) {
+        self.queue_generation = self.queue_generation.wrapping_add(1);
         let tx = self.command_tx.clone();
         let snap = self.queue.clone();
         tokio::spawn(async move {
@@ -854,6 +938,10 @@ impl PortalManager {
     /// persist, so results recorded here live only as long as the process. They
     /// still appear on the portal detail page, which is all the operator has to
     /// go on. `persist` logs each skipped write.
+    ///
+    /// `under_review` marks a result a captain protested: once the portal has
+    /// it, it stays queued for review instead of resolving (see
+    /// `on_item_resolved`).
     pub fn enqueue_game_end(
         &mut self,
         event_id: String,
@@ -861,6 +949,7 @@ impl PortalManager {
         black_score: u8,
         white_score: u8,
         stats: String,
+        under_review: bool,
     ) -> std::io::Result<()> {
         let item = QueuedItem {
             id: ItemId {
@@ -875,6 +964,8 @@ impl PortalManager {
             last_attempt_at: None,
             force: false,
             score_sent: false,
+            under_review,
+            review_sent: false,
         };
         self.queue.items.push(item);
         self.persist()?;
@@ -945,7 +1036,9 @@ impl PortalManager {
         // Reset every item; touching a stats-pending item's attempt/queued_at
         // fields is harmless (they are unused while score_sent == true) and
         // keeps this a single pass.
-        for item in &mut self.queue.items {
+        // Results waiting on a review are already sent, so they are left
+        // alone and keep their place in the expiry window.
+        for item in self.queue.items.iter_mut().filter(|it| !it.review_sent) {
             item.attempts = 0;
             item.last_attempt_at = None;
             item.queued_at = now;
@@ -1080,6 +1173,9 @@ impl PortalManager {
     /// (newest first, capped at `RECENT_SUCCESS_CAP`), and persists the
     /// shrunken queue so a restart does not re-send the item.
     ///
+    /// A result under review is not final yet: it stays on the queue,
+    /// marked `review_sent` so it is not sent again, until `mark_final`.
+    ///
     /// I/O errors on the queue save are logged and otherwise ignored:
     /// the in-memory state is already correct, and re-sending a
     /// successfully-posted item would be a worse failure mode than
@@ -1097,6 +1193,20 @@ impl PortalManager {
             return;
         }
 
+        if let Some(item) = self.find_mut(&id)
+            && item.under_review
+        {
+            if !item.review_sent {
+                item.review_sent = true;
+                if let Err(e) = self.persist() {
+                    log::warn!("portal queue save after result sent for review failed: {e}");
+                }
+                self.recompute_indicator();
+                self.push_queue_snapshot();
+            }
+            return;
+        }
+
         // Only record a recent success for items that were actually on
         // the queue at the time of the call. An `on_item_resolved` for
         // an unknown id (e.g. the background task reporting a resolve
@@ -1130,6 +1240,18 @@ impl PortalManager {
         self.push_queue_snapshot();
     }
 
+    /// Operator tapped an under-review row: the protest is settled and the
+    /// result the portal has stands. Resolves the item like any other
+    /// success. A no-op unless the portal already has the result, so a
+    /// protested game can never leave the queue unsent.
+    pub fn mark_final(&mut self, id: ItemId) {
+        match self.find_mut(&id) {
+            Some(item) if item.review_sent => item.under_review = false,
+            _ => return,
+        }
+        self.on_item_resolved(id);
+    }
+
     /// Compute the ordered list of rows displayed on the portal detail
     /// page. Ordering:
     /// 1. `StartupFailed`, if the portal subsystem never started; then the
@@ -1138,7 +1260,8 @@ impl PortalManager {
     ///    flag — but the order is defined so the page is deterministic.
     /// 2. `Stuck` items (queued ≥ 30 min ago), oldest first.
     /// 3. `Pending` items (queued < 30 min ago), oldest first.
-    /// 4. `RecentSuccess` rows, newest first, capped at
+    /// 4. `StatsPending`, then `UnderReview` items, oldest first.
+    /// 5. `RecentSuccess` rows, newest first, capped at
     ///    `RECENT_SUCCESS_CAP`.
     pub fn detail_rows(&self) -> Vec<DetailRow> {
         let mut out: Vec<DetailRow> = Vec::new();
@@ -1164,7 +1287,7 @@ impl PortalManager {
             }
         }
         for it in &items {
-            if !it.score_sent && !is_item_stuck(it, now) {
+            if !it.score_sent && !it.review_sent && !is_item_stuck(it, now) {
                 out.push(DetailRow::Pending {
                     id: it.id.clone(),
                     game_number: it.id.game_number.clone(),
@@ -1180,6 +1303,14 @@ impl PortalManager {
                 });
             }
         }
+        for it in &items {
+            if it.review_sent {
+                out.push(DetailRow::UnderReview {
+                    id: it.id.clone(),
+                    game_number: it.id.game_number.clone(),
+                });
+            }
+        }
 
         let now_instant = Instant::now();
         for rs in &self.recent_successes {
@@ -1225,8 +1356,8 @@ mod tests {
     /// Produce the exact error `reqwest` raises when `https_only` refuses a
     /// plain-http URL. It is raised inside `execute`, before any socket is
     /// opened, so this reaches no network at all.
-    async fn https_only_refusal(url: &str) -> Box<dyn std::error::Error> {
-        Box::new(test_client(true).get(url).send().await.unwrap_err())
+    async fn https_only_refusal(url: &str) -> PortalError {
+        test_client(true).get(url).send().await.unwrap_err().into()
     }
 
     /// The refbox refusing to send is a configuration choice, not a broken
@@ -1258,9 +1389,8 @@ mod tests {
     #[tokio::test]
     async fn an_unusable_address_is_also_not_a_connectivity_failure() {
         for url in ["ftp://example.test/api", "localhost:9099", "127.0.0.1:9099"] {
-            let err: Box<dyn std::error::Error> =
-                Box::new(test_client(false).get(url).send().await.unwrap_err());
-            let classified = classify_error(err);
+            let err = test_client(false).get(url).send().await.unwrap_err();
+            let classified = classify_error(err.into());
             assert!(
                 matches!(classified, health::PortalCallError::NotSent(_)),
                 "{url} never reaches the network, got {classified:?}"
@@ -1273,7 +1403,7 @@ mod tests {
     /// fails without leaving the machine.
     #[tokio::test]
     async fn a_genuine_transport_failure_is_still_a_connectivity_failure() {
-        let err: Box<dyn std::error::Error> = Box::new(
+        let err = PortalError::from(
             test_client(false)
                 .get("http://127.0.0.1:1/")
                 .send()
@@ -1300,6 +1430,8 @@ mod tests {
             last_attempt_at: None,
             force: false,
             score_sent: false,
+            under_review: false,
+            review_sent: false,
         }
     }
 
@@ -1335,6 +1467,41 @@ mod tests {
         assert_eq!(m.indicator_state().health, HealthState::Red);
     }
 
+    #[test]
+    fn queued_results_are_listed_oldest_first_with_stuck_ones_marked() {
+        let mut young = mk_young_item();
+        young.id.game_number = "G2".into();
+        let q = QueueFile {
+            version: 1,
+            items: vec![young, mk_stuck_item()],
+        };
+        let m = PortalManager::new_for_test(q, false, false);
+        let results = m.queued_results();
+        assert_eq!(
+            results
+                .iter()
+                .map(|r| (r.id.game_number.as_str(), r.stuck))
+                .collect::<Vec<_>>(),
+            [("G1", true), ("G2", false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_version_changes_with_the_queue_and_with_items_going_stuck() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
+        let empty = m.queue_version();
+        assert_eq!(m.queue_version(), empty);
+
+        m.enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into(), false)
+            .unwrap();
+        let queued = m.queue_version();
+        assert_ne!(queued, empty);
+
+        m.queue.items[0].queued_at = OffsetDateTime::now_utc() - STUCK_THRESHOLD;
+        assert_ne!(m.queue_version(), queued);
+    }
+
     #[test]
     fn token_known_problem_is_red() {
         let m = PortalManager::new_for_test(QueueFile::empty(), false, true);
@@ -1506,7 +1673,7 @@ mod tests {
 
         assert_eq!(m.indicator_state().health, HealthState::Green);
 
-        m.enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
 
         // Fresh item: Yellow (retrying silently), not Red.
@@ -1518,7 +1685,7 @@ mod tests {
     async fn discard_removes_item_and_returns_to_green() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
 
         let id = m.queue.items[0].id.clone();
@@ -1560,7 +1727,7 @@ mod tests {
     async fn sweep_removes_expired_item_archives_it_and_returns_to_green() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e1".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("e1".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
         // Backdate past the 120h limit.
         m.queue.items[0].queued_at = OffsetDateTime::now_utc() - TimeDuration::hours(121);
@@ -1578,7 +1745,7 @@ mod tests {
     async fn sweep_keeps_a_fresh_item() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e1".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e1".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
 
         m.sweep_expired().unwrap();
@@ -1627,7 +1794,7 @@ mod tests {
     async fn force_submit_flags_force_and_resets_attempt_counters() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
 
@@ -1648,9 +1815,9 @@ mod tests {
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // One stuck game (queued 31 min ago) and one young pending game.
-        m.enqueue_game_end("event".into(), "G_STUCK".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "G_STUCK".into(), 0, 0, "{}".into(), false)
             .unwrap();
-        m.enqueue_game_end("event".into(), "G_YOUNG".into(), 1, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "G_YOUNG".into(), 1, 0, "{}".into(), false)
             .unwrap();
 
         // Age the first past the 30-min stuck threshold and give both
@@ -1685,7 +1852,7 @@ mod tests {
     async fn token_refreshed_clears_flag_and_resets_queue_items() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.token_known_problem = true;
         m.queue.items[0].attempts = 4;
@@ -1871,10 +2038,10 @@ mod tests {
         {
             let (mut healthy, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
             healthy
-                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into(), false)
                 .unwrap();
             healthy
-                .enqueue_game_end("event".into(), "G2".into(), 2, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G2".into(), 2, 0, "{}".into(), false)
                 .unwrap();
         }
         let path = tmp.path().join("portal_queue.json");
@@ -1895,7 +2062,8 @@ mod tests {
                 degraded.store.is_none(),
                 "a queue we could not read must leave this session with no write target"
             );
-            let _ = degraded.enqueue_game_end("event".into(), "G3".into(), 3, 0, "{}".into());
+            let _ =
+                degraded.enqueue_game_end("event".into(), "G3".into(), 3, 0, "{}".into(), false);
             assert_eq!(
                 degraded.queue.items.len(),
                 1,
@@ -1940,7 +2108,7 @@ mod tests {
                 "a readable queue must still give this session a write target"
             );
             degraded
-                .enqueue_game_end("event".into(), "G7".into(), 5, 4, "{}".into())
+                .enqueue_game_end("event".into(), "G7".into(), 5, 4, "{}".into(), false)
                 .unwrap();
         }
 
@@ -1957,7 +2125,7 @@ mod tests {
     async fn tenant_switch_flush_clears_a_queue_this_session_owns() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("old-tenant".into(), "G1".into(), 1, 0, "{}".into())
+        m.enqueue_game_end("old-tenant".into(), "G1".into(), 1, 0, "{}".into(), false)
             .unwrap();
 
         m.flush_queue_for_tenant_switch().unwrap();
@@ -1983,7 +2151,7 @@ mod tests {
         {
             let (mut healthy, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
             healthy
-                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into(), false)
                 .unwrap();
         }
         let path = tmp.path().join("portal_queue.json");
@@ -2022,7 +2190,7 @@ mod tests {
         {
             let (mut degraded, _rx) = PortalManager::new_degraded(tmp.path());
             degraded
-                .enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into())
+                .enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into(), false)
                 .unwrap();
         }
 
@@ -2047,10 +2215,10 @@ mod tests {
         {
             let (mut healthy, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
             healthy
-                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G1".into(), 1, 0, "{}".into(), false)
                 .unwrap();
             healthy
-                .enqueue_game_end("event".into(), "G2".into(), 2, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G2".into(), 2, 0, "{}".into(), false)
                 .unwrap();
         }
 
@@ -2058,7 +2226,7 @@ mod tests {
         {
             let (mut degraded, _rx) = PortalManager::new_degraded(tmp.path());
             degraded
-                .enqueue_game_end("event".into(), "G3".into(), 3, 0, "{}".into())
+                .enqueue_game_end("event".into(), "G3".into(), 3, 0, "{}".into(), false)
                 .unwrap();
         }
 
@@ -2080,7 +2248,7 @@ mod tests {
     async fn queue_with_only_stats_pending_item_is_green() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
         // Mark it stats-pending and age it well past the stuck threshold.
         m.queue.items[0].score_sent = true;
@@ -2093,21 +2261,54 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn protested_result_stays_under_review_until_marked_final() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
+        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into(), true)
+            .unwrap();
+        let id = m.queue.items[0].id.clone();
+
+        // Marking it final before the portal has it would drop it unsent
+        m.mark_final(id.clone());
+        assert_eq!(m.queue.items.len(), 1);
+
+        m.on_item_resolved(id.clone());
+        assert!(m.is_under_review(&id));
+        m.queue.items[0].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(120);
+        m.recompute_indicator();
+        assert_eq!(
+            m.indicator_state().health,
+            HealthState::Green,
+            "a result under review is sent and must not hold the dot yellow or red"
+        );
+        assert!(
+            matches!(&m.detail_rows()[..], [DetailRow::UnderReview { game_number, .. }] if game_number == "G1")
+        );
+
+        m.mark_final(id.clone());
+        assert!(m.queue.items.is_empty());
+        assert!(matches!(
+            &m.detail_rows()[..],
+            [DetailRow::RecentSuccess { .. }]
+        ));
+    }
+
     #[tokio::test]
     async fn detail_rows_orders_token_then_stuck_then_pending_oldest_first() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // One stuck item: queued 40 min ago.
-        m.enqueue_game_end("e".into(), "G3".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("e".into(), "G3".into(), 3, 2, "{}".into(), false)
             .unwrap();
         m.queue.items[0].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(40);
 
         // Two young pendings: queued at different recent times.
-        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[1].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(5);
-        m.enqueue_game_end("e".into(), "G2".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G2".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[2].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(2);
 
@@ -2139,7 +2340,7 @@ mod tests {
     async fn on_item_resolved_removes_queue_item_and_adds_recent_success() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("event".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
 
         let id = m.queue.items[0].id.clone();
@@ -2164,7 +2365,7 @@ mod tests {
         // Push six resolutions, numbered G1..G6.
         for n in 1..=6u32 {
             let game = format!("G{n}");
-            m.enqueue_game_end("e".into(), game.clone(), 0, 0, "{}".into())
+            m.enqueue_game_end("e".into(), game.clone(), 0, 0, "{}".into(), false)
                 .unwrap();
             let id = m.queue.items[0].id.clone();
             m.on_item_resolved(id);
@@ -2188,7 +2389,7 @@ mod tests {
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // 1. Enqueue one item (id A).
-        m.enqueue_game_end("event".into(), "GA".into(), 1, 0, "{}".into())
+        m.enqueue_game_end("event".into(), "GA".into(), 1, 0, "{}".into(), false)
             .unwrap();
         let id_a = m.queue.items[0].id.clone();
 
@@ -2226,12 +2427,12 @@ mod tests {
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // Resolve two items, G1 then G2.
-        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id1 = m.queue.items[0].id.clone();
         m.on_item_resolved(id1);
 
-        m.enqueue_game_end("e".into(), "G2".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G2".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id2 = m.queue.items[0].id.clone();
         m.on_item_resolved(id2);
@@ -2253,13 +2454,13 @@ mod tests {
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // Stuck item: queued 40 minutes ago.
-        m.enqueue_game_end("e".into(), "G_STUCK".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G_STUCK".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[0].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(40);
         let stuck_id = m.queue.items[0].id.clone();
 
         // Young item: queued 5 minutes ago.
-        m.enqueue_game_end("e".into(), "G_YOUNG".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G_YOUNG".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[1].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(5);
         let young_id = m.queue.items[1].id.clone();
@@ -2284,7 +2485,7 @@ mod tests {
     async fn find_returns_item_or_none() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
 
@@ -2306,7 +2507,7 @@ mod tests {
     async fn force_immediate_retry_clears_last_attempt_at_and_is_noop_for_unknown() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
 
@@ -2352,7 +2553,7 @@ mod tests {
         // cleared, and the on-disk queue reflects the change.
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
         m.queue.items[0].last_attempt_at = Some(OffsetDateTime::now_utc());
@@ -2381,7 +2582,7 @@ mod tests {
     async fn on_score_sent_stats_pending_marks_item_and_stays_green() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 3, 2, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
         assert_eq!(m.indicator_state().health, HealthState::Yellow);
@@ -2406,7 +2607,7 @@ mod tests {
     async fn is_stats_pending_reflects_score_sent_flag() {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
-        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G1".into(), 0, 0, "{}".into(), false)
             .unwrap();
         let id = m.queue.items[0].id.clone();
         assert!(!m.is_stats_pending(&id), "fresh item is score-pending");
@@ -2441,15 +2642,15 @@ mod tests {
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
 
         // A score-pending (young) game and a stats-pending game.
-        m.enqueue_game_end("e".into(), "G_SCORE".into(), 0, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G_SCORE".into(), 0, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[0].queued_at = OffsetDateTime::now_utc() - TimeDuration::minutes(2);
-        m.enqueue_game_end("e".into(), "G_STATS".into(), 1, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G_STATS".into(), 1, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[1].score_sent = true;
 
         // And one recent success.
-        m.enqueue_game_end("e".into(), "G_DONE".into(), 2, 1, "{}".into())
+        m.enqueue_game_end("e".into(), "G_DONE".into(), 2, 1, "{}".into(), false)
             .unwrap();
         let done_id = m.queue.items[2].id.clone();
         m.on_item_resolved(done_id);
@@ -2478,10 +2679,10 @@ mod tests {
         let tmp = tempfile::TempDir::new().unwrap();
         let (mut m, _rx) = PortalManager::new(tmp.path(), NullIo).unwrap();
         // One stats-pending game and one score-pending game.
-        m.enqueue_game_end("e".into(), "G_STATS".into(), 1, 0, "{}".into())
+        m.enqueue_game_end("e".into(), "G_STATS".into(), 1, 0, "{}".into(), false)
             .unwrap();
         m.queue.items[0].score_sent = true;
-        m.enqueue_game_end("e".into(), "G_SCORE".into(), 2, 1, "{}".into())
+        m.enqueue_game_end("e".into(), "G_SCORE".into(), 2, 1, "{}".into(), false)
             .unwrap();
         m.queue.items[1].attempts = 3;
         m.queue.items[1].last_attempt_at = Some(OffsetDateTime::now_utc());
@@ -2505,4 +2706,151 @@ mod tests {
             "retry_all must not set force on the score-pending item"
         );
     }
+
+    // --- End to end, against `fake_portal` ---
+    //
+    // These run the real background task over a real `UwhPortalClient`, so they wait out real
+    // poll ticks: a couple of seconds each.
+
+    const FAKE_EVENT: &str = "events/1234-A";
+
+    fn fake_portal_io(
+        portal: &fake_portal::FakePortal,
+        timeout: std::time::Duration,
+    ) -> UwhPortalIo {
+        let mut client = uwh_common::uwhportal::UwhPortalClient::new(
+            &portal.url(),
+            Some(fake_portal::ACCESS_KEY),
+            false,
+            timeout,
+        )
+        .unwrap();
+        // A scripted failure is meant for the manager's own retries, not the client's
+        client.set_retry_policy(uwh_common::uwhportal::RetryPolicy::NONE);
+        let event_id = uwh_common::uwhportal::schedule::EventId::from_full(FAKE_EVENT).unwrap();
+        UwhPortalIo::new(
+            std::sync::Arc::new(std::sync::Mutex::new(client)),
+            std::sync::Arc::new(std::sync::Mutex::new(Some(event_id))),
+        )
+    }
+
+    /// Apply one background-task event the way `RefBoxApp::update` does
+    fn apply(m: &mut PortalManager, event: PortalEvent) {
+        match event {
+            PortalEvent::ItemResolved(id) => m.on_item_resolved(id),
+            PortalEvent::ScoreSentStatsPending(id) => m.on_score_sent_stats_pending(id),
+            PortalEvent::ItemAttempted { id, attempts, at } => {
+                m.on_item_attempted(id, attempts, at)
+            }
+            PortalEvent::HealthChanged | PortalEvent::ItemUpdated => m.ui_tick(),
+            PortalEvent::TokenStatus(valid) => m.on_token_status(valid),
+            PortalEvent::TokenUnreachable => m.on_token_unreachable(),
+        }
+    }
+
+    /// Feed the background task's events to `m` until `done` holds
+    async fn drive_until(
+        m: &mut PortalManager,
+        rx: &mut mpsc::Receiver<PortalEvent>,
+        done: impl Fn(&PortalManager) -> bool,
+    ) {
+        while !done(m) {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(10), rx.recv())
+                .await
+                .expect("the background task went quiet")
+                .expect("the background task stopped");
+            apply(m, event);
+        }
+    }
+
+    #[tokio::test]
+    async fn a_result_refused_by_a_server_error_is_sent_on_the_next_attempt() {
+        let portal = fake_portal::FakePortal::start().unwrap();
+        portal.fail_next(
+            fake_portal::Endpoint::PostScores,
+            fake_portal::Failure::Status(503),
+        );
+        let tmp = tempfile::TempDir::new().unwrap();
+        let io = fake_portal_io(&portal, std::time::Duration::from_secs(5));
+        let (mut m, mut rx) = PortalManager::new(tmp.path(), io).unwrap();
+
+        m.enqueue_game_end(FAKE_EVENT.into(), "1".into(), 3, 2, "[]".into(), false)
+            .unwrap();
+        let id = m.queue.items[0].id.clone();
+        drive_until(&mut m, &mut rx, |m| {
+            m.find(&id).is_some_and(|it| it.attempts == 1)
+        })
+        .await;
+        assert!(portal.posted_scores().is_empty());
+        assert_eq!(m.indicator_state().health, HealthState::Yellow);
+
+        m.force_immediate_retry(&id).unwrap();
+        drive_until(&mut m, &mut rx, |m| m.queue.items.is_empty()).await;
+
+        let scores = portal.posted_scores();
+        assert_eq!(scores.len(), 1);
+        assert_eq!((scores[0].dark, scores[0].light), (3, 2));
+        assert_eq!(portal.posted_stats().len(), 1);
+        assert_eq!(m.indicator_state().health, HealthState::Green);
+    }
+
+    #[tokio::test]
+    async fn a_refused_stats_upload_leaves_the_game_stats_pending_until_retried() {
+        let portal = fake_portal::FakePortal::start().unwrap();
+        portal.fail_always(
+            fake_portal::Endpoint::PostStats,
+            fake_portal::Failure::Status(400),
+        );
+        let tmp = tempfile::TempDir::new().unwrap();
+        let io = fake_portal_io(&portal, std::time::Duration::from_secs(5));
+        let (mut m, mut rx) = PortalManager::new(tmp.path(), io).unwrap();
+
+        m.enqueue_game_end(FAKE_EVENT.into(), "2".into(), 0, 1, "[]".into(), false)
+            .unwrap();
+        let id = m.queue.items[0].id.clone();
+        drive_until(&mut m, &mut rx, |m| m.is_stats_pending(&id)).await;
+        assert_eq!(portal.posted_scores().len(), 1);
+        assert!(portal.posted_stats().is_empty());
+        assert_eq!(m.indicator_state().health, HealthState::Green);
+
+        portal.clear_failures();
+        m.request_stats_retry(&id);
+        drive_until(&mut m, &mut rx, |m| m.queue.items.is_empty()).await;
+        assert_eq!(portal.posted_stats().len(), 1);
+        // The score went once; retrying the stats did not send it again.
+        assert_eq!(portal.count(fake_portal::Endpoint::PostScores), 1);
+    }
+
+    #[tokio::test]
+    async fn an_expired_key_is_reported_as_an_expired_login() {
+        let portal = fake_portal::FakePortal::start().unwrap();
+        portal.expire_tokens();
+        let tmp = tempfile::TempDir::new().unwrap();
+        let io = fake_portal_io(&portal, std::time::Duration::from_secs(5));
+        let (mut m, mut rx) = PortalManager::new(tmp.path(), io).unwrap();
+
+        drive_until(&mut m, &mut rx, |m| {
+            m.indicator_state().health == HealthState::Red
+        })
+        .await;
+        assert!(m.indicator_state().token_expired);
+    }
+
+    #[tokio::test]
+    async fn a_portal_that_never_answers_is_a_connection_problem_not_an_expired_login() {
+        let portal = fake_portal::FakePortal::start().unwrap();
+        portal.fail_always(
+            fake_portal::Endpoint::VerifyToken,
+            fake_portal::Failure::Delay(std::time::Duration::from_secs(5)),
+        );
+        let tmp = tempfile::TempDir::new().unwrap();
+        let io = fake_portal_io(&portal, std::time::Duration::from_millis(300));
+        let (mut m, mut rx) = PortalManager::new(tmp.path(), io).unwrap();
+
+        drive_until(&mut m, &mut rx, |m| {
+            m.indicator_state().health == HealthState::Red
+        })
+        .await;
+        assert!(!m.indicator_state().token_expired);
+    }
 }
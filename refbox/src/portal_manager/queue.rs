@@ -1,13 +1,12 @@
-//! On-disk persistence for the portal retry queue.
+//! On-disk persistence for the portal retry queue, kept as a [`state_file`].
 
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
-use time::macros::format_description;
 
 use super::ItemId;
+use crate::state_file;
 
 /// Top-level envelope for `portal_queue.json`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -67,68 +66,31 @@ pub struct QueuedItem {
     /// load as score-pending.
     #[serde(default)]
     pub score_sent: bool,
+    /// A captain lodged a protest against this result, so it is under
+    /// review rather than final. The stats sent with it carry the
+    /// protest. `#[serde(default)]` so older queue files load as final.
+    #[serde(default)]
+    pub under_review: bool,
+    /// Whether the portal has accepted a result that is `under_review`.
+    /// The item then stays on the queue, no longer sent and never stuck,
+    /// until the operator marks the result final.
+    #[serde(default)]
+    pub review_sent: bool,
 }
 
-const QUEUE_FILE_NAME: &str = "portal_queue.json";
-const TMP_FILE_NAME: &str = "portal_queue.json.tmp";
-
-fn queue_path(dir: &Path) -> PathBuf {
-    dir.join(QUEUE_FILE_NAME)
-}
-
-fn tmp_path(dir: &Path) -> PathBuf {
-    dir.join(TMP_FILE_NAME)
-}
+const QUEUE_FILE_STEM: &str = "portal_queue";
 
 /// Load the queue file from `dir`. If missing, return an empty queue. If
-/// present but unparseable, rename to `portal_queue.corrupt.<ts>.json`,
-/// log an error, and return an empty queue.
+/// present but unparseable, rename it aside, log an error, and return an
+/// empty queue (see [`state_file::load`]).
 pub(super) fn load_or_empty(dir: &Path) -> std::io::Result<QueueFile> {
-    let path = queue_path(dir);
-    if !path.exists() {
-        return Ok(QueueFile::empty());
-    }
-    let bytes = fs::read(&path)?;
-    match serde_json::from_slice::<QueueFile>(&bytes) {
-        Ok(q) if q.version == QueueFile::CURRENT_VERSION => Ok(q),
-        Ok(q) => {
-            log::error!(
-                "portal_queue.json has unknown version {}; renaming and starting fresh",
-                q.version
-            );
-            rename_corrupt(&path)?;
-            Ok(QueueFile::empty())
-        }
-        Err(e) => {
-            log::error!("portal_queue.json failed to parse ({e}); renaming and starting fresh");
-            rename_corrupt(&path)?;
-            Ok(QueueFile::empty())
-        }
-    }
-}
-
-fn rename_corrupt(path: &Path) -> std::io::Result<()> {
-    // Format: YYYYMMDDTHHMMSSZ, e.g. "20260419T142203Z".
-    let fmt = format_description!("[year][month][day]T[hour][minute][second]Z");
-    let ts = OffsetDateTime::now_utc()
-        .format(&fmt)
-        .unwrap_or_else(|_| "unknown-time".to_string());
-    let mut new_path = path.to_path_buf();
-    new_path.set_file_name(format!("portal_queue.corrupt.{ts}.json"));
-    fs::rename(path, &new_path)
-}
-
-/// Atomically write a queue-file envelope to `target`: write to `tmp`,
-/// fsync, then rename over the target.
-fn write_atomic(target: &Path, tmp: &Path, q: &QueueFile) -> std::io::Result<()> {
-    {
-        let mut f = fs::File::create(tmp)?;
-        serde_json::to_writer(&f, q).map_err(std::io::Error::other)?;
-        f.flush()?;
-        f.sync_all()?;
-    }
-    fs::rename(tmp, target)?;
-    Ok(())
+    let q = state_file::load(
+        dir,
+        QUEUE_FILE_STEM,
+        QueueFile::CURRENT_VERSION,
+        |q: &QueueFile| q.version,
+    )?;
+    Ok(q.unwrap_or_else(QueueFile::empty))
 }
 
 /// Atomically write the queue file to `dir/portal_queue.json`.
@@ -140,7 +102,7 @@ fn write_atomic(target: &Path, tmp: &Path, q: &QueueFile) -> std::io::Result<()>
 /// this area were introduced in `mod.rs`, so `pub(super)` here would leave the
 /// door open in exactly the place it has always been walked through.
 fn save(dir: &Path, q: &QueueFile) -> std::io::Result<()> {
-    write_atomic(&queue_path(dir), &tmp_path(dir), q)
+    state_file::save(dir, QUEUE_FILE_STEM, q)
 }
 
 /// Seed a queue file directly, for tests that need to arrange on-disk state.
@@ -159,22 +121,13 @@ pub(super) fn seed_for_test(dir: &Path, q: &QueueFile) -> std::io::Result<()> {
 // active queue, but copied here first so nothing is ever silently lost. This
 // is a behind-the-scenes safety net, not surfaced in the UI.
 
-const ARCHIVE_FILE_NAME: &str = "portal_queue.expired.json";
-const ARCHIVE_TMP_FILE_NAME: &str = "portal_queue.expired.json.tmp";
-
-fn archive_path(dir: &Path) -> PathBuf {
-    dir.join(ARCHIVE_FILE_NAME)
-}
-
-fn archive_tmp_path(dir: &Path) -> PathBuf {
-    dir.join(ARCHIVE_TMP_FILE_NAME)
-}
+const ARCHIVE_FILE_STEM: &str = "portal_queue.expired";
 
 /// Load the archive of expired queue items. Missing → empty. Unparseable or
 /// unknown-version → logged and treated as empty (the archive is a
 /// best-effort safety net, so a corrupt archive must never block a sweep).
 pub(super) fn load_archive_or_empty(dir: &Path) -> std::io::Result<QueueFile> {
-    let path = archive_path(dir);
+    let path = state_file::path(dir, ARCHIVE_FILE_STEM);
     if !path.exists() {
         return Ok(QueueFile::empty());
     }
@@ -196,7 +149,7 @@ fn append_to_archive(dir: &Path, items: &[QueuedItem]) -> std::io::Result<()> {
     }
     let mut archive = load_archive_or_empty(dir)?;
     archive.items.extend_from_slice(items);
-    write_atomic(&archive_path(dir), &archive_tmp_path(dir), &archive)
+    state_file::save(dir, ARCHIVE_FILE_STEM, &archive)
 }
 
 /// A directory this session successfully read the portal queue from, and is
@@ -213,7 +166,7 @@ fn append_to_archive(dir: &Path, items: &[QueuedItem]) -> std::io::Result<()> {
 /// What holding a store does NOT prove is that writing will succeed. When the
 /// queue file is absent, `load_or_empty` returns success without touching the
 /// disk, so an unwritable directory still yields a store and its first write
-/// fails at `write_atomic`. That hole predates this type — see
+/// fails at `state_file::save`. That hole predates this type — see
 /// `docs/backlog/unwritable-config-dir-looks-healthy/NOTE.md`. The guarantee
 /// here is narrow and exact: we never write where a read *failed*.
 ///
@@ -280,6 +233,8 @@ mod tests {
                 last_attempt_at: Some(datetime!(2026-04-19 14:23:15 UTC)),
                 force: false,
                 score_sent: false,
+                under_review: false,
+                review_sent: false,
             }],
         };
         let s = serde_json::to_string_pretty(&q).unwrap();
@@ -308,6 +263,8 @@ mod tests {
                     last_attempt_at: None,
                     force: false,
                     score_sent: false,
+                    under_review: false,
+                    review_sent: false,
                 }],
             }
         }
@@ -354,7 +311,7 @@ mod tests {
             use std::os::unix::fs::PermissionsExt;
             let tmp = TempDir::new().unwrap();
             save(tmp.path(), &QueueFile::empty()).unwrap();
-            let path = queue_path(tmp.path());
+            let path = state_file::path(tmp.path(), QUEUE_FILE_STEM);
             std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o000)).unwrap();
 
             let result = QueueStore::open(tmp.path());
@@ -393,6 +350,8 @@ mod tests {
                     last_attempt_at: None,
                     force: false,
                     score_sent: false,
+                    under_review: false,
+                    review_sent: false,
                 }],
             };
             save(tmp.path(), &q).unwrap();
@@ -447,6 +406,8 @@ mod tests {
             last_attempt_at: None,
             force: false,
             score_sent: true,
+            under_review: false,
+            review_sent: false,
         };
         let s = serde_json::to_string(&item).unwrap();
         let back: QueuedItem = serde_json::from_str(&s).unwrap();
@@ -470,6 +431,8 @@ mod tests {
             last_attempt_at: None,
             force: false,
             score_sent: true,
+            under_review: false,
+            review_sent: false,
         };
         let mut v = serde_json::to_value(&item).unwrap();
         v.as_object_mut().unwrap().remove("score_sent");
@@ -479,6 +442,31 @@ mod tests {
             "an item with no score_sent field must load as score-pending (false)"
         );
     }
+
+    #[test]
+    fn missing_review_fields_load_as_a_final_result() {
+        let item = QueuedItem {
+            id: ItemId {
+                event_id: "e1".into(),
+                game_number: "G1".into(),
+            },
+            black_score: 0,
+            white_score: 0,
+            stats: "{}".into(),
+            queued_at: datetime!(2026-06-25 12:00:00 UTC),
+            attempts: 0,
+            last_attempt_at: None,
+            force: false,
+            score_sent: false,
+            under_review: true,
+            review_sent: true,
+        };
+        let mut v = serde_json::to_value(&item).unwrap();
+        v.as_object_mut().unwrap().remove("under_review");
+        v.as_object_mut().unwrap().remove("review_sent");
+        let back: QueuedItem = serde_json::from_value(v).unwrap();
+        assert!(!back.under_review && !back.review_sent);
+    }
 }
 
 #[cfg(test)]
@@ -500,6 +488,8 @@ mod archive_tests {
             last_attempt_at: None,
             force: false,
             score_sent: false,
+            under_review: false,
+            review_sent: false,
         }
     }
 
@@ -0,0 +1,789 @@
+//! On-device archive of every finished game ("results archive").
+//!
+//! Results are otherwise only sent to the portal and then forgotten, which leaves nothing behind
+//! at a tournament without reliable internet. Each finished game is kept here with its final
+//! score, the `GameStats` events sent to the portal (among them the audit trail of changes made by
+//! hand), the timing rule it was played under, its referees, the referees' incident reports and
+//! when it started and ended. A whole event day can be exported to JSON and CSV, to hand the
+//! results over on a USB stick, and so can an event's player statistics.
+//!
+//! Kept as a [`state_file`].
+
+use crate::{
+    state_file,
+    tournament_manager::{
+        TimeoutKind,
+        audit::{AuditChange, AuditClocks, AuditEntry, AuditFoul, AuditPenalty},
+        incident::IncidentReport,
+        penalty::PenaltyKind,
+    },
+};
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use time::{Date, OffsetDateTime, macros::format_description};
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    config::Game as GameConfig,
+    uwhportal::{
+        bracket::GameResults,
+        player_stats::{PlayerStats, PlayerTotals},
+        schedule::{EventId, GameNumber, TeamId},
+    },
+};
+
+const FILE_STEM: &str = "results";
+const EXPORT_DIR_NAME: &str = "exports";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultsArchive {
+    pub version: u32,
+    games: Vec<ArchivedGame>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedGame {
+    pub event_id: Option<EventId>,
+    pub game_number: GameNumber,
+    /// The event day the game belongs to, in the event's time zone when it is known
+    pub day: Date,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub started_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub ended_at: OffsetDateTime,
+    /// Team names, for games from a schedule
+    pub teams: BlackWhiteBundle<Option<String>>,
+    /// Team ids, for games from a schedule with the teams assigned. Missing from archives written
+    /// before player statistics were kept.
+    #[serde(default)]
+    pub team_ids: BlackWhiteBundle<Option<TeamId>>,
+    pub scores: BlackWhiteBundle<u8>,
+    /// The schedule's name for the timing rule, if the game came from one
+    pub timing_rule: Option<String>,
+    /// The timings the game was actually played with
+    pub config: GameConfig,
+    pub referees: Vec<ArchivedReferee>,
+    /// The game's `GameStats` events, exactly as sent to the portal
+    pub stats: serde_json::Value,
+    /// The referees' incident reports, which the portal is not sent. Missing from archives
+    /// written before incidents were reported.
+    #[serde(default)]
+    pub incidents: Vec<IncidentReport>,
+}
+
+impl ArchivedGame {
+    /// The changes made by hand during the game, taken from its events
+    pub fn audit_trail(&self) -> Vec<AuditEntry> {
+        self.stats
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|event| event["$type"] == "edit")
+            .filter_map(|event| serde_json::from_value(event.clone()).ok())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedReferee {
+    pub role: String,
+    pub name: String,
+}
+
+impl Default for ResultsArchive {
+    fn default() -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            games: Vec::new(),
+        }
+    }
+}
+
+impl ResultsArchive {
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// Adds a finished game. A game that is finished again (after an undo back into it) replaces
+    /// its earlier result. It is told apart by when it started as well as by its number, since
+    /// without a schedule the numbers start again from 1 when the refbox is restarted.
+    pub fn record(&mut self, game: ArchivedGame) {
+        if let Some(existing) = self.games.iter_mut().find(|g| {
+            g.event_id == game.event_id
+                && g.game_number == game.game_number
+                && g.day == game.day
+                && g.started_at == game.started_at
+        }) {
+            info!("Replacing the archived result of game {}", game.game_number);
+            *existing = game;
+        } else {
+            info!("Archiving the result of game {}", game.game_number);
+            self.games.push(game);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// The most recent day with a game on it
+    pub fn latest_day(&self) -> Option<Date> {
+        self.games.iter().map(|g| g.day).max()
+    }
+
+    /// Every game played on `day`, in the order they finished
+    pub fn games_on(&self, day: Date) -> impl Iterator<Item = &ArchivedGame> {
+        self.games.iter().filter(move |g| g.day == day)
+    }
+
+    /// The final score of every archived game of `event_id`, for working out brackets offline
+    pub fn game_results(&self, event_id: &EventId) -> GameResults {
+        self.games
+            .iter()
+            .filter(|g| g.event_id.as_ref() == Some(event_id))
+            .map(|g| (g.game_number.clone(), g.scores))
+            .collect()
+    }
+
+    /// The event of the most recently finished game
+    pub fn latest_event(&self) -> Option<&EventId> {
+        self.games.last()?.event_id.as_ref()
+    }
+
+    /// Goal, penalty and foul totals by player over every archived game of `event_id`, without
+    /// names
+    pub fn player_stats(&self, event_id: &EventId) -> PlayerStats {
+        let mut stats = PlayerStats::default();
+        for game in self
+            .games
+            .iter()
+            .filter(|g| g.event_id.as_ref() == Some(event_id))
+        {
+            if let Err(e) = stats.add_game(&game.team_ids, &game.teams, &game.stats) {
+                warn!(
+                    "Leaving game {} out of the player statistics: {e}",
+                    game.game_number
+                );
+            }
+        }
+        stats
+    }
+
+    /// Writes `results-<day>.json` and `results-<day>.csv` for every game played on `day` into
+    /// `dir`, `incidents-<day>.csv` when any of them had an incident reported and `edits-<day>.csv`
+    /// when any of them was changed by hand, and returns the paths written
+    pub fn export_day(&self, day: Date, dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let games: Vec<_> = self.games_on(day).collect();
+        let day = day
+            .format(format_description!("[year]-[month]-[day]"))
+            .map_err(std::io::Error::other)?;
+
+        let json_path = dir.join(format!("results-{day}.json"));
+        let json = serde_json::to_vec_pretty(&games).map_err(std::io::Error::other)?;
+        write_synced(&json_path, &json)?;
+
+        let csv_path = dir.join(format!("results-{day}.csv"));
+        write_synced(&csv_path, &to_csv(&games)?)?;
+
+        info!(
+            "Exported {} game(s) from {day} to {json_path:?} and {csv_path:?}",
+            games.len()
+        );
+        let mut paths = vec![json_path, csv_path];
+
+        if games.iter().any(|g| !g.incidents.is_empty()) {
+            let incidents_path = dir.join(format!("incidents-{day}.csv"));
+            write_synced(&incidents_path, &incidents_csv(&games)?)?;
+            info!("Exported the incident reports from {day} to {incidents_path:?}");
+            paths.push(incidents_path);
+        }
+
+        if games.iter().any(|g| !g.audit_trail().is_empty()) {
+            let edits_path = dir.join(format!("edits-{day}.csv"));
+            write_synced(&edits_path, &edits_csv(&games)?)?;
+            info!("Exported the audit trails from {day} to {edits_path:?}");
+            paths.push(edits_path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Writes `player-stats-<event>.json` and `player-stats-<event>.csv` into `dir`, and returns the
+/// paths written
+pub fn export_player_stats(
+    stats: &PlayerStats,
+    event_id: &EventId,
+    dir: &Path,
+) -> std::io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let players: Vec<_> = stats.players().collect();
+    let event = event_id.partial();
+
+    let json_path = dir.join(format!("player-stats-{event}.json"));
+    let json = serde_json::to_vec_pretty(&players).map_err(std::io::Error::other)?;
+    write_synced(&json_path, &json)?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(PlayerTotals::CSV_HEADERS)?;
+    for player in &players {
+        writer.write_record(player.csv_record())?;
+    }
+    let csv_path = dir.join(format!("player-stats-{event}.csv"));
+    write_synced(&csv_path, &writer.into_inner().map_err(|e| e.into_error())?)?;
+
+    info!(
+        "Exported statistics for {} player(s) to {json_path:?} and {csv_path:?}",
+        players.len()
+    );
+    Ok(vec![json_path, csv_path])
+}
+
+/// One row per game, for opening in a spreadsheet. The full event list is only in the JSON.
+fn to_csv(games: &[&ArchivedGame]) -> std::io::Result<Vec<u8>> {
+    let time_format = format_description!("[hour]:[minute]:[second]");
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "event",
+        "game",
+        "started",
+        "ended",
+        "black team",
+        "white team",
+        "black score",
+        "white score",
+        "timing rule",
+        "referees",
+    ])?;
+    for game in games {
+        let time = |t: OffsetDateTime| t.format(time_format).unwrap_or_default();
+        writer.write_record([
+            game.event_id
+                .as_ref()
+                .map(|id| id.full().to_string())
+                .unwrap_or_default(),
+            game.game_number.clone(),
+            game.started_at.map(time).unwrap_or_default(),
+            time(game.ended_at),
+            game.teams.black.clone().unwrap_or_default(),
+            game.teams.white.clone().unwrap_or_default(),
+            game.scores.black.to_string(),
+            game.scores.white.to_string(),
+            game.timing_rule.clone().unwrap_or_default(),
+            game.referees
+                .iter()
+                .map(|r| format!("{}: {}", r.role, r.name))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ])?;
+    }
+    writer.into_inner().map_err(|e| e.into_error())
+}
+
+/// One row per change made by hand, in the order the games finished
+fn edits_csv(games: &[&ArchivedGame]) -> std::io::Result<Vec<u8>> {
+    let time_format = format_description!("[hour]:[minute]:[second]");
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "event",
+        "game",
+        "changed",
+        "period",
+        "time left",
+        "change",
+        "source",
+        "from",
+        "to",
+    ])?;
+    for game in games {
+        for entry in game.audit_trail() {
+            let (from, to) = match &entry.change {
+                AuditChange::Score { from, to, .. }
+                | AuditChange::GoalDisallowed { from, to, .. } => {
+                    (score_text(*from), score_text(*to))
+                }
+                AuditChange::GameClock { from, to } | AuditChange::TimeoutClock { from, to } => {
+                    (clock_text(*from), clock_text(*to))
+                }
+                AuditChange::Penalty { from, to } => (
+                    from.as_ref().map(penalty_text).unwrap_or_default(),
+                    to.as_ref().map(penalty_text).unwrap_or_default(),
+                ),
+                AuditChange::Foul { from, to } => (
+                    from.as_ref().map(foul_text).unwrap_or_default(),
+                    to.as_ref().map(foul_text).unwrap_or_default(),
+                ),
+                AuditChange::Clocks { from, to } => (clocks_text(from), clocks_text(to)),
+            };
+            writer.write_record([
+                game.event_id
+                    .as_ref()
+                    .map(|id| id.full().to_string())
+                    .unwrap_or_default(),
+                game.game_number.clone(),
+                entry
+                    .occurred_on
+                    .to_offset(game.ended_at.offset())
+                    .format(time_format)
+                    .unwrap_or_default(),
+                entry.game_period.to_string(),
+                clock_text(entry.period_time),
+                entry.as_str().to_string(),
+                entry.source.as_str().to_string(),
+                from,
+                to,
+            ])?;
+        }
+    }
+    writer.into_inner().map_err(|e| e.into_error())
+}
+
+fn score_text(scores: BlackWhiteBundle<u8>) -> String {
+    format!("{}-{}", scores.black, scores.white)
+}
+
+fn clock_text(secs: f32) -> String {
+    let secs = secs as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn penalty_text(penalty: &AuditPenalty) -> String {
+    let kind = match penalty.kind {
+        PenaltyKind::Timed(secs) => clock_text(secs.into()),
+        PenaltyKind::TotalDismissal => "Total dismissal".to_string(),
+    };
+    format!(
+        "{} #{} {kind} {}",
+        penalty.color, penalty.player_number, penalty.infraction
+    )
+}
+
+fn foul_text(foul: &AuditFoul) -> String {
+    let team = foul
+        .color
+        .map_or_else(|| "No team".to_string(), |color| color.to_string());
+    match foul.player_number {
+        Some(number) => format!("{team} #{number} {}", foul.infraction),
+        None => format!("{team} {}", foul.infraction),
+    }
+}
+
+fn clocks_text(clocks: &AuditClocks) -> String {
+    let mut text = clock_text(clocks.game_clock);
+    if !clocks.running {
+        text.push_str(" stopped");
+    }
+    match clocks.timeout {
+        Some(TimeoutKind::Team(color)) => text.push_str(&format!(", {color} timeout")),
+        Some(TimeoutKind::Ref) => text.push_str(", ref timeout"),
+        Some(TimeoutKind::PenaltyShot) => text.push_str(", penalty shot"),
+        None => {}
+    }
+    text
+}
+
+/// One row per incident report, in the order the games finished
+fn incidents_csv(games: &[&ArchivedGame]) -> std::io::Result<Vec<u8>> {
+    let time_format = format_description!("[hour]:[minute]:[second]");
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "event",
+        "game",
+        "reported",
+        "period",
+        "time left",
+        "category",
+        "team",
+        "player",
+        "report",
+    ])?;
+    for game in games {
+        for incident in &game.incidents {
+            let secs = incident.period_time.as_secs();
+            writer.write_record([
+                game.event_id
+                    .as_ref()
+                    .map(|id| id.full().to_string())
+                    .unwrap_or_default(),
+                game.game_number.clone(),
+                incident
+                    .occurred_on
+                    .to_offset(game.ended_at.offset())
+                    .format(time_format)
+                    .unwrap_or_default(),
+                incident.game_period.to_string(),
+                format!("{}:{:02}", secs / 60, secs % 60),
+                incident.category.as_str().to_string(),
+                incident
+                    .color
+                    .map(|color| color.to_string())
+                    .unwrap_or_default(),
+                incident
+                    .player_number
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+                incident.description.clone(),
+            ])?;
+        }
+    }
+    writer.into_inner().map_err(|e| e.into_error())
+}
+
+fn write_synced(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let mut f = fs::File::create(path)?;
+    f.write_all(bytes)?;
+    f.flush()?;
+    f.sync_all()
+}
+
+/// Where an export from the UI goes: the first USB stick found mounted under `/media` or
+/// `/run/media`, or else an `exports` directory next to the config file.
+pub fn default_export_dir(config_dir: &Path) -> PathBuf {
+    removable_drives()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| config_dir.join(EXPORT_DIR_NAME))
+}
+
+/// The mounted USB sticks that can be written to
+fn removable_drives() -> Vec<PathBuf> {
+    mounted_drives()
+        .into_iter()
+        .filter(|drive| {
+            fs::metadata(drive)
+                .map(|m| !m.permissions().readonly())
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Desktop Linux mounts a stick at `/media/<user>/<label>` or `/run/media/<user>/<label>`
+pub fn mounted_drives() -> Vec<PathBuf> {
+    let subdirs = |dir: &Path| -> Vec<PathBuf> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut dirs: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+        dirs
+    };
+    ["/media", "/run/media"]
+        .into_iter()
+        .flat_map(|root| subdirs(Path::new(root)))
+        .flat_map(|user| subdirs(&user))
+        .collect()
+}
+
+/// Load the archive. Missing → empty. Present but unparseable or of an unknown version → renamed
+/// aside and empty, see [`state_file::load`]. Never blocks startup.
+pub fn load_or_default(dir: &Path) -> std::io::Result<ResultsArchive> {
+    let archive = state_file::load(
+        dir,
+        FILE_STEM,
+        ResultsArchive::CURRENT_VERSION,
+        |archive: &ResultsArchive| archive.version,
+    )?;
+    Ok(archive.unwrap_or_default())
+}
+
+/// Atomically write the archive.
+pub fn save(dir: &Path, archive: &ResultsArchive) -> std::io::Result<()> {
+    state_file::save(dir, FILE_STEM, archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tournament_manager::{audit::AuditSource, incident::IncidentCategory};
+    use time::macros::{date, datetime};
+    use uwh_common::{
+        color::Color,
+        game_snapshot::{GamePeriod, Infraction},
+    };
+
+    fn game(number: &str, day: Date, black: u8) -> ArchivedGame {
+        ArchivedGame {
+            event_id: Some(EventId::from_partial("spring-cup")),
+            game_number: number.to_string(),
+            day,
+            started_at: Some(datetime!(2026-06-22 9:00 +2)),
+            ended_at: datetime!(2026-06-22 9:35 +2),
+            teams: BlackWhiteBundle {
+                black: Some("Sharks, North".to_string()),
+                white: Some("Eels".to_string()),
+            },
+            team_ids: BlackWhiteBundle {
+                black: Some(TeamId::from_partial("1-A")),
+                white: Some(TeamId::from_partial("2-A")),
+            },
+            scores: BlackWhiteBundle { black, white: 2 },
+            timing_rule: Some("Pool".to_string()),
+            config: GameConfig::default(),
+            referees: vec![ArchivedReferee {
+                role: "Chief Referee".to_string(),
+                name: "Sam".to_string(),
+            }],
+            stats: serde_json::json!([{"$type": "goal", "playerCapNumber": 4, "side": "dark",
+                "occurredOn": "2026-06-22T09:10:00+02:00"}]),
+            incidents: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn a_game_finished_again_replaces_its_result() {
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 22), 1));
+        archive.record(game("2", date!(2026 - 06 - 22), 0));
+        archive.record(game("1", date!(2026 - 06 - 22), 3));
+
+        let scores: Vec<_> = archive
+            .games_on(date!(2026 - 06 - 22))
+            .map(|g| (g.game_number.as_str(), g.scores.black))
+            .collect();
+        assert_eq!(scores, vec![("1", 3), ("2", 0)]);
+    }
+
+    #[test]
+    fn a_game_with_the_same_number_after_a_restart_is_kept_as_well() {
+        let mut archive = ResultsArchive::default();
+        archive.record(ArchivedGame {
+            event_id: None,
+            ..game("1", date!(2026 - 06 - 22), 1)
+        });
+        archive.record(ArchivedGame {
+            event_id: None,
+            started_at: Some(datetime!(2026-06-22 14:00 +2)),
+            ended_at: datetime!(2026-06-22 14:35 +2),
+            ..game("1", date!(2026 - 06 - 22), 4)
+        });
+
+        let scores: Vec<_> = archive
+            .games_on(date!(2026 - 06 - 22))
+            .map(|g| (g.game_number.as_str(), g.scores.black))
+            .collect();
+        assert_eq!(scores, vec![("1", 1), ("1", 4)]);
+    }
+
+    #[test]
+    fn game_results_are_for_one_event() {
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 22), 3));
+        archive.record(ArchivedGame {
+            event_id: Some(EventId::from_partial("autumn-cup")),
+            ..game("2", date!(2026 - 06 - 22), 0)
+        });
+
+        let results = archive.game_results(&EventId::from_partial("spring-cup"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results["1"], BlackWhiteBundle { black: 3, white: 2 });
+    }
+
+    #[test]
+    fn export_writes_only_the_chosen_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 21), 1));
+        archive.record(game("7", date!(2026 - 06 - 22), 4));
+        assert_eq!(archive.latest_day(), Some(date!(2026 - 06 - 22)));
+
+        let paths = archive
+            .export_day(date!(2026 - 06 - 22), &dir.path().join("usb"))
+            .unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("usb/results-2026-06-22.json"),
+                dir.path().join("usb/results-2026-06-22.csv")
+            ]
+        );
+
+        let json: Vec<ArchivedGame> =
+            serde_json::from_slice(&fs::read(&paths[0]).unwrap()).unwrap();
+        assert_eq!(json, vec![game("7", date!(2026 - 06 - 22), 4)]);
+
+        let csv = fs::read_to_string(&paths[1]).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "event,game,started,ended,black team,white team,black score,white score,\
+                 timing rule,referees"
+            )
+        );
+        // The comma in the team name is quoted
+        assert_eq!(
+            lines.next(),
+            Some(
+                "events/spring-cup,7,09:00:00,09:35:00,\"Sharks, North\",Eels,4,2,Pool,\
+                 Chief Referee: Sam"
+            )
+        );
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn incidents_are_exported_when_any_were_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 22), 1));
+        archive.record(ArchivedGame {
+            incidents: vec![IncidentReport {
+                category: IncidentCategory::EquipmentFailure,
+                color: Some(Color::White),
+                player_number: Some(9),
+                game_period: GamePeriod::SecondHalf,
+                period_time: std::time::Duration::from_secs(245),
+                occurred_on: datetime!(2026-06-22 7:21:30 UTC),
+                description: "Snorkel snapped, replaced".to_string(),
+            }],
+            ..game("2", date!(2026 - 06 - 22), 0)
+        });
+
+        let paths = archive
+            .export_day(date!(2026 - 06 - 22), dir.path())
+            .unwrap();
+        assert_eq!(paths[2], dir.path().join("incidents-2026-06-22.csv"));
+        assert_eq!(
+            fs::read_to_string(&paths[2])
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>(),
+            vec![
+                "event,game,reported,period,time left,category,team,player,report",
+                "events/spring-cup,2,09:21:30,Second Half,4:05,Equipment failure,White,9,\
+                 \"Snorkel snapped, replaced\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn edits_are_exported_when_any_were_made() {
+        let dir = tempfile::tempdir().unwrap();
+        let edit = |period_time, change| {
+            let mut event = serde_json::to_value(AuditEntry {
+                game_period: GamePeriod::FirstHalf,
+                period_time,
+                occurred_on: datetime!(2026-06-22 7:15:00 UTC),
+                source: AuditSource::EditPage,
+                change,
+            })
+            .unwrap();
+            event["$type"] = "edit".into();
+            event
+        };
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 22), 1));
+        archive.record(ArchivedGame {
+            stats: serde_json::json!([
+                {"$type": "goal", "playerCapNumber": 4, "side": "dark",
+                    "occurredOn": "2026-06-22T09:10:00+02:00"},
+                edit(412.0, AuditChange::Score {
+                    from: BlackWhiteBundle { black: 2, white: 2 },
+                    to: BlackWhiteBundle { black: 1, white: 2 },
+                }),
+                edit(400.0, AuditChange::Penalty {
+                    from: Some(AuditPenalty {
+                        color: Color::White,
+                        player_number: 9,
+                        kind: PenaltyKind::ONE_MINUTE,
+                        infraction: Infraction::OBSTRUCTION,
+                    }),
+                    to: None,
+                }),
+            ]),
+            ..game("2", date!(2026 - 06 - 22), 1)
+        });
+
+        let paths = archive
+            .export_day(date!(2026 - 06 - 22), dir.path())
+            .unwrap();
+        assert_eq!(paths[2], dir.path().join("edits-2026-06-22.csv"));
+        assert_eq!(
+            fs::read_to_string(&paths[2])
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>(),
+            vec![
+                "event,game,changed,period,time left,change,source,from,to",
+                "events/spring-cup,2,09:15:00,First Half,6:52,Score edited,Edit page,2-2,1-2",
+                "events/spring-cup,2,09:15:00,First Half,6:40,Penalty deleted,Edit page,\
+                 White #9 1:00 Obstruction,",
+            ]
+        );
+    }
+
+    #[test]
+    fn player_stats_are_for_one_event_and_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 21), 1));
+        archive.record(game("7", date!(2026 - 06 - 22), 4));
+        archive.record(ArchivedGame {
+            event_id: Some(EventId::from_partial("autumn-cup")),
+            ..game("2", date!(2026 - 06 - 22), 0)
+        });
+        let event_id = EventId::from_partial("spring-cup");
+        assert_eq!(
+            archive.latest_event(),
+            Some(&EventId::from_partial("autumn-cup"))
+        );
+
+        let stats = archive.player_stats(&event_id);
+        assert_eq!(stats.games, 2);
+        let scorers: Vec<_> = stats
+            .top_scorers()
+            .into_iter()
+            .map(|p| (p.team_display(), p.cap_number, p.goals))
+            .collect();
+        assert_eq!(scorers, vec![("Sharks, North", 4, 2)]);
+
+        let paths = export_player_stats(&stats, &event_id, dir.path()).unwrap();
+        let csv = fs::read_to_string(&paths[1]).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            vec![
+                "Team,Cap,Player,Goals,Penalties,Penalty Time,Total Dismissals,Fouls",
+                "\"Sharks, North\",4,,2,0,0:00,0,0",
+            ]
+        );
+    }
+
+    #[test]
+    fn an_archive_without_team_ids_still_loads() {
+        let mut json = serde_json::to_value(game("1", date!(2026 - 06 - 22), 1)).unwrap();
+        json.as_object_mut().unwrap().remove("team_ids");
+        let game: ArchivedGame = serde_json::from_value(json).unwrap();
+        assert_eq!(game.team_ids, BlackWhiteBundle::default());
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut archive = ResultsArchive::default();
+        archive.record(game("1", date!(2026 - 06 - 22), 1));
+        save(dir.path(), &archive).unwrap();
+        assert_eq!(load_or_default(dir.path()).unwrap(), archive);
+    }
+
+    #[test]
+    fn a_corrupt_archive_is_set_aside() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(state_file::path(dir.path(), FILE_STEM), b"not json").unwrap();
+        assert!(load_or_default(dir.path()).unwrap().is_empty());
+        assert!(!state_file::path(dir.path(), FILE_STEM).exists());
+        let renamed = fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| {
+                e.file_name()
+                    .to_string_lossy()
+                    .starts_with("results.corrupt.")
+            });
+        assert!(renamed);
+    }
+}
@@ -0,0 +1,476 @@
+//! Rule profiles describe the parts of the rules that differ between federations: the penalty
+//! lengths offered in each mode, the periods penalty time is served in, and the infractions
+//! referees can call. A profile is loaded from a TOML file named in the config; without one the
+//! built-in profile, which matches the CMAS rules, is used.
+
+use crate::{config::Mode, tournament_manager::penalty::PenaltyKind};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+use uwh_common::{
+    config::Game as GameConfig,
+    game_snapshot::{GamePeriod, Infraction},
+};
+
+/// The longest penalty the wire format can carry. 511 s is reserved for total dismissals.
+pub const MAX_PENALTY_SECS: u16 = 510;
+/// The penalty page has room for three timed penalty buttons next to the total dismissal one
+pub const MAX_PENALTY_LENGTHS: usize = 3;
+/// The infraction picker has two rows of six buttons, one of which is `Unknown`
+pub const MAX_INFRACTIONS: usize = 11;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleProfile {
+    pub name: String,
+    pub penalties: PenaltyRules,
+    pub infractions: Vec<InfractionRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PenaltyRules {
+    /// Timed penalty lengths in seconds offered in each mode, from least to most severe. A total
+    /// dismissal is always offered too.
+    pub hockey_6v6: Vec<u16>,
+    pub hockey_3v3: Vec<u16>,
+    pub rugby: Vec<u16>,
+    /// The periods during which penalty time counts down
+    pub served_in: Vec<GamePeriod>,
+    /// How many of their team's following games a player given a total dismissal sits out.
+    /// 0 turns suspension tracking off.
+    #[serde(default = "default_dismissal_suspension_games")]
+    pub dismissal_suspension_games: u16,
+}
+
+fn default_dismissal_suspension_games() -> u16 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InfractionRule {
+    /// The name the infraction is recorded under in game stats and snapshots
+    pub id: Infraction,
+    /// Infractions from the built-in profile fall back to their translated names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+    /// A PNG file, relative to the profile. Infractions from the built-in profile fall back to
+    /// their built-in icons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<PathBuf>,
+    #[serde(skip)]
+    pub icon_png: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Error)]
+pub enum RuleProfileError {
+    #[error("Could not read {0:?}: {1}")]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Could not parse the rule profile: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("{0:?} needs between 1 and {MAX_PENALTY_LENGTHS} penalty lengths")]
+    PenaltyLengthCount(Mode),
+    #[error("A penalty length of {0} s is not between 1 and {MAX_PENALTY_SECS} s")]
+    PenaltyLength(u16),
+    #[error("Penalty time can't be served during {0:?}")]
+    ServedIn(GamePeriod),
+    #[error("Infraction {0} is listed more than once")]
+    DuplicateInfraction(Infraction),
+    #[error(
+        "Infraction {} is always available and can't be listed",
+        Infraction::UNKNOWN
+    )]
+    UnknownListed,
+    #[error("At most {MAX_INFRACTIONS} infractions can be listed")]
+    TooManyInfractions,
+}
+
+pub type RuleProfileResult<T> = std::result::Result<T, RuleProfileError>;
+
+impl Default for RuleProfile {
+    fn default() -> Self {
+        Self {
+            name: "Built-in".to_string(),
+            penalties: PenaltyRules {
+                hockey_6v6: vec![60, 120, 300],
+                hockey_3v3: vec![30, 60, 120],
+                rugby: vec![120, 240, 300],
+                served_in: vec![
+                    GamePeriod::FirstHalf,
+                    GamePeriod::SecondHalf,
+                    GamePeriod::OvertimeFirstHalf,
+                    GamePeriod::OvertimeSecondHalf,
+                    GamePeriod::SuddenDeath,
+                ],
+                dismissal_suspension_games: default_dismissal_suspension_games(),
+            },
+            infractions: Infraction::BUILT_IN
+                .into_iter()
+                .map(|id| InfractionRule {
+                    id,
+                    short_name: None,
+                    icon: None,
+                    icon_png: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl RuleProfile {
+    /// Parses and validates a profile. Icons are not loaded, use `load` for that.
+    pub fn parse(text: &str) -> RuleProfileResult<Self> {
+        let profile: Self = toml::from_str(text)?;
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    /// Reads a profile from `path`, along with any icons it names
+    pub fn load(path: &Path) -> RuleProfileResult<Self> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| RuleProfileError::Io(path.into(), e))?;
+        let mut profile = Self::parse(&text)?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for rule in profile.infractions.iter_mut() {
+            if let Some(icon) = &rule.icon {
+                let icon_path = dir.join(icon);
+                rule.icon_png = Some(
+                    std::fs::read(&icon_path).map_err(|e| RuleProfileError::Io(icon_path, e))?,
+                );
+            }
+        }
+        Ok(profile)
+    }
+
+    pub fn validate(&self) -> RuleProfileResult<()> {
+        self.penalties.validate()?;
+
+        if self.infractions.len() > MAX_INFRACTIONS {
+            return Err(RuleProfileError::TooManyInfractions);
+        }
+        let mut seen = HashSet::new();
+        for rule in &self.infractions {
+            if rule.id == Infraction::UNKNOWN {
+                return Err(RuleProfileError::UnknownListed);
+            }
+            if !seen.insert(rule.id) {
+                return Err(RuleProfileError::DuplicateInfraction(rule.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// The infractions to offer, starting with `Unknown`
+    pub fn infraction_choices(&self) -> impl Iterator<Item = Infraction> + Clone + '_ {
+        std::iter::once(Infraction::UNKNOWN).chain(self.infractions.iter().map(|rule| rule.id))
+    }
+
+    fn rule(&self, infraction: Infraction) -> Option<&InfractionRule> {
+        self.infractions.iter().find(|rule| rule.id == infraction)
+    }
+
+    /// The short name given by the profile, if any
+    pub fn short_name(&self, infraction: Infraction) -> Option<&str> {
+        self.rule(infraction)?.short_name.as_deref()
+    }
+
+    /// An untranslated name, for logs
+    pub fn log_name(&self, infraction: Infraction) -> String {
+        self.short_name(infraction)
+            .or_else(|| infraction.builtin_short_name())
+            .unwrap_or_else(|| infraction.id())
+            .to_string()
+    }
+
+    /// The PNG icon for `infraction`, falling back to the built-in icon and then to the one for
+    /// `Unknown`
+    pub fn icon(&self, infraction: Infraction) -> &[u8] {
+        self.rule(infraction)
+            .and_then(|rule| rule.icon_png.as_deref())
+            .or_else(|| infraction.builtin_image())
+            .or_else(|| Infraction::UNKNOWN.builtin_image())
+            .unwrap_or_default()
+    }
+}
+
+impl PenaltyRules {
+    fn validate(&self) -> RuleProfileResult<()> {
+        for (mode, lengths) in [
+            (Mode::Hockey6V6, &self.hockey_6v6),
+            (Mode::Hockey3V3, &self.hockey_3v3),
+            (Mode::Rugby, &self.rugby),
+        ] {
+            if lengths.is_empty() || lengths.len() > MAX_PENALTY_LENGTHS {
+                return Err(RuleProfileError::PenaltyLengthCount(mode));
+            }
+            if let Some(secs) = lengths.iter().find(|s| !(1..=MAX_PENALTY_SECS).contains(s)) {
+                return Err(RuleProfileError::PenaltyLength(*secs));
+            }
+        }
+
+        if let Some(period) = self
+            .served_in
+            .iter()
+            .find(|p| matches!(p, GamePeriod::BetweenGames | GamePeriod::PenaltyShootout))
+        {
+            return Err(RuleProfileError::ServedIn(*period));
+        }
+        Ok(())
+    }
+
+    /// The timed penalties offered in `mode`, from least to most severe
+    pub fn kinds(&self, mode: Mode) -> Vec<PenaltyKind> {
+        let lengths: &[u16] = match mode {
+            Mode::Hockey6V6 => &self.hockey_6v6,
+            Mode::Hockey3V3 => &self.hockey_3v3,
+            Mode::Rugby => &self.rugby,
+            Mode::BeepTest => &[],
+        };
+        lengths.iter().copied().map(PenaltyKind::Timed).collect()
+    }
+
+    /// The penalty preselected when adding a new one in `mode`
+    pub fn default_kind(&self, mode: Mode) -> PenaltyKind {
+        self.kinds(mode).first().copied().unwrap_or_default()
+    }
+
+    /// Whether penalty time counts down during `period`. Overtime and sudden death periods only
+    /// count when the config allows them to be played.
+    pub fn penalties_run(&self, period: GamePeriod, config: &GameConfig) -> bool {
+        let reachable = match period {
+            GamePeriod::BetweenGames | GamePeriod::PenaltyShootout => false,
+            GamePeriod::FirstHalf | GamePeriod::HalfTime | GamePeriod::SecondHalf => true,
+            GamePeriod::PreOvertime
+            | GamePeriod::OvertimeFirstHalf
+            | GamePeriod::OvertimeHalfTime
+            | GamePeriod::OvertimeSecondHalf => config.overtime_allowed,
+            GamePeriod::PreSuddenDeath | GamePeriod::SuddenDeath => config.sudden_death_allowed,
+        };
+        reachable && self.served_in.contains(&period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+name = "Example league"
+
+[penalties]
+hockey_6v6 = [60, 120]
+hockey_3v3 = [30, 60, 120]
+rugby = [120, 300]
+served_in = ["FirstHalf", "HalfTime", "SecondHalf", "SuddenDeath"]
+dismissal_suspension_games = 2
+
+[[infractions]]
+id = "StickInfringement"
+
+[[infractions]]
+id = "SnorkelGrab"
+short_name = "Snorkel"
+icon = "snorkel.png"
+"#;
+
+    #[test]
+    fn default_profile_matches_the_built_in_rules() {
+        let rules = RuleProfile::default();
+        rules.validate().unwrap();
+        assert_eq!(
+            rules.penalties.kinds(Mode::Hockey6V6),
+            vec![
+                PenaltyKind::ONE_MINUTE,
+                PenaltyKind::TWO_MINUTE,
+                PenaltyKind::FIVE_MINUTE
+            ]
+        );
+        assert_eq!(
+            rules.penalties.kinds(Mode::Hockey3V3),
+            vec![
+                PenaltyKind::THIRTY_SECOND,
+                PenaltyKind::ONE_MINUTE,
+                PenaltyKind::TWO_MINUTE
+            ]
+        );
+        assert_eq!(
+            rules.penalties.kinds(Mode::Rugby),
+            vec![
+                PenaltyKind::TWO_MINUTE,
+                PenaltyKind::FOUR_MINUTE,
+                PenaltyKind::FIVE_MINUTE
+            ]
+        );
+        assert!(rules.penalties.kinds(Mode::BeepTest).is_empty());
+        assert_eq!(
+            rules.infraction_choices().collect::<Vec<_>>()[1..],
+            Infraction::BUILT_IN
+        );
+        assert_eq!(rules.log_name(Infraction::FREE_ARM), "Free Arm");
+        assert_eq!(rules.penalties.dismissal_suspension_games, 1);
+    }
+
+    #[test]
+    fn default_profile_serves_penalties_in_played_periods() {
+        let rules = RuleProfile::default().penalties;
+        let all_periods_config = GameConfig {
+            overtime_allowed: true,
+            sudden_death_allowed: true,
+            ..Default::default()
+        };
+        let sd_only_config = GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: true,
+            ..Default::default()
+        };
+        let no_sd_no_ot_config = GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            ..Default::default()
+        };
+
+        let run = |period, config| rules.penalties_run(period, config);
+        for config in [&all_periods_config, &sd_only_config, &no_sd_no_ot_config] {
+            assert!(!run(GamePeriod::BetweenGames, config));
+            assert!(run(GamePeriod::FirstHalf, config));
+            assert!(!run(GamePeriod::HalfTime, config));
+            assert!(run(GamePeriod::SecondHalf, config));
+            assert!(!run(GamePeriod::PreOvertime, config));
+            assert!(!run(GamePeriod::OvertimeHalfTime, config));
+            assert!(!run(GamePeriod::PreSuddenDeath, config));
+            assert!(!run(GamePeriod::PenaltyShootout, config));
+        }
+
+        assert!(run(GamePeriod::OvertimeFirstHalf, &all_periods_config));
+        assert!(run(GamePeriod::OvertimeSecondHalf, &all_periods_config));
+        assert!(run(GamePeriod::SuddenDeath, &all_periods_config));
+
+        assert!(!run(GamePeriod::OvertimeFirstHalf, &sd_only_config));
+        assert!(!run(GamePeriod::OvertimeSecondHalf, &sd_only_config));
+        assert!(run(GamePeriod::SuddenDeath, &sd_only_config));
+
+        assert!(!run(GamePeriod::OvertimeFirstHalf, &no_sd_no_ot_config));
+        assert!(!run(GamePeriod::OvertimeSecondHalf, &no_sd_no_ot_config));
+        assert!(!run(GamePeriod::SuddenDeath, &no_sd_no_ot_config));
+    }
+
+    #[test]
+    fn parse_reads_every_section() {
+        let rules = RuleProfile::parse(EXAMPLE).unwrap();
+        assert_eq!(rules.name, "Example league");
+        assert_eq!(rules.penalties.dismissal_suspension_games, 2);
+        assert_eq!(
+            RuleProfile::parse(&EXAMPLE.replace("dismissal_suspension_games = 2\n", ""))
+                .unwrap()
+                .penalties
+                .dismissal_suspension_games,
+            1
+        );
+        assert_eq!(
+            rules.penalties.kinds(Mode::Rugby),
+            vec![PenaltyKind::TWO_MINUTE, PenaltyKind::FIVE_MINUTE]
+        );
+        assert_eq!(
+            rules.penalties.default_kind(Mode::Hockey3V3),
+            PenaltyKind::THIRTY_SECOND
+        );
+        assert!(
+            rules
+                .penalties
+                .penalties_run(GamePeriod::HalfTime, &GameConfig::default())
+        );
+
+        let snorkel = Infraction::new("SnorkelGrab").unwrap();
+        assert_eq!(
+            rules.infraction_choices().collect::<Vec<_>>(),
+            vec![Infraction::UNKNOWN, Infraction::STICK_INFRINGEMENT, snorkel]
+        );
+        assert_eq!(rules.short_name(snorkel), Some("Snorkel"));
+        assert_eq!(rules.short_name(Infraction::STICK_INFRINGEMENT), None);
+        assert_eq!(rules.log_name(Infraction::STICK_INFRINGEMENT), "Stick Foul");
+        // Not loaded by `parse`, so the Unknown icon stands in
+        assert_eq!(rules.icon(snorkel), rules.icon(Infraction::UNKNOWN));
+    }
+
+    #[test]
+    fn load_reads_icons_next_to_the_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rules.toml");
+        std::fs::write(&path, EXAMPLE).unwrap();
+        assert!(matches!(
+            RuleProfile::load(&path),
+            Err(RuleProfileError::Io(p, _)) if p == dir.path().join("snorkel.png")
+        ));
+
+        std::fs::write(dir.path().join("snorkel.png"), [1, 2, 3]).unwrap();
+        let rules = RuleProfile::load(&path).unwrap();
+        assert_eq!(
+            rules.icon(Infraction::new("SnorkelGrab").unwrap()),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            rules.icon(Infraction::STICK_INFRINGEMENT),
+            Infraction::STICK_INFRINGEMENT.builtin_image().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_profiles_are_rejected() {
+        let with = |from: &str, to: &str| RuleProfile::parse(&EXAMPLE.replace(from, to));
+
+        assert!(matches!(
+            with("rugby = [120, 300]", "rugby = []"),
+            Err(RuleProfileError::PenaltyLengthCount(Mode::Rugby))
+        ));
+        assert!(matches!(
+            with("[30, 60, 120]", "[30, 60, 120, 240]"),
+            Err(RuleProfileError::PenaltyLengthCount(Mode::Hockey3V3))
+        ));
+        assert!(matches!(
+            with("[60, 120]", "[60, 600]"),
+            Err(RuleProfileError::PenaltyLength(600))
+        ));
+        assert!(matches!(
+            with("[60, 120]", "[0, 120]"),
+            Err(RuleProfileError::PenaltyLength(0))
+        ));
+        assert!(matches!(
+            with("\"SuddenDeath\"]", "\"PenaltyShootout\"]"),
+            Err(RuleProfileError::ServedIn(GamePeriod::PenaltyShootout))
+        ));
+        assert!(matches!(
+            with("\"SnorkelGrab\"", "\"StickInfringement\""),
+            Err(RuleProfileError::DuplicateInfraction(
+                Infraction::STICK_INFRINGEMENT
+            ))
+        ));
+        assert!(matches!(
+            with("\"SnorkelGrab\"", "\"Unknown\""),
+            Err(RuleProfileError::UnknownListed)
+        ));
+        assert!(matches!(
+            with("\"SnorkelGrab\"", "\"AnInfractionIdThatIsFarTooLongToUse\""),
+            Err(RuleProfileError::Parse(_))
+        ));
+        assert!(matches!(
+            with("name =", "colour = \"blue\"\nname ="),
+            Err(RuleProfileError::Parse(_))
+        ));
+
+        let mut too_many = RuleProfile::default();
+        too_many.infractions.push(InfractionRule {
+            id: Infraction::new("Extra").unwrap(),
+            short_name: None,
+            icon: None,
+            icon_png: None,
+        });
+        assert!(matches!(
+            too_many.validate(),
+            Err(RuleProfileError::TooManyInfractions)
+        ));
+    }
+}
@@ -0,0 +1,434 @@
+//! A schedule on disk, for events with no network at all ("schedule file" game source).
+//!
+//! The folder holds two files. `schedule.json` is exactly what schedule-processor's "Save
+//! Schedule to File" writes. `event.json` supplies what the portal would otherwise fill in around
+//! it: the event's id and name, its teams with their rosters, and optionally the names of the
+//! referees the schedule assigns:
+//!
+//! ```json
+//! {
+//!   "eventId": "events/1234-A",
+//!   "name": "Spring Cup",
+//!   "teams": [
+//!     {
+//!       "id": "teams/10753-A",
+//!       "name": "Sharks",
+//!       "roster": [{ "number": 7, "name": "Sam", "isCaptain": true }]
+//!     }
+//!   ],
+//!   "referees": { "users/42-A": "Alex" }
+//! }
+//! ```
+//!
+//! The folder is the one named in the config, or else a `refbox-schedule` folder on a USB stick,
+//! or else one next to the config file.
+
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+use time::OffsetDateTime;
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    game_data::{self, GameDataSource, SourceError, SourceFuture, Unsupported, with_referee_names},
+    uwhportal::{
+        RosterPlayer,
+        schedule::{
+            DateRange, Event, EventId, GameNumber, Schedule, SendableSchedule, TeamId, TeamList,
+        },
+    },
+};
+
+pub const SCHEDULE_FILE_NAME: &str = "schedule.json";
+pub const EVENT_FILE_NAME: &str = "event.json";
+/// The folder looked for on a USB stick and next to the config file
+pub const FOLDER_NAME: &str = "refbox-schedule";
+
+#[derive(Debug, Error)]
+pub enum ScheduleFileError {
+    #[error("Could not read {0:?}: {1}")]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Could not parse {0:?}: {1}")]
+    Parse(PathBuf, #[source] serde_json::Error),
+    #[error("Game {0} has team {1:?}, which is not in the event's team list")]
+    UnknownTeam(GameNumber, TeamId),
+    #[error("The schedule folder holds event {0:?}, not {1:?}")]
+    OtherEvent(EventId, EventId),
+    #[error("Team {0:?} is not in the schedule folder's team list")]
+    TeamNotInFile(TeamId),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventFile {
+    #[serde(rename = "eventId")]
+    event_id: EventId,
+    name: String,
+    #[serde(default)]
+    teams: Vec<TeamFile>,
+    /// Referee display names by user id, standing in for the portal's `/referees` endpoint
+    #[serde(default)]
+    referees: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TeamFile {
+    id: TeamId,
+    name: String,
+    #[serde(default)]
+    roster: Vec<PlayerFile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PlayerFile {
+    number: Option<u8>,
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "isCaptain")]
+    is_captain: bool,
+    #[serde(default, rename = "isViceCaptain")]
+    is_vice_captain: bool,
+}
+
+/// Everything the portal path would have fetched for one event
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleFile {
+    /// The folder it was loaded from
+    pub folder: PathBuf,
+    /// The event with its teams filled in. Its schedule and courts are left for the app to fill
+    /// in from `schedule`, the same way it does for a fetched schedule.
+    pub event: Event,
+    pub schedule: Schedule,
+    pub rosters: BTreeMap<TeamId, Vec<RosterPlayer>>,
+    /// Referee display names by user id, already applied to `schedule`
+    pub referees: HashMap<String, String>,
+}
+
+/// The schedule folder to use: `configured` (relative to `config_dir`) when set, otherwise the
+/// first `refbox-schedule` folder found on a USB stick or next to the config file. A folder only
+/// counts if it has a schedule in it.
+pub fn find_folder(configured: Option<&Path>, config_dir: &Path) -> Option<PathBuf> {
+    if let Some(configured) = configured {
+        return Some(config_dir.join(configured));
+    }
+    crate::results_archive::mounted_drives()
+        .into_iter()
+        .chain([config_dir.to_path_buf()])
+        .map(|dir| dir.join(FOLDER_NAME))
+        .find(|folder| folder.join(SCHEDULE_FILE_NAME).is_file())
+}
+
+pub fn load(folder: &Path) -> Result<ScheduleFile, ScheduleFileError> {
+    let event_file: EventFile = read_json(&folder.join(EVENT_FILE_NAME))?;
+    let sendable: SendableSchedule = read_json(&folder.join(SCHEDULE_FILE_NAME))?;
+    let schedule = Schedule::from((sendable, event_file.event_id.clone()));
+
+    let teams: BTreeMap<_, _> = event_file
+        .teams
+        .iter()
+        .map(|team| (team.id.clone(), team.name.clone()))
+        .collect();
+    // A team the team list does not know is most likely an `event.json` from a different event,
+    // which would otherwise only show up as blank team names once games are under way
+    for game in schedule.games.values() {
+        for team in [&game.dark, &game.light] {
+            if let Some(id) = team.assigned() {
+                if !teams.contains_key(id) {
+                    return Err(ScheduleFileError::UnknownTeam(
+                        game.number.clone(),
+                        id.clone(),
+                    ));
+                }
+            }
+        }
+    }
+
+    let referees: HashMap<_, _> = event_file.referees.into_iter().collect();
+    let schedule = with_referee_names(schedule, &referees);
+
+    let rosters = event_file
+        .teams
+        .into_iter()
+        .map(|team| {
+            let mut players: Vec<_> = team
+                .roster
+                .into_iter()
+                .map(|p| RosterPlayer {
+                    number: p.number,
+                    name: p.name,
+                    is_captain: p.is_captain,
+                    is_vice_captain: p.is_vice_captain,
+                })
+                .collect();
+            players.sort_by_key(|p| p.number);
+            (team.id, players)
+        })
+        .collect();
+
+    // The file has no date range of its own, so the event spans its games
+    let now = OffsetDateTime::now_utc();
+    let start_times = || schedule.games.values().map(|g| g.start_time);
+    let date_range = DateRange {
+        start: start_times().min().unwrap_or(now),
+        end: start_times().max().unwrap_or(now),
+    };
+
+    Ok(ScheduleFile {
+        folder: folder.to_path_buf(),
+        event: Event {
+            id: event_file.event_id,
+            name: event_file.name,
+            slug: String::new(),
+            date_range,
+            teams: Some(teams),
+            schedule: None,
+            courts: None,
+        },
+        schedule,
+        rosters,
+        referees,
+    })
+}
+
+/// The folder answers from what was loaded. It is only read again when the app loads it again,
+/// so that an edit made mid-game cannot move the schedule under the operator's hand.
+impl GameDataSource for ScheduleFile {
+    fn list_events(&self, past: bool, _schedule_published: bool) -> SourceFuture<Vec<Event>> {
+        let events = if past {
+            Vec::new()
+        } else {
+            vec![self.event.clone()]
+        };
+        game_data::ready(Ok(events))
+    }
+
+    fn schedule(&self, event_id: &EventId) -> SourceFuture<Schedule> {
+        game_data::ready(self.check_event(event_id).map(|()| self.schedule.clone()))
+    }
+
+    fn teams(&self, event_id: &EventId) -> SourceFuture<TeamList> {
+        game_data::ready(
+            self.check_event(event_id)
+                .map(|()| self.event.teams.clone().unwrap_or_default()),
+        )
+    }
+
+    fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>> {
+        game_data::ready(self.rosters.get(team_id).cloned().ok_or_else(|| {
+            SourceError::Other(ScheduleFileError::TeamNotInFile(team_id.clone()).into())
+        }))
+    }
+
+    fn referee_names(&self, event_id: &EventId) -> SourceFuture<HashMap<String, String>> {
+        game_data::ready(self.check_event(event_id).map(|()| self.referees.clone()))
+    }
+
+    fn submit_scores(
+        &self,
+        _event_id: &EventId,
+        _game_number: &GameNumber,
+        _scores: BlackWhiteBundle<u8>,
+        _force: bool,
+    ) -> SourceFuture<()> {
+        game_data::ready(Err(UNSUPPORTED_SUBMIT.into()))
+    }
+
+    fn submit_stats(
+        &self,
+        _event_id: &EventId,
+        _game_number: &GameNumber,
+        _stats_json: String,
+    ) -> SourceFuture<()> {
+        game_data::ready(Err(UNSUPPORTED_SUBMIT.into()))
+    }
+}
+
+const UNSUPPORTED_SUBMIT: Unsupported = Unsupported {
+    source: "schedule folder",
+    call: "accept results",
+};
+
+impl ScheduleFile {
+    fn check_event(&self, event_id: &EventId) -> Result<(), SourceError> {
+        if *event_id == self.event.id {
+            Ok(())
+        } else {
+            let e = ScheduleFileError::OtherEvent(self.event.id.clone(), event_id.clone());
+            Err(SourceError::Other(e.into()))
+        }
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, ScheduleFileError> {
+    let bytes = fs::read(path).map_err(|e| ScheduleFileError::Io(path.to_path_buf(), e))?;
+    serde_json::from_slice(&bytes).map_err(|e| ScheduleFileError::Parse(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEDULE: &str = r#"{
+        "games": [
+            {
+                "number": "2",
+                "dark": { "teamId": "teams/3-A" },
+                "light": { "teamId": "teams/1-A" },
+                "startsOn": "2026-06-27T09:30:00Z",
+                "court": "1",
+                "timingRule": { "name": "RR" },
+                "refereeAssignments": [{ "role": "Chief Referee", "userId": "users/42-A" }]
+            },
+            {
+                "number": "1",
+                "dark": { "teamId": "teams/1-A" },
+                "light": { "teamId": "teams/3-A" },
+                "startsOn": "2026-06-27T09:00:00Z",
+                "court": "1",
+                "timingRule": { "name": "RR" }
+            }
+        ],
+        "nonGameEntries": [],
+        "groups": [],
+        "timingRules": []
+    }"#;
+
+    const EVENT: &str = r#"{
+        "eventId": "events/spring-cup",
+        "name": "Spring Cup",
+        "teams": [
+            {
+                "id": "teams/1-A",
+                "name": "Sharks",
+                "roster": [
+                    { "number": 9, "name": "Kim" },
+                    { "number": 3, "name": "Sam", "isCaptain": true }
+                ]
+            },
+            { "id": "teams/3-A", "name": "Eels" }
+        ],
+        "referees": { "users/42-A": "Alex" }
+    }"#;
+
+    fn folder(schedule: &str, event: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(SCHEDULE_FILE_NAME), schedule).unwrap();
+        fs::write(dir.path().join(EVENT_FILE_NAME), event).unwrap();
+        dir
+    }
+
+    #[test]
+    fn loads_schedule_teams_rosters_and_referees() {
+        let dir = folder(SCHEDULE, EVENT);
+        let file = load(dir.path()).unwrap();
+
+        let event_id = EventId::from_partial("spring-cup");
+        assert_eq!(file.event.id, event_id);
+        assert_eq!(file.event.name, "Spring Cup");
+        assert_eq!(file.schedule.event_id, event_id);
+        assert_eq!(
+            file.event.teams.as_ref().unwrap()[&TeamId::from_partial("1-A")],
+            "Sharks"
+        );
+        assert_eq!(file.event.date_range.start.hour(), 9);
+        assert_eq!(file.event.date_range.end.minute(), 30);
+
+        let chief = &file.schedule.games["2"]
+            .referee_assignments
+            .as_ref()
+            .unwrap()[0];
+        assert_eq!(chief.display_name.as_deref(), Some("Alex"));
+
+        let sharks = &file.rosters[&TeamId::from_partial("1-A")];
+        assert_eq!(
+            sharks.iter().map(|p| p.number).collect::<Vec<_>>(),
+            [Some(3), Some(9)]
+        );
+        assert!(sharks[0].is_captain);
+        assert!(file.rosters[&TeamId::from_partial("3-A")].is_empty());
+    }
+
+    #[test]
+    fn a_team_missing_from_the_team_list_is_an_error() {
+        let event = EVENT.replace("teams/3-A", "teams/4-A");
+        let dir = folder(SCHEDULE, &event);
+        assert!(matches!(
+            load(dir.path()),
+            Err(ScheduleFileError::UnknownTeam(game, team))
+                if game == "2" && team == TeamId::from_partial("3-A")
+        ));
+    }
+
+    #[test]
+    fn a_missing_event_file_names_the_file() {
+        let dir = folder(SCHEDULE, EVENT);
+        fs::remove_file(dir.path().join(EVENT_FILE_NAME)).unwrap();
+        match load(dir.path()) {
+            Err(ScheduleFileError::Io(path, _)) => assert!(path.ends_with(EVENT_FILE_NAME)),
+            other => panic!("expected an Io error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn answers_as_a_source_for_its_own_event_only() {
+        let dir = folder(SCHEDULE, EVENT);
+        let file = load(dir.path()).unwrap();
+        let event_id = EventId::from_partial("spring-cup");
+
+        let events = file.list_events(false, true).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0], file.event);
+        assert_eq!(file.schedule(&event_id).await.unwrap(), file.schedule);
+        assert_eq!(file.teams(&event_id).await.unwrap().len(), 2);
+        assert_eq!(
+            file.referee_names(&event_id).await.unwrap()["users/42-A"],
+            "Alex"
+        );
+        let sharks = file.roster(&TeamId::from_partial("1-A")).await.unwrap();
+        assert_eq!(sharks.len(), 2);
+
+        let other = EventId::from_partial("autumn-cup");
+        assert!(file.schedule(&other).await.is_err());
+        assert!(file.roster(&TeamId::from_partial("9-A")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn refuses_results() {
+        let dir = folder(SCHEDULE, EVENT);
+        let file = load(dir.path()).unwrap();
+        let event_id = EventId::from_partial("spring-cup");
+
+        let scores = BlackWhiteBundle { black: 2, white: 0 };
+        let e = file
+            .submit_scores(&event_id, &"1".to_string(), scores, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(e, SourceError::Unsupported(_)));
+        assert!(
+            file.submit_stats(&event_id, &"1".to_string(), String::new())
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn finds_the_folder_next_to_the_config_file() {
+        let config_dir = tempfile::tempdir().unwrap();
+        assert_eq!(find_folder(None, config_dir.path()), None);
+
+        let folder = config_dir.path().join(FOLDER_NAME);
+        fs::create_dir(&folder).unwrap();
+        fs::write(folder.join(SCHEDULE_FILE_NAME), SCHEDULE).unwrap();
+        assert_eq!(find_folder(None, config_dir.path()), Some(folder));
+
+        assert_eq!(
+            find_folder(Some(Path::new("/mnt/stick")), config_dir.path()),
+            Some(PathBuf::from("/mnt/stick"))
+        );
+    }
+}
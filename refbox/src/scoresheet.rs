@@ -0,0 +1,990 @@
+//! Completed scoresheets, written by the refbox after each game.
+//!
+//! schedule-processor prints the blank sheets that are otherwise filled in by hand at the signing
+//! table. This fills one in from the refbox's own record of the game instead: every goal with its
+//! time and scorer, the penalties, warnings, fouls and timeouts, the referees' incident reports,
+//! the referees and the final score.
+//! `Detailed` gives each team its own half of a landscape page, like schedule-processor's Detailed
+//! sheet, while `Simple` is a portrait page with one running log of the game, and `CmasOfficial`
+//! fills in the CMAS sheet's roster of goals by half, time penalties and team timeouts.
+//! schedule-processor's SimpleTeamRefs and Col3x3 sheets are only for writing on by hand, so they
+//! have no completed counterpart.
+//!
+//! Sheets are saved as HTML in a `scoresheets` folder next to `results.json`, and printed to PDF
+//! next to it when a browser is available to do the printing.
+
+use crate::{
+    config::ScoresheetStyle,
+    results_archive::ArchivedGame,
+    rule_profile::RuleProfile,
+    tournament_manager::{SheetLine, SheetLineKind, TeamSignOff, TimeoutKind},
+};
+use log::*;
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use time::macros::format_description;
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    color::Color,
+    game_snapshot::{GamePeriod, GoalDisallowReason},
+};
+
+const DIR_NAME: &str = "scoresheets";
+
+/// Everything that goes on one game's sheet
+pub(crate) struct CompletedGame<'a> {
+    pub(crate) event_name: Option<&'a str>,
+    pub(crate) court: Option<&'a str>,
+    pub(crate) game: &'a ArchivedGame,
+    pub(crate) lines: &'a [SheetLine],
+    /// Player names by cap number, from the team rosters
+    pub(crate) players: BlackWhiteBundle<BTreeMap<u8, String>>,
+    /// How the captains signed off the result on the refbox, if they were asked to
+    pub(crate) sign_offs: Option<BlackWhiteBundle<TeamSignOff>>,
+    /// The rule profile the game was played under, for the infraction names
+    pub(crate) rules: &'a RuleProfile,
+}
+
+/// Where the sheets go
+pub fn dir(config_dir: &Path) -> PathBuf {
+    config_dir.join(DIR_NAME)
+}
+
+/// Writes the sheet for `game` as HTML into `dir` and returns its path. A game that is finished
+/// again (after an undo back into it) overwrites its earlier sheet.
+pub(crate) fn save(
+    dir: &Path,
+    style: ScoresheetStyle,
+    game: &CompletedGame,
+) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let day = game
+        .game
+        .day
+        .format(format_description!("[year]-[month]-[day]"))
+        .map_err(std::io::Error::other)?;
+    let event = game
+        .game
+        .event_id
+        .as_ref()
+        .map(|id| format!("{}-", id.partial()))
+        .unwrap_or_default();
+    let number: String = game
+        .game
+        .game_number
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let path = dir.join(format!("scoresheet-{event}{day}-game-{number}.html"));
+    fs::write(&path, render_html(style, game))?;
+    info!("Wrote the scoresheet for game {number} to {path:?}");
+    Ok(path)
+}
+
+/// Prints the sheet at `html_path` to a PDF beside it. Slow, so it belongs on a blocking thread.
+pub fn print_pdf(html_path: &Path) -> Option<PathBuf> {
+    let pdf_path = html_path.with_extension("pdf");
+    if uwh_common::html_pdf::print_to_pdf(html_path, &pdf_path) {
+        Some(pdf_path)
+    } else {
+        warn!(
+            "No web browser was found to print {html_path:?} to PDF; only the HTML was saved. Set \
+             the {} environment variable to a browser's full path to use one that isn't found.",
+            uwh_common::html_pdf::BROWSER_ENV_VAR
+        );
+        None
+    }
+}
+
+pub(crate) fn render_html(style: ScoresheetStyle, game: &CompletedGame) -> String {
+    match style {
+        ScoresheetStyle::Off => unreachable!("no sheet is written when scoresheets are off"),
+        ScoresheetStyle::Detailed => render_detailed(game),
+        ScoresheetStyle::Simple => render_simple(game),
+        ScoresheetStyle::CmasOfficial => render_cmas_official(game),
+    }
+}
+
+const CSS: &str = "\
+body { font-family: Arial, Helvetica, sans-serif; font-size: 11px; margin: 0; }
+.page { padding: 8mm; }
+.head { background: #1f4e79; color: #fff; padding: 6px 10px; border-radius: 4px; }
+.tname { font-size: 18px; font-weight: bold; text-align: center; }
+.fields { display: flex; flex-wrap: wrap; gap: 4px 16px; justify-content: center; margin-top: 4px; }
+.lbl { font-weight: bold; margin-right: 4px; }
+.score { display: flex; justify-content: center; gap: 24px; font-size: 20px; font-weight: bold; margin: 8px 0; }
+.sides { display: flex; gap: 10px; }
+.side { flex: 1; border: 1px solid #000; padding: 6px; }
+.side.black { background: #eee; }
+.team { font-size: 14px; font-weight: bold; margin-bottom: 4px; }
+h3 { font-size: 12px; margin: 6px 0 2px; }
+table { width: 100%; border-collapse: collapse; }
+th, td { border: 1px solid #888; padding: 2px 4px; text-align: left; }
+th { background: #d9e2f3; }
+.none { color: #666; font-style: italic; }
+.struck { text-decoration: line-through; }
+.sigline { display: flex; gap: 20px; margin-top: 28px; }
+.sigline .line { flex: 1; border-top: 1px solid #000; text-align: center; padding-top: 2px; font-weight: bold; }
+.sigline .signoff { font-weight: normal; }
+";
+
+fn render_detailed(game: &CompletedGame) -> String {
+    let side = |color: Color| {
+        let of_team = |pred: fn(&SheetLineKind) -> bool| -> Vec<&SheetLine> {
+            game.lines
+                .iter()
+                .filter(|line| line.color == Some(color) && pred(&line.kind))
+                .collect()
+        };
+        let players = &game.players[color];
+        let mut html = format!(
+            "<div class='team'>{} TEAM: {}</div>",
+            team_label(color),
+            html_escape(game.game.teams[color].as_deref().unwrap_or_default())
+        );
+        html.push_str(&table(
+            "Goals",
+            &["Period", "Time", "Player", ""],
+            of_team(|k| matches!(k, SheetLineKind::Goal { .. })),
+            |line| {
+                let note = match &line.kind {
+                    SheetLineKind::Goal {
+                        disallowed: Some(reason),
+                    } => format!("Disallowed: {}", disallow_reason(*reason)),
+                    _ => String::new(),
+                };
+                vec![
+                    period_label(line.period),
+                    clock_time(line.period_time),
+                    player_label(line.player_number, players),
+                    note,
+                ]
+            },
+        ));
+        html.push_str(&table(
+            "Penalties",
+            &["Period", "Time", "Player", "Penalty", "Infraction"],
+            of_team(|k| matches!(k, SheetLineKind::Penalty { .. })),
+            |line| {
+                let SheetLineKind::Penalty {
+                    duration,
+                    infraction,
+                } = &line.kind
+                else {
+                    unreachable!()
+                };
+                vec![
+                    period_label(line.period),
+                    clock_time(line.period_time),
+                    player_label(line.player_number, players),
+                    penalty_label(*duration),
+                    infraction
+                        .map(|i| game.rules.log_name(i))
+                        .unwrap_or_default(),
+                ]
+            },
+        ));
+        html.push_str(&table(
+            "Warnings & Fouls",
+            &["Period", "Time", "Player", "Called", "Infraction"],
+            of_team(|k| matches!(k, SheetLineKind::Warning(_) | SheetLineKind::Foul(_))),
+            |line| {
+                let (called, infraction) = match &line.kind {
+                    SheetLineKind::Warning(i) => ("Warning", i),
+                    SheetLineKind::Foul(i) => ("Foul", i),
+                    _ => unreachable!(),
+                };
+                vec![
+                    period_label(line.period),
+                    clock_time(line.period_time),
+                    player_label(line.player_number, players),
+                    called.to_string(),
+                    game.rules.log_name(*infraction),
+                ]
+            },
+        ));
+        html.push_str(&table(
+            "Timeouts",
+            &["Period", "Time"],
+            of_team(|k| matches!(k, SheetLineKind::Timeout(_))),
+            |line| vec![period_label(line.period), clock_time(line.period_time)],
+        ));
+        let attempts = of_team(|k| matches!(k, SheetLineKind::ShootoutAttempt { .. }));
+        if !attempts.is_empty() {
+            html.push_str(&table(
+                "Penalty Shootout",
+                &["Round", "Player", "Result"],
+                attempts,
+                |line| {
+                    let SheetLineKind::ShootoutAttempt { round, scored } = &line.kind else {
+                        unreachable!()
+                    };
+                    vec![
+                        round.to_string(),
+                        player_label(line.player_number, players),
+                        if *scored { "Scored" } else { "Missed" }.to_string(),
+                    ]
+                },
+            ));
+        }
+        html
+    };
+
+    // Ref timeouts, penalty shots and fouls on both teams belong to neither side
+    let neither: Vec<&SheetLine> = game.lines.iter().filter(|l| l.color.is_none()).collect();
+    let neither = if neither.is_empty() {
+        String::new()
+    } else {
+        table(
+            "Other Stoppages & Fouls",
+            &["Period", "Time", "Event"],
+            neither,
+            |line| {
+                vec![
+                    period_label(line.period),
+                    clock_time(line.period_time),
+                    event_label(game.rules, line),
+                ]
+            },
+        )
+    };
+
+    format!(
+        "<!doctype html><html><head><meta charset='utf-8'/>\
+         <title>Scoresheet G{game_number}</title>\
+         <style>@page {{ size: A4 landscape; margin: 0; }}\n{CSS}</style></head><body>\
+         <div class='page'>{header}\
+         <div class='sides'><div class='side white'>{white}</div>\
+         <div class='side black'>{black}</div></div>\
+         {neither}{incidents}{officials}{signatures}</div></body></html>",
+        game_number = html_escape(&game.game.game_number),
+        header = header(game),
+        white = side(Color::White),
+        black = side(Color::Black),
+        incidents = incidents(game),
+        officials = officials(game),
+        signatures = signatures(game),
+    )
+}
+
+fn render_simple(game: &CompletedGame) -> String {
+    let log = table(
+        "Game Log",
+        &["Period", "Time", "Team", "Player", "Event"],
+        game.lines.iter().collect(),
+        |line| {
+            vec![
+                period_label(line.period),
+                clock_time(line.period_time),
+                line.color.map(team_label).unwrap_or_default().to_string(),
+                line.color
+                    .map(|color| player_label(line.player_number, &game.players[color]))
+                    .unwrap_or_default(),
+                event_label(game.rules, line),
+            ]
+        },
+    );
+
+    format!(
+        "<!doctype html><html><head><meta charset='utf-8'/>\
+         <title>Scoresheet G{game_number}</title>\
+         <style>@page {{ size: A4 portrait; margin: 0; }}\n{CSS}</style></head><body>\
+         <div class='page'>{header}{log}{incidents}{officials}{signatures}</div></body></html>",
+        game_number = html_escape(&game.game.game_number),
+        header = header(game),
+        incidents = incidents(game),
+        officials = officials(game),
+        signatures = signatures(game),
+    )
+}
+
+/// Laid out like schedule-processor's CmasOfficial sheet: a roster of each team's goals by half,
+/// then the time penalties, team timeouts and referees, with the captains' signatures
+fn render_cmas_official(game: &CompletedGame) -> String {
+    const ROSTER_ROWS: usize = 12;
+
+    let archived = game.game;
+    let goals = |color: Color| {
+        game.lines.iter().filter(move |line| {
+            line.color == Some(color)
+                && matches!(line.kind, SheetLineKind::Goal { disallowed: None })
+        })
+    };
+    // Which of the 1st half, 2nd half and extra time columns a goal goes in
+    let column = |period: GamePeriod| match period {
+        GamePeriod::FirstHalf => 0,
+        GamePeriod::SecondHalf => 1,
+        _ => 2,
+    };
+    let count = |n: u8| {
+        if n == 0 { String::new() } else { n.to_string() }
+    };
+
+    let roster = |color: Color| {
+        let players = &game.players[color];
+        let mut by_cap: BTreeMap<u8, [u8; 3]> =
+            players.keys().map(|&number| (number, [0; 3])).collect();
+        let mut unknown = [0; 3];
+        for goal in goals(color) {
+            let halves = match goal.player_number {
+                Some(number) => by_cap.entry(number).or_default(),
+                None => &mut unknown,
+            };
+            halves[column(goal.period)] += 1;
+        }
+
+        let mut html = format!(
+            "<div class='team'>{} TEAM: {}</div><table><tr><th>CAP No</th><th>NAME</th>\
+             <th>1st Half Goals</th><th>2nd Half Goals</th><th>Extra Time Goals</th>\
+             <th>Sum Goals</th></tr>",
+            team_label(color),
+            html_escape(archived.teams[color].as_deref().unwrap_or_default())
+        );
+        let rows = by_cap.len().max(ROSTER_ROWS);
+        let mut caps = by_cap.iter().map(Some).chain(std::iter::repeat(None));
+        for _ in 0..rows {
+            match caps.next().flatten() {
+                Some((number, halves)) => {
+                    let _ = write!(
+                        html,
+                        "<tr><td>{number}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+                         <td>{}</td></tr>",
+                        html_escape(players.get(number).map_or("", String::as_str)),
+                        count(halves[0]),
+                        count(halves[1]),
+                        count(halves[2]),
+                        count(halves.iter().sum()),
+                    );
+                }
+                None => html.push_str(
+                    "<tr><td>&nbsp;</td><td></td><td></td><td></td><td></td><td></td></tr>",
+                ),
+            }
+        }
+        let _ = write!(
+            html,
+            "<tr><td colspan='2'>Unknown / Own Goals</td><td>{}</td><td>{}</td><td>{}</td>\
+             <td>{}</td></tr><tr><th colspan='5'>Total Score</th><th>{}</th></tr></table>",
+            count(unknown[0]),
+            count(unknown[1]),
+            count(unknown[2]),
+            count(unknown.iter().sum()),
+            archived.scores[color],
+        );
+        html
+    };
+
+    let penalties = table(
+        "Time Penalties",
+        &["#", "Mins", "Period", "Time"],
+        game.lines
+            .iter()
+            .filter(|line| matches!(line.kind, SheetLineKind::Penalty { .. }))
+            .collect(),
+        |line| {
+            let SheetLineKind::Penalty { duration, .. } = &line.kind else {
+                unreachable!()
+            };
+            let team = match line.color {
+                Some(Color::Black) => "(B)",
+                Some(Color::White) => "(W)",
+                None => "",
+            };
+            vec![
+                match line.player_number {
+                    Some(number) => format!("{number} {team}"),
+                    None => team.to_string(),
+                },
+                penalty_label(*duration),
+                period_label(line.period),
+                clock_time(line.period_time),
+            ]
+        },
+    );
+
+    let mut timeouts = String::from(
+        "<h3>Team Time Outs</h3><table><tr><th></th><th>1st Half</th><th>2nd Half</th>\
+         <th>Extra Time</th></tr>",
+    );
+    for color in [Color::Black, Color::White] {
+        let mut halves: [Vec<String>; 3] = Default::default();
+        for line in game
+            .lines
+            .iter()
+            .filter(|line| line.kind == SheetLineKind::Timeout(TimeoutKind::Team(color)))
+        {
+            halves[column(line.period)].push(clock_time(line.period_time));
+        }
+        let _ = write!(
+            timeouts,
+            "<tr><th>{}</th><td>{}</td><td>{}</td><td>{}</td></tr>",
+            team_label(color),
+            halves[0].join(", "),
+            halves[1].join(", "),
+            halves[2].join(", "),
+        );
+    }
+    timeouts.push_str("</table>");
+
+    let time_format = format_description!("[hour]:[minute]");
+    let mut fields = String::new();
+    let mut field = |label: &str, value: &str| {
+        let _ = write!(
+            fields,
+            "<div><span class='lbl'>{label}:</span>{}</div>",
+            html_escape(value)
+        );
+    };
+    field(
+        "Division",
+        archived.timing_rule.as_deref().unwrap_or_default(),
+    );
+    field("Game #", &archived.game_number);
+    field("Court #", game.court.unwrap_or_default());
+    field(
+        "Date",
+        &archived
+            .day
+            .format(format_description!("[year]-[month]-[day]"))
+            .unwrap_or_default(),
+    );
+    if let Some(started) = archived.started_at {
+        field(
+            "Actual Start Time",
+            &started.format(time_format).unwrap_or_default(),
+        );
+    }
+    field(
+        "End Time",
+        &archived.ended_at.format(time_format).unwrap_or_default(),
+    );
+
+    format!(
+        "<!doctype html><html><head><meta charset='utf-8'/>\
+         <title>Scoresheet G{game_number}</title>\
+         <style>@page {{ size: A4 landscape; margin: 0; }}\n{CSS}</style></head><body>\
+         <div class='page'><div class='head'>\
+         <div class='tname'>CMAS UNDERWATER HOCKEY SCORESHEET</div>\
+         <div class='tname'>{event}</div><div class='fields'>{fields}</div></div>\
+         <div class='sides'><div class='side black'>{black}</div>\
+         <div class='side white'>{white}</div></div>\
+         <div class='sides'><div class='side'>{penalties}</div>\
+         <div class='side'>{timeouts}{officials}</div></div>\
+         {incidents}{signatures}</div></body></html>",
+        game_number = html_escape(&archived.game_number),
+        event = html_escape(game.event_name.unwrap_or_default()),
+        black = roster(Color::Black),
+        white = roster(Color::White),
+        officials = officials(game),
+        incidents = incidents(game),
+        signatures = signatures(game),
+    )
+}
+
+fn header(game: &CompletedGame) -> String {
+    let time_format = format_description!("[hour]:[minute]");
+    let archived = game.game;
+    let mut fields = String::new();
+    let mut field = |label: &str, value: &str| {
+        let _ = write!(
+            fields,
+            "<div><span class='lbl'>{label}:</span>{}</div>",
+            html_escape(value)
+        );
+    };
+    if let Some(court) = game.court {
+        field("COURT", court);
+    }
+    field("GAME #", &archived.game_number);
+    field(
+        "DATE",
+        &archived
+            .day
+            .format(format_description!("[year]-[month]-[day]"))
+            .unwrap_or_default(),
+    );
+    if let Some(rule) = &archived.timing_rule {
+        field("TYPE", rule);
+    }
+    if let Some(started) = archived.started_at {
+        field(
+            "ACTUAL START TIME",
+            &started.format(time_format).unwrap_or_default(),
+        );
+    }
+    field(
+        "END TIME",
+        &archived.ended_at.format(time_format).unwrap_or_default(),
+    );
+
+    let team = |color: Color| {
+        html_escape(
+            archived.teams[color]
+                .as_deref()
+                .unwrap_or(team_label(color)),
+        )
+    };
+    format!(
+        "<div class='head'><div class='tname'>{event}</div><div class='fields'>{fields}</div></div>\
+         <div class='score'><div>{white} (WHITE) {white_score}</div><div>-</div>\
+         <div>{black_score} {black} (BLACK)</div></div>",
+        event = html_escape(game.event_name.unwrap_or("Scoresheet")),
+        white = team(Color::White),
+        black = team(Color::Black),
+        white_score = archived.scores.white,
+        black_score = archived.scores.black,
+    )
+}
+
+fn officials(game: &CompletedGame) -> String {
+    if game.game.referees.is_empty() {
+        return String::new();
+    }
+    let mut html = String::from("<h3>Officials</h3><div class='fields'>");
+    for referee in &game.game.referees {
+        let _ = write!(
+            html,
+            "<div><span class='lbl'>{}:</span>{}</div>",
+            html_escape(&referee.role.to_uppercase()),
+            html_escape(&referee.name)
+        );
+    }
+    html.push_str("</div>");
+    html
+}
+
+/// The referees' incident reports, left off sheets of games that had none
+fn incidents(game: &CompletedGame) -> String {
+    if game.game.incidents.is_empty() {
+        return String::new();
+    }
+    let mut html = String::from(
+        "<h3>Incident Reports</h3><table><tr><th>Period</th><th>Time</th><th>Team</th>\
+         <th>Player</th><th>Category</th><th>Report</th></tr>",
+    );
+    for incident in &game.game.incidents {
+        let cells = [
+            period_label(incident.game_period),
+            clock_time(incident.period_time),
+            incident
+                .color
+                .map(team_label)
+                .unwrap_or_default()
+                .to_string(),
+            incident
+                .color
+                .zip(incident.player_number)
+                .map(|(color, number)| player_label(Some(number), &game.players[color]))
+                .unwrap_or_default(),
+            incident.category.as_str().to_string(),
+            incident.description.clone(),
+        ];
+        html.push_str("<tr>");
+        for cell in cells {
+            let _ = write!(html, "<td>{}</td>", html_escape(&cell));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    html
+}
+
+/// The signature lines, with each captain's sign-off on the refbox under their line
+fn signatures(game: &CompletedGame) -> String {
+    let captain = |color: Color| {
+        let sign_off = game.sign_offs.map(|sign_offs| match sign_offs[color] {
+            TeamSignOff { protest: true, .. } => "Protest lodged".to_string(),
+            TeamSignOff {
+                signed_by: Some(number),
+                ..
+            } => format!("Signed on the refbox: {number}"),
+            TeamSignOff {
+                signed_by: None, ..
+            } => "Not signed on the refbox".to_string(),
+        });
+        format!(
+            "<div class='line'>{} CAPTAIN SIGNATURE{}</div>",
+            team_label(color),
+            sign_off
+                .map(|note| format!("<div class='signoff'>{note}</div>"))
+                .unwrap_or_default()
+        )
+    };
+    format!(
+        "<div class='sigline'>{}<div class='line'>CHIEF REFEREE SIGNATURE</div>{}</div>",
+        captain(Color::White),
+        captain(Color::Black)
+    )
+}
+
+fn table(
+    title: &str,
+    headers: &[&str],
+    lines: Vec<&SheetLine>,
+    cells: impl Fn(&SheetLine) -> Vec<String>,
+) -> String {
+    let mut html = format!("<h3>{title}</h3>");
+    if lines.is_empty() {
+        html.push_str("<div class='none'>None</div>");
+        return html;
+    }
+    html.push_str("<table><tr>");
+    for header in headers {
+        let _ = write!(html, "<th>{header}</th>");
+    }
+    html.push_str("</tr>");
+    for line in lines {
+        let struck = matches!(
+            line.kind,
+            SheetLineKind::Goal {
+                disallowed: Some(_)
+            }
+        );
+        html.push_str(if struck {
+            "<tr class='struck'>"
+        } else {
+            "<tr>"
+        });
+        for cell in cells(line) {
+            let _ = write!(html, "<td>{}</td>", html_escape(&cell));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    html
+}
+
+fn event_label(rules: &RuleProfile, line: &SheetLine) -> String {
+    match &line.kind {
+        SheetLineKind::Goal { disallowed: None } => "Goal".to_string(),
+        SheetLineKind::Goal {
+            disallowed: Some(reason),
+        } => format!("Goal, disallowed: {}", disallow_reason(*reason)),
+        SheetLineKind::Penalty {
+            duration,
+            infraction,
+        } => match infraction {
+            Some(i) => format!(
+                "{} penalty, {}",
+                penalty_label(*duration),
+                rules.log_name(*i)
+            ),
+            None => format!("{} penalty", penalty_label(*duration)),
+        },
+        SheetLineKind::Warning(i) => format!("Warning, {}", rules.log_name(*i)),
+        SheetLineKind::Foul(i) => match line.color {
+            Some(_) => format!("Foul, {}", rules.log_name(*i)),
+            None => format!("Foul on both teams, {}", rules.log_name(*i)),
+        },
+        SheetLineKind::Timeout(TimeoutKind::Team(_)) => "Team timeout".to_string(),
+        SheetLineKind::Timeout(TimeoutKind::Ref) => "Referee timeout".to_string(),
+        SheetLineKind::Timeout(TimeoutKind::PenaltyShot) => "Penalty shot".to_string(),
+        SheetLineKind::ShootoutAttempt { round, scored } => format!(
+            "Shootout round {round}, {}",
+            if *scored { "scored" } else { "missed" }
+        ),
+        SheetLineKind::PeriodStart => "Period begins".to_string(),
+    }
+}
+
+fn team_label(color: Color) -> &'static str {
+    match color {
+        Color::Black => "BLACK",
+        Color::White => "WHITE",
+    }
+}
+
+fn player_label(number: Option<u8>, names: &BTreeMap<u8, String>) -> String {
+    match number {
+        Some(number) => match names.get(&number) {
+            Some(name) if !name.is_empty() => format!("#{number} {name}"),
+            _ => format!("#{number}"),
+        },
+        None => "Team".to_string(),
+    }
+}
+
+fn penalty_label(duration: Option<Duration>) -> String {
+    match duration {
+        None => "TD".to_string(),
+        Some(d) if d.as_secs() % 60 == 0 => format!("{} min", d.as_secs() / 60),
+        Some(d) => clock_time(d),
+    }
+}
+
+fn disallow_reason(reason: GoalDisallowReason) -> &'static str {
+    match reason {
+        GoalDisallowReason::Foul => "foul",
+        GoalDisallowReason::Infraction => "infraction",
+        GoalDisallowReason::ScorerCorrection => "wrong scorer",
+    }
+}
+
+fn period_label(period: GamePeriod) -> String {
+    match period {
+        GamePeriod::FirstHalf => "1st Half".to_string(),
+        GamePeriod::SecondHalf => "2nd Half".to_string(),
+        GamePeriod::OvertimeFirstHalf => "OT 1st Half".to_string(),
+        GamePeriod::OvertimeSecondHalf => "OT 2nd Half".to_string(),
+        GamePeriod::PenaltyShootout => "Shootout".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn clock_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        results_archive::ArchivedReferee,
+        tournament_manager::incident::{IncidentCategory, IncidentReport},
+    };
+    use time::{OffsetDateTime, macros::datetime};
+    use uwh_common::{
+        config::Game as GameConfig, game_snapshot::Infraction, uwhportal::schedule::EventId,
+    };
+
+    fn archived() -> ArchivedGame {
+        ArchivedGame {
+            event_id: Some(EventId::from_partial("spring-cup")),
+            game_number: "4".to_string(),
+            day: datetime!(2026-06-22 9:00 +2).date(),
+            started_at: Some(datetime!(2026-06-22 9:00 +2)),
+            ended_at: datetime!(2026-06-22 9:35 +2),
+            teams: BlackWhiteBundle {
+                black: Some("Sharks".to_string()),
+                white: Some("Eels <B>".to_string()),
+            },
+            team_ids: Default::default(),
+            scores: BlackWhiteBundle { black: 1, white: 0 },
+            timing_rule: Some("RR".to_string()),
+            config: GameConfig::default(),
+            referees: vec![ArchivedReferee {
+                role: "Chief Referee".to_string(),
+                name: "Alex".to_string(),
+            }],
+            stats: serde_json::Value::Null,
+            incidents: Vec::new(),
+        }
+    }
+
+    fn line(color: Option<Color>, player: Option<u8>, kind: SheetLineKind) -> SheetLine {
+        SheetLine {
+            color,
+            player_number: player,
+            period: GamePeriod::FirstHalf,
+            period_time: Duration::from_secs(754),
+            occurred_on: OffsetDateTime::UNIX_EPOCH,
+            kind,
+        }
+    }
+
+    fn lines() -> Vec<SheetLine> {
+        vec![
+            line(
+                Some(Color::Black),
+                Some(7),
+                SheetLineKind::Goal { disallowed: None },
+            ),
+            line(
+                Some(Color::White),
+                Some(3),
+                SheetLineKind::Penalty {
+                    duration: None,
+                    infraction: Some(Infraction::UNSPORTSMANLIKE_CONDUCT),
+                },
+            ),
+            line(
+                Some(Color::White),
+                None,
+                SheetLineKind::Warning(Infraction::DELAY_OF_GAME),
+            ),
+            line(None, None, SheetLineKind::Timeout(TimeoutKind::Ref)),
+        ]
+    }
+
+    fn completed<'a>(
+        game: &'a ArchivedGame,
+        lines: &'a [SheetLine],
+        rules: &'a RuleProfile,
+    ) -> CompletedGame<'a> {
+        let mut players: BlackWhiteBundle<BTreeMap<u8, String>> = Default::default();
+        players.black.insert(7, "Sam".to_string());
+        CompletedGame {
+            event_name: Some("Spring Cup"),
+            court: Some("1"),
+            game,
+            lines,
+            players,
+            sign_offs: None,
+            rules,
+        }
+    }
+
+    #[test]
+    fn detailed_sheet_fills_in_each_team_and_the_officials() {
+        let game = archived();
+        let lines = lines();
+        let rules = RuleProfile::default();
+        let html = render_html(ScoresheetStyle::Detailed, &completed(&game, &lines, &rules));
+
+        assert!(html.contains("Spring Cup"));
+        assert!(html.contains("<td>#7 Sam</td>"));
+        assert!(html.contains("<td>12:34</td>"));
+        assert!(html.contains("<td>TD</td>"));
+        assert!(html.contains(&html_escape(
+            &rules.log_name(Infraction::UNSPORTSMANLIKE_CONDUCT)
+        )));
+        assert!(html.contains("Referee timeout"));
+        assert!(html.contains("Alex"));
+        assert!(html.contains("CHIEF REFEREE SIGNATURE"));
+        // Team names come from the schedule, so they are escaped
+        assert!(html.contains("Eels &lt;B&gt;"));
+        assert!(!html.contains("Eels <B>"));
+    }
+
+    #[test]
+    fn simple_sheet_is_one_log_in_order() {
+        let game = archived();
+        let lines = lines();
+        let rules = RuleProfile::default();
+        let html = render_html(ScoresheetStyle::Simple, &completed(&game, &lines, &rules));
+
+        let goal = html.find("<td>Goal</td>").unwrap();
+        let penalty = html.find("TD penalty").unwrap();
+        let timeout = html.find("Referee timeout").unwrap();
+        assert!(goal < penalty && penalty < timeout);
+        assert!(html.contains("A4 portrait"));
+    }
+
+    #[test]
+    fn cmas_official_sheet_counts_each_players_goals_by_half() {
+        let game = archived();
+        let mut lines = lines();
+        lines.push(SheetLine {
+            period: GamePeriod::SecondHalf,
+            ..line(
+                Some(Color::Black),
+                Some(7),
+                SheetLineKind::Goal { disallowed: None },
+            )
+        });
+        lines.push(line(
+            Some(Color::Black),
+            Some(7),
+            SheetLineKind::Goal {
+                disallowed: Some(GoalDisallowReason::Foul),
+            },
+        ));
+        lines.push(line(
+            Some(Color::White),
+            None,
+            SheetLineKind::Timeout(TimeoutKind::Team(Color::White)),
+        ));
+        let rules = RuleProfile::default();
+        let html = render_html(
+            ScoresheetStyle::CmasOfficial,
+            &completed(&game, &lines, &rules),
+        );
+
+        assert!(html.contains("CMAS UNDERWATER HOCKEY SCORESHEET"));
+        // The disallowed goal isn't counted
+        assert!(html.contains("<td>7</td><td>Sam</td><td>1</td><td>1</td><td></td><td>2</td>"));
+        assert!(html.contains("<td>3 (W)</td><td>TD</td>"));
+        assert!(html.contains("<th>WHITE</th><td>12:34</td><td></td>"));
+        // Black is on the left, as on the printed sheet
+        assert!(html.find("BLACK TEAM").unwrap() < html.find("WHITE TEAM").unwrap());
+    }
+
+    #[test]
+    fn captains_sign_offs_go_under_their_signatures() {
+        let game = archived();
+        let lines = lines();
+        let rules = RuleProfile::default();
+        let unasked = render_html(ScoresheetStyle::Simple, &completed(&game, &lines, &rules));
+        assert!(!unasked.contains("on the refbox"));
+
+        let html = render_html(
+            ScoresheetStyle::Simple,
+            &CompletedGame {
+                sign_offs: Some(BlackWhiteBundle {
+                    black: TeamSignOff {
+                        signed_by: Some(7),
+                        protest: false,
+                    },
+                    white: TeamSignOff {
+                        signed_by: None,
+                        protest: true,
+                    },
+                }),
+                ..completed(&game, &lines, &rules)
+            },
+        );
+        let white = html.find("Protest lodged").unwrap();
+        let black = html.find("Signed on the refbox: 7").unwrap();
+        assert!(white < black);
+    }
+
+    #[test]
+    fn incident_reports_are_listed_when_there_are_any() {
+        let game = archived();
+        let lines = lines();
+        let rules = RuleProfile::default();
+        let html = render_html(ScoresheetStyle::Detailed, &completed(&game, &lines, &rules));
+        assert!(!html.contains("Incident Reports"));
+
+        let game = ArchivedGame {
+            incidents: vec![IncidentReport {
+                category: IncidentCategory::Injury,
+                color: Some(Color::Black),
+                player_number: Some(7),
+                game_period: GamePeriod::FirstHalf,
+                period_time: Duration::from_secs(61),
+                occurred_on: OffsetDateTime::UNIX_EPOCH,
+                description: "Cut finger <left hand>".to_string(),
+            }],
+            ..archived()
+        };
+        let html = render_html(ScoresheetStyle::Detailed, &completed(&game, &lines, &rules));
+        assert!(html.contains("Incident Reports"));
+        assert!(html.contains("<td>1:01</td><td>BLACK</td><td>#7 Sam</td><td>Injury</td>"));
+        assert!(html.contains("Cut finger &lt;left hand&gt;"));
+    }
+
+    #[test]
+    fn saves_one_sheet_per_game() {
+        let dir = tempfile::tempdir().unwrap();
+        let game = archived();
+        let lines = lines();
+        let rules = RuleProfile::default();
+        let path = save(
+            dir.path(),
+            ScoresheetStyle::Detailed,
+            &completed(&game, &lines, &rules),
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            dir.path()
+                .join("scoresheet-spring-cup-2026-06-22-game-4.html")
+        );
+        assert!(fs::read_to_string(&path).unwrap().contains("Sharks"));
+    }
+}
@@ -269,10 +269,10 @@ impl CaptureApp {
 
 fn push_variant(sim: &mut SimRefBoxApp, variant: Variant) {
     // `update` returns Task::none() for NewSnapshot; nothing to schedule.
-    let _ = sim.update(SimMessage::NewSnapshot(SimFrame {
+    let _ = sim.update(SimMessage::NewSnapshot(Box::new(SimFrame {
         layout: variant.layout(),
         data: variant.sample(),
-    }));
+    })));
 }
 
 fn save_png(dir: &Path, variant: Variant, shot: &Screenshot) {
@@ -53,7 +53,7 @@ pub fn sunlight_window_size(matrix_scale: f32) -> Size {
 
 #[derive(Clone, Debug)]
 pub enum Message {
-    NewSnapshot(SimFrame),
+    NewSnapshot(Box<SimFrame>),
     Stop,
 }
 
@@ -111,7 +111,7 @@ impl SimRefBoxApp {
         trace!("Handling message: {message:?}");
         match message {
             Message::NewSnapshot(frame) => {
-                let SimFrame { layout, data } = frame;
+                let SimFrame { layout, data } = *frame;
                 *self.layout.lock().unwrap() = layout;
                 *self.latest.lock().unwrap() = Some(data.clone());
 
@@ -326,7 +326,10 @@ fn snapshot_listener() -> impl futures_lite::Stream<Item = Message> {
                     }
                 };
 
-                msg_tx.send(Message::NewSnapshot(frame)).await.unwrap();
+                msg_tx
+                    .send(Message::NewSnapshot(Box::new(frame)))
+                    .await
+                    .unwrap();
             }
         }
     })
@@ -0,0 +1,154 @@
+//! Crash-safe JSON files in the config directory ("state files").
+//!
+//! The game journal, the portal queue and link session, the results archive and the suspension
+//! ledger are all kept the same way. A save writes a temp file, fsyncs it and renames it over the
+//! target, so a crash leaves either the old file or the new one, never half of each. A load never
+//! blocks startup: a missing file is `None`, and one that doesn't parse or has an unknown version
+//! is renamed to `<stem>.corrupt.<ts>.json` and logged, so it is kept for a look later but never
+//! read again.
+
+use log::*;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use time::{OffsetDateTime, macros::format_description};
+
+/// Where the state file `stem` lives in `dir`
+pub fn path(dir: &Path, stem: &str) -> PathBuf {
+    dir.join(format!("{stem}.json"))
+}
+
+fn tmp_path(dir: &Path, stem: &str) -> PathBuf {
+    dir.join(format!("{stem}.json.tmp"))
+}
+
+/// Load the state file `stem` from `dir`. Missing → `None`. Present but unparseable, or with a
+/// `version` other than `current_version` → renamed aside, logged, and `None`. Only an I/O
+/// failure is an `Err`.
+pub fn load<T: DeserializeOwned>(
+    dir: &Path,
+    stem: &str,
+    current_version: u32,
+    version: impl FnOnce(&T) -> u32,
+) -> std::io::Result<Option<T>> {
+    let path = path(dir, stem);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let bytes = fs::read(&path)?;
+    match serde_json::from_slice::<T>(&bytes) {
+        Ok(value) => {
+            let found = version(&value);
+            if found == current_version {
+                return Ok(Some(value));
+            }
+            error!("{stem}.json has unknown version {found}; renaming and ignoring");
+        }
+        Err(e) => error!("{stem}.json failed to parse ({e}); renaming and ignoring"),
+    }
+    rename_corrupt(&path, stem)?;
+    Ok(None)
+}
+
+fn rename_corrupt(path: &Path, stem: &str) -> std::io::Result<()> {
+    // Format: YYYYMMDDTHHMMSSZ, e.g. "20260622T142203Z".
+    let fmt = format_description!("[year][month][day]T[hour][minute][second]Z");
+    let ts = OffsetDateTime::now_utc()
+        .format(&fmt)
+        .unwrap_or_else(|_| "unknown-time".to_string());
+    let mut new_path = path.to_path_buf();
+    new_path.set_file_name(format!("{stem}.corrupt.{ts}.json"));
+    fs::rename(path, &new_path)
+}
+
+/// Atomically write the state file `stem` to `dir`: temp file → fsync → rename over target.
+pub fn save<T: Serialize>(dir: &Path, stem: &str, value: &T) -> std::io::Result<()> {
+    let tmp = tmp_path(dir, stem);
+    {
+        let mut f = fs::File::create(&tmp)?;
+        serde_json::to_writer(&f, value).map_err(std::io::Error::other)?;
+        f.flush()?;
+        f.sync_all()?;
+    }
+    fs::rename(&tmp, path(dir, stem))?;
+    Ok(())
+}
+
+/// Remove the state file `stem` from `dir`. A missing file is treated as success.
+pub fn delete(dir: &Path, stem: &str) -> std::io::Result<()> {
+    match fs::remove_file(path(dir, stem)) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use tempfile::TempDir;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Note {
+        version: u32,
+        text: String,
+    }
+
+    fn note(version: u32) -> Note {
+        Note {
+            version,
+            text: "hello".to_string(),
+        }
+    }
+
+    fn load_note(dir: &Path) -> Option<Note> {
+        load(dir, "note", 1, |note: &Note| note.version).unwrap()
+    }
+
+    fn corrupt_files(dir: &Path) -> usize {
+        fs::read_dir(dir)
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+                let name = name.to_string_lossy();
+                name.starts_with("note.corrupt.") && name.ends_with(".json")
+            })
+            .count()
+    }
+
+    #[test]
+    fn save_then_load_round_trips_without_leaving_a_temp_file() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(load_note(dir.path()), None);
+
+        save(dir.path(), "note", &note(1)).unwrap();
+        assert_eq!(load_note(dir.path()), Some(note(1)));
+        assert!(!tmp_path(dir.path(), "note").exists());
+    }
+
+    #[test]
+    fn an_unreadable_or_unknown_version_file_is_set_aside() {
+        let dir = TempDir::new().unwrap();
+        fs::write(path(dir.path(), "note"), "not json").unwrap();
+        assert_eq!(load_note(dir.path()), None);
+        assert!(!path(dir.path(), "note").exists());
+        assert_eq!(corrupt_files(dir.path()), 1);
+
+        save(dir.path(), "note", &note(2)).unwrap();
+        assert_eq!(load_note(dir.path()), None);
+        assert!(!path(dir.path(), "note").exists());
+    }
+
+    #[test]
+    fn delete_is_ok_when_the_file_is_missing() {
+        let dir = TempDir::new().unwrap();
+        save(dir.path(), "note", &note(1)).unwrap();
+        delete(dir.path(), "note").unwrap();
+        assert!(!path(dir.path(), "note").exists());
+        delete(dir.path(), "note").unwrap();
+    }
+}
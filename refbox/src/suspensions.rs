@@ -0,0 +1,364 @@
+//! Tournament-wide suspensions for total dismissals ("suspension ledger").
+//!
+//! A player given a total dismissal also sits out their team's next game(s), as set by the rule
+//! profile's `dismissal_suspension_games`. The ledger is built from the total dismissals recorded
+//! in each game's stats, keyed by event, team and cap number, and persisted so it survives a
+//! restart. Every game a suspended player's team plays afterwards counts towards the suspension,
+//! which is dropped once served.
+//!
+//! Teams are only known for games from a portal schedule, so games played without one are not
+//! recorded.
+//!
+//! Kept as a [`state_file`].
+
+use crate::state_file;
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    color::Color,
+    uwhportal::schedule::{EventId, GameNumber, TeamId},
+};
+
+const FILE_STEM: &str = "suspensions";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspensionLedger {
+    pub version: u32,
+    events: BTreeMap<EventId, EventSuspensions>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct EventSuspensions {
+    /// Games already taken into the ledger, so a game is never counted twice
+    recorded_games: BTreeSet<GameNumber>,
+    suspensions: Vec<Suspension>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suspension {
+    pub team: TeamId,
+    pub cap_number: u8,
+    /// The game the player was dismissed in
+    pub dismissed_in: GameNumber,
+    pub games_remaining: u16,
+}
+
+impl Default for SuspensionLedger {
+    fn default() -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            events: BTreeMap::new(),
+        }
+    }
+}
+
+impl SuspensionLedger {
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// Takes a finished game into the ledger: the game counts towards the suspensions of both
+    /// teams that played it, then every player dismissed in it is suspended for their team's next
+    /// `games` games. A game ended again, after an undo back into it, only counts once, but any
+    /// dismissal new since it was first recorded is still suspended. Returns whether the ledger
+    /// changed.
+    pub fn record_game(
+        &mut self,
+        event: &EventId,
+        game: &GameNumber,
+        teams: &BlackWhiteBundle<Option<TeamId>>,
+        dismissals: &[(Color, u8)],
+        games: u16,
+    ) -> bool {
+        let entry = self.events.entry(event.clone()).or_default();
+        let mut changed = entry.recorded_games.insert(game.clone());
+
+        entry.suspensions.retain_mut(|suspension| {
+            if !changed
+                || suspension.dismissed_in == *game
+                || !teams
+                    .iter()
+                    .any(|(_, team)| team.as_ref() == Some(&suspension.team))
+            {
+                return true;
+            }
+            suspension.games_remaining = suspension.games_remaining.saturating_sub(1);
+            if suspension.games_remaining == 0 {
+                info!(
+                    "{} #{} has served their suspension from game {}",
+                    suspension.team, suspension.cap_number, suspension.dismissed_in
+                );
+                false
+            } else {
+                true
+            }
+        });
+
+        if games > 0 {
+            for &(color, cap_number) in dismissals {
+                let Some(team) = teams[color].clone() else {
+                    warn!("No team known for {color} in game {game}, can't suspend #{cap_number}");
+                    continue;
+                };
+                if entry.suspensions.iter().any(|s| {
+                    s.team == team && s.cap_number == cap_number && s.dismissed_in == *game
+                }) {
+                    continue;
+                }
+                info!("{team} #{cap_number} is suspended for {games} game(s)");
+                entry.suspensions.push(Suspension {
+                    team,
+                    cap_number,
+                    dismissed_in: game.clone(),
+                    games_remaining: games,
+                });
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// The suspensions still to be served by players on `teams`, by the color their team plays
+    pub fn for_teams(
+        &self,
+        event: &EventId,
+        teams: &BlackWhiteBundle<Option<TeamId>>,
+    ) -> Vec<(Color, &Suspension)> {
+        let Some(entry) = self.events.get(event) else {
+            return Vec::new();
+        };
+        teams
+            .iter()
+            .filter_map(|(color, team)| Some((color, team.as_ref()?)))
+            .flat_map(|(color, team)| {
+                entry
+                    .suspensions
+                    .iter()
+                    .filter(move |s| s.team == *team)
+                    .map(move |s| (color, s))
+            })
+            .collect()
+    }
+}
+
+/// Load the ledger. Missing → empty. Present but unparseable or of an unknown version → renamed
+/// aside and empty, see [`state_file::load`]. Never blocks startup.
+pub fn load_or_default(dir: &Path) -> std::io::Result<SuspensionLedger> {
+    let ledger = state_file::load(
+        dir,
+        FILE_STEM,
+        SuspensionLedger::CURRENT_VERSION,
+        |ledger: &SuspensionLedger| ledger.version,
+    )?;
+    Ok(ledger.unwrap_or_default())
+}
+
+/// Atomically write the ledger.
+pub fn save(dir: &Path, ledger: &SuspensionLedger) -> std::io::Result<()> {
+    state_file::save(dir, FILE_STEM, ledger)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn event() -> EventId {
+        EventId::from_partial("spring-cup")
+    }
+
+    fn teams(black: &str, white: &str) -> BlackWhiteBundle<Option<TeamId>> {
+        BlackWhiteBundle {
+            black: Some(TeamId::from_partial(black)),
+            white: Some(TeamId::from_partial(white)),
+        }
+    }
+
+    fn caps(ledger: &SuspensionLedger, teams: &BlackWhiteBundle<Option<TeamId>>) -> Vec<u8> {
+        ledger
+            .for_teams(&event(), teams)
+            .into_iter()
+            .map(|(_, s)| s.cap_number)
+            .collect()
+    }
+
+    #[test]
+    fn a_dismissal_is_served_over_the_teams_next_games() {
+        let mut ledger = SuspensionLedger::default();
+        let event = event();
+        assert!(ledger.record_game(
+            &event,
+            &"1".to_string(),
+            &teams("sharks", "eels"),
+            &[(Color::White, 7)],
+            2,
+        ));
+
+        let suspended = ledger.for_teams(&event, &teams("rays", "eels"));
+        assert_eq!(suspended.len(), 1);
+        assert_eq!(suspended[0].0, Color::White);
+        assert_eq!(suspended[0].1.team, TeamId::from_partial("eels"));
+        assert_eq!(suspended[0].1.cap_number, 7);
+        assert_eq!(suspended[0].1.games_remaining, 2);
+        // Their opponents are not affected
+        assert!(caps(&ledger, &teams("sharks", "rays")).is_empty());
+
+        // A game the eels don't play doesn't count
+        ledger.record_game(&event, &"2".to_string(), &teams("sharks", "rays"), &[], 2);
+        assert_eq!(caps(&ledger, &teams("eels", "rays")), vec![7]);
+
+        ledger.record_game(&event, &"3".to_string(), &teams("eels", "rays"), &[], 2);
+        let suspended = ledger.for_teams(&event, &teams("eels", "rays"));
+        assert_eq!(suspended[0].0, Color::Black);
+        assert_eq!(suspended[0].1.games_remaining, 1);
+
+        ledger.record_game(&event, &"4".to_string(), &teams("sharks", "eels"), &[], 2);
+        assert!(caps(&ledger, &teams("sharks", "eels")).is_empty());
+    }
+
+    #[test]
+    fn a_game_is_only_recorded_once() {
+        let mut ledger = SuspensionLedger::default();
+        let event = event();
+        let game_teams = teams("sharks", "eels");
+        ledger.record_game(
+            &event,
+            &"1".to_string(),
+            &game_teams,
+            &[(Color::Black, 3)],
+            1,
+        );
+        let before = ledger.clone();
+        assert!(!ledger.record_game(
+            &event,
+            &"1".to_string(),
+            &game_teams,
+            &[(Color::Black, 3)],
+            1
+        ));
+        assert_eq!(ledger, before);
+        assert_eq!(caps(&ledger, &game_teams), vec![3]);
+    }
+
+    #[test]
+    fn a_game_ended_again_adds_its_new_dismissals() {
+        let mut ledger = SuspensionLedger::default();
+        let event = event();
+        let game_teams = teams("sharks", "eels");
+        ledger.record_game(
+            &event,
+            &"1".to_string(),
+            &game_teams,
+            &[(Color::Black, 3)],
+            2,
+        );
+        ledger.record_game(&event, &"2".to_string(), &game_teams, &[], 2);
+
+        // Undone back into game 2, which then ends again with a dismissal given after the undo
+        assert!(ledger.record_game(
+            &event,
+            &"2".to_string(),
+            &game_teams,
+            &[(Color::White, 9)],
+            2
+        ));
+        let suspended = ledger.for_teams(&event, &game_teams);
+        let remaining = |cap| {
+            suspended
+                .iter()
+                .find(|(_, s)| s.cap_number == cap)
+                .map(|(_, s)| s.games_remaining)
+        };
+        // Game 2 still counts once towards #3's suspension
+        assert_eq!(remaining(3), Some(1));
+        assert_eq!(remaining(9), Some(2));
+    }
+
+    #[test]
+    fn events_are_tracked_separately() {
+        let mut ledger = SuspensionLedger::default();
+        let game_teams = teams("sharks", "eels");
+        ledger.record_game(
+            &event(),
+            &"1".to_string(),
+            &game_teams,
+            &[(Color::Black, 3)],
+            1,
+        );
+        assert!(
+            ledger
+                .for_teams(&EventId::from_partial("autumn-cup"), &game_teams)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn nothing_is_suspended_without_suspension_games_or_a_team() {
+        let mut ledger = SuspensionLedger::default();
+        let event = event();
+        let game_teams = teams("sharks", "eels");
+        ledger.record_game(
+            &event,
+            &"1".to_string(),
+            &game_teams,
+            &[(Color::Black, 3)],
+            0,
+        );
+        assert!(caps(&ledger, &game_teams).is_empty());
+
+        let unknown_black = BlackWhiteBundle {
+            black: None,
+            white: Some(TeamId::from_partial("eels")),
+        };
+        ledger.record_game(
+            &event,
+            &"2".to_string(),
+            &unknown_black,
+            &[(Color::Black, 3)],
+            1,
+        );
+        assert!(caps(&ledger, &game_teams).is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            load_or_default(dir.path()).unwrap(),
+            SuspensionLedger::default()
+        );
+
+        let mut ledger = SuspensionLedger::default();
+        ledger.record_game(
+            &event(),
+            &"1".to_string(),
+            &teams("sharks", "eels"),
+            &[(Color::White, 7)],
+            1,
+        );
+        save(dir.path(), &ledger).unwrap();
+        assert_eq!(load_or_default(dir.path()).unwrap(), ledger);
+    }
+
+    #[test]
+    fn a_corrupt_ledger_is_set_aside() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(state_file::path(dir.path(), FILE_STEM), "not json").unwrap();
+        assert_eq!(
+            load_or_default(dir.path()).unwrap(),
+            SuspensionLedger::default()
+        );
+        assert!(!state_file::path(dir.path(), FILE_STEM).exists());
+        assert!(fs::read_dir(dir.path()).unwrap().any(|entry| {
+            entry
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with("suspensions.corrupt.")
+        }));
+    }
+}
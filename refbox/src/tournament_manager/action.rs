@@ -0,0 +1,408 @@
+//! The operator actions that change a game ("actions").
+//!
+//! The app changes the `TournamentManager` only by passing an [`Action`] to
+//! [`TournamentManager::apply`], which runs it through [`apply_action`]. A recorded session
+//! (see `super::session`) is a list of these actions, and the golden driver replays sessions and
+//! the test scenarios through the same dispatch, so a replay can't apply an action differently
+//! from the app.
+
+use super::*;
+
+/// Every action a scenario or a recorded session can inject at a given time offset.
+//
+// The scenario shorthands (`SetupPeriod`, `ScoreSuddenDeath`, `ConfirmScore`, `ConfirmGameEnd`)
+// are only built by the test scenarios; recorded sessions use the actions the app applies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    #[cfg(test)]
+    /// Set the active period and remaining clock time (test-only engine method).
+    SetupPeriod(GamePeriod, Duration),
+    /// Start the game clock (and any active timeout clock).
+    StartClock,
+    /// Manually start/advance play — the operator "Start" button.
+    ///
+    /// Mirrors `Message::StartPlayNow` → `tm.start_play_now(now)`. From
+    /// `BetweenGames` this begins a new game via `start_game` (which sets
+    /// `has_reset = false` → `is_old_game = true`); from `HalfTime` / `PreOvertime`
+    /// / `OvertimeHalfTime` / `PreSuddenDeath` it advances to the next play period.
+    ///
+    /// DISTINCT from `StartClock` (the bare `start_clock(now)` resume primitive):
+    /// `StartClock` never calls `start_game`, so scenarios built only from
+    /// `SetupPeriod` + `StartClock` leave `has_reset = true` and can never exercise
+    /// the between-games auto-reset.
+    StartPlayNow,
+    /// Manually reset the game (operator's end-game / "new game" reset).
+    ///
+    /// Mirrors `tm.reset_game(now)` as called from the `ConfirmationOption::EndGameAndApply`
+    /// path in `app/mod.rs` (apply edited settings mid-game → end the current game and reset).
+    /// `reset_game` moves to `BetweenGames`, stops the clock at `minimum_break`, clears the
+    /// timeout, and calls `reset()` — which zeroes the scores, clears penalties/warnings/fouls,
+    /// and sets `has_reset = true` (→ `is_old_game = false`, `old?=N`). If the clock was
+    /// running it is restarted (the break counts down).
+    ResetGame,
+    /// Stop the game clock.
+    StopClock,
+    /// Record a goal for the given colour and player number.
+    ///
+    /// Mirrors `Message::AddNewScore` in the non-SuddenDeath path: calls
+    /// `tm.add_score(color, player, now)` directly.  Do NOT use this variant in
+    /// SuddenDeath — use [`Action::ScoreSuddenDeath`] + [`Action::ConfirmScore`] instead.
+    AddScore(Color, u8),
+    /// Disallow the given colour's goal at the index, counting from its first goal of the game.
+    DisallowGoal(Color, usize, GoalDisallowReason),
+    #[cfg(test)]
+    /// Mirror the SuddenDeath score-entry in `app/mod.rs` (AddScoreComplete, SD branch):
+    ///   the held score is incremented locally, then `tm.pause_for_confirm(now)` is called.
+    ///   The engine score is NOT changed yet; the increment is conceptually held by the
+    ///   operator until `ConfirmScore` fires.
+    ///
+    /// Cross-reference: `app/mod.rs` ~line 2048–2053.
+    ScoreSuddenDeath(Color),
+    #[cfg(test)]
+    /// Mirror the operator confirming a SuddenDeath score (`Message::ScoreConfirmation { correct: true }`):
+    ///   recomputes the held score, calls `tm.set_scores(held, now)` then `tm.end_confirm_pause(now)`.
+    ///
+    /// Cross-reference: `app/mod.rs` ~line 2907–2911.
+    ConfirmScore(Color),
+    #[cfg(test)]
+    /// Operator confirms a game-ending score with NO pending goal to add — mirrors
+    /// `Message::ScoreConfirmation { correct: true }` (app/mod.rs ~3913) when the held
+    /// score is unchanged: `tm.set_scores(current, now)` then `tm.end_confirm_pause(now)`.
+    ///
+    /// Needed to finish a game-ending timeout's confirm pause, which the driver's tick loop
+    /// cannot auto-end because `end_game_ending_timeout` → `halt_clock` drops the start/stop
+    /// latch (so no tick fires to reach `pause_has_ended`). Distinct from [`Action::ConfirmScore`],
+    /// which *increments* the score for the sudden-death held-goal path.
+    ConfirmGameEnd,
+    /// Start a timed penalty for `(color, player_number, kind, infraction)`.
+    StartPenalty(Color, u8, PenaltyKind, Infraction),
+    /// Start a team timeout for the given colour.
+    StartTeamTimeout(Color),
+    /// Start a referee timeout.
+    StartRefTimeout,
+    /// Start a (non-rugby) penalty shot.
+    StartPenaltyShot,
+    /// Start a rugby penalty shot.
+    StartRugbyPenaltyShot,
+    /// End the current timeout and resume play.
+    EndTimeout,
+    /// Manually set the game clock to the given duration (clock must be stopped).
+    SetGameClock(Duration),
+    /// Undo the most recent operator action.
+    Undo,
+    /// Redo the most recently undone action.
+    Redo,
+
+    // ── Primitives only recorded sessions use ──
+    /// `tm.update(now)`, which some handlers call to settle the state straight away.
+    Update,
+    /// `tm.pause_for_confirm(now)`: a goal or game end awaiting the operator's confirmation.
+    PauseForConfirm,
+    /// `tm.end_confirm_pause(now)`.
+    EndConfirmPause,
+    /// `tm.set_scores(scores, now)`.
+    SetScores(BlackWhiteBundle<u8>),
+    /// Manually set the timeout clock (clock must be stopped).
+    SetTimeoutClock(Duration),
+    /// Switch the running timeout to a team timeout for the given colour.
+    SwitchToTeamTimeout(Color),
+    /// Switch the running timeout to a referee timeout.
+    SwitchToRefTimeout,
+    /// Switch the running timeout to a (non-rugby) penalty shot.
+    SwitchToPenaltyShot,
+    /// Switch the running timeout to a rugby penalty shot.
+    SwitchToRugbyPenaltyShot,
+    /// Cancel the running team timeout without using it up.
+    CancelTeamTimeout,
+    /// Give the given colour back a timeout it has used.
+    ReviveTeamTimeout(Color),
+    EditPenalty {
+        old_color: Color,
+        index: usize,
+        new_color: Color,
+        player_number: u8,
+        kind: PenaltyKind,
+        infraction: Infraction,
+    },
+    DeletePenalty(Color, usize),
+    /// Drop the oldest finished penalties past the given list length.
+    LimitPenalties(Color, usize),
+    AddWarning(Color, Option<u8>, Infraction),
+    EditWarning {
+        old_color: Color,
+        index: usize,
+        new_color: Color,
+        player_number: Option<u8>,
+        infraction: Infraction,
+    },
+    DeleteWarning(Color, usize),
+    AddFoul(Option<Color>, Option<u8>, Infraction),
+    EditFoul {
+        old_color: Option<Color>,
+        index: usize,
+        new_color: Option<Color>,
+        player_number: Option<u8>,
+        infraction: Infraction,
+    },
+    DeleteFoul(Option<Color>, usize),
+    StartShootout,
+    /// A shootout attempt by `(color, player_number, scored)`.
+    AddShootoutAttempt(Color, u8, bool),
+    RemoveLastShootoutAttempt,
+    SetConfig(Box<GameConfig>),
+    SetGameNumber(GameNumber),
+    SetNextGame(Box<NextGameInfo>),
+    ApplyNextGameStart,
+    ClearScheduledGameStart,
+    ClearPortalNextGame,
+    ResetToManualBreak,
+    SetCatchUpPlan(Option<CatchUpPlan>),
+    /// Resume a journaled game, read against the session's wall clock.
+    RestoreJournal(Box<journal::GameJournal>),
+}
+
+// ─── KNOWN COUPLING POINT ────────────────────────────────────────────────────
+//
+// The app changes the game only through `TournamentManager::apply`, which dispatches here,
+// so every arm the app uses runs the app's own calls. The scenario shorthands
+// (`SetupPeriod`, `ScoreSuddenDeath`, `ConfirmScore`, `ConfirmGameEnd`) are the exception:
+// they are hand-copies of a sequence of app handlers, and if any of those handlers ever
+// changes which actions it applies (call order, extra follow-up calls, etc.), the shorthand
+// MUST be updated in lockstep or the golden traces will silently stop reflecting the real
+// application.
+//
+// Cross-reference targets in `app/mod.rs` (as of master at the time this was written):
+//   StartClock           → models the bare start_clock(now) resume primitive (resume the
+//                          clock in the already-set-up period), which is also what
+//                          Message::StartClock (the stop-time RESTART CLOCK button) calls.
+//                          NOT Message::StartPlayNow, which calls the distinct
+//                          start_play_now(now) to begin/advance a period.
+//   StartPlayNow         → Message::StartPlayNow (start_play_now(now)): begin a new
+//                          game from BetweenGames (→ start_game, has_reset=false) or
+//                          advance HalfTime/PreOvertime/etc. to the next play period.
+//   ResetGame            → tm.reset_game(now), as called from ConfirmationOption::EndGameAndApply
+//                          (app/mod.rs ~line 964): end the current game and reset → BetweenGames,
+//                          scores zeroed, penalties/warnings/fouls cleared, has_reset=true.
+//   StopClock            → Message::EditTime  (stop_clock + clock_is_running check)
+//   AddScore             → Message::AddNewScore  (add_score(color, 0, now); non-SD path)
+//   ScoreSuddenDeath     → Message::AddScoreComplete SD branch (~line 2048–2053):
+//                          hold score locally, pause_for_confirm(now)
+//   ConfirmScore         → Message::ScoreConfirmation { correct: true } (~line 2907–2911):
+//                          set_scores(held, now) + end_confirm_pause(now)
+//   ConfirmGameEnd       → Message::ScoreConfirmation { correct: true } with an UNCHANGED score
+//                          (~line 3913): set_scores(current) + end_confirm_pause(now). Finishes a
+//                          game-ending timeout's confirm pause (halt_clock stopped the latch, so
+//                          the tick loop cannot auto-confirm it).
+//   StartPenalty         → penalty_editor.rs add_to_tm → tm.start_penalty(...)
+//   StartTeamTimeout     → Message::TeamTimeout  (start_team_timeout)
+//   StartRefTimeout      → Message::RefTimeout   (start_ref_timeout)
+//   StartPenaltyShot     → Message::PenaltyShot  (start_penalty_shot, UWH mode)
+//   StartRugbyPenaltyShot→ Message::PenaltyShot  (start_rugby_penalty_shot, Rugby mode)
+//   EndTimeout           → Message::EndTimeout   (mirrors BOTH branches of the app handler:
+//                          timeout_end_would_end_game ? end_game_ending_timeout(now)  [R6, arms the
+//                          end-of-game confirm pause]  :  end_timeout(now) + update(now))
+//   SetGameClock         → Message::TimeEditComplete (set_game_clock_time). Models the
+//                          clock-stopped path only; the real handler additionally calls
+//                          start_clock(now)+update(now) when the clock was running. Scenarios
+//                          always StopClock before SetGameClock, so was_running == false here.
+//   Undo / Redo          → Message::Undo / Message::Redo (undo(now) / redo(now) + update(now))
+//   SetupPeriod          → test-only; no real handler (uses pub(super) test method)
+//
+// The primitives after `Redo` are one variant per `TournamentManager` method, so they only
+// need updating here if a method's signature changes.
+//
+// CLOCK-LATCH COUPLING: the tick decision in the golden driver reads the engine's start/stop watch
+// channel via `tm.get_start_stop_rx()`, exactly as the real `time_updater` loop in
+// `app/mod.rs` (~line 4132–4165) does.  `apply_action` does NOT maintain any separate
+// `clock_running` bool; the engine owns that state and broadcasts it via the latch.
+//
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub(super) fn apply_action(tm: &mut TournamentManager, action: Action, now: Instant) -> Result<()> {
+    match action {
+        #[cfg(test)]
+        Action::SetupPeriod(period, clock_time) => {
+            tm.set_period_and_game_clock_time(period, clock_time);
+        }
+        Action::StartClock => {
+            tm.start_clock(now);
+        }
+        Action::StartPlayNow => {
+            // Mirrors Message::StartPlayNow → start_play_now(now).
+            // From BetweenGames: start_game → has_reset = false (is_old_game = true)
+            // and the clock starts running (send_clock_running(true)); the driver's
+            // latch tick then drives the game forward.
+            tm.start_play_now(now)?;
+        }
+        Action::ResetGame => {
+            // Mirrors tm.reset_game(now) (app/mod.rs ConfirmationOption::EndGameAndApply):
+            // → BetweenGames, clock stopped at minimum_break (restarted if it was running),
+            // timeout cleared, reset() zeroes scores + clears penalties/warnings/fouls and
+            // sets has_reset = true (is_old_game = false).
+            tm.reset_game(now);
+        }
+        Action::StopClock => {
+            tm.stop_clock(now)?;
+        }
+        Action::AddScore(color, player_number) => {
+            // Mirrors Message::AddNewScore.
+            // Non-SuddenDeath path only — use ScoreSuddenDeath + ConfirmScore for SD goals.
+            tm.add_score(color, player_number, now);
+        }
+        Action::DisallowGoal(color, goal, reason) => tm.disallow_goal(color, goal, reason, now)?,
+        #[cfg(test)]
+        Action::ScoreSuddenDeath(color) => {
+            // Mirrors app/mod.rs AddScoreComplete SD branch (~line 2048–2053):
+            // the new score is held locally (not yet sent to the engine); the engine
+            // is told to enter a confirmation pause so the operator can verify.
+            let mut s = tm.get_scores();
+            s[color] = s[color].saturating_add(1);
+            // NOTE: `s` is the held score — do NOT call tm.set_scores here.
+            // The held value is stored implicitly; ConfirmScore recomputes it.
+            let _ = s; // suppress unused-variable warning; ConfirmScore will recompute
+            tm.pause_for_confirm(now)?;
+        }
+        #[cfg(test)]
+        Action::ConfirmScore(color) => {
+            // Mirrors app/mod.rs ScoreConfirmation { correct: true } (~line 2907–2911):
+            // recompute the held score (same increment as ScoreSuddenDeath), apply it
+            // to the engine, then end the confirmation pause.
+            let mut s = tm.get_scores();
+            s[color] = s[color].saturating_add(1);
+            tm.set_scores(s, now);
+            tm.end_confirm_pause(now)?;
+        }
+        #[cfg(test)]
+        Action::ConfirmGameEnd => {
+            // Mirrors Message::ScoreConfirmation { correct: true } with an unchanged held
+            // score (app/mod.rs ~3913): the score already reflects the game-ending shot's
+            // outcome, so confirmation re-sets the same scores and ends the pause, finishing
+            // the game. (The tick loop cannot do this: end_game_ending_timeout's halt_clock
+            // stops the latch, so no tick fires to reach pause_has_ended.)
+            let s = tm.get_scores();
+            tm.set_scores(s, now);
+            tm.end_confirm_pause(now)?;
+        }
+        Action::StartPenalty(color, player_number, kind, infraction) => {
+            // Mirrors penalty_editor.rs add_to_tm.
+            tm.start_penalty(color, player_number, kind, now, infraction)?;
+        }
+        Action::StartTeamTimeout(color) => {
+            // Mirrors Message::TeamTimeout { switch: false }.
+            // Does NOT touch the latch — the engine's start_team_timeout does not
+            // call send_clock_running, so the latch remains true (tick loop keeps
+            // firing, driving the timeout countdown).
+            tm.start_team_timeout(color, now)?;
+        }
+        Action::StartRefTimeout => {
+            // Mirrors Message::RefTimeout { switch: false }.
+            // Same latch note as StartTeamTimeout.
+            tm.start_ref_timeout(now)?;
+        }
+        Action::StartPenaltyShot => {
+            // Mirrors Message::PenaltyShot { switch: false } in UWH mode.
+            // Same latch note as StartTeamTimeout.
+            tm.start_penalty_shot(now)?;
+        }
+        Action::StartRugbyPenaltyShot => {
+            // Mirrors Message::PenaltyShot { switch: false } in Rugby mode.
+            // Same latch note as StartTeamTimeout.
+            tm.start_rugby_penalty_shot(now)?;
+        }
+        Action::EndTimeout => {
+            // Mirrors Message::EndTimeout (app/mod.rs). A game-ending timeout (e.g. a
+            // game-ending rugby penalty shot at the end of the second half) arms the
+            // end-of-game confirm pause via end_game_ending_timeout so the confirm screen
+            // finishes the game cleanly (R6); otherwise the timeout ends and play resumes.
+            if tm.timeout_end_would_end_game(now)? {
+                tm.end_game_ending_timeout(now)?;
+            } else {
+                tm.end_timeout(now)?;
+                tm.update(now)?;
+            }
+        }
+        Action::SetGameClock(duration) => {
+            // Mirrors Message::TimeEditComplete (clock must already be stopped)
+            tm.set_game_clock_time(duration)?;
+        }
+        Action::Undo => {
+            tm.undo(now)?;
+            tm.update(now)?;
+        }
+        Action::Redo => {
+            tm.redo(now)?;
+            tm.update(now)?;
+        }
+        Action::Update => tm.update(now)?,
+        Action::PauseForConfirm => tm.pause_for_confirm(now)?,
+        Action::EndConfirmPause => tm.end_confirm_pause(now)?,
+        Action::SetScores(scores) => tm.set_scores(scores, now),
+        Action::SetTimeoutClock(duration) => tm.set_timeout_clock_time(duration)?,
+        Action::SwitchToTeamTimeout(color) => tm.switch_to_team_timeout(color)?,
+        Action::SwitchToRefTimeout => tm.switch_to_ref_timeout(now)?,
+        Action::SwitchToPenaltyShot => tm.switch_to_penalty_shot()?,
+        Action::SwitchToRugbyPenaltyShot => tm.switch_to_rugby_penalty_shot(now)?,
+        Action::CancelTeamTimeout => {
+            tm.cancel_team_timeout(now)?;
+            tm.update(now)?;
+        }
+        Action::ReviveTeamTimeout(color) => tm.revive_team_timeout(color)?,
+        Action::EditPenalty {
+            old_color,
+            index,
+            new_color,
+            player_number,
+            kind,
+            infraction,
+        } => tm.edit_penalty(old_color, index, new_color, player_number, kind, infraction)?,
+        Action::DeletePenalty(color, index) => tm.delete_penalty(color, index)?,
+        Action::LimitPenalties(color, limit) => {
+            // Mirrors penalty_editor.rs apply_changes, where a list that is still too long
+            // after dropping the finished penalties is reported to the operator, not an error
+            match tm.limit_pen_list_len(color, limit, now) {
+                Ok(()) | Err(TournamentManagerError::TooManyPenalties(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Action::AddWarning(color, player_number, infraction) => {
+            tm.add_warning(color, player_number, infraction, now)?
+        }
+        Action::EditWarning {
+            old_color,
+            index,
+            new_color,
+            player_number,
+            infraction,
+        } => tm.edit_warning(old_color, index, new_color, player_number, infraction)?,
+        Action::DeleteWarning(color, index) => tm.delete_warning(color, index)?,
+        Action::AddFoul(color, player_number, infraction) => {
+            tm.add_foul(color, player_number, infraction, now)?
+        }
+        Action::EditFoul {
+            old_color,
+            index,
+            new_color,
+            player_number,
+            infraction,
+        } => tm.edit_foul(old_color, index, new_color, player_number, infraction)?,
+        Action::DeleteFoul(color, index) => tm.delete_foul(color, index)?,
+        Action::StartShootout => tm.start_penalty_shootout(now)?,
+        Action::AddShootoutAttempt(color, player_number, scored) => {
+            tm.add_shootout_attempt(color, player_number, scored, now)?
+        }
+        Action::RemoveLastShootoutAttempt => tm.remove_last_shootout_attempt(now)?,
+        Action::SetConfig(config) => tm.set_config(*config)?,
+        Action::SetGameNumber(number) => tm.set_game_number(number),
+        Action::SetNextGame(info) => tm.set_next_game(*info),
+        Action::ApplyNextGameStart => tm.apply_next_game_start(now)?,
+        Action::ClearScheduledGameStart => tm.clear_scheduled_game_start(),
+        Action::ClearPortalNextGame => tm.clear_portal_next_game(),
+        Action::ResetToManualBreak => tm.reset_to_manual_break(now),
+        Action::SetCatchUpPlan(plan) => tm.set_catch_up_plan(plan)?,
+        Action::RestoreJournal(journal) => {
+            let anchor = tm.wall_clock.unwrap_or_else(WallAnchor::now);
+            tm.restore_journal(&journal, &anchor, now);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use super::{
+    TimeoutKind,
+    game_stats::iso8601_short_year,
+    infraction::InfractionDetails,
+    penalty::{Penalty, PenaltyKind},
+};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    color::Color,
+    game_snapshot::{GamePeriod, GoalDisallowReason, Infraction},
+};
+
+/// A change a referee made by hand to the score, a clock, a penalty or a foul, as the game's audit
+/// trail keeps it. The trail is only ever added to, and is sent to the portal with the game's
+/// other events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub game_period: GamePeriod,
+    /// Seconds left in the period when the change was made
+    pub period_time: f32,
+    #[serde(with = "iso8601_short_year")]
+    pub occurred_on: OffsetDateTime,
+    pub source: AuditSource,
+    pub change: AuditChange,
+}
+
+/// How the change was made
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditSource {
+    EditPage,
+    /// The score was settled while confirming it
+    Confirm,
+    /// An earlier action was undone, putting things back the way they were before it
+    Undo,
+    /// An undone action was put back
+    Redo,
+    DisallowedGoal,
+}
+
+/// What was changed, from what to what
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AuditChange {
+    Score {
+        from: BlackWhiteBundle<u8>,
+        to: BlackWhiteBundle<u8>,
+    },
+    /// Seconds on the game clock
+    GameClock { from: f32, to: f32 },
+    /// Seconds on the timeout clock
+    TimeoutClock { from: f32, to: f32 },
+    /// `from` is `None` when the penalty was added back by a redo, and `to` is `None` when it was
+    /// deleted
+    Penalty {
+        from: Option<AuditPenalty>,
+        to: Option<AuditPenalty>,
+    },
+    /// Only undo and redo change fouls on the trail: `from` is `None` when the foul was added back,
+    /// and `to` is `None` when it was taken away
+    Foul {
+        from: Option<AuditFoul>,
+        to: Option<AuditFoul>,
+    },
+    /// The clocks after undoing or redoing a clock start or stop, or the start of a timeout
+    Clocks { from: AuditClocks, to: AuditClocks },
+    /// A goal taken off the score, with the score before and after
+    GoalDisallowed {
+        color: Color,
+        player_number: u8,
+        reason: GoalDisallowReason,
+        from: BlackWhiteBundle<u8>,
+        to: BlackWhiteBundle<u8>,
+    },
+}
+
+/// A penalty as it was before or after an edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditPenalty {
+    pub color: Color,
+    pub player_number: u8,
+    pub kind: PenaltyKind,
+    pub infraction: Infraction,
+}
+
+impl AuditPenalty {
+    pub(crate) fn of(penalty: &Penalty, color: Color) -> Self {
+        Self {
+            color,
+            player_number: penalty.player_number,
+            kind: penalty.kind,
+            infraction: penalty.infraction,
+        }
+    }
+}
+
+/// A foul as it was before or after an undo or redo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditFoul {
+    /// `None` for a foul given to neither team
+    pub color: Option<Color>,
+    pub player_number: Option<u8>,
+    pub infraction: Infraction,
+}
+
+impl AuditFoul {
+    pub(crate) fn of(foul: &InfractionDetails, color: Option<Color>) -> Self {
+        Self {
+            color,
+            player_number: foul.player_number,
+            infraction: foul.infraction,
+        }
+    }
+}
+
+/// The game clock and any timeout as they were before or after an undo or redo
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditClocks {
+    /// Seconds on the game clock
+    pub game_clock: f32,
+    pub running: bool,
+    pub timeout: Option<TimeoutKind>,
+}
+
+impl AuditEntry {
+    /// How the exported audit trail names the change
+    pub fn as_str(&self) -> &'static str {
+        match &self.change {
+            AuditChange::Score { .. } if self.source == AuditSource::Confirm => "Score confirmed",
+            AuditChange::Score { .. } => "Score edited",
+            AuditChange::GameClock { .. } => "Game clock set",
+            AuditChange::TimeoutClock { .. } => "Timeout clock set",
+            AuditChange::Penalty { from: None, .. } => "Penalty added",
+            AuditChange::Penalty { to: Some(_), .. } => "Penalty edited",
+            AuditChange::Penalty { to: None, .. } => "Penalty deleted",
+            AuditChange::Foul { to: Some(_), .. } => "Foul added",
+            AuditChange::Foul { to: None, .. } => "Foul deleted",
+            AuditChange::Clocks { .. } => "Clocks set",
+            AuditChange::GoalDisallowed { .. } => "Goal disallowed",
+        }
+    }
+}
+
+impl AuditSource {
+    /// How the exported audit trail names where the change came from
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EditPage => "Edit page",
+            Self::Confirm => "Confirm",
+            Self::Undo => "Undo",
+            Self::Redo => "Redo",
+            Self::DisallowedGoal => "Disallowed goal",
+        }
+    }
+}
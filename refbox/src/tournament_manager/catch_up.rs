@@ -0,0 +1,284 @@
+//! Planning how a court gets back on schedule after running late.
+//!
+//! The break before each game already shrinks towards the timing rule's minimum break when the
+//! previous game ends late, so the plan's break reductions are a forecast of what the tournament
+//! manager will do on its own. Shortening half-times is the one extra lever: it's only part of
+//! a plan when the operator asks for it, and only takes effect once the plan is accepted.
+
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{cmp::min, time::Duration};
+use uwh_common::{
+    config::Game as GameConfig,
+    uwhportal::schedule::{Game, GameNumber, Schedule},
+};
+
+/// A half-time is never shortened to less than this fraction of its scheduled length
+const MIN_HALF_TIME_DIVISOR: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatchUpPlan {
+    /// The games still starting late, in order, beginning with the next game
+    pub games: Vec<PlannedGame>,
+    /// The first game that starts on time again, if the plan gets there
+    pub on_schedule_by: Option<GameNumber>,
+    /// How late the last planned game still finishes
+    pub remaining: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedGame {
+    pub number: GameNumber,
+    /// How much shorter than scheduled the break before this game is
+    pub break_cut: Duration,
+    /// How late this game starts
+    pub late_by: Duration,
+    /// The shortened half-time, if this game's half-time is shortened
+    pub half_time: Option<Duration>,
+}
+
+impl CatchUpPlan {
+    pub fn game(&self, number: &GameNumber) -> Option<&PlannedGame> {
+        self.games.iter().find(|game| game.number == *number)
+    }
+
+    /// Whether accepting the plan changes anything beyond what happens without it
+    pub fn shortens_half_times(&self) -> bool {
+        self.games.iter().any(|game| game.half_time.is_some())
+    }
+}
+
+fn config_for(schedule: &Schedule, game: &Game) -> Option<GameConfig> {
+    let timing = schedule.get_game_timing(&game.number);
+    if timing.is_none() {
+        warn!(
+            "No timing rule for game {}, can't plan past it",
+            game.number
+        );
+    }
+    Some(timing?.clone().into())
+}
+
+fn next_on_court<'a>(schedule: &'a Schedule, game: &Game) -> Option<&'a Game> {
+    schedule
+        .games
+        .values()
+        .filter(|other| other.court == game.court)
+        .filter(|other| other.start_time > game.start_time)
+        .min_by_key(|other| other.start_time)
+}
+
+/// Plans how the games from `next_game` on, on its court, make up `late_by`. Every break is cut
+/// as far as the minimum break of the game before it allows; with `shorten_half_times` each
+/// game's half-time is cut as well, to no less than half its scheduled length. Returns `None`
+/// when `next_game` isn't in the schedule.
+pub fn plan_catch_up(
+    schedule: &Schedule,
+    next_game: &GameNumber,
+    late_by: Duration,
+    shorten_half_times: bool,
+) -> Option<CatchUpPlan> {
+    let mut game = schedule.games.get(next_game)?;
+    let mut late_by = late_by;
+    let mut break_cut = Duration::ZERO;
+    let mut games = Vec::new();
+
+    loop {
+        if late_by.is_zero() {
+            return Some(CatchUpPlan {
+                games,
+                on_schedule_by: Some(game.number.clone()),
+                remaining: Duration::ZERO,
+            });
+        }
+
+        let Some(config) = config_for(schedule, game) else {
+            break;
+        };
+
+        let mut half_time = None;
+        if shorten_half_times && !config.single_half {
+            let max_cut =
+                config.half_time_duration - config.half_time_duration / MIN_HALF_TIME_DIVISOR;
+            let cut = min(late_by, max_cut);
+            if !cut.is_zero() {
+                half_time = Some(config.half_time_duration - cut);
+            }
+        }
+
+        games.push(PlannedGame {
+            number: game.number.clone(),
+            break_cut,
+            late_by,
+            half_time,
+        });
+        late_by -= config.half_time_duration - half_time.unwrap_or(config.half_time_duration);
+
+        let Some(next) = next_on_court(schedule, game) else {
+            break;
+        };
+
+        let gap: Duration = (next.start_time - game.start_time)
+            .try_into()
+            .unwrap_or(Duration::ZERO);
+        let scheduled_break = gap.saturating_sub(config.regulation_play());
+        let planned_break = scheduled_break
+            .saturating_sub(late_by)
+            .max(config.minimum_break);
+        break_cut = scheduled_break.saturating_sub(planned_break);
+        late_by = (late_by + planned_break).saturating_sub(scheduled_break);
+        game = next;
+    }
+
+    Some(CatchUpPlan {
+        games,
+        on_schedule_by: None,
+        remaining: late_by,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{OffsetDateTime, macros::datetime};
+    use uwh_common::uwhportal::schedule::{EventId, ScheduledTeam, TeamId, TimingRule};
+
+    const MIN: u64 = 60;
+
+    fn rule(name: &str, half_time: u64, minimum_break: u64) -> TimingRule {
+        TimingRule {
+            name: name.to_string(),
+            team_timeout_count: 1,
+            team_timeouts_counted_per_half: false,
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            last_2_min_stop_time: false,
+            half_play_duration: Duration::from_secs(10 * MIN),
+            half_time_duration: Duration::from_secs(half_time),
+            team_timeout_duration: Duration::from_secs(MIN),
+            ot_half_play_duration: Duration::ZERO,
+            ot_half_time_duration: Duration::ZERO,
+            pre_overtime_break: Duration::ZERO,
+            pre_sudden_death_duration: Duration::ZERO,
+            minimum_break: Duration::from_secs(minimum_break),
+            game_block: None,
+        }
+    }
+
+    fn game(number: &str, court: &str, start_time: OffsetDateTime, timing_rule: &str) -> Game {
+        Game {
+            number: number.to_string(),
+            dark: ScheduledTeam::new_team_id(TeamId::from_partial("dark")),
+            light: ScheduledTeam::new_team_id(TeamId::from_partial("light")),
+            start_time,
+            court: court.to_string(),
+            timing_rule: timing_rule.to_string(),
+            referee_assignments: None,
+            description: None,
+        }
+    }
+
+    /// Games 1-4 on court A every 30 min (10 min halves, a 3 min half-time, so a 7 min break
+    /// with a 2 min minimum), and game 10 on court B alongside game 2.
+    fn schedule() -> Schedule {
+        let games = [
+            game("1", "A", datetime!(2026-06-01 9:00 UTC), "RR"),
+            game("2", "A", datetime!(2026-06-01 9:30 UTC), "RR"),
+            game("10", "B", datetime!(2026-06-01 9:30 UTC), "RR"),
+            game("3", "A", datetime!(2026-06-01 10:00 UTC), "RR"),
+            game("4", "A", datetime!(2026-06-01 10:30 UTC), "RR"),
+        ];
+        Schedule {
+            event_id: EventId::from_partial("spring-cup"),
+            games: games
+                .into_iter()
+                .map(|game| (game.number.clone(), game))
+                .collect(),
+            non_game_entries: vec![],
+            groups: vec![],
+            timing_rules: vec![rule("RR", 3 * MIN, 2 * MIN)],
+            standings_order: None,
+            final_results_order: None,
+            referees_by_game_number: None,
+        }
+    }
+
+    fn mins(mins: u64) -> Duration {
+        Duration::from_secs(mins * MIN)
+    }
+
+    #[test]
+    fn on_time_needs_no_plan() {
+        let plan = plan_catch_up(&schedule(), &"2".to_string(), Duration::ZERO, false).unwrap();
+        assert!(plan.games.is_empty());
+        assert_eq!(plan.on_schedule_by, Some("2".to_string()));
+        assert!(!plan.shortens_half_times());
+    }
+
+    #[test]
+    fn breaks_are_cut_down_to_the_minimum() {
+        let plan = plan_catch_up(&schedule(), &"1".to_string(), mins(8), false).unwrap();
+        assert_eq!(
+            plan.games,
+            vec![
+                PlannedGame {
+                    number: "1".to_string(),
+                    break_cut: Duration::ZERO,
+                    late_by: mins(8),
+                    half_time: None,
+                },
+                // Court B's game is skipped, and the break is cut from 7 min to 2
+                PlannedGame {
+                    number: "2".to_string(),
+                    break_cut: mins(5),
+                    late_by: mins(3),
+                    half_time: None,
+                },
+            ]
+        );
+        assert_eq!(plan.on_schedule_by, Some("3".to_string()));
+        assert_eq!(plan.remaining, Duration::ZERO);
+    }
+
+    #[test]
+    fn shortened_half_times_catch_up_sooner() {
+        let plan = plan_catch_up(&schedule(), &"1".to_string(), mins(8), true).unwrap();
+        // Half-time goes from 3 min to the 1.5 min floor, then the break takes the rest
+        assert_eq!(plan.games.len(), 2);
+        assert_eq!(plan.games[0].half_time, Some(Duration::from_secs(90)));
+        assert_eq!(plan.games[1].break_cut, Duration::from_secs(5 * MIN));
+        assert_eq!(plan.games[1].late_by, Duration::from_secs(90));
+        assert_eq!(plan.games[1].half_time, Some(Duration::from_secs(90)));
+        assert_eq!(plan.on_schedule_by, Some("3".to_string()));
+        assert!(plan.shortens_half_times());
+    }
+
+    #[test]
+    fn a_delay_too_big_to_recover_is_carried_to_the_last_game() {
+        let plan = plan_catch_up(&schedule(), &"3".to_string(), mins(20), false).unwrap();
+        assert_eq!(
+            plan.games.iter().map(|g| g.late_by).collect::<Vec<_>>(),
+            vec![mins(20), mins(15)]
+        );
+        assert_eq!(plan.on_schedule_by, None);
+        assert_eq!(plan.remaining, mins(15));
+    }
+
+    #[test]
+    fn a_break_shorter_than_the_minimum_adds_delay() {
+        let mut schedule = schedule();
+        schedule.timing_rules = vec![rule("RR", 3 * MIN, 8 * MIN)];
+        let plan = plan_catch_up(&schedule, &"3".to_string(), mins(1), false).unwrap();
+        assert_eq!(plan.games[1].break_cut, Duration::ZERO);
+        assert_eq!(plan.games[1].late_by, mins(2));
+        assert_eq!(plan.remaining, mins(2));
+    }
+
+    #[test]
+    fn an_unknown_game_has_no_plan() {
+        assert_eq!(
+            plan_catch_up(&schedule(), &"99".to_string(), mins(5), false),
+            None
+        );
+    }
+}
@@ -1,24 +1,136 @@
 use super::{
-    Color, Duration, GamePeriod, Infraction, InfractionDetails, Instant, OffsetDateTime, Penalty,
-    PenaltyKind,
+    AuditChange, AuditEntry, AuditSource, BlackWhiteBundle, Color, Duration, GamePeriod,
+    IncidentReport, Infraction, InfractionDetails, Instant, OffsetDateTime, Penalty, PenaltyKind,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use time::format_description::well_known::{Iso8601, iso8601};
-use uwh_common::uwhportal::schedule::GameNumber;
+use uwh_common::{
+    game_snapshot::{GoalDisallowReason, GoalSnapshot},
+    uwhportal::schedule::GameNumber,
+};
 
 const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
     .set_year_is_six_digits(false)
     .encode();
 const FORMAT: Iso8601<CONFIG> = Iso8601::<CONFIG>;
-time::serde::format_description!(iso8601_short_year, OffsetDateTime, FORMAT);
+time::serde::format_description!(pub(super) iso8601_short_year, OffsetDateTime, FORMAT);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct GameStats {
     game_number: GameNumber,
+    #[serde(with = "time::serde::rfc3339::option")]
     start_timestamp: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
     end_timestamp: Option<OffsetDateTime>,
     events: Vec<Event>,
+    /// What the completed scoresheet shows beyond the events sent to the portal
+    #[serde(default)]
+    sheet: SheetRecords,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct SheetRecords {
+    timeouts: Vec<TimeoutRecord>,
+    warnings: Vec<InfractionRecord>,
+    /// Every foul, including the team and "both teams at fault" fouls held back from the portal
+    fouls: Vec<InfractionRecord>,
+    /// The infraction of each `Penalty` event, which the portal does not take
+    penalty_infractions: Vec<(OffsetDateTime, Infraction)>,
+    #[serde(default)]
+    period_starts: Vec<PeriodStartRecord>,
+    #[serde(default)]
+    incidents: Vec<IncidentReport>,
+}
+
+/// What stopped play, as the scoresheet records it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum TimeoutKind {
+    Team(Color),
+    Ref,
+    PenaltyShot,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TimeoutRecord {
+    kind: TimeoutKind,
+    game_period: GamePeriod,
+    period_time: f32,
+    occurred_on: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PeriodStartRecord {
+    game_period: GamePeriod,
+    period_time: f32,
+    occurred_on: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct InfractionRecord {
+    color: Option<Color>,
+    player_number: Option<u8>,
+    game_period: GamePeriod,
+    period_time: f32,
+    occurred_on: OffsetDateTime,
+    infraction: Infraction,
+}
+
+impl From<(&InfractionDetails, Option<Color>)> for InfractionRecord {
+    fn from((details, color): (&InfractionDetails, Option<Color>)) -> Self {
+        Self {
+            color,
+            player_number: details.player_number,
+            game_period: details.start_period,
+            period_time: details.start_time.as_secs_f32(),
+            occurred_on: calculate_timestamp(details.start_instant),
+            infraction: details.infraction,
+        }
+    }
+}
+
+/// How a team's captain, or whoever stood in for them, signed off the result at the end of the
+/// game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TeamSignOff {
+    /// The cap number, or other number identifying the captain, entered for the team, if anyone
+    /// signed. It is sent and exported with the result, so it is never a secret.
+    pub(crate) signed_by: Option<u32>,
+    pub(crate) protest: bool,
+}
+
+/// One line of a completed scoresheet
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SheetLine {
+    /// `None` for a foul on both teams, a ref timeout or a penalty shot
+    pub(crate) color: Option<Color>,
+    pub(crate) player_number: Option<u8>,
+    pub(crate) period: GamePeriod,
+    /// Time left in the period
+    pub(crate) period_time: Duration,
+    pub(crate) occurred_on: OffsetDateTime,
+    pub(crate) kind: SheetLineKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SheetLineKind {
+    Goal {
+        disallowed: Option<GoalDisallowReason>,
+    },
+    /// `duration` is `None` for a total dismissal
+    Penalty {
+        duration: Option<Duration>,
+        infraction: Option<Infraction>,
+    },
+    Warning(Infraction),
+    Foul(Infraction),
+    Timeout(TimeoutKind),
+    ShootoutAttempt {
+        round: u16,
+        scored: bool,
+    },
+    /// `period` began, with `period_time` on the clock
+    PeriodStart,
 }
 
 impl GameStats {
@@ -28,6 +140,7 @@ impl GameStats {
             start_timestamp: None,
             end_timestamp: None,
             events: Vec::new(),
+            sheet: SheetRecords::default(),
         }
     }
 
@@ -41,6 +154,14 @@ impl GameStats {
         self.end_timestamp = Some(timestamp);
     }
 
+    pub(crate) fn start_timestamp(&self) -> Option<OffsetDateTime> {
+        self.start_timestamp
+    }
+
+    pub(crate) fn end_timestamp(&self) -> Option<OffsetDateTime> {
+        self.end_timestamp
+    }
+
     pub(crate) fn add_goal(
         &mut self,
         period: GamePeriod,
@@ -63,6 +184,10 @@ impl GameStats {
     }
 
     pub(crate) fn add_penalty(&mut self, penalty: &Penalty, color: Color) {
+        let occurred_on = calculate_timestamp(penalty.start_instant);
+        self.sheet
+            .penalty_infractions
+            .push((occurred_on, penalty.infraction));
         let event = Event::Penalty {
             player_cap_number: penalty.player_number,
             side: match color {
@@ -71,7 +196,7 @@ impl GameStats {
             },
             game_period: penalty.start_period,
             period_time: penalty.start_time.as_secs_f32(),
-            occurred_on: calculate_timestamp(penalty.start_instant),
+            occurred_on,
             duration: match penalty.kind {
                 PenaltyKind::TotalDismissal => None,
                 _ => Some(penalty.kind.as_duration().unwrap().as_secs()),
@@ -93,18 +218,478 @@ impl GameStats {
         self.events.push(event);
     }
 
+    pub(crate) fn add_shootout_attempt(
+        &mut self,
+        color: Color,
+        round: u16,
+        player_num: u8,
+        scored: bool,
+        instant: Instant,
+    ) {
+        let event = Event::ShootoutAttempt {
+            player_cap_number: player_num,
+            side: side_str(color),
+            round,
+            scored,
+            occurred_on: calculate_timestamp(instant),
+        };
+        self.events.push(event);
+    }
+
+    /// Remove the event `add_shootout_attempt` recorded for this team's attempt
+    /// in `round`. Returns whether one was found.
+    pub(crate) fn remove_shootout_attempt(&mut self, color: Color, round: u16) -> bool {
+        let side = side_str(color);
+        self.remove_last(|event| {
+            matches!(event, Event::ShootoutAttempt { side: s, round: r, .. }
+                if *s == side && *r == round)
+        })
+    }
+
+    /// Remove the most recent goal matching these details, as recorded by
+    /// `add_goal`. Returns whether one was found.
+    pub(crate) fn remove_goal(
+        &mut self,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        color: Color,
+        player_num: u8,
+    ) -> bool {
+        let time = time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32();
+        let side = side_str(color);
+        self.remove_last(|event| {
+            matches!(event, Event::Goal { player_cap_number, side: s, game_period, period_time, .. }
+                if *player_cap_number == player_num
+                    && *s == side
+                    && *game_period == period
+                    && *period_time == time)
+        })
+    }
+
+    /// Remove the event `add_penalty` recorded for this penalty. Returns
+    /// whether one was found.
+    pub(crate) fn remove_penalty(&mut self, penalty: &Penalty, color: Color) -> bool {
+        let time = penalty.start_time.as_secs_f32();
+        let side = side_str(color);
+        let Some(i) = self.events.iter().rposition(|event| {
+            matches!(event, Event::Penalty { player_cap_number, side: s, game_period, period_time, is_total_dismissal, .. }
+                if *player_cap_number == penalty.player_number
+                    && *s == side
+                    && *game_period == penalty.start_period
+                    && *period_time == time
+                    && *is_total_dismissal == (penalty.kind == PenaltyKind::TotalDismissal))
+        }) else {
+            return false;
+        };
+        if let Event::Penalty { occurred_on, .. } = self.events.remove(i) {
+            self.sheet
+                .penalty_infractions
+                .retain(|(on, _)| *on != occurred_on);
+        }
+        true
+    }
+
+    /// Record a warning for the scoresheet. Warnings are not sent to the portal.
+    pub(crate) fn add_warning(&mut self, details: &InfractionDetails, color: Color) {
+        self.sheet.warnings.push((details, Some(color)).into());
+    }
+
+    /// Record a foul for the scoresheet, whether or not `add_foul` sends it to the portal
+    pub(crate) fn add_sheet_foul(&mut self, details: &InfractionDetails, color: Option<Color>) {
+        self.sheet.fouls.push((details, color).into());
+    }
+
+    /// Record a timeout or penalty shot for the scoresheet as it starts
+    pub(crate) fn add_timeout(
+        &mut self,
+        kind: TimeoutKind,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        instant: Instant,
+    ) {
+        self.sheet.timeouts.push(TimeoutRecord {
+            kind,
+            game_period: period,
+            period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
+            occurred_on: calculate_timestamp(instant),
+        });
+    }
+
+    /// Change what the latest timeout was, when the referees switch it to another kind
+    pub(crate) fn change_last_timeout(&mut self, kind: TimeoutKind) {
+        if let Some(timeout) = self.sheet.timeouts.last_mut() {
+            timeout.kind = kind;
+        }
+    }
+
+    /// Remove the latest timeout, when it is cancelled or undone. Returns whether there was one.
+    pub(crate) fn remove_last_timeout(&mut self) -> bool {
+        self.sheet.timeouts.pop().is_some()
+    }
+
+    /// Record that a period of the game began, with `time_left_in_period` on the clock
+    pub(crate) fn add_period_start(
+        &mut self,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        instant: Instant,
+    ) {
+        self.sheet.period_starts.push(PeriodStartRecord {
+            game_period: period,
+            period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
+            occurred_on: calculate_timestamp(instant),
+        });
+    }
+
+    /// Record a referee's incident report. Incidents are not sent to the portal.
+    pub(crate) fn add_incident(&mut self, incident: IncidentReport) {
+        self.sheet.incidents.push(incident);
+    }
+
+    /// The incident reports filed during the game, in the order they were filed
+    pub(crate) fn incidents(&self) -> &[IncidentReport] {
+        &self.sheet.incidents
+    }
+
+    /// Everything the completed scoresheet lists, in the order it happened
+    pub(crate) fn sheet_lines(&self) -> Vec<SheetLine> {
+        let mut lines = Vec::new();
+        let goals = self.goals();
+        let mut goal_index: BlackWhiteBundle<usize> = Default::default();
+        for event in &self.events {
+            let (color, player_number, period, period_time, occurred_on, kind) = match event {
+                Event::Goal {
+                    player_cap_number,
+                    side,
+                    game_period,
+                    period_time,
+                    occurred_on,
+                } => {
+                    let color = side_color(side);
+                    let disallowed = goals[color][goal_index[color]].disallowed;
+                    goal_index[color] += 1;
+                    (
+                        Some(color),
+                        Some(*player_cap_number),
+                        *game_period,
+                        *period_time,
+                        *occurred_on,
+                        SheetLineKind::Goal { disallowed },
+                    )
+                }
+                Event::Penalty {
+                    player_cap_number,
+                    side,
+                    game_period,
+                    period_time,
+                    occurred_on,
+                    duration,
+                    ..
+                } => {
+                    let infraction = self
+                        .sheet
+                        .penalty_infractions
+                        .iter()
+                        .find(|(on, _)| on == occurred_on)
+                        .map(|(_, infraction)| *infraction);
+                    (
+                        Some(side_color(side)),
+                        Some(*player_cap_number),
+                        *game_period,
+                        *period_time,
+                        *occurred_on,
+                        SheetLineKind::Penalty {
+                            duration: duration.map(Duration::from_secs),
+                            infraction,
+                        },
+                    )
+                }
+                Event::ShootoutAttempt {
+                    player_cap_number,
+                    side,
+                    round,
+                    scored,
+                    occurred_on,
+                } => (
+                    Some(side_color(side)),
+                    Some(*player_cap_number),
+                    GamePeriod::PenaltyShootout,
+                    0.0,
+                    *occurred_on,
+                    SheetLineKind::ShootoutAttempt {
+                        round: *round,
+                        scored: *scored,
+                    },
+                ),
+                // Fouls come from the sheet's own list, which has the ones the portal doesn't,
+                // disallowed goals are shown on the goal itself, the sign-offs by the
+                // signatures, and the edits have a trail of their own
+                Event::Foul { .. }
+                | Event::GoalDisallowed { .. }
+                | Event::ResultConfirmation { .. }
+                | Event::Edit(_) => continue,
+            };
+            lines.push(SheetLine {
+                color,
+                player_number,
+                period,
+                period_time: Duration::from_secs_f32(period_time),
+                occurred_on,
+                kind,
+            });
+        }
+
+        let infractions = |records: &[InfractionRecord], kind: fn(Infraction) -> SheetLineKind| {
+            records
+                .iter()
+                .map(move |record| SheetLine {
+                    color: record.color,
+                    player_number: record.player_number,
+                    period: record.game_period,
+                    period_time: Duration::from_secs_f32(record.period_time),
+                    occurred_on: record.occurred_on,
+                    kind: kind(record.infraction),
+                })
+                .collect::<Vec<_>>()
+        };
+        lines.extend(infractions(&self.sheet.warnings, SheetLineKind::Warning));
+        lines.extend(infractions(&self.sheet.fouls, SheetLineKind::Foul));
+        lines.extend(self.sheet.timeouts.iter().map(|timeout| SheetLine {
+            color: match timeout.kind {
+                TimeoutKind::Team(color) => Some(color),
+                TimeoutKind::Ref | TimeoutKind::PenaltyShot => None,
+            },
+            player_number: None,
+            period: timeout.game_period,
+            period_time: Duration::from_secs_f32(timeout.period_time),
+            occurred_on: timeout.occurred_on,
+            kind: SheetLineKind::Timeout(timeout.kind),
+        }));
+        lines.extend(self.sheet.period_starts.iter().map(|start| SheetLine {
+            color: None,
+            player_number: None,
+            period: start.game_period,
+            period_time: Duration::from_secs_f32(start.period_time),
+            occurred_on: start.occurred_on,
+            kind: SheetLineKind::PeriodStart,
+        }));
+
+        lines.sort_by_key(|line| line.occurred_on);
+        lines
+    }
+
+    /// Record that `color`'s `goal`th goal of the game, counting from zero in the order
+    /// `add_goal` recorded them, was disallowed. Returns whether there is such a goal.
+    pub(crate) fn add_goal_disallowed(
+        &mut self,
+        color: Color,
+        goal: usize,
+        reason: GoalDisallowReason,
+        instant: Instant,
+    ) -> bool {
+        let side = side_str(color);
+        let Some(Event::Goal {
+            player_cap_number,
+            game_period,
+            period_time,
+            occurred_on,
+            ..
+        }) = self
+            .events
+            .iter()
+            .filter(|event| matches!(event, Event::Goal { side: s, .. } if *s == side))
+            .nth(goal)
+        else {
+            return false;
+        };
+        let event = Event::GoalDisallowed {
+            player_cap_number: *player_cap_number,
+            side,
+            game_period: *game_period,
+            period_time: *period_time,
+            goal_occurred_on: *occurred_on,
+            reason,
+            occurred_on: calculate_timestamp(instant),
+        };
+        self.events.push(event);
+        true
+    }
+
+    /// Remove the event `add_goal_disallowed` recorded for this goal. Returns whether one was
+    /// found.
+    pub(crate) fn remove_goal_disallowed(&mut self, color: Color, goal: usize) -> bool {
+        let side = side_str(color);
+        let Some(goal_time) = self
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Goal {
+                    side: s,
+                    occurred_on,
+                    ..
+                } if *s == side => Some(*occurred_on),
+                _ => None,
+            })
+            .nth(goal)
+        else {
+            return false;
+        };
+        self.remove_last(|event| {
+            matches!(event, Event::GoalDisallowed { side: s, goal_occurred_on, .. }
+                if *s == side && *goal_occurred_on == goal_time)
+        })
+    }
+
+    /// Every goal recorded this game, with the reason for any that were disallowed
+    pub(crate) fn goals(&self) -> BlackWhiteBundle<Vec<GoalSnapshot>> {
+        let mut goals: BlackWhiteBundle<Vec<GoalSnapshot>> = Default::default();
+        for event in &self.events {
+            if let Event::Goal {
+                player_cap_number,
+                side,
+                game_period,
+                period_time,
+                occurred_on,
+            } = event
+            {
+                let disallowed = self.events.iter().find_map(|event| match event {
+                    Event::GoalDisallowed {
+                        side: s,
+                        goal_occurred_on,
+                        reason,
+                        ..
+                    } if s == side && goal_occurred_on == occurred_on => Some(*reason),
+                    _ => None,
+                });
+                goals[side_color(side)].push(GoalSnapshot {
+                    player_number: *player_cap_number,
+                    period: *game_period,
+                    secs_in_period: *period_time as u32,
+                    disallowed,
+                });
+            }
+        }
+        goals
+    }
+
+    /// The team and cap number of every player given a total dismissal
+    pub(crate) fn total_dismissals(&self) -> Vec<(Color, u8)> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Penalty {
+                    player_cap_number,
+                    side,
+                    is_total_dismissal: true,
+                    ..
+                } => Some((side_color(side), *player_cap_number)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Record the captains' sign-off of the result, replacing any made before
+    pub(crate) fn set_sign_offs(&mut self, sign_offs: BlackWhiteBundle<TeamSignOff>, now: Instant) {
+        self.events
+            .retain(|event| !matches!(event, Event::ResultConfirmation { .. }));
+        let occurred_on = calculate_timestamp(now);
+        for (color, sign_off) in sign_offs.iter() {
+            self.events.push(Event::ResultConfirmation {
+                side: side_str(color),
+                signed_by: sign_off.signed_by,
+                protest_lodged: sign_off.protest,
+                occurred_on,
+            });
+        }
+    }
+
+    /// The captains' sign-off of the result, if it has been asked for
+    pub(crate) fn sign_offs(&self) -> Option<BlackWhiteBundle<TeamSignOff>> {
+        let mut sign_offs: Option<BlackWhiteBundle<TeamSignOff>> = None;
+        for event in &self.events {
+            if let Event::ResultConfirmation {
+                side,
+                signed_by,
+                protest_lodged,
+                ..
+            } = event
+            {
+                sign_offs.get_or_insert_default()[side_color(side)] = TeamSignOff {
+                    signed_by: *signed_by,
+                    protest: *protest_lodged,
+                };
+            }
+        }
+        sign_offs
+    }
+
+    /// Whether either team lodged a protest against the result
+    pub(crate) fn protest_lodged(&self) -> bool {
+        self.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::ResultConfirmation {
+                    protest_lodged: true,
+                    ..
+                }
+            )
+        })
+    }
+
+    /// Add a change made by hand to the audit trail
+    pub(crate) fn add_edit(
+        &mut self,
+        period: GamePeriod,
+        time_left_in_period: Option<Duration>,
+        source: AuditSource,
+        change: AuditChange,
+        instant: Instant,
+    ) {
+        self.events.push(Event::Edit(AuditEntry {
+            game_period: period,
+            period_time: time_left_in_period.unwrap_or(Duration::ZERO).as_secs_f32(),
+            occurred_on: calculate_timestamp(instant),
+            source,
+            change,
+        }));
+    }
+
+    /// The changes made by hand during the game, in the order they were made
+    pub(crate) fn audit_trail(&self) -> Vec<AuditEntry> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Edit(entry) => Some(entry.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn remove_last(&mut self, pred: impl Fn(&Event) -> bool) -> bool {
+        if let Some(i) = self.events.iter().rposition(pred) {
+            self.events.remove(i);
+            true
+        } else {
+            false
+        }
+    }
+
     pub(crate) fn as_json(&self) -> String {
         let mut events = self.events.clone();
         events.sort_unstable_by_key(|event| match event {
             Event::Goal { occurred_on, .. } => *occurred_on,
             Event::Penalty { occurred_on, .. } => *occurred_on,
             Event::Foul { occurred_on, .. } => *occurred_on,
+            Event::ShootoutAttempt { occurred_on, .. } => *occurred_on,
+            Event::GoalDisallowed { occurred_on, .. } => *occurred_on,
+            Event::ResultConfirmation { occurred_on, .. } => *occurred_on,
+            Event::Edit(entry) => entry.occurred_on,
         });
         serde_json::to_string(&events).unwrap()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "$type")]
 enum Event {
     #[serde(rename = "goal")]
@@ -150,9 +735,53 @@ enum Event {
         occurred_on: OffsetDateTime,
         called: Infraction,
     },
+    #[serde(rename = "shootoutAttempt")]
+    ShootoutAttempt {
+        #[serde(rename = "playerCapNumber")]
+        player_cap_number: u8,
+        side: String,
+        round: u16,
+        scored: bool,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+    },
+    /// Refers back to the `Goal` event with the same side and `occurredOn`
+    #[serde(rename = "goalDisallowed")]
+    GoalDisallowed {
+        #[serde(rename = "playerCapNumber")]
+        player_cap_number: u8,
+        side: String,
+        #[serde(rename = "gamePeriod")]
+        game_period: GamePeriod,
+        #[serde(rename = "periodTime")]
+        period_time: f32,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "goalOccurredOn")]
+        goal_occurred_on: OffsetDateTime,
+        reason: GoalDisallowReason,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+    },
+    /// A team's sign-off of the result, one for each team
+    #[serde(rename = "resultConfirmation")]
+    ResultConfirmation {
+        side: String,
+        #[serde(rename = "signedBy")]
+        signed_by: Option<u32>,
+        #[serde(rename = "protestLodged")]
+        protest_lodged: bool,
+        #[serde(with = "iso8601_short_year")]
+        #[serde(rename = "occurredOn")]
+        occurred_on: OffsetDateTime,
+    },
+    /// A change a referee made by hand, for the game's audit trail
+    #[serde(rename = "edit")]
+    Edit(AuditEntry),
 }
 
-fn calculate_timestamp(instant: Instant) -> OffsetDateTime {
+pub(super) fn calculate_timestamp(instant: Instant) -> OffsetDateTime {
     let now = Instant::now();
     let mut timestamp = OffsetDateTime::now_utc();
 
@@ -177,6 +806,14 @@ fn side_str(color: Color) -> String {
     }
 }
 
+fn side_color(side: &str) -> Color {
+    if side == "dark" {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,11 +837,11 @@ mod tests {
         let mut stats = GameStats::new("1");
         // Player foul against White.
         stats.add_foul(
-            &details(Some(7), Infraction::Obstruction),
+            &details(Some(7), Infraction::OBSTRUCTION),
             Some(Color::White),
         );
         // Team-level, neither-side foul ("both at fault"), no player.
-        stats.add_foul(&details(None, Infraction::DelayOfGame), None);
+        stats.add_foul(&details(None, Infraction::DELAY_OF_GAME), None);
 
         let events = events_of(&stats);
         let fouls: Vec<&serde_json::Value> =
@@ -220,4 +857,158 @@ mod tests {
         assert!(neither["playerCapNumber"].is_null());
         assert_eq!(neither["called"], "DelayOfGame");
     }
+
+    #[test]
+    fn shootout_attempts_serialize_with_round_and_result() {
+        let mut stats = GameStats::new("1");
+        let now = Instant::now();
+        stats.add_shootout_attempt(Color::Black, 1, 4, true, now);
+        stats.add_shootout_attempt(Color::White, 1, 9, false, now);
+
+        let events = events_of(&stats);
+        assert_eq!(events.len(), 2);
+        let black = events.iter().find(|e| e["side"] == "dark").unwrap();
+        assert_eq!(black["$type"], "shootoutAttempt");
+        assert_eq!(black["playerCapNumber"], 4);
+        assert_eq!(black["round"], 1);
+        assert_eq!(black["scored"], true);
+
+        assert!(stats.remove_shootout_attempt(Color::White, 1));
+        assert!(!stats.remove_shootout_attempt(Color::White, 1));
+        assert_eq!(events_of(&stats).len(), 1);
+    }
+
+    #[test]
+    fn total_dismissals_lists_each_dismissed_player() {
+        let penalty = |player_number, kind| Penalty {
+            kind,
+            player_number,
+            start_period: GamePeriod::FirstHalf,
+            start_time: Duration::from_secs(400),
+            start_instant: Instant::now(),
+            infraction: Infraction::UNKNOWN,
+        };
+        let mut stats = GameStats::new("1");
+        stats.add_penalty(&penalty(3, PenaltyKind::TotalDismissal), Color::White);
+        stats.add_penalty(&penalty(4, PenaltyKind::ONE_MINUTE), Color::Black);
+        stats.add_penalty(&penalty(9, PenaltyKind::TotalDismissal), Color::Black);
+        stats.add_goal(GamePeriod::FirstHalf, None, Color::Black, 5, Instant::now());
+
+        assert_eq!(
+            stats.total_dismissals(),
+            vec![(Color::White, 3), (Color::Black, 9)]
+        );
+    }
+
+    #[test]
+    fn sheet_lines_have_what_the_portal_does_not() {
+        let start = Instant::now();
+        let mut stats = GameStats::new("1");
+        let penalty = Penalty {
+            kind: PenaltyKind::ONE_MINUTE,
+            player_number: 4,
+            start_period: GamePeriod::FirstHalf,
+            start_time: Duration::from_secs(700),
+            start_instant: start + Duration::from_secs(20),
+            infraction: Infraction::OBSTRUCTION,
+        };
+        stats.add_goal(GamePeriod::FirstHalf, None, Color::Black, 5, start);
+        stats.add_timeout(
+            TimeoutKind::Ref,
+            GamePeriod::FirstHalf,
+            Some(Duration::from_secs(650)),
+            start + Duration::from_secs(60),
+        );
+        stats.change_last_timeout(TimeoutKind::PenaltyShot);
+        stats.add_penalty(&penalty, Color::White);
+        stats.add_warning(
+            &InfractionDetails {
+                start_instant: start + Duration::from_secs(10),
+                ..details(Some(9), Infraction::DELAY_OF_GAME)
+            },
+            Color::Black,
+        );
+        stats.add_sheet_foul(
+            &InfractionDetails {
+                start_instant: start + Duration::from_secs(30),
+                ..details(None, Infraction::OBSTRUCTION)
+            },
+            None,
+        );
+        stats.add_period_start(
+            GamePeriod::HalfTime,
+            Some(Duration::from_secs(180)),
+            start + Duration::from_secs(90),
+        );
+
+        let kinds: Vec<_> = stats.sheet_lines().into_iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SheetLineKind::Goal { disallowed: None },
+                SheetLineKind::Warning(Infraction::DELAY_OF_GAME),
+                SheetLineKind::Penalty {
+                    duration: Some(Duration::from_secs(60)),
+                    infraction: Some(Infraction::OBSTRUCTION),
+                },
+                SheetLineKind::Foul(Infraction::OBSTRUCTION),
+                SheetLineKind::Timeout(TimeoutKind::PenaltyShot),
+                SheetLineKind::PeriodStart,
+            ]
+        );
+        // None of it changes what the portal is sent
+        assert_eq!(events_of(&stats).len(), 2);
+
+        assert!(stats.remove_penalty(&penalty, Color::White));
+        assert!(stats.remove_last_timeout());
+        assert!(!stats.remove_last_timeout());
+        assert_eq!(stats.sheet_lines().len(), 4);
+    }
+
+    #[test]
+    fn sign_offs_are_sent_and_replace_earlier_ones() {
+        let now = Instant::now();
+        let mut stats = GameStats::new("1");
+        stats.add_goal(GamePeriod::FirstHalf, None, Color::Black, 5, now);
+        assert_eq!(stats.sign_offs(), None);
+        assert!(!stats.protest_lodged());
+
+        stats.set_sign_offs(
+            BlackWhiteBundle {
+                black: TeamSignOff {
+                    signed_by: Some(4321),
+                    protest: false,
+                },
+                white: TeamSignOff::default(),
+            },
+            now,
+        );
+        let sign_offs = BlackWhiteBundle {
+            black: TeamSignOff {
+                signed_by: Some(4321),
+                protest: false,
+            },
+            white: TeamSignOff {
+                signed_by: Some(7),
+                protest: true,
+            },
+        };
+        stats.set_sign_offs(sign_offs, now);
+        assert_eq!(stats.sign_offs(), Some(sign_offs));
+        assert!(stats.protest_lodged());
+
+        let events = events_of(&stats);
+        let confirmations: Vec<_> = events
+            .iter()
+            .filter(|e| e["$type"] == "resultConfirmation")
+            .collect();
+        assert_eq!(confirmations.len(), 2);
+        let white = confirmations.iter().find(|e| e["side"] == "light").unwrap();
+        assert_eq!(white["signedBy"], 7);
+        assert_eq!(white["protestLodged"], true);
+        let black = confirmations.iter().find(|e| e["side"] == "dark").unwrap();
+        assert_eq!(black["protestLodged"], false);
+        // The scoresheet shows them by the signatures, not as lines
+        assert_eq!(stats.sheet_lines().len(), 1);
+    }
 }
@@ -17,93 +17,22 @@
 //! * Fixed-step faithfulness: this driver is faithful as long as `update` recomputes state
 //!   purely from `start_time + elapsed` without accumulating per-call state. If the engine
 //!   ever accumulates per-call state, this driver could diverge from the real app.
+//!
+//! * Recorded sessions: the app applies every [`Action`] through [`TournamentManager::apply`],
+//!   which records it (see `super::session`) and runs it through the same dispatch as this
+//!   driver. `replay` turns a session into a trace that can be checked in next to the scenario
+//!   traces. It and the driver it runs on are all of this module that is built outside tests.
 
-use super::*;
+use super::{session::Session, *};
 use uwh_common::game_snapshot::{PenaltyTime, TimeoutSnapshot};
 
-pub(super) mod scenarios;
-
-// ─── Public types ─────────────────────────────────────────────────────────────
-
-/// Every action a scenario can inject at a given time offset.
-//
-// Several variants are unused by the smoke test but are part of the stable public API
-// of this driver module — Tasks 3+ will exercise them. Suppress the warning here rather
-// than leaving them as `todo!()` stubs.
-#[allow(dead_code)]
-#[derive(Clone, Copy)]
-pub(super) enum Action {
-    /// Set the active period and remaining clock time (test-only engine method).
-    SetupPeriod(GamePeriod, Duration),
-    /// Start the game clock (and any active timeout clock).
-    StartClock,
-    /// Manually start/advance play — the operator "Start" button.
-    ///
-    /// Mirrors `Message::StartPlayNow` → `tm.start_play_now(now)`. From
-    /// `BetweenGames` this begins a new game via `start_game` (which sets
-    /// `has_reset = false` → `is_old_game = true`); from `HalfTime` / `PreOvertime`
-    /// / `OvertimeHalfTime` / `PreSuddenDeath` it advances to the next play period.
-    ///
-    /// DISTINCT from `StartClock` (the bare `start_clock(now)` resume primitive):
-    /// `StartClock` never calls `start_game`, so scenarios built only from
-    /// `SetupPeriod` + `StartClock` leave `has_reset = true` and can never exercise
-    /// the between-games auto-reset.
-    StartPlayNow,
-    /// Manually reset the game (operator's end-game / "new game" reset).
-    ///
-    /// Mirrors `tm.reset_game(now)` as called from the `ConfirmationOption::EndGameAndApply`
-    /// path in `app/mod.rs` (apply edited settings mid-game → end the current game and reset).
-    /// `reset_game` moves to `BetweenGames`, stops the clock at `minimum_break`, clears the
-    /// timeout, and calls `reset()` — which zeroes the scores, clears penalties/warnings/fouls,
-    /// and sets `has_reset = true` (→ `is_old_game = false`, `old?=N`). If the clock was
-    /// running it is restarted (the break counts down).
-    ResetGame,
-    /// Stop the game clock.
-    StopClock,
-    /// Record a goal for the given colour (player number 0).
-    ///
-    /// Mirrors `Message::AddNewScore` in the non-SuddenDeath path: calls
-    /// `tm.add_score(color, 0, now)` directly.  Do NOT use this variant in
-    /// SuddenDeath — use [`Action::ScoreSuddenDeath`] + [`Action::ConfirmScore`] instead.
-    AddScore(Color),
-    /// Mirror the SuddenDeath score-entry in `app/mod.rs` (AddScoreComplete, SD branch):
-    ///   the held score is incremented locally, then `tm.pause_for_confirm(now)` is called.
-    ///   The engine score is NOT changed yet; the increment is conceptually held by the
-    ///   operator until `ConfirmScore` fires.
-    ///
-    /// Cross-reference: `app/mod.rs` ~line 2048–2053.
-    ScoreSuddenDeath(Color),
-    /// Mirror the operator confirming a SuddenDeath score (`Message::ScoreConfirmation { correct: true }`):
-    ///   recomputes the held score, calls `tm.set_scores(held, now)` then `tm.end_confirm_pause(now)`.
-    ///
-    /// Cross-reference: `app/mod.rs` ~line 2907–2911.
-    ConfirmScore(Color),
-    /// Operator confirms a game-ending score with NO pending goal to add — mirrors
-    /// `Message::ScoreConfirmation { correct: true }` (app/mod.rs ~3913) when the held
-    /// score is unchanged: `tm.set_scores(current, now)` then `tm.end_confirm_pause(now)`.
-    ///
-    /// Needed to finish a game-ending timeout's confirm pause, which the driver's tick loop
-    /// cannot auto-end because `end_game_ending_timeout` → `halt_clock` drops the start/stop
-    /// latch (so no tick fires to reach `pause_has_ended`). Distinct from [`Action::ConfirmScore`],
-    /// which *increments* the score for the sudden-death held-goal path.
-    ConfirmGameEnd,
-    /// Start a timed penalty for `(color, player_number, kind)`.
-    StartPenalty(Color, u8, PenaltyKind),
-    /// Start a team timeout for the given colour.
-    StartTeamTimeout(Color),
-    /// Start a referee timeout.
-    StartRefTimeout,
-    /// Start a (non-rugby) penalty shot.
-    StartPenaltyShot,
-    /// Start a rugby penalty shot.
-    StartRugbyPenaltyShot,
-    /// End the current timeout and resume play.
-    EndTimeout,
-    /// Manually set the game clock to the given duration (clock must be stopped).
-    SetGameClock(Duration),
-}
+#[cfg(test)]
+mod properties;
+#[cfg(test)]
+mod scenarios;
 
 /// A single replay scenario.
+#[cfg(test)]
 pub(super) struct Scenario {
     /// Human-readable identifier used in assertion messages.
     pub name: &'static str,
@@ -159,169 +88,13 @@ fn snapshot_with_retry(tm: &mut TournamentManager, now: Instant) -> GameSnapshot
 /// }
 /// ```
 /// Unlike the spike this function does NOT call or return `next_update_time`.
-fn tick(tm: &mut TournamentManager, now: Instant) {
-    if tm.could_end_game(now).unwrap() {
-        tm.pause_for_confirm(now).unwrap();
+fn tick(tm: &mut TournamentManager, now: Instant) -> Result<()> {
+    if tm.could_end_game(now)? {
+        tm.pause_for_confirm(now)
     } else if tm.pause_has_ended(now) {
-        tm.end_confirm_pause(now).unwrap();
+        tm.end_confirm_pause(now)
     } else {
-        tm.update(now).unwrap();
-    }
-}
-
-// ─── KNOWN COUPLING POINT ────────────────────────────────────────────────────
-//
-// `apply_action` is a hand-copy of the real action handlers in `app/mod.rs`.
-// If any handler in that file ever changes which `TournamentManager` methods it calls
-// (argument list, call order, extra follow-up calls, etc.), the corresponding arm below
-// MUST be updated in lockstep or the golden traces will silently stop reflecting the
-// real application.
-//
-// Cross-reference targets in `app/mod.rs` (as of master at the time this was written):
-//   StartClock           → models the bare start_clock(now) resume primitive (resume the
-//                          clock in the already-set-up period). NOT Message::StartPlayNow,
-//                          which calls the distinct start_play_now(now) to begin/advance a period.
-//   StartPlayNow         → Message::StartPlayNow (start_play_now(now)): begin a new
-//                          game from BetweenGames (→ start_game, has_reset=false) or
-//                          advance HalfTime/PreOvertime/etc. to the next play period.
-//   ResetGame            → tm.reset_game(now), as called from ConfirmationOption::EndGameAndApply
-//                          (app/mod.rs ~line 964): end the current game and reset → BetweenGames,
-//                          scores zeroed, penalties/warnings/fouls cleared, has_reset=true.
-//   StopClock            → Message::EditTime  (stop_clock + clock_is_running check)
-//   AddScore             → Message::AddNewScore  (add_score(color, 0, now); non-SD path)
-//   ScoreSuddenDeath     → Message::AddScoreComplete SD branch (~line 2048–2053):
-//                          hold score locally, pause_for_confirm(now)
-//   ConfirmScore         → Message::ScoreConfirmation { correct: true } (~line 2907–2911):
-//                          set_scores(held, now) + end_confirm_pause(now)
-//   ConfirmGameEnd       → Message::ScoreConfirmation { correct: true } with an UNCHANGED score
-//                          (~line 3913): set_scores(current) + end_confirm_pause(now). Finishes a
-//                          game-ending timeout's confirm pause (halt_clock stopped the latch, so
-//                          the tick loop cannot auto-confirm it).
-//   StartPenalty         → penalty_editor.rs add_to_tm → tm.start_penalty(...)
-//   StartTeamTimeout     → Message::TeamTimeout  (start_team_timeout)
-//   StartRefTimeout      → Message::RefTimeout   (start_ref_timeout)
-//   StartPenaltyShot     → Message::PenaltyShot  (start_penalty_shot, UWH mode)
-//   StartRugbyPenaltyShot→ Message::PenaltyShot  (start_rugby_penalty_shot, Rugby mode)
-//   EndTimeout           → Message::EndTimeout   (mirrors BOTH branches of the app handler:
-//                          timeout_end_would_end_game ? end_game_ending_timeout(now)  [R6, arms the
-//                          end-of-game confirm pause]  :  end_timeout(now) + update(now))
-//   SetGameClock         → Message::TimeEditComplete (set_game_clock_time). Models the
-//                          clock-stopped path only; the real handler additionally calls
-//                          start_clock(now)+update(now) when the clock was running. Scenarios
-//                          always StopClock before SetGameClock, so was_running == false here.
-//   SetupPeriod          → test-only; no real handler (uses pub(super) test method)
-//
-// CLOCK-LATCH COUPLING: the tick decision in `run()` reads the engine's start/stop watch
-// channel via `tm.get_start_stop_rx()`, exactly as the real `time_updater` loop in
-// `app/mod.rs` (~line 4132–4165) does.  `apply_action` does NOT maintain any separate
-// `clock_running` bool; the engine owns that state and broadcasts it via the latch.
-//
-// ─────────────────────────────────────────────────────────────────────────────
-
-fn apply_action(tm: &mut TournamentManager, action: Action, now: Instant) {
-    match action {
-        Action::SetupPeriod(period, clock_time) => {
-            tm.set_period_and_game_clock_time(period, clock_time);
-        }
-        Action::StartClock => {
-            tm.start_clock(now);
-        }
-        Action::StartPlayNow => {
-            // Mirrors Message::StartPlayNow → start_play_now(now).
-            // From BetweenGames: start_game → has_reset = false (is_old_game = true)
-            // and the clock starts running (send_clock_running(true)); the driver's
-            // latch tick then drives the game forward.
-            tm.start_play_now(now).unwrap();
-        }
-        Action::ResetGame => {
-            // Mirrors tm.reset_game(now) (app/mod.rs ConfirmationOption::EndGameAndApply):
-            // → BetweenGames, clock stopped at minimum_break (restarted if it was running),
-            // timeout cleared, reset() zeroes scores + clears penalties/warnings/fouls and
-            // sets has_reset = true (is_old_game = false).
-            tm.reset_game(now);
-        }
-        Action::StopClock => {
-            tm.stop_clock(now).unwrap();
-        }
-        Action::AddScore(color) => {
-            // Mirrors Message::AddNewScore without the collect_scorer_cap_num path.
-            // Non-SuddenDeath path only — use ScoreSuddenDeath + ConfirmScore for SD goals.
-            tm.add_score(color, 0, now);
-        }
-        Action::ScoreSuddenDeath(color) => {
-            // Mirrors app/mod.rs AddScoreComplete SD branch (~line 2048–2053):
-            // the new score is held locally (not yet sent to the engine); the engine
-            // is told to enter a confirmation pause so the operator can verify.
-            let mut s = tm.get_scores();
-            s[color] = s[color].saturating_add(1);
-            // NOTE: `s` is the held score — do NOT call tm.set_scores here.
-            // The held value is stored implicitly; ConfirmScore recomputes it.
-            let _ = s; // suppress unused-variable warning; ConfirmScore will recompute
-            tm.pause_for_confirm(now).unwrap();
-        }
-        Action::ConfirmScore(color) => {
-            // Mirrors app/mod.rs ScoreConfirmation { correct: true } (~line 2907–2911):
-            // recompute the held score (same increment as ScoreSuddenDeath), apply it
-            // to the engine, then end the confirmation pause.
-            let mut s = tm.get_scores();
-            s[color] = s[color].saturating_add(1);
-            tm.set_scores(s, now);
-            tm.end_confirm_pause(now).unwrap();
-        }
-        Action::ConfirmGameEnd => {
-            // Mirrors Message::ScoreConfirmation { correct: true } with an unchanged held
-            // score (app/mod.rs ~3913): the score already reflects the game-ending shot's
-            // outcome, so confirmation re-sets the same scores and ends the pause, finishing
-            // the game. (The tick loop cannot do this: end_game_ending_timeout's halt_clock
-            // stops the latch, so no tick fires to reach pause_has_ended.)
-            let s = tm.get_scores();
-            tm.set_scores(s, now);
-            tm.end_confirm_pause(now).unwrap();
-        }
-        Action::StartPenalty(color, player_number, kind) => {
-            // Mirrors penalty_editor.rs add_to_tm (Infraction::Unknown is the
-            // default when no specific infraction is tracked).
-            tm.start_penalty(color, player_number, kind, now, Infraction::Unknown)
-                .unwrap();
-        }
-        Action::StartTeamTimeout(color) => {
-            // Mirrors Message::TeamTimeout { switch: false }.
-            // Does NOT touch the latch — the engine's start_team_timeout does not
-            // call send_clock_running, so the latch remains true (tick loop keeps
-            // firing, driving the timeout countdown).
-            tm.start_team_timeout(color, now).unwrap();
-        }
-        Action::StartRefTimeout => {
-            // Mirrors Message::RefTimeout { switch: false }.
-            // Same latch note as StartTeamTimeout.
-            tm.start_ref_timeout(now).unwrap();
-        }
-        Action::StartPenaltyShot => {
-            // Mirrors Message::PenaltyShot { switch: false } in UWH mode.
-            // Same latch note as StartTeamTimeout.
-            tm.start_penalty_shot(now).unwrap();
-        }
-        Action::StartRugbyPenaltyShot => {
-            // Mirrors Message::PenaltyShot { switch: false } in Rugby mode.
-            // Same latch note as StartTeamTimeout.
-            tm.start_rugby_penalty_shot(now).unwrap();
-        }
-        Action::EndTimeout => {
-            // Mirrors Message::EndTimeout (app/mod.rs). A game-ending timeout (e.g. a
-            // game-ending rugby penalty shot at the end of the second half) arms the
-            // end-of-game confirm pause via end_game_ending_timeout so the confirm screen
-            // finishes the game cleanly (R6); otherwise the timeout ends and play resumes.
-            if tm.timeout_end_would_end_game(now).unwrap() {
-                tm.end_game_ending_timeout(now).unwrap();
-            } else {
-                tm.end_timeout(now).unwrap();
-                tm.update(now).unwrap();
-            }
-        }
-        Action::SetGameClock(duration) => {
-            // Mirrors Message::TimeEditComplete (clock must already be stopped)
-            tm.set_game_clock_time(duration).unwrap();
-        }
+        tm.update(now)
     }
 }
 
@@ -391,7 +164,70 @@ fn render(snap: &GameSnapshot) -> String {
     )
 }
 
+/// A recorded action that the engine refused when it was replayed, which means the replay
+/// has diverged from what happened in the session.
+#[derive(Debug, Error)]
+#[error("Replaying {action:?} {at:?} into the session failed: {source}")]
+pub struct ReplayError {
+    pub at: Duration,
+    pub action: Option<Action>,
+    pub source: TournamentManagerError,
+}
+
 /// Run a scenario through `TournamentManager` and return the deduplicated state trace.
+#[cfg(test)]
+pub(super) fn run(scenario: &Scenario) -> Vec<String> {
+    let actions = scenario
+        .actions
+        .iter()
+        .map(|(offset_secs, action)| (Duration::from_secs(*offset_secs), action.clone()))
+        .collect::<Vec<_>>();
+    let mut tm = TournamentManager::new(scenario.config.clone());
+    let base = Instant::now();
+    drive(
+        &mut tm,
+        base,
+        &actions,
+        Duration::from_secs(scenario.run_secs),
+    )
+    .unwrap_or_else(|e| panic!("scenario '{}': {e}", scenario.name))
+}
+
+/// Replay a recorded session and return its trace, running on for `run_on` after the last
+/// recorded action.
+pub fn replay(
+    session: &Session,
+    run_on: Duration,
+) -> std::result::Result<Vec<String>, ReplayError> {
+    replay_to_snapshot(session, run_on).map(|(trace, _)| trace)
+}
+
+/// [`replay`], also returning the snapshot the replay ends on
+fn replay_to_snapshot(
+    session: &Session,
+    run_on: Duration,
+) -> std::result::Result<(Vec<String>, GameSnapshot), ReplayError> {
+    let mut tm = TournamentManager::new(session.header.config.clone());
+    tm.set_rules(session.header.rules.clone())
+        .map_err(|source| ReplayError {
+            at: Duration::ZERO,
+            action: None,
+            source,
+        })?;
+    let base = Instant::now();
+    tm.wall_clock = Some(WallAnchor::new(base, session.header.started_at));
+
+    let actions = session
+        .entries
+        .iter()
+        .map(|entry| (Duration::from_millis(entry.at_ms), entry.action.clone()))
+        .collect::<Vec<_>>();
+    let end = actions.last().map_or(Duration::ZERO, |(at, _)| *at) + run_on;
+    let trace = drive(&mut tm, base, &actions, end)?;
+    Ok((trace, snapshot_with_retry(&mut tm, base + end)))
+}
+
+/// Drive `tm` through timed `actions` and return the deduplicated state trace.
 ///
 /// The trace contains one entry per observed state *change*; unchanged consecutive states
 /// are collapsed into the previous entry. There is no timestamp column.
@@ -403,7 +239,7 @@ fn render(snap: &GameSnapshot) -> String {
 /// ```
 ///
 /// The step is 100 ms (finding #1 from the spike). At each step:
-/// 1. Apply any `Scenario::actions` whose offset falls within `(prev_elapsed, elapsed]`.
+/// 1. Apply any `actions` whose offset falls within `(prev_elapsed, elapsed]`.
 ///    Record the new state immediately after each action if it changed.
 /// 2. Read the engine's start/stop latch (`*rx.borrow()`). If true, call `tick()` and
 ///    record if the state changed.
@@ -417,14 +253,17 @@ fn render(snap: &GameSnapshot) -> String {
 /// The latch is read fresh after every action (actions may flip it) and at every tick
 /// boundary. The engine is the sole authority on whether the tick loop fires — there is
 /// no separate hand-tracked bool in this driver.
-pub(super) fn run(scenario: &Scenario) -> Vec<String> {
+fn drive(
+    tm: &mut TournamentManager,
+    base: Instant,
+    actions: &[(Duration, Action)],
+    end: Duration,
+) -> std::result::Result<Vec<String>, ReplayError> {
     const STEP: Duration = Duration::from_millis(100);
     // NOTE: fixed-step is faithful only while `update` is idempotent w.r.t. call
     // frequency (it recomputes state from start_time+elapsed). If the engine ever
     // accumulates per-call state, this driver could diverge from the real app.
 
-    let mut tm = TournamentManager::new(scenario.config.clone());
-    let base = Instant::now();
     // Mirror the real time_updater: read the engine's start/stop watch channel.
     // The latch starts `false`; actions that call start_clock flip it to `true`.
     let rx = tm.get_start_stop_rx();
@@ -434,7 +273,7 @@ pub(super) fn run(scenario: &Scenario) -> Vec<String> {
     // Helper: push render(snapshot) onto trace iff it differs from the last entry.
     macro_rules! record {
         ($now:expr) => {{
-            let snap = snapshot_with_retry(&mut tm, $now);
+            let snap = snapshot_with_retry(tm, $now);
             let line = render(&snap);
             if last.as_deref() != Some(&line) {
                 trace.push(line.clone());
@@ -443,18 +282,25 @@ pub(super) fn run(scenario: &Scenario) -> Vec<String> {
         }};
     }
 
+    let apply = |tm: &mut TournamentManager, at: Duration, action: &Action| {
+        tm.apply(action.clone(), base + at)
+            .map_err(|source| ReplayError {
+                at,
+                action: Some(action.clone()),
+                source,
+            })
+    };
+
     // Apply setup actions: any action at offset 0 is treated as a setup step.
     // These run before the main loop, at virtual time t=base.
     let mut action_index = 0;
-    while action_index < scenario.actions.len() && scenario.actions[action_index].0 == 0 {
-        let (_, action) = scenario.actions[action_index];
-        apply_action(&mut tm, action, base);
+    while action_index < actions.len() && actions[action_index].0.is_zero() {
+        apply(tm, Duration::ZERO, &actions[action_index].1)?;
         action_index += 1;
     }
     record!(base);
 
-    // Main loop: advance virtual time from STEP to run_secs inclusive.
-    let end = Duration::from_secs(scenario.run_secs);
+    // Main loop: advance virtual time from STEP to `end` inclusive.
     let mut elapsed = Duration::ZERO;
 
     while elapsed < end {
@@ -462,28 +308,30 @@ pub(super) fn run(scenario: &Scenario) -> Vec<String> {
         let now = base + elapsed;
 
         // Apply all actions whose offset falls within the current step window.
-        while action_index < scenario.actions.len() {
-            let (offset_secs, action) = scenario.actions[action_index];
-            let action_at = Duration::from_secs(offset_secs);
+        while action_index < actions.len() {
+            let (action_at, ref action) = actions[action_index];
             if action_at > elapsed {
                 break;
             }
             // Actions at exactly their offset instant, not at `now`.
-            let action_now = base + action_at;
-            apply_action(&mut tm, action, action_now);
-            record!(action_now);
+            apply(tm, action_at, action)?;
+            record!(base + action_at);
             action_index += 1;
         }
 
         // Tick the engine at the step boundary if the engine's latch says running.
         // Read the latch AFTER applying due actions (an action may have flipped it).
         if *rx.borrow() {
-            tick(&mut tm, now);
+            tick(tm, now).map_err(|source| ReplayError {
+                at: elapsed,
+                action: None,
+                source,
+            })?;
             record!(now);
         }
     }
 
-    trace
+    Ok(trace)
 }
 
 // ─── Golden file harness ──────────────────────────────────────────────────────
@@ -616,6 +464,127 @@ mod tests {
         );
     }
 
+    /// Regression guard for recorded sessions: replays every session file checked in to
+    /// `golden_sessions/` and compares its trace against the golden file `session_<name>`.
+    ///
+    /// To turn a session recorded with `--record-session` into a regression test, copy it to
+    /// `golden_sessions/<name>.jsonl` and bless it:
+    ///   `UPDATE_GOLDEN=1 cargo test -p refbox recorded_sessions_match_baseline`
+    #[test]
+    fn recorded_sessions_match_baseline() {
+        let bless = std::env::var("UPDATE_GOLDEN").is_ok();
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/tournament_manager/golden_sessions");
+        let mut paths = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert!(!paths.is_empty(), "no session files in golden_sessions/");
+
+        let mut failures = Vec::new();
+        for path in paths {
+            let name = format!("session_{}", path.file_stem().unwrap().to_string_lossy());
+            let session = super::session::load(&path).unwrap();
+            let a = replay(&session, Duration::ZERO).unwrap_or_else(|e| panic!("{name}: {e}"));
+            let b = replay(&session, Duration::ZERO).unwrap_or_else(|e| panic!("{name}: {e}"));
+            assert_eq!(a, b, "session '{name}' is non-deterministic");
+            if let Err(diff) = check_or_bless(&name, &a, bless) {
+                failures.push(format!("--- {name} ---\n{diff}"));
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "recorded session mismatches:\n\n{}",
+            failures.join("\n\n")
+        );
+    }
+
+    /// A game played through `TournamentManager::apply` while recording replays from its
+    /// session file to the same final snapshot.
+    #[test]
+    fn a_recorded_game_replays_to_the_same_final_snapshot() {
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(20),
+            half_time_duration: Duration::from_secs(8),
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            post_game_duration: Duration::from_secs(5),
+            team_timeout_duration: Duration::from_secs(5),
+            ..Default::default()
+        };
+        let rules = RuleProfile::default();
+        let dir = tempfile::tempdir().unwrap();
+        let base = Instant::now();
+        let mut live = TournamentManager::new(config.clone());
+        live.set_rules(rules.clone()).unwrap();
+        live.start_recording(
+            super::session::SessionRecorder::create(dir.path(), &config, &rules, base).unwrap(),
+        );
+
+        let ms = Duration::from_millis;
+        let actions = [
+            (ms(0), Action::StartClock),
+            (ms(1_000), Action::StartPlayNow),
+            (ms(3_450), Action::AddScore(Color::Black, 4)),
+            (
+                ms(5_200),
+                Action::StartPenalty(
+                    Color::White,
+                    7,
+                    PenaltyKind::ONE_MINUTE,
+                    Infraction::OBSTRUCTION,
+                ),
+            ),
+            (ms(8_000), Action::StartTeamTimeout(Color::White)),
+            (ms(10_730), Action::EndTimeout),
+            (
+                ms(12_300),
+                Action::AddFoul(Some(Color::Black), Some(2), Infraction::DELAY_OF_GAME),
+            ),
+            (
+                ms(14_000),
+                Action::AddWarning(Color::White, Some(3), Infraction::OBSTRUCTION),
+            ),
+            (ms(15_500), Action::Undo),
+            (ms(33_000), Action::AddScore(Color::White, 9)),
+        ];
+        let run_on = Duration::from_secs(30);
+        let end = actions.last().unwrap().0 + run_on;
+        let live_trace = drive(&mut live, base, &actions, end).unwrap();
+        let live_snapshot = snapshot_with_retry(&mut live, base + end);
+
+        let mut files = std::fs::read_dir(dir.path()).unwrap();
+        let path = files.next().unwrap().unwrap().path();
+        let session = super::session::load(&path).unwrap();
+        assert_eq!(session.entries.len(), actions.len());
+
+        let (trace, snapshot) = replay_to_snapshot(&session, run_on).unwrap();
+        assert_eq!(trace, live_trace);
+        assert_eq!(snapshot, live_snapshot);
+    }
+
+    /// A replay that diverges from the session names the action the engine refused.
+    #[test]
+    fn a_diverging_replay_names_the_refused_action() {
+        let session = Session {
+            header: super::session::SessionHeader {
+                version: super::session::SessionHeader::CURRENT_VERSION,
+                started_at: OffsetDateTime::UNIX_EPOCH,
+                config: GameConfig::default(),
+                rules: RuleProfile::default(),
+            },
+            entries: vec![super::session::SessionEntry {
+                at_ms: 1500,
+                action: Action::EndTimeout,
+            }],
+        };
+        let err = replay(&session, Duration::ZERO).unwrap_err();
+        assert_eq!(err.at, Duration::from_millis(1500));
+        assert_eq!(err.action, Some(Action::EndTimeout));
+    }
+
     /// Compile-time completeness guard for `render`.
     ///
     /// This exhaustive destructure has no `..`, so adding a field to `GameSnapshot`
@@ -637,13 +606,17 @@ mod tests {
             is_old_game: _,
             conf_pause_time: _,
             // ── Intentionally NOT rendered (see design doc §4) ──
-            warnings: _,             // inert Vec-append; not core time/state logic
-            fouls: _,                // inert Vec-append; not core time/state logic
-            game_number: _,          // not core timing/state
-            next_game_number: _,     // not core timing/state
-            event_id: _,             // hardcoded None in the snapshot constructor
-            recent_goal: _,          // display sugar; verified deterministic, no outcome effect
-            next_period_len_secs: _, // not core timing/state
+            warnings: _,               // inert Vec-append; not core time/state logic
+            fouls: _,                  // inert Vec-append; not core time/state logic
+            game_number: _,            // not core timing/state
+            next_game_number: _,       // not core timing/state
+            event_id: _,               // hardcoded None in the snapshot constructor
+            team_names: _,             // filled in by the app, empty in the snapshot constructor
+            recent_goal: _,            // display sugar; verified deterministic, no outcome effect
+            goals: _,                  // the score is rendered; the goal list is stats, not state
+            recent_disallowed_goal: _, // display sugar, like `recent_goal`
+            next_period_len_secs: _,   // not core timing/state
+            shootout: _,               // None outside PenaltyShootout, which no scenario reaches
         } = snap;
     }
 
@@ -0,0 +1,349 @@
+//! Randomized operator sessions checked against the engine's invariants ("property tests").
+//!
+//! proptest builds a game config and a sequence of operator [`Action`]s at random times, and
+//! [`check`] drives them through `TournamentManager` on the same fixed step as `drive`,
+//! checking after every step that:
+//!
+//! * a tick never fails, and the game clock and any running timeout clock can always be read,
+//!   i.e. they never go negative;
+//! * `generate_snapshot` is `Some` after every tick, without the retry the app falls back on;
+//! * penalties never gain time, except through an action that edits them;
+//! * periods only move forward, apart from a game ending;
+//! * `could_end_game` only says the game can end once the last period's clock has run out,
+//!   and a game only ends through the confirmation pause it asks for.
+//!
+//! Actions the engine refuses are skipped, as the operator can press buttons that don't apply
+//! to the current state. Like the app, a goal in sudden death goes through the confirmation
+//! pause, and nothing but the confirmation is offered while the pause is on. A failing case is
+//! shrunk to a minimal config and list of `(ms since the previous action, Action)` pairs, which
+//! translates directly into a scenario.
+//! proptest keeps the seeds of past failures in `proptest-regressions/` and retries them first.
+
+use super::*;
+use crate::tournament_manager::action::apply_action;
+use proptest::prelude::*;
+use uwh_common::game_snapshot::PenaltySnapshot;
+
+const STEP: Duration = Duration::from_millis(100);
+
+/// How long the engine keeps running after the last action, long enough for any generated game
+/// to finish on its own
+const RUN_ON: Duration = Duration::from_secs(300);
+
+fn color() -> impl Strategy<Value = Color> {
+    prop_oneof![Just(Color::Black), Just(Color::White)]
+}
+
+fn penalty_kind() -> impl Strategy<Value = PenaltyKind> {
+    prop_oneof![
+        Just(PenaltyKind::THIRTY_SECOND),
+        Just(PenaltyKind::ONE_MINUTE),
+        Just(PenaltyKind::TWO_MINUTE),
+        Just(PenaltyKind::TotalDismissal),
+    ]
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        4 => Just(Action::StartPlayNow),
+        2 => Just(Action::StopClock),
+        2 => Just(Action::StartClock),
+        4 => (color(), 1..10u8).prop_map(|(color, player)| Action::AddScore(color, player)),
+        1 => (color(), 0..3usize).prop_map(|(color, goal)| {
+            Action::DisallowGoal(color, goal, GoalDisallowReason::Foul)
+        }),
+        3 => (color(), 1..10u8, penalty_kind()).prop_map(|(color, player, kind)| {
+            Action::StartPenalty(color, player, kind, Infraction::UNKNOWN)
+        }),
+        1 => (color(), 0..3usize).prop_map(|(color, index)| Action::DeletePenalty(color, index)),
+        2 => color().prop_map(Action::StartTeamTimeout),
+        1 => Just(Action::StartRefTimeout),
+        1 => Just(Action::StartPenaltyShot),
+        1 => Just(Action::StartRugbyPenaltyShot),
+        3 => Just(Action::EndTimeout),
+        1 => color().prop_map(Action::SwitchToTeamTimeout),
+        1 => Just(Action::SwitchToRefTimeout),
+        1 => Just(Action::SwitchToPenaltyShot),
+        1 => Just(Action::CancelTeamTimeout),
+        1 => (0..90u64).prop_map(|secs| Action::SetGameClock(Duration::from_secs(secs))),
+        2 => Just(Action::Undo),
+        1 => Just(Action::Redo),
+        1 => color().prop_map(Action::ConfirmScore),
+        1 => Just(Action::ConfirmGameEnd),
+        1 => Just(Action::StartShootout),
+        1 => (color(), 1..10u8, any::<bool>()).prop_map(|(color, player, scored)| {
+            Action::AddShootoutAttempt(color, player, scored)
+        }),
+        1 => Just(Action::RemoveLastShootoutAttempt),
+        1 => Just(Action::ResetGame),
+    ]
+}
+
+/// A config with short periods, so a generated session gets through whole games
+fn config() -> impl Strategy<Value = GameConfig> {
+    let toggles = (
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+    );
+    let lengths = (
+        20..60u64,
+        5..15u64,
+        5..15u64,
+        5..15u64,
+        10..30u64,
+        5..10u64,
+        5..10u64,
+        10..30u64,
+        5..10u64,
+    );
+    (toggles, lengths, 0..3u16).prop_map(
+        |(
+            (overtime, sudden_death, shootout, single_half, per_half, stop_time),
+            (half, half_time, timeout, shot, ot_half, ot_half_time, pre_ot, nominal, minimum),
+            timeouts,
+        )| GameConfig {
+            num_team_timeouts_allowed: timeouts,
+            timeouts_counted_per_half: per_half,
+            overtime_allowed: overtime,
+            sudden_death_allowed: sudden_death,
+            penalty_shootout_allowed: shootout,
+            penalty_shootout_rounds: 2,
+            stop_time_allowed: stop_time,
+            single_half,
+            half_play_duration: Duration::from_secs(half),
+            half_time_duration: Duration::from_secs(half_time),
+            team_timeout_duration: Duration::from_secs(timeout),
+            penalty_shot_duration: Duration::from_secs(shot),
+            stop_time_window: Duration::from_secs(half / 4),
+            ot_half_play_duration: Duration::from_secs(ot_half),
+            ot_half_time_duration: Duration::from_secs(ot_half_time),
+            pre_overtime_break: Duration::from_secs(pre_ot),
+            pre_sudden_death_duration: Duration::from_secs(pre_ot),
+            post_game_duration: Duration::from_secs(minimum),
+            nominal_break: Duration::from_secs(nominal),
+            minimum_break: Duration::from_secs(minimum),
+            game_block: Duration::ZERO,
+        },
+    )
+}
+
+/// What the invariants compare from one step to the next
+#[derive(Debug)]
+struct Observed {
+    period: GamePeriod,
+    /// The whole seconds shown on the game clock
+    secs: u32,
+    penalties: BlackWhiteBundle<Vec<PenaltySnapshot>>,
+}
+
+impl Observed {
+    fn new(snapshot: GameSnapshot) -> Self {
+        Self {
+            period: snapshot.current_period,
+            secs: snapshot.secs_in_period,
+            penalties: snapshot.penalties,
+        }
+    }
+
+    /// Whether every penalty still in the lists has at most the time it had `before`
+    fn no_penalty_gained_time(&self, before: &Self) -> std::result::Result<(), TestCaseError> {
+        for (color, list) in self.penalties.iter() {
+            let old = &before.penalties[color];
+            if list.len() != old.len()
+                || list
+                    .iter()
+                    .zip(old)
+                    .any(|(new, old)| new.player_number != old.player_number)
+            {
+                // A penalty was added, removed or culled, so the lists can't be compared
+                continue;
+            }
+            for (new, old) in list.iter().zip(old) {
+                if let (PenaltyTime::Seconds(new_secs), PenaltyTime::Seconds(old_secs)) =
+                    (new.time, old.time)
+                {
+                    prop_assert!(
+                        new_secs <= old_secs,
+                        "{color} #{}'s penalty went from {old_secs}s to {new_secs}s",
+                        new.player_number
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn observe(tm: &mut TournamentManager, now: Instant) -> Observed {
+    Observed::new(snapshot_with_retry(tm, now))
+}
+
+/// Actions that may legitimately give a penalty back time
+fn edits_penalty_time(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::SetGameClock(_) | Action::Undo | Action::Redo | Action::ResetGame
+    )
+}
+
+/// Runs `actions` through a fresh engine and checks the invariants after every step
+fn check(config: &GameConfig, actions: &[(u64, Action)]) -> std::result::Result<(), TestCaseError> {
+    let mut tm = TournamentManager::new(config.clone());
+    let rx = tm.get_start_stop_rx();
+    let base = Instant::now();
+
+    let mut at = Duration::ZERO;
+    let timed = actions
+        .iter()
+        .map(|(delay_ms, action)| {
+            at += Duration::from_millis(*delay_ms);
+            (at, action)
+        })
+        .collect::<Vec<_>>();
+    let end = at + RUN_ON;
+
+    let mut last = observe(&mut tm, base);
+    let mut next_action = timed.iter().peekable();
+    let mut elapsed = Duration::ZERO;
+
+    while elapsed < end {
+        elapsed = (elapsed + STEP).min(end);
+        let now = base + elapsed;
+
+        while let Some((action_at, action)) = next_action.next_if(|(at, _)| *at <= elapsed) {
+            let action_now = base + *action_at;
+            // As in the app, a goal in sudden death waits for the operator to confirm it
+            let action = match action {
+                Action::AddScore(color, _) if tm.current_period() == GamePeriod::SuddenDeath => {
+                    &Action::ScoreSuddenDeath(*color)
+                }
+                action => *action,
+            };
+            // The app shows only the score confirmation while the pause is on
+            if tm.time_pause_confirmation.is_some()
+                && !matches!(action, Action::ConfirmScore(_) | Action::ConfirmGameEnd)
+            {
+                continue;
+            }
+            // A refused action is fine; whatever state it leaves is checked like any other
+            let _ = apply_action(&mut tm, action.clone(), action_now);
+            let observed = observe(&mut tm, action_now);
+            if !matches!(action, Action::ResetGame) && observed.period != GamePeriod::BetweenGames {
+                prop_assert!(
+                    observed.period >= last.period,
+                    "{action:?} moved the game back from {:?} to {:?}",
+                    last.period,
+                    observed.period
+                );
+            }
+            if !edits_penalty_time(action) {
+                observed.no_penalty_gained_time(&last)?;
+            }
+            last = observed;
+        }
+
+        if !*rx.borrow() {
+            continue;
+        }
+
+        let could_end = tm.could_end_game(now);
+        prop_assert!(could_end.is_ok(), "could_end_game failed: {could_end:?}");
+        let could_end = could_end.unwrap();
+        if could_end {
+            let final_period = match tm.current_period() {
+                GamePeriod::FirstHalf => config.single_half,
+                GamePeriod::SecondHalf
+                | GamePeriod::OvertimeSecondHalf
+                | GamePeriod::SuddenDeath => true,
+                _ => false,
+            };
+            prop_assert!(
+                final_period,
+                "could_end_game is true in {:?}",
+                tm.current_period()
+            );
+            // A step can overshoot zero, where the clock reads `None` until the pause stops it.
+            // With a timeout that just ended, the clock only runs out once the timeout is over.
+            if tm.current_period() != GamePeriod::SuddenDeath && tm.timeout_state.is_none() {
+                let clock = tm.game_clock_time(now);
+                prop_assert!(
+                    clock.is_none_or(|clock| clock.is_zero()),
+                    "could_end_game is true with {clock:?} on the clock"
+                );
+            }
+        }
+        let confirming = tm.pause_has_ended(now);
+
+        let ticked = tick(&mut tm, now);
+        prop_assert!(ticked.is_ok(), "tick failed: {ticked:?}");
+
+        prop_assert!(
+            tm.game_clock_time(now).is_some(),
+            "the game clock went negative"
+        );
+        let snapshot = tm.generate_snapshot(now);
+        prop_assert!(snapshot.is_some(), "no snapshot after a tick");
+        let snapshot = snapshot.unwrap();
+        if snapshot.timeout.is_some() {
+            prop_assert!(
+                tm.timeout_clock_time(now).is_some(),
+                "the timeout clock went negative"
+            );
+        }
+        let observed = Observed::new(snapshot);
+
+        if observed.period != GamePeriod::BetweenGames {
+            prop_assert!(
+                observed.period >= last.period,
+                "the clock moved the game back from {:?} to {:?}",
+                last.period,
+                observed.period
+            );
+        } else if last.period != GamePeriod::BetweenGames {
+            prop_assert!(
+                confirming,
+                "the game ended from {:?} without a confirmation pause",
+                last.period
+            );
+        }
+        if observed.period == last.period
+            && last.period != GamePeriod::SuddenDeath
+            && tm.timeout_clock_time(now).is_none()
+        {
+            prop_assert!(
+                observed.secs <= last.secs,
+                "the {:?} clock went up from {}s to {}s",
+                observed.period,
+                last.secs,
+                observed.secs
+            );
+        }
+        // The pause parks the clock at 10 ms, which can be a few ms back from where it was
+        if !could_end || tm.current_period() == GamePeriod::SuddenDeath {
+            observed.no_penalty_gained_time(&last)?;
+        }
+        last = observed;
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        // Each case runs a whole session in 100 ms steps, so fewer cases than the default
+        cases: 64,
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn random_sessions_keep_the_engine_consistent(
+        config in config(),
+        actions in prop::collection::vec((0..20_000u64, action()), 0..40),
+    ) {
+        check(&config, &actions)?;
+    }
+}
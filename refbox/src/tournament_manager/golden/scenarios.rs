@@ -13,13 +13,17 @@
 
 use super::{Action, Scenario};
 use std::time::Duration;
-use uwh_common::{color::Color, config::Game as GameConfig, game_snapshot::GamePeriod};
+use uwh_common::{
+    color::Color,
+    config::Game as GameConfig,
+    game_snapshot::{GamePeriod, Infraction},
+};
 
 use crate::tournament_manager::{
-    golden::Action::{
-        AddScore, ConfirmGameEnd, ConfirmScore, EndTimeout, ResetGame, ScoreSuddenDeath,
+    action::Action::{
+        AddScore, ConfirmGameEnd, ConfirmScore, EndTimeout, Redo, ResetGame, ScoreSuddenDeath,
         SetGameClock, SetupPeriod, StartClock, StartPenalty, StartPenaltyShot, StartPlayNow,
-        StartRefTimeout, StartRugbyPenaltyShot, StartTeamTimeout, StopClock,
+        StartRefTimeout, StartRugbyPenaltyShot, StartTeamTimeout, StopClock, Undo,
     },
     penalty::PenaltyKind,
 };
@@ -86,8 +90,8 @@ static REGULATION_WITH_SCORES_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(20)),
     ),
     (0, StartClock),
-    (5, AddScore(Color::Black)),
-    (12, AddScore(Color::White)),
+    (5, AddScore(Color::Black, 0)),
+    (12, AddScore(Color::White, 0)),
 ];
 
 // ── Family 2 — Penalties over time ───────────────────────────────────────────
@@ -100,7 +104,15 @@ static PENALTY_ONE_MINUTE_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(80)),
     ),
     (0, StartClock),
-    (5, StartPenalty(Color::Black, 3, PenaltyKind::OneMinute)),
+    (
+        5,
+        StartPenalty(
+            Color::Black,
+            3,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
 ];
 
 // 4. penalty_crosses_break — penalty started late in FirstHalf, frozen across
@@ -113,7 +125,15 @@ static PENALTY_CROSSES_BREAK_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(20)),
     ),
     (0, StartClock),
-    (5, StartPenalty(Color::White, 9, PenaltyKind::OneMinute)),
+    (
+        5,
+        StartPenalty(
+            Color::White,
+            9,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
 ];
 
 // 5. penalty_concurrent — two simultaneous penalties: OneMinute (Black) and
@@ -124,8 +144,24 @@ static PENALTY_CONCURRENT_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(90)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::Black, 1, PenaltyKind::OneMinute)),
-    (2, StartPenalty(Color::White, 2, PenaltyKind::TwoMinute)),
+    (
+        2,
+        StartPenalty(
+            Color::Black,
+            1,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
+    (
+        2,
+        StartPenalty(
+            Color::White,
+            2,
+            PenaltyKind::TWO_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
 ];
 
 // 6. penalty_during_stoppage — spike scenario: FirstHalf 40 s, B#7 ThirtySecond
@@ -136,7 +172,15 @@ static PENALTY_DURING_STOPPAGE_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(40)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::Black, 7, PenaltyKind::ThirtySecond)),
+    (
+        2,
+        StartPenalty(
+            Color::Black,
+            7,
+            PenaltyKind::THIRTY_SECOND,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (15, StopClock),
     (18, StartClock),
 ];
@@ -150,7 +194,12 @@ static PENALTY_TOTAL_DISMISSAL_ACTIONS: &[(u64, Action)] = &[
     (0, StartClock),
     (
         3,
-        StartPenalty(Color::White, 5, PenaltyKind::TotalDismissal),
+        StartPenalty(
+            Color::White,
+            5,
+            PenaltyKind::TotalDismissal,
+            Infraction::UNKNOWN,
+        ),
     ),
 ];
 
@@ -163,7 +212,15 @@ static PENALTY_EXPIRES_AT_BOUNDARY_ACTIONS: &[(u64, Action)] = &[
         0,
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(30)),
     ),
-    (0, StartPenalty(Color::Black, 4, PenaltyKind::ThirtySecond)),
+    (
+        0,
+        StartPenalty(
+            Color::Black,
+            4,
+            PenaltyKind::THIRTY_SECOND,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (0, StartClock),
 ];
 
@@ -234,7 +291,15 @@ static TIMEOUT_FREEZES_PENALTY_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(80)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::Black, 8, PenaltyKind::OneMinute)),
+    (
+        2,
+        StartPenalty(
+            Color::Black,
+            8,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (10, StartTeamTimeout(Color::White)),
     (25, EndTimeout),
 ];
@@ -286,7 +351,7 @@ static OVERTIME_SCORE_ENDS_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::OvertimeSecondHalf, Duration::from_secs(20)),
     ),
     (0, StartClock),
-    (8, AddScore(Color::White)),
+    (8, AddScore(Color::White, 0)),
 ];
 
 // ── Special sub-cases ─────────────────────────────────────────────────────────
@@ -314,7 +379,7 @@ static SINGLE_HALF_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(20)),
     ),
     (0, StartClock),
-    (5, AddScore(Color::Black)),
+    (5, AddScore(Color::Black, 0)),
 ];
 
 // 20. manual_clock_edit — StopClock, SetGameClock(new value), StartClock;
@@ -346,7 +411,15 @@ static PENALTY_STARTED_DURING_TIMEOUT_ACTIONS: &[(u64, Action)] = &[
     ),
     (0, StartClock),
     (5, StartTeamTimeout(Color::Black)),
-    (8, StartPenalty(Color::White, 2, PenaltyKind::ThirtySecond)),
+    (
+        8,
+        StartPenalty(
+            Color::White,
+            2,
+            PenaltyKind::THIRTY_SECOND,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (20, EndTimeout),
 ];
 
@@ -360,7 +433,15 @@ static PENALTY_DURING_CONFIRM_PAUSE_ACTIONS: &[(u64, Action)] = &[
         0,
         SetupPeriod(GamePeriod::SecondHalf, Duration::from_secs(5)),
     ),
-    (0, StartPenalty(Color::Black, 1, PenaltyKind::TwoMinute)),
+    (
+        0,
+        StartPenalty(
+            Color::Black,
+            1,
+            PenaltyKind::TWO_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (0, StartClock),
 ];
 
@@ -377,7 +458,15 @@ static MANUAL_CLOCK_EDIT_WHILE_PENALTY_RUNNING_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(60)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::Black, 6, PenaltyKind::ThirtySecond)),
+    (
+        2,
+        StartPenalty(
+            Color::Black,
+            6,
+            PenaltyKind::THIRTY_SECOND,
+            Infraction::UNKNOWN,
+        ),
+    ),
     (10, StopClock),
     (10, SetGameClock(Duration::from_secs(50))),
     (15, StartClock),
@@ -433,7 +522,15 @@ static OVERTIME_WITH_ACTIVE_PENALTY_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::SecondHalf, Duration::from_secs(5)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::White, 3, PenaltyKind::OneMinute)),
+    (
+        2,
+        StartPenalty(
+            Color::White,
+            3,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
 ];
 
 // 27. sudden_death_with_timeout — reach SuddenDeath (0-0 through OT),
@@ -528,9 +625,17 @@ static MANUAL_CLOCK_EDIT_REWINDS_PENALTY_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(20)),
     ),
     (0, StartClock),
-    (2, StartPenalty(Color::Black, 7, PenaltyKind::OneMinute)), // starts at clock=18s
-    (5, StopClock),                                             // clock=15s
-    (5, SetGameClock(Duration::from_secs(19))),                 // rewind to before penalty start
+    (
+        2,
+        StartPenalty(
+            Color::Black,
+            7,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ), // starts at clock=18s
+    (5, StopClock),                             // clock=15s
+    (5, SetGameClock(Duration::from_secs(19))), // rewind to before penalty start
     (6, StartClock),
 ];
 
@@ -578,7 +683,7 @@ static SINGLE_HALF_DECIDED_ACTIONS: &[(u64, Action)] = &[
         SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(10)),
     ),
     (0, StartClock),
-    (3, AddScore(Color::Black)), // 1-0: decided, so the single half ends the game
+    (3, AddScore(Color::Black, 0)), // 1-0: decided, so the single half ends the game
 ];
 
 // Single-half game that ends in a DRAW with no overtime/sudden-death configured:
@@ -627,7 +732,7 @@ static BETWEEN_GAMES_AUTO_RESET_ACTIONS: &[(u64, Action)] = &[(0, StartPlayNow)]
 // (short two-half, no OT/SD) so the reset lands mid-FirstHalf and the trace is compact.
 static MANUAL_RESET_GAME_ACTIONS: &[(u64, Action)] = &[
     (0, StartPlayNow),
-    (1, AddScore(Color::Black)),
+    (1, AddScore(Color::Black, 0)),
     (2, ResetGame),
 ];
 
@@ -698,11 +803,75 @@ static GAME_ENDING_RUGBY_PENALTY_SHOT_ACTIONS: &[(u64, Action)] = &[
     ),
     (0, StartClock),
     (1, StartRugbyPenaltyShot), // 15s shot; game clock hits 0 at ~t=5 → half extended
-    (6, AddScore(Color::Black)), // goal scored on the shot → B1/W0 (decisive)
+    (6, AddScore(Color::Black, 0)), // goal scored on the shot → B1/W0 (decisive)
     (8, EndTimeout), // would-end-game → end_game_ending_timeout arms the confirm pause (R6)
     (10, ConfirmGameEnd), // confirm the score → game ends → BetweenGames
 ];
 
+// ── Family 12 — undo/redo ─────────────────────────────────────────────────────
+
+// undo_redo — an undone clock stop resumes as if never stopped; an undone team
+//   timeout is refunded and the game clock carries on from where the timeout
+//   began; undo takes the penalty and then the goal off the board, newest
+//   first, and redo brings the goal back. Half = 40 s.
+static UNDO_REDO_ACTIONS: &[(u64, Action)] = &[
+    (
+        0,
+        SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(40)),
+    ),
+    (0, StartClock),
+    (3, StopClock),
+    (6, Undo),
+    (8, StartTeamTimeout(Color::Black)),
+    (10, Undo),
+    (12, AddScore(Color::White, 0)),
+    (
+        13,
+        StartPenalty(
+            Color::Black,
+            4,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
+        ),
+    ),
+    (15, Undo),
+    (16, Undo),
+    (18, Redo),
+];
+
+// ── Family 13 — stop time ─────────────────────────────────────────────────────
+
+// Both stop-time scenarios share these actions. A goal well before the window,
+// then a goal and a penalty inside the last 8 s of the first half, each
+// followed by the restart the operator is prompted for. Half = 20 s.
+//
+// stop_time_off — the setting is off, so the whistles leave the clock running,
+//   the restarts are no-ops, and the game plays out exactly like a plain
+//   running-clock game.
+// stop_time_on — each whistle in the window stops the clock (the goal at 6 s
+//   left and the penalty at 4 s left) until the restart, pushing the end of the
+//   first half out by 5 s. The penalty's 30 s is frozen along with the clock.
+static STOP_TIME_ACTIONS: &[(u64, Action)] = &[
+    (
+        0,
+        SetupPeriod(GamePeriod::FirstHalf, Duration::from_secs(20)),
+    ),
+    (0, StartClock),
+    (3, AddScore(Color::Black, 0)),
+    (14, AddScore(Color::White, 0)),
+    (16, StartClock),
+    (
+        18,
+        StartPenalty(
+            Color::Black,
+            4,
+            PenaltyKind::THIRTY_SECOND,
+            Infraction::UNKNOWN,
+        ),
+    ),
+    (21, StartClock),
+];
+
 // ── Public entry point ────────────────────────────────────────────────────────
 
 /// Return every scenario in the library.
@@ -1114,5 +1283,33 @@ pub(super) fn all() -> Vec<Scenario> {
             actions: GAME_ENDING_RUGBY_PENALTY_SHOT_ACTIONS,
             run_secs: 20,
         },
+        // ── Family 12 — undo/redo ────────────────────────────────────────────
+        Scenario {
+            name: "undo_redo",
+            config: reg_config(),
+            actions: UNDO_REDO_ACTIONS,
+            run_secs: 25,
+        },
+        // ── Family 13 — stop time ────────────────────────────────────────────
+        Scenario {
+            name: "stop_time_off",
+            config: GameConfig {
+                stop_time_allowed: false,
+                stop_time_window: Duration::from_secs(8),
+                ..reg_config()
+            },
+            actions: STOP_TIME_ACTIONS,
+            run_secs: 60,
+        },
+        Scenario {
+            name: "stop_time_on",
+            config: GameConfig {
+                stop_time_allowed: true,
+                stop_time_window: Duration::from_secs(8),
+                ..reg_config()
+            },
+            actions: STOP_TIME_ACTIONS,
+            run_secs: 60,
+        },
     ]
 }
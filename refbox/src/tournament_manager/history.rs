@@ -0,0 +1,918 @@
+//! Undo/redo log of operator actions.
+//!
+//! Every goal, disallowed goal, penalty, foul, team timeout and clock start/stop is recorded as
+//! an [`Action`] holding what it changed. Undo reverses the newest action and
+//! moves it to the redo stack; redo re-applies it; any new action clears the
+//! redo stack.
+//!
+//! Clock actions record the clock and timeout state before and after. A running
+//! `ClockState` is anchored at the instant it started, so restoring the
+//! "before" state of a clock stop resumes the clock as if it had never stopped,
+//! and the time that passed in between is not lost. An action is only reversed
+//! if the state it changed is still exactly as it left it; otherwise (an
+//! editor changed it, a timeout expired) the history is dropped rather than
+//! guessing. The same goes for a game or timeout clock that would already have
+//! run out by the time it's restored, since it should have ended in the meantime.
+//! Actions belong to the period they were taken in and are discarded once the
+//! period or game changes. Every undo and redo goes on the game's audit trail,
+//! with what it changed.
+
+use super::*;
+
+/// Oldest actions are forgotten beyond this many.
+const MAX_ACTIONS: usize = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ClockRecord {
+    pub(super) clock_state: ClockState,
+    pub(super) timeout_state: Option<TimeoutState>,
+    pub(super) timeouts_used: BlackWhiteBundle<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Action {
+    Score {
+        color: Color,
+        player_number: u8,
+        period_time: Option<Duration>,
+        instant: Instant,
+        before: BlackWhiteBundle<u8>,
+        after: BlackWhiteBundle<u8>,
+    },
+    GoalDisallowed {
+        color: Color,
+        goal: usize,
+        player_number: u8,
+        reason: GoalDisallowReason,
+        instant: Instant,
+        before: BlackWhiteBundle<u8>,
+        after: BlackWhiteBundle<u8>,
+    },
+    Penalty {
+        color: Color,
+        penalty: Penalty,
+        /// Whether the penalty had been served, and so moved into the game stats, when it was
+        /// undone. A redo puts it back there.
+        served: bool,
+    },
+    Foul {
+        color: Option<Color>,
+        foul: InfractionDetails,
+    },
+    Clock {
+        before: ClockRecord,
+        after: ClockRecord,
+    },
+    /// A goal, penalty or foul and the `Clock` change of the stop-time whistle it caused, which
+    /// are undone together
+    Whistled {
+        action: Box<Action>,
+        clock: Box<Action>,
+    },
+}
+
+impl ClockRecord {
+    fn audit(&self, now: Instant) -> AuditClocks {
+        AuditClocks {
+            game_clock: self
+                .clock_state
+                .clock_time(now)
+                .unwrap_or(Duration::ZERO)
+                .as_secs_f32(),
+            running: self.clock_state.is_running(),
+            timeout: self.timeout_state.as_ref().map(TimeoutState::sheet_kind),
+        }
+    }
+}
+
+/// `(before, after)` for a redo, or the other way round for an undo
+fn ordered<T>(undone: bool, before: T, after: T) -> (T, T) {
+    if undone {
+        (after, before)
+    } else {
+        (before, after)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Entry {
+    pub(super) game_number: GameNumber,
+    pub(super) period: GamePeriod,
+    pub(super) action: Action,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct History {
+    pub(super) undo: Vec<Entry>,
+    pub(super) redo: Vec<Entry>,
+}
+
+impl History {
+    pub(super) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl TournamentManager {
+    pub(super) fn clock_record(&self) -> ClockRecord {
+        ClockRecord {
+            clock_state: self.clock_state.clone(),
+            timeout_state: self.timeout_state.clone(),
+            timeouts_used: self.timeouts_used,
+        }
+    }
+
+    fn set_clock_record(&mut self, record: &ClockRecord) {
+        self.clock_state = record.clock_state.clone();
+        self.timeout_state = record.timeout_state.clone();
+        self.timeouts_used = record.timeouts_used;
+        self.send_clock_running(self.clock_is_running());
+    }
+
+    fn is_current(&self, entry: &Entry) -> bool {
+        entry.period == self.current_period && entry.game_number == self.game_number
+    }
+
+    fn drop_stale_history(&mut self) {
+        let game_number = &self.game_number;
+        let period = self.current_period;
+        let current = |e: &Entry| e.period == period && e.game_number == *game_number;
+        self.history.undo.retain(current);
+        self.history.redo.retain(current);
+    }
+
+    fn record(&mut self, action: Action) {
+        self.drop_stale_history();
+        self.history.redo.clear();
+        self.history.undo.push(Entry {
+            game_number: self.game_number.clone(),
+            period: self.current_period,
+            action,
+        });
+        if self.history.undo.len() > MAX_ACTIONS {
+            self.history.undo.remove(0);
+        }
+    }
+
+    pub(super) fn record_score(
+        &mut self,
+        color: Color,
+        player_number: u8,
+        period_time: Option<Duration>,
+        instant: Instant,
+        before: BlackWhiteBundle<u8>,
+    ) {
+        let after = self.scores;
+        self.record(Action::Score {
+            color,
+            player_number,
+            period_time,
+            instant,
+            before,
+            after,
+        });
+    }
+
+    pub(super) fn record_goal_disallowed(
+        &mut self,
+        color: Color,
+        goal: usize,
+        player_number: u8,
+        reason: GoalDisallowReason,
+        instant: Instant,
+        before: BlackWhiteBundle<u8>,
+    ) {
+        let after = self.scores;
+        self.record(Action::GoalDisallowed {
+            color,
+            goal,
+            player_number,
+            reason,
+            instant,
+            before,
+            after,
+        });
+    }
+
+    pub(super) fn record_penalty(&mut self, color: Color, penalty: Penalty) {
+        self.record(Action::Penalty {
+            color,
+            penalty,
+            served: false,
+        });
+    }
+
+    pub(super) fn record_foul(&mut self, color: Option<Color>, foul: InfractionDetails) {
+        self.record(Action::Foul { color, foul });
+    }
+
+    /// Records the change to the clocks since `before` was taken, if any.
+    pub(super) fn record_clock_change(&mut self, before: ClockRecord) {
+        let after = self.clock_record();
+        if before != after {
+            self.record(Action::Clock { before, after });
+        }
+    }
+
+    /// Records the clocks stopping for a whistle since `before` was taken. With
+    /// `with_last_action` the stop joins the most recent action, so one undo reverses both.
+    pub(super) fn record_whistle(&mut self, before: ClockRecord, with_last_action: bool) {
+        let after = self.clock_record();
+        if before == after {
+            return;
+        }
+        let clock = Action::Clock { before, after };
+        if with_last_action
+            && self.history.undo.last().is_some_and(|e| self.is_current(e))
+            && let Some(entry) = self.history.undo.pop()
+        {
+            self.history.undo.push(Entry {
+                action: Action::Whistled {
+                    action: Box::new(entry.action),
+                    clock: Box::new(clock),
+                },
+                ..entry
+            });
+        } else {
+            self.record(clock);
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history.undo.last().is_some_and(|e| self.is_current(e))
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history.redo.last().is_some_and(|e| self.is_current(e))
+    }
+
+    /// Reverse the most recent action. If the game has changed underneath it
+    /// the whole history is dropped and `HistoryConflict` returned.
+    pub fn undo(&mut self, now: Instant) -> Result<()> {
+        self.drop_stale_history();
+        let mut entry = self
+            .history
+            .undo
+            .pop()
+            .ok_or(TournamentManagerError::NothingToUndo)?;
+        info!("{} Undoing {:?}", self.status_string(now), entry.action);
+        if let Err(e) = self.reverse(&mut entry.action, now) {
+            warn!(
+                "{} Can't undo, clearing history: {e}",
+                self.status_string(now)
+            );
+            self.history.clear();
+            return Err(e);
+        }
+        for change in self.audit_changes(&entry.action, true, now) {
+            self.record_edit(change, AuditSource::Undo, now);
+        }
+        self.history.redo.push(entry);
+        Ok(())
+    }
+
+    /// Re-apply the most recently undone action.
+    pub fn redo(&mut self, now: Instant) -> Result<()> {
+        self.drop_stale_history();
+        let entry = self
+            .history
+            .redo
+            .pop()
+            .ok_or(TournamentManagerError::NothingToRedo)?;
+        info!("{} Redoing {:?}", self.status_string(now), entry.action);
+        if let Err(e) = self.reapply(&entry.action, now) {
+            warn!(
+                "{} Can't redo, clearing redo list: {e}",
+                self.status_string(now)
+            );
+            self.history.redo.clear();
+            return Err(e);
+        }
+        for change in self.audit_changes(&entry.action, false, now) {
+            self.record_edit(change, AuditSource::Redo, now);
+        }
+        self.history.undo.push(entry);
+        Ok(())
+    }
+
+    /// What undoing `action` changed, or redoing it when `undone` is false, in the order the
+    /// changes were made
+    fn audit_changes(&self, action: &Action, undone: bool, now: Instant) -> Vec<AuditChange> {
+        let change = match action {
+            Action::Score { before, after, .. } => {
+                let (from, to) = ordered(undone, *before, *after);
+                AuditChange::Score { from, to }
+            }
+            Action::GoalDisallowed {
+                color,
+                player_number,
+                reason,
+                before,
+                after,
+                ..
+            } => {
+                let (from, to) = ordered(undone, *before, *after);
+                AuditChange::GoalDisallowed {
+                    color: *color,
+                    player_number: *player_number,
+                    reason: *reason,
+                    from,
+                    to,
+                }
+            }
+            Action::Penalty { color, penalty, .. } => {
+                let (from, to) = ordered(undone, None, Some(AuditPenalty::of(penalty, *color)));
+                AuditChange::Penalty { from, to }
+            }
+            Action::Foul { color, foul } => {
+                let (from, to) = ordered(undone, None, Some(AuditFoul::of(foul, *color)));
+                AuditChange::Foul { from, to }
+            }
+            Action::Clock { before, after } => {
+                let (from, to) = ordered(undone, before, after);
+                AuditChange::Clocks {
+                    from: from.audit(now),
+                    to: to.audit(now),
+                }
+            }
+            Action::Whistled { action, clock } => {
+                let (first, second) = ordered(undone, action, clock);
+                let mut changes = self.audit_changes(first, undone, now);
+                changes.extend(self.audit_changes(second, undone, now));
+                return changes;
+            }
+        };
+        vec![change]
+    }
+
+    fn set_clock_record_at(&mut self, record: &ClockRecord, now: Instant) -> Result<()> {
+        let has_run_out = |cs: &ClockState| match cs {
+            ClockState::CountingDown { .. } => cs.clock_time(now).is_none_or(|t| t.is_zero()),
+            ClockState::CountingUp { .. } | ClockState::Stopped { .. } => {
+                cs.clock_time(now).is_none()
+            }
+        };
+        let timeout_clock = match &record.timeout_state {
+            Some(
+                TimeoutState::Team(_, cs)
+                | TimeoutState::Ref(cs)
+                | TimeoutState::PenaltyShot(cs)
+                | TimeoutState::RugbyPenaltyShot(cs),
+            ) => Some(cs),
+            None => None,
+        };
+        if has_run_out(&record.clock_state) || timeout_clock.is_some_and(has_run_out) {
+            return Err(TournamentManagerError::HistoryConflict);
+        }
+        self.set_clock_record(record);
+        Ok(())
+    }
+
+    /// Undo `action`, noting in it anything a redo needs to know about where things were
+    fn reverse(&mut self, action: &mut Action, now: Instant) -> Result<()> {
+        match action {
+            Action::Score {
+                color,
+                player_number,
+                period_time,
+                before,
+                after,
+                ..
+            } => {
+                if self.scores != *after {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.scores = *before;
+                self.current_game_stats.remove_goal(
+                    self.current_period,
+                    *period_time,
+                    *color,
+                    *player_number,
+                );
+                if self
+                    .recent_goal
+                    .is_some_and(|(c, p, ..)| c == *color && p == *player_number)
+                {
+                    self.recent_goal = None;
+                }
+            }
+            Action::GoalDisallowed {
+                color,
+                goal,
+                before,
+                after,
+                ..
+            } => {
+                if self.scores != *after {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.scores = *before;
+                self.current_game_stats
+                    .remove_goal_disallowed(*color, *goal);
+                self.recent_disallowed_goal = None;
+            }
+            Action::Penalty {
+                color,
+                penalty,
+                served,
+            } => {
+                // A penalty that has already been served is no longer in the
+                // list; it was moved into the game stats instead.
+                if let Some(i) = self.penalties[*color].iter().rposition(|p| p == penalty) {
+                    self.penalties[*color].remove(i);
+                    *served = false;
+                } else if self.current_game_stats.remove_penalty(penalty, *color) {
+                    *served = true;
+                } else {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+            }
+            Action::Foul { color, foul } => {
+                let i = self.fouls[*color]
+                    .iter()
+                    .rposition(|f| f == foul)
+                    .ok_or(TournamentManagerError::HistoryConflict)?;
+                self.fouls[*color].remove(i);
+            }
+            Action::Clock { before, after } => {
+                if self.clock_record() != *after {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.set_clock_record_at(before, now)?;
+                if before.timeout_state.is_none() && after.timeout_state.is_some() {
+                    self.current_game_stats.remove_last_timeout();
+                }
+            }
+            Action::Whistled { action, clock } => {
+                // Either half can conflict, so the clocks are put back if the action can't be
+                let clocks = self.clock_record();
+                self.reverse(clock, now)?;
+                if let Err(e) = self.reverse(action, now) {
+                    self.set_clock_record(&clocks);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn reapply(&mut self, action: &Action, now: Instant) -> Result<()> {
+        match action {
+            Action::Score {
+                color,
+                player_number,
+                period_time,
+                instant,
+                before,
+                after,
+            } => {
+                if self.scores != *before {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.scores = *after;
+                self.current_game_stats.add_goal(
+                    self.current_period,
+                    *period_time,
+                    *color,
+                    *player_number,
+                    *instant,
+                );
+            }
+            Action::GoalDisallowed {
+                color,
+                goal,
+                reason,
+                instant,
+                before,
+                after,
+                ..
+            } => {
+                if self.scores != *before {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.scores = *after;
+                self.current_game_stats
+                    .add_goal_disallowed(*color, *goal, *reason, *instant);
+            }
+            Action::Penalty {
+                color,
+                penalty,
+                served,
+            } => {
+                if *served {
+                    self.current_game_stats.add_penalty(penalty, *color);
+                } else {
+                    self.penalties[*color].push(penalty.clone());
+                }
+            }
+            Action::Foul { color, foul } => self.fouls[*color].push(foul.clone()),
+            Action::Clock { before, after } => {
+                if self.clock_record() != *before {
+                    return Err(TournamentManagerError::HistoryConflict);
+                }
+                self.set_clock_record_at(after, now)?;
+                if before.timeout_state.is_none() && after.timeout_state.is_some() {
+                    self.record_timeout_start(now);
+                }
+            }
+            Action::Whistled { action, clock } => {
+                let clocks = self.clock_record();
+                self.reapply(clock, now)?;
+                if let Err(e) = self.reapply(action, now) {
+                    self.set_clock_record(&clocks);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_first_half(start: Instant) -> TournamentManager {
+        let mut tm = TournamentManager::new(GameConfig::default());
+        tm.start_play_now(start).unwrap();
+        tm.update(start).unwrap();
+        tm
+    }
+
+    #[test]
+    fn undo_and_redo_a_goal() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        tm.add_score(Color::White, 3, start + Duration::from_secs(10));
+        assert_eq!(tm.get_scores().white, 1);
+        assert!(tm.current_game_stats.as_json().contains("\"goal\""));
+
+        tm.undo(start + Duration::from_secs(12)).unwrap();
+        assert_eq!(tm.get_scores().white, 0);
+        assert!(
+            !tm.current_game_stats
+                .as_json()
+                .contains("\"$type\":\"goal\"")
+        );
+        assert!(!tm.can_undo());
+        assert!(tm.can_redo());
+
+        tm.redo(start + Duration::from_secs(14)).unwrap();
+        assert_eq!(tm.get_scores().white, 1);
+        assert!(tm.current_game_stats.as_json().contains("\"goal\""));
+        assert!(tm.can_undo());
+        assert!(!tm.can_redo());
+    }
+
+    #[test]
+    fn one_undo_reverses_a_goal_and_its_stop_time_whistle() {
+        let config = GameConfig {
+            stop_time_allowed: true,
+            stop_time_window: Duration::from_secs(120),
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(100));
+        tm.start_clock(start);
+        let t = |s| start + Duration::from_secs(s);
+
+        tm.add_score(Color::Black, 4, t(10));
+        assert!(!tm.clock_is_running());
+
+        tm.undo(t(20)).unwrap();
+        assert_eq!(tm.get_scores().black, 0);
+        assert!(tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(t(20)), Some(Duration::from_secs(80)));
+
+        tm.redo(t(30)).unwrap();
+        assert_eq!(tm.get_scores().black, 1);
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(t(30)), Some(Duration::from_secs(90)));
+        let sources: Vec<_> = tm.audit_trail().into_iter().map(|e| e.source).collect();
+        assert_eq!(
+            sources,
+            vec![
+                AuditSource::Undo,
+                AuditSource::Undo,
+                AuditSource::Redo,
+                AuditSource::Redo
+            ]
+        );
+    }
+
+    #[test]
+    fn a_whistled_goal_that_cannot_be_undone_leaves_the_clock_stopped() {
+        let config = GameConfig {
+            stop_time_allowed: true,
+            stop_time_window: Duration::from_secs(120),
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(100));
+        tm.start_clock(start);
+        let t = |s| start + Duration::from_secs(s);
+
+        tm.add_score(Color::Black, 4, t(10));
+        // The score is corrected on the edit page, which the goal can't be undone over
+        tm.set_scores(BlackWhiteBundle { black: 2, white: 0 }, t(15));
+
+        assert_eq!(tm.undo(t(20)), Err(TournamentManagerError::HistoryConflict));
+        assert_eq!(tm.get_scores().black, 2);
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(t(20)), Some(Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn undo_and_redo_a_disallowed_goal() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        tm.add_score(Color::Black, 5, start + Duration::from_secs(10));
+        tm.disallow_goal(
+            Color::Black,
+            0,
+            GoalDisallowReason::Foul,
+            start + Duration::from_secs(12),
+        )
+        .unwrap();
+        assert_eq!(tm.get_scores().black, 0);
+        assert!(
+            tm.current_game_stats
+                .as_json()
+                .contains("\"$type\":\"goalDisallowed\"")
+        );
+
+        tm.undo(start + Duration::from_secs(14)).unwrap();
+        assert_eq!(tm.get_scores().black, 1);
+        assert!(
+            !tm.current_game_stats
+                .as_json()
+                .contains("\"$type\":\"goalDisallowed\"")
+        );
+        assert_eq!(tm.current_game_stats.goals().black[0].disallowed, None);
+
+        tm.redo(start + Duration::from_secs(16)).unwrap();
+        assert_eq!(tm.get_scores().black, 0);
+        assert_eq!(
+            tm.current_game_stats.goals().black[0].disallowed,
+            Some(GoalDisallowReason::Foul)
+        );
+    }
+
+    #[test]
+    fn undo_and_redo_go_on_the_audit_trail() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let t = |s| start + Duration::from_secs(s);
+        let score = |black, white| BlackWhiteBundle { black, white };
+        tm.add_score(Color::Black, 5, t(10));
+        tm.disallow_goal(Color::Black, 0, GoalDisallowReason::Foul, t(12))
+            .unwrap();
+        tm.undo(t(14)).unwrap();
+        tm.redo(t(16)).unwrap();
+
+        let disallowed = |from, to| AuditChange::GoalDisallowed {
+            color: Color::Black,
+            player_number: 5,
+            reason: GoalDisallowReason::Foul,
+            from,
+            to,
+        };
+        let trail: Vec<_> = tm
+            .audit_trail()
+            .into_iter()
+            .map(|e| (e.source, e.change))
+            .collect();
+        assert_eq!(
+            trail,
+            vec![
+                (
+                    AuditSource::DisallowedGoal,
+                    disallowed(score(1, 0), score(0, 0))
+                ),
+                (AuditSource::Undo, disallowed(score(0, 0), score(1, 0))),
+                (AuditSource::Redo, disallowed(score(1, 0), score(0, 0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn undoing_a_clock_stop_goes_on_the_audit_trail() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let half = tm.config().half_play_duration;
+        let t = |s| start + Duration::from_secs(s);
+        tm.stop_clock(t(20)).unwrap();
+        tm.undo(t(50)).unwrap();
+
+        let entry = tm.audit_trail().pop().unwrap();
+        assert_eq!(entry.source, AuditSource::Undo);
+        assert_eq!(
+            entry.change,
+            AuditChange::Clocks {
+                from: AuditClocks {
+                    game_clock: (half - Duration::from_secs(20)).as_secs_f32(),
+                    running: false,
+                    timeout: None,
+                },
+                to: AuditClocks {
+                    game_clock: (half - Duration::from_secs(50)).as_secs_f32(),
+                    running: true,
+                    timeout: None,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn undoing_a_clock_stop_keeps_the_time_that_passed() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let half = tm.config().half_play_duration;
+
+        tm.stop_clock(start + Duration::from_secs(20)).unwrap();
+        assert!(!tm.clock_is_running());
+
+        let later = start + Duration::from_secs(50);
+        tm.undo(later).unwrap();
+        assert!(tm.clock_is_running());
+        assert!(*tm.get_start_stop_rx().borrow());
+        assert_eq!(
+            tm.game_clock_time(later),
+            Some(half - Duration::from_secs(50))
+        );
+
+        // Redo puts the clock back where the original stop left it.
+        tm.redo(later).unwrap();
+        assert!(!tm.clock_is_running());
+        assert_eq!(
+            tm.game_clock_time(later),
+            Some(half - Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn a_clock_stop_is_not_undone_once_the_clock_would_have_run_out() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let half = tm.config().half_play_duration;
+
+        tm.stop_clock(start + Duration::from_secs(20)).unwrap();
+        let later = start + half + Duration::from_secs(1);
+        assert_eq!(tm.undo(later), Err(TournamentManagerError::HistoryConflict));
+        assert!(!tm.clock_is_running());
+        assert!(!tm.can_undo());
+    }
+
+    #[test]
+    fn undoing_a_team_timeout_refunds_it_and_resumes_the_clock() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let half = tm.config().half_play_duration;
+
+        tm.start_team_timeout(Color::Black, start + Duration::from_secs(30))
+            .unwrap();
+        assert_eq!(tm.timeouts_used.black, 1);
+
+        let later = start + Duration::from_secs(40);
+        tm.undo(later).unwrap();
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(tm.timeouts_used.black, 0);
+        assert_eq!(
+            tm.game_clock_time(later),
+            Some(half - Duration::from_secs(40))
+        );
+    }
+
+    #[test]
+    fn an_undone_team_timeout_leaves_the_scoresheet() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let t = |s| start + Duration::from_secs(s);
+        let timeouts = |tm: &TournamentManager| {
+            tm.current_game_stats
+                .sheet_lines()
+                .into_iter()
+                .filter(|line| matches!(line.kind, SheetLineKind::Timeout(_)))
+                .count()
+        };
+
+        tm.start_team_timeout(Color::Black, t(30)).unwrap();
+        assert_eq!(timeouts(&tm), 1);
+        tm.undo(t(31)).unwrap();
+        assert_eq!(timeouts(&tm), 0);
+        tm.redo(t(32)).unwrap();
+        assert_eq!(timeouts(&tm), 1);
+        tm.switch_to_team_timeout(Color::White).unwrap();
+        assert_eq!(
+            tm.current_game_stats.sheet_lines().last().unwrap().kind,
+            SheetLineKind::Timeout(TimeoutKind::Team(Color::White))
+        );
+        tm.cancel_team_timeout(t(33)).unwrap();
+        assert_eq!(timeouts(&tm), 0);
+    }
+
+    #[test]
+    fn multi_step_undo_runs_newest_first() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        let t = |s| start + Duration::from_secs(s);
+
+        tm.add_score(Color::Black, 1, t(5));
+        tm.start_penalty(
+            Color::White,
+            9,
+            PenaltyKind::ONE_MINUTE,
+            t(6),
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.add_foul(None, None, Infraction::DELAY_OF_GAME, t(7))
+            .unwrap();
+
+        tm.undo(t(8)).unwrap();
+        assert!(tm.get_fouls()[None].is_empty());
+        tm.undo(t(8)).unwrap();
+        assert!(tm.get_penalties().white.is_empty());
+        tm.undo(t(8)).unwrap();
+        assert_eq!(tm.get_scores().black, 0);
+        assert_eq!(tm.undo(t(8)), Err(TournamentManagerError::NothingToUndo));
+
+        tm.redo(t(9)).unwrap();
+        tm.redo(t(9)).unwrap();
+        assert_eq!(tm.get_scores().black, 1);
+        assert_eq!(tm.get_penalties().white.len(), 1);
+        assert!(tm.can_redo());
+
+        // A new action forgets what was left to redo.
+        tm.add_score(Color::White, 2, t(10));
+        assert!(!tm.can_redo());
+    }
+
+    #[test]
+    fn undoing_a_served_penalty_takes_it_from_the_stats_and_redoing_puts_it_back() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        tm.start_penalty(
+            Color::Black,
+            4,
+            PenaltyKind::THIRTY_SECOND,
+            start,
+            Infraction::UNKNOWN,
+        )
+        .unwrap();
+        tm.limit_pen_list_len(Color::Black, 0, start + Duration::from_secs(60))
+            .unwrap();
+        assert!(tm.current_game_stats.as_json().contains("\"penalty\""));
+
+        tm.undo(start + Duration::from_secs(61)).unwrap();
+        assert!(
+            !tm.current_game_stats
+                .as_json()
+                .contains("\"$type\":\"penalty\"")
+        );
+
+        // Redoing puts it back in the stats, not the list of penalties still running
+        tm.redo(start + Duration::from_secs(62)).unwrap();
+        assert!(tm.penalties.black.is_empty());
+        assert!(
+            tm.current_game_stats
+                .as_json()
+                .contains("\"$type\":\"penalty\"")
+        );
+    }
+
+    #[test]
+    fn edited_state_is_not_undone() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        tm.add_score(Color::Black, 1, start);
+        tm.set_scores(BlackWhiteBundle { black: 3, white: 0 }, start);
+
+        assert_eq!(
+            tm.undo(start + Duration::from_secs(1)),
+            Err(TournamentManagerError::HistoryConflict)
+        );
+        assert_eq!(tm.get_scores().black, 3);
+        assert!(!tm.can_undo());
+    }
+
+    #[test]
+    fn history_ends_with_the_period() {
+        let start = Instant::now();
+        let mut tm = in_first_half(start);
+        tm.add_score(Color::Black, 1, start);
+        assert!(tm.can_undo());
+
+        let half_end = start + tm.config().half_play_duration + Duration::from_secs(1);
+        tm.update(half_end).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::HalfTime);
+        assert!(!tm.can_undo());
+        assert_eq!(
+            tm.undo(half_end),
+            Err(TournamentManagerError::NothingToUndo)
+        );
+    }
+}
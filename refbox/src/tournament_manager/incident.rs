@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tokio::time::{Duration, Instant};
+use uwh_common::{color::Color, game_snapshot::GamePeriod};
+
+/// What an incident report is about
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IncidentCategory {
+    #[default]
+    Injury,
+    EquipmentFailure,
+    Misconduct,
+    Other,
+}
+
+impl IncidentCategory {
+    pub const ALL: [Self; 4] = [
+        Self::Injury,
+        Self::EquipmentFailure,
+        Self::Misconduct,
+        Self::Other,
+    ];
+
+    /// How the scoresheet and the exported report name the category
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Injury => "Injury",
+            Self::EquipmentFailure => "Equipment failure",
+            Self::Misconduct => "Misconduct",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// An incident report as it is being filed, timed from when the referee began filing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IncidentDetails {
+    pub(crate) category: IncidentCategory,
+    pub(crate) color: Option<Color>,
+    pub(crate) player_number: Option<u8>,
+    pub(crate) start_period: GamePeriod,
+    pub(crate) start_time: Duration,
+    pub(crate) start_instant: Instant,
+    pub(crate) description: String,
+}
+
+/// A referee's report of something that happened in a game that is not an infraction: an
+/// injury, broken equipment, misconduct. Kept with the game for the scoresheet and the results
+/// archive, but never sent to the portal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncidentReport {
+    pub category: IncidentCategory,
+    /// `None` when no one team was involved
+    pub color: Option<Color>,
+    pub player_number: Option<u8>,
+    pub game_period: GamePeriod,
+    /// Time left in the period
+    pub period_time: Duration,
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurred_on: OffsetDateTime,
+    pub description: String,
+}
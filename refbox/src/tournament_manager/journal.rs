@@ -0,0 +1,1001 @@
+//! Crash-safe journal of the game in progress ("game journal").
+//!
+//! Records everything `TournamentManager` needs to carry a game across a cold
+//! start: the period and clocks, scores, penalties, warnings, fouls, timeout
+//! state, the stats that will be posted when the game ends, the undo history
+//! and the accepted catch-up plan. The app writes
+//! a new journal whenever that state changes, and on startup offers to resume
+//! a fresh one. This is Option B of ADR 013.
+//!
+//! `Instant`s mean nothing to another process, so every point in time is
+//! stored as wall-clock time. A clock that was running is stored by when it
+//! started rather than by what it read, which keeps the journal unchanged
+//! while the clock simply runs; on restore it is rebuilt from the wall-clock
+//! time that has passed since, as if the refbox had never stopped.
+//!
+//! Kept as a [`state_file`].
+
+use super::{
+    history::{self, ClockRecord, Entry, History},
+    *,
+};
+use crate::state_file;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// How recent a journal must be to be offered for resume. Longer than any
+/// game plus its overtime, short enough that yesterday's game is never
+/// offered (ADR 013, Option B freshness policy).
+pub const FRESHNESS_WINDOW: time::Duration = time::Duration::hours(4);
+
+const FILE_STEM: &str = "game_journal";
+
+/// Maps this process's monotonic `Instant`s to wall-clock time and back.
+///
+/// Captured once per process so that converting the same `Instant` always
+/// gives the same wall-clock time, which is what lets two journals of an
+/// unchanged game compare equal.
+#[derive(Debug, Clone, Copy)]
+pub struct WallAnchor {
+    instant: Instant,
+    wall: OffsetDateTime,
+}
+
+impl WallAnchor {
+    pub fn now() -> Self {
+        Self::new(Instant::now(), OffsetDateTime::now_utc())
+    }
+
+    pub fn new(instant: Instant, wall: OffsetDateTime) -> Self {
+        Self { instant, wall }
+    }
+
+    pub fn wall_at(&self, instant: Instant) -> OffsetDateTime {
+        if instant >= self.instant {
+            self.wall + (instant - self.instant)
+        } else {
+            self.wall - (self.instant - instant)
+        }
+    }
+
+    /// `None` if `wall` is so far in the past that this process cannot
+    /// represent it as an `Instant` (e.g. before the machine booted).
+    pub fn instant_at(&self, wall: OffsetDateTime) -> Option<Instant> {
+        let offset = wall - self.wall;
+        let magnitude: Duration = offset.unsigned_abs();
+        if offset.is_negative() {
+            self.instant.checked_sub(magnitude)
+        } else {
+            self.instant.checked_add(magnitude)
+        }
+    }
+}
+
+/// The persisted journal. `saved_at` is excluded from [`GameJournal::same_state`]
+/// so a rewrite is only needed when the game itself changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameJournal {
+    pub version: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub saved_at: OffsetDateTime,
+    state: JournaledState,
+}
+
+impl GameJournal {
+    /// Bumped whenever the shape of the journal changes. A journal of any other version is set
+    /// aside rather than read (see [`load_or_none`]), so no field needs a default for older
+    /// journals.
+    pub const CURRENT_VERSION: u32 = 2;
+
+    pub fn same_state(&self, other: &GameJournal) -> bool {
+        self.state == other.state
+    }
+
+    pub fn game_number(&self) -> &GameNumber {
+        &self.state.game_number
+    }
+
+    pub fn scores(&self) -> BlackWhiteBundle<u8> {
+        self.state.scores
+    }
+
+    /// Worth offering to the operator: a game was under way when it was
+    /// written, and it was written within [`FRESHNESS_WINDOW`] of `now`.
+    pub fn is_resumable(&self, now: OffsetDateTime) -> bool {
+        self.state.current_period != GamePeriod::BetweenGames
+            && now >= self.saved_at
+            && (now - self.saved_at) <= FRESHNESS_WINDOW
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledState {
+    config: GameConfig,
+    game_number: GameNumber,
+    #[serde(with = "time::serde::rfc3339")]
+    game_start_time: OffsetDateTime,
+    current_period: GamePeriod,
+    clock_state: JournaledClock,
+    timeout_state: Option<JournaledTimeout>,
+    timeouts_used: BlackWhiteBundle<u16>,
+    scores: BlackWhiteBundle<u8>,
+    penalties: BlackWhiteBundle<Vec<JournaledPenalty>>,
+    warnings: BlackWhiteBundle<Vec<JournaledInfraction>>,
+    fouls: OptColorBundle<Vec<JournaledInfraction>>,
+    has_reset: bool,
+    next_game: Option<NextGameInfo>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    next_scheduled_start: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    current_scheduled_start: Option<OffsetDateTime>,
+    reset_game_time: Duration,
+    stats: GameStats,
+    confirm_pause: Option<JournaledConfirmPause>,
+    shootout: JournaledShootout,
+    history: JournaledHistory,
+    catch_up: Option<CatchUpPlan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum JournaledClock {
+    Stopped {
+        clock_time: Duration,
+    },
+    CountingDown {
+        #[serde(with = "time::serde::rfc3339")]
+        started_at: OffsetDateTime,
+        time_remaining_at_start: Duration,
+    },
+    CountingUp {
+        #[serde(with = "time::serde::rfc3339")]
+        started_at: OffsetDateTime,
+        time_at_start: Duration,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum JournaledTimeout {
+    Team(Color, JournaledClock),
+    Ref(JournaledClock),
+    PenaltyShot(JournaledClock),
+    RugbyPenaltyShot(JournaledClock),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledPenalty {
+    kind: PenaltyKind,
+    player_number: u8,
+    start_period: GamePeriod,
+    start_time: Duration,
+    #[serde(with = "time::serde::rfc3339")]
+    started_at: OffsetDateTime,
+    infraction: Infraction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledInfraction {
+    player_number: Option<u8>,
+    start_period: GamePeriod,
+    start_time: Duration,
+    #[serde(with = "time::serde::rfc3339")]
+    started_at: OffsetDateTime,
+    infraction: Infraction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledConfirmPause {
+    #[serde(with = "time::serde::rfc3339")]
+    pause_began: OffsetDateTime,
+    duration_of_pause: Duration,
+    clock_time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledShootout {
+    attempts: BlackWhiteBundle<Vec<JournaledShootoutAttempt>>,
+    first_shooter: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledShootoutAttempt {
+    player_number: u8,
+    scored: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    taken_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledHistory {
+    undo: Vec<JournaledEntry>,
+    redo: Vec<JournaledEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledEntry {
+    game_number: GameNumber,
+    period: GamePeriod,
+    action: JournaledAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum JournaledAction {
+    Score {
+        color: Color,
+        player_number: u8,
+        period_time: Option<Duration>,
+        #[serde(with = "time::serde::rfc3339")]
+        scored_at: OffsetDateTime,
+        before: BlackWhiteBundle<u8>,
+        after: BlackWhiteBundle<u8>,
+    },
+    GoalDisallowed {
+        color: Color,
+        goal: usize,
+        player_number: u8,
+        reason: GoalDisallowReason,
+        #[serde(with = "time::serde::rfc3339")]
+        disallowed_at: OffsetDateTime,
+        before: BlackWhiteBundle<u8>,
+        after: BlackWhiteBundle<u8>,
+    },
+    Penalty {
+        color: Color,
+        penalty: JournaledPenalty,
+        served: bool,
+    },
+    Foul {
+        color: Option<Color>,
+        foul: JournaledInfraction,
+    },
+    Clock {
+        before: JournaledClockRecord,
+        after: JournaledClockRecord,
+    },
+    Whistled {
+        action: Box<JournaledAction>,
+        clock: Box<JournaledAction>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct JournaledClockRecord {
+    clock_state: JournaledClock,
+    timeout_state: Option<JournaledTimeout>,
+    timeouts_used: BlackWhiteBundle<u16>,
+}
+
+impl JournaledClock {
+    fn record(cs: &ClockState, anchor: &WallAnchor) -> Self {
+        match *cs {
+            ClockState::Stopped { clock_time } => Self::Stopped { clock_time },
+            ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start,
+            } => Self::CountingDown {
+                started_at: anchor.wall_at(start_time),
+                time_remaining_at_start,
+            },
+            ClockState::CountingUp {
+                start_time,
+                time_at_start,
+            } => Self::CountingUp {
+                started_at: anchor.wall_at(start_time),
+                time_at_start,
+            },
+        }
+    }
+
+    /// Rebuild a running clock so that it reads what it would have read had
+    /// it kept running through the outage. If the start is too long ago to be
+    /// an `Instant` here, the clock is restarted at `now` with the time it
+    /// would read now, which shows the same value.
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> ClockState {
+        let elapsed = |started_at: OffsetDateTime| {
+            let since = anchor.wall_at(now) - started_at;
+            if since.is_negative() {
+                Duration::ZERO
+            } else {
+                since.unsigned_abs()
+            }
+        };
+        match *self {
+            Self::Stopped { clock_time } => ClockState::Stopped { clock_time },
+            Self::CountingDown {
+                started_at,
+                time_remaining_at_start,
+            } => match anchor.instant_at(started_at) {
+                Some(start_time) => ClockState::CountingDown {
+                    start_time,
+                    time_remaining_at_start,
+                },
+                None => ClockState::CountingDown {
+                    start_time: now,
+                    time_remaining_at_start: time_remaining_at_start
+                        .saturating_sub(elapsed(started_at)),
+                },
+            },
+            Self::CountingUp {
+                started_at,
+                time_at_start,
+            } => match anchor.instant_at(started_at) {
+                Some(start_time) => ClockState::CountingUp {
+                    start_time,
+                    time_at_start,
+                },
+                None => ClockState::CountingUp {
+                    start_time: now,
+                    time_at_start: time_at_start + elapsed(started_at),
+                },
+            },
+        }
+    }
+}
+
+impl JournaledTimeout {
+    fn record(ts: &TimeoutState, anchor: &WallAnchor) -> Self {
+        match ts {
+            TimeoutState::Team(color, cs) => Self::Team(*color, JournaledClock::record(cs, anchor)),
+            TimeoutState::Ref(cs) => Self::Ref(JournaledClock::record(cs, anchor)),
+            TimeoutState::PenaltyShot(cs) => Self::PenaltyShot(JournaledClock::record(cs, anchor)),
+            TimeoutState::RugbyPenaltyShot(cs) => {
+                Self::RugbyPenaltyShot(JournaledClock::record(cs, anchor))
+            }
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> TimeoutState {
+        match self {
+            Self::Team(color, cs) => TimeoutState::Team(*color, cs.rebuild(anchor, now)),
+            Self::Ref(cs) => TimeoutState::Ref(cs.rebuild(anchor, now)),
+            Self::PenaltyShot(cs) => TimeoutState::PenaltyShot(cs.rebuild(anchor, now)),
+            Self::RugbyPenaltyShot(cs) => TimeoutState::RugbyPenaltyShot(cs.rebuild(anchor, now)),
+        }
+    }
+}
+
+impl JournaledPenalty {
+    fn record(pen: &Penalty, anchor: &WallAnchor) -> Self {
+        Self {
+            kind: pen.kind,
+            player_number: pen.player_number,
+            start_period: pen.start_period,
+            start_time: pen.start_time,
+            started_at: anchor.wall_at(pen.start_instant),
+            infraction: pen.infraction,
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> Penalty {
+        Penalty {
+            kind: self.kind,
+            player_number: self.player_number,
+            start_period: self.start_period,
+            start_time: self.start_time,
+            start_instant: anchor.instant_at(self.started_at).unwrap_or(now),
+            infraction: self.infraction,
+        }
+    }
+}
+
+impl JournaledInfraction {
+    fn record(details: &InfractionDetails, anchor: &WallAnchor) -> Self {
+        Self {
+            player_number: details.player_number,
+            start_period: details.start_period,
+            start_time: details.start_time,
+            started_at: anchor.wall_at(details.start_instant),
+            infraction: details.infraction,
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> InfractionDetails {
+        InfractionDetails {
+            player_number: self.player_number,
+            start_period: self.start_period,
+            start_time: self.start_time,
+            start_instant: anchor.instant_at(self.started_at).unwrap_or(now),
+            infraction: self.infraction,
+        }
+    }
+}
+
+impl JournaledShootout {
+    fn record(shootout: &Shootout, anchor: &WallAnchor) -> Self {
+        Self {
+            attempts: shootout
+                .attempts
+                .iter()
+                .map(|(color, attempts)| {
+                    let attempts = attempts.iter().map(|a| JournaledShootoutAttempt {
+                        player_number: a.player_number,
+                        scored: a.scored,
+                        taken_at: anchor.wall_at(a.instant),
+                    });
+                    (color, attempts.collect())
+                })
+                .collect(),
+            first_shooter: shootout.first_shooter,
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> Shootout {
+        Shootout {
+            attempts: self
+                .attempts
+                .iter()
+                .map(|(color, attempts)| {
+                    let attempts = attempts.iter().map(|a| ShootoutAttempt {
+                        player_number: a.player_number,
+                        scored: a.scored,
+                        instant: anchor.instant_at(a.taken_at).unwrap_or(now),
+                    });
+                    (color, attempts.collect())
+                })
+                .collect(),
+            first_shooter: self.first_shooter,
+        }
+    }
+}
+
+impl JournaledHistory {
+    fn record(history: &History, anchor: &WallAnchor) -> Self {
+        let record = |entries: &[Entry]| {
+            entries
+                .iter()
+                .map(|e| JournaledEntry {
+                    game_number: e.game_number.clone(),
+                    period: e.period,
+                    action: JournaledAction::record(&e.action, anchor),
+                })
+                .collect()
+        };
+        Self {
+            undo: record(&history.undo),
+            redo: record(&history.redo),
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> History {
+        let rebuild = |entries: &[JournaledEntry]| {
+            entries
+                .iter()
+                .map(|e| Entry {
+                    game_number: e.game_number.clone(),
+                    period: e.period,
+                    action: e.action.rebuild(anchor, now),
+                })
+                .collect()
+        };
+        History {
+            undo: rebuild(&self.undo),
+            redo: rebuild(&self.redo),
+        }
+    }
+}
+
+impl JournaledAction {
+    fn record(action: &history::Action, anchor: &WallAnchor) -> Self {
+        match action {
+            history::Action::Score {
+                color,
+                player_number,
+                period_time,
+                instant,
+                before,
+                after,
+            } => Self::Score {
+                color: *color,
+                player_number: *player_number,
+                period_time: *period_time,
+                scored_at: anchor.wall_at(*instant),
+                before: *before,
+                after: *after,
+            },
+            history::Action::GoalDisallowed {
+                color,
+                goal,
+                player_number,
+                reason,
+                instant,
+                before,
+                after,
+            } => Self::GoalDisallowed {
+                color: *color,
+                goal: *goal,
+                player_number: *player_number,
+                reason: *reason,
+                disallowed_at: anchor.wall_at(*instant),
+                before: *before,
+                after: *after,
+            },
+            history::Action::Penalty {
+                color,
+                penalty,
+                served,
+            } => Self::Penalty {
+                color: *color,
+                penalty: JournaledPenalty::record(penalty, anchor),
+                served: *served,
+            },
+            history::Action::Foul { color, foul } => Self::Foul {
+                color: *color,
+                foul: JournaledInfraction::record(foul, anchor),
+            },
+            history::Action::Clock { before, after } => Self::Clock {
+                before: JournaledClockRecord::record(before, anchor),
+                after: JournaledClockRecord::record(after, anchor),
+            },
+            history::Action::Whistled { action, clock } => Self::Whistled {
+                action: Box::new(Self::record(action, anchor)),
+                clock: Box::new(Self::record(clock, anchor)),
+            },
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> history::Action {
+        let instant_or_now = |wall: OffsetDateTime| anchor.instant_at(wall).unwrap_or(now);
+        match self {
+            Self::Score {
+                color,
+                player_number,
+                period_time,
+                scored_at,
+                before,
+                after,
+            } => history::Action::Score {
+                color: *color,
+                player_number: *player_number,
+                period_time: *period_time,
+                instant: instant_or_now(*scored_at),
+                before: *before,
+                after: *after,
+            },
+            Self::GoalDisallowed {
+                color,
+                goal,
+                player_number,
+                reason,
+                disallowed_at,
+                before,
+                after,
+            } => history::Action::GoalDisallowed {
+                color: *color,
+                goal: *goal,
+                player_number: *player_number,
+                reason: *reason,
+                instant: instant_or_now(*disallowed_at),
+                before: *before,
+                after: *after,
+            },
+            Self::Penalty {
+                color,
+                penalty,
+                served,
+            } => history::Action::Penalty {
+                color: *color,
+                penalty: penalty.rebuild(anchor, now),
+                served: *served,
+            },
+            Self::Foul { color, foul } => history::Action::Foul {
+                color: *color,
+                foul: foul.rebuild(anchor, now),
+            },
+            Self::Clock { before, after } => history::Action::Clock {
+                before: before.rebuild(anchor, now),
+                after: after.rebuild(anchor, now),
+            },
+            Self::Whistled { action, clock } => history::Action::Whistled {
+                action: Box::new(action.rebuild(anchor, now)),
+                clock: Box::new(clock.rebuild(anchor, now)),
+            },
+        }
+    }
+}
+
+impl JournaledClockRecord {
+    fn record(record: &ClockRecord, anchor: &WallAnchor) -> Self {
+        Self {
+            clock_state: JournaledClock::record(&record.clock_state, anchor),
+            timeout_state: record
+                .timeout_state
+                .as_ref()
+                .map(|ts| JournaledTimeout::record(ts, anchor)),
+            timeouts_used: record.timeouts_used,
+        }
+    }
+
+    fn rebuild(&self, anchor: &WallAnchor, now: Instant) -> ClockRecord {
+        ClockRecord {
+            clock_state: self.clock_state.rebuild(anchor, now),
+            timeout_state: self
+                .timeout_state
+                .as_ref()
+                .map(|ts| ts.rebuild(anchor, now)),
+            timeouts_used: self.timeouts_used,
+        }
+    }
+}
+
+impl TournamentManager {
+    /// Capture the current game as a journal stamped `saved_at`.
+    pub fn journal(&self, anchor: &WallAnchor, saved_at: OffsetDateTime) -> GameJournal {
+        let wall_opt = |i: Option<Instant>| i.map(|i| anchor.wall_at(i));
+        let state = JournaledState {
+            config: self.config.clone(),
+            game_number: self.game_number.clone(),
+            game_start_time: anchor.wall_at(self.game_start_time),
+            current_period: self.current_period,
+            clock_state: JournaledClock::record(&self.clock_state, anchor),
+            timeout_state: self
+                .timeout_state
+                .as_ref()
+                .map(|ts| JournaledTimeout::record(ts, anchor)),
+            timeouts_used: self.timeouts_used,
+            scores: self.scores,
+            penalties: self
+                .penalties
+                .iter()
+                .map(|(color, pens)| {
+                    let pens = pens.iter().map(|p| JournaledPenalty::record(p, anchor));
+                    (color, pens.collect())
+                })
+                .collect(),
+            warnings: self
+                .warnings
+                .iter()
+                .map(|(color, warns)| {
+                    let warns = warns.iter().map(|w| JournaledInfraction::record(w, anchor));
+                    (color, warns.collect())
+                })
+                .collect(),
+            fouls: self
+                .fouls
+                .iter()
+                .map(|(color, fouls)| {
+                    let fouls = fouls.iter().map(|f| JournaledInfraction::record(f, anchor));
+                    (color, fouls.collect())
+                })
+                .collect(),
+            has_reset: self.has_reset,
+            next_game: self.next_game.clone(),
+            next_scheduled_start: wall_opt(self.next_scheduled_start),
+            current_scheduled_start: wall_opt(self.current_scheduled_start),
+            reset_game_time: self.reset_game_time,
+            stats: self.current_game_stats.clone(),
+            confirm_pause: self
+                .time_pause_confirmation
+                .as_ref()
+                .map(|p| JournaledConfirmPause {
+                    pause_began: anchor.wall_at(p.pause_began),
+                    duration_of_pause: p.duration_of_pause,
+                    clock_time: p.clock_time,
+                }),
+            shootout: JournaledShootout::record(&self.shootout, anchor),
+            history: JournaledHistory::record(&self.history, anchor),
+            catch_up: self.catch_up.clone(),
+        };
+        GameJournal {
+            version: GameJournal::CURRENT_VERSION,
+            saved_at,
+            state,
+        }
+    }
+
+    /// Replace the current game with the journaled one, rebuilding every
+    /// running clock from the wall-clock time that has passed since it was
+    /// written. The start/stop latch is re-sent so the clock updater picks the
+    /// restored clock up.
+    pub fn restore_journal(&mut self, journal: &GameJournal, anchor: &WallAnchor, now: Instant) {
+        let state = &journal.state;
+        let instant_or_now = |wall: OffsetDateTime| anchor.instant_at(wall).unwrap_or(now);
+
+        self.config = state.config.clone();
+        self.game_number = state.game_number.clone();
+        self.game_start_time = instant_or_now(state.game_start_time);
+        self.current_period = state.current_period;
+        self.clock_state = state.clock_state.rebuild(anchor, now);
+        self.timeout_state = state
+            .timeout_state
+            .as_ref()
+            .map(|ts| ts.rebuild(anchor, now));
+        self.timeouts_used = state.timeouts_used;
+        self.scores = state.scores;
+        for color in [Color::Black, Color::White] {
+            self.penalties[color] = state.penalties[color]
+                .iter()
+                .map(|p| p.rebuild(anchor, now))
+                .collect();
+            self.warnings[color] = state.warnings[color]
+                .iter()
+                .map(|w| w.rebuild(anchor, now))
+                .collect();
+        }
+        for color in [Some(Color::Black), None, Some(Color::White)] {
+            self.fouls[color] = state.fouls[color]
+                .iter()
+                .map(|f| f.rebuild(anchor, now))
+                .collect();
+        }
+        self.has_reset = state.has_reset;
+        self.next_game = state.next_game.clone();
+        self.next_scheduled_start = state.next_scheduled_start.map(instant_or_now);
+        self.current_scheduled_start = state.current_scheduled_start.map(instant_or_now);
+        self.reset_game_time = state.reset_game_time;
+        self.recent_goal = None;
+        self.recent_disallowed_goal = None;
+        self.history = state.history.rebuild(anchor, now);
+        self.catch_up = state.catch_up.clone();
+        self.current_game_stats = state.stats.clone();
+        self.time_pause_confirmation = state.confirm_pause.as_ref().map(|p| ConfirmPause {
+            pause_began: instant_or_now(p.pause_began),
+            duration_of_pause: p.duration_of_pause,
+            clock_time: p.clock_time,
+        });
+        self.shootout = state.shootout.rebuild(anchor, now);
+
+        info!(
+            "{} Restored game {} from the journal written at {}",
+            self.status_string(now),
+            self.game_number,
+            journal.saved_at
+        );
+
+        self.send_clock_running(self.clock_is_running());
+    }
+}
+
+/// Load the journal. Missing → `None`. Present but unparseable or of an
+/// unknown version → renamed aside and `None`, see [`state_file::load`].
+/// Never blocks startup.
+pub fn load_or_none(dir: &Path) -> std::io::Result<Option<GameJournal>> {
+    state_file::load(
+        dir,
+        FILE_STEM,
+        GameJournal::CURRENT_VERSION,
+        |journal: &GameJournal| journal.version,
+    )
+}
+
+/// Atomically write the journal.
+pub fn save(dir: &Path, journal: &GameJournal) -> std::io::Result<()> {
+    state_file::save(dir, FILE_STEM, journal)
+}
+
+/// Remove the journal. A missing file is treated as success.
+pub fn delete(dir: &Path) -> std::io::Result<()> {
+    state_file::delete(dir, FILE_STEM)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn game_in_progress(now: Instant) -> TournamentManager {
+        let mut tm = TournamentManager::new(GameConfig::default());
+        tm.start_play_now(now).unwrap();
+        tm.add_score(Color::Black, 4, now + Duration::from_secs(30));
+        tm.start_penalty(
+            Color::White,
+            7,
+            PenaltyKind::TWO_MINUTE,
+            now + Duration::from_secs(60),
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.add_foul(
+            Some(Color::Black),
+            Some(2),
+            Infraction::DELAY_OF_GAME,
+            now + Duration::from_secs(90),
+        )
+        .unwrap();
+        tm.update(now + Duration::from_secs(100)).unwrap();
+        tm
+    }
+
+    #[test]
+    fn journal_is_unchanged_while_the_clock_just_runs() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let tm = game_in_progress(start);
+        let wall = OffsetDateTime::now_utc();
+        let a = tm.journal(&anchor, wall);
+        let b = tm.journal(&anchor, wall + time::Duration::seconds(45));
+        assert!(a.same_state(&b));
+        assert_ne!(a.saved_at, b.saved_at);
+    }
+
+    #[test]
+    fn restore_reproduces_the_snapshot() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let mut tm = game_in_progress(start);
+        let journal = tm.journal(&anchor, OffsetDateTime::now_utc());
+
+        let now = start + Duration::from_secs(120);
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &anchor, now);
+
+        assert_eq!(restored.generate_snapshot(now), tm.generate_snapshot(now));
+        assert!(restored.clock_is_running());
+        assert!(*restored.get_start_stop_rx().borrow());
+    }
+
+    #[test]
+    fn running_clock_is_rebuilt_from_wall_clock_time() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let tm = game_in_progress(start);
+        let before = tm
+            .game_clock_time(start + Duration::from_secs(100))
+            .unwrap();
+        let journal = tm.journal(&anchor, anchor.wall_at(start + Duration::from_secs(100)));
+
+        // A new process whose anchor says 40 s of wall-clock time have passed
+        // since the journal was written.
+        let later = Instant::now();
+        let new_anchor = WallAnchor::new(later, anchor.wall_at(start + Duration::from_secs(140)));
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &new_anchor, later);
+
+        let after = restored.game_clock_time(later).unwrap();
+        assert_eq!(before - after, Duration::from_secs(40));
+    }
+
+    #[test]
+    fn stopped_clock_is_restored_as_it_was() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let mut tm = game_in_progress(start);
+        tm.stop_clock(start + Duration::from_secs(100)).unwrap();
+        let journal = tm.journal(&anchor, OffsetDateTime::now_utc());
+
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &anchor, start + Duration::from_secs(900));
+        assert!(!restored.clock_is_running());
+        assert_eq!(
+            restored.game_clock_time(start + Duration::from_secs(900)),
+            Some(Duration::from_secs(800))
+        );
+    }
+
+    #[test]
+    fn shootout_attempts_are_restored() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let mut tm = TournamentManager::new(GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            penalty_shootout_allowed: true,
+            ..Default::default()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.update(start + Duration::from_secs(10)).unwrap();
+        let now = start + Duration::from_secs(30);
+        tm.add_shootout_attempt(Color::White, 3, true, now).unwrap();
+        tm.add_shootout_attempt(Color::Black, 9, false, now)
+            .unwrap();
+        let journal = tm.journal(&anchor, OffsetDateTime::now_utc());
+
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &anchor, now);
+
+        assert_eq!(restored.current_period(), GamePeriod::PenaltyShootout);
+        assert_eq!(restored.generate_snapshot(now), tm.generate_snapshot(now));
+        assert_eq!(
+            restored.add_shootout_attempt(Color::Black, 9, true, now),
+            Err(TournamentManagerError::ShootoutOutOfTurn(Color::White))
+        );
+    }
+
+    #[test]
+    fn undo_history_and_catch_up_plan_are_restored() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let mut tm = game_in_progress(start);
+        let plan = CatchUpPlan {
+            games: vec![catch_up::PlannedGame {
+                number: "2".to_string(),
+                break_cut: Duration::from_secs(60),
+                late_by: Duration::from_secs(120),
+                half_time: Some(Duration::from_secs(120)),
+            }],
+            on_schedule_by: None,
+            remaining: Duration::from_secs(120),
+        };
+        tm.set_catch_up_plan(Some(plan.clone())).unwrap();
+        tm.undo(start + Duration::from_secs(100)).unwrap();
+        let journal = tm.journal(&anchor, OffsetDateTime::now_utc());
+
+        let now = start + Duration::from_secs(120);
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &anchor, now);
+        assert_eq!(restored.catch_up, Some(plan));
+
+        // The foul was undone before the restart, so it can be redone, then the penalty and
+        // the goal undone
+        restored.redo(now).unwrap();
+        assert_eq!(restored.fouls[Some(Color::Black)].len(), 1);
+        restored.undo(now).unwrap();
+        restored.undo(now).unwrap();
+        assert!(restored.penalties[Color::White].is_empty());
+        restored.undo(now).unwrap();
+        assert_eq!(restored.get_scores().black, 0);
+    }
+
+    #[test]
+    fn a_clock_stop_can_be_undone_in_a_new_process() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let mut tm = game_in_progress(start);
+        tm.stop_clock(start + Duration::from_secs(100)).unwrap();
+        let journal = tm.journal(&anchor, anchor.wall_at(start + Duration::from_secs(100)));
+
+        let later = Instant::now();
+        let new_anchor = WallAnchor::new(later, anchor.wall_at(start + Duration::from_secs(140)));
+        let mut restored = TournamentManager::new(GameConfig::default());
+        restored.restore_journal(&journal, &new_anchor, later);
+        restored.undo(later).unwrap();
+
+        // As if the clock had never stopped
+        let stopped_at = tm.game_clock_time(start).unwrap();
+        assert!(restored.clock_is_running());
+        assert_eq!(
+            restored.game_clock_time(later),
+            Some(stopped_at - Duration::from_secs(40))
+        );
+    }
+
+    #[test]
+    fn is_resumable_needs_a_game_and_a_fresh_journal() {
+        let start = Instant::now();
+        let anchor = WallAnchor::now();
+        let saved_at = OffsetDateTime::now_utc();
+
+        let journal = game_in_progress(start).journal(&anchor, saved_at);
+        assert!(journal.is_resumable(saved_at));
+        assert!(journal.is_resumable(saved_at + FRESHNESS_WINDOW));
+        assert!(!journal.is_resumable(saved_at + FRESHNESS_WINDOW + time::Duration::seconds(1)));
+        assert!(!journal.is_resumable(saved_at - time::Duration::hours(1)));
+
+        let idle = TournamentManager::new(GameConfig::default()).journal(&anchor, saved_at);
+        assert!(!idle.is_resumable(saved_at));
+    }
+
+    #[test]
+    fn save_then_load_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let anchor = WallAnchor::now();
+        let journal = game_in_progress(Instant::now()).journal(&anchor, OffsetDateTime::now_utc());
+        save(tmp.path(), &journal).unwrap();
+        let loaded = load_or_none(tmp.path()).unwrap().unwrap();
+        assert!(loaded.same_state(&journal));
+        assert!(!tmp.path().join("game_journal.json.tmp").exists());
+    }
+
+    #[test]
+    fn missing_file_loads_as_none_and_delete_is_idempotent() {
+        let tmp = TempDir::new().unwrap();
+        assert!(load_or_none(tmp.path()).unwrap().is_none());
+        delete(tmp.path()).unwrap();
+    }
+
+    #[test]
+    fn corrupt_file_is_renamed_and_none_returned() {
+        let tmp = TempDir::new().unwrap();
+        let path = state_file::path(tmp.path(), FILE_STEM);
+        std::fs::write(&path, b"not json").unwrap();
+        assert!(load_or_none(tmp.path()).unwrap().is_none());
+        assert!(!path.exists());
+        let renamed = std::fs::read_dir(tmp.path()).unwrap().any(|e| {
+            e.unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with("game_journal.corrupt")
+        });
+        assert!(renamed, "expected a corrupt backup file");
+    }
+}
@@ -1,4 +1,5 @@
 use log::*;
+use serde::{Deserialize, Serialize};
 use std::{
     cmp::{max, min},
     convert::TryInto,
@@ -14,7 +15,7 @@ use uwh_common::{
     color::Color,
     config::Game as GameConfig,
     drawing_support::*,
-    game_snapshot::{GamePeriod, GameSnapshot, Infraction, TimeoutSnapshot},
+    game_snapshot::{GamePeriod, GameSnapshot, GoalDisallowReason, Infraction, TimeoutSnapshot},
     uwhportal::schedule::{GameNumber, TimingRule},
 };
 
@@ -24,10 +25,41 @@ use penalty::*;
 pub mod infraction;
 use infraction::*;
 
+pub mod incident;
+pub(crate) use incident::IncidentDetails;
+use incident::*;
+
+pub mod audit;
+use audit::*;
+
 mod game_stats;
 use game_stats::*;
+pub(crate) use game_stats::{SheetLine, SheetLineKind, TeamSignOff, TimeoutKind};
+
+pub mod journal;
+
+pub mod catch_up;
+use catch_up::CatchUpPlan;
+
+pub mod action;
+use action::Action;
+
+pub mod golden;
+
+pub mod session;
+use session::SessionRecorder;
+
+mod history;
+use history::History;
 
-use crate::penalty_editor::IterHelp;
+mod shootout;
+use shootout::*;
+
+mod timeline;
+pub(crate) use timeline::{TimelineEdit, TimelineEntry};
+
+use crate::{penalty_editor::IterHelp, rule_profile::RuleProfile};
+use journal::WallAnchor;
 
 const MAX_TIME_VAL: Duration = Duration::from_secs(MAX_LONG_STRINGABLE_SECS as u64);
 const RECENT_GOAL_TIME: Duration = Duration::from_secs(15);
@@ -56,9 +88,20 @@ pub struct TournamentManager {
     current_scheduled_start: Option<Instant>,
     reset_game_time: Duration,
     recent_goal: Option<(Color, u8, GamePeriod, Duration)>,
+    recent_disallowed_goal: Option<(Color, u8, GamePeriod, Duration)>,
     current_game_stats: GameStats,
     last_game_info: Option<LastGameInfo>,
     time_pause_confirmation: Option<ConfirmPause>,
+    history: History,
+    shootout: Shootout,
+    rules: RuleProfile,
+    /// An accepted catch-up plan, applied as each planned game's timing is adopted
+    catch_up: Option<CatchUpPlan>,
+    /// Where operator actions are recorded, when the session is being recorded
+    session: Option<SessionRecorder>,
+    /// Pinned while replaying a session, so scheduled start times are read against the
+    /// session's wall clock instead of the machine's
+    wall_clock: Option<WallAnchor>,
 }
 
 impl TournamentManager {
@@ -86,9 +129,53 @@ impl TournamentManager {
             reset_game_time: config.nominal_break,
             config,
             recent_goal: None,
+            recent_disallowed_goal: None,
             current_game_stats: GameStats::new("0"),
             last_game_info: None,
             time_pause_confirmation: None,
+            history: History::default(),
+            shootout: Shootout::default(),
+            rules: RuleProfile::default(),
+            catch_up: None,
+            session: None,
+            wall_clock: None,
+        }
+    }
+
+    /// Record every action passed to [`Self::apply`] from now on
+    pub fn start_recording(&mut self, recorder: SessionRecorder) {
+        self.session = Some(recorder);
+    }
+
+    /// Apply an operator action and, if it succeeded, record it to the session file. The app
+    /// changes the game only through here, with the same dispatch a replay uses, so a recorded
+    /// session can't miss an action or replay it differently.
+    pub fn apply(&mut self, action: Action, now: Instant) -> Result<()> {
+        let recorded = self.session.is_some().then(|| action.clone());
+        action::apply_action(self, action, now)?;
+        if let Some(action) = recorded {
+            self.record_to_session(now, action);
+        }
+        Ok(())
+    }
+
+    /// Record an operator action to the session file, if the session is being recorded. A
+    /// failed write stops the recording rather than the game.
+    fn record_to_session(&mut self, now: Instant, action: Action) {
+        if let Some(session) = &mut self.session
+            && let Err(e) = session.record(now, action)
+        {
+            error!("Failed to record the session, recording stopped: {e}");
+            self.session = None;
+        }
+    }
+
+    /// The current `Instant` and wall-clock time, taken from the session's wall clock during
+    /// a replay
+    fn clock_now(&self, now: Instant) -> (Instant, OffsetDateTime) {
+        match &self.wall_clock {
+            Some(anchor) => (now, anchor.wall_at(now)),
+            None => (Instant::now(), OffsetDateTime::now_utc()),
         }
     }
 
@@ -107,26 +194,110 @@ impl TournamentManager {
             "{} Score by {color} player #{player_num}",
             self.status_string(now)
         );
-        self.current_game_stats.add_goal(
+        let period_time = self.game_clock_time(now);
+        self.current_game_stats
+            .add_goal(self.current_period, period_time, color, player_num, now);
+        self.recent_goal = period_time.map(|time| (color, player_num, self.current_period, time));
+        let before = self.scores;
+        let mut scores = self.scores;
+        scores[color] += 1;
+        self.apply_scores(scores, now);
+        self.record_score(color, player_num, period_time, now, before);
+        if let Err(e) = self.whistle(now, true) {
+            error!("Failed to stop the clock for the goal: {e}");
+        }
+    }
+
+    /// Disallow `color`'s `goal`th goal of the game, counting from zero, and take it back off
+    /// the score. The goal stays in the game stats, followed by an event saying why it was
+    /// disallowed.
+    pub fn disallow_goal(
+        &mut self,
+        color: Color,
+        goal: usize,
+        reason: GoalDisallowReason,
+        now: Instant,
+    ) -> Result<()> {
+        // Once sudden death starts any change to the score decides the game, so the goals
+        // from before it stand
+        if matches!(
             self.current_period,
-            self.game_clock_time(now),
-            color,
-            player_num,
-            now,
+            GamePeriod::BetweenGames | GamePeriod::SuddenDeath | GamePeriod::PenaltyShootout
+        ) {
+            return Err(TournamentManagerError::CantDisallowGoal(
+                self.current_period,
+            ));
+        }
+        let goals = self.current_game_stats.goals();
+        let scored = goals[color]
+            .get(goal)
+            .ok_or(TournamentManagerError::InvalidGoalIndex(color, goal))?;
+        if scored.disallowed.is_some() {
+            return Err(TournamentManagerError::GoalAlreadyDisallowed(color, goal));
+        }
+        info!(
+            "{} Disallowing {color} player #{}'s goal for {reason:?}",
+            self.status_string(now),
+            scored.player_number
         );
-        self.recent_goal = self
+        self.current_game_stats
+            .add_goal_disallowed(color, goal, reason, now);
+        if self
+            .recent_goal
+            .is_some_and(|(c, p, ..)| c == color && p == scored.player_number)
+        {
+            self.recent_goal = None;
+        }
+        self.recent_disallowed_goal = self
             .game_clock_time(now)
-            .map(|time| (color, player_num, self.current_period, time));
+            .map(|time| (color, scored.player_number, self.current_period, time));
+        let before = self.scores;
         let mut scores = self.scores;
-        scores[color] += 1;
-        self.set_scores(scores, now);
+        scores[color] = scores[color].saturating_sub(1);
+        // Not `set_scores`, which would put a hand edit of the score on the audit trail as well
+        self.record_edit(
+            AuditChange::GoalDisallowed {
+                color,
+                player_number: scored.player_number,
+                reason,
+                from: before,
+                to: scores,
+            },
+            AuditSource::DisallowedGoal,
+            now,
+        );
+        self.apply_scores(scores, now);
+        self.record_goal_disallowed(color, goal, scored.player_number, reason, now, before);
+        Ok(())
     }
 
     pub fn get_scores(&self) -> BlackWhiteBundle<u8> {
         self.scores
     }
 
+    /// Set the score by hand, either editing it or settling it while it is being confirmed. The
+    /// change goes on the game's audit trail.
     pub fn set_scores(&mut self, scores: BlackWhiteBundle<u8>, now: Instant) {
+        let confirmation = self.in_score_confirm_pause();
+        if confirmation || scores != self.scores {
+            let source = if confirmation {
+                AuditSource::Confirm
+            } else {
+                AuditSource::EditPage
+            };
+            self.record_edit(
+                AuditChange::Score {
+                    from: self.scores,
+                    to: scores,
+                },
+                source,
+                now,
+            );
+        }
+        self.apply_scores(scores, now);
+    }
+
+    fn apply_scores(&mut self, scores: BlackWhiteBundle<u8>, now: Instant) {
         self.scores = scores;
         info!("{} Scores set to {scores}", self.status_string(now));
 
@@ -142,6 +313,22 @@ impl TournamentManager {
         self.current_period
     }
 
+    /// Add a change made by hand to the audit trail of the game in progress. Changes made between
+    /// games belong to no game and are only logged.
+    fn record_edit(&mut self, change: AuditChange, source: AuditSource, now: Instant) {
+        if self.current_period == GamePeriod::BetweenGames {
+            return;
+        }
+        let period_time = self.game_clock_time(now);
+        self.current_game_stats
+            .add_edit(self.current_period, period_time, source, change, now);
+    }
+
+    /// The changes made by hand to the game in progress, in the order they were made
+    pub(crate) fn audit_trail(&self) -> Vec<AuditEntry> {
+        self.current_game_stats.audit_trail()
+    }
+
     pub fn config(&self) -> &GameConfig {
         &self.config
     }
@@ -159,10 +346,33 @@ impl TournamentManager {
         Ok(())
     }
 
+    /// The rule profile can only be changed between games
+    pub fn set_rules(&mut self, rules: RuleProfile) -> Result<()> {
+        if self.current_period != GamePeriod::BetweenGames {
+            return Err(TournamentManagerError::GameInProgress);
+        }
+        self.rules = rules;
+        Ok(())
+    }
+
     pub(crate) fn last_game_info(&self) -> Option<&LastGameInfo> {
         self.last_game_info.as_ref()
     }
 
+    /// Record the captains' sign-off of the result of the game that last ended
+    pub(crate) fn sign_off_result(
+        &mut self,
+        sign_offs: BlackWhiteBundle<TeamSignOff>,
+        now: Instant,
+    ) -> Result<()> {
+        let info = self
+            .last_game_info
+            .as_mut()
+            .ok_or(TournamentManagerError::NoResultToSignOff)?;
+        info.stats.set_sign_offs(sign_offs, now);
+        Ok(())
+    }
+
     pub fn clear_scheduled_game_start(&mut self) {
         self.next_scheduled_start = None;
     }
@@ -174,6 +384,7 @@ impl TournamentManager {
     pub fn clear_portal_next_game(&mut self) {
         self.next_game = None;
         self.next_scheduled_start = None;
+        self.catch_up = None;
     }
 
     /// Return to the fresh-manual-launch before-game state: forget any loaded next-game
@@ -246,13 +457,25 @@ impl TournamentManager {
         self.penalties.iter_mut().for_each(|(_, p)| p.clear());
         self.warnings.iter_mut().for_each(|(_, w)| w.clear());
         self.fouls.iter_mut().for_each(|(_, f)| f.clear());
+        self.shootout.clear();
         self.current_game_stats = GameStats::new(self.next_game_number());
         self.current_scheduled_start = None;
         self.has_reset = true;
     }
 
+    /// A timeout can't be started while the clock is held for the scores to be confirmed, as
+    /// the period it would belong to is over
+    fn check_not_confirming(&self) -> Result<()> {
+        if self.time_pause_confirmation.is_some() {
+            Err(TournamentManagerError::ConfirmingScores)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Returns `Ok` if timeout can be started, otherwise returns `Err` describing why not
     pub fn can_start_team_timeout(&self, color: Color) -> Result<()> {
+        self.check_not_confirming()?;
         if let Some(ts @ TimeoutState::Team(timeout_color, _)) = &self.timeout_state {
             if *timeout_color == color {
                 return Err(TournamentManagerError::AlreadyInTimeout(
@@ -280,6 +503,7 @@ impl TournamentManager {
 
     /// Returns `Ok` if timeout can be started, otherwise returns `Err` describing why not
     pub fn can_start_ref_timeout(&self) -> Result<()> {
+        self.check_not_confirming()?;
         if let Some(ts @ TimeoutState::Ref(_)) = &self.timeout_state {
             Err(TournamentManagerError::AlreadyInTimeout(
                 ts.as_snapshot(Instant::now()),
@@ -291,6 +515,7 @@ impl TournamentManager {
 
     /// Returns `Ok` if penalty shot can be started, otherwise returns `Err` describing why not
     pub fn can_start_penalty_shot(&self) -> Result<()> {
+        self.check_not_confirming()?;
         if let Some(ts @ TimeoutState::PenaltyShot(_)) = &self.timeout_state {
             return Err(TournamentManagerError::AlreadyInTimeout(
                 ts.as_snapshot(Instant::now()),
@@ -315,6 +540,7 @@ impl TournamentManager {
 
     /// Returns `Ok` if penalty shot can be started, otherwise returns `Err` describing why not
     pub fn can_start_rugby_penalty_shot(&self) -> Result<()> {
+        self.check_not_confirming()?;
         if let Some(ts @ TimeoutState::RugbyPenaltyShot(_)) = &self.timeout_state {
             return Err(TournamentManagerError::AlreadyInTimeout(
                 ts.as_snapshot(Instant::now()),
@@ -410,6 +636,7 @@ impl TournamentManager {
     pub fn start_team_timeout(&mut self, color: Color, now: Instant) -> Result<()> {
         self.can_start_team_timeout(color)?;
         info!("{} Starting a {color} timeout", self.status_string(now));
+        let before = self.clock_record();
         let cs = if self.clock_is_running() {
             self.stop_game_clock(now)?;
             ClockState::CountingDown {
@@ -423,6 +650,8 @@ impl TournamentManager {
         };
         self.timeout_state = Some(TimeoutState::Team(color, cs));
         self.timeouts_used[color] += 1;
+        self.record_timeout_start(now);
+        self.record_clock_change(before);
         Ok(())
     }
 
@@ -440,6 +669,7 @@ impl TournamentManager {
                 clock_time: Duration::ZERO,
             }));
         }
+        self.record_timeout_start(now);
         Ok(())
     }
 
@@ -457,6 +687,7 @@ impl TournamentManager {
                 clock_time: Duration::ZERO,
             }));
         }
+        self.record_timeout_start(now);
         Ok(())
     }
 
@@ -473,9 +704,22 @@ impl TournamentManager {
                 clock_time: self.config.penalty_shot_duration,
             }));
         }
+        self.record_timeout_start(now);
         Ok(())
     }
 
+    /// Put the timeout that just started on the scoresheet
+    pub(super) fn record_timeout_start(&mut self, now: Instant) {
+        if let Some(timeout) = &self.timeout_state {
+            self.current_game_stats.add_timeout(
+                timeout.sheet_kind(),
+                self.current_period,
+                self.game_clock_time(now),
+                now,
+            );
+        }
+    }
+
     pub fn switch_to_team_timeout(&mut self, new_color: Color) -> Result<()> {
         self.can_switch_to_team_timeout(new_color)?;
         info!("Switching to a {new_color} timeout");
@@ -485,6 +729,8 @@ impl TournamentManager {
         self.timeouts_used[new_color] += 1;
         self.timeouts_used[new_color.other()] =
             self.timeouts_used[new_color.other()].saturating_sub(1);
+        self.current_game_stats
+            .change_last_timeout(TimeoutKind::Team(new_color));
         Ok(())
     }
 
@@ -526,11 +772,21 @@ impl TournamentManager {
         if let Some(TimeoutState::PenaltyShot(cs)) = &self.timeout_state {
             self.timeout_state = Some(TimeoutState::Ref(cs.clone()));
         } else if let Some(TimeoutState::RugbyPenaltyShot(_)) = &self.timeout_state {
-            self.timeout_state = Some(TimeoutState::Ref(ClockState::CountingUp {
-                start_time: now,
-                time_at_start: Duration::ZERO,
-            }));
+            // The game clock runs during a rugby penalty shot, but not during a ref timeout
+            self.stop_game_clock(now)?;
+            if self.clock_is_running() {
+                self.timeout_state = Some(TimeoutState::Ref(ClockState::CountingUp {
+                    start_time: now,
+                    time_at_start: Duration::ZERO,
+                }));
+            } else {
+                self.timeout_state = Some(TimeoutState::Ref(ClockState::Stopped {
+                    clock_time: Duration::ZERO,
+                }));
+            }
         }
+        self.current_game_stats
+            .change_last_timeout(TimeoutKind::Ref);
         Ok(())
     }
 
@@ -540,6 +796,8 @@ impl TournamentManager {
         if let Some(TimeoutState::Ref(cs)) = &self.timeout_state {
             self.timeout_state = Some(TimeoutState::PenaltyShot(cs.clone()));
         }
+        self.current_game_stats
+            .change_last_timeout(TimeoutKind::PenaltyShot);
         Ok(())
     }
 
@@ -560,6 +818,8 @@ impl TournamentManager {
 
             self.timeout_state = Some(TimeoutState::RugbyPenaltyShot(new_cs));
         }
+        self.current_game_stats
+            .change_last_timeout(TimeoutKind::PenaltyShot);
         Ok(())
     }
 
@@ -570,14 +830,14 @@ impl TournamentManager {
             self.timeout_state
         {
             if let ClockState::Stopped { clock_time } = self.clock_state {
+                let tie_continues = if self.current_period == GamePeriod::OvertimeSecondHalf {
+                    self.overtime_tie_continues()
+                } else {
+                    self.regulation_tie_continues()
+                };
                 return Ok(clock_time.is_zero()
-                    && ((self.current_period == GamePeriod::SecondHalf
-                        && (self.scores.are_not_equal()
-                            || (!self.config.overtime_allowed
-                                && !self.config.sudden_death_allowed)))
-                        || (self.current_period == GamePeriod::OvertimeSecondHalf
-                            && (self.scores.are_not_equal()
-                                || !self.config.sudden_death_allowed))));
+                    && self.in_final_half()
+                    && (self.scores.are_not_equal() || !tie_continues));
             } else if let ClockState::CountingDown {
                 start_time,
                 time_remaining_at_start,
@@ -682,6 +942,7 @@ impl TournamentManager {
                     }
                 }
                 self.timeouts_used[color] = self.timeouts_used[color].saturating_sub(1);
+                self.current_game_stats.remove_last_timeout();
                 Ok(())
             }
             _ => Err(TournamentManagerError::NotInTimeout),
@@ -712,21 +973,31 @@ impl TournamentManager {
             start_instant: now,
             infraction,
         };
-        self.penalties[color].push(penalty);
-        Ok(())
+        self.penalties[color].push(penalty.clone());
+        self.record_penalty(color, penalty);
+        self.whistle(now, true)
     }
 
     pub fn delete_penalty(&mut self, color: Color, index: usize) -> Result<()> {
         if self.penalties[color].len() < index + 1 {
             return Err(TournamentManagerError::InvalidPenIndex(color, index));
         }
+        let now = Instant::now();
         let pen = self.penalties[color].remove(index);
         info!(
             "{} Deleting {color} player #{}'s {:?} penalty",
-            self.status_string(Instant::now()),
+            self.status_string(now),
             pen.player_number,
             pen.kind
         );
+        self.record_edit(
+            AuditChange::Penalty {
+                from: Some(AuditPenalty::of(&pen, color)),
+                to: None,
+            },
+            AuditSource::EditPage,
+            now,
+        );
 
         Ok(())
     }
@@ -740,7 +1011,8 @@ impl TournamentManager {
         new_kind: PenaltyKind,
         new_infraction: Infraction,
     ) -> Result<()> {
-        let status_str = self.status_string(Instant::now());
+        let now = Instant::now();
+        let status_str = self.status_string(now);
         let penalty = self.penalties[old_color]
             .get_mut(index)
             .ok_or(TournamentManagerError::InvalidPenIndex(old_color, index))?;
@@ -750,13 +1022,25 @@ impl TournamentManager {
             penalty.player_number, penalty.kind
         );
 
+        let from = AuditPenalty::of(penalty, old_color);
         penalty.player_number = new_player_number;
         penalty.kind = new_kind;
         penalty.infraction = new_infraction;
+        let to = AuditPenalty::of(penalty, new_color);
         if old_color != new_color {
             let penalty = self.penalties[old_color].remove(index);
             self.penalties[new_color].push(penalty);
         }
+        if from != to {
+            self.record_edit(
+                AuditChange::Penalty {
+                    from: Some(from),
+                    to: Some(to),
+                },
+                AuditSource::EditPage,
+                now,
+            );
+        }
         Ok(())
     }
 
@@ -769,7 +1053,7 @@ impl TournamentManager {
         while self.penalties[color].len() > limit {
             let mut index = None;
             'inner: for (i, pen) in self.penalties[color].iter().enumerate() {
-                if pen.is_complete(period, time, &self.config)? {
+                if pen.is_complete(period, time, &self.config, &self.rules.penalties)? {
                     index = Some(i);
                     break 'inner;
                 }
@@ -796,7 +1080,10 @@ impl TournamentManager {
         for color in [Color::Black, Color::White] {
             let keep = self.penalties[color]
                 .iter()
-                .map(|pen| pen.is_complete(period, time, &self.config).map(|k| !k))
+                .map(|pen| {
+                    pen.is_complete(period, time, &self.config, &self.rules.penalties)
+                        .map(|k| !k)
+                })
                 .collect::<PenaltyResult<Vec<_>>>()?;
             let mut i = 0;
             self.penalties[color].retain(|pen| {
@@ -827,7 +1114,7 @@ impl TournamentManager {
             "{} Adding {color} {} warning for {}",
             self.status_string(now),
             print_p_num_warn(player_number),
-            infraction.short_name()
+            self.rules.log_name(infraction)
         );
         let start_time = self
             .game_clock_time(now)
@@ -841,7 +1128,7 @@ impl TournamentManager {
             infraction,
         };
         self.warnings[color].push(warning);
-        Ok(())
+        self.whistle(now, false)
     }
 
     pub fn add_foul(
@@ -856,7 +1143,7 @@ impl TournamentManager {
             self.status_string(now),
             print_color(color),
             print_p_num_foul(player_number),
-            infraction.short_name()
+            self.rules.log_name(infraction)
         );
         let start_time = self
             .game_clock_time(now)
@@ -869,7 +1156,35 @@ impl TournamentManager {
             start_instant: now,
             infraction,
         };
-        self.fouls[color].push(foul);
+        self.fouls[color].push(foul.clone());
+        self.record_foul(color, foul);
+        self.whistle(now, true)
+    }
+
+    /// File a referee's incident report against the game in progress
+    pub(crate) fn add_incident(&mut self, incident: IncidentDetails) -> Result<()> {
+        if self.current_period == GamePeriod::BetweenGames {
+            return Err(TournamentManagerError::NoGameInProgress);
+        }
+        info!(
+            "{} Adding {:?} incident report{}{}",
+            self.status_string(incident.start_instant),
+            incident.category,
+            incident
+                .color
+                .map(|color| format!(" for {color}"))
+                .unwrap_or_default(),
+            print_p_num_foul(incident.player_number)
+        );
+        self.current_game_stats.add_incident(IncidentReport {
+            category: incident.category,
+            color: incident.color,
+            player_number: incident.player_number,
+            game_period: incident.start_period,
+            period_time: incident.start_time,
+            occurred_on: calculate_timestamp(incident.start_instant),
+            description: incident.description,
+        });
         Ok(())
     }
 
@@ -897,9 +1212,9 @@ impl TournamentManager {
             "{status_str} Editing {old_color} {} warning for {}: \
             it is now {new_color} {} warning for {}",
             print_p_num_warn(warning.player_number),
-            warning.infraction.short_name(),
+            self.rules.log_name(warning.infraction),
             print_p_num_warn(new_player_number),
-            new_infraction.short_name()
+            self.rules.log_name(new_infraction)
         );
 
         warning.player_number = new_player_number;
@@ -930,10 +1245,10 @@ impl TournamentManager {
             it is now {}{} foul for {}",
             print_color(old_color),
             print_p_num_foul(foul.player_number),
-            foul.infraction.short_name(),
+            self.rules.log_name(foul.infraction),
             print_color(new_color),
             print_p_num_foul(new_player_number),
-            new_infraction.short_name()
+            self.rules.log_name(new_infraction)
         );
 
         foul.player_number = new_player_number;
@@ -956,7 +1271,7 @@ impl TournamentManager {
             "{} Deleting {color} {} warning for {}",
             self.status_string(Instant::now()),
             print_p_num_warn(warning.player_number),
-            warning.infraction.short_name()
+            self.rules.log_name(warning.infraction)
         );
 
         Ok(())
@@ -972,7 +1287,7 @@ impl TournamentManager {
             self.status_string(Instant::now()),
             print_color(color),
             print_p_num_foul(foul.player_number),
-            foul.infraction.short_name()
+            self.rules.log_name(foul.infraction)
         );
 
         Ok(())
@@ -984,7 +1299,7 @@ impl TournamentManager {
     /// known (manual mode before the first game).
     fn next_game_scheduled_start(&self, now: Instant) -> Option<Instant> {
         if let Some(start_time) = self.next_game.as_ref().and_then(|info| info.start_time) {
-            let delta = start_time - OffsetDateTime::now_utc(); // signed time::Duration
+            let delta = start_time - self.clock_now(now).1; // signed time::Duration
             if delta.is_negative() {
                 Some(now.checked_sub(delta.unsigned_abs()).unwrap_or(now))
             } else {
@@ -1003,7 +1318,7 @@ impl TournamentManager {
         let scheduled_start = if let Some(start_time) =
             self.next_game.as_ref().and_then(|info| info.start_time)
         {
-            let cur_time = OffsetDateTime::now_utc();
+            let (cur_instant, cur_time) = self.clock_now(now);
             info!("Current time is: {cur_time}");
             info!("Start time is: {start_time}");
 
@@ -1013,7 +1328,7 @@ impl TournamentManager {
             match time_to_game.try_into() {
                 // Guard against Instant overflow on an absurd scheduled time;
                 // fall back to `now` (matching the conversion-error arm below).
-                Ok(dur) => Instant::now().checked_add(dur).unwrap_or(now),
+                Ok(dur) => cur_instant.checked_add(dur).unwrap_or(now),
                 Err(e) => {
                     warn!(
                         "Next game start time is in the past ({e}); using current time as anchor"
@@ -1050,6 +1365,38 @@ impl TournamentManager {
         }
     }
 
+    /// The config for a game played under a portal timing rule. The rule's stop-time flag is
+    /// only displayed (see ADR 022), so the local stop-time and shootout settings stay in force.
+    /// A half-time shortened by the accepted catch-up plan replaces the rule's.
+    fn config_from_timing(&self, timing: TimingRule, game_number: &GameNumber) -> GameConfig {
+        let mut config = timing.game_config(&self.config);
+        Self::normalize_degenerate_overtime(&mut config);
+        if let Some(half_time) = self
+            .catch_up
+            .as_ref()
+            .and_then(|plan| plan.game(game_number)?.half_time)
+        {
+            info!("Catch-up plan shortens game {game_number}'s half-time to {half_time:?}");
+            config.half_time_duration = half_time;
+        }
+        config
+    }
+
+    /// Accept a catch-up plan, replacing any earlier one, or drop it with `None`. Breaks shrink
+    /// on their own, so a plan that doesn't shorten any half-times is rejected and any earlier
+    /// plan stays in force.
+    pub fn set_catch_up_plan(&mut self, plan: Option<CatchUpPlan>) -> Result<()> {
+        if plan
+            .as_ref()
+            .is_some_and(|plan| !plan.shortens_half_times())
+        {
+            return Err(TournamentManagerError::CatchUpPlanOnlyCutsBreaks);
+        }
+        info!("Catch-up plan set to {plan:?}");
+        self.catch_up = plan;
+        Ok(())
+    }
+
     pub fn apply_next_game_start(&mut self, now: Instant) -> Result<()> {
         if self.current_period != GamePeriod::BetweenGames {
             return Err(TournamentManagerError::GameInProgress);
@@ -1062,9 +1409,7 @@ impl TournamentManager {
         };
 
         if let Some(ref timing) = next_game_info.timing {
-            let mut config: GameConfig = timing.clone().into();
-            Self::normalize_degenerate_overtime(&mut config);
-            self.config = config;
+            self.config = self.config_from_timing(timing.clone(), &next_game_info.number);
         }
 
         let time_remaining_at_start = self.calc_time_to_next_game(now, now);
@@ -1100,6 +1445,18 @@ impl TournamentManager {
             }
         }
 
+        // A penalty given before the game was served during it, but between games it would read
+        // as not started yet, so it's moved to the start of the game once the stats have it
+        for (_, penalties) in self.penalties.iter_mut() {
+            for penalty in penalties
+                .iter_mut()
+                .filter(|penalty| penalty.start_period == GamePeriod::BetweenGames)
+            {
+                penalty.start_period = GamePeriod::FirstHalf;
+                penalty.start_time = self.config.half_play_duration;
+            }
+        }
+
         // Only fully-attributed fouls (a specific player AND a specific team) are sent to the
         // portal: the portal currently rejects a foul with a null player or side, which 400s the
         // whole upload. Team fouls (no player) and "both teams at fault" fouls (no team) are held
@@ -1112,6 +1469,18 @@ impl TournamentManager {
             }
         }
 
+        // The scoresheet has every foul and warning, whatever the portal takes
+        for color in [Some(Color::Black), Some(Color::White), None] {
+            for foul in self.fouls[color].iter() {
+                self.current_game_stats.add_sheet_foul(foul, color);
+            }
+        }
+        for color in [Color::Black, Color::White] {
+            for warning in self.warnings[color].iter() {
+                self.current_game_stats.add_warning(warning, color);
+            }
+        }
+
         self.current_game_stats.add_end_time(now);
         self.last_game_info = Some(LastGameInfo {
             game_number: self.game_number.clone(),
@@ -1146,6 +1515,8 @@ impl TournamentManager {
             start_time: game_end,
             time_remaining_at_start,
         };
+        // A timeout still running when a sudden-death score is entered ends with the game
+        self.timeout_state = None;
 
         if !was_running {
             self.send_clock_running(true);
@@ -1179,9 +1550,10 @@ impl TournamentManager {
 
         self.game_number = self.next_game_number();
 
-        if let Some(timing) = self.next_game.take().and_then(|info| info.timing) {
-            self.config = timing.into();
-            Self::normalize_degenerate_overtime(&mut self.config);
+        if let Some(info) = self.next_game.take()
+            && let Some(timing) = info.timing
+        {
+            self.config = self.config_from_timing(timing, &info.number);
         }
 
         info!(
@@ -1190,7 +1562,7 @@ impl TournamentManager {
             self.game_number
         );
         self.current_game_stats.add_start_time(start_time);
-        self.current_period = GamePeriod::FirstHalf;
+        self.enter_period(GamePeriod::FirstHalf, start_time);
         self.game_start_time = start_time;
         self.timeouts_used.black = 0;
         self.timeouts_used.white = 0;
@@ -1220,6 +1592,23 @@ impl TournamentManager {
                 }
             };
 
+            // The game clock can also run out in the same tick that a team timeout ends
+            if let Some(TimeoutState::Team(
+                _,
+                ClockState::CountingDown {
+                    start_time,
+                    time_remaining_at_start,
+                },
+            )) = self.timeout_state
+                && let ClockState::Stopped { clock_time } = self.clock_state
+            {
+                return self.check_time_remaining(
+                    now,
+                    start_time,
+                    time_remaining_at_start + clock_time,
+                );
+            }
+
             if let ClockState::CountingUp { .. } = self.clock_state {
                 if (self.current_period == GamePeriod::SuddenDeath) & (self.scores.are_not_equal())
                 {
@@ -1255,9 +1644,16 @@ impl TournamentManager {
             .checked_duration_since(start_time)
             .ok_or(TournamentManagerError::InvalidNowValue)?;
 
-        Ok(time >= time_remaining_at_start
-            && (self.current_period == GamePeriod::SecondHalf
-                || (self.current_period == GamePeriod::OvertimeSecondHalf)))
+        Ok(time >= time_remaining_at_start && self.in_final_half())
+    }
+
+    /// Whether the game can end when the current period runs out
+    fn in_final_half(&self) -> bool {
+        match self.current_period {
+            GamePeriod::FirstHalf => self.config.single_half,
+            GamePeriod::SecondHalf | GamePeriod::OvertimeSecondHalf => true,
+            _ => false,
+        }
     }
 
     pub fn pause_has_ended(&self, now: Instant) -> bool {
@@ -1327,7 +1723,7 @@ impl TournamentManager {
                     }
                     (GamePeriod::HalfTime, _) => {
                         info!("{} Entering second half", self.status_string(now));
-                        self.current_period = GamePeriod::SecondHalf;
+                        self.enter_period(GamePeriod::SecondHalf, now);
                         if self.config.timeouts_counted_per_half {
                             self.timeouts_used.white = 0;
                             self.timeouts_used.black = 0;
@@ -1346,7 +1742,7 @@ impl TournamentManager {
                     }
                     (GamePeriod::PreOvertime, _) => {
                         info!("{} Entering overtime first half", self.status_string(now));
-                        self.current_period = GamePeriod::OvertimeFirstHalf;
+                        self.enter_period(GamePeriod::OvertimeFirstHalf, now);
                         need_cull = true;
                     }
                     (GamePeriod::OvertimeFirstHalf, false) => {
@@ -1361,7 +1757,7 @@ impl TournamentManager {
                     }
                     (GamePeriod::OvertimeHalfTime, _) => {
                         info!("{} Entering overtime second half", self.status_string(now));
-                        self.current_period = GamePeriod::OvertimeSecondHalf;
+                        self.enter_period(GamePeriod::OvertimeSecondHalf, now);
                         need_cull = true;
                     }
                     (GamePeriod::OvertimeSecondHalf, false) => {
@@ -1376,7 +1772,7 @@ impl TournamentManager {
                     }
                     (GamePeriod::PreSuddenDeath, _) => {
                         info!("{} Entering sudden death", self.status_string(now));
-                        self.current_period = GamePeriod::SuddenDeath;
+                        self.enter_period(GamePeriod::SuddenDeath, now);
                         need_cull = true;
                     }
                     (GamePeriod::SuddenDeath, _) => {
@@ -1385,9 +1781,17 @@ impl TournamentManager {
                             self.status_string(now)
                         )
                     }
+                    (GamePeriod::PenaltyShootout, _) => {
+                        error!(
+                            "{} Impossible state: in penalty shootout with clock counting down",
+                            self.status_string(now)
+                        )
+                    }
                 }
                 if leave_game_clock_running {
-                    if self.current_period != GamePeriod::BetweenGames {
+                    if self.current_period != GamePeriod::BetweenGames
+                        && self.current_period != GamePeriod::PenaltyShootout
+                    {
                         self.clock_state = if self.current_period != GamePeriod::SuddenDeath {
                             ClockState::CountingDown {
                                 start_time: start_time + time_remaining_at_start,
@@ -1413,6 +1817,15 @@ impl TournamentManager {
                 }
             }
         } else if let ClockState::CountingUp { .. } = self.clock_state {
+            // A rugby penalty shot in sudden death runs alongside the game clock
+            if let Some(TimeoutState::RugbyPenaltyShot(ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start,
+            })) = self.timeout_state
+                && now.duration_since(start_time) >= time_remaining_at_start
+            {
+                self.handle_rugby_pen_shot_end(now, start_time, time_remaining_at_start)?;
+            }
             // In sudden death, check if in socre confirmation pause
             if self.time_pause_confirmation.is_some() & self.pause_has_ended(now) {
                 self.end_confirm_pause(now)?;
@@ -1460,16 +1873,25 @@ impl TournamentManager {
                 },
                 Some(TimeoutState::Ref(_)) | Some(TimeoutState::PenaltyShot(_)) | None => {}
             };
+            if self.timeout_state.is_none() && self.clock_state.clock_time(now).is_none() {
+                // The clock restarted at the end of the timeout has already run out as well
+                return self.update(now);
+            }
         };
 
         Ok(())
     }
 
+    /// Move on to `period`, noting when it began for the timeline and the scoresheet
+    fn enter_period(&mut self, period: GamePeriod, now: Instant) {
+        self.current_period = period;
+        self.current_game_stats
+            .add_period_start(period, period.duration(&self.config), now);
+    }
+
     fn end_first_half(&mut self, now: Instant) {
         if self.config.single_half {
-            if self.scores.are_not_equal()
-                || (!self.config.overtime_allowed && !self.config.sudden_death_allowed)
-            {
+            if self.scores.are_not_equal() || !self.regulation_tie_continues() {
                 self.end_game(now);
             } else if self.config.overtime_allowed {
                 info!(
@@ -1477,25 +1899,25 @@ impl TournamentManager {
                     self.status_string(now),
                     self.scores
                 );
-                self.current_period = GamePeriod::PreOvertime;
-            } else {
+                self.enter_period(GamePeriod::PreOvertime, now);
+            } else if self.config.sudden_death_allowed {
                 info!(
                     "{} Entering pre-sudden death. Score is {}",
                     self.status_string(now),
                     self.scores
                 );
-                self.current_period = GamePeriod::PreSuddenDeath;
+                self.enter_period(GamePeriod::PreSuddenDeath, now);
+            } else {
+                self.enter_penalty_shootout(now);
             }
         } else {
             info!("{} Entering half time", self.status_string(now));
-            self.current_period = GamePeriod::HalfTime;
+            self.enter_period(GamePeriod::HalfTime, now);
         }
     }
 
     fn end_second_half(&mut self, now: Instant) {
-        if self.scores.are_not_equal()
-            || (!self.config.overtime_allowed && !self.config.sudden_death_allowed)
-        {
+        if self.scores.are_not_equal() || !self.regulation_tie_continues() {
             self.end_game(now);
         } else if self.config.overtime_allowed {
             info!(
@@ -1503,32 +1925,64 @@ impl TournamentManager {
                 self.status_string(now),
                 self.scores
             );
-            self.current_period = GamePeriod::PreOvertime;
-        } else {
+            self.enter_period(GamePeriod::PreOvertime, now);
+        } else if self.config.sudden_death_allowed {
             info!(
                 "{} Entering pre-sudden death. Score is {}",
                 self.status_string(now),
                 self.scores
             );
-            self.current_period = GamePeriod::PreSuddenDeath;
+            self.enter_period(GamePeriod::PreSuddenDeath, now);
+        } else {
+            self.enter_penalty_shootout(now);
         }
     }
 
     fn end_overtime_first_half(&mut self, now: Instant) {
         info!("{} Entering overtime half time", self.status_string(now));
-        self.current_period = GamePeriod::OvertimeHalfTime;
+        self.enter_period(GamePeriod::OvertimeHalfTime, now);
     }
 
     fn end_overtime_second_half(&mut self, now: Instant) {
-        if self.scores.are_not_equal() || !self.config.sudden_death_allowed {
+        if self.scores.are_not_equal() || !self.overtime_tie_continues() {
             self.end_game(now);
-        } else {
+        } else if self.config.sudden_death_allowed {
             info!(
                 "{} Entering pre-sudden death. Score is {}",
                 self.status_string(now),
                 self.scores
             );
-            self.current_period = GamePeriod::PreSuddenDeath;
+            self.enter_period(GamePeriod::PreSuddenDeath, now);
+        } else {
+            self.enter_penalty_shootout(now);
+        }
+    }
+
+    /// Whether a tie at the end of regulation play is settled by a later period
+    fn regulation_tie_continues(&self) -> bool {
+        self.config.overtime_allowed || self.overtime_tie_continues()
+    }
+
+    /// Whether a tie at the end of overtime is settled by a later period
+    fn overtime_tie_continues(&self) -> bool {
+        self.config.sudden_death_allowed || self.config.penalty_shootout_allowed
+    }
+
+    /// The shootout has no game clock, so it is left stopped at zero until the game ends
+    fn enter_penalty_shootout(&mut self, now: Instant) {
+        info!(
+            "{} Entering penalty shootout. Score is {}",
+            self.status_string(now),
+            self.scores
+        );
+        let was_running = self.clock_is_running();
+        self.enter_period(GamePeriod::PenaltyShootout, now);
+        self.clock_state = ClockState::Stopped {
+            clock_time: Duration::ZERO,
+        };
+        self.shootout.clear();
+        if was_running {
+            self.send_clock_running(false);
         }
     }
 
@@ -1543,7 +1997,29 @@ impl TournamentManager {
             self.status_string(now)
         );
         if let ClockState::Stopped { clock_time } = self.clock_state {
-            if clock_time == Duration::ZERO {
+            let resume_at = min(now, start_time + time_remaining_at_start);
+            if self.current_period == GamePeriod::SuddenDeath {
+                // The shot was switched to from a referee timeout, which stopped the clock. In
+                // sudden death it carries on counting up from there.
+                info!(
+                    "{} Restarting the game clock after the penalty shot",
+                    self.status_string(now)
+                );
+                self.clock_state = ClockState::CountingUp {
+                    start_time: resume_at,
+                    time_at_start: clock_time,
+                }
+            } else if clock_time != Duration::ZERO {
+                // The shot was switched to from a referee timeout, which stopped the clock
+                info!(
+                    "{} Restarting the game clock after the penalty shot",
+                    self.status_string(now)
+                );
+                self.clock_state = ClockState::CountingDown {
+                    start_time: resume_at,
+                    time_remaining_at_start: clock_time,
+                }
+            } else {
                 match self.current_period {
                     GamePeriod::FirstHalf => {
                         self.end_first_half(now);
@@ -1568,7 +2044,8 @@ impl TournamentManager {
                     | GamePeriod::HalfTime
                     | GamePeriod::PreOvertime
                     | GamePeriod::OvertimeHalfTime
-                    | GamePeriod::PreSuddenDeath => {
+                    | GamePeriod::PreSuddenDeath
+                    | GamePeriod::PenaltyShootout => {
                         error!(
                             "{} Impossible state: penalty shot ended during non-play period",
                             self.status_string(now)
@@ -1576,10 +2053,19 @@ impl TournamentManager {
                         return Err(TournamentManagerError::InvalidState);
                     }
                 }
-            }
-            self.clock_state = ClockState::CountingDown {
-                start_time: min(now, start_time + time_remaining_at_start),
-                time_remaining_at_start: self.current_period.duration(&self.config).unwrap(),
+                // The end of the period may also have ended the game
+                if !matches!(
+                    self.current_period,
+                    GamePeriod::BetweenGames | GamePeriod::PenaltyShootout
+                ) {
+                    self.clock_state = ClockState::CountingDown {
+                        start_time: resume_at,
+                        time_remaining_at_start: self
+                            .current_period
+                            .duration(&self.config)
+                            .unwrap(),
+                    }
+                }
             }
         }
         self.timeout_state = None;
@@ -1594,6 +2080,9 @@ impl TournamentManager {
     // Returns true if the clock was started, false if it was already running
     fn start_game_clock(&mut self, now: Instant) -> bool {
         if let ClockState::Stopped { clock_time } = self.clock_state {
+            if self.current_period == GamePeriod::PenaltyShootout {
+                return false;
+            }
             info!("{} Starting the game clock", self.status_string(now));
             match self.current_period {
                 GamePeriod::SuddenDeath => {
@@ -1637,6 +2126,15 @@ impl TournamentManager {
     }
 
     pub fn start_clock(&mut self, now: Instant) {
+        if self.time_pause_confirmation.is_some() {
+            // The pause holds the clock until the scores are confirmed
+            info!(
+                "{} Not starting the clock during the confirmation pause",
+                self.status_string(now)
+            );
+            return;
+        }
+        let before = self.clock_record();
         let mut need_to_send = false;
         let status_str = self.status_string(now);
         match &mut self.timeout_state {
@@ -1680,9 +2178,18 @@ impl TournamentManager {
         if need_to_send {
             self.send_clock_running(true);
         }
+        self.record_clock_change(before);
     }
 
     pub fn stop_clock(&mut self, now: Instant) -> Result<()> {
+        let before = self.clock_record();
+        self.stop_clocks(now)?;
+        self.record_clock_change(before);
+        Ok(())
+    }
+
+    /// Stops whichever clock is running, without recording the change in the undo history
+    fn stop_clocks(&mut self, now: Instant) -> Result<()> {
         let mut need_to_send = false;
         let status_str = self.status_string(now);
         match &mut self.timeout_state {
@@ -1701,11 +2208,14 @@ impl TournamentManager {
             Some(TimeoutState::RugbyPenaltyShot(cs)) => {
                 if let ClockState::CountingDown { .. } = cs {
                     info!("{status_str} Stopping the timeout clock");
-                    *cs = ClockState::Stopped {
-                        clock_time: cs
-                            .clock_time(now)
-                            .ok_or(TournamentManagerError::NeedsUpdate)?,
-                    };
+                    let clock_time = cs
+                        .clock_time(now)
+                        .ok_or(TournamentManagerError::NeedsUpdate)?;
+                    // Check the game clock before touching either, so that both stop or neither
+                    self.clock_state
+                        .clock_time(now)
+                        .ok_or(TournamentManagerError::NeedsUpdate)?;
+                    *cs = ClockState::Stopped { clock_time };
                     if !self.stop_game_clock(now)? {
                         warn!(
                             "{status_str} Stopping the penalty shot clock, but the game clock was not running"
@@ -1732,6 +2242,28 @@ impl TournamentManager {
         Ok(())
     }
 
+    /// Whether the game clock is inside the stop-time window at the end of a play period
+    pub fn in_stop_time_window(&self, now: Instant) -> bool {
+        self.game_clock_time(now)
+            .is_some_and(|time| self.config.in_stop_time_window(self.current_period, time))
+    }
+
+    /// Stops the game clock for a whistle inside the stop-time window. The operator restarts it
+    /// when play resumes. With `with_last_action`, undoing the action just recorded also undoes
+    /// the stop.
+    fn whistle(&mut self, now: Instant, with_last_action: bool) -> Result<()> {
+        if self.timeout_state.is_none()
+            && self.clock_state.is_running()
+            && self.in_stop_time_window(now)
+        {
+            info!("{} Whistle during stop time", self.status_string(now));
+            let before = self.clock_record();
+            self.stop_clocks(now)?;
+            self.record_whistle(before, with_last_action);
+        }
+        Ok(())
+    }
+
     pub fn halt_clock(&mut self, now: Instant, mut end_timeout: bool) -> Result<()> {
         if end_timeout {
             self.timeout_state = None;
@@ -1826,13 +2358,16 @@ impl TournamentManager {
             | GamePeriod::SecondHalf
             | GamePeriod::OvertimeFirstHalf
             | GamePeriod::OvertimeSecondHalf
-            | GamePeriod::SuddenDeath => return Err(TournamentManagerError::AlreadyInPlayPeriod),
+            | GamePeriod::SuddenDeath
+            | GamePeriod::PenaltyShootout => {
+                return Err(TournamentManagerError::AlreadyInPlayPeriod);
+            }
             GamePeriod::BetweenGames => {
                 self.start_game(now);
             }
             GamePeriod::HalfTime => {
                 info!("{} Entering second half", self.status_string(now));
-                self.current_period = GamePeriod::SecondHalf;
+                self.enter_period(GamePeriod::SecondHalf, now);
                 if self.config.timeouts_counted_per_half {
                     self.timeouts_used.white = 0;
                     self.timeouts_used.black = 0;
@@ -1841,17 +2376,17 @@ impl TournamentManager {
             }
             GamePeriod::PreOvertime => {
                 info!("{} Entering overtime first half", self.status_string(now));
-                self.current_period = GamePeriod::OvertimeFirstHalf;
+                self.enter_period(GamePeriod::OvertimeFirstHalf, now);
                 need_cull = true;
             }
             GamePeriod::OvertimeHalfTime => {
                 info!("{} Entering overtime second half", self.status_string(now));
-                self.current_period = GamePeriod::OvertimeSecondHalf;
+                self.enter_period(GamePeriod::OvertimeSecondHalf, now);
                 need_cull = true;
             }
             GamePeriod::PreSuddenDeath => {
                 info!("{} Entering sudden death", self.status_string(now));
-                self.current_period = GamePeriod::SuddenDeath;
+                self.enter_period(GamePeriod::SuddenDeath, now);
                 need_cull = true;
             }
         }
@@ -1886,34 +2421,140 @@ impl TournamentManager {
         Ok(())
     }
 
-    pub fn set_game_clock_time(&mut self, clock_time: Duration) -> Result<()> {
-        if !self.clock_is_running() {
-            let time = clock_time.as_secs_f64();
-            info!(
-                "Setting game clock to {:02.0}:{:06.3}",
-                (time / 60.0).floor(),
-                time % 60.0
-            );
-
-            for pen in self
-                .penalties
-                .black
-                .iter_mut()
-                .chain(self.penalties.white.iter_mut())
-            {
-                if (pen.kind != PenaltyKind::TotalDismissal)
-                    && (pen.time_remaining(self.current_period, clock_time, &self.config)?
-                        > pen.kind.as_duration().unwrap())
-                {
-                    pen.start_period = self.current_period;
-                    pen.start_time = clock_time;
-                }
+    /// Settle a tie in sudden death with a penalty shootout instead
+    pub fn start_penalty_shootout(&mut self, now: Instant) -> Result<()> {
+        if !self.config.penalty_shootout_allowed {
+            return Err(TournamentManagerError::ShootoutNotAllowed);
+        }
+        if let Some(ref ts) = self.timeout_state {
+            return Err(TournamentManagerError::AlreadyInTimeout(
+                ts.as_snapshot(Instant::now()),
+            ));
+        }
+        match self.current_period {
+            GamePeriod::PreSuddenDeath | GamePeriod::SuddenDeath => {
+                self.enter_penalty_shootout(now);
+                Ok(())
             }
-
-            self.clock_state = ClockState::Stopped { clock_time };
-            Ok(())
-        } else {
-            Err(TournamentManagerError::ClockIsRunning)
+            _ => Err(TournamentManagerError::NotInSuddenDeath),
+        }
+    }
+
+    /// Record a shootout attempt. Once the shootout is decided the winning team is awarded
+    /// one goal, as in sudden death, and the game ends.
+    pub fn add_shootout_attempt(
+        &mut self,
+        color: Color,
+        player_num: u8,
+        scored: bool,
+        now: Instant,
+    ) -> Result<()> {
+        if self.current_period != GamePeriod::PenaltyShootout {
+            return Err(TournamentManagerError::NotInShootout);
+        }
+        let rounds = self.config.penalty_shootout_rounds;
+        if let Some(next) = self.shootout.next_shooter(rounds)
+            && next != color
+        {
+            return Err(TournamentManagerError::ShootoutOutOfTurn(next));
+        }
+
+        let round = self.shootout.round_of_next(color);
+        info!(
+            "{} Shootout round {round}: {color} player #{player_num} {}",
+            self.status_string(now),
+            if scored { "scored" } else { "missed" }
+        );
+        self.current_game_stats
+            .add_shootout_attempt(color, round, player_num, scored, now);
+        self.shootout.push(
+            color,
+            ShootoutAttempt {
+                player_number: player_num,
+                scored,
+                instant: now,
+            },
+        );
+
+        if let Some(winner) = self.shootout.winner(rounds) {
+            let tally = self.shootout.tally();
+            info!(
+                "{} {winner} wins the shootout {}-{}",
+                self.status_string(now),
+                tally.black,
+                tally.white
+            );
+            self.scores[winner] += 1;
+            self.end_game(now);
+        }
+        Ok(())
+    }
+
+    /// Remove the most recent shootout attempt, as long as the shootout is still in progress
+    pub fn remove_last_shootout_attempt(&mut self, now: Instant) -> Result<()> {
+        if self.current_period != GamePeriod::PenaltyShootout {
+            return Err(TournamentManagerError::NotInShootout);
+        }
+        let (color, attempt) = self
+            .shootout
+            .pop()
+            .ok_or(TournamentManagerError::NoShootoutAttempts)?;
+        let round = self.shootout.round_of_next(color);
+        info!(
+            "{} Removed shootout round {round} attempt by {color} player #{}",
+            self.status_string(now),
+            attempt.player_number
+        );
+        self.current_game_stats
+            .remove_shootout_attempt(color, round);
+        Ok(())
+    }
+
+    pub fn set_game_clock_time(&mut self, clock_time: Duration) -> Result<()> {
+        if !self.clock_is_running() {
+            let time = clock_time.as_secs_f64();
+            info!(
+                "Setting game clock to {:02.0}:{:06.3}",
+                (time / 60.0).floor(),
+                time % 60.0
+            );
+
+            for pen in self
+                .penalties
+                .black
+                .iter_mut()
+                .chain(self.penalties.white.iter_mut())
+            {
+                if (pen.kind != PenaltyKind::TotalDismissal)
+                    && (pen.time_remaining(
+                        self.current_period,
+                        clock_time,
+                        &self.config,
+                        &self.rules.penalties,
+                    )? > pen.kind.as_duration().unwrap())
+                {
+                    pen.start_period = self.current_period;
+                    pen.start_time = clock_time;
+                }
+            }
+
+            let now = Instant::now();
+            if let Some(from) = self.game_clock_time(now)
+                && from != clock_time
+            {
+                self.record_edit(
+                    AuditChange::GameClock {
+                        from: from.as_secs_f32(),
+                        to: clock_time.as_secs_f32(),
+                    },
+                    AuditSource::EditPage,
+                    now,
+                );
+            }
+            self.clock_state = ClockState::Stopped { clock_time };
+            Ok(())
+        } else {
+            Err(TournamentManagerError::ClockIsRunning)
         }
     }
 
@@ -1925,6 +2566,19 @@ impl TournamentManager {
                 (time / 60.0).floor(),
                 time % 60.0
             );
+            let now = Instant::now();
+            if let Some(from) = self.timeout_clock_time(now)
+                && from != clock_time
+            {
+                self.record_edit(
+                    AuditChange::TimeoutClock {
+                        from: from.as_secs_f32(),
+                        to: clock_time.as_secs_f32(),
+                    },
+                    AuditSource::EditPage,
+                    now,
+                );
+            }
             let new_cs = ClockState::Stopped { clock_time };
             match self.timeout_state {
                 Some(TimeoutState::Team(_, ref mut cs))
@@ -1946,7 +2600,7 @@ impl TournamentManager {
     /// (`end_game_ending_timeout`) so both windows count down for the same duration.
     fn confirm_pause_duration(&self) -> Duration {
         match self.current_period {
-            GamePeriod::SecondHalf => {
+            GamePeriod::FirstHalf | GamePeriod::SecondHalf if self.in_final_half() => {
                 if self.config.overtime_allowed {
                     min(self.config.pre_overtime_break, self.config.minimum_break) / 2
                 } else if self.config.sudden_death_allowed {
@@ -1976,12 +2630,25 @@ impl TournamentManager {
     }
 
     pub fn pause_for_confirm(&mut self, now: Instant) -> Result<()> {
+        // A rugby penalty shot still running when the period's clock ran out holds the clock at
+        // zero until the shot ends, which is when the clock updater asks for this pause. The shot,
+        // or a team timeout, may also run out in the same tick as the period, before the updater
+        // has seen either.
+        let timeout_ended = matches!(
+            &self.timeout_state,
+            Some(TimeoutState::RugbyPenaltyShot(cs) | TimeoutState::Team(_, cs))
+                if cs.clock_time(now).is_none_or(|t| t.is_zero())
+        );
+
         // A deciding sudden-death goal must always go through the confirm window, even when the
         // game clock is stopped or a referee timeout / penalty shot is active (R2). Outside sudden
         // death the confirm pause is only ever armed by the clock updater with the clock running
         // and no timeout, so the strict guards remain there as backstops.
         let in_sudden_death = self.current_period == GamePeriod::SuddenDeath;
-        if !in_sudden_death {
+        if !in_sudden_death && !self.in_final_half() {
+            return Err(TournamentManagerError::InvalidState);
+        }
+        if !in_sudden_death && !timeout_ended {
             if self.timeout_state.is_some() {
                 return Err(TournamentManagerError::PausingDuringTimeout);
             }
@@ -1989,7 +2656,8 @@ impl TournamentManager {
                 return Err(TournamentManagerError::ClockStopped);
             }
         } else if self.timeout_state.is_some() {
-            // The deciding goal ends the game, so any active referee timeout / penalty shot is over.
+            // The deciding goal or shot ends the game, so any active referee timeout / penalty
+            // shot, or a team timeout that outlasted the period, is over.
             self.timeout_state = None;
         }
         info!("Pausing for Confirmation");
@@ -1999,29 +2667,25 @@ impl TournamentManager {
                 time_remaining_at_start,
             } => min(start_time + time_remaining_at_start, now),
             ClockState::CountingUp { .. } => now,
-            // Sudden-death goal recorded with the game clock stopped (R2); non-sudden-death
-            // callers are rejected above while the clock is stopped, so this is SD-only.
+            // Sudden-death goal recorded with the game clock stopped (R2), or a rugby penalty
+            // shot or team timeout that outlasted the period; other callers are rejected above.
             ClockState::Stopped { .. } => now,
         };
 
-        let dur_pause = self.confirm_pause_duration();
-
         info!("Current Period: {}", self.current_period);
 
-        let clock_at_pause = match self.current_period {
-            GamePeriod::SuddenDeath => self.clock_state.clock_time(now).unwrap(),
-            GamePeriod::SecondHalf | GamePeriod::OvertimeSecondHalf => Duration::from_millis(10),
-            GamePeriod::BetweenGames
-            | GamePeriod::FirstHalf
-            | GamePeriod::HalfTime
-            | GamePeriod::OvertimeFirstHalf
-            | GamePeriod::OvertimeHalfTime
-            | GamePeriod::PreOvertime
-            | GamePeriod::PreSuddenDeath => unreachable!(),
+        let clock_at_pause = if in_sudden_death {
+            self.clock_state.clock_time(now).unwrap()
+        } else {
+            Duration::from_millis(10)
         };
 
+        let dur_pause = self.confirm_pause_duration();
+
+        // In sudden death the clock counts up, so it stops where it is, or penalties would read as
+        // having gained time during the pause
         self.clock_state = ClockState::Stopped {
-            clock_time: Duration::from_millis(10),
+            clock_time: clock_at_pause,
         };
 
         self.time_pause_confirmation = Some(ConfirmPause {
@@ -2039,16 +2703,18 @@ impl TournamentManager {
             "Ending Pause, Pause Duration: {:?}",
             self.time_pause_confirmation
         );
-        if let Some(confirm_pause) = &self.time_pause_confirmation {
+        if let Some(confirm_pause) = self.time_pause_confirmation.clone() {
             let scores = self.scores;
-            self.current_period = match self.current_period {
-                GamePeriod::SecondHalf => {
+            let period = match self.current_period {
+                GamePeriod::FirstHalf | GamePeriod::SecondHalf if self.in_final_half() => {
                     if scores.are_not_equal() {
                         GamePeriod::BetweenGames
                     } else if self.config.overtime_allowed {
                         GamePeriod::PreOvertime
                     } else if self.config.sudden_death_allowed {
                         GamePeriod::PreSuddenDeath
+                    } else if self.config.penalty_shootout_allowed {
+                        GamePeriod::PenaltyShootout
                     } else {
                         GamePeriod::BetweenGames
                     }
@@ -2058,6 +2724,8 @@ impl TournamentManager {
                         GamePeriod::BetweenGames
                     } else if self.config.sudden_death_allowed {
                         GamePeriod::PreSuddenDeath
+                    } else if self.config.penalty_shootout_allowed {
+                        GamePeriod::PenaltyShootout
                     } else {
                         GamePeriod::BetweenGames
                     }
@@ -2078,6 +2746,11 @@ impl TournamentManager {
                     return Ok(());
                 }
             };
+            if period == self.current_period || period == GamePeriod::BetweenGames {
+                self.current_period = period;
+            } else {
+                self.enter_period(period, now);
+            }
 
             info!("Current Period: {}", self.current_period);
 
@@ -2097,6 +2770,10 @@ impl TournamentManager {
 
             if self.current_period == GamePeriod::BetweenGames {
                 self.end_game(now)
+            } else if self.current_period == GamePeriod::PenaltyShootout {
+                info!("{} Entering penalty shootout", self.status_string(now));
+                self.shootout.clear();
+                self.send_clock_running(false);
             } else {
                 self.send_clock_running(true);
             }
@@ -2117,6 +2794,9 @@ impl TournamentManager {
                     start_time: (now),
                     time_at_start: (time_into_sd),
                 },
+                GamePeriod::PenaltyShootout => ClockState::Stopped {
+                    clock_time: Duration::ZERO,
+                },
                 _ => unreachable!(),
             };
 
@@ -2167,12 +2847,22 @@ impl TournamentManager {
     ) -> Option<PenaltyTimePrintable> {
         let cur_time = self.game_clock_time(now)?;
         if pen
-            .is_complete(self.current_period, cur_time, &self.config)
+            .is_complete(
+                self.current_period,
+                cur_time,
+                &self.config,
+                &self.rules.penalties,
+            )
             .ok()?
         {
             return Some(PenaltyTimePrintable::Served);
         }
-        if let Ok(time) = pen.time_remaining(self.current_period, cur_time, &self.config) {
+        if let Ok(time) = pen.time_remaining(
+            self.current_period,
+            cur_time,
+            &self.config,
+            &self.rules.penalties,
+        ) {
             let time = time.whole_seconds();
             Some(PenaltyTimePrintable::Remaining(time))
         } else {
@@ -2307,7 +2997,14 @@ impl TournamentManager {
                 (
                     c,
                     pens.iter()
-                        .map(|p| p.as_snapshot(self.current_period, cur_time, &self.config))
+                        .map(|p| {
+                            p.as_snapshot(
+                                self.current_period,
+                                cur_time,
+                                &self.config,
+                                &self.rules.penalties,
+                            )
+                        })
                         .collect::<PenaltyResult<Vec<_>>>()
                         .ok(),
                 )
@@ -2330,11 +3027,13 @@ impl TournamentManager {
             .collect();
         trace!("Got fouls");
 
-        if let Some((_, _, goal_per, goal_time)) = self.recent_goal {
-            if (goal_per != self.current_period)
-                | (goal_time.saturating_sub(cur_time) > RECENT_GOAL_TIME)
-            {
-                self.recent_goal = None;
+        for recent in [&mut self.recent_goal, &mut self.recent_disallowed_goal] {
+            if let Some((_, _, goal_per, goal_time)) = *recent {
+                if (goal_per != self.current_period)
+                    | (goal_time.saturating_sub(cur_time) > RECENT_GOAL_TIME)
+                {
+                    *recent = None;
+                }
             }
         }
 
@@ -2363,9 +3062,18 @@ impl TournamentManager {
             game_number: self.game_number(),
             next_game_number: self.next_game_number(),
             event_id: None,
+            team_names: Default::default(),
             recent_goal: self.recent_goal.map(|(c, n, _, _)| (c, n)),
+            goals: self.current_game_stats.goals(),
+            recent_disallowed_goal: self.recent_disallowed_goal.map(|(c, n, _, _)| (c, n)),
             next_period_len_secs,
             conf_pause_time,
+            shootout: (self.current_period == GamePeriod::PenaltyShootout
+                || !self.shootout.is_empty())
+            .then(|| {
+                self.shootout
+                    .as_snapshot(self.config.penalty_shootout_rounds)
+            }),
         })
     }
 
@@ -2442,6 +3150,7 @@ impl TournamentManager {
             GamePeriod::OvertimeSecondHalf => "OTSCNDH]",
             GamePeriod::PreSuddenDeath => "PRESDND]",
             GamePeriod::SuddenDeath => "SUDNDTH]",
+            GamePeriod::PenaltyShootout => "SHOOTOT]",
         });
 
         string
@@ -2509,6 +3218,16 @@ enum TimeoutState {
 }
 
 impl TimeoutState {
+    fn sheet_kind(&self) -> TimeoutKind {
+        match self {
+            TimeoutState::Team(color, _) => TimeoutKind::Team(*color),
+            TimeoutState::Ref(_) => TimeoutKind::Ref,
+            TimeoutState::PenaltyShot(_) | TimeoutState::RugbyPenaltyShot(_) => {
+                TimeoutKind::PenaltyShot
+            }
+        }
+    }
+
     fn as_snapshot(&self, now: Instant) -> TimeoutSnapshot {
         match self {
             TimeoutState::Team(Color::Black, cs) => TimeoutSnapshot::Black(cs.as_secs_u16(now)),
@@ -2521,10 +3240,11 @@ impl TimeoutState {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NextGameInfo {
     pub number: GameNumber,
     pub timing: Option<TimingRule>,
+    #[serde(with = "time::serde::rfc3339::option")]
     pub start_time: Option<OffsetDateTime>,
 }
 
@@ -2582,18 +3302,48 @@ pub enum TournamentManagerError {
     InvalidWarnIndex(Color, usize),
     #[error("No {0:?} penalty exists at the index {1}")]
     InvalidFoulIndex(Option<Color>, usize),
+    #[error("No {0} goal exists at the index {1}")]
+    InvalidGoalIndex(Color, usize),
+    #[error("The {0} goal at the index {1} has already been disallowed")]
+    GoalAlreadyDisallowed(Color, usize),
+    #[error("Can't disallow a goal during {0}")]
+    CantDisallowGoal(GamePeriod),
     #[error("Can't halt game from the current state")]
     InvalidState,
     #[error("Next Game Info is needed to perform this action")]
     NoNextGameInfo,
+    #[error("The catch-up plan only cuts breaks, which already happens without accepting it")]
+    CatchUpPlanOnlyCutsBreaks,
+    #[error("No game has ended whose result could be signed off")]
+    NoResultToSignOff,
+    #[error("Incidents can only be reported during a game")]
+    NoGameInProgress,
     #[error("Penalty error: {0}")]
     PenaltyError(#[from] PenaltyError),
     #[error("Time not paused")]
     NotPaused,
     #[error("Cannot pause during timeout")]
     PausingDuringTimeout,
+    #[error("Can't start a timeout while the scores are being confirmed")]
+    ConfirmingScores,
     #[error("The clock is already stopped")]
     ClockStopped,
+    #[error("There is nothing to undo")]
+    NothingToUndo,
+    #[error("There is nothing to redo")]
+    NothingToRedo,
+    #[error("The game has changed since that action was taken")]
+    HistoryConflict,
+    #[error("Can only start a penalty shootout from sudden death")]
+    NotInSuddenDeath,
+    #[error("Penalty shootouts are not enabled")]
+    ShootoutNotAllowed,
+    #[error("Not in a penalty shootout")]
+    NotInShootout,
+    #[error("It is the {0} team's turn to shoot")]
+    ShootoutOutOfTurn(Color),
+    #[error("No penalty shootout attempts have been taken")]
+    NoShootoutAttempts,
 }
 
 pub type Result<T> = std::result::Result<T, TournamentManagerError>;
@@ -2622,9 +3372,6 @@ fn print_p_num_foul(num: Option<u8>) -> String {
     }
 }
 
-#[cfg(test)]
-mod golden;
-
 #[cfg(test)]
 mod test {
     use super::TournamentManagerError as TMErr;
@@ -2653,16 +3400,16 @@ mod test {
         tm.start_play_now(g).unwrap();
 
         // Warning: tracked, never sent to the portal.
-        tm.add_warning(Color::Black, Some(5), Infraction::Unknown, g)
+        tm.add_warning(Color::Black, Some(5), Infraction::UNKNOWN, g)
             .unwrap();
         // Fully-attributed foul (player + team): SENT.
-        tm.add_foul(Some(Color::White), Some(7), Infraction::Obstruction, g)
+        tm.add_foul(Some(Color::White), Some(7), Infraction::OBSTRUCTION, g)
             .unwrap();
         // Team foul (team but no player): held back until the portal accepts it.
-        tm.add_foul(Some(Color::Black), None, Infraction::DelayOfGame, g)
+        tm.add_foul(Some(Color::Black), None, Infraction::DELAY_OF_GAME, g)
             .unwrap();
         // "Both teams at fault" foul (no team): held back.
-        tm.add_foul(None, None, Infraction::FreeArm, g).unwrap();
+        tm.add_foul(None, None, Infraction::FREE_ARM, g).unwrap();
 
         tm.stop_clock(g).unwrap();
         tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(0));
@@ -2716,6 +3463,152 @@ mod test {
         assert_eq!(info.scores.black, 1);
     }
 
+    #[test]
+    fn sign_off_is_recorded_with_the_ended_game() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let g = Instant::now();
+        let protest = BlackWhiteBundle {
+            black: TeamSignOff::default(),
+            white: TeamSignOff {
+                signed_by: Some(12),
+                protest: true,
+            },
+        };
+        assert_eq!(
+            tm.sign_off_result(protest, g),
+            Err(TournamentManagerError::NoResultToSignOff)
+        );
+
+        tm.start_play_now(g).unwrap();
+        tm.stop_clock(g).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(0));
+        tm.end_game(g);
+        tm.sign_off_result(protest, g).unwrap();
+
+        let stats = &tm.last_game_info().unwrap().stats;
+        assert_eq!(stats.sign_offs(), Some(protest));
+        assert!(stats.protest_lodged());
+    }
+
+    #[test]
+    fn incidents_are_kept_with_the_game_they_were_filed_in() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let g = Instant::now();
+        let incident = IncidentDetails {
+            category: IncidentCategory::Injury,
+            color: Some(Color::White),
+            player_number: Some(7),
+            start_period: GamePeriod::FirstHalf,
+            start_time: Duration::from_secs(312),
+            start_instant: g,
+            description: "Cut above the eye".to_string(),
+        };
+        assert_eq!(
+            tm.add_incident(incident.clone()),
+            Err(TournamentManagerError::NoGameInProgress)
+        );
+
+        tm.start_play_now(g).unwrap();
+        tm.add_incident(incident).unwrap();
+        assert_eq!(tm.current_game_stats.incidents().len(), 1);
+
+        tm.stop_clock(g).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(0));
+        tm.end_game(g);
+
+        let incidents = tm.last_game_info().unwrap().stats.incidents();
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].category, IncidentCategory::Injury);
+        assert_eq!(incidents[0].color, Some(Color::White));
+        assert_eq!(incidents[0].player_number, Some(7));
+        assert_eq!(incidents[0].game_period, GamePeriod::FirstHalf);
+        assert_eq!(incidents[0].period_time, Duration::from_secs(312));
+        assert_eq!(incidents[0].description, "Cut above the eye");
+    }
+
+    #[test]
+    fn changes_made_by_hand_are_kept_on_the_audit_trail() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let g = Instant::now();
+        // Between games there is no game to keep a trail for
+        tm.set_game_clock_time(Duration::from_secs(100)).unwrap();
+
+        tm.start_play_now(g).unwrap();
+        tm.add_score(Color::Black, 4, g);
+        tm.stop_clock(g).unwrap();
+        tm.set_scores(BlackWhiteBundle { black: 1, white: 0 }, g);
+        tm.set_scores(BlackWhiteBundle { black: 0, white: 0 }, g);
+        tm.start_penalty(
+            Color::White,
+            9,
+            PenaltyKind::ONE_MINUTE,
+            g,
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.edit_penalty(
+            Color::White,
+            0,
+            Color::White,
+            9,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.edit_penalty(
+            Color::White,
+            0,
+            Color::Black,
+            3,
+            PenaltyKind::TWO_MINUTE,
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.delete_penalty(Color::Black, 0).unwrap();
+        tm.set_game_clock_time(Duration::from_secs(600)).unwrap();
+
+        let penalty = |color, player_number, kind| AuditPenalty {
+            color,
+            player_number,
+            kind,
+            infraction: Infraction::OBSTRUCTION,
+        };
+        let changes: Vec<_> = tm.audit_trail().into_iter().map(|e| e.change).collect();
+        // The goal itself is not an edit, nor are the changes that changed nothing
+        assert_eq!(
+            changes,
+            vec![
+                AuditChange::Score {
+                    from: BlackWhiteBundle { black: 1, white: 0 },
+                    to: BlackWhiteBundle { black: 0, white: 0 },
+                },
+                AuditChange::Penalty {
+                    from: Some(penalty(Color::White, 9, PenaltyKind::ONE_MINUTE)),
+                    to: Some(penalty(Color::Black, 3, PenaltyKind::TWO_MINUTE)),
+                },
+                AuditChange::Penalty {
+                    from: Some(penalty(Color::Black, 3, PenaltyKind::TWO_MINUTE)),
+                    to: None,
+                },
+                AuditChange::GameClock {
+                    from: tm.config.half_play_duration.as_secs_f32(),
+                    to: 600.0,
+                },
+            ]
+        );
+
+        // The trail goes to the portal with the rest of the game's events
+        let events: Vec<serde_json::Value> =
+            serde_json::from_str(&tm.current_game_stats.as_json()).unwrap();
+        let edits: Vec<_> = events.iter().filter(|e| e["$type"] == "edit").collect();
+        assert_eq!(edits.len(), 4);
+        assert_eq!(edits[0]["change"]["kind"], "score");
+        assert_eq!(edits[0]["gamePeriod"], "FirstHalf");
+    }
+
     #[test]
     fn abandoning_a_game_leaves_the_result_labelled_with_the_earlier_game() {
         // The forfeit incident: game 16 finishes normally, game 18 starts and is abandoned
@@ -2760,6 +3653,86 @@ mod test {
         assert_eq!(tm.game_number(), "18", "the clock still reports game 18");
     }
 
+    #[test]
+    fn a_sudden_death_score_ends_a_running_timeout_with_the_game() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let now = Instant::now();
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(30));
+        tm.start_ref_timeout(now).unwrap();
+
+        tm.set_scores(BlackWhiteBundle { black: 1, white: 0 }, now);
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.timeout_state, None);
+    }
+
+    #[test]
+    fn disallowing_a_goal_takes_it_off_the_score() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start = Instant::now();
+        tm.start_play_now(start).unwrap();
+        tm.add_score(Color::White, 4, start + Duration::from_secs(10));
+        tm.add_score(Color::White, 8, start + Duration::from_secs(20));
+
+        let now = start + Duration::from_secs(25);
+        assert_eq!(
+            tm.disallow_goal(Color::Black, 0, GoalDisallowReason::Foul, now),
+            Err(TMErr::InvalidGoalIndex(Color::Black, 0))
+        );
+        tm.disallow_goal(Color::White, 1, GoalDisallowReason::ScorerCorrection, now)
+            .unwrap();
+        assert_eq!(
+            tm.disallow_goal(Color::White, 1, GoalDisallowReason::Foul, now),
+            Err(TMErr::GoalAlreadyDisallowed(Color::White, 1))
+        );
+
+        let snapshot = tm.generate_snapshot(now).unwrap();
+        assert_eq!(snapshot.scores, BlackWhiteBundle { black: 0, white: 1 });
+        assert_eq!(snapshot.recent_goal, None);
+        assert_eq!(snapshot.recent_disallowed_goal, Some((Color::White, 8)));
+        let disallowed: Vec<_> = snapshot.goals.white.iter().map(|g| g.disallowed).collect();
+        assert_eq!(
+            disallowed,
+            vec![None, Some(GoalDisallowReason::ScorerCorrection)]
+        );
+
+        // Both the goal and the disallowal are sent with the game's stats
+        let events: Vec<serde_json::Value> =
+            serde_json::from_str(&tm.current_game_stats.as_json()).unwrap();
+        let of_type = |kind: &str| -> Vec<&serde_json::Value> {
+            events.iter().filter(|e| e["$type"] == kind).collect()
+        };
+        let goal = of_type("goal")[1];
+        let disallowed = of_type("goalDisallowed")[0];
+        assert_eq!(disallowed["playerCapNumber"], 8);
+        assert_eq!(disallowed["reason"], "ScorerCorrection");
+        assert_eq!(disallowed["goalOccurredOn"], goal["occurredOn"]);
+
+        // The audit trail has one entry for it, not a hand edit of the score as well
+        assert_eq!(
+            tm.audit_trail()
+                .into_iter()
+                .map(|e| e.change)
+                .collect::<Vec<_>>(),
+            vec![AuditChange::GoalDisallowed {
+                color: Color::White,
+                player_number: 8,
+                reason: GoalDisallowReason::ScorerCorrection,
+                from: BlackWhiteBundle { black: 0, white: 2 },
+                to: BlackWhiteBundle { black: 0, white: 1 },
+            }]
+        );
+        assert_eq!(of_type("edit").len(), 1);
+
+        tm.stop_clock(now).unwrap();
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(5));
+        assert_eq!(
+            tm.disallow_goal(Color::White, 0, GoalDisallowReason::Foul, now),
+            Err(TMErr::CantDisallowGoal(GamePeriod::SuddenDeath))
+        );
+    }
+
     // TODO: test correct sending of time start/stop signals
 
     #[test]
@@ -3950,12 +4923,12 @@ mod test {
         let mut now = Instant::now();
 
         let b_pen = Penalty {
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             player_number: 12,
             start_period: GamePeriod::SecondHalf,
             start_time: Duration::from_secs(234),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
         let w_pen = Penalty {
             kind: PenaltyKind::TotalDismissal,
@@ -3963,7 +4936,7 @@ mod test {
             start_period: GamePeriod::FirstHalf,
             start_time: Duration::from_secs(413),
             start_instant: now,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
 
         // Test the internal automatic reset during the BetweenGame Period
@@ -5324,7 +6297,7 @@ mod test {
         assert_eq!(tm.start_play_now(now), Err(TMErr::AlreadyInPlayPeriod));
 
         now += Duration::from_secs(10);
-        tm.stop_game_clock(now).unwrap();
+        tm.stop_clock(now).unwrap();
         tm.set_period_and_game_clock_time(GamePeriod::HalfTime, fifteen_secs);
         assert_eq!(tm.start_play_now(now), Ok(()));
         assert_eq!(tm.current_period, GamePeriod::SecondHalf);
@@ -5332,7 +6305,7 @@ mod test {
         assert_eq!(tm.start_play_now(now), Err(TMErr::AlreadyInPlayPeriod));
 
         now += Duration::from_secs(10);
-        tm.stop_game_clock(now).unwrap();
+        tm.stop_clock(now).unwrap();
         tm.set_period_and_game_clock_time(GamePeriod::PreOvertime, fifteen_secs);
         assert_eq!(tm.start_play_now(now), Ok(()));
         assert_eq!(tm.current_period, GamePeriod::OvertimeFirstHalf);
@@ -5340,7 +6313,7 @@ mod test {
         assert_eq!(tm.start_play_now(now), Err(TMErr::AlreadyInPlayPeriod));
 
         now += Duration::from_secs(10);
-        tm.stop_game_clock(now).unwrap();
+        tm.stop_clock(now).unwrap();
         tm.set_period_and_game_clock_time(GamePeriod::OvertimeHalfTime, fifteen_secs);
         assert_eq!(tm.start_play_now(now), Ok(()));
         assert_eq!(tm.current_period, GamePeriod::OvertimeSecondHalf);
@@ -5348,7 +6321,7 @@ mod test {
         assert_eq!(tm.start_play_now(now), Err(TMErr::AlreadyInPlayPeriod));
 
         now += Duration::from_secs(10);
-        tm.stop_game_clock(now).unwrap();
+        tm.stop_clock(now).unwrap();
         tm.set_period_and_game_clock_time(GamePeriod::PreSuddenDeath, fifteen_secs);
         assert_eq!(tm.start_play_now(now), Ok(()));
         assert_eq!(tm.current_period, GamePeriod::SuddenDeath);
@@ -6045,9 +7018,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             first_time,
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
         )
         .unwrap();
 
@@ -6056,12 +7029,12 @@ mod test {
         assert_eq!(
             tm.penalties.black,
             vec![Penalty {
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 player_number: 2,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: first_time,
-                infraction: Infraction::StickInfringement,
+                infraction: Infraction::STICK_INFRINGEMENT,
             }]
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6071,17 +7044,17 @@ mod test {
         tm.start_penalty(
             Color::Black,
             3,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             time,
-            Infraction::DelayOfGame,
+            Infraction::DELAY_OF_GAME,
         )
         .unwrap();
         tm.start_penalty(
             Color::Black,
             4,
-            PenaltyKind::FiveMinute,
+            PenaltyKind::FIVE_MINUTE,
             time,
-            Infraction::FalseStart,
+            Infraction::FALSE_START,
         )
         .unwrap();
         tm.start_penalty(
@@ -6089,31 +7062,31 @@ mod test {
             5,
             PenaltyKind::TotalDismissal,
             time,
-            Infraction::FreeArm,
+            Infraction::FREE_ARM,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             6,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             time,
-            Infraction::GrabbingTheBarrier,
+            Infraction::GRABBING_THE_BARRIER,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             7,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             time,
-            Infraction::IllegalAdvancement,
+            Infraction::ILLEGAL_ADVANCEMENT,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             8,
-            PenaltyKind::FiveMinute,
+            PenaltyKind::FIVE_MINUTE,
             time,
-            Infraction::IllegalSubstitution,
+            Infraction::ILLEGAL_SUBSTITUTION,
         )
         .unwrap();
         tm.start_penalty(
@@ -6121,7 +7094,7 @@ mod test {
             9,
             PenaltyKind::TotalDismissal,
             time,
-            Infraction::IllegallyStoppingThePuck,
+            Infraction::ILLEGALLY_STOPPING_THE_PUCK,
         )
         .unwrap();
 
@@ -6130,28 +7103,28 @@ mod test {
             tm.penalties.black,
             vec![
                 Penalty {
-                    kind: PenaltyKind::OneMinute,
+                    kind: PenaltyKind::ONE_MINUTE,
                     player_number: 2,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(24),
                     start_instant: first_time,
-                    infraction: Infraction::StickInfringement,
+                    infraction: Infraction::STICK_INFRINGEMENT,
                 },
                 Penalty {
-                    kind: PenaltyKind::TwoMinute,
+                    kind: PenaltyKind::TWO_MINUTE,
                     player_number: 3,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::DelayOfGame,
+                    infraction: Infraction::DELAY_OF_GAME,
                 },
                 Penalty {
-                    kind: PenaltyKind::FiveMinute,
+                    kind: PenaltyKind::FIVE_MINUTE,
                     player_number: 4,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::FalseStart,
+                    infraction: Infraction::FALSE_START,
                 },
                 Penalty {
                     kind: PenaltyKind::TotalDismissal,
@@ -6159,7 +7132,7 @@ mod test {
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::FreeArm,
+                    infraction: Infraction::FREE_ARM,
                 },
             ]
         );
@@ -6167,28 +7140,28 @@ mod test {
             tm.penalties.white,
             vec![
                 Penalty {
-                    kind: PenaltyKind::OneMinute,
+                    kind: PenaltyKind::ONE_MINUTE,
                     player_number: 6,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::GrabbingTheBarrier,
+                    infraction: Infraction::GRABBING_THE_BARRIER,
                 },
                 Penalty {
-                    kind: PenaltyKind::TwoMinute,
+                    kind: PenaltyKind::TWO_MINUTE,
                     player_number: 7,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::IllegalAdvancement,
+                    infraction: Infraction::ILLEGAL_ADVANCEMENT,
                 },
                 Penalty {
-                    kind: PenaltyKind::FiveMinute,
+                    kind: PenaltyKind::FIVE_MINUTE,
                     player_number: 8,
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::IllegalSubstitution,
+                    infraction: Infraction::ILLEGAL_SUBSTITUTION,
                 },
                 Penalty {
                     kind: PenaltyKind::TotalDismissal,
@@ -6196,7 +7169,7 @@ mod test {
                     start_period: GamePeriod::FirstHalf,
                     start_time: Duration::from_secs(22),
                     start_instant: time,
-                    infraction: Infraction::IllegallyStoppingThePuck,
+                    infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                 },
             ]
         );
@@ -6215,9 +7188,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             time,
-            Infraction::StickInfringement,
+            Infraction::STICK_INFRINGEMENT,
         )
         .unwrap();
 
@@ -6226,12 +7199,12 @@ mod test {
         assert_eq!(
             tm.penalties.black,
             vec![Penalty {
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 player_number: 2,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: time,
-                infraction: Infraction::StickInfringement,
+                infraction: Infraction::STICK_INFRINGEMENT,
             }],
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6259,9 +7232,9 @@ mod test {
         tm.start_penalty(
             Color::White,
             3,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             time,
-            Infraction::Obstruction,
+            Infraction::OBSTRUCTION,
         )
         .unwrap();
 
@@ -6270,12 +7243,12 @@ mod test {
         assert_eq!(
             tm.penalties.white,
             vec![Penalty {
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 player_number: 3,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(21),
                 start_instant: time,
-                infraction: Infraction::Obstruction,
+                infraction: Infraction::OBSTRUCTION,
             }],
         );
 
@@ -6309,9 +7282,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             pen_start_time,
-            Infraction::OutOfBounds,
+            Infraction::OUT_OF_BOUNDS,
         )
         .unwrap();
 
@@ -6320,12 +7293,12 @@ mod test {
         assert_eq!(
             tm.penalties.black,
             vec![Penalty {
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 player_number: 2,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::OutOfBounds,
+                infraction: Infraction::OUT_OF_BOUNDS,
             }],
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6337,8 +7310,8 @@ mod test {
                 1,
                 Color::Black,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::IllegalAdvancement
+                PenaltyKind::TWO_MINUTE,
+                Infraction::ILLEGAL_ADVANCEMENT
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::Black, 1))
         );
@@ -6348,8 +7321,8 @@ mod test {
                 0,
                 Color::Black,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::IllegalAdvancement
+                PenaltyKind::TWO_MINUTE,
+                Infraction::ILLEGAL_ADVANCEMENT
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::White, 0))
         );
@@ -6359,8 +7332,8 @@ mod test {
                 1,
                 Color::Black,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::IllegalAdvancement
+                PenaltyKind::TWO_MINUTE,
+                Infraction::ILLEGAL_ADVANCEMENT
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::White, 1))
         );
@@ -6369,20 +7342,20 @@ mod test {
             0,
             Color::Black,
             3,
-            PenaltyKind::TwoMinute,
-            Infraction::Unknown,
+            PenaltyKind::TWO_MINUTE,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
         assert_eq!(
             tm.penalties.black,
             vec![Penalty {
-                kind: PenaltyKind::TwoMinute,
+                kind: PenaltyKind::TWO_MINUTE,
                 player_number: 3,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6393,20 +7366,20 @@ mod test {
             0,
             Color::Black,
             4,
-            PenaltyKind::FiveMinute,
-            Infraction::Unknown,
+            PenaltyKind::FIVE_MINUTE,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
         assert_eq!(
             tm.penalties.black,
             vec![Penalty {
-                kind: PenaltyKind::FiveMinute,
+                kind: PenaltyKind::FIVE_MINUTE,
                 player_number: 4,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6418,7 +7391,7 @@ mod test {
             Color::Black,
             5,
             PenaltyKind::TotalDismissal,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
@@ -6430,7 +7403,7 @@ mod test {
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
         assert_eq!(tm.penalties.white, vec![]);
@@ -6442,7 +7415,7 @@ mod test {
             Color::White,
             6,
             PenaltyKind::TotalDismissal,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
@@ -6455,7 +7428,7 @@ mod test {
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
 
@@ -6466,8 +7439,8 @@ mod test {
                 1,
                 Color::White,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::Unknown
+                PenaltyKind::TWO_MINUTE,
+                Infraction::UNKNOWN
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::White, 1))
         );
@@ -6477,8 +7450,8 @@ mod test {
                 0,
                 Color::Black,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::Unknown
+                PenaltyKind::TWO_MINUTE,
+                Infraction::UNKNOWN
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::Black, 0))
         );
@@ -6488,8 +7461,8 @@ mod test {
                 1,
                 Color::Black,
                 2,
-                PenaltyKind::TwoMinute,
-                Infraction::Unknown
+                PenaltyKind::TWO_MINUTE,
+                Infraction::UNKNOWN
             ),
             Err(TournamentManagerError::InvalidPenIndex(Color::Black, 1))
         );
@@ -6498,8 +7471,8 @@ mod test {
             0,
             Color::White,
             7,
-            PenaltyKind::FiveMinute,
-            Infraction::Unknown,
+            PenaltyKind::FIVE_MINUTE,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
@@ -6507,12 +7480,12 @@ mod test {
         assert_eq!(
             tm.penalties.white,
             vec![Penalty {
-                kind: PenaltyKind::FiveMinute,
+                kind: PenaltyKind::FIVE_MINUTE,
                 player_number: 7,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
 
@@ -6522,8 +7495,8 @@ mod test {
             0,
             Color::White,
             8,
-            PenaltyKind::TwoMinute,
-            Infraction::Unknown,
+            PenaltyKind::TWO_MINUTE,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
@@ -6531,12 +7504,12 @@ mod test {
         assert_eq!(
             tm.penalties.white,
             vec![Penalty {
-                kind: PenaltyKind::TwoMinute,
+                kind: PenaltyKind::TWO_MINUTE,
                 player_number: 8,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
 
@@ -6546,8 +7519,8 @@ mod test {
             0,
             Color::White,
             10,
-            PenaltyKind::OneMinute,
-            Infraction::Unknown,
+            PenaltyKind::ONE_MINUTE,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.update(next_time).unwrap();
@@ -6555,12 +7528,12 @@ mod test {
         assert_eq!(
             tm.penalties.white,
             vec![Penalty {
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 player_number: 10,
                 start_period: GamePeriod::FirstHalf,
                 start_time: Duration::from_secs(24),
                 start_instant: pen_start_time,
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             }],
         );
     }
@@ -6584,9 +7557,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             next_time,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
 
@@ -6599,7 +7572,7 @@ mod test {
                 black: vec![PenaltySnapshot {
                     player_number: 2,
                     time: PenaltyTime::Seconds(59),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                 }],
                 white: vec![]
             }
@@ -6609,9 +7582,9 @@ mod test {
         tm.start_penalty(
             Color::White,
             3,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             next_time,
-            Infraction::UnsportsmanlikeConduct,
+            Infraction::UNSPORTSMANLIKE_CONDUCT,
         )
         .unwrap();
 
@@ -6624,12 +7597,12 @@ mod test {
                 black: vec![PenaltySnapshot {
                     player_number: 2,
                     time: PenaltyTime::Seconds(57),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                 }],
                 white: vec![PenaltySnapshot {
                     player_number: 3,
                     time: PenaltyTime::Seconds(59),
-                    infraction: Infraction::UnsportsmanlikeConduct,
+                    infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                 }]
             }
         );
@@ -6638,17 +7611,17 @@ mod test {
         tm.start_penalty(
             Color::Black,
             4,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             next_time,
-            Infraction::DelayOfGame,
+            Infraction::DELAY_OF_GAME,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             5,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             next_time,
-            Infraction::FalseStart,
+            Infraction::FALSE_START,
         )
         .unwrap();
 
@@ -6662,24 +7635,24 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(55),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(119),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(57),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(119),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                 ]
             }
@@ -6689,17 +7662,17 @@ mod test {
         tm.start_penalty(
             Color::Black,
             6,
-            PenaltyKind::FiveMinute,
+            PenaltyKind::FIVE_MINUTE,
             next_time,
-            Infraction::IllegalAdvancement,
+            Infraction::ILLEGAL_ADVANCEMENT,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             7,
-            PenaltyKind::FiveMinute,
+            PenaltyKind::FIVE_MINUTE,
             next_time,
-            Infraction::IllegallyStoppingThePuck,
+            Infraction::ILLEGALLY_STOPPING_THE_PUCK,
         )
         .unwrap();
 
@@ -6713,34 +7686,34 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(53),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(117),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(299),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(55),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(117),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(299),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                 ]
             }
@@ -6752,7 +7725,7 @@ mod test {
             8,
             PenaltyKind::TotalDismissal,
             next_time,
-            Infraction::IllegalSubstitution,
+            Infraction::ILLEGAL_SUBSTITUTION,
         )
         .unwrap();
         tm.start_penalty(
@@ -6760,7 +7733,7 @@ mod test {
             9,
             PenaltyKind::TotalDismissal,
             next_time,
-            Infraction::Obstruction,
+            Infraction::OBSTRUCTION,
         )
         .unwrap();
 
@@ -6774,44 +7747,44 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(51),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(115),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(297),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                     PenaltySnapshot {
                         player_number: 8,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(53),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(115),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(297),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                     PenaltySnapshot {
                         player_number: 9,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                     },
                 ]
             }
@@ -6828,44 +7801,44 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(36),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(100),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(282),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                     PenaltySnapshot {
                         player_number: 8,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(38),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(100),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(282),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                     PenaltySnapshot {
                         player_number: 9,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                     },
                 ]
             }
@@ -6882,44 +7855,44 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(26),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(90),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(272),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                     PenaltySnapshot {
                         player_number: 8,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(28),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(90),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(272),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                     PenaltySnapshot {
                         player_number: 9,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                     },
                 ]
             }
@@ -6936,44 +7909,44 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(60),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(242),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                     PenaltySnapshot {
                         player_number: 8,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(60),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(242),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                     PenaltySnapshot {
                         player_number: 9,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                     },
                 ]
             }
@@ -6990,44 +7963,44 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                     PenaltySnapshot {
                         player_number: 8,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalSubstitution,
+                        infraction: Infraction::ILLEGAL_SUBSTITUTION,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::UnsportsmanlikeConduct,
+                        infraction: Infraction::UNSPORTSMANLIKE_CONDUCT,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::IllegallyStoppingThePuck,
+                        infraction: Infraction::ILLEGALLY_STOPPING_THE_PUCK,
                     },
                     PenaltySnapshot {
                         player_number: 9,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::Obstruction,
+                        infraction: Infraction::OBSTRUCTION,
                     },
                 ]
             }
@@ -7054,9 +8027,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             next_time,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
 
@@ -7069,7 +8042,7 @@ mod test {
                 black: vec![PenaltySnapshot {
                     player_number: 2,
                     time: PenaltyTime::Seconds(59),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                 }],
                 white: vec![]
             }
@@ -7079,9 +8052,9 @@ mod test {
         tm.start_penalty(
             Color::White,
             3,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             next_time,
-            Infraction::DelayOfGame,
+            Infraction::DELAY_OF_GAME,
         )
         .unwrap();
         tm.start_penalty(
@@ -7089,7 +8062,7 @@ mod test {
             5,
             PenaltyKind::TotalDismissal,
             next_time,
-            Infraction::FalseStart,
+            Infraction::FALSE_START,
         )
         .unwrap();
 
@@ -7103,18 +8076,18 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(57),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     }
                 ],
                 white: vec![PenaltySnapshot {
                     player_number: 3,
                     time: PenaltyTime::Seconds(119),
-                    infraction: Infraction::DelayOfGame,
+                    infraction: Infraction::DELAY_OF_GAME,
                 }]
             }
         );
@@ -7131,18 +8104,18 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                 ],
                 white: vec![PenaltySnapshot {
                     player_number: 3,
                     time: PenaltyTime::Seconds(0),
-                    infraction: Infraction::DelayOfGame,
+                    infraction: Infraction::DELAY_OF_GAME,
                 },]
             }
         );
@@ -7168,9 +8141,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             pen_start,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
 
@@ -7181,7 +8154,7 @@ mod test {
                 black: vec![PenaltySnapshot {
                     player_number: 2,
                     time: PenaltyTime::Seconds(61),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                 }],
                 white: vec![]
             }
@@ -7213,9 +8186,9 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             pen_start,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
 
@@ -7231,7 +8204,7 @@ mod test {
                 black: vec![PenaltySnapshot {
                     player_number: 2,
                     time: PenaltyTime::Seconds(50),
-                    infraction: Infraction::Unknown,
+                    infraction: Infraction::UNKNOWN,
                 }],
                 white: vec![]
             }
@@ -7257,33 +8230,33 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             next_time,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             3,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             next_time,
-            Infraction::DelayOfGame,
+            Infraction::DELAY_OF_GAME,
         )
         .unwrap();
         tm.start_penalty(
             Color::Black,
             4,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             next_time,
-            Infraction::FalseStart,
+            Infraction::FALSE_START,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             5,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             next_time,
-            Infraction::FreeArm,
+            Infraction::FREE_ARM,
         )
         .unwrap();
         tm.start_penalty(
@@ -7291,7 +8264,7 @@ mod test {
             6,
             PenaltyKind::TotalDismissal,
             next_time,
-            Infraction::GrabbingTheBarrier,
+            Infraction::GRABBING_THE_BARRIER,
         )
         .unwrap();
         tm.start_penalty(
@@ -7299,7 +8272,7 @@ mod test {
             7,
             PenaltyKind::TotalDismissal,
             next_time,
-            Infraction::IllegalAdvancement,
+            Infraction::ILLEGAL_ADVANCEMENT,
         )
         .unwrap();
 
@@ -7314,34 +8287,34 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(59),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(119),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::GrabbingTheBarrier,
+                        infraction: Infraction::GRABBING_THE_BARRIER,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(59),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(119),
-                        infraction: Infraction::FreeArm,
+                        infraction: Infraction::FREE_ARM,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                 ]
             }
@@ -7358,34 +8331,34 @@ mod test {
                     PenaltySnapshot {
                         player_number: 2,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::Unknown,
+                        infraction: Infraction::UNKNOWN,
                     },
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(50),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::GrabbingTheBarrier,
+                        infraction: Infraction::GRABBING_THE_BARRIER,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 3,
                         time: PenaltyTime::Seconds(0),
-                        infraction: Infraction::DelayOfGame,
+                        infraction: Infraction::DELAY_OF_GAME,
                     },
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(50),
-                        infraction: Infraction::FreeArm,
+                        infraction: Infraction::FREE_ARM,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                 ]
             }
@@ -7402,24 +8375,24 @@ mod test {
                     PenaltySnapshot {
                         player_number: 4,
                         time: PenaltyTime::Seconds(44),
-                        infraction: Infraction::FalseStart,
+                        infraction: Infraction::FALSE_START,
                     },
                     PenaltySnapshot {
                         player_number: 6,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::GrabbingTheBarrier,
+                        infraction: Infraction::GRABBING_THE_BARRIER,
                     },
                 ],
                 white: vec![
                     PenaltySnapshot {
                         player_number: 5,
                         time: PenaltyTime::Seconds(44),
-                        infraction: Infraction::FreeArm,
+                        infraction: Infraction::FREE_ARM,
                     },
                     PenaltySnapshot {
                         player_number: 7,
                         time: PenaltyTime::TotalDismissal,
-                        infraction: Infraction::IllegalAdvancement,
+                        infraction: Infraction::ILLEGAL_ADVANCEMENT,
                     },
                 ]
             }
@@ -7445,33 +8418,33 @@ mod test {
         tm.start_penalty(
             Color::Black,
             2,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             pen_start,
-            Infraction::Unknown,
+            Infraction::UNKNOWN,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             3,
-            PenaltyKind::OneMinute,
+            PenaltyKind::ONE_MINUTE,
             pen_start,
-            Infraction::DelayOfGame,
+            Infraction::DELAY_OF_GAME,
         )
         .unwrap();
         tm.start_penalty(
             Color::Black,
             4,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             pen_start,
-            Infraction::FalseStart,
+            Infraction::FALSE_START,
         )
         .unwrap();
         tm.start_penalty(
             Color::White,
             5,
-            PenaltyKind::TwoMinute,
+            PenaltyKind::TWO_MINUTE,
             pen_start,
-            Infraction::FreeArm,
+            Infraction::FREE_ARM,
         )
         .unwrap();
         tm.start_penalty(
@@ -7479,7 +8452,7 @@ mod test {
             6,
             PenaltyKind::TotalDismissal,
             pen_start,
-            Infraction::GrabbingTheBarrier,
+            Infraction::GRABBING_THE_BARRIER,
         )
         .unwrap();
         tm.start_penalty(
@@ -7487,7 +8460,7 @@ mod test {
             7,
             PenaltyKind::TotalDismissal,
             pen_start,
-            Infraction::IllegalAdvancement,
+            Infraction::ILLEGAL_ADVANCEMENT,
         )
         .unwrap();
 
@@ -7685,35 +8658,392 @@ mod test {
     }
 
     #[test]
-    fn test_timeout_end_would_end_game() {
+    fn test_no_timeouts_or_clock_start_during_the_confirm_pause() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start = Instant::now();
+        let game_end = start + Duration::from_secs(30);
+
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(30));
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 1, white: 0 }, start);
+        tm.pause_for_confirm(game_end).unwrap();
+
+        // The second half is over, so there's nothing left for a timeout to belong to
+        let err = Err(TournamentManagerError::ConfirmingScores);
+        assert_eq!(tm.can_start_team_timeout(Color::Black), err);
+        assert_eq!(tm.can_start_ref_timeout(), err);
+        assert_eq!(tm.can_start_penalty_shot(), err);
+        assert_eq!(tm.can_start_rugby_penalty_shot(), err);
+
+        // The clock stays held until the scores are confirmed
+        tm.start_clock(game_end + Duration::from_secs(1));
+        assert!(!tm.clock_is_running());
+        assert!(tm.in_score_confirm_pause());
+    }
+
+    #[test]
+    fn test_single_half_game_pauses_for_confirm_at_the_end_of_the_first_half() {
         initialize();
         let config = GameConfig {
+            single_half: true,
             overtime_allowed: false,
             sudden_death_allowed: false,
             ..Default::default()
         };
         let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+        let game_end = start + Duration::from_secs(30);
 
-        let start_time = Instant::now();
-        let next_time = start_time + Duration::from_secs(1);
-
-        tm.stop_clock(start_time).unwrap();
-        tm.current_period = GamePeriod::SecondHalf;
-        tm.set_timeout_state(Some(TimeoutState::RugbyPenaltyShot(
-            ClockState::CountingDown {
-                start_time,
-                time_remaining_at_start: Duration::from_secs(20),
-            },
-        )));
-        tm.clock_state = ClockState::CountingDown {
-            start_time,
-            time_remaining_at_start: Duration::from_nanos(10),
-        };
-        assert_eq!(Ok(true), tm.timeout_end_would_end_game(next_time));
-        tm.clock_state = ClockState::Stopped {
-            clock_time: Duration::ZERO,
-        };
-        assert_eq!(Ok(true), tm.timeout_end_would_end_game(next_time));
+        // In a single-half game the first half is also the last one
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(30));
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 2, white: 1 }, start);
+        assert_eq!(Ok(true), tm.could_end_game(game_end));
+
+        tm.pause_for_confirm(game_end).unwrap();
+        let confirm = tm.time_pause_confirmation.as_ref().unwrap();
+        assert_eq!(confirm.duration_of_pause, tm.config.minimum_break / 2);
+        assert_eq!(tm.current_period, GamePeriod::FirstHalf);
+
+        tm.end_confirm_pause(game_end + confirm.duration_of_pause)
+            .unwrap();
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+
+        // A two-half game can't end with the first half
+        let mut tm = TournamentManager::new(GameConfig::default());
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(30));
+        tm.start_game_clock(start);
+        assert_eq!(Ok(false), tm.could_end_game(game_end));
+        assert_eq!(
+            tm.pause_for_confirm(game_end),
+            Err(TournamentManagerError::InvalidState)
+        );
+    }
+
+    #[test]
+    fn test_sudden_death_confirm_pause_holds_the_clock_where_it_was() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start = Instant::now();
+        let goal = start + Duration::from_secs(15);
+
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(30));
+        tm.start_game_clock(start);
+        // The deciding goal is held by the app until it's confirmed
+        tm.pause_for_confirm(goal).unwrap();
+
+        // Sudden death counts up, so dropping to zero would give penalties back the time served
+        let held = Duration::from_secs(45);
+        assert_eq!(tm.clock_state, ClockState::Stopped { clock_time: held });
+        assert_eq!(
+            tm.time_pause_confirmation.as_ref().unwrap().clock_time,
+            held
+        );
+        assert_eq!(
+            tm.game_clock_time(goal + Duration::from_secs(10)),
+            Some(held)
+        );
+    }
+
+    #[test]
+    fn test_penalty_given_between_games_is_served_by_the_end_of_the_game() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let now = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::BetweenGames, Duration::from_secs(60));
+        tm.start_penalty(
+            Color::White,
+            7,
+            PenaltyKind::ONE_MINUTE,
+            now,
+            Infraction::FALSE_START,
+        )
+        .unwrap();
+        assert_eq!(tm.penalties.white[0].start_period, GamePeriod::BetweenGames);
+
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::ZERO);
+        tm.end_game(now);
+
+        // Left as given between games it would read as not started in the break that follows
+        let pen = &tm.penalties.white[0];
+        assert_eq!(pen.start_period, GamePeriod::FirstHalf);
+        assert_eq!(pen.start_time, tm.config.half_play_duration);
+        assert_eq!(
+            pen.time_remaining(
+                GamePeriod::BetweenGames,
+                Duration::from_secs(30),
+                &tm.config,
+                &tm.rules.penalties,
+            ),
+            Ok(time::Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_team_timeout_ending_with_the_final_half() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start_time = Instant::now();
+
+        // The timeout ends 10 ms before the tick, with 5 ms left on the clock
+        tm.current_period = GamePeriod::SecondHalf;
+        tm.clock_state = ClockState::Stopped {
+            clock_time: Duration::from_millis(5),
+        };
+        tm.set_timeout_state(Some(TimeoutState::Team(
+            Color::Black,
+            ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start: Duration::from_secs(60),
+            },
+        )));
+        let now = start_time + Duration::from_millis(60_010);
+
+        assert_eq!(Ok(true), tm.could_end_game(now));
+        assert_eq!(Ok(()), tm.pause_for_confirm(now));
+        assert_eq!(tm.timeout_state, None);
+    }
+
+    #[test]
+    fn test_team_timeout_and_half_ending_in_the_same_tick() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start_time = Instant::now();
+
+        // The timeout ends 10 ms before the tick, with 5 ms left on the clock
+        tm.current_period = GamePeriod::FirstHalf;
+        tm.clock_state = ClockState::Stopped {
+            clock_time: Duration::from_millis(5),
+        };
+        tm.set_timeout_state(Some(TimeoutState::Team(
+            Color::White,
+            ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start: Duration::from_secs(60),
+            },
+        )));
+        let now = start_time + Duration::from_millis(60_010);
+
+        // One update ends both, rather than leaving a clock that has already run out
+        tm.update(now).unwrap();
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(tm.current_period, GamePeriod::HalfTime);
+        assert!(tm.game_clock_time(now).is_some());
+    }
+
+    #[test]
+    fn test_rugby_penalty_shot_outlasting_the_final_half() {
+        initialize();
+        let start_time = Instant::now();
+        let new_tm = || {
+            let mut tm = TournamentManager::new(GameConfig::default());
+            tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(1));
+            tm.set_scores(BlackWhiteBundle { black: 1, white: 0 }, start_time);
+            tm.start_clock(start_time);
+            tm.start_rugby_penalty_shot(start_time).unwrap();
+            tm
+        };
+        let half_end = start_time + Duration::from_secs(2);
+        let shot_end = start_time + GameConfig::default().penalty_shot_duration;
+
+        // The half waits for the shot, then the game ends through the confirm pause
+        let mut tm = new_tm();
+        tm.update(half_end).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::SecondHalf);
+        assert_eq!(Ok(false), tm.could_end_game(half_end));
+        assert_eq!(Ok(true), tm.could_end_game(shot_end));
+        assert_eq!(Ok(()), tm.pause_for_confirm(shot_end));
+        assert_eq!(tm.timeout_state, None);
+
+        // Without the pause, the end of the shot ends the game directly
+        let mut tm = new_tm();
+        tm.update(half_end).unwrap();
+        tm.update(shot_end).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::BetweenGames);
+        assert_eq!(tm.timeout_state, None);
+    }
+
+    #[test]
+    fn test_rugby_penalty_shot_in_sudden_death() {
+        initialize();
+        let config = GameConfig {
+            penalty_shot_duration: Duration::from_secs(45),
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start_time = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(20));
+        tm.start_clock(start_time);
+        tm.start_rugby_penalty_shot(start_time).unwrap();
+
+        // Switching to a ref timeout stops the game clock as well as the shot
+        let ref_time = start_time + Duration::from_secs(10);
+        tm.switch_to_ref_timeout(ref_time).unwrap();
+        assert_eq!(
+            tm.clock_state,
+            ClockState::Stopped {
+                clock_time: Duration::from_secs(30)
+            }
+        );
+
+        // Back to a shot, which restarts the clock counting up when it ends
+        tm.switch_to_rugby_penalty_shot(ref_time).unwrap();
+        let end_time = ref_time + Duration::from_secs(45);
+        tm.update(end_time).unwrap();
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(
+            tm.game_clock_time(end_time + Duration::from_secs(5)),
+            Some(Duration::from_secs(35))
+        );
+    }
+
+    #[test]
+    fn test_rugby_penalty_shot_ending_in_sudden_death_at_zero() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start_time = Instant::now();
+        let shot = tm.config.penalty_shot_duration;
+
+        // Sudden death counts up from zero, so a clock stopped at zero isn't the end of a period
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::ZERO);
+        tm.set_timeout_state(Some(TimeoutState::RugbyPenaltyShot(
+            ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start: shot,
+            },
+        )));
+
+        let end_time = start_time + shot;
+        assert_eq!(
+            tm.handle_rugby_pen_shot_end(end_time, start_time, shot),
+            Ok(())
+        );
+        assert_eq!(tm.current_period, GamePeriod::SuddenDeath);
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(
+            tm.game_clock_time(end_time + Duration::from_secs(5)),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn test_rugby_penalty_shot_switched_to_a_ref_timeout_stops_the_game_clock() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start_time = Instant::now();
+        let shot = tm.config.penalty_shot_duration;
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(60));
+        tm.start_clock(start_time);
+        tm.start_rugby_penalty_shot(start_time).unwrap();
+
+        // The game clock doesn't run during a ref timeout
+        let ref_time = start_time + Duration::from_secs(10);
+        tm.switch_to_ref_timeout(ref_time).unwrap();
+        assert_eq!(
+            tm.clock_state,
+            ClockState::Stopped {
+                clock_time: Duration::from_secs(50)
+            }
+        );
+
+        // It carries on from where it stopped when the shot it's switched back to ends
+        let switch_time = ref_time + Duration::from_secs(20);
+        tm.switch_to_rugby_penalty_shot(switch_time).unwrap();
+        tm.start_clock(switch_time);
+        let end_time = switch_time + shot;
+        tm.update(end_time).unwrap();
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(tm.current_period, GamePeriod::FirstHalf);
+        assert_eq!(
+            tm.game_clock_time(end_time + Duration::from_secs(5)),
+            Some(Duration::from_secs(45))
+        );
+    }
+
+    #[test]
+    fn test_rugby_penalty_shot_ends_with_the_sudden_death_clock_running() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start_time = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(20));
+        tm.start_clock(start_time);
+        tm.start_rugby_penalty_shot(start_time).unwrap();
+
+        let end_time = start_time + tm.config.penalty_shot_duration;
+        tm.update(end_time).unwrap();
+        assert_eq!(tm.timeout_state, None);
+        assert_eq!(
+            tm.game_clock_time(end_time),
+            Some(Duration::from_secs(20) + tm.config.penalty_shot_duration)
+        );
+    }
+
+    #[test]
+    fn test_stopping_a_rugby_penalty_shot_needs_an_update_first() {
+        initialize();
+        let mut tm = TournamentManager::new(Default::default());
+        let start_time = Instant::now();
+
+        tm.set_period_and_game_clock_time(GamePeriod::FirstHalf, Duration::from_secs(1));
+        tm.start_rugby_penalty_shot(start_time).unwrap();
+        tm.start_clock(start_time);
+
+        // The game clock has run out, but the tick hasn't seen it yet
+        let stop_time = start_time + Duration::from_secs(2);
+        assert_eq!(
+            tm.stop_clock(stop_time),
+            Err(TournamentManagerError::NeedsUpdate)
+        );
+        assert_eq!(
+            tm.timeout_state,
+            Some(TimeoutState::RugbyPenaltyShot(ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start: tm.config.penalty_shot_duration,
+            }))
+        );
+
+        // The half waits for the shot instead
+        tm.update(stop_time).unwrap();
+        assert_eq!(tm.current_period, GamePeriod::FirstHalf);
+        tm.stop_clock(stop_time).unwrap();
+        assert!(!tm.clock_is_running());
+    }
+
+    #[test]
+    fn test_timeout_end_would_end_game() {
+        initialize();
+        let config = GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+
+        let start_time = Instant::now();
+        let next_time = start_time + Duration::from_secs(1);
+
+        tm.stop_clock(start_time).unwrap();
+        tm.current_period = GamePeriod::SecondHalf;
+        tm.set_timeout_state(Some(TimeoutState::RugbyPenaltyShot(
+            ClockState::CountingDown {
+                start_time,
+                time_remaining_at_start: Duration::from_secs(20),
+            },
+        )));
+        tm.clock_state = ClockState::CountingDown {
+            start_time,
+            time_remaining_at_start: Duration::from_nanos(10),
+        };
+        assert_eq!(Ok(true), tm.timeout_end_would_end_game(next_time));
+        tm.clock_state = ClockState::Stopped {
+            clock_time: Duration::ZERO,
+        };
+        assert_eq!(Ok(true), tm.timeout_end_would_end_game(next_time));
 
         tm.current_period = GamePeriod::FirstHalf;
         tm.set_timeout_state(Some(TimeoutState::RugbyPenaltyShot(
@@ -7980,6 +9310,194 @@ mod test {
         assert!(tm.config.sudden_death_allowed);
     }
 
+    #[test]
+    fn test_portal_timing_keeps_the_local_stop_time_and_shootout_settings() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig {
+            stop_time_allowed: true,
+            stop_time_window: Duration::from_secs(90),
+            penalty_shootout_allowed: true,
+            penalty_shootout_rounds: 5,
+            ..Default::default()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::BetweenGames, Duration::from_secs(60));
+        let mut timing = finals_timing_rule();
+        // Display only, so it neither enables nor disables stop time
+        timing.last_2_min_stop_time = false;
+        tm.set_next_game(NextGameInfo {
+            number: "53".to_string(),
+            timing: Some(timing),
+            start_time: Some(OffsetDateTime::now_utc() + time::Duration::minutes(30)),
+        });
+        tm.apply_next_game_start(Instant::now()).unwrap();
+        assert!(tm.config.stop_time_allowed);
+        assert_eq!(tm.config.stop_time_window, Duration::from_secs(90));
+        assert!(tm.config.penalty_shootout_allowed);
+        assert_eq!(tm.config.penalty_shootout_rounds, 5);
+        assert_eq!(tm.config.half_play_duration, Duration::from_secs(600));
+    }
+
+    #[test]
+    fn test_catch_up_plan_shortens_planned_half_times() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        tm.set_period_and_game_clock_time(GamePeriod::BetweenGames, Duration::from_secs(60));
+        let timing = finals_timing_rule();
+        tm.set_catch_up_plan(Some(CatchUpPlan {
+            games: vec![catch_up::PlannedGame {
+                number: "53".to_string(),
+                break_cut: Duration::ZERO,
+                late_by: Duration::from_secs(120),
+                half_time: Some(Duration::from_secs(60)),
+            }],
+            on_schedule_by: Some("54".to_string()),
+            remaining: Duration::ZERO,
+        }))
+        .unwrap();
+
+        tm.set_next_game(NextGameInfo {
+            number: "53".to_string(),
+            timing: Some(timing.clone()),
+            start_time: Some(OffsetDateTime::now_utc() + time::Duration::minutes(30)),
+        });
+        tm.apply_next_game_start(Instant::now()).unwrap();
+        assert_eq!(tm.config.half_time_duration, Duration::from_secs(60));
+
+        let start = Instant::now();
+        tm.start_play_now(start).unwrap();
+        assert_eq!(tm.game_number(), "53");
+        assert_eq!(tm.config.half_time_duration, Duration::from_secs(60));
+
+        // A game the plan leaves alone keeps the rule's half-time
+        tm.set_next_game(NextGameInfo {
+            number: "54".to_string(),
+            timing: Some(timing.clone()),
+            start_time: None,
+        });
+        tm.end_game(start);
+        tm.apply_next_game_start(start).unwrap();
+        assert_eq!(tm.config.half_time_duration, timing.half_time_duration);
+    }
+
+    #[test]
+    fn test_catch_up_plan_that_only_cuts_breaks_is_rejected() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let planned = |half_time| CatchUpPlan {
+            games: vec![catch_up::PlannedGame {
+                number: "53".to_string(),
+                break_cut: Duration::from_secs(60),
+                late_by: Duration::from_secs(120),
+                half_time,
+            }],
+            on_schedule_by: None,
+            remaining: Duration::from_secs(60),
+        };
+        let shortening = planned(Some(Duration::from_secs(60)));
+        tm.set_catch_up_plan(Some(shortening.clone())).unwrap();
+
+        assert_eq!(
+            tm.set_catch_up_plan(Some(planned(None))),
+            Err(TournamentManagerError::CatchUpPlanOnlyCutsBreaks)
+        );
+        assert_eq!(tm.catch_up, Some(shortening));
+
+        tm.set_catch_up_plan(None).unwrap();
+        assert_eq!(tm.catch_up, None);
+    }
+
+    #[test]
+    fn test_whistles_stop_the_clock_in_the_stop_time_window() {
+        initialize();
+        let config = GameConfig {
+            stop_time_allowed: true,
+            stop_time_window: Duration::from_secs(120),
+            ..Default::default()
+        };
+        let mut tm = TournamentManager::new(config);
+        let start = Instant::now();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(125));
+        tm.start_clock(start);
+
+        // Outside the window play goes on
+        tm.add_score(Color::Black, 3, start);
+        assert!(tm.clock_is_running());
+        assert!(!tm.in_stop_time_window(start));
+
+        let t = start + Duration::from_secs(10);
+        assert!(tm.in_stop_time_window(t));
+        tm.add_score(Color::White, 5, t);
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(t), Some(Duration::from_secs(115)));
+
+        // Nothing runs off while the clock is stopped
+        let t = t + Duration::from_secs(20);
+        assert_eq!(tm.game_clock_time(t), Some(Duration::from_secs(115)));
+        tm.start_clock(t);
+
+        let t = t + Duration::from_secs(5);
+        tm.start_penalty(
+            Color::Black,
+            2,
+            PenaltyKind::ONE_MINUTE,
+            t,
+            Infraction::UNKNOWN,
+        )
+        .unwrap();
+        assert!(!tm.clock_is_running());
+        tm.start_clock(t);
+
+        let t = t + Duration::from_secs(5);
+        tm.add_foul(None, None, Infraction::UNKNOWN, t).unwrap();
+        assert!(!tm.clock_is_running());
+        tm.start_clock(t);
+
+        let t = t + Duration::from_secs(5);
+        tm.add_warning(Color::White, Some(7), Infraction::UNKNOWN, t)
+            .unwrap();
+        assert!(!tm.clock_is_running());
+        assert_eq!(tm.game_clock_time(t), Some(Duration::from_secs(100)));
+
+        // A timeout already stops the game clock, and a whistle leaves its clock alone
+        tm.start_clock(t);
+        tm.start_ref_timeout(t).unwrap();
+        tm.add_foul(None, None, Infraction::UNKNOWN, t).unwrap();
+        assert!(tm.clock_is_running());
+    }
+
+    #[test]
+    fn test_whistles_do_not_stop_the_clock_without_stop_time() {
+        initialize();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        let start = Instant::now();
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(30));
+        tm.start_clock(start);
+        assert!(!tm.in_stop_time_window(start));
+        tm.add_score(Color::Black, 3, start);
+        tm.start_penalty(
+            Color::Black,
+            2,
+            PenaltyKind::ONE_MINUTE,
+            start,
+            Infraction::UNKNOWN,
+        )
+        .unwrap();
+        tm.add_foul(None, None, Infraction::UNKNOWN, start).unwrap();
+        tm.add_warning(Color::White, Some(7), Infraction::UNKNOWN, start)
+            .unwrap();
+        assert!(tm.clock_is_running());
+
+        // Sudden death has no end to count down to
+        let mut tm = TournamentManager::new(GameConfig {
+            stop_time_allowed: true,
+            ..Default::default()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(30));
+        tm.start_clock(start);
+        tm.add_foul(None, None, Infraction::UNKNOWN, start).unwrap();
+        assert!(tm.clock_is_running());
+    }
+
     #[test]
     fn test_finals_normalized_nonzero_pause_and_tie_to_sudden_death() {
         initialize();
@@ -8976,4 +10494,255 @@ mod test {
         // No leftover grid/portal time: the break falls back to the nominal break (30s), not 40s.
         assert_eq!(tm.game_clock_time(now), Some(Duration::from_secs(30)));
     }
+
+    fn shootout_config() -> GameConfig {
+        GameConfig {
+            overtime_allowed: false,
+            sudden_death_allowed: false,
+            penalty_shootout_allowed: true,
+            penalty_shootout_rounds: 3,
+            ..Default::default()
+        }
+    }
+
+    /// A tied second half that runs out with only shootouts enabled
+    fn tm_in_shootout(start: Instant) -> TournamentManager {
+        let mut tm = TournamentManager::new(shootout_config());
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 2, white: 2 }, start);
+        tm.update(start + Duration::from_secs(10)).unwrap();
+        tm
+    }
+
+    #[test]
+    fn test_tied_second_half_goes_to_penalty_shootout() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = tm_in_shootout(start);
+        let now = start + Duration::from_secs(11);
+
+        assert_eq!(tm.current_period(), GamePeriod::PenaltyShootout);
+        assert!(!tm.clock_is_running());
+        assert!(!*tm.get_start_stop_rx().borrow());
+        assert_eq!(Err(TMErr::AlreadyInPlayPeriod), tm.start_play_now(now));
+        assert!(tm.can_start_team_timeout(Color::Black).is_err());
+        assert_eq!(Ok(()), tm.can_start_ref_timeout());
+
+        let snapshot = tm.generate_snapshot(now).unwrap();
+        let shootout = snapshot.shootout.unwrap();
+        assert_eq!(shootout.rounds, 3);
+        assert_eq!(shootout.next_shooter, None);
+        assert_eq!(shootout.winner, None);
+    }
+
+    #[test]
+    fn test_untied_second_half_skips_penalty_shootout() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(shootout_config());
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 3, white: 2 }, start);
+        tm.update(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+    }
+
+    #[test]
+    fn test_tied_overtime_goes_to_penalty_shootout() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(GameConfig {
+            overtime_allowed: true,
+            ..shootout_config()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::OvertimeSecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 2, white: 2 }, start);
+        tm.update(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::PenaltyShootout);
+    }
+
+    #[test]
+    fn test_confirm_pause_at_tied_second_half_goes_to_penalty_shootout() {
+        initialize();
+        let start = Instant::now();
+        let game_end = start + Duration::from_secs(10);
+        let mut tm = TournamentManager::new(shootout_config());
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.set_scores(BlackWhiteBundle { black: 1, white: 1 }, start);
+
+        assert_eq!(Ok(false), tm.timeout_end_would_end_game(start));
+        tm.pause_for_confirm(game_end).unwrap();
+        tm.end_confirm_pause(game_end + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::PenaltyShootout);
+        assert!(!tm.clock_is_running());
+        assert!(!*tm.get_start_stop_rx().borrow());
+    }
+
+    #[test]
+    fn test_start_penalty_shootout_from_sudden_death() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(GameConfig {
+            sudden_death_allowed: true,
+            ..shootout_config()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::SecondHalf, Duration::from_secs(10));
+        tm.set_game_start(start);
+        tm.start_game_clock(start);
+        tm.update(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::PreSuddenDeath);
+
+        let now = start + Duration::from_secs(11);
+        tm.start_play_now(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::SuddenDeath);
+        tm.start_penalty_shootout(now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::PenaltyShootout);
+        assert!(!tm.clock_is_running());
+        assert_eq!(Err(TMErr::NotInSuddenDeath), tm.start_penalty_shootout(now));
+
+        let mut tm = TournamentManager::new(GameConfig {
+            sudden_death_allowed: true,
+            penalty_shootout_allowed: false,
+            ..shootout_config()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::ZERO);
+        assert_eq!(
+            Err(TMErr::ShootoutNotAllowed),
+            tm.start_penalty_shootout(now)
+        );
+    }
+
+    #[test]
+    fn test_timed_penalties_are_over_once_the_shootout_starts() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(GameConfig {
+            sudden_death_allowed: true,
+            ..shootout_config()
+        });
+        tm.set_period_and_game_clock_time(GamePeriod::SuddenDeath, Duration::from_secs(10));
+        tm.start_game_clock(start);
+        let pen_time = start + Duration::from_secs(5);
+        tm.start_penalty(
+            Color::Black,
+            3,
+            PenaltyKind::TWO_MINUTE,
+            pen_time,
+            Infraction::STICK_INFRINGEMENT,
+        )
+        .unwrap();
+        tm.start_penalty(
+            Color::White,
+            4,
+            PenaltyKind::TotalDismissal,
+            pen_time,
+            Infraction::FALSE_START,
+        )
+        .unwrap();
+
+        let now = start + Duration::from_secs(20);
+        tm.start_penalty_shootout(now).unwrap();
+
+        // Play is over, so the two minute penalty is too, but the dismissal still stands
+        let snapshot = tm.generate_snapshot(now).unwrap();
+        assert_eq!(snapshot.penalties.black[0].time, PenaltyTime::Seconds(0));
+        assert_eq!(
+            snapshot.penalties.white[0].time,
+            PenaltyTime::TotalDismissal
+        );
+    }
+
+    #[test]
+    fn test_shootout_attempts_alternate_and_decide_the_game() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = tm_in_shootout(start);
+        let now = start + Duration::from_secs(20);
+
+        assert_eq!(
+            Err(TMErr::NotInShootout),
+            TournamentManager::new(shootout_config()).add_shootout_attempt(
+                Color::Black,
+                1,
+                true,
+                now
+            )
+        );
+
+        // White shoots first, so it's Black's turn next
+        tm.add_shootout_attempt(Color::White, 3, true, now).unwrap();
+        assert_eq!(
+            Err(TMErr::ShootoutOutOfTurn(Color::Black)),
+            tm.add_shootout_attempt(Color::White, 4, true, now)
+        );
+        tm.add_shootout_attempt(Color::Black, 5, false, now)
+            .unwrap();
+        tm.add_shootout_attempt(Color::White, 4, false, now)
+            .unwrap();
+        tm.add_shootout_attempt(Color::Black, 6, false, now)
+            .unwrap();
+        // 1-0 with one attempt each left, and the game scores are unchanged until the shootout
+        // is decided
+        assert_eq!(tm.get_scores(), BlackWhiteBundle { black: 2, white: 2 });
+        assert_eq!(tm.current_period(), GamePeriod::PenaltyShootout);
+
+        // 2-0 with one Black attempt left
+        tm.add_shootout_attempt(Color::White, 7, true, now).unwrap();
+        assert_eq!(tm.current_period(), GamePeriod::BetweenGames);
+        assert_eq!(tm.get_scores(), BlackWhiteBundle { black: 2, white: 3 });
+
+        let snapshot = tm.generate_snapshot(now).unwrap();
+        let shootout = snapshot.shootout.unwrap();
+        assert_eq!(shootout.winner, Some(Color::White));
+        assert_eq!(shootout.tally(), BlackWhiteBundle { black: 0, white: 2 });
+
+        let stats: Vec<serde_json::Value> =
+            serde_json::from_str(&tm.last_game_info.as_ref().unwrap().stats.as_json()).unwrap();
+        let attempts: Vec<_> = stats
+            .iter()
+            .filter(|e| e["$type"] == "shootoutAttempt")
+            .collect();
+        assert_eq!(attempts.len(), 5);
+        assert!(
+            attempts
+                .iter()
+                .any(|e| e["side"] == "light" && e["round"] == 3 && e["playerCapNumber"] == 7)
+        );
+    }
+
+    #[test]
+    fn test_remove_last_shootout_attempt() {
+        initialize();
+        let start = Instant::now();
+        let mut tm = tm_in_shootout(start);
+        let now = start + Duration::from_secs(20);
+
+        assert_eq!(
+            Err(TMErr::NoShootoutAttempts),
+            tm.remove_last_shootout_attempt(now)
+        );
+        tm.add_shootout_attempt(Color::Black, 5, true, now).unwrap();
+        tm.add_shootout_attempt(Color::White, 3, false, now)
+            .unwrap();
+        tm.remove_last_shootout_attempt(now).unwrap();
+
+        let shootout = tm.generate_snapshot(now).unwrap().shootout.unwrap();
+        assert_eq!(shootout.attempts.white.len(), 0);
+        assert_eq!(shootout.next_shooter, Some(Color::White));
+        assert!(!tm.current_game_stats.as_json().contains("\"light\""));
+
+        tm.remove_last_shootout_attempt(now).unwrap();
+        let shootout = tm.generate_snapshot(now).unwrap().shootout.unwrap();
+        assert_eq!(shootout.next_shooter, None);
+        // Either team may shoot first again
+        tm.add_shootout_attempt(Color::White, 3, true, now).unwrap();
+    }
 }
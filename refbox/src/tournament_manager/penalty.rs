@@ -1,5 +1,6 @@
-use derivative::Derivative;
-use std::{cmp::Ordering, convert::TryInto};
+use crate::rule_profile::PenaltyRules;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, cmp::Ordering, convert::TryInto};
 use thiserror::Error;
 use time::Duration as SignedDuration;
 use tokio::time::{Duration, Instant};
@@ -8,38 +9,45 @@ use uwh_common::{
     game_snapshot::{GamePeriod, Infraction, PenaltySnapshot, PenaltyTime},
 };
 
-#[derive(Derivative)]
-#[derivative(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// How long a penalty lasts. The lengths on offer come from the active rule profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PenaltyKind {
-    ThirtySecond,
-    #[derivative(Default)]
-    OneMinute,
-    TwoMinute,
-    FourMinute,
-    FiveMinute,
+    /// Served for the given number of seconds of game time
+    Timed(u16),
     TotalDismissal,
 }
 
+impl Default for PenaltyKind {
+    fn default() -> Self {
+        Self::ONE_MINUTE
+    }
+}
+
 impl PenaltyKind {
+    pub const THIRTY_SECOND: Self = Self::Timed(30);
+    pub const ONE_MINUTE: Self = Self::Timed(60);
+    pub const TWO_MINUTE: Self = Self::Timed(120);
+    pub const FOUR_MINUTE: Self = Self::Timed(240);
+    pub const FIVE_MINUTE: Self = Self::Timed(300);
+
     pub(crate) fn as_duration(self) -> Option<Duration> {
         match self {
-            Self::ThirtySecond => Some(Duration::from_secs(30)),
-            Self::OneMinute => Some(Duration::from_secs(60)),
-            Self::TwoMinute => Some(Duration::from_secs(120)),
-            Self::FourMinute => Some(Duration::from_secs(240)),
-            Self::FiveMinute => Some(Duration::from_secs(300)),
+            Self::Timed(secs) => Some(Duration::from_secs(secs.into())),
             Self::TotalDismissal => None,
         }
     }
 
-    pub fn fluent(&self) -> &'static str {
-        match self {
-            Self::ThirtySecond => "thirty-seconds",
-            Self::OneMinute => "one-minute",
-            Self::TwoMinute => "two-minutes",
-            Self::FourMinute => "four-minutes",
-            Self::FiveMinute => "five-minutes",
-            Self::TotalDismissal => "total-dismissal",
+    /// The `penalty-kind` selector for the lengths that have a translation, and `m:ss` for the
+    /// others
+    pub fn fluent(&self) -> Cow<'static, str> {
+        match *self {
+            Self::THIRTY_SECOND => "thirty-seconds".into(),
+            Self::ONE_MINUTE => "one-minute".into(),
+            Self::TWO_MINUTE => "two-minutes".into(),
+            Self::FOUR_MINUTE => "four-minutes".into(),
+            Self::FIVE_MINUTE => "five-minutes".into(),
+            Self::TotalDismissal => "total-dismissal".into(),
+            Self::Timed(secs) => format!("{}:{:02}", secs / 60, secs % 60).into(),
         }
     }
 }
@@ -60,24 +68,25 @@ impl Penalty {
         cur_per: GamePeriod,
         cur_time: Duration,
         config: &GameConfig,
+        rules: &PenaltyRules,
     ) -> PenaltyResult<SignedDuration> {
         let calc_time_between = |earlier_period: GamePeriod,
                                  earlier_time: Duration,
                                  later_period: GamePeriod,
                                  later_time: Duration| {
-            let mut elapsed = if earlier_period.penalties_run(config) {
+            let mut elapsed = if rules.penalties_run(earlier_period, config) {
                 earlier_time.try_into()?
             } else {
                 SignedDuration::ZERO
             };
             let mut period = earlier_period.next_period().unwrap();
             while period < later_period {
-                if period.penalties_run(config) {
+                if rules.penalties_run(period, config) {
                     elapsed += period.duration(config).unwrap();
                 }
                 period = period.next_period().unwrap();
             }
-            if later_period.penalties_run(config) {
+            if rules.penalties_run(later_period, config) {
                 elapsed += later_period
                     .time_elapsed_at(later_time, config)
                     .ok_or(time::error::ConversionRange)?; // Because we know the period must have a duration
@@ -87,7 +96,7 @@ impl Penalty {
 
         match cur_per.cmp(&self.start_period) {
             Ordering::Equal => {
-                if cur_per.penalties_run(config) {
+                if rules.penalties_run(cur_per, config) {
                     Ok(cur_per.time_between(self.start_time.try_into()?, cur_time.try_into()?))
                 } else {
                     Ok(SignedDuration::ZERO)
@@ -107,14 +116,21 @@ impl Penalty {
         cur_per: GamePeriod,
         cur_time: Duration,
         config: &GameConfig,
+        rules: &PenaltyRules,
     ) -> PenaltyResult<SignedDuration> {
-        let elapsed = self.time_elapsed(cur_per, cur_time, config);
-
         if cur_per == GamePeriod::BetweenGames && self.start_period != GamePeriod::BetweenGames {
             // In this case, the game in which the penalty started has completed, and we
             // are counting down to the next game. By definition, any penalties have been
             // served in this situation.
             Ok(SignedDuration::ZERO)
+        } else if cur_per == GamePeriod::PenaltyShootout
+            && self.start_period != GamePeriod::PenaltyShootout
+            && self.kind != PenaltyKind::TotalDismissal
+        {
+            // Play is over once the shootout starts, so the same goes for timed penalties from
+            // before it. Sudden death has no set length, so the time served in it couldn't be
+            // worked out here anyway.
+            Ok(SignedDuration::ZERO)
         } else {
             // In all other cases we do the normal calculation and return `None` if the
             // penalty is a TD or an error occurred
@@ -124,8 +140,9 @@ impl Penalty {
                 .ok_or(PenaltyError::NoDuration)?
                 .try_into()?;
 
+            let elapsed = self.time_elapsed(cur_per, cur_time, config, rules)?;
             duration
-                .checked_sub(elapsed?)
+                .checked_sub(elapsed)
                 .ok_or(PenaltyError::DurationOverflow)
         }
     }
@@ -135,15 +152,12 @@ impl Penalty {
         cur_per: GamePeriod,
         cur_time: Duration,
         config: &GameConfig,
+        rules: &PenaltyRules,
     ) -> PenaltyResult<bool> {
         match self.kind {
             PenaltyKind::TotalDismissal => Ok(false),
-            PenaltyKind::ThirtySecond
-            | PenaltyKind::OneMinute
-            | PenaltyKind::TwoMinute
-            | PenaltyKind::FourMinute
-            | PenaltyKind::FiveMinute => self
-                .time_remaining(cur_per, cur_time, config)
+            PenaltyKind::Timed(_) => self
+                .time_remaining(cur_per, cur_time, config, rules)
                 .map(|rem| rem <= SignedDuration::ZERO),
         }
     }
@@ -153,8 +167,9 @@ impl Penalty {
         cur_per: GamePeriod,
         cur_time: Duration,
         config: &GameConfig,
+        rules: &PenaltyRules,
     ) -> PenaltyResult<PenaltySnapshot> {
-        let time = match self.time_remaining(cur_per, cur_time, config) {
+        let time = match self.time_remaining(cur_per, cur_time, config, rules) {
             Ok(dur) => {
                 if dur.is_negative() {
                     PenaltyTime::Seconds(0)
@@ -213,10 +228,12 @@ impl PenaltyTimePrintable {
 mod test {
     use super::super::test::initialize;
     use super::*;
+    use crate::rule_profile::RuleProfile;
 
     #[test]
     fn test_penalty_time_elapsed() {
         initialize();
+        let rules = RuleProfile::default().penalties;
         let all_periods_config = GameConfig {
             overtime_allowed: true,
             sudden_death_allowed: true,
@@ -354,14 +371,14 @@ mod test {
         for (start_period, start_time, end_period, end_time, config, result, msg) in test_cases {
             let penalty = Penalty {
                 player_number: 0,
-                kind: PenaltyKind::OneMinute,
+                kind: PenaltyKind::ONE_MINUTE,
                 start_time,
                 start_period,
                 start_instant: Instant::now(),
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             };
             assert_eq!(
-                penalty.time_elapsed(end_period, end_time, config),
+                penalty.time_elapsed(end_period, end_time, config, &rules),
                 result,
                 "{}",
                 msg
@@ -372,6 +389,7 @@ mod test {
     #[test]
     fn test_penalty_time_remaining() {
         initialize();
+        let rules = RuleProfile::default().penalties;
         let config = GameConfig {
             overtime_allowed: true,
             sudden_death_allowed: true,
@@ -389,7 +407,7 @@ mod test {
             (
                 GamePeriod::FirstHalf,
                 Duration::from_secs(4),
-                PenaltyKind::OneMinute,
+                PenaltyKind::ONE_MINUTE,
                 GamePeriod::FirstHalf,
                 Duration::from_secs(2),
                 Ok(SignedDuration::seconds(58)),
@@ -398,7 +416,7 @@ mod test {
             (
                 GamePeriod::FirstHalf,
                 Duration::from_secs(4),
-                PenaltyKind::TwoMinute,
+                PenaltyKind::TWO_MINUTE,
                 GamePeriod::FirstHalf,
                 Duration::from_secs(2),
                 Ok(SignedDuration::seconds(118)),
@@ -407,7 +425,7 @@ mod test {
             (
                 GamePeriod::FirstHalf,
                 Duration::from_secs(4),
-                PenaltyKind::FiveMinute,
+                PenaltyKind::FIVE_MINUTE,
                 GamePeriod::FirstHalf,
                 Duration::from_secs(2),
                 Ok(SignedDuration::seconds(298)),
@@ -425,7 +443,7 @@ mod test {
             (
                 GamePeriod::SuddenDeath,
                 Duration::from_secs(5),
-                PenaltyKind::OneMinute,
+                PenaltyKind::ONE_MINUTE,
                 GamePeriod::SuddenDeath,
                 Duration::from_secs(70),
                 Ok(SignedDuration::seconds(-5)),
@@ -434,7 +452,7 @@ mod test {
             (
                 GamePeriod::FirstHalf,
                 Duration::from_secs(5),
-                PenaltyKind::OneMinute,
+                PenaltyKind::ONE_MINUTE,
                 GamePeriod::BetweenGames,
                 Duration::from_secs(10),
                 Ok(SignedDuration::seconds(0)),
@@ -449,6 +467,24 @@ mod test {
                 Ok(SignedDuration::seconds(0)),
                 "Game Ended, TD",
             ),
+            (
+                GamePeriod::SecondHalf,
+                Duration::from_secs(3),
+                PenaltyKind::ONE_MINUTE,
+                GamePeriod::PenaltyShootout,
+                Duration::ZERO,
+                Ok(SignedDuration::seconds(0)),
+                "Shootout",
+            ),
+            (
+                GamePeriod::SecondHalf,
+                Duration::from_secs(3),
+                PenaltyKind::TotalDismissal,
+                GamePeriod::PenaltyShootout,
+                Duration::ZERO,
+                Err(PenaltyError::NoDuration),
+                "Shootout, TD",
+            ),
         ];
 
         for (start_period, start_time, kind, end_period, end_time, result, msg) in test_cases {
@@ -458,10 +494,10 @@ mod test {
                 start_time,
                 start_period,
                 start_instant: Instant::now(),
-                infraction: Infraction::Unknown,
+                infraction: Infraction::UNKNOWN,
             };
             assert_eq!(
-                penalty.time_remaining(end_period, end_time, &config),
+                penalty.time_remaining(end_period, end_time, &config, &rules),
                 result,
                 "{}",
                 msg
@@ -472,6 +508,7 @@ mod test {
     #[test]
     fn test_penalty_is_complete() {
         initialize();
+        let rules = RuleProfile::default().penalties;
         let config = GameConfig {
             overtime_allowed: true,
             sudden_death_allowed: true,
@@ -486,64 +523,109 @@ mod test {
 
         let penalty = Penalty {
             player_number: 0,
-            kind: PenaltyKind::OneMinute,
+            kind: PenaltyKind::ONE_MINUTE,
             start_time: Duration::from_secs(5),
             start_period: GamePeriod::SuddenDeath,
             start_instant: Instant::now(),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(60), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(60),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(65), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(65),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(70), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(70),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
 
         let penalty = Penalty {
             player_number: 0,
-            kind: PenaltyKind::TwoMinute,
+            kind: PenaltyKind::TWO_MINUTE,
             start_time: Duration::from_secs(5),
             start_period: GamePeriod::SuddenDeath,
             start_instant: Instant::now(),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(120), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(120),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(125), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(125),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(130), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(130),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
 
         let penalty = Penalty {
             player_number: 0,
-            kind: PenaltyKind::FiveMinute,
+            kind: PenaltyKind::FIVE_MINUTE,
             start_time: Duration::from_secs(5),
             start_period: GamePeriod::SuddenDeath,
             start_instant: Instant::now(),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(300), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(300),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(305), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(305),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(310), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(310),
+                &config,
+                &rules
+            ),
             Ok(true)
         );
 
@@ -553,19 +635,74 @@ mod test {
             start_time: Duration::from_secs(5),
             start_period: GamePeriod::SuddenDeath,
             start_instant: Instant::now(),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         };
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(300), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(300),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(305), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(305),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
         assert_eq!(
-            penalty.is_complete(GamePeriod::SuddenDeath, Duration::from_secs(310), &config),
+            penalty.is_complete(
+                GamePeriod::SuddenDeath,
+                Duration::from_secs(310),
+                &config,
+                &rules
+            ),
             Ok(false)
         );
     }
+
+    #[test]
+    fn test_profile_decides_which_periods_serve_penalty_time() {
+        initialize();
+        let config = GameConfig {
+            half_play_duration: Duration::from_secs(5),
+            half_time_duration: Duration::from_secs(7),
+            ..Default::default()
+        };
+        let mut rules = RuleProfile::default().penalties;
+        rules.served_in.push(GamePeriod::HalfTime);
+
+        let penalty = Penalty {
+            player_number: 0,
+            kind: PenaltyKind::Timed(90),
+            start_time: Duration::from_secs(4),
+            start_period: GamePeriod::FirstHalf,
+            start_instant: Instant::now(),
+            infraction: Infraction::UNKNOWN,
+        };
+        // 4 s of the first half, all 7 s of half time and 3 s of the second half
+        assert_eq!(
+            penalty.time_remaining(
+                GamePeriod::SecondHalf,
+                Duration::from_secs(2),
+                &config,
+                &rules
+            ),
+            Ok(SignedDuration::seconds(76))
+        );
+    }
+
+    #[test]
+    fn test_penalty_kind_fluent() {
+        assert_eq!(PenaltyKind::THIRTY_SECOND.fluent(), "thirty-seconds");
+        assert_eq!(PenaltyKind::FIVE_MINUTE.fluent(), "five-minutes");
+        assert_eq!(PenaltyKind::TotalDismissal.fluent(), "total-dismissal");
+        assert_eq!(PenaltyKind::Timed(90).fluent(), "1:30");
+        assert_eq!(PenaltyKind::Timed(45).fluent(), "0:45");
+    }
 }
@@ -0,0 +1,257 @@
+//! Recording what the operator does to the game, so a session can be replayed ("session file").
+//!
+//! With `--record-session` the app writes every action that changes the `TournamentManager` to a
+//! session file, as the [`Action`]s the app applies, with the time since the session
+//! started. The clock ticks themselves aren't recorded: the driver ticks the engine on its own,
+//! exactly as it does for the hand-written scenarios. `--replay-session` feeds a session file back
+//! through that driver and prints the resulting golden trace, so a bug seen on a tournament day
+//! can be checked in as a regression test.
+//!
+//! The file is JSON lines: a [`SessionHeader`] with the game config and rules the session started
+//! with, then one [`SessionEntry`] per action. Each line is written as soon as the action happens,
+//! so a session cut short by a crash keeps everything up to the crash; a torn last line is
+//! skipped when the session is loaded.
+
+use super::action::Action;
+use crate::rule_profile::RuleProfile;
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    path::Path,
+};
+use thiserror::Error;
+use time::{OffsetDateTime, macros::format_description};
+use tokio::time::Instant;
+use uwh_common::config::Game as GameConfig;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionHeader {
+    pub version: u32,
+    /// The wall-clock time the session started, which is when `at_ms` counts from
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    pub config: GameConfig,
+    pub rules: RuleProfile,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionEntry {
+    /// Milliseconds since the session started, from the monotonic clock
+    pub at_ms: u64,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub header: SessionHeader,
+    pub entries: Vec<SessionEntry>,
+}
+
+impl SessionHeader {
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Line {line} of the session file is invalid: {source}")]
+    Parse {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("The session file is empty")]
+    Empty,
+    #[error("Unknown session file version {0}")]
+    UnknownVersion(u32),
+}
+
+/// Appends the actions of one session to its session file
+#[derive(Debug)]
+pub struct SessionRecorder {
+    file: fs::File,
+    started: Instant,
+}
+
+impl SessionRecorder {
+    /// Starts a new session file in `dir`, named after the time it started
+    pub fn create(
+        dir: &Path,
+        config: &GameConfig,
+        rules: &RuleProfile,
+        now: Instant,
+    ) -> Result<Self, SessionError> {
+        let started_at = OffsetDateTime::now_utc();
+        // Format: YYYYMMDDTHHMMSSZ, e.g. "20260622T142203Z".
+        let fmt = format_description!("[year][month][day]T[hour][minute][second]Z");
+        let ts = started_at
+            .format(&fmt)
+            .unwrap_or_else(|_| "unknown-time".to_string());
+        let path = dir.join(format!("session-{ts}.jsonl"));
+        let file = fs::OpenOptions::new()
+            .create_new(true)
+            .write(true)
+            .open(&path)?;
+        info!("Recording the session to {}", path.display());
+
+        let mut recorder = Self { file, started: now };
+        recorder.write_line(&SessionHeader {
+            version: SessionHeader::CURRENT_VERSION,
+            started_at,
+            config: config.clone(),
+            rules: rules.clone(),
+        })?;
+        Ok(recorder)
+    }
+
+    pub fn record(&mut self, now: Instant, action: Action) -> Result<(), SessionError> {
+        let at_ms = now
+            .saturating_duration_since(self.started)
+            .as_millis()
+            .try_into()
+            .unwrap_or(u64::MAX);
+        self.write_line(&SessionEntry { at_ms, action })
+    }
+
+    fn write_line<T: Serialize>(&mut self, value: &T) -> Result<(), SessionError> {
+        let mut line = serde_json::to_vec(value).map_err(std::io::Error::other)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// Loads a session file. The last line is skipped with a warning if it doesn't parse, since
+/// that's what a crash in the middle of writing it leaves behind.
+pub fn load(path: &Path) -> Result<Session, SessionError> {
+    let lines = BufReader::new(fs::File::open(path)?)
+        .lines()
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let mut lines = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+
+    let (_, header) = lines.next().ok_or(SessionError::Empty)?;
+    let header: SessionHeader =
+        serde_json::from_str(header).map_err(|source| SessionError::Parse { line: 1, source })?;
+    if header.version != SessionHeader::CURRENT_VERSION {
+        return Err(SessionError::UnknownVersion(header.version));
+    }
+
+    let mut entries = Vec::new();
+    while let Some((i, line)) = lines.next() {
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) if lines.peek().is_none() => {
+                warn!("Skipping the torn last line of {}: {e}", path.display());
+            }
+            Err(source) => {
+                return Err(SessionError::Parse {
+                    line: i + 1,
+                    source,
+                });
+            }
+        }
+    }
+
+    Ok(Session { header, entries })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use uwh_common::color::Color;
+
+    fn only_file(dir: &Path) -> PathBuf {
+        let mut files = fs::read_dir(dir).unwrap();
+        let path = files.next().unwrap().unwrap().path();
+        assert!(files.next().is_none());
+        path
+    }
+
+    #[test]
+    fn a_recorded_session_loads_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let start = Instant::now();
+        let mut recorder = SessionRecorder::create(
+            dir.path(),
+            &GameConfig::default(),
+            &RuleProfile::default(),
+            start,
+        )
+        .unwrap();
+        recorder.record(start, Action::StartClock).unwrap();
+        recorder
+            .record(
+                start + Duration::from_millis(1500),
+                Action::AddScore(Color::White, 7),
+            )
+            .unwrap();
+
+        let path = only_file(dir.path());
+        assert!(
+            path.file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("session-")
+        );
+        let session = load(&path).unwrap();
+        assert_eq!(session.header.config, GameConfig::default());
+        assert_eq!(session.header.rules, RuleProfile::default());
+        assert_eq!(
+            session.entries,
+            vec![
+                SessionEntry {
+                    at_ms: 0,
+                    action: Action::StartClock,
+                },
+                SessionEntry {
+                    at_ms: 1500,
+                    action: Action::AddScore(Color::White, 7),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn a_torn_last_line_is_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let start = Instant::now();
+        let mut recorder = SessionRecorder::create(
+            dir.path(),
+            &GameConfig::default(),
+            &RuleProfile::default(),
+            start,
+        )
+        .unwrap();
+        recorder.record(start, Action::StartClock).unwrap();
+        let path = &only_file(dir.path());
+        let mut torn = fs::read_to_string(path).unwrap();
+        torn.push_str("{\"at_ms\":20,\"act");
+        fs::write(path, &torn).unwrap();
+        assert_eq!(load(path).unwrap().entries.len(), 1);
+
+        // A bad line anywhere else is an error
+        torn.push_str("\n{\"at_ms\":30,\"action\":\"StartClock\"}\n");
+        fs::write(path, torn).unwrap();
+        assert!(matches!(
+            load(path),
+            Err(SessionError::Parse { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn an_empty_file_is_not_a_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session-empty.jsonl");
+        fs::write(&path, "").unwrap();
+        assert!(matches!(load(&path), Err(SessionError::Empty)));
+    }
+}
@@ -0,0 +1,230 @@
+use tokio::time::Instant;
+use uwh_common::{
+    bundles::BlackWhiteBundle,
+    color::Color,
+    game_snapshot::{ShootoutAttemptSnapshot, ShootoutSnapshot},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ShootoutAttempt {
+    pub(crate) player_number: u8,
+    pub(crate) scored: bool,
+    pub(crate) instant: Instant,
+}
+
+/// The attempts of a penalty shootout. The teams alternate, with whichever team shoots first
+/// keeping that place in every round. The first `rounds` rounds are a best-of series that ends
+/// as soon as one team can no longer be caught; after that each complete round is sudden death.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Shootout {
+    pub(super) attempts: BlackWhiteBundle<Vec<ShootoutAttempt>>,
+    pub(super) first_shooter: Option<Color>,
+}
+
+impl Shootout {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.first_shooter.is_none()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The number of successful attempts by each team
+    pub(crate) fn tally(&self) -> BlackWhiteBundle<u8> {
+        self.attempts
+            .iter()
+            .map(|(color, attempts)| {
+                let scored = attempts.iter().filter(|a| a.scored).count();
+                (color, scored.try_into().unwrap_or(u8::MAX))
+            })
+            .collect()
+    }
+
+    /// The team that has won the shootout, if it has been decided
+    pub(crate) fn winner(&self, rounds: u16) -> Option<Color> {
+        let rounds = usize::from(rounds);
+        let taken = BlackWhiteBundle {
+            black: self.attempts.black.len(),
+            white: self.attempts.white.len(),
+        };
+        let tally = self.tally();
+        let tally = BlackWhiteBundle {
+            black: usize::from(tally.black),
+            white: usize::from(tally.white),
+        };
+
+        if taken.black <= rounds && taken.white <= rounds {
+            // Best-of series: decided once the leader is out of reach of the other team's
+            // remaining attempts
+            if tally.black > tally.white + (rounds - taken.white) {
+                Some(Color::Black)
+            } else if tally.white > tally.black + (rounds - taken.black) {
+                Some(Color::White)
+            } else {
+                None
+            }
+        } else if taken.black == taken.white && tally.black != tally.white {
+            // Sudden-death rounds: decided at the end of any round that isn't level
+            Some(if tally.black > tally.white {
+                Color::Black
+            } else {
+                Color::White
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The team due to shoot next. `None` before the first attempt, when either team may start,
+    /// and once the shootout has been decided.
+    pub(crate) fn next_shooter(&self, rounds: u16) -> Option<Color> {
+        let first = self.first_shooter?;
+        if self.winner(rounds).is_some() {
+            return None;
+        }
+        let first_taken = self.attempts[first].len();
+        let other_taken = self.attempts[first.other()].len();
+        Some(if first_taken > other_taken {
+            first.other()
+        } else {
+            first
+        })
+    }
+
+    /// The 1-based round that `color`'s next attempt belongs to
+    pub(crate) fn round_of_next(&self, color: Color) -> u16 {
+        (self.attempts[color].len() + 1)
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+
+    pub(crate) fn push(&mut self, color: Color, attempt: ShootoutAttempt) {
+        self.first_shooter.get_or_insert(color);
+        self.attempts[color].push(attempt);
+    }
+
+    /// Remove the most recent attempt, returning it and the team that took it
+    pub(crate) fn pop(&mut self) -> Option<(Color, ShootoutAttempt)> {
+        let first = self.first_shooter?;
+        let color = if self.attempts[first].len() > self.attempts[first.other()].len() {
+            first
+        } else {
+            first.other()
+        };
+        let attempt = self.attempts[color].pop()?;
+        if self.attempts.black.is_empty() && self.attempts.white.is_empty() {
+            self.first_shooter = None;
+        }
+        Some((color, attempt))
+    }
+
+    pub(crate) fn as_snapshot(&self, rounds: u16) -> ShootoutSnapshot {
+        ShootoutSnapshot {
+            attempts: self
+                .attempts
+                .iter()
+                .map(|(color, attempts)| {
+                    let attempts = attempts.iter().map(|a| ShootoutAttemptSnapshot {
+                        player_number: a.player_number,
+                        scored: a.scored,
+                    });
+                    (color, attempts.collect())
+                })
+                .collect(),
+            rounds,
+            next_shooter: self.next_shooter(rounds),
+            winner: self.winner(rounds),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shootout(first: Color, results: &[bool]) -> Shootout {
+        let mut shootout = Shootout::default();
+        let now = Instant::now();
+        for (i, scored) in results.iter().enumerate() {
+            let color = if i % 2 == 0 { first } else { first.other() };
+            shootout.push(
+                color,
+                ShootoutAttempt {
+                    player_number: i as u8 + 1,
+                    scored: *scored,
+                    instant: now,
+                },
+            );
+        }
+        shootout
+    }
+
+    #[test]
+    fn teams_alternate_from_whoever_shoots_first() {
+        let empty = Shootout::default();
+        assert_eq!(empty.next_shooter(3), None);
+
+        let s = shootout(Color::White, &[true]);
+        assert_eq!(s.next_shooter(3), Some(Color::Black));
+        let s = shootout(Color::White, &[true, false]);
+        assert_eq!(s.next_shooter(3), Some(Color::White));
+        assert_eq!(s.round_of_next(Color::White), 2);
+    }
+
+    #[test]
+    fn best_of_series_ends_once_the_leader_is_out_of_reach() {
+        // B scores, W misses, B misses, W misses: 1-0 and W can still level it
+        let s = shootout(Color::Black, &[true, false, false, false]);
+        assert_eq!(s.winner(3), None);
+        // B scores again: 2-0 and W can reach at most 1
+        let s = shootout(Color::Black, &[true, false, false, false, true]);
+        assert_eq!(s.winner(3), Some(Color::Black));
+        assert_eq!(s.next_shooter(3), None);
+        // W leads 2-0 with only one B attempt left
+        let s = shootout(Color::Black, &[false, true, false, true]);
+        assert_eq!(s.winner(3), Some(Color::White));
+    }
+
+    #[test]
+    fn level_series_goes_to_sudden_death_rounds() {
+        let s = shootout(Color::Black, &[true, true, false, false, true, true]);
+        assert_eq!(s.winner(3), None);
+        assert_eq!(s.next_shooter(3), Some(Color::Black));
+        // The first team scoring in a sudden-death round doesn't decide it
+        let s = shootout(Color::Black, &[true, true, false, false, true, true, true]);
+        assert_eq!(s.winner(3), None);
+        let s = shootout(
+            Color::Black,
+            &[true, true, false, false, true, true, true, true],
+        );
+        assert_eq!(s.winner(3), None);
+        let s = shootout(
+            Color::Black,
+            &[
+                true, true, false, false, true, true, true, true, false, true,
+            ],
+        );
+        assert_eq!(s.winner(3), Some(Color::White));
+    }
+
+    #[test]
+    fn zero_rounds_is_sudden_death_from_the_start() {
+        let s = shootout(Color::White, &[false]);
+        assert_eq!(s.winner(0), None);
+        let s = shootout(Color::White, &[false, true]);
+        assert_eq!(s.winner(0), Some(Color::Black));
+    }
+
+    #[test]
+    fn pop_removes_the_latest_attempt() {
+        let mut s = shootout(Color::White, &[true, false, true]);
+        let (color, attempt) = s.pop().unwrap();
+        assert_eq!(color, Color::White);
+        assert_eq!(attempt.player_number, 3);
+        assert_eq!(s.pop().unwrap().0, Color::Black);
+        assert_eq!(s.pop().unwrap().0, Color::White);
+        assert!(s.is_empty());
+        assert_eq!(s.pop(), None);
+    }
+}
@@ -0,0 +1,184 @@
+//! Everything that has happened in the current game, for the head referee to go over when a
+//! call is disputed.
+//!
+//! The game's stats hold the goals, the penalties already served, the timeouts, the shootout
+//! attempts and the start of each period. The penalties, warnings and fouls still in the
+//! editable lists only reach the stats at the end of the game, so until then they are taken from
+//! the lists, along with where to find them for editing.
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TimelineEntry {
+    pub(crate) line: SheetLine,
+    /// The editor that can still change this entry, if any
+    pub(crate) edit: Option<TimelineEdit>,
+}
+
+/// Where a timeline entry can be changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimelineEdit {
+    /// The goal's team and its index among that team's goals, as `disallow_goal` takes it
+    Goal(Color, usize),
+    /// An index into the penalty list of the team
+    Penalty(Color, usize),
+    Warning(Color, usize),
+    Foul(Option<Color>, usize),
+    /// The clock of the period or timeout in progress
+    GameClock,
+}
+
+impl TournamentManager {
+    /// The current game's timeline, most recent first. Once the game has ended nothing on it can
+    /// be edited any more.
+    pub(crate) fn timeline(&self) -> Vec<TimelineEntry> {
+        let ended = self.current_game_stats.end_timestamp().is_some();
+        let lines = self.current_game_stats.sheet_lines();
+        let last_timeout = lines
+            .iter()
+            .rposition(|line| matches!(line.kind, SheetLineKind::Timeout(_)));
+        let last_period_start = lines
+            .iter()
+            .rposition(|line| line.kind == SheetLineKind::PeriodStart);
+
+        let mut goal_index: BlackWhiteBundle<usize> = Default::default();
+        let mut entries = Vec::with_capacity(lines.len());
+        for (i, line) in lines.into_iter().enumerate() {
+            let edit = match line.kind {
+                SheetLineKind::Goal { .. } => line.color.map(|color| {
+                    goal_index[color] += 1;
+                    TimelineEdit::Goal(color, goal_index[color] - 1)
+                }),
+                // Until the game ends these are only in the lists, added below
+                SheetLineKind::Warning(_) | SheetLineKind::Foul(_) if !ended => continue,
+                SheetLineKind::Timeout(_)
+                    if Some(i) == last_timeout && self.timeout_state.is_some() =>
+                {
+                    Some(TimelineEdit::GameClock)
+                }
+                SheetLineKind::PeriodStart if Some(i) == last_period_start => {
+                    Some(TimelineEdit::GameClock)
+                }
+                _ => None,
+            };
+            entries.push(TimelineEntry {
+                line,
+                edit: edit.filter(|_| !ended),
+            });
+        }
+
+        if !ended {
+            let infraction_line = |details: &InfractionDetails, color, kind| SheetLine {
+                color,
+                player_number: details.player_number,
+                period: details.start_period,
+                period_time: details.start_time,
+                occurred_on: calculate_timestamp(details.start_instant),
+                kind,
+            };
+            for (color, penalties) in self.penalties.iter() {
+                entries.extend(
+                    penalties
+                        .iter()
+                        .enumerate()
+                        .map(|(i, penalty)| TimelineEntry {
+                            line: SheetLine {
+                                color: Some(color),
+                                player_number: Some(penalty.player_number),
+                                period: penalty.start_period,
+                                period_time: penalty.start_time,
+                                occurred_on: calculate_timestamp(penalty.start_instant),
+                                kind: SheetLineKind::Penalty {
+                                    duration: penalty.kind.as_duration(),
+                                    infraction: Some(penalty.infraction),
+                                },
+                            },
+                            edit: Some(TimelineEdit::Penalty(color, i)),
+                        }),
+                );
+            }
+            for (color, warnings) in self.warnings.iter() {
+                entries.extend(
+                    warnings
+                        .iter()
+                        .enumerate()
+                        .map(|(i, warning)| TimelineEntry {
+                            line: infraction_line(
+                                warning,
+                                Some(color),
+                                SheetLineKind::Warning(warning.infraction),
+                            ),
+                            edit: Some(TimelineEdit::Warning(color, i)),
+                        }),
+                );
+            }
+            for (color, fouls) in self.fouls.iter() {
+                entries.extend(fouls.iter().enumerate().map(|(i, foul)| TimelineEntry {
+                    line: infraction_line(foul, color, SheetLineKind::Foul(foul.infraction)),
+                    edit: Some(TimelineEdit::Foul(color, i)),
+                }));
+            }
+        }
+
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.line.occurred_on));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeline_points_each_entry_at_its_editor() {
+        let start = Instant::now();
+        let mut tm = TournamentManager::new(GameConfig::default());
+        tm.start_play_now(start).unwrap();
+        tm.update(start).unwrap();
+
+        tm.add_score(Color::White, 4, start + Duration::from_secs(5));
+        tm.add_score(Color::White, 7, start + Duration::from_secs(10));
+        tm.start_penalty(
+            Color::Black,
+            3,
+            PenaltyKind::ONE_MINUTE,
+            start + Duration::from_secs(15),
+            Infraction::OBSTRUCTION,
+        )
+        .unwrap();
+        tm.add_warning(
+            Color::White,
+            None,
+            Infraction::DELAY_OF_GAME,
+            start + Duration::from_secs(20),
+        )
+        .unwrap();
+        tm.add_foul(
+            None,
+            None,
+            Infraction::OBSTRUCTION,
+            start + Duration::from_secs(25),
+        )
+        .unwrap();
+        tm.start_ref_timeout(start + Duration::from_secs(30))
+            .unwrap();
+
+        let edits: Vec<_> = tm.timeline().into_iter().map(|e| e.edit).collect();
+        assert_eq!(
+            edits,
+            vec![
+                Some(TimelineEdit::GameClock),
+                Some(TimelineEdit::Foul(None, 0)),
+                Some(TimelineEdit::Warning(Color::White, 0)),
+                Some(TimelineEdit::Penalty(Color::Black, 0)),
+                Some(TimelineEdit::Goal(Color::White, 1)),
+                Some(TimelineEdit::Goal(Color::White, 0)),
+                Some(TimelineEdit::GameClock),
+            ]
+        );
+        assert_eq!(
+            tm.timeline().last().unwrap().line.kind,
+            SheetLineKind::PeriodStart
+        );
+    }
+}
@@ -481,3 +481,132 @@ gi-unknown = ???
 shut-down = HERUNTERFAHREN
 restart-pi = PI NEU STARTEN
 restart-refbox = REFBOX NEU STARTEN
+resume-saved-game = Beim Beenden der Refbox lief ein Spiel.
+    Spiel { $game_number }    Schwarz: { $score_black }    Weiß: { $score_white }
+    Dieses Spiel fortsetzen?
+resume-game = SPIEL FORTSETZEN
+start-fresh = NEU BEGINNEN
+undo = RÜCKGÄNGIG
+redo = WIEDERHOLEN
+penalty-shootout = PENALTYSCHIESSEN
+start-shootout = PENALTYSCHIESSEN STARTEN
+start-shootout-confirm = Plötzlichen Tod beenden und das Spiel im Penaltyschießen entscheiden? Dies kann nicht rückgängig gemacht werden.
+remove-last-attempt = LETZTEN VERSUCH ENTFERNEN
+shootout-line-1 = PENALTY-
+shootout-line-2 = VERSUCH
+shootout-round = Runde { $round }
+shot-scored = TOR
+shot-missed = VERFEHLT
+shootout-goal = TOR
+shootout-miss = VERFEHLT
+stop-clock = UHR ANHALTEN
+restart-clock = UHR NEU STARTEN
+suspended-players = GESPERRTE SPIELER
+suspended-player = { $team } #{ $number }: NOCH { $games } SPIEL(E)
+catch-up = AUFHOLEN
+catch-up-plan-game = SPIEL { $game }: PAUSE -{ $cut }, BEGINNT { $late } ZU SPÄT
+catch-up-plan-game-half-time = SPIEL { $game }: PAUSE -{ $cut }, BEGINNT { $late } ZU SPÄT, HALBZEIT { $half_time }
+catch-up-plan-on-time = AB SPIEL { $game } WIEDER IM ZEITPLAN
+catch-up-plan-late = NACH DEM LETZTEN SPIEL NOCH { $late } IM RÜCKSTAND
+accept-plan = PLAN ANNEHMEN
+shorten-half-times = HALBZEITPAUSEN KÜRZEN
+keep-half-times = HALBZEITPAUSEN BEIBEHALTEN
+disallow-goal = TOR ABERKENNEN
+disallowed-goals = ABERKANNTE TORE
+black-goals = TORE SCHWARZ
+white-goals = TORE WEISS
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FOUL
+    [infraction] REGELVERSTOSS
+   *[scorer-correction] FALSCHER TORSCHÜTZE
+}
+export-results = ERGEBNISSE EXPORTIEREN
+results-exported = Die Ergebnisse wurden exportiert nach:
+    { $dir }
+source-file = DATEI
+schedule-file = SPIELPLANDATEI:
+not-found = Nicht gefunden
+player-stats = SPIELERSTATISTIK
+top-scorers = TORSCHÜTZEN
+discipline = DISZIPLIN
+export = EXPORTIEREN
+no-player-stats = Noch keine Tore, Strafzeiten oder Fouls erfasst
+player-stats-goals = { $goals ->
+    [one] 1 Tor
+   *[other] { $goals } Tore
+}
+player-stats-discipline = TD { $dismissals }  STR { $penalties } ({ $time })  FOULS { $fouls }
+completed-scoresheet = AUSGEFÜLLTER SPIELBERICHT
+scoresheet-detailed = DETAILLIERT
+scoresheet-simple = EINFACH
+scoresheet-cmas-official = CMAS OFFIZIELL
+timeline = ZEITLEISTE
+no-timeline-entries = In diesem Spiel ist noch nichts passiert
+timeline-goal = TOR
+timeline-disallowed-goal = ABERKANNTES TOR
+timeline-penalty = STRAFE ({ penalty-kind })
+timeline-warning = VERWARNUNG
+timeline-foul = FOUL
+timeline-team-timeout = TEAM-AUSZEIT
+timeline-ref-timeout = SCHIRI-AUSZEIT
+timeline-penalty-shot = STRAFWURF
+timeline-period-start = ABSCHNITT BEGINNT
+captains-sign-off-result = KAPITÄNE BESTÄTIGEN
+    ERGEBNIS
+cap-number-or-id = KAPPEN-NR.
+    ODER ID:
+sign-off-entry-hint = Kappennummer oder eine andere Nummer des Kapitäns eingeben, oder 0 zum Löschen
+sign-off-result = SPIEL { $game } BESTÄTIGEN: SCHWARZ { $score_black } - WEISS { $score_white }
+captain-signed = KAPITÄN BESTÄTIGT
+not-signed = NICHT BESTÄTIGT
+protest-lodged = PROTEST EINGELEGT
+portal-row-under-review = Spiel { $game } In Prüfung, tippen, sobald der Protest geklärt ist
+report-incident = VORFALL MELDEN
+incident-category = {$category ->
+    [injury] VERLETZUNG
+    [equipment-failure] AUSRÜSTUNG
+    [misconduct] FEHLVERHALTEN
+   *[other] SONSTIGES
+}
+incident-player = SPIELER
+incident-time = Vorfall bei { $period } { $time }
+no-team = KEIN TEAM
+incident-description = Beschreiben Sie, was passiert ist
+incident-player-hint = Kappennummer des beteiligten Spielers eingeben, oder 0 für keinen
+edit-history = ÄNDERUNGEN
+no-edits = In diesem Spiel wurde nichts von Hand geändert
+audit-change = {$change ->
+    [score-confirmed] STAND BESTÄTIGT
+    [score-edited] STAND GEÄNDERT
+    [game-clock] SPIELUHR GESTELLT
+    [timeout-clock] AUSZEITUHR GESTELLT
+    [penalty-edited] STRAFE GEÄNDERT
+    [goal-disallowed] TOR ABERKANNT
+    [penalty-added] STRAFE HINZUGEFÜGT
+    [foul-added] FOUL HINZUGEFÜGT
+    [foul-deleted] FOUL GELÖSCHT
+    [clocks-set] UHREN GESTELLT
+   *[penalty-deleted] STRAFE GELÖSCHT
+}
+audit-clock-stopped = ANGEHALTEN
+penalty-shootout-allowed = PENALTY-
+    SCHIESSEN:
+shootout-rounds = SCHIESSEN
+    RUNDEN:
+gi-penalty-shootout = Penaltyschießen
+gi-shootout-rounds = Runden im Penaltyschießen
+stop-time-allowed = STOPPZEIT:
+stop-time-window = STOPPZEIT
+    FENSTER:
+stop-time-window-len = STOPPZEIT-FENSTER
+stop-time-window-help = Wie lange vor dem Ende jeder Spielzeit jeder Pfiff die Spieluhr anhält
+gi-stop-time = Stoppzeit
+gi-stop-time-window = Stoppzeit-Fenster
@@ -481,3 +481,132 @@ gi-unknown = ???
 shut-down = SHUT DOWN
 restart-pi = RESTART PI
 restart-refbox = RESTART REFBOX
+resume-saved-game = A game was in progress when the refbox stopped.
+    Game { $game_number }    Black: { $score_black }    White: { $score_white }
+    Resume this game?
+resume-game = RESUME GAME
+start-fresh = START FRESH
+undo = UNDO
+redo = REDO
+penalty-shootout = PENALTY SHOOTOUT
+start-shootout = START SHOOTOUT
+start-shootout-confirm = End sudden death and settle the game with a penalty shootout? This can't be undone.
+remove-last-attempt = REMOVE LAST ATTEMPT
+shootout-line-1 = SHOOTOUT
+shootout-line-2 = ATTEMPT
+shootout-round = Round { $round }
+shot-scored = SCORED
+shot-missed = MISSED
+shootout-goal = GOAL
+shootout-miss = MISS
+stop-clock = STOP CLOCK
+restart-clock = RESTART CLOCK
+suspended-players = SUSPENDED PLAYERS
+suspended-player = { $team } #{ $number }: { $games } GAME(S) LEFT
+catch-up = CATCH UP
+catch-up-plan-game = GAME { $game }: BREAK -{ $cut }, STARTS { $late } LATE
+catch-up-plan-game-half-time = GAME { $game }: BREAK -{ $cut }, STARTS { $late } LATE, HALF-TIME { $half_time }
+catch-up-plan-on-time = BACK ON SCHEDULE BY GAME { $game }
+catch-up-plan-late = STILL { $late } BEHIND AFTER THE LAST GAME
+accept-plan = ACCEPT PLAN
+shorten-half-times = SHORTEN HALF-TIMES
+keep-half-times = KEEP HALF-TIMES
+disallow-goal = DISALLOW GOAL
+disallowed-goals = DISALLOWED GOALS
+black-goals = BLACK GOALS
+white-goals = WHITE GOALS
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FOUL
+    [infraction] INFRACTION
+   *[scorer-correction] WRONG SCORER
+}
+export-results = EXPORT RESULTS
+results-exported = The results were exported to:
+    { $dir }
+source-file = FILE
+schedule-file = SCHEDULE FILE:
+not-found = Not Found
+player-stats = PLAYER STATS
+top-scorers = TOP SCORERS
+discipline = DISCIPLINE
+export = EXPORT
+no-player-stats = No goals, penalties or fouls recorded yet
+player-stats-goals = { $goals ->
+    [one] 1 goal
+   *[other] { $goals } goals
+}
+player-stats-discipline = TD { $dismissals }  PEN { $penalties } ({ $time })  FOULS { $fouls }
+completed-scoresheet = COMPLETED SCORESHEET
+scoresheet-detailed = DETAILED
+scoresheet-simple = SIMPLE
+scoresheet-cmas-official = CMAS OFFICIAL
+timeline = TIMELINE
+no-timeline-entries = Nothing has happened in this game yet
+timeline-goal = GOAL
+timeline-disallowed-goal = DISALLOWED GOAL
+timeline-penalty = PENALTY ({ penalty-kind })
+timeline-warning = WARNING
+timeline-foul = FOUL
+timeline-team-timeout = TEAM TIMEOUT
+timeline-ref-timeout = REF TIMEOUT
+timeline-penalty-shot = PENALTY SHOT
+timeline-period-start = PERIOD STARTS
+captains-sign-off-result = CAPTAINS SIGN
+    OFF RESULT
+cap-number-or-id = CAP # OR
+    ID:
+sign-off-entry-hint = Enter the captain's cap number or another number identifying them, or 0 to clear
+sign-off-result = SIGN OFF GAME { $game }: BLACK { $score_black } - WHITE { $score_white }
+captain-signed = CAPTAIN SIGNED
+not-signed = NOT SIGNED
+protest-lodged = PROTEST LODGED
+portal-row-under-review = Game { $game } Under review, tap once the protest is settled
+report-incident = REPORT INCIDENT
+incident-category = {$category ->
+    [injury] INJURY
+    [equipment-failure] EQUIPMENT
+    [misconduct] MISCONDUCT
+   *[other] OTHER
+}
+incident-player = PLAYER
+incident-time = Incident at { $period } { $time }
+no-team = NO TEAM
+incident-description = Describe what happened
+incident-player-hint = Enter the cap number of the player involved, or 0 for none
+edit-history = EDIT HISTORY
+no-edits = Nothing has been changed by hand in this game
+audit-change = {$change ->
+    [score-confirmed] SCORE CONFIRMED
+    [score-edited] SCORE EDITED
+    [game-clock] GAME CLOCK SET
+    [timeout-clock] TIMEOUT CLOCK SET
+    [penalty-edited] PENALTY EDITED
+    [goal-disallowed] GOAL DISALLOWED
+    [penalty-added] PENALTY ADDED
+    [foul-added] FOUL ADDED
+    [foul-deleted] FOUL DELETED
+    [clocks-set] CLOCKS SET
+   *[penalty-deleted] PENALTY DELETED
+}
+audit-clock-stopped = STOPPED
+penalty-shootout-allowed = PENALTY
+    SHOOTOUT:
+shootout-rounds = SHOOTOUT
+    ROUNDS:
+gi-penalty-shootout = Penalty Shootout
+gi-shootout-rounds = Shootout Rounds
+stop-time-allowed = STOP TIME:
+stop-time-window = STOP TIME
+    WINDOW:
+stop-time-window-len = STOP TIME WINDOW
+stop-time-window-help = How long before the end of each play period every whistle stops the game clock
+gi-stop-time = Stop Time
+gi-stop-time-window = Stop Time Window
@@ -493,3 +493,133 @@ gi-unknown = ???
 shut-down = APAGAR
 restart-pi = REINICIAR PI
 restart-refbox = REINICIAR REFBOX
+resume-saved-game = Había un partido en curso cuando se detuvo el refbox.
+    Partido { $game_number }    Negro: { $score_black }    Blanco: { $score_white }
+    ¿Reanudar este partido?
+resume-game = REANUDAR PARTIDO
+start-fresh = EMPEZAR DE NUEVO
+undo = DESHACER
+redo = REHACER
+penalty-shootout = TANDA DE PENALTIS
+start-shootout = INICIAR PENALTIS
+start-shootout-confirm = ¿Terminar la muerte súbita y decidir el partido con una tanda de penaltis? No se puede deshacer.
+remove-last-attempt = QUITAR ÚLTIMO INTENTO
+shootout-line-1 = INTENTO DE
+shootout-line-2 = PENALTI
+shootout-round = Ronda { $round }
+shot-scored = GOL
+shot-missed = FALLADO
+shootout-goal = GOL
+shootout-miss = FALLO
+stop-clock = PARAR RELOJ
+restart-clock = REANUDAR RELOJ
+suspended-players = JUGADORES SUSPENDIDOS
+suspended-player = { $team } #{ $number }: QUEDAN { $games } PARTIDO(S)
+catch-up = RECUPERAR TIEMPO
+catch-up-plan-game = PARTIDO { $game }: DESCANSO -{ $cut }, EMPIEZA CON { $late } DE RETRASO
+catch-up-plan-game-half-time = PARTIDO { $game }: DESCANSO -{ $cut }, EMPIEZA CON { $late } DE RETRASO, MEDIO TIEMPO { $half_time }
+catch-up-plan-on-time = DE VUELTA EN HORARIO EN EL PARTIDO { $game }
+catch-up-plan-late = AÚN { $late } DE RETRASO TRAS EL ÚLTIMO PARTIDO
+accept-plan = ACEPTAR PLAN
+shorten-half-times = ACORTAR MEDIOS TIEMPOS
+keep-half-times = MANTENER MEDIOS TIEMPOS
+disallow-goal = ANULAR GOL
+disallowed-goals = GOLES ANULADOS
+black-goals = GOLES NEGRO
+white-goals = GOLES BLANCO
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FALTA
+    [infraction] INFRACCIÓN
+   *[scorer-correction] GOLEADOR ERRÓNEO
+}
+export-results = EXPORTAR RESULTADOS
+results-exported = Los resultados se exportaron a:
+    { $dir }
+source-file = ARCHIVO
+schedule-file = ARCHIVO DE CALENDARIO:
+not-found = No encontrado
+player-stats = ESTADÍSTICAS
+top-scorers = GOLEADORES
+discipline = DISCIPLINA
+export = EXPORTAR
+no-player-stats = Aún no hay goles, expulsiones ni faltas
+player-stats-goals = { $goals ->
+    [one] 1 gol
+   *[other] { $goals } goles
+}
+player-stats-discipline = ED { $dismissals }  EXP { $penalties } ({ $time })  FALTAS { $fouls }
+completed-scoresheet = ACTA COMPLETADA
+scoresheet-detailed = DETALLADA
+scoresheet-simple = SIMPLE
+scoresheet-cmas-official = CMAS OFICIAL
+timeline = CRONOLOGÍA
+no-timeline-entries = Todavía no ha pasado nada en este partido
+timeline-goal = GOL
+timeline-disallowed-goal = GOL ANULADO
+timeline-penalty = PENALIZACIÓN ({ penalty-kind })
+timeline-warning = ADVERTENCIA
+timeline-foul = FALTA
+timeline-team-timeout = TIEMPO MUERTO DE EQUIPO
+timeline-ref-timeout = TIEMPO MUERTO DEL ÁRBITRO
+timeline-penalty-shot = TIRO PENAL
+timeline-period-start = COMIENZA EL PERIODO
+captains-sign-off-result = CAPITANES FIRMAN
+    RESULTADO
+cap-number-or-id = N.º GORRO
+    O ID:
+sign-off-entry-hint = Introduzca el número de gorro del capitán u otro número que lo identifique, o 0 para borrar
+sign-off-result = FIRMAR PARTIDO { $game }: NEGRO { $score_black } - BLANCO { $score_white }
+captain-signed = CAPITÁN FIRMÓ
+not-signed = SIN FIRMAR
+protest-lodged = PROTESTA PRESENTADA
+portal-row-under-review = Partido { $game } En revisión, toque cuando se resuelva la protesta
+report-incident = REPORTAR INCIDENTE
+incident-category = {$category ->
+    [injury] LESIÓN
+    [equipment-failure] EQUIPO
+    [misconduct] CONDUCTA
+   *[other] OTRO
+}
+incident-player = JUGADOR
+incident-time = Incidente en { $period } { $time }
+no-team = SIN EQUIPO
+incident-description = Describa lo ocurrido
+incident-player-hint = Introduzca el número de gorro del jugador implicado, o 0 para ninguno
+edit-history = HISTORIAL DE CAMBIOS
+no-edits = No se ha cambiado nada a mano en este partido
+audit-change = {$change ->
+    [score-confirmed] MARCADOR CONFIRMADO
+    [score-edited] MARCADOR EDITADO
+    [game-clock] RELOJ DE JUEGO AJUSTADO
+    [timeout-clock] RELOJ DE TIEMPO MUERTO AJUSTADO
+    [penalty-edited] PENALIZACIÓN EDITADA
+    [goal-disallowed] GOL ANULADO
+    [penalty-added] PENALIZACIÓN AÑADIDA
+    [foul-added] FALTA AÑADIDA
+    [foul-deleted] FALTA ELIMINADA
+    [clocks-set] RELOJES AJUSTADOS
+   *[penalty-deleted] PENALIZACIÓN ELIMINADA
+}
+audit-clock-stopped = DETENIDO
+penalty-shootout-allowed = TANDA DE
+    PENALES:
+shootout-rounds = RONDAS DE
+    PENALES:
+gi-penalty-shootout = Tanda de Penales
+gi-shootout-rounds = Rondas de Penales
+stop-time-allowed = TIEMPO
+    PARADO:
+stop-time-window = VENTANA DE
+    TIEMPO PARADO:
+stop-time-window-len = VENTANA DE TIEMPO PARADO
+stop-time-window-help = Cuánto tiempo antes del final de cada periodo de juego cada silbato detiene el reloj
+gi-stop-time = Tiempo Parado
+gi-stop-time-window = Ventana de Tiempo Parado
@@ -485,3 +485,133 @@ gi-unknown = ???
 shut-down = ÉTEINDRE
 restart-pi = REDÉMARRER PI
 restart-refbox = REDÉMARRER REFBOX
+resume-saved-game = Un match était en cours lorsque la refbox s'est arrêtée.
+    Match { $game_number }    Noir : { $score_black }    Blanc : { $score_white }
+    Reprendre ce match ?
+resume-game = REPRENDRE LE MATCH
+start-fresh = RECOMMENCER
+undo = ANNULER
+redo = RÉTABLIR
+penalty-shootout = TIRS AU BUT
+start-shootout = COMMENCER LES TIRS AU BUT
+start-shootout-confirm = Terminer la mort subite et départager le match aux tirs au but ? Cette action est irréversible.
+remove-last-attempt = SUPPRIMER LE DERNIER TIR
+shootout-line-1 = TIR
+shootout-line-2 = AU BUT
+shootout-round = Tour { $round }
+shot-scored = MARQUÉ
+shot-missed = MANQUÉ
+shootout-goal = BUT
+shootout-miss = RATÉ
+stop-clock = ARRÊTER LE CHRONO
+restart-clock = RELANCER LE CHRONO
+suspended-players = JOUEURS SUSPENDUS
+suspended-player = { $team } #{ $number } : ENCORE { $games } MATCH(S)
+catch-up = RATTRAPER LE RETARD
+catch-up-plan-game = MATCH { $game } : PAUSE -{ $cut }, COMMENCE AVEC { $late } DE RETARD
+catch-up-plan-game-half-time = MATCH { $game } : PAUSE -{ $cut }, COMMENCE AVEC { $late } DE RETARD, MI-TEMPS { $half_time }
+catch-up-plan-on-time = DE RETOUR À L'HEURE AU MATCH { $game }
+catch-up-plan-late = ENCORE { $late } DE RETARD APRÈS LE DERNIER MATCH
+accept-plan = ACCEPTER LE PLAN
+shorten-half-times = RACCOURCIR LES MI-TEMPS
+keep-half-times = GARDER LES MI-TEMPS
+disallow-goal = REFUSER LE BUT
+disallowed-goals = BUTS REFUSÉS
+black-goals = BUTS NOIR
+white-goals = BUTS BLANC
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FAUTE
+    [infraction] INFRACTION
+   *[scorer-correction] MAUVAIS BUTEUR
+}
+export-results = EXPORTER LES RÉSULTATS
+results-exported = Les résultats ont été exportés vers :
+    { $dir }
+source-file = FICHIER
+schedule-file = FICHIER DU PROGRAMME:
+not-found = Introuvable
+player-stats = STATS JOUEURS
+top-scorers = BUTEURS
+discipline = DISCIPLINE
+export = EXPORTER
+no-player-stats = Aucun but, pénalité ou faute enregistré
+player-stats-goals = { $goals ->
+    [one] 1 but
+   *[other] { $goals } buts
+}
+player-stats-discipline = ED { $dismissals }  PÉN { $penalties } ({ $time })  FAUTES { $fouls }
+completed-scoresheet = FEUILLE DE MATCH REMPLIE
+scoresheet-detailed = DÉTAILLÉE
+scoresheet-simple = SIMPLE
+scoresheet-cmas-official = CMAS OFFICIEL
+timeline = CHRONOLOGIE
+no-timeline-entries = Rien ne s'est encore passé dans ce match
+timeline-goal = BUT
+timeline-disallowed-goal = BUT REFUSÉ
+timeline-penalty = PÉNALITÉ ({ penalty-kind })
+timeline-warning = AVERTISSEMENT
+timeline-foul = FAUTE
+timeline-team-timeout = TEMPS MORT D'ÉQUIPE
+timeline-ref-timeout = TEMPS MORT ARBITRE
+timeline-penalty-shot = TIR DE PÉNALITÉ
+timeline-period-start = DÉBUT DE PÉRIODE
+captains-sign-off-result = CAPITAINES SIGNENT
+    RÉSULTAT
+cap-number-or-id = N° BONNET
+    OU ID :
+sign-off-entry-hint = Saisir le numéro de bonnet du capitaine ou un autre numéro qui l'identifie, ou 0 pour effacer
+sign-off-result = SIGNER LE MATCH { $game } : NOIR { $score_black } - BLANC { $score_white }
+captain-signed = CAPITAINE A SIGNÉ
+not-signed = NON SIGNÉ
+protest-lodged = RÉCLAMATION DÉPOSÉE
+portal-row-under-review = Match { $game } En examen, touchez une fois la réclamation réglée
+report-incident = SIGNALER UN INCIDENT
+incident-category = {$category ->
+    [injury] BLESSURE
+    [equipment-failure] ÉQUIPEMENT
+    [misconduct] INCONDUITE
+   *[other] AUTRE
+}
+incident-player = JOUEUR
+incident-time = Incident à { $period } { $time }
+no-team = AUCUNE ÉQUIPE
+incident-description = Décrivez ce qui s'est passé
+incident-player-hint = Saisir le numéro de bonnet du joueur impliqué, ou 0 pour aucun
+edit-history = HISTORIQUE DES MODIFICATIONS
+no-edits = Rien n'a été modifié à la main dans ce match
+audit-change = {$change ->
+    [score-confirmed] SCORE CONFIRMÉ
+    [score-edited] SCORE MODIFIÉ
+    [game-clock] CHRONO DE JEU RÉGLÉ
+    [timeout-clock] CHRONO DE TEMPS MORT RÉGLÉ
+    [penalty-edited] PÉNALITÉ MODIFIÉE
+    [goal-disallowed] BUT REFUSÉ
+    [penalty-added] PÉNALITÉ AJOUTÉE
+    [foul-added] FAUTE AJOUTÉE
+    [foul-deleted] FAUTE SUPPRIMÉE
+    [clocks-set] CHRONOS RÉGLÉS
+   *[penalty-deleted] PÉNALITÉ SUPPRIMÉE
+}
+audit-clock-stopped = ARRÊTÉ
+penalty-shootout-allowed = TIRS AU
+    BUT:
+shootout-rounds = TOURS DE
+    TIRS AU BUT:
+gi-penalty-shootout = Tirs au But
+gi-shootout-rounds = Tours de Tirs au But
+stop-time-allowed = TEMPS
+    ARRÊTÉ:
+stop-time-window = FENÊTRE DE
+    TEMPS ARRÊTÉ:
+stop-time-window-len = FENÊTRE DE TEMPS ARRÊTÉ
+stop-time-window-help = Combien de temps avant la fin de chaque période de jeu chaque coup de sifflet arrête le chrono
+gi-stop-time = Temps Arrêté
+gi-stop-time-window = Fenêtre de Temps Arrêté
@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = MATIKAN
 restart-pi = MULAI ULANG PI
 restart-refbox = MULAI ULANG REFBOX
+resume-saved-game = Ada pertandingan yang sedang berlangsung saat refbox berhenti.
+    Pertandingan { $game_number }    Hitam: { $score_black }    Putih: { $score_white }
+    Lanjutkan pertandingan ini?
+resume-game = LANJUTKAN PERTANDINGAN
+start-fresh = MULAI BARU
+undo = URUNGKAN
+redo = ULANGI
+penalty-shootout = ADU PENALTI
+start-shootout = MULAI ADU PENALTI
+start-shootout-confirm = Akhiri sudden death dan selesaikan pertandingan dengan adu penalti? Ini tidak dapat dibatalkan.
+remove-last-attempt = HAPUS TENDANGAN TERAKHIR
+shootout-line-1 = TENDANGAN
+shootout-line-2 = PENALTI
+shootout-round = Babak { $round }
+shot-scored = GOL
+shot-missed = GAGAL
+shootout-goal = GOL
+shootout-miss = GAGAL
+stop-clock = HENTIKAN JAM
+restart-clock = JALANKAN JAM LAGI
+suspended-players = PEMAIN DISKORS
+suspended-player = { $team } #{ $number }: SISA { $games } PERTANDINGAN
+catch-up = KEJAR JADWAL
+catch-up-plan-game = PERTANDINGAN { $game }: ISTIRAHAT -{ $cut }, MULAI TERLAMBAT { $late }
+catch-up-plan-game-half-time = PERTANDINGAN { $game }: ISTIRAHAT -{ $cut }, MULAI TERLAMBAT { $late }, TURUN MINUM { $half_time }
+catch-up-plan-on-time = KEMBALI SESUAI JADWAL PADA PERTANDINGAN { $game }
+catch-up-plan-late = MASIH TERLAMBAT { $late } SETELAH PERTANDINGAN TERAKHIR
+accept-plan = TERIMA RENCANA
+shorten-half-times = PERSINGKAT TURUN MINUM
+keep-half-times = PERTAHANKAN TURUN MINUM
+disallow-goal = BATALKAN GOL
+disallowed-goals = GOL DIBATALKAN
+black-goals = GOL HITAM
+white-goals = GOL PUTIH
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] PELANGGARAN
+    [infraction] INFRAKSI
+   *[scorer-correction] PENCETAK SALAH
+}
+export-results = EKSPOR HASIL
+results-exported = Hasil telah diekspor ke:
+    { $dir }
+source-file = BERKAS
+schedule-file = BERKAS JADWAL:
+not-found = Tidak Ditemukan
+player-stats = STATISTIK PEMAIN
+top-scorers = PENCETAK GOL
+discipline = DISIPLIN
+export = EKSPOR
+no-player-stats = Belum ada gol, penalti, atau pelanggaran
+player-stats-goals = { $goals ->
+    [one] 1 gol
+   *[other] { $goals } gol
+}
+player-stats-discipline = TD { $dismissals }  PEN { $penalties } ({ $time })  PLG { $fouls }
+completed-scoresheet = LEMBAR SKOR LENGKAP
+scoresheet-detailed = TERPERINCI
+scoresheet-simple = SEDERHANA
+scoresheet-cmas-official = CMAS RESMI
+timeline = LINIMASA
+no-timeline-entries = Belum ada kejadian di pertandingan ini
+timeline-goal = GOL
+timeline-disallowed-goal = GOL DIANULIR
+timeline-penalty = PENALTI ({ penalty-kind })
+timeline-warning = PERINGATAN
+timeline-foul = PELANGGARAN
+timeline-team-timeout = TIMEOUT TIM
+timeline-ref-timeout = TIMEOUT WASIT
+timeline-penalty-shot = TEMBAKAN PENALTI
+timeline-period-start = BABAK DIMULAI
+captains-sign-off-result = KAPTEN TANDA TANGANI
+    HASIL
+cap-number-or-id = NO. TOPI
+    ATAU ID:
+sign-off-entry-hint = Masukkan nomor topi kapten atau nomor lain yang mengidentifikasinya, atau 0 untuk menghapus
+sign-off-result = TANDA TANGANI PERTANDINGAN { $game }: HITAM { $score_black } - PUTIH { $score_white }
+captain-signed = KAPTEN MENANDATANGANI
+not-signed = BELUM DITANDATANGANI
+protest-lodged = PROTES DIAJUKAN
+portal-row-under-review = Pertandingan { $game } Sedang ditinjau, ketuk setelah protes selesai
+report-incident = LAPORKAN INSIDEN
+incident-category = {$category ->
+    [injury] CEDERA
+    [equipment-failure] PERALATAN
+    [misconduct] PELANGGARAN ETIKA
+   *[other] LAINNYA
+}
+incident-player = PEMAIN
+incident-time = Insiden pada { $period } { $time }
+no-team = TANPA TIM
+incident-description = Jelaskan apa yang terjadi
+incident-player-hint = Masukkan nomor topi pemain yang terlibat, atau 0 jika tidak ada
+edit-history = RIWAYAT PERUBAHAN
+no-edits = Tidak ada yang diubah secara manual dalam pertandingan ini
+audit-change = {$change ->
+    [score-confirmed] SKOR DIKONFIRMASI
+    [score-edited] SKOR DIUBAH
+    [game-clock] JAM PERTANDINGAN DIATUR
+    [timeout-clock] JAM TIMEOUT DIATUR
+    [penalty-edited] PENALTI DIUBAH
+    [goal-disallowed] GOL DIANULIR
+    [penalty-added] PENALTI DITAMBAHKAN
+    [foul-added] PELANGGARAN DITAMBAHKAN
+    [foul-deleted] PELANGGARAN DIHAPUS
+    [clocks-set] JAM DIATUR
+   *[penalty-deleted] PENALTI DIHAPUS
+}
+audit-clock-stopped = BERHENTI
+penalty-shootout-allowed = ADU
+    PENALTI:
+shootout-rounds = BABAK ADU
+    PENALTI:
+gi-penalty-shootout = Adu Penalti
+gi-shootout-rounds = Babak Adu Penalti
+stop-time-allowed = WAKTU
+    BERHENTI:
+stop-time-window = JENDELA WAKTU
+    BERHENTI:
+stop-time-window-len = JENDELA WAKTU BERHENTI
+stop-time-window-help = Berapa lama sebelum akhir setiap periode permainan setiap peluit menghentikan jam permainan
+gi-stop-time = Waktu Berhenti
+gi-stop-time-window = Jendela Waktu Berhenti
@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = SPEGNI
 restart-pi = RIAVVIA PI
 restart-refbox = RIAVVIA REFBOX
+resume-saved-game = Una partita era in corso quando il refbox si è fermato.
+    Partita { $game_number }    Nero: { $score_black }    Bianco: { $score_white }
+    Riprendere questa partita?
+resume-game = RIPRENDI PARTITA
+start-fresh = RICOMINCIA DA CAPO
+undo = ANNULLA
+redo = RIPETI
+penalty-shootout = RIGORI
+start-shootout = INIZIA RIGORI
+start-shootout-confirm = Terminare la morte improvvisa e decidere la partita ai rigori? L'operazione non può essere annullata.
+remove-last-attempt = RIMUOVI ULTIMO TIRO
+shootout-line-1 = TIRO
+shootout-line-2 = DI RIGORE
+shootout-round = Turno { $round }
+shot-scored = SEGNATO
+shot-missed = SBAGLIATO
+shootout-goal = GOL
+shootout-miss = SBAGLIATO
+stop-clock = FERMA CRONOMETRO
+restart-clock = RIAVVIA CRONOMETRO
+suspended-players = GIOCATORI SQUALIFICATI
+suspended-player = { $team } #{ $number }: ANCORA { $games } PARTITA/E
+catch-up = RECUPERA RITARDO
+catch-up-plan-game = PARTITA { $game }: PAUSA -{ $cut }, INIZIA CON { $late } DI RITARDO
+catch-up-plan-game-half-time = PARTITA { $game }: PAUSA -{ $cut }, INIZIA CON { $late } DI RITARDO, INTERVALLO { $half_time }
+catch-up-plan-on-time = DI NUOVO IN ORARIO DALLA PARTITA { $game }
+catch-up-plan-late = ANCORA { $late } DI RITARDO DOPO L'ULTIMA PARTITA
+accept-plan = ACCETTA PIANO
+shorten-half-times = ACCORCIA INTERVALLI
+keep-half-times = MANTIENI INTERVALLI
+disallow-goal = ANNULLA GOL
+disallowed-goals = GOL ANNULLATI
+black-goals = GOL NERO
+white-goals = GOL BIANCO
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FALLO
+    [infraction] INFRAZIONE
+   *[scorer-correction] MARCATORE ERRATO
+}
+export-results = ESPORTA RISULTATI
+results-exported = I risultati sono stati esportati in:
+    { $dir }
+source-file = FILE
+schedule-file = FILE CALENDARIO:
+not-found = Non trovato
+player-stats = STATISTICHE
+top-scorers = MARCATORI
+discipline = DISCIPLINA
+export = ESPORTA
+no-player-stats = Nessun gol, penalità o fallo registrato
+player-stats-goals = { $goals ->
+    [one] 1 gol
+   *[other] { $goals } gol
+}
+player-stats-discipline = ED { $dismissals }  PEN { $penalties } ({ $time })  FALLI { $fouls }
+completed-scoresheet = REFERTO COMPILATO
+scoresheet-detailed = DETTAGLIATO
+scoresheet-simple = SEMPLICE
+scoresheet-cmas-official = CMAS UFFICIALE
+timeline = CRONOLOGIA
+no-timeline-entries = In questa partita non è ancora successo nulla
+timeline-goal = GOL
+timeline-disallowed-goal = GOL ANNULLATO
+timeline-penalty = PENALITÀ ({ penalty-kind })
+timeline-warning = AMMONIZIONE
+timeline-foul = FALLO
+timeline-team-timeout = TIMEOUT SQUADRA
+timeline-ref-timeout = TIMEOUT ARBITRO
+timeline-penalty-shot = TIRO DI RIGORE
+timeline-period-start = INIZIO PERIODO
+captains-sign-off-result = CAPITANI FIRMANO
+    RISULTATO
+cap-number-or-id = N. CUFFIA
+    O ID:
+sign-off-entry-hint = Inserire il numero di cuffia del capitano o un altro numero che lo identifichi, o 0 per cancellare
+sign-off-result = FIRMA PARTITA { $game }: NERO { $score_black } - BIANCO { $score_white }
+captain-signed = CAPITANO HA FIRMATO
+not-signed = NON FIRMATO
+protest-lodged = RECLAMO PRESENTATO
+portal-row-under-review = Partita { $game } In revisione, toccare quando il reclamo è risolto
+report-incident = SEGNALA INCIDENTE
+incident-category = {$category ->
+    [injury] INFORTUNIO
+    [equipment-failure] ATTREZZATURA
+    [misconduct] CONDOTTA SCORRETTA
+   *[other] ALTRO
+}
+incident-player = GIOCATORE
+incident-time = Incidente a { $period } { $time }
+no-team = NESSUNA SQUADRA
+incident-description = Descrivere l'accaduto
+incident-player-hint = Inserire il numero di cuffia del giocatore coinvolto, o 0 per nessuno
+edit-history = STORICO MODIFICHE
+no-edits = Nulla è stato modificato a mano in questa partita
+audit-change = {$change ->
+    [score-confirmed] PUNTEGGIO CONFERMATO
+    [score-edited] PUNTEGGIO MODIFICATO
+    [game-clock] CRONOMETRO DI GIOCO IMPOSTATO
+    [timeout-clock] CRONOMETRO TIMEOUT IMPOSTATO
+    [penalty-edited] PENALITÀ MODIFICATA
+    [goal-disallowed] GOL ANNULLATO
+    [penalty-added] PENALITÀ AGGIUNTA
+    [foul-added] FALLO AGGIUNTO
+    [foul-deleted] FALLO ELIMINATO
+    [clocks-set] CRONOMETRI IMPOSTATI
+   *[penalty-deleted] PENALITÀ ELIMINATA
+}
+audit-clock-stopped = FERMO
+penalty-shootout-allowed = TIRI DI
+    RIGORE:
+shootout-rounds = TURNI DI
+    RIGORI:
+gi-penalty-shootout = Tiri di Rigore
+gi-shootout-rounds = Turni di Rigori
+stop-time-allowed = TEMPO
+    EFFETTIVO:
+stop-time-window = FINESTRA TEMPO
+    EFFETTIVO:
+stop-time-window-len = FINESTRA TEMPO EFFETTIVO
+stop-time-window-help = Quanto tempo prima della fine di ogni periodo di gioco ogni fischio ferma il cronometro
+gi-stop-time = Tempo Effettivo
+gi-stop-time-window = Finestra Tempo Effettivo
@@ -481,3 +481,132 @@ gi-unknown = ???
 shut-down = シャットダウン
 restart-pi = Pi を再起動
 restart-refbox = Refbox を再起動
+resume-saved-game = Refboxが停止したとき、試合が進行中でした。
+    試合 { $game_number }    黒: { $score_black }    白: { $score_white }
+    この試合を再開しますか？
+resume-game = 試合を再開
+start-fresh = 新しく始める
+undo = 元に戻す
+redo = やり直す
+penalty-shootout = PK戦
+start-shootout = PK戦開始
+start-shootout-confirm = サドンデスを終了し、PK戦で試合を決めますか？元に戻すことはできません。
+remove-last-attempt = 最後のキックを削除
+shootout-line-1 = PK
+shootout-line-2 = キック
+shootout-round = 第{ $round }ラウンド
+shot-scored = 成功
+shot-missed = 失敗
+shootout-goal = 成功
+shootout-miss = 失敗
+stop-clock = 時計を停止
+restart-clock = 時計を再開
+suspended-players = 出場停止選手
+suspended-player = { $team } #{ $number }: 残り{ $games }試合
+catch-up = 遅れを取り戻す
+catch-up-plan-game = 試合 { $game }: 休憩 -{ $cut }、{ $late } 遅れて開始
+catch-up-plan-game-half-time = 試合 { $game }: 休憩 -{ $cut }、{ $late } 遅れて開始、ハーフタイム { $half_time }
+catch-up-plan-on-time = 試合 { $game } でスケジュールに復帰
+catch-up-plan-late = 最終試合後も { $late } の遅れ
+accept-plan = 計画を承認
+shorten-half-times = ハーフタイムを短縮
+keep-half-times = ハーフタイムを維持
+disallow-goal = ゴール取り消し
+disallowed-goals = 取り消されたゴール
+black-goals = 黒チームゴール
+white-goals = 白チームゴール
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] ファウル
+    [infraction] 反則
+   *[scorer-correction] 得点者の訂正
+}
+export-results = 結果をエクスポート
+results-exported = 結果のエクスポート先:
+    { $dir }
+source-file = ファイル
+schedule-file = スケジュールファイル:
+not-found = 見つかりません
+player-stats = 選手成績
+top-scorers = 得点ランキング
+discipline = 反則記録
+export = エクスポート
+no-player-stats = 得点・退水・反則の記録はまだありません
+player-stats-goals = { $goals ->
+    [one] 1 得点
+   *[other] { $goals } 得点
+}
+player-stats-discipline = 完全退場 { $dismissals }  退水 { $penalties } ({ $time })  反則 { $fouls }
+completed-scoresheet = 記入済みスコアシート
+scoresheet-detailed = 詳細
+scoresheet-simple = シンプル
+scoresheet-cmas-official = CMAS 公式
+timeline = タイムライン
+no-timeline-entries = この試合ではまだ何も起きていません
+timeline-goal = ゴール
+timeline-disallowed-goal = 取り消されたゴール
+timeline-penalty = ペナルティ ({ penalty-kind })
+timeline-warning = 警告
+timeline-foul = ファウル
+timeline-team-timeout = チームタイムアウト
+timeline-ref-timeout = レフェリータイムアウト
+timeline-penalty-shot = ペナルティショット
+timeline-period-start = ピリオド開始
+captains-sign-off-result = キャプテンの
+    結果承認
+cap-number-or-id = キャップ番号
+    またはID:
+sign-off-entry-hint = キャプテンのキャップ番号または識別番号を入力(0でクリア)
+sign-off-result = 試合 { $game } の承認: 黒 { $score_black } - 白 { $score_white }
+captain-signed = キャプテン署名済み
+not-signed = 未署名
+protest-lodged = 抗議あり
+portal-row-under-review = 試合 { $game } 審査中、抗議が解決したらタップ
+report-incident = 事故を報告
+incident-category = {$category ->
+    [injury] 負傷
+    [equipment-failure] 用具
+    [misconduct] 不正行為
+   *[other] その他
+}
+incident-player = 選手
+incident-time = 事故発生: { $period } { $time }
+no-team = チームなし
+incident-description = 何が起きたかを記入
+incident-player-hint = 関係した選手のキャップ番号を入力(なしは0)
+edit-history = 変更履歴
+no-edits = この試合では手動の変更はありません
+audit-change = {$change ->
+    [score-confirmed] スコア確認
+    [score-edited] スコア編集
+    [game-clock] 試合時計を設定
+    [timeout-clock] タイムアウト時計を設定
+    [penalty-edited] ペナルティ編集
+    [goal-disallowed] ゴール取り消し
+    [penalty-added] ペナルティ追加
+    [foul-added] ファウル追加
+    [foul-deleted] ファウル削除
+    [clocks-set] 時計を設定
+   *[penalty-deleted] ペナルティ削除
+}
+audit-clock-stopped = 停止中
+penalty-shootout-allowed = PK戦:
+shootout-rounds = PK戦
+    ラウンド数:
+gi-penalty-shootout = PK戦
+gi-shootout-rounds = PK戦ラウンド数
+stop-time-allowed = ストップ
+    タイム:
+stop-time-window = ストップタイム
+    時間帯:
+stop-time-window-len = ストップタイム時間帯
+stop-time-window-help = 各ピリオド終了前のどれだけの時間、笛のたびに試合時計を止めるか
+gi-stop-time = ストップタイム
+gi-stop-time-window = ストップタイム時間帯
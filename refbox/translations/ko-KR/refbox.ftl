@@ -483,3 +483,132 @@ gi-unknown = ???
 shut-down = 종료
 restart-pi = Pi 재시작
 restart-refbox = Refbox 재시작
+resume-saved-game = Refbox가 중지되었을 때 경기가 진행 중이었습니다.
+    경기 { $game_number }    흑: { $score_black }    백: { $score_white }
+    이 경기를 재개하시겠습니까?
+resume-game = 경기 재개
+start-fresh = 새로 시작
+undo = 실행 취소
+redo = 다시 실행
+penalty-shootout = 승부차기
+start-shootout = 승부차기 시작
+start-shootout-confirm = 서든 데스를 종료하고 승부차기로 경기를 결정하시겠습니까? 되돌릴 수 없습니다.
+remove-last-attempt = 마지막 시도 삭제
+shootout-line-1 = 승부차기
+shootout-line-2 = 시도
+shootout-round = { $round }라운드
+shot-scored = 성공
+shot-missed = 실패
+shootout-goal = 성공
+shootout-miss = 실패
+stop-clock = 시계 정지
+restart-clock = 시계 재시작
+suspended-players = 출전 정지 선수
+suspended-player = { $team } #{ $number }: { $games }경기 남음
+catch-up = 일정 만회
+catch-up-plan-game = 경기 { $game }: 휴식 -{ $cut }, { $late } 늦게 시작
+catch-up-plan-game-half-time = 경기 { $game }: 휴식 -{ $cut }, { $late } 늦게 시작, 하프타임 { $half_time }
+catch-up-plan-on-time = 경기 { $game }부터 일정 회복
+catch-up-plan-late = 마지막 경기 후에도 { $late } 지연
+accept-plan = 계획 승인
+shorten-half-times = 하프타임 단축
+keep-half-times = 하프타임 유지
+disallow-goal = 골 취소
+disallowed-goals = 취소된 골
+black-goals = 흑팀 골
+white-goals = 백팀 골
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] 파울
+    [infraction] 반칙
+   *[scorer-correction] 득점자 정정
+}
+export-results = 결과 내보내기
+results-exported = 결과를 내보낸 위치:
+    { $dir }
+source-file = 파일
+schedule-file = 일정 파일:
+not-found = 찾을 수 없음
+player-stats = 선수 통계
+top-scorers = 득점 순위
+discipline = 징계
+export = 내보내기
+no-player-stats = 기록된 득점, 페널티, 반칙이 없습니다
+player-stats-goals = { $goals ->
+    [one] 1 골
+   *[other] { $goals } 골
+}
+player-stats-discipline = 퇴장 { $dismissals }  페널티 { $penalties } ({ $time })  반칙 { $fouls }
+completed-scoresheet = 작성된 기록지
+scoresheet-detailed = 상세
+scoresheet-simple = 간단
+scoresheet-cmas-official = CMAS 공식
+timeline = 타임라인
+no-timeline-entries = 이 경기에서는 아직 아무 일도 없었습니다
+timeline-goal = 골
+timeline-disallowed-goal = 취소된 골
+timeline-penalty = 페널티 ({ penalty-kind })
+timeline-warning = 경고
+timeline-foul = 파울
+timeline-team-timeout = 팀 타임아웃
+timeline-ref-timeout = 심판 타임아웃
+timeline-penalty-shot = 페널티 샷
+timeline-period-start = 피리어드 시작
+captains-sign-off-result = 주장 결과
+    서명
+cap-number-or-id = 캡 번호
+    또는 ID:
+sign-off-entry-hint = 주장의 캡 번호 또는 식별 번호를 입력하세요 (0은 지우기)
+sign-off-result = 경기 { $game } 서명: 흑 { $score_black } - 백 { $score_white }
+captain-signed = 주장 서명함
+not-signed = 서명 안 함
+protest-lodged = 항의 제출됨
+portal-row-under-review = 경기 { $game } 검토 중, 항의가 해결되면 탭하세요
+report-incident = 사고 보고
+incident-category = {$category ->
+    [injury] 부상
+    [equipment-failure] 장비
+    [misconduct] 비신사적 행위
+   *[other] 기타
+}
+incident-player = 선수
+incident-time = 사고 시점: { $period } { $time }
+no-team = 팀 없음
+incident-description = 무슨 일이 있었는지 기술하세요
+incident-player-hint = 관련 선수의 캡 번호를 입력하세요 (없으면 0)
+edit-history = 수정 기록
+no-edits = 이 경기에서 수동으로 변경된 내용이 없습니다
+audit-change = {$change ->
+    [score-confirmed] 점수 확인
+    [score-edited] 점수 수정
+    [game-clock] 경기 시계 설정
+    [timeout-clock] 타임아웃 시계 설정
+    [penalty-edited] 페널티 수정
+    [goal-disallowed] 골 취소
+    [penalty-added] 페널티 추가
+    [foul-added] 파울 추가
+    [foul-deleted] 파울 삭제
+    [clocks-set] 시계 설정
+   *[penalty-deleted] 페널티 삭제
+}
+audit-clock-stopped = 정지됨
+penalty-shootout-allowed = 승부차기:
+shootout-rounds = 승부차기
+    라운드:
+gi-penalty-shootout = 승부차기
+gi-shootout-rounds = 승부차기 라운드
+stop-time-allowed = 스톱
+    타임:
+stop-time-window = 스톱 타임
+    구간:
+stop-time-window-len = 스톱 타임 구간
+stop-time-window-help = 각 경기 기간이 끝나기 전 얼마 동안 모든 휘슬에 경기 시계를 멈출지
+gi-stop-time = 스톱 타임
+gi-stop-time-window = 스톱 타임 구간
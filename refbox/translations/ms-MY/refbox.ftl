@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = MATIKAN
 restart-pi = MULA SEMULA PI
 restart-refbox = MULA SEMULA REFBOX
+resume-saved-game = Perlawanan sedang berlangsung semasa refbox berhenti.
+    Perlawanan { $game_number }    Hitam: { $score_black }    Putih: { $score_white }
+    Sambung semula perlawanan ini?
+resume-game = SAMBUNG PERLAWANAN
+start-fresh = MULA SEMULA
+undo = BUAT ASAL
+redo = BUAT SEMULA
+penalty-shootout = SEPAKAN PENALTI
+start-shootout = MULA SEPAKAN PENALTI
+start-shootout-confirm = Tamatkan sudden death dan tentukan perlawanan dengan sepakan penalti? Ini tidak boleh dibatalkan.
+remove-last-attempt = BUANG CUBAAN TERAKHIR
+shootout-line-1 = CUBAAN
+shootout-line-2 = PENALTI
+shootout-round = Pusingan { $round }
+shot-scored = GOL
+shot-missed = GAGAL
+shootout-goal = GOL
+shootout-miss = GAGAL
+stop-clock = HENTIKAN JAM
+restart-clock = MULAKAN SEMULA JAM
+suspended-players = PEMAIN DIGANTUNG
+suspended-player = { $team } #{ $number }: BAKI { $games } PERLAWANAN
+catch-up = KEJAR JADUAL
+catch-up-plan-game = PERLAWANAN { $game }: REHAT -{ $cut }, BERMULA LEWAT { $late }
+catch-up-plan-game-half-time = PERLAWANAN { $game }: REHAT -{ $cut }, BERMULA LEWAT { $late }, SEPARUH MASA { $half_time }
+catch-up-plan-on-time = KEMBALI MENGIKUT JADUAL PADA PERLAWANAN { $game }
+catch-up-plan-late = MASIH LEWAT { $late } SELEPAS PERLAWANAN TERAKHIR
+accept-plan = TERIMA PELAN
+shorten-half-times = PENDEKKAN SEPARUH MASA
+keep-half-times = KEKALKAN SEPARUH MASA
+disallow-goal = BATALKAN GOL
+disallowed-goals = GOL DIBATALKAN
+black-goals = GOL HITAM
+white-goals = GOL PUTIH
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] KESALAHAN
+    [infraction] PELANGGARAN
+   *[scorer-correction] PENJARING SALAH
+}
+export-results = EKSPORT KEPUTUSAN
+results-exported = Keputusan telah dieksport ke:
+    { $dir }
+source-file = FAIL
+schedule-file = FAIL JADUAL:
+not-found = Tidak Ditemui
+player-stats = STATISTIK PEMAIN
+top-scorers = PENJARING
+discipline = DISIPLIN
+export = EKSPORT
+no-player-stats = Belum ada gol, penalti atau kesalahan direkodkan
+player-stats-goals = { $goals ->
+    [one] 1 gol
+   *[other] { $goals } gol
+}
+player-stats-discipline = TD { $dismissals }  PEN { $penalties } ({ $time })  SLH { $fouls }
+completed-scoresheet = LEMBARAN SKOR LENGKAP
+scoresheet-detailed = TERPERINCI
+scoresheet-simple = RINGKAS
+scoresheet-cmas-official = CMAS RASMI
+timeline = GARIS MASA
+no-timeline-entries = Belum ada apa-apa berlaku dalam perlawanan ini
+timeline-goal = GOL
+timeline-disallowed-goal = GOL DIBATALKAN
+timeline-penalty = PENALTI ({ penalty-kind })
+timeline-warning = AMARAN
+timeline-foul = KESALAHAN
+timeline-team-timeout = MASA REHAT PASUKAN
+timeline-ref-timeout = MASA REHAT PENGADIL
+timeline-penalty-shot = TEMBAKAN PENALTI
+timeline-period-start = TEMPOH BERMULA
+captains-sign-off-result = KAPTEN TANDATANGAN
+    KEPUTUSAN
+cap-number-or-id = NO. TOPI
+    ATAU ID:
+sign-off-entry-hint = Masukkan nombor topi kapten atau nombor lain yang mengenal pastinya, atau 0 untuk kosongkan
+sign-off-result = TANDATANGAN PERLAWANAN { $game }: HITAM { $score_black } - PUTIH { $score_white }
+captain-signed = KAPTEN TELAH TANDATANGAN
+not-signed = BELUM DITANDATANGAN
+protest-lodged = BANTAHAN DIFAILKAN
+portal-row-under-review = Perlawanan { $game } Dalam semakan, ketik selepas bantahan selesai
+report-incident = LAPORKAN INSIDEN
+incident-category = {$category ->
+    [injury] KECEDERAAN
+    [equipment-failure] PERALATAN
+    [misconduct] SALAH LAKU
+   *[other] LAIN-LAIN
+}
+incident-player = PEMAIN
+incident-time = Insiden pada { $period } { $time }
+no-team = TIADA PASUKAN
+incident-description = Terangkan apa yang berlaku
+incident-player-hint = Masukkan nombor topi pemain yang terlibat, atau 0 jika tiada
+edit-history = SEJARAH SUNTINGAN
+no-edits = Tiada apa-apa diubah secara manual dalam perlawanan ini
+audit-change = {$change ->
+    [score-confirmed] MARKAH DISAHKAN
+    [score-edited] MARKAH DISUNTING
+    [game-clock] JAM PERLAWANAN DITETAPKAN
+    [timeout-clock] JAM MASA REHAT DITETAPKAN
+    [penalty-edited] PENALTI DISUNTING
+    [goal-disallowed] GOL DIBATALKAN
+    [penalty-added] PENALTI DITAMBAH
+    [foul-added] KESALAHAN DITAMBAH
+    [foul-deleted] KESALAHAN DIPADAM
+    [clocks-set] JAM DITETAPKAN
+   *[penalty-deleted] PENALTI DIPADAM
+}
+audit-clock-stopped = BERHENTI
+penalty-shootout-allowed = SEPAKAN
+    PENALTI:
+shootout-rounds = PUSINGAN
+    PENALTI:
+gi-penalty-shootout = Sepakan Penalti
+gi-shootout-rounds = Pusingan Penalti
+stop-time-allowed = MASA
+    BERHENTI:
+stop-time-window = TEMPOH MASA
+    BERHENTI:
+stop-time-window-len = TEMPOH MASA BERHENTI
+stop-time-window-help = Berapa lama sebelum tamat setiap tempoh permainan setiap wisel menghentikan jam permainan
+gi-stop-time = Masa Berhenti
+gi-stop-time-window = Tempoh Masa Berhenti
@@ -481,3 +481,132 @@ gi-unknown = ???
 shut-down = AFSLUITEN
 restart-pi = PI HERSTARTEN
 restart-refbox = REFBOX HERSTARTEN
+resume-saved-game = Er was een wedstrijd bezig toen de refbox stopte.
+    Wedstrijd { $game_number }    Zwart: { $score_black }    Wit: { $score_white }
+    Deze wedstrijd hervatten?
+resume-game = WEDSTRIJD HERVATTEN
+start-fresh = OPNIEUW BEGINNEN
+undo = ONGEDAAN MAKEN
+redo = OPNIEUW
+penalty-shootout = STRAFSCHOPPEN
+start-shootout = START STRAFSCHOPPEN
+start-shootout-confirm = Plotselinge dood beëindigen en de wedstrijd met strafschoppen beslissen? Dit kan niet ongedaan worden gemaakt.
+remove-last-attempt = LAATSTE POGING VERWIJDEREN
+shootout-line-1 = STRAFSCHOP
+shootout-line-2 = POGING
+shootout-round = Ronde { $round }
+shot-scored = GESCOORD
+shot-missed = GEMIST
+shootout-goal = DOELPUNT
+shootout-miss = GEMIST
+stop-clock = KLOK STOPPEN
+restart-clock = KLOK HERSTARTEN
+suspended-players = GESCHORSTE SPELERS
+suspended-player = { $team } #{ $number }: NOG { $games } WEDSTRIJD(EN)
+catch-up = INHALEN
+catch-up-plan-game = WEDSTRIJD { $game }: PAUZE -{ $cut }, BEGINT { $late } TE LAAT
+catch-up-plan-game-half-time = WEDSTRIJD { $game }: PAUZE -{ $cut }, BEGINT { $late } TE LAAT, RUST { $half_time }
+catch-up-plan-on-time = VANAF WEDSTRIJD { $game } WEER OP SCHEMA
+catch-up-plan-late = NA DE LAATSTE WEDSTRIJD NOG { $late } ACHTER
+accept-plan = PLAN ACCEPTEREN
+shorten-half-times = RUST INKORTEN
+keep-half-times = RUST BEHOUDEN
+disallow-goal = DOELPUNT AFKEUREN
+disallowed-goals = AFGEKEURDE DOELPUNTEN
+black-goals = DOELPUNTEN ZWART
+white-goals = DOELPUNTEN WIT
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] OVERTREDING
+    [infraction] REGELOVERTREDING
+   *[scorer-correction] VERKEERDE SCHUTTER
+}
+export-results = UITSLAGEN EXPORTEREN
+results-exported = De uitslagen zijn geëxporteerd naar:
+    { $dir }
+source-file = BESTAND
+schedule-file = SCHEMABESTAND:
+not-found = Niet gevonden
+player-stats = SPELERSSTATS
+top-scorers = TOPSCORERS
+discipline = DISCIPLINE
+export = EXPORTEREN
+no-player-stats = Nog geen doelpunten, uitsluitingen of overtredingen
+player-stats-goals = { $goals ->
+    [one] 1 doelpunt
+   *[other] { $goals } doelpunten
+}
+player-stats-discipline = DU { $dismissals }  UITS { $penalties } ({ $time })  OVERTR { $fouls }
+completed-scoresheet = INGEVULD WEDSTRIJDFORMULIER
+scoresheet-detailed = GEDETAILLEERD
+scoresheet-simple = EENVOUDIG
+scoresheet-cmas-official = CMAS OFFICIEEL
+timeline = TIJDLIJN
+no-timeline-entries = Er is in deze wedstrijd nog niets gebeurd
+timeline-goal = DOELPUNT
+timeline-disallowed-goal = AFGEKEURD DOELPUNT
+timeline-penalty = STRAF ({ penalty-kind })
+timeline-warning = WAARSCHUWING
+timeline-foul = OVERTREDING
+timeline-team-timeout = TEAM-TIME-OUT
+timeline-ref-timeout = SCHEIDS-TIME-OUT
+timeline-penalty-shot = STRAFWORP
+timeline-period-start = PERIODE BEGINT
+captains-sign-off-result = AANVOERDERS TEKENEN
+    UITSLAG
+cap-number-or-id = CAPNR.
+    OF ID:
+sign-off-entry-hint = Voer het capnummer of een ander identificerend nummer van de aanvoerder in, of 0 om te wissen
+sign-off-result = WEDSTRIJD { $game } AFTEKENEN: ZWART { $score_black } - WIT { $score_white }
+captain-signed = AANVOERDER GETEKEND
+not-signed = NIET GETEKEND
+protest-lodged = PROTEST INGEDIEND
+portal-row-under-review = Wedstrijd { $game } In behandeling, tik zodra het protest is afgehandeld
+report-incident = INCIDENT MELDEN
+incident-category = {$category ->
+    [injury] BLESSURE
+    [equipment-failure] UITRUSTING
+    [misconduct] WANGEDRAG
+   *[other] OVERIG
+}
+incident-player = SPELER
+incident-time = Incident bij { $period } { $time }
+no-team = GEEN TEAM
+incident-description = Beschrijf wat er gebeurde
+incident-player-hint = Voer het capnummer van de betrokken speler in, of 0 voor niemand
+edit-history = WIJZIGINGEN
+no-edits = In deze wedstrijd is niets met de hand gewijzigd
+audit-change = {$change ->
+    [score-confirmed] STAND BEVESTIGD
+    [score-edited] STAND GEWIJZIGD
+    [game-clock] SPEELKLOK GEZET
+    [timeout-clock] TIME-OUTKLOK GEZET
+    [penalty-edited] STRAF GEWIJZIGD
+    [goal-disallowed] DOELPUNT AFGEKEURD
+    [penalty-added] STRAF TOEGEVOEGD
+    [foul-added] OVERTREDING TOEGEVOEGD
+    [foul-deleted] OVERTREDING VERWIJDERD
+    [clocks-set] KLOKKEN GEZET
+   *[penalty-deleted] STRAF VERWIJDERD
+}
+audit-clock-stopped = GESTOPT
+penalty-shootout-allowed = STRAFWORP-
+    SERIE:
+shootout-rounds = RONDES
+    STRAFWORPEN:
+gi-penalty-shootout = Strafworpserie
+gi-shootout-rounds = Rondes Strafworpen
+stop-time-allowed = STOPTIJD:
+stop-time-window = STOPTIJD
+    VENSTER:
+stop-time-window-len = STOPTIJD-VENSTER
+stop-time-window-help = Hoe lang voor het einde van elke speelperiode elk fluitsignaal de wedstrijdklok stopt
+gi-stop-time = Stoptijd
+gi-stop-time-window = Stoptijd-venster
@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = DESLIGAR
 restart-pi = REINICIAR PI
 restart-refbox = REINICIAR REFBOX
+resume-saved-game = Estava um jogo a decorrer quando a refbox parou.
+    Jogo { $game_number }    Preto: { $score_black }    Branco: { $score_white }
+    Retomar este jogo?
+resume-game = RETOMAR JOGO
+start-fresh = COMEÇAR DE NOVO
+undo = DESFAZER
+redo = REFAZER
+penalty-shootout = GRANDES PENALIDADES
+start-shootout = INICIAR PENALIDADES
+start-shootout-confirm = Terminar a morte súbita e decidir o jogo por grandes penalidades? Não é possível anular.
+remove-last-attempt = REMOVER ÚLTIMA TENTATIVA
+shootout-line-1 = TENTATIVA
+shootout-line-2 = DE PENÁLTI
+shootout-round = Ronda { $round }
+shot-scored = GOLO
+shot-missed = FALHADO
+shootout-goal = GOLO
+shootout-miss = FALHA
+stop-clock = PARAR RELÓGIO
+restart-clock = REINICIAR RELÓGIO
+suspended-players = JOGADORES SUSPENSOS
+suspended-player = { $team } #{ $number }: FALTAM { $games } JOGO(S)
+catch-up = RECUPERAR ATRASO
+catch-up-plan-game = JOGO { $game }: INTERVALO -{ $cut }, COMEÇA COM { $late } DE ATRASO
+catch-up-plan-game-half-time = JOGO { $game }: INTERVALO -{ $cut }, COMEÇA COM { $late } DE ATRASO, MEIO-TEMPO { $half_time }
+catch-up-plan-on-time = DE VOLTA AO HORÁRIO NO JOGO { $game }
+catch-up-plan-late = AINDA { $late } DE ATRASO APÓS O ÚLTIMO JOGO
+accept-plan = ACEITAR PLANO
+shorten-half-times = ENCURTAR MEIOS-TEMPOS
+keep-half-times = MANTER MEIOS-TEMPOS
+disallow-goal = ANULAR GOLO
+disallowed-goals = GOLOS ANULADOS
+black-goals = GOLOS PRETO
+white-goals = GOLOS BRANCO
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FALTA
+    [infraction] INFRAÇÃO
+   *[scorer-correction] MARCADOR ERRADO
+}
+export-results = EXPORTAR RESULTADOS
+results-exported = Os resultados foram exportados para:
+    { $dir }
+source-file = FICHEIRO
+schedule-file = FICHEIRO DO CALENDÁRIO:
+not-found = Não encontrado
+player-stats = ESTATÍSTICAS
+top-scorers = MARCADORES
+discipline = DISCIPLINA
+export = EXPORTAR
+no-player-stats = Ainda sem golos, penalidades ou faltas
+player-stats-goals = { $goals ->
+    [one] 1 golo
+   *[other] { $goals } golos
+}
+player-stats-discipline = ED { $dismissals }  PEN { $penalties } ({ $time })  FALTAS { $fouls }
+completed-scoresheet = BOLETIM PREENCHIDO
+scoresheet-detailed = DETALHADO
+scoresheet-simple = SIMPLES
+scoresheet-cmas-official = CMAS OFICIAL
+timeline = CRONOLOGIA
+no-timeline-entries = Ainda não aconteceu nada neste jogo
+timeline-goal = GOLO
+timeline-disallowed-goal = GOLO ANULADO
+timeline-penalty = PENALIDADE ({ penalty-kind })
+timeline-warning = ADVERTÊNCIA
+timeline-foul = FALTA
+timeline-team-timeout = TEMPO DE EQUIPA
+timeline-ref-timeout = TEMPO DO ÁRBITRO
+timeline-penalty-shot = LANCE DE PENÁLTI
+timeline-period-start = INÍCIO DO PERÍODO
+captains-sign-off-result = CAPITÃES ASSINAM
+    RESULTADO
+cap-number-or-id = N.º TOUCA
+    OU ID:
+sign-off-entry-hint = Introduza o número de touca do capitão ou outro número que o identifique, ou 0 para limpar
+sign-off-result = ASSINAR JOGO { $game }: PRETO { $score_black } - BRANCO { $score_white }
+captain-signed = CAPITÃO ASSINOU
+not-signed = NÃO ASSINADO
+protest-lodged = PROTESTO APRESENTADO
+portal-row-under-review = Jogo { $game } Em revisão, toque quando o protesto estiver resolvido
+report-incident = REPORTAR INCIDENTE
+incident-category = {$category ->
+    [injury] LESÃO
+    [equipment-failure] EQUIPAMENTO
+    [misconduct] MÁ CONDUTA
+   *[other] OUTRO
+}
+incident-player = JOGADOR
+incident-time = Incidente em { $period } { $time }
+no-team = SEM EQUIPA
+incident-description = Descreva o que aconteceu
+incident-player-hint = Introduza o número de touca do jogador envolvido, ou 0 para nenhum
+edit-history = HISTÓRICO DE ALTERAÇÕES
+no-edits = Nada foi alterado manualmente neste jogo
+audit-change = {$change ->
+    [score-confirmed] RESULTADO CONFIRMADO
+    [score-edited] RESULTADO EDITADO
+    [game-clock] RELÓGIO DE JOGO ACERTADO
+    [timeout-clock] RELÓGIO DE DESCONTO ACERTADO
+    [penalty-edited] PENALIDADE EDITADA
+    [goal-disallowed] GOLO ANULADO
+    [penalty-added] PENALIDADE ADICIONADA
+    [foul-added] FALTA ADICIONADA
+    [foul-deleted] FALTA ELIMINADA
+    [clocks-set] RELÓGIOS ACERTADOS
+   *[penalty-deleted] PENALIDADE ELIMINADA
+}
+audit-clock-stopped = PARADO
+penalty-shootout-allowed = DESEMPATE
+    POR PENÁLTIS:
+shootout-rounds = RONDAS DE
+    PENÁLTIS:
+gi-penalty-shootout = Desempate por Penáltis
+gi-shootout-rounds = Rondas de Penáltis
+stop-time-allowed = TEMPO
+    PARADO:
+stop-time-window = JANELA DE
+    TEMPO PARADO:
+stop-time-window-len = JANELA DE TEMPO PARADO
+stop-time-window-help = Quanto tempo antes do fim de cada período de jogo cada apito para o relógio
+gi-stop-time = Tempo Parado
+gi-stop-time-window = Janela de Tempo Parado
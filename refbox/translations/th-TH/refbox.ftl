@@ -480,3 +480,133 @@ gi-unknown = ???
 shut-down = ปิดเครื่อง
 restart-pi = รีสตาร์ท PI
 restart-refbox = รีสตาร์ท REFBOX
+resume-saved-game = มีการแข่งขันดำเนินอยู่ขณะที่ refbox หยุดทำงาน
+    เกม { $game_number }    ดำ: { $score_black }    ขาว: { $score_white }
+    ดำเนินการแข่งขันนี้ต่อหรือไม่?
+resume-game = เล่นเกมต่อ
+start-fresh = เริ่มใหม่
+undo = เลิกทำ
+redo = ทำซ้ำ
+penalty-shootout = ยิงจุดโทษ
+start-shootout = เริ่มยิงจุดโทษ
+start-shootout-confirm = จบช่วงตายกะทันหันและตัดสินด้วยการยิงจุดโทษหรือไม่? ไม่สามารถยกเลิกได้
+remove-last-attempt = ลบครั้งล่าสุด
+shootout-line-1 = ยิง
+shootout-line-2 = จุดโทษ
+shootout-round = รอบที่ { $round }
+shot-scored = เข้าประตู
+shot-missed = พลาด
+shootout-goal = เข้า
+shootout-miss = พลาด
+stop-clock = หยุดนาฬิกา
+restart-clock = เริ่มนาฬิกาใหม่
+suspended-players = ผู้เล่นที่ถูกพักการแข่งขัน
+suspended-player = { $team } #{ $number }: เหลืออีก { $games } นัด
+catch-up = เร่งตามกำหนดการ
+catch-up-plan-game = เกม { $game }: พัก -{ $cut }, เริ่มช้า { $late }
+catch-up-plan-game-half-time = เกม { $game }: พัก -{ $cut }, เริ่มช้า { $late }, พักครึ่ง { $half_time }
+catch-up-plan-on-time = กลับเข้ากำหนดการที่เกม { $game }
+catch-up-plan-late = ยังช้า { $late } หลังเกมสุดท้าย
+accept-plan = ยอมรับแผน
+shorten-half-times = ลดเวลาพักครึ่ง
+keep-half-times = คงเวลาพักครึ่ง
+disallow-goal = ยกเลิกประตู
+disallowed-goals = ประตูที่ถูกยกเลิก
+black-goals = ประตูทีมดำ
+white-goals = ประตูทีมขาว
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] ฟาวล์
+    [infraction] การละเมิดกติกา
+   *[scorer-correction] ผู้ทำประตูผิดคน
+}
+export-results = ส่งออกผลการแข่งขัน
+results-exported = ส่งออกผลการแข่งขันไปที่:
+    { $dir }
+source-file = ไฟล์
+schedule-file = ไฟล์ตารางการแข่งขัน:
+not-found = ไม่พบ
+player-stats = สถิติผู้เล่น
+top-scorers = ดาวซัลโว
+discipline = วินัย
+export = ส่งออก
+no-player-stats = ยังไม่มีการบันทึกประตู โทษ หรือฟาวล์
+player-stats-goals = { $goals ->
+    [one] 1 ประตู
+   *[other] { $goals } ประตู
+}
+player-stats-discipline = ไล่ออก { $dismissals }  โทษ { $penalties } ({ $time })  ฟาวล์ { $fouls }
+completed-scoresheet = ใบบันทึกคะแนนที่กรอกแล้ว
+scoresheet-detailed = แบบละเอียด
+scoresheet-simple = แบบง่าย
+scoresheet-cmas-official = CMAS ทางการ
+timeline = ไทม์ไลน์
+no-timeline-entries = ยังไม่มีเหตุการณ์ใดในเกมนี้
+timeline-goal = ประตู
+timeline-disallowed-goal = ประตูที่ไม่นับ
+timeline-penalty = จุดโทษ ({ penalty-kind })
+timeline-warning = การเตือน
+timeline-foul = ฟาวล์
+timeline-team-timeout = ขอเวลานอกของทีม
+timeline-ref-timeout = ขอเวลานอกของกรรมการ
+timeline-penalty-shot = ยิงลูกโทษ
+timeline-period-start = เริ่มช่วงเวลา
+captains-sign-off-result = กัปตันลงนาม
+    ผลการแข่งขัน
+cap-number-or-id = หมายเลขหมวก
+    หรือ ID:
+sign-off-entry-hint = ป้อนหมายเลขหมวกหรือหมายเลขระบุตัวตนของกัปตัน หรือ 0 เพื่อล้าง
+sign-off-result = ลงนามเกม { $game }: ดำ { $score_black } - ขาว { $score_white }
+captain-signed = กัปตันลงนามแล้ว
+not-signed = ยังไม่ลงนาม
+protest-lodged = ยื่นประท้วงแล้ว
+portal-row-under-review = เกม { $game } อยู่ระหว่างพิจารณา แตะเมื่อการประท้วงได้ข้อยุติ
+report-incident = รายงานเหตุการณ์
+incident-category = {$category ->
+    [injury] บาดเจ็บ
+    [equipment-failure] อุปกรณ์
+    [misconduct] ประพฤติผิด
+   *[other] อื่น ๆ
+}
+incident-player = ผู้เล่น
+incident-time = เหตุการณ์เมื่อ { $period } { $time }
+no-team = ไม่มีทีม
+incident-description = อธิบายสิ่งที่เกิดขึ้น
+incident-player-hint = ป้อนหมายเลขหมวกของผู้เล่นที่เกี่ยวข้อง หรือ 0 หากไม่มี
+edit-history = ประวัติการแก้ไข
+no-edits = ไม่มีการแก้ไขด้วยมือในเกมนี้
+audit-change = {$change ->
+    [score-confirmed] ยืนยันคะแนน
+    [score-edited] แก้ไขคะแนน
+    [game-clock] ตั้งนาฬิกาเกม
+    [timeout-clock] ตั้งนาฬิกาเวลานอก
+    [penalty-edited] แก้ไขการลงโทษ
+    [goal-disallowed] ยกเลิกประตู
+    [penalty-added] เพิ่มการลงโทษ
+    [foul-added] เพิ่มฟาวล์
+    [foul-deleted] ลบฟาวล์
+    [clocks-set] ตั้งนาฬิกา
+   *[penalty-deleted] ลบการลงโทษ
+}
+audit-clock-stopped = หยุด
+penalty-shootout-allowed = ดวล
+    จุดโทษ:
+shootout-rounds = จำนวนรอบ
+    ดวลจุดโทษ:
+gi-penalty-shootout = ดวลจุดโทษ
+gi-shootout-rounds = จำนวนรอบดวลจุดโทษ
+stop-time-allowed = หยุด
+    เวลา:
+stop-time-window = ช่วงเวลา
+    หยุดเวลา:
+stop-time-window-len = ช่วงเวลาหยุดเวลา
+stop-time-window-help = ระยะเวลาก่อนจบแต่ละช่วงการเล่นที่ทุกการเป่านกหวีดจะหยุดนาฬิกาเกม
+gi-stop-time = หยุดเวลา
+gi-stop-time-window = ช่วงเวลาหยุดเวลา
@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = I-SHUT DOWN
 restart-pi = I-RESTART ANG PI
 restart-refbox = I-RESTART ANG REFBOX
+resume-saved-game = May larong nagaganap nang huminto ang refbox.
+    Laro { $game_number }    Itim: { $score_black }    Puti: { $score_white }
+    Ituloy ang larong ito?
+resume-game = ITULOY ANG LARO
+start-fresh = MAGSIMULA MULI
+undo = I-UNDO
+redo = I-REDO
+penalty-shootout = PENALTY SHOOTOUT
+start-shootout = SIMULAN ANG SHOOTOUT
+start-shootout-confirm = Tapusin ang biglaang kamatayan at tapusin ang laro sa penalty shootout? Hindi na ito maibabalik.
+remove-last-attempt = ALISIN ANG HULING TIRA
+shootout-line-1 = TIRA SA
+shootout-line-2 = SHOOTOUT
+shootout-round = Round { $round }
+shot-scored = PUMASOK
+shot-missed = SABLAY
+shootout-goal = GOL
+shootout-miss = SABLAY
+stop-clock = IHINTO ANG ORASAN
+restart-clock = SIMULAN MULI ANG ORASAN
+suspended-players = MGA SUSPENDIDONG MANLALARO
+suspended-player = { $team } #{ $number }: { $games } LARO PA
+catch-up = HABULIN ANG ISKEDYUL
+catch-up-plan-game = LARO { $game }: PAHINGA -{ $cut }, MAGSISIMULA NANG { $late } HULI
+catch-up-plan-game-half-time = LARO { $game }: PAHINGA -{ $cut }, MAGSISIMULA NANG { $late } HULI, HALF-TIME { $half_time }
+catch-up-plan-on-time = BALIK SA ISKEDYUL SA LARO { $game }
+catch-up-plan-late = HULI PA RIN NANG { $late } PAGKATAPOS NG HULING LARO
+accept-plan = TANGGAPIN ANG PLANO
+shorten-half-times = PAIKLIIN ANG HALF-TIME
+keep-half-times = PANATILIHIN ANG HALF-TIME
+disallow-goal = IPAWALANG-BISA ANG GOL
+disallowed-goals = MGA PINAWALANG-BISANG GOL
+black-goals = MGA GOL NG ITIM
+white-goals = MGA GOL NG PUTI
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FOUL
+    [infraction] PAGLABAG
+   *[scorer-correction] MALING NAKAPUNTOS
+}
+export-results = I-EXPORT ANG MGA RESULTA
+results-exported = Na-export ang mga resulta sa:
+    { $dir }
+source-file = FILE
+schedule-file = FILE NG ISKEDYUL:
+not-found = Hindi Nahanap
+player-stats = STATS NG MANLALARO
+top-scorers = MGA TOP SCORER
+discipline = DISIPLINA
+export = I-EXPORT
+no-player-stats = Wala pang naitalang goal, parusa o poul
+player-stats-goals = { $goals ->
+    [one] 1 goal
+   *[other] { $goals } goal
+}
+player-stats-discipline = TD { $dismissals }  PARUSA { $penalties } ({ $time })  POUL { $fouls }
+completed-scoresheet = KUMPLETONG SCORESHEET
+scoresheet-detailed = DETALYADO
+scoresheet-simple = SIMPLE
+scoresheet-cmas-official = CMAS OPISYAL
+timeline = TIMELINE
+no-timeline-entries = Wala pang nangyayari sa larong ito
+timeline-goal = GOAL
+timeline-disallowed-goal = HINDI TINANGGAP NA GOAL
+timeline-penalty = PENALTY ({ penalty-kind })
+timeline-warning = BABALA
+timeline-foul = FOUL
+timeline-team-timeout = TIMEOUT NG KOPONAN
+timeline-ref-timeout = TIMEOUT NG REFEREE
+timeline-penalty-shot = PENALTY SHOT
+timeline-period-start = NAGSIMULA ANG PERIOD
+captains-sign-off-result = PIRMA NG KAPITAN
+    SA RESULTA
+cap-number-or-id = NUMERO NG CAP
+    O ID:
+sign-off-entry-hint = Ilagay ang numero ng cap ng kapitan o ibang numerong tumutukoy sa kanya, o 0 para burahin
+sign-off-result = PIRMAHAN ANG LARO { $game }: ITIM { $score_black } - PUTI { $score_white }
+captain-signed = NAKAPIRMA ANG KAPITAN
+not-signed = HINDI NAKAPIRMA
+protest-lodged = MAY PROTESTA
+portal-row-under-review = Laro { $game } Sinusuri, i-tap kapag naayos na ang protesta
+report-incident = IULAT ANG INSIDENTE
+incident-category = {$category ->
+    [injury] PINSALA
+    [equipment-failure] KAGAMITAN
+    [misconduct] MASAMANG ASAL
+   *[other] IBA PA
+}
+incident-player = MANLALARO
+incident-time = Insidente sa { $period } { $time }
+no-team = WALANG KOPONAN
+incident-description = Ilarawan ang nangyari
+incident-player-hint = Ilagay ang numero ng cap ng sangkot na manlalaro, o 0 kung wala
+edit-history = KASAYSAYAN NG PAGBABAGO
+no-edits = Walang binago nang mano-mano sa larong ito
+audit-change = {$change ->
+    [score-confirmed] KINUMPIRMA ANG ISKOR
+    [score-edited] BINAGO ANG ISKOR
+    [game-clock] ITINAKDA ANG ORASAN NG LARO
+    [timeout-clock] ITINAKDA ANG ORASAN NG TIMEOUT
+    [penalty-edited] BINAGO ANG PARUSA
+    [goal-disallowed] GOL NA HINDI PINAYAGAN
+    [penalty-added] IDINAGDAG ANG PARUSA
+    [foul-added] IDINAGDAG ANG FOUL
+    [foul-deleted] BINURA ANG FOUL
+    [clocks-set] ITINAKDA ANG MGA ORASAN
+   *[penalty-deleted] BINURA ANG PARUSA
+}
+audit-clock-stopped = NAKAHINTO
+penalty-shootout-allowed = PENALTY
+    SHOOTOUT:
+shootout-rounds = MGA ROUND NG
+    SHOOTOUT:
+gi-penalty-shootout = Penalty Shootout
+gi-shootout-rounds = Mga Round ng Shootout
+stop-time-allowed = STOP
+    TIME:
+stop-time-window = STOP TIME
+    WINDOW:
+stop-time-window-len = STOP TIME WINDOW
+stop-time-window-help = Gaano katagal bago matapos ang bawat yugto ng laro na hinihinto ng bawat pito ang orasan
+gi-stop-time = Stop Time
+gi-stop-time-window = Stop Time Window
@@ -481,3 +481,133 @@ gi-unknown = ???
 shut-down = KAPAT
 restart-pi = PI'Yİ YENİDEN BAŞLAT
 restart-refbox = REFBOX'U YENİDEN BAŞLAT
+resume-saved-game = Refbox durduğunda devam eden bir maç vardı.
+    Maç { $game_number }    Siyah: { $score_black }    Beyaz: { $score_white }
+    Bu maça devam edilsin mi?
+resume-game = MAÇA DEVAM ET
+start-fresh = YENİDEN BAŞLA
+undo = GERİ AL
+redo = YİNELE
+penalty-shootout = PENALTI ATIŞLARI
+start-shootout = PENALTILARI BAŞLAT
+start-shootout-confirm = Ani ölüm bitirilip maç penaltı atışlarıyla mı belirlensin? Bu geri alınamaz.
+remove-last-attempt = SON ATIŞI SİL
+shootout-line-1 = PENALTI
+shootout-line-2 = ATIŞI
+shootout-round = Tur { $round }
+shot-scored = GOL
+shot-missed = KAÇTI
+shootout-goal = GOL
+shootout-miss = KAÇTI
+stop-clock = SAATİ DURDUR
+restart-clock = SAATİ YENİDEN BAŞLAT
+suspended-players = CEZALI OYUNCULAR
+suspended-player = { $team } #{ $number }: { $games } MAÇ KALDI
+catch-up = GECİKMEYİ KAPAT
+catch-up-plan-game = MAÇ { $game }: ARA -{ $cut }, { $late } GEÇ BAŞLAR
+catch-up-plan-game-half-time = MAÇ { $game }: ARA -{ $cut }, { $late } GEÇ BAŞLAR, DEVRE ARASI { $half_time }
+catch-up-plan-on-time = { $game } NUMARALI MAÇTA PROGRAMA DÖNÜLÜR
+catch-up-plan-late = SON MAÇTAN SONRA HÂLÂ { $late } GECİKME
+accept-plan = PLANI KABUL ET
+shorten-half-times = DEVRE ARALARINI KISALT
+keep-half-times = DEVRE ARALARINI KORU
+disallow-goal = GOLÜ İPTAL ET
+disallowed-goals = İPTAL EDİLEN GOLLER
+black-goals = SİYAH GOLLER
+white-goals = BEYAZ GOLLER
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] FAUL
+    [infraction] İHLAL
+   *[scorer-correction] YANLIŞ GOLCÜ
+}
+export-results = SONUÇLARI DIŞA AKTAR
+results-exported = Sonuçlar şuraya aktarıldı:
+    { $dir }
+source-file = DOSYA
+schedule-file = FİKSTÜR DOSYASI:
+not-found = Bulunamadı
+player-stats = OYUNCU İSTAT.
+top-scorers = GOL KRALLIĞI
+discipline = DİSİPLİN
+export = DIŞA AKTAR
+no-player-stats = Henüz gol, ceza veya faul kaydedilmedi
+player-stats-goals = { $goals ->
+    [one] 1 gol
+   *[other] { $goals } gol
+}
+player-stats-discipline = İH { $dismissals }  CEZA { $penalties } ({ $time })  FAUL { $fouls }
+completed-scoresheet = DOLDURULMUŞ MAÇ CETVELİ
+scoresheet-detailed = AYRINTILI
+scoresheet-simple = BASİT
+scoresheet-cmas-official = CMAS RESMİ
+timeline = ZAMAN ÇİZELGESİ
+no-timeline-entries = Bu maçta henüz bir şey olmadı
+timeline-goal = GOL
+timeline-disallowed-goal = İPTAL EDİLEN GOL
+timeline-penalty = CEZA ({ penalty-kind })
+timeline-warning = UYARI
+timeline-foul = FAUL
+timeline-team-timeout = TAKIM MOLASI
+timeline-ref-timeout = HAKEM MOLASI
+timeline-penalty-shot = PENALTI ATIŞI
+timeline-period-start = DEVRE BAŞLADI
+captains-sign-off-result = KAPTANLAR SONUCU
+    ONAYLASIN
+cap-number-or-id = BONE NO
+    VEYA ID:
+sign-off-entry-hint = Kaptanın bone numarasını veya onu tanımlayan başka bir numarayı girin, silmek için 0
+sign-off-result = MAÇ { $game } ONAYI: SİYAH { $score_black } - BEYAZ { $score_white }
+captain-signed = KAPTAN İMZALADI
+not-signed = İMZALANMADI
+protest-lodged = İTİRAZ EDİLDİ
+portal-row-under-review = Maç { $game } İnceleniyor, itiraz çözülünce dokunun
+report-incident = OLAY BİLDİR
+incident-category = {$category ->
+    [injury] SAKATLIK
+    [equipment-failure] EKİPMAN
+    [misconduct] KÖTÜ DAVRANIŞ
+   *[other] DİĞER
+}
+incident-player = OYUNCU
+incident-time = Olay: { $period } { $time }
+no-team = TAKIM YOK
+incident-description = Ne olduğunu açıklayın
+incident-player-hint = İlgili oyuncunun bone numarasını girin, yoksa 0
+edit-history = DEĞİŞİKLİK GEÇMİŞİ
+no-edits = Bu maçta elle hiçbir şey değiştirilmedi
+audit-change = {$change ->
+    [score-confirmed] SKOR ONAYLANDI
+    [score-edited] SKOR DÜZENLENDİ
+    [game-clock] OYUN SAATİ AYARLANDI
+    [timeout-clock] MOLA SAATİ AYARLANDI
+    [penalty-edited] CEZA DÜZENLENDİ
+    [goal-disallowed] GOL İPTAL
+    [penalty-added] CEZA EKLENDİ
+    [foul-added] FAUL EKLENDİ
+    [foul-deleted] FAUL SİLİNDİ
+    [clocks-set] SAATLER AYARLANDI
+   *[penalty-deleted] CEZA SİLİNDİ
+}
+audit-clock-stopped = DURDU
+penalty-shootout-allowed = PENALTI
+    ATIŞLARI:
+shootout-rounds = PENALTI
+    TURLARI:
+gi-penalty-shootout = Penaltı Atışları
+gi-shootout-rounds = Penaltı Turları
+stop-time-allowed = DURAN
+    SAAT:
+stop-time-window = DURAN SAAT
+    SÜRESİ:
+stop-time-window-len = DURAN SAAT SÜRESİ
+stop-time-window-help = Her oyun periyodunun bitiminden ne kadar önce her düdüğün oyun saatini durduracağı
+gi-stop-time = Duran Saat
+gi-stop-time-window = Duran Saat Süresi
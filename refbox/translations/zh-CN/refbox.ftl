@@ -480,3 +480,133 @@ gi-unknown = ???
 shut-down = 关机
 restart-pi = 重启 PI
 restart-refbox = 重启 REFBOX
+resume-saved-game = Refbox 停止时有一场比赛正在进行。
+    比赛 { $game_number }    黑方：{ $score_black }    白方：{ $score_white }
+    是否继续这场比赛？
+resume-game = 继续比赛
+start-fresh = 重新开始
+undo = 撤销
+redo = 重做
+penalty-shootout = 点球大战
+start-shootout = 开始点球大战
+start-shootout-confirm = 结束突然死亡并以点球大战决定比赛？此操作无法撤销。
+remove-last-attempt = 删除上一次罚球
+shootout-line-1 = 点球
+shootout-line-2 = 罚球
+shootout-round = 第 { $round } 轮
+shot-scored = 罚进
+shot-missed = 罚失
+shootout-goal = 进
+shootout-miss = 失
+stop-clock = 停止计时
+restart-clock = 重新开始计时
+suspended-players = 停赛球员
+suspended-player = { $team } #{ $number }：还剩 { $games } 场
+catch-up = 追赶进度
+catch-up-plan-game = 比赛 { $game }：休息 -{ $cut }，推迟 { $late } 开始
+catch-up-plan-game-half-time = 比赛 { $game }：休息 -{ $cut }，推迟 { $late } 开始，中场休息 { $half_time }
+catch-up-plan-on-time = 第 { $game } 场比赛恢复正常进度
+catch-up-plan-late = 最后一场比赛后仍落后 { $late }
+accept-plan = 接受计划
+shorten-half-times = 缩短中场休息
+keep-half-times = 保留中场休息
+disallow-goal = 取消进球
+disallowed-goals = 已取消的进球
+black-goals = 黑队进球
+white-goals = 白队进球
+goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$period} {$time}
+        *[number] #{$player_number} - {$period} {$time}
+    }
+disallowed-goal-entry = {$player_number ->
+        [none] { team-warning-abreviation } - {$reason}
+        *[number] #{$player_number} - {$reason}
+    }
+disallow-reason = {$reason ->
+    [foul] 犯规
+    [infraction] 违例
+   *[scorer-correction] 进球者更正
+}
+export-results = 导出结果
+results-exported = 结果已导出到：
+    { $dir }
+source-file = 文件
+schedule-file = 赛程文件：
+not-found = 未找到
+player-stats = 球员统计
+top-scorers = 射手榜
+discipline = 纪律
+export = 导出
+no-player-stats = 尚无进球、罚时或犯规记录
+player-stats-goals = { $goals ->
+    [one] 1 球
+   *[other] { $goals } 球
+}
+player-stats-discipline = 罚出场 { $dismissals }  罚时 { $penalties } ({ $time })  犯规 { $fouls }
+completed-scoresheet = 已填写的记分表
+scoresheet-detailed = 详细
+scoresheet-simple = 简单
+scoresheet-cmas-official = CMAS 官方
+timeline = 时间线
+no-timeline-entries = 本场比赛尚无任何事件
+timeline-goal = 进球
+timeline-disallowed-goal = 无效进球
+timeline-penalty = 判罚 ({ penalty-kind })
+timeline-warning = 警告
+timeline-foul = 犯规
+timeline-team-timeout = 球队暂停
+timeline-ref-timeout = 裁判暂停
+timeline-penalty-shot = 罚球
+timeline-period-start = 时段开始
+captains-sign-off-result = 队长签认
+    比赛结果
+cap-number-or-id = 帽号
+    或编号:
+sign-off-entry-hint = 输入队长的帽号或其他识别编号,输入 0 清除
+sign-off-result = 签认比赛 { $game }: 黑队 { $score_black } - 白队 { $score_white }
+captain-signed = 队长已签认
+not-signed = 未签认
+protest-lodged = 已提出申诉
+portal-row-under-review = 比赛 { $game } 审核中,申诉处理完毕后点击
+report-incident = 报告事件
+incident-category = {$category ->
+    [injury] 受伤
+    [equipment-failure] 器材
+    [misconduct] 不当行为
+   *[other] 其他
+}
+incident-player = 球员
+incident-time = 事件发生于 { $period } { $time }
+no-team = 无队伍
+incident-description = 描述发生的情况
+incident-player-hint = 输入相关球员的帽号,无则输入 0
+edit-history = 修改记录
+no-edits = 本场比赛没有手动修改
+audit-change = {$change ->
+    [score-confirmed] 比分已确认
+    [score-edited] 比分已修改
+    [game-clock] 比赛时钟已设置
+    [timeout-clock] 暂停时钟已设置
+    [penalty-edited] 判罚已修改
+    [goal-disallowed] 进球无效
+    [penalty-added] 判罚已添加
+    [foul-added] 犯规已添加
+    [foul-deleted] 犯规已删除
+    [clocks-set] 时钟已设置
+   *[penalty-deleted] 判罚已删除
+}
+audit-clock-stopped = 已停止
+penalty-shootout-allowed = 点球
+    大战:
+shootout-rounds = 点球
+    轮数:
+gi-penalty-shootout = 点球大战
+gi-shootout-rounds = 点球轮数
+stop-time-allowed = 停表
+    时间:
+stop-time-window = 停表
+    时段:
+stop-time-window-len = 停表时段
+stop-time-window-help = 每个比赛时段结束前多长时间内每次鸣哨都会停止比赛时钟
+gi-stop-time = 停表时间
+gi-stop-time-window = 停表时段
@@ -12,8 +12,10 @@ use rfd::FileDialog;
 use std::{
     collections::BTreeMap,
     fmt::{Display, Write},
+    sync::{Arc, Mutex},
     vec,
 };
+use uwh_common::game_data::{self, SharedSource};
 use uwh_common::uwhportal::{CoinFlipTeam, SetCoinFlipModel, UwhPortalClient, schedule::*};
 
 mod cmas_official;
@@ -24,13 +26,15 @@ use csv_parser::parse_csv;
 mod json_loader;
 use json_loader::parse_json;
 
+mod player_stats;
+
 mod schedule_checks;
 use schedule_checks::run_schedule_checks;
 
 mod scoresheets;
 use scoresheets::{
     RenderInputs, SheetStyle, generate_example_rule_sheets, generate_scoresheets_for_event,
-    style_needs_sanctioning_logo, style_needs_tournament_logo,
+    needs_login, style_needs_sanctioning_logo, style_needs_tournament_logo,
 };
 
 mod site;
@@ -211,14 +215,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Using URL: {}", target.base_url);
     info!("Fetching event list from uwhportal...");
 
-    let mut portal_client = UwhPortalClient::new(
+    // The event's data is read through `source`, as the refbox reads it. Logging in, uploading and
+    // resolving coin tosses are portal calls that no other source has, so they go to the client.
+    let portal_client = Arc::new(Mutex::new(UwhPortalClient::new(
         &target.base_url,
         pasted_key.as_deref(),
         target.require_https,
         std::time::Duration::from_secs(10),
-    )?;
+    )?));
+    let source: SharedSource = portal_client.clone();
 
-    let mut events = portal_client.get_event_list(false, false).await?;
+    let mut events = source.list_events(false, false).await?;
     // Show soonest events first: nearest date at the top, further-future toward the bottom.
     events.sort_by_key(|e| e.date_range.start);
 
@@ -341,6 +348,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             ResolveCoinTosses,
             GenerateScoreSheets,
             GenerateExampleSheets,
+            PlayerStats,
             SaveTeamMap,
             SaveTeamMapDisabled,
             PrintTeamMap,
@@ -361,6 +369,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     StepChoice::GenerateExampleSheets => {
                         write!(f, "Generate Example Sheets (rule options)")
                     }
+                    StepChoice::PlayerStats => write!(f, "Player Statistics from Results"),
                     StepChoice::SaveTeamMap => write!(f, "Save Team Map to File"),
                     StepChoice::SaveTeamMapDisabled => write!(f, "S̶a̶v̶e̶ ̶T̶e̶a̶m̶ ̶M̶a̶p̶ ̶t̶o̶ ̶F̶i̶l̶e̶"),
                     StepChoice::PrintTeamMap => write!(f, "Print Team Map"),
@@ -379,6 +388,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 StepChoice::ResolveCoinTosses,
                 StepChoice::GenerateScoreSheets,
                 StepChoice::GenerateExampleSheets,
+                StepChoice::PlayerStats,
                 StepChoice::SaveTeamMapDisabled,
                 StepChoice::PrintTeamMapDisabled,
                 StepChoice::Exit,
@@ -392,6 +402,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 StepChoice::ResolveCoinTosses,
                 StepChoice::GenerateScoreSheets,
                 StepChoice::GenerateExampleSheets,
+                StepChoice::PlayerStats,
                 StepChoice::SaveTeamMap,
                 StepChoice::PrintTeamMap,
                 StepChoice::Exit,
@@ -427,7 +438,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .unique()
                     .collect();
 
-                let event_teams = match portal_client.get_event_teams(&event.id).await {
+                let event_teams = match source.teams(&event.id).await {
                     Ok(teams) => teams,
                     Err(e) => {
                         error!("Getting event teams failed. Please try again. Reason: {e}");
@@ -581,7 +592,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .clone()
                     .into();
 
-                if !portal_client.has_token() {
+                if !game_data::lock(&portal_client).has_token() {
                     match target.kind {
                         site::SiteKind::Custom => {
                             // Custom sites have no organiser login — the access
@@ -601,7 +612,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 // startup key is not held separately, so a
                                 // later `clear_token` asks for it again.
                                 Ok(Some(key)) => {
-                                    if let Err(why) = portal_client.set_token(&key) {
+                                    if let Err(why) =
+                                        game_data::lock(&portal_client).set_token(&key)
+                                    {
                                         error!("{why}");
                                         continue 'outer;
                                     }
@@ -636,10 +649,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             };
 
-                            let token = match portal_client
-                                .login_with_email_and_password(&email, &password)
-                                .await
-                            {
+                            let login = game_data::lock(&portal_client)
+                                .login_with_email_and_password(&email, &password);
+                            let token = match login.await {
                                 Ok(token) => token,
                                 Err(e) => {
                                     error!("uwhportal login failed. Please try again. Reason: {e}");
@@ -647,7 +659,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             };
 
-                            if let Err(why) = portal_client.set_token(&token) {
+                            if let Err(why) = game_data::lock(&portal_client).set_token(&token) {
                                 error!(
                                     "The site returned an access key that cannot be used: {why}"
                                 );
@@ -671,31 +683,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 };
 
                 info!("Uploading schedule for event: {}", event.slug);
-                match portal_client
-                    .push_event_schedule(&event.slug, &sendable_schedule, force)
-                    .await
-                {
+                let upload = game_data::lock(&portal_client).push_event_schedule(
+                    &event.slug,
+                    &sendable_schedule,
+                    force,
+                );
+                match upload.await {
                     Ok(_) => {
                         info!("Schedule uploaded successfully.");
                     }
                     Err(e) => {
                         error!("Failed to upload schedule. Please try again. Reason: {e}");
-                        portal_client.clear_token();
+                        game_data::lock(&portal_client).clear_token();
                         continue 'outer;
                     }
                 }
 
                 info!("Sending team map");
-                match portal_client
-                    .push_team_map(&event.slug, &sendable_team_map(&team_map))
-                    .await
-                {
+                let team_map_upload = game_data::lock(&portal_client)
+                    .push_team_map(&event.slug, &sendable_team_map(&team_map));
+                match team_map_upload.await {
                     Ok(_) => {
                         info!("Team map sent successfully.");
                     }
                     Err(e) => {
                         error!("Failed to send team map. Please try again. Reason: {e}");
-                        portal_client.clear_token();
+                        game_data::lock(&portal_client).clear_token();
                         continue 'outer;
                     }
                 }
@@ -782,7 +795,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             StepChoice::ResolveCoinTosses => {
                 // Requires login to access privileged coin-flip endpoints.
-                if !portal_client.has_token() {
+                if !game_data::lock(&portal_client).has_token() {
                     #[allow(non_snake_case)]
                     let emailOrusername =
                         match Text::new("Enter your uwhportal emailOrusername:").prompt() {
@@ -803,12 +816,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             continue 'outer;
                         }
                     };
-                    match portal_client
-                        .login_with_email_and_password(&emailOrusername, &password)
-                        .await
-                    {
+                    let login = game_data::lock(&portal_client)
+                        .login_with_email_and_password(&emailOrusername, &password);
+                    match login.await {
                         Ok(token) => {
-                            if let Err(why) = portal_client.set_token(&token) {
+                            if let Err(why) = game_data::lock(&portal_client).set_token(&token) {
                                 error!(
                                     "The site returned an access key that cannot be used: {why}"
                                 );
@@ -822,16 +834,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
 
-                let team_lookup: BTreeMap<String, String> =
-                    match portal_client.get_event_teams(&event.id).await {
-                        Ok(teams) => teams
-                            .into_iter()
-                            .map(|(id, name)| (id.full().to_string(), name))
-                            .collect(),
-                        Err(_) => BTreeMap::new(),
-                    };
+                let team_lookup: BTreeMap<String, String> = match source.teams(&event.id).await {
+                    Ok(teams) => teams
+                        .into_iter()
+                        .map(|(id, name)| (id.full().to_string(), name))
+                        .collect(),
+                    Err(_) => BTreeMap::new(),
+                };
 
-                let details = match portal_client.get_coin_flips(&event.slug).await {
+                let coin_flips = game_data::lock(&portal_client).get_coin_flips(&event.slug);
+                let details = match coin_flips.await {
                     Ok(d) => d,
                     Err(e) => {
                         error!("Failed to fetch coin tosses: {e}");
@@ -962,10 +974,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     kind,
                 };
 
-                match portal_client
-                    .set_coin_flip_result(&event.slug, &model, false)
-                    .await
-                {
+                let resolution = game_data::lock(&portal_client).set_coin_flip_result(
+                    &event.slug,
+                    &model,
+                    false,
+                );
+                match resolution.await {
                     Ok(()) => info!("Coin toss resolved successfully."),
                     Err(e) => error!("Failed to set coin toss: {e}"),
                 }
@@ -1096,7 +1110,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // Portal official display names require a login.
                 let wants_portal_names =
                     include_referees && (ref_csv_path.is_none() || prefer_portal_officials);
-                if wants_portal_names && !portal_client.has_token() {
+                if wants_portal_names && !game_data::lock(&portal_client).has_token() {
                     if let Ok(true) =
                         Confirm::new("Use display names for officials? (requires uwhportal login)")
                             .with_default(true)
@@ -1120,12 +1134,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 .prompt()
                                 .unwrap_or_default();
                             if !password.is_empty() {
-                                match portal_client
-                                    .login_with_email_and_password(&emailOrusername, &password)
-                                    .await
-                                {
+                                let login = game_data::lock(&portal_client)
+                                    .login_with_email_and_password(&emailOrusername, &password);
+                                match login.await {
                                     Ok(token) => {
-                                        if let Err(why) = portal_client.set_token(&token) {
+                                        if let Err(why) =
+                                            game_data::lock(&portal_client).set_token(&token)
+                                        {
                                             error!(
                                                 "The site returned an access key that cannot be \
                                                  used. Proceeding without login. Reason: {why}"
@@ -1144,7 +1159,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let csv_schedule_opt = schedule.as_ref();
                 match generate_scoresheets_for_event(
-                    &mut portal_client,
+                    &portal_client,
                     &event,
                     inputs.clone(),
                     csv_schedule_opt,
@@ -1154,8 +1169,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 {
                     Ok(()) => info!("Score sheets generated."),
                     Err(e) => {
-                        let msg = e.to_string();
-                        if msg.contains("authentication required for schedule") {
+                        if needs_login(e.as_ref()) {
                             info!(
                                 "A login is required to read the schedule; prompting for login..."
                             );
@@ -1179,12 +1193,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     continue 'outer;
                                 }
                             };
-                            match portal_client
-                                .login_with_email_and_password(&emailOrusername, &password)
-                                .await
-                            {
+                            let login = game_data::lock(&portal_client)
+                                .login_with_email_and_password(&emailOrusername, &password);
+                            match login.await {
                                 Ok(token) => {
-                                    if let Err(why) = portal_client.set_token(&token) {
+                                    if let Err(why) =
+                                        game_data::lock(&portal_client).set_token(&token)
+                                    {
                                         error!(
                                             "The site returned an access key that cannot be used: {why}"
                                         );
@@ -1198,7 +1213,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                             let csv_schedule_opt = schedule.as_ref();
                             match generate_scoresheets_for_event(
-                                &mut portal_client,
+                                &portal_client,
                                 &event,
                                 inputs,
                                 csv_schedule_opt,
@@ -1242,6 +1257,76 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     Err(e) => error!("Failed to generate example sheets: {e}"),
                 }
             }
+            StepChoice::PlayerStats => {
+                info!(
+                    "Please select the refbox's results.json, or the results files it exported, \
+                    in the file dialog."
+                );
+                let Some(paths) = FileDialog::new()
+                    .add_filter("JSON files", &["json"])
+                    .set_title("Select Refbox Results Files")
+                    .pick_files()
+                else {
+                    error!("No results file selected. Skipping player statistics.");
+                    continue 'outer;
+                };
+                let games = match player_stats::load_games(&paths) {
+                    Ok(games) => games,
+                    Err(e) => {
+                        error!("Could not read the results: {e}");
+                        continue 'outer;
+                    }
+                };
+                let mut stats = player_stats::event_stats(&games, &event.id);
+                if stats.games == 0 {
+                    error!("The selected results have no games from {}.", event.name);
+                    continue 'outer;
+                }
+
+                // Rosters come from an admin endpoint, so names need a login
+                if game_data::lock(&portal_client).has_token() {
+                    let mut rosters = BTreeMap::new();
+                    for team in player_stats::teams(&stats) {
+                        match source.roster(&team).await {
+                            Ok(players) => {
+                                rosters.insert(team, players);
+                            }
+                            Err(e) => log::warn!(
+                                "Could not get the roster of team {}, its players are listed \
+                                by cap number: {e}",
+                                team.full()
+                            ),
+                        }
+                    }
+                    stats.add_names(&rosters);
+                } else {
+                    log::warn!("Not logged in to uwhportal, players are listed by cap number");
+                }
+
+                println!(
+                    "Top Scorers ({} games)\n{}",
+                    stats.games,
+                    player_stats::top_scorers_table(&stats)
+                );
+                println!("Discipline\n{}", player_stats::discipline_table(&stats));
+
+                let save = Confirm::new("Save the player statistics to a CSV file?")
+                    .with_default(true)
+                    .prompt()
+                    .unwrap_or(false);
+                if save {
+                    let output_path = FileDialog::new()
+                        .add_filter("CSV files", &["csv"])
+                        .set_title("Save Player Statistics as CSV")
+                        .save_file();
+                    if let Some(path) = output_path {
+                        info!("Saving player statistics to file: {}", path.display());
+                        std::fs::write(path, player_stats::to_csv(&stats)?)?;
+                    } else {
+                        error!("No file selected for saving. Skipping save.");
+                    }
+                }
+            }
             StepChoice::Exit => {
                 info!("Exiting the application.");
                 return Ok(());
@@ -0,0 +1,182 @@
+//! Player statistics from refbox results archives
+//!
+//! Reads the refbox's `results.json`, or the `results-<day>.json` files it exports to a USB stick,
+//! and produces the same top scorers and discipline tables as the refbox's player statistics page.
+
+use prettytable::{Cell, Row, Table};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    path::Path,
+};
+use uwh_common::uwhportal::{
+    player_stats::{ArchivedGameStats, PlayerStats, PlayerTotals},
+    schedule::{EventId, TeamId},
+};
+
+/// Either shape of file the refbox writes: the archive itself, or a day's export
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ArchiveFile {
+    Archive { games: Vec<ArchivedGameStats> },
+    DayExport(Vec<ArchivedGameStats>),
+}
+
+/// Reads every game from the given files. A game found in more than one file (the archive and a
+/// day exported from it) is only kept once.
+pub fn load_games(paths: &[impl AsRef<Path>]) -> Result<Vec<ArchivedGameStats>, Box<dyn Error>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        let contents = std::fs::read(path)?;
+        files.push(
+            parse_games(&contents)
+                .map_err(|e| format!("{} is not a refbox results file: {e}", path.display()))?,
+        );
+    }
+    Ok(merge(files))
+}
+
+fn parse_games(contents: &[u8]) -> Result<Vec<ArchivedGameStats>, serde_json::Error> {
+    let (ArchiveFile::Archive { games } | ArchiveFile::DayExport(games)) =
+        serde_json::from_slice(contents)?;
+    Ok(games)
+}
+
+fn merge(files: Vec<Vec<ArchivedGameStats>>) -> Vec<ArchivedGameStats> {
+    let mut games = BTreeMap::new();
+    for game in files.into_iter().flatten() {
+        let key = (game.event_id.clone(), game.game_number.clone(), game.day);
+        games.insert(key, game);
+    }
+    games.into_values().collect()
+}
+
+/// The totals over every game of `event_id`, without names
+pub fn event_stats(games: &[ArchivedGameStats], event_id: &EventId) -> PlayerStats {
+    let mut stats = PlayerStats::default();
+    for game in games
+        .iter()
+        .filter(|g| g.event_id.as_ref() == Some(event_id))
+    {
+        if let Err(e) = stats.add_game(&game.team_ids, &game.teams, &game.stats) {
+            log::warn!(
+                "Leaving game {} out of the player statistics: {e}",
+                game.game_number
+            );
+        }
+    }
+    stats
+}
+
+/// The teams whose rosters are needed to name the players
+pub fn teams(stats: &PlayerStats) -> BTreeSet<TeamId> {
+    stats.players().map(|p| p.team.clone()).collect()
+}
+
+pub fn top_scorers_table(stats: &PlayerStats) -> Table {
+    let scorers = stats.top_scorers();
+    let mut table = Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.set_titles(Row::new(vec![
+        Cell::new("Rank"),
+        Cell::new("Player"),
+        Cell::new("Team"),
+        Cell::new("Goals"),
+    ]));
+    for player in &scorers {
+        // Players level on goals share a rank
+        let rank = 1 + scorers.iter().filter(|p| p.goals > player.goals).count();
+        table.add_row(Row::new(vec![
+            Cell::new(&rank.to_string()),
+            Cell::new(&player.player_display()),
+            Cell::new(player.team_display()),
+            Cell::new(&player.goals.to_string()),
+        ]));
+    }
+    table
+}
+
+pub fn discipline_table(stats: &PlayerStats) -> Table {
+    let mut table = Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+    table.set_titles(Row::new(vec![
+        Cell::new("Player"),
+        Cell::new("Team"),
+        Cell::new("Total Dismissals"),
+        Cell::new("Penalties"),
+        Cell::new("Penalty Time"),
+        Cell::new("Fouls"),
+    ]));
+    for player in stats.discipline() {
+        table.add_row(Row::new(vec![
+            Cell::new(&player.player_display()),
+            Cell::new(player.team_display()),
+            Cell::new(&player.total_dismissals.to_string()),
+            Cell::new(&player.penalties.to_string()),
+            Cell::new(&player.penalty_time()),
+            Cell::new(&player.fouls.to_string()),
+        ]));
+    }
+    table
+}
+
+/// The same CSV the refbox exports
+pub fn to_csv(stats: &PlayerStats) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(PlayerTotals::CSV_HEADERS)?;
+    for player in stats.players() {
+        writer.write_record(player.csv_record())?;
+    }
+    Ok(writer.into_inner()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = r#"{
+        "event_id": "events/spring-cup",
+        "game_number": "1",
+        "day": "2026-06-22",
+        "ended_at": "2026-06-22T09:35:00+02:00",
+        "teams": { "black": "Sharks", "white": "Eels" },
+        "team_ids": { "black": "teams/1-A", "white": "teams/2-A" },
+        "scores": { "black": 1, "white": 0 },
+        "stats": [
+            { "$type": "goal", "playerCapNumber": 7, "side": "dark", "gamePeriod": "FirstHalf",
+              "periodTime": 100.0, "occurredOn": "2026-06-22T09:10:00+02:00" }
+        ]
+    }"#;
+
+    #[test]
+    fn reads_the_archive_and_day_exports_without_counting_a_game_twice() {
+        let archive = format!(r#"{{ "version": 1, "games": [{GAME}] }}"#);
+        let export = format!("[{GAME}]");
+        let games = merge(vec![
+            parse_games(archive.as_bytes()).unwrap(),
+            parse_games(export.as_bytes()).unwrap(),
+        ]);
+        assert_eq!(games.len(), 1);
+
+        let stats = event_stats(&games, &EventId::from_partial("spring-cup"));
+        let scorers: Vec<_> = stats
+            .top_scorers()
+            .into_iter()
+            .map(|p| (p.team_display(), p.cap_number, p.goals))
+            .collect();
+        assert_eq!(scorers, [("Sharks", 7, 1)]);
+        assert_eq!(
+            teams(&stats).into_iter().collect::<Vec<_>>(),
+            [TeamId::from_partial("1-A")]
+        );
+
+        assert!(event_stats(&games, &EventId::from_partial("autumn-cup")).is_empty());
+    }
+
+    #[test]
+    fn a_file_that_is_not_a_results_file_is_an_error() {
+        assert!(parse_games(br#"{ "games": "nope" }"#).is_err());
+    }
+}
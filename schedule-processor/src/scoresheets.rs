@@ -2,11 +2,12 @@ use std::collections::HashMap;
 use std::{
     fmt, fs,
     path::{Path, PathBuf},
-    process::Command,
+    sync::Mutex,
 };
 use time::{
     Duration as TimeDur, OffsetDateTime, format_description::FormatItem, macros::format_description,
 };
+use uwh_common::game_data::{self, GameDataSource, SourceError};
 use uwh_common::uwhportal::UwhPortalClient;
 use uwh_common::uwhportal::schedule::{
     DateRange, Event, EventId, Game, ScheduledTeam, TeamId, TeamList, TimingRule,
@@ -42,6 +43,13 @@ impl fmt::Display for AuthRequiredError {
 }
 impl std::error::Error for AuthRequiredError {}
 
+/// Whether `e` from `generate_scoresheets_for_event` is fixed by logging in: either no login was
+/// held, or the portal refused the one that was.
+pub fn needs_login(e: &(dyn std::error::Error + 'static)) -> bool {
+    e.is::<AuthRequiredError>()
+        || matches!(e.downcast_ref(), Some(SourceError::Portal(e)) if e.is_auth())
+}
+
 // Output style for scoresheets
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SheetStyle {
@@ -83,7 +91,7 @@ pub struct RenderInputs {
 }
 
 pub async fn generate_scoresheets_for_event(
-    portal_client: &mut UwhPortalClient,
+    portal_client: &Mutex<UwhPortalClient>,
     event: &Event,
     inputs: RenderInputs,
     csv_schedule: Option<&uwh_common::uwhportal::schedule::Schedule>,
@@ -92,14 +100,12 @@ pub async fn generate_scoresheets_for_event(
     // The schedule is only available to a logged-in caller. The portal does serve an
     // unauthenticated schedule on the same path, but in a different shape that this
     // crate has never been able to read, so there is nothing to fall back to.
-    let schedule = if portal_client.has_token() {
-        portal_client
-            .get_event_schedule_privileged(&event.id)
-            .await?
+    let schedule = if game_data::lock(portal_client).has_token() {
+        portal_client.schedule(&event.id).await?
     } else {
         return Err(Box::new(AuthRequiredError));
     };
-    let teams = portal_client.get_event_teams(&event.id).await?;
+    let teams = portal_client.teams(&event.id).await?;
 
     fs::create_dir_all(&inputs.output_dir)?;
 
@@ -133,10 +139,7 @@ pub async fn generate_scoresheets_for_event(
 
     let mut name_cache: HashMap<String, String> = HashMap::new();
     // Pre-fill official name cache: try public /referees first (no auth), then merge /participants (auth)
-    match portal_client
-        .get_event_referee_name_map_from_referees(&event.id)
-        .await
-    {
+    match portal_client.referee_names(&event.id).await {
         Ok(map) => {
             let count = map.len();
             for (uid, name) in map {
@@ -148,7 +151,8 @@ pub async fn generate_scoresheets_for_event(
             log::warn!("Could not prefill officials from /referees: {}", e);
         }
     }
-    match portal_client.get_event_referee_name_map(&event.id).await {
+    let participants = game_data::lock(portal_client).get_event_referee_name_map(&event.id);
+    match participants.await {
         Ok(map) => {
             let mut added = 0usize;
             for (uid, name) in map {
@@ -180,8 +184,7 @@ pub async fn generate_scoresheets_for_event(
         let officials = if !inputs.include_referees {
             OfficialNames::default()
         } else if inputs.prefer_portal_officials {
-            let resolved =
-                resolve_officials(&*portal_client, &event.id, game, &mut name_cache).await;
+            let resolved = resolve_officials(portal_client, &event.id, game, &mut name_cache).await;
             // Fallback to CSV for this game if portal provides nothing
             if resolved.chief.is_empty()
                 && resolved.water1.is_empty()
@@ -197,7 +200,7 @@ pub async fn generate_scoresheets_for_event(
         } else if let Some(o) = ref_overrides.get(num) {
             o.clone()
         } else {
-            resolve_officials(&*portal_client, &event.id, game, &mut name_cache).await
+            resolve_officials(portal_client, &event.id, game, &mut name_cache).await
         };
 
         let tr = find_timing_rule(game, csv_schedule, &schedule)?;
@@ -205,9 +208,9 @@ pub async fn generate_scoresheets_for_event(
         // Fetch rosters for styles that print player names
         let (black_roster, white_roster) = if style_needs_rosters(inputs.style) {
             log::info!("Fetching rosters for game {}", num);
-            let black = fetch_team_roster(&*portal_client, game.dark.assigned()).await;
+            let black = fetch_team_roster(portal_client, game.dark.assigned()).await;
             log::info!("Black team roster: {} players", black.players.len());
-            let white = fetch_team_roster(&*portal_client, game.light.assigned()).await;
+            let white = fetch_team_roster(portal_client, game.light.assigned()).await;
             log::info!("White team roster: {} players", white.players.len());
             (black, white)
         } else {
@@ -362,106 +365,7 @@ pub async fn generate_scoresheets_for_event(
         fs::write(&all_html_path, all_html.as_bytes())?;
         let all_pdf_path = inputs.output_dir.join("scoresheets-all.pdf");
 
-        // Convert to file:/// URL for Chrome without introducing Windows \\?\ prefix
-        let html_abs = if all_html_path.is_absolute() {
-            all_html_path.clone()
-        } else {
-            std::env::current_dir()
-                .unwrap_or_else(|_| std::path::PathBuf::from("."))
-                .join(&all_html_path)
-        };
-        let html_url = format!("file:///{}", html_abs.to_string_lossy().replace('\\', "/"));
-
-        // Browsers to try for HTML->PDF, across Linux/WSL, macOS and Windows.
-        // Override with the SCORESHEET_BROWSER env var (full path to a browser).
-        let candidates = browser_candidates(
-            std::env::var("SCORESHEET_BROWSER").ok().as_deref(),
-            std::env::var("LOCALAPPDATA").ok().as_deref(),
-        );
-        let try_arg_sets: [&[&str]; 4] = [
-            &[
-                "--headless=new",
-                "--disable-gpu",
-                "--allow-file-access-from-files",
-                "--virtual-time-budget=8000",
-                "--no-sandbox",
-                "--disable-extensions",
-                "--disable-dev-shm-usage",
-            ],
-            &[
-                "--headless",
-                "--disable-gpu",
-                "--allow-file-access-from-files",
-                "--virtual-time-budget=8000",
-                "--no-sandbox",
-                "--disable-extensions",
-                "--disable-dev-shm-usage",
-            ],
-            &[
-                "--headless=new",
-                "--disable-gpu",
-                "--allow-file-access-from-files",
-                "--run-all-compositor-stages-before-draw",
-                "--no-sandbox",
-            ],
-            &[
-                "--headless",
-                "--disable-gpu",
-                "--allow-file-access-from-files",
-                "--run-all-compositor-stages-before-draw",
-                "--no-sandbox",
-            ],
-        ];
-        let mut printed = false;
-        'outer: for c in &candidates {
-            // If 'c' looks like a path, ensure it exists before trying
-            let is_path_like = c.contains('\\') || c.contains('/') || c.contains(':');
-            if is_path_like && !std::path::Path::new(c).exists() {
-                log::debug!("Browser not found at: {}", c);
-                continue;
-            }
-            for args in &try_arg_sets {
-                let mut cmd = Command::new(c);
-                let print_arg = format!("--print-to-pdf={}", all_pdf_path.display());
-                cmd.args(*args)
-                    .arg("--no-first-run")
-                    .arg("--no-default-browser-check")
-                    .arg(print_arg)
-                    .arg(&html_url);
-                log::debug!(
-                    "Attempting to print via '{}' url={} pdf={}",
-                    c,
-                    &html_url,
-                    all_pdf_path.display()
-                );
-                match cmd.output() {
-                    Ok(out) if out.status.success() => {
-                        log::info!("Wrote combined PDF to {}", all_pdf_path.display());
-                        printed = true;
-                        break 'outer;
-                    }
-                    Ok(out) => {
-                        let so = String::from_utf8_lossy(&out.stdout);
-                        let se = String::from_utf8_lossy(&out.stderr);
-                        log::debug!(
-                            "Browser '{}' run failed (status {}). stdout: {} stderr: {}",
-                            c,
-                            out.status,
-                            so.trim(),
-                            se.trim()
-                        );
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                        // Browser isn't installed; skip its remaining arg sets.
-                        log::debug!("Browser '{}' is not installed", c);
-                        continue 'outer;
-                    }
-                    Err(e) => {
-                        log::debug!("Failed to run browser '{}': {}", c, e);
-                    }
-                }
-            }
-        }
+        let printed = uwh_common::html_pdf::print_to_pdf(&all_html_path, &all_pdf_path);
 
         if printed {
             // Success: leave only the PDF. Remove the intermediate combined HTML and the
@@ -494,52 +398,6 @@ pub async fn generate_scoresheets_for_event(
     Ok(())
 }
 
-/// Build the ordered list of browser executables to try when converting the
-/// combined score-sheet HTML into a PDF via headless Chrome/Chromium.
-///
-/// Order: an explicit override (`SCORESHEET_BROWSER`) first, then common
-/// Linux/macOS names resolved via PATH, then the standard Windows Chrome
-/// install locations, then a bare `chrome` on PATH.
-///
-/// `explicit` is the value of the `SCORESHEET_BROWSER` env var (a full path to
-/// a browser executable); `localappdata` is the Windows `LOCALAPPDATA` env var.
-fn browser_candidates(explicit: Option<&str>, localappdata: Option<&str>) -> Vec<String> {
-    let mut candidates: Vec<String> = Vec::new();
-
-    // 1. Explicit override wins, when set and non-empty.
-    if let Some(path) = explicit {
-        if !path.is_empty() {
-            candidates.push(path.to_string());
-        }
-    }
-
-    // 2. Linux / macOS names, resolved via PATH.
-    for name in [
-        "chromium",
-        "chromium-browser",
-        "google-chrome",
-        "google-chrome-stable",
-        "microsoft-edge",
-    ] {
-        candidates.push(name.to_string());
-    }
-
-    // 3. Standard Windows Chrome install locations (kept for the Windows build).
-    candidates.push(r#"C:\\Program Files\\Google\\Chrome\\Application\\chrome.exe"#.to_string());
-    candidates
-        .push(r#"C:\\Program Files (x86)\\Google\\Chrome\\Application\\chrome.exe"#.to_string());
-    if let Some(local) = localappdata {
-        let mut p = PathBuf::from(local);
-        p.push("Google\\Chrome\\Application\\chrome.exe");
-        candidates.push(p.to_string_lossy().into_owned());
-    }
-
-    // 4. Bare `chrome` on PATH.
-    candidates.push("chrome".to_string());
-
-    candidates
-}
-
 fn copy_logo(
     output_dir: &Path,
     src: Option<&Path>,
@@ -708,7 +566,7 @@ struct OfficialNames {
 }
 
 async fn resolve_officials(
-    portal_client: &UwhPortalClient,
+    portal_client: &Mutex<UwhPortalClient>,
     event_id: &EventId,
     game: &Game,
     cache: &mut HashMap<String, String>,
@@ -727,10 +585,9 @@ async fn resolve_officials(
             } else {
                 // On first miss for this game, fetch all game refs in one call
                 if !fetched_for_game {
-                    if let Ok(per_game) = portal_client
-                        .get_game_referee_name_map(event_id, &game.number)
-                        .await
-                    {
+                    let per_game = game_data::lock(portal_client)
+                        .get_game_referee_name_map(event_id, &game.number);
+                    if let Ok(per_game) = per_game.await {
                         for (uid, name) in per_game {
                             cache.entry(uid).or_insert(name);
                         }
@@ -772,7 +629,7 @@ async fn resolve_officials(
 }
 
 async fn fetch_team_roster(
-    portal_client: &UwhPortalClient,
+    source: &dyn GameDataSource,
     team_id: Option<&TeamId>,
 ) -> TeamRosterInfo {
     let Some(id) = team_id else {
@@ -781,7 +638,7 @@ async fn fetch_team_roster(
     };
 
     log::debug!("fetch_team_roster: Fetching roster for team {}", id);
-    match portal_client.get_team_roster(id).await {
+    match source.roster(id).await {
         Ok(players) => {
             log::debug!("fetch_team_roster: Got {} players", players.len());
             TeamRosterInfo { players }
@@ -2716,9 +2573,113 @@ pub(crate) fn html_escape(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        SheetStyle, browser_candidates, style_needs_rosters, style_needs_sanctioning_logo,
-        style_needs_tournament_logo,
+        AuthRequiredError, SheetStyle, fetch_team_roster, needs_login, style_needs_rosters,
+        style_needs_sanctioning_logo, style_needs_tournament_logo,
     };
+    use std::collections::{BTreeMap, HashMap};
+    use uwh_common::{
+        bundles::BlackWhiteBundle,
+        game_data::{GameDataSource, SourceError, SourceFuture, Unsupported, ready},
+        uwhportal::{
+            PortalError, RosterPlayer,
+            schedule::{Event, EventId, GameNumber, Schedule, TeamId, TeamList},
+        },
+    };
+
+    /// A source that only knows rosters
+    struct Rosters(BTreeMap<TeamId, Vec<RosterPlayer>>);
+
+    const UNSUPPORTED: Unsupported = Unsupported {
+        source: "roster table",
+        call: "answer that",
+    };
+
+    impl GameDataSource for Rosters {
+        fn list_events(&self, _: bool, _: bool) -> SourceFuture<Vec<Event>> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+
+        fn schedule(&self, _: &EventId) -> SourceFuture<Schedule> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+
+        fn teams(&self, _: &EventId) -> SourceFuture<TeamList> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+
+        fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>> {
+            ready(
+                self.0
+                    .get(team_id)
+                    .cloned()
+                    .ok_or_else(|| SourceError::Other("no such team".into())),
+            )
+        }
+
+        fn referee_names(&self, _: &EventId) -> SourceFuture<HashMap<String, String>> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+
+        fn submit_scores(
+            &self,
+            _: &EventId,
+            _: &GameNumber,
+            _: BlackWhiteBundle<u8>,
+            _: bool,
+        ) -> SourceFuture<()> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+
+        fn submit_stats(&self, _: &EventId, _: &GameNumber, _: String) -> SourceFuture<()> {
+            ready(Err(UNSUPPORTED.into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn a_roster_that_cannot_be_fetched_is_left_empty() {
+        let sharks = TeamId::from_partial("1-A");
+        let source = Rosters(BTreeMap::from([(
+            sharks.clone(),
+            vec![RosterPlayer {
+                number: Some(3),
+                name: "Sam".to_string(),
+                is_captain: true,
+                is_vice_captain: false,
+            }],
+        )]));
+
+        assert_eq!(
+            fetch_team_roster(&source, Some(&sharks))
+                .await
+                .players
+                .len(),
+            1
+        );
+        let eels = TeamId::from_partial("3-A");
+        assert!(
+            fetch_team_roster(&source, Some(&eels))
+                .await
+                .players
+                .is_empty()
+        );
+        assert!(fetch_team_roster(&source, None).await.players.is_empty());
+    }
+
+    #[test]
+    fn only_a_missing_or_refused_login_prompts_for_one() {
+        let missing: Box<dyn std::error::Error> = Box::new(AuthRequiredError);
+        assert!(needs_login(missing.as_ref()));
+        let refused: Box<dyn std::error::Error> = Box::new(SourceError::Portal(
+            PortalError::AuthExpired("token expired".to_string()),
+        ));
+        assert!(needs_login(refused.as_ref()));
+        let outage: Box<dyn std::error::Error> =
+            Box::new(SourceError::Portal(PortalError::ServerError {
+                status: 502,
+                body: String::new(),
+            }));
+        assert!(!needs_login(outage.as_ref()));
+    }
 
     #[test]
     fn cmas_official_needs_rosters() {
@@ -2741,40 +2702,4 @@ mod tests {
         assert!(!style_needs_tournament_logo(SheetStyle::Simple));
         assert!(!style_needs_sanctioning_logo(SheetStyle::Simple));
     }
-
-    #[test]
-    fn candidates_include_linux_names() {
-        let candidates = browser_candidates(None, None);
-        for name in [
-            "chromium",
-            "chromium-browser",
-            "google-chrome",
-            "google-chrome-stable",
-        ] {
-            assert!(
-                candidates.iter().any(|c| c == name),
-                "expected candidate list to include {name}, got {candidates:?}"
-            );
-        }
-    }
-
-    #[test]
-    fn explicit_browser_is_tried_first() {
-        let candidates = browser_candidates(Some("/opt/custom/chrome"), None);
-        assert_eq!(
-            candidates.first().map(String::as_str),
-            Some("/opt/custom/chrome"),
-            "explicit SCORESHEET_BROWSER path should be first"
-        );
-    }
-
-    #[test]
-    fn empty_explicit_is_ignored() {
-        let candidates = browser_candidates(Some(""), None);
-        assert_eq!(
-            candidates.first().map(String::as_str),
-            Some("chromium"),
-            "empty override should be skipped, Linux names come first"
-        );
-    }
 }
@@ -1,3 +1,4 @@
+use crate::game_snapshot::GamePeriod;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use toml::Table;
@@ -11,6 +12,14 @@ pub struct Game {
     pub timeouts_counted_per_half: bool,
     pub overtime_allowed: bool,
     pub sudden_death_allowed: bool,
+    /// Whether a game still tied after every other period is settled by a penalty shootout
+    pub penalty_shootout_allowed: bool,
+    /// Rounds in the best-of shootout series, after which it continues in sudden-death rounds
+    pub penalty_shootout_rounds: u16,
+    /// Whether every whistle stops the game clock during the last `stop_time_window` of each
+    /// play period. This is separate from the portal's `last_2_min_stop_time`, which is only
+    /// displayed.
+    pub stop_time_allowed: bool,
     pub single_half: bool,
     #[serde(with = "secs_only_duration")]
     pub half_play_duration: Duration,
@@ -21,6 +30,8 @@ pub struct Game {
     #[serde(with = "secs_only_duration")]
     pub penalty_shot_duration: Duration,
     #[serde(with = "secs_only_duration")]
+    pub stop_time_window: Duration,
+    #[serde(with = "secs_only_duration")]
     pub ot_half_play_duration: Duration,
     #[serde(with = "secs_only_duration")]
     pub ot_half_time_duration: Duration,
@@ -45,11 +56,15 @@ impl Default for Game {
             timeouts_counted_per_half: true,
             overtime_allowed: true,
             sudden_death_allowed: true,
+            penalty_shootout_allowed: false,
+            penalty_shootout_rounds: 3,
+            stop_time_allowed: false,
             single_half: false,
             half_play_duration: Duration::from_secs(900),
             half_time_duration: Duration::from_secs(180),
             team_timeout_duration: Duration::from_secs(60),
             penalty_shot_duration: Duration::from_secs(45),
+            stop_time_window: Duration::from_secs(120),
             ot_half_play_duration: Duration::from_secs(300),
             ot_half_time_duration: Duration::from_secs(180),
             pre_overtime_break: Duration::from_secs(180),
@@ -69,11 +84,15 @@ impl Game {
             mut timeouts_counted_per_half,
             mut overtime_allowed,
             mut sudden_death_allowed,
+            mut penalty_shootout_allowed,
+            mut penalty_shootout_rounds,
+            mut stop_time_allowed,
             mut single_half,
             mut half_play_duration,
             mut half_time_duration,
             mut team_timeout_duration,
             mut penalty_shot_duration,
+            mut stop_time_window,
             mut ot_half_play_duration,
             mut ot_half_time_duration,
             mut pre_overtime_break,
@@ -127,6 +146,23 @@ impl Game {
                 sudden_death_allowed = old_sudden_death_allowed;
             }
         }
+        if let Some(old_penalty_shootout_allowed) = old.get("penalty_shootout_allowed") {
+            if let Some(old_penalty_shootout_allowed) = old_penalty_shootout_allowed.as_bool() {
+                penalty_shootout_allowed = old_penalty_shootout_allowed;
+            }
+        }
+        if let Some(old_penalty_shootout_rounds) = old.get("penalty_shootout_rounds") {
+            if let Some(old_penalty_shootout_rounds) = old_penalty_shootout_rounds.as_integer() {
+                if let Ok(old_penalty_shootout_rounds) = old_penalty_shootout_rounds.try_into() {
+                    penalty_shootout_rounds = old_penalty_shootout_rounds;
+                }
+            }
+        }
+        if let Some(old_stop_time_allowed) = old.get("stop_time_allowed") {
+            if let Some(old_stop_time_allowed) = old_stop_time_allowed.as_bool() {
+                stop_time_allowed = old_stop_time_allowed;
+            }
+        }
         if let Some(old_single_half) = old.get("single_half") {
             if let Some(old_single_half) = old_single_half.as_bool() {
                 single_half = old_single_half;
@@ -136,6 +172,7 @@ impl Game {
         process_duration(old, "half_time_duration", &mut half_time_duration);
         process_duration(old, "team_timeout_duration", &mut team_timeout_duration);
         process_duration(old, "penalty_shot_duration", &mut penalty_shot_duration);
+        process_duration(old, "stop_time_window", &mut stop_time_window);
         process_duration(old, "ot_half_play_duration", &mut ot_half_play_duration);
         process_duration(old, "ot_half_time_duration", &mut ot_half_time_duration);
         process_duration(old, "pre_overtime_break", &mut pre_overtime_break);
@@ -167,11 +204,15 @@ impl Game {
             timeouts_counted_per_half,
             overtime_allowed,
             sudden_death_allowed,
+            penalty_shootout_allowed,
+            penalty_shootout_rounds,
+            stop_time_allowed,
             single_half,
             half_play_duration,
             half_time_duration,
             team_timeout_duration,
             penalty_shot_duration,
+            stop_time_window,
             ot_half_play_duration,
             ot_half_time_duration,
             pre_overtime_break,
@@ -211,6 +252,20 @@ impl Game {
         count * self.team_timeout_duration
     }
 
+    /// Whether a whistle at `clock_time` in `period` stops the game clock. Sudden death has no
+    /// end to count down to, so it never has a stop-time window.
+    pub fn in_stop_time_window(&self, period: GamePeriod, clock_time: Duration) -> bool {
+        self.stop_time_allowed
+            && matches!(
+                period,
+                GamePeriod::FirstHalf
+                    | GamePeriod::SecondHalf
+                    | GamePeriod::OvertimeFirstHalf
+                    | GamePeriod::OvertimeSecondHalf
+            )
+            && clock_time <= self.stop_time_window
+    }
+
     /// Slack between the Game Block and the math minimum (saturating at zero).
     pub fn game_block_buffer(&self) -> Duration {
         self.game_block.saturating_sub(self.game_block_minimum())
@@ -260,6 +315,16 @@ pub mod test {
             "sudden_death_allowed".to_string(),
             toml::Value::Boolean(false),
         );
+        old.insert(
+            "penalty_shootout_allowed".to_string(),
+            toml::Value::Boolean(true),
+        );
+        old.insert(
+            "penalty_shootout_rounds".to_string(),
+            toml::Value::Integer(5),
+        );
+        old.insert("stop_time_allowed".to_string(), toml::Value::Boolean(true));
+        old.insert("stop_time_window".to_string(), toml::Value::Integer(60));
         old.insert("half_play_duration".to_string(), toml::Value::Integer(123));
         old.insert("half_time_duration".to_string(), toml::Value::Integer(45));
         old.insert(
@@ -291,6 +356,10 @@ pub mod test {
         assert_eq!(gm.num_team_timeouts_allowed, 2);
         assert!(!gm.overtime_allowed);
         assert!(!gm.sudden_death_allowed);
+        assert!(gm.penalty_shootout_allowed);
+        assert_eq!(gm.penalty_shootout_rounds, 5);
+        assert!(gm.stop_time_allowed);
+        assert_eq!(gm.stop_time_window, Duration::from_secs(60));
         assert_eq!(gm.half_play_duration, Duration::from_secs(123));
         assert_eq!(gm.half_time_duration, Duration::from_secs(45));
         assert_eq!(gm.team_timeout_duration, Duration::from_secs(67));
@@ -377,4 +446,38 @@ pub mod test {
         assert_eq!(g.team_timeout_allotment(), Duration::from_secs(240));
         assert_eq!(g.game_block_buffer(), Duration::from_secs(80)); // 800-720
     }
+
+    #[test]
+    fn test_in_stop_time_window() {
+        let two_mins = Duration::from_secs(120);
+        let off = Game::default();
+        assert!(!off.in_stop_time_window(GamePeriod::SecondHalf, Duration::from_secs(30)));
+
+        let on = Game {
+            stop_time_allowed: true,
+            stop_time_window: two_mins,
+            ..Default::default()
+        };
+        for period in [
+            GamePeriod::FirstHalf,
+            GamePeriod::SecondHalf,
+            GamePeriod::OvertimeFirstHalf,
+            GamePeriod::OvertimeSecondHalf,
+        ] {
+            assert!(on.in_stop_time_window(period, two_mins));
+            assert!(on.in_stop_time_window(period, Duration::ZERO));
+            assert!(!on.in_stop_time_window(period, two_mins + Duration::from_millis(1)));
+        }
+        for period in [
+            GamePeriod::BetweenGames,
+            GamePeriod::HalfTime,
+            GamePeriod::PreOvertime,
+            GamePeriod::OvertimeHalfTime,
+            GamePeriod::PreSuddenDeath,
+            GamePeriod::SuddenDeath,
+            GamePeriod::PenaltyShootout,
+        ] {
+            assert!(!on.in_stop_time_window(period, Duration::from_secs(30)));
+        }
+    }
 }
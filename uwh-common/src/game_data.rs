@@ -0,0 +1,398 @@
+//! Where an event's data comes from, and where its results go.
+//!
+//! `GameDataSource` is the set of calls the refbox and schedule-processor make about an event:
+//! listing events, fetching the schedule, teams, rosters and referee names, and submitting
+//! results. The UWH Portal answers all of them through `UwhPortalClient`. A third-party site
+//! built to the custom-site contract answers them through the same client, wrapped in
+//! `SingleEventSite` because it stands for the one event its address names. A schedule folder
+//! on disk answers the reads and refuses the submissions; that source lives in the refbox.
+//!
+//! Each call returns a boxed `'static` future, built before it is awaited, so a caller holding a
+//! shared source never keeps it locked across the network round trip. A failed call says why in
+//! a `SourceError`, which keeps a portal's `PortalError` as it was.
+
+use crate::{
+    bundles::BlackWhiteBundle,
+    uwhportal::{
+        PortalError, RosterPlayer, UwhPortalClient,
+        schedule::{DateRange, Event, EventId, GameNumber, Schedule, TeamId, TeamList},
+    },
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+pub type SourceFuture<T> = Pin<Box<dyn Future<Output = Result<T, SourceError>> + Send>>;
+
+/// A source shared between the app and its background tasks
+pub type SharedSource = Arc<dyn GameDataSource + Send + Sync>;
+
+pub trait GameDataSource {
+    /// The events on offer. `past` asks for finished events instead of current and upcoming
+    /// ones; `schedule_published` leaves out events with no published schedule.
+    fn list_events(&self, past: bool, schedule_published: bool) -> SourceFuture<Vec<Event>>;
+
+    fn schedule(&self, event_id: &EventId) -> SourceFuture<Schedule>;
+
+    fn teams(&self, event_id: &EventId) -> SourceFuture<TeamList>;
+
+    fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>>;
+
+    /// Display names of the event's referees, by user id
+    fn referee_names(&self, event_id: &EventId) -> SourceFuture<HashMap<String, String>>;
+
+    fn submit_scores(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        scores: BlackWhiteBundle<u8>,
+        force: bool,
+    ) -> SourceFuture<()>;
+
+    fn submit_stats(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        stats_json: String,
+    ) -> SourceFuture<()>;
+}
+
+/// A call the source has no way to answer, such as a result sent to a file on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsupported {
+    pub source: &'static str,
+    pub call: &'static str,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A {} cannot {}", self.source, self.call)
+    }
+}
+
+impl Error for Unsupported {}
+
+/// Why a source could not answer a call
+#[derive(Debug)]
+pub enum SourceError {
+    /// The portal, or a site speaking its calls, failed the call
+    Portal(PortalError),
+    /// The source has no way to answer the call
+    Unsupported(Unsupported),
+    /// Anything else, such as a schedule folder asked about an event it does not hold
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Portal(e) => e.fmt(f),
+            Self::Unsupported(e) => e.fmt(f),
+            Self::Other(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for SourceError {}
+
+impl From<PortalError> for SourceError {
+    fn from(e: PortalError) -> Self {
+        Self::Portal(e)
+    }
+}
+
+impl From<Unsupported> for SourceError {
+    fn from(e: Unsupported) -> Self {
+        Self::Unsupported(e)
+    }
+}
+
+/// A future that is already finished, for sources that answer without any I/O
+pub fn ready<T: Send + 'static>(result: Result<T, SourceError>) -> SourceFuture<T> {
+    Box::pin(async move { result })
+}
+
+/// A portal call as a source's future
+fn portal<T>(
+    call: impl Future<Output = Result<T, PortalError>> + Send + 'static,
+) -> SourceFuture<T> {
+    Box::pin(async move { call.await.map_err(Into::into) })
+}
+
+impl GameDataSource for UwhPortalClient {
+    fn list_events(&self, past: bool, schedule_published: bool) -> SourceFuture<Vec<Event>> {
+        portal(self.get_event_list(past, schedule_published))
+    }
+
+    fn schedule(&self, event_id: &EventId) -> SourceFuture<Schedule> {
+        portal(self.get_event_schedule_privileged(event_id))
+    }
+
+    fn teams(&self, event_id: &EventId) -> SourceFuture<TeamList> {
+        portal(self.get_event_teams(event_id))
+    }
+
+    fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>> {
+        portal(self.get_team_roster(team_id))
+    }
+
+    fn referee_names(&self, event_id: &EventId) -> SourceFuture<HashMap<String, String>> {
+        portal(self.get_event_referee_name_map_from_referees(event_id))
+    }
+
+    fn submit_scores(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        scores: BlackWhiteBundle<u8>,
+        force: bool,
+    ) -> SourceFuture<()> {
+        portal(self.post_game_scores(event_id, game_number, scores, force))
+    }
+
+    fn submit_stats(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        stats_json: String,
+    ) -> SourceFuture<()> {
+        portal(self.post_game_stats(event_id, game_number, stats_json))
+    }
+}
+
+/// A source behind a lock is locked only while the request is built
+impl<S: GameDataSource + ?Sized> GameDataSource for Mutex<S> {
+    fn list_events(&self, past: bool, schedule_published: bool) -> SourceFuture<Vec<Event>> {
+        lock(self).list_events(past, schedule_published)
+    }
+
+    fn schedule(&self, event_id: &EventId) -> SourceFuture<Schedule> {
+        lock(self).schedule(event_id)
+    }
+
+    fn teams(&self, event_id: &EventId) -> SourceFuture<TeamList> {
+        lock(self).teams(event_id)
+    }
+
+    fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>> {
+        lock(self).roster(team_id)
+    }
+
+    fn referee_names(&self, event_id: &EventId) -> SourceFuture<HashMap<String, String>> {
+        lock(self).referee_names(event_id)
+    }
+
+    fn submit_scores(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        scores: BlackWhiteBundle<u8>,
+        force: bool,
+    ) -> SourceFuture<()> {
+        lock(self).submit_scores(event_id, game_number, scores, force)
+    }
+
+    fn submit_stats(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        stats_json: String,
+    ) -> SourceFuture<()> {
+        lock(self).submit_stats(event_id, game_number, stats_json)
+    }
+}
+
+/// The source behind a lock, for calls the trait does not cover. Like the trait's own calls,
+/// these should only build their request while it is held.
+pub fn lock<S: ?Sized>(mutex: &Mutex<S>) -> MutexGuard<'_, S> {
+    // Building a request never leaves a source half-changed, so a panic elsewhere while the
+    // lock was held is no reason to stop using it.
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A third-party site built to the custom-site contract.
+///
+/// The site speaks the portal's calls, so everything but the event list goes straight through
+/// to `site`. Its address names a single event, and the site is not asked for a list: the list
+/// is that one event, as `placeholder_event` describes it.
+pub struct SingleEventSite<S: ?Sized> {
+    event_id: EventId,
+    site: Arc<S>,
+}
+
+impl<S: GameDataSource + ?Sized> SingleEventSite<S> {
+    pub fn new(event_id: EventId, site: Arc<S>) -> Self {
+        Self { event_id, site }
+    }
+}
+
+/// A custom site's event as far as its address describes it: the event id stands in for the
+/// name, as no other detail of it is known
+pub fn placeholder_event(event_id: &EventId) -> Event {
+    let now = time::OffsetDateTime::now_utc();
+    Event {
+        id: event_id.clone(),
+        name: event_id.partial().to_string(),
+        slug: String::new(),
+        // A custom site serves no event-level date range, so both ends are set to now rather
+        // than invented.
+        date_range: DateRange {
+            start: now,
+            end: now,
+        },
+        teams: None,
+        schedule: None,
+        courts: None,
+    }
+}
+
+impl<S: GameDataSource + ?Sized> GameDataSource for SingleEventSite<S> {
+    fn list_events(&self, past: bool, _schedule_published: bool) -> SourceFuture<Vec<Event>> {
+        // The site's event is the one being run, never a finished one
+        let events = if past {
+            Vec::new()
+        } else {
+            vec![placeholder_event(&self.event_id)]
+        };
+        ready(Ok(events))
+    }
+
+    fn schedule(&self, event_id: &EventId) -> SourceFuture<Schedule> {
+        self.site.schedule(event_id)
+    }
+
+    fn teams(&self, event_id: &EventId) -> SourceFuture<TeamList> {
+        self.site.teams(event_id)
+    }
+
+    fn roster(&self, team_id: &TeamId) -> SourceFuture<Vec<RosterPlayer>> {
+        self.site.roster(team_id)
+    }
+
+    fn referee_names(&self, event_id: &EventId) -> SourceFuture<HashMap<String, String>> {
+        self.site.referee_names(event_id)
+    }
+
+    fn submit_scores(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        scores: BlackWhiteBundle<u8>,
+        force: bool,
+    ) -> SourceFuture<()> {
+        self.site
+            .submit_scores(event_id, game_number, scores, force)
+    }
+
+    fn submit_stats(
+        &self,
+        event_id: &EventId,
+        game_number: &GameNumber,
+        stats_json: String,
+    ) -> SourceFuture<()> {
+        self.site.submit_stats(event_id, game_number, stats_json)
+    }
+}
+
+/// The schedule with each assigned referee's display name filled in from `names`
+pub fn with_referee_names(mut schedule: Schedule, names: &HashMap<String, String>) -> Schedule {
+    for game in schedule.games.values_mut() {
+        if let Some(assignments) = &mut game.referee_assignments {
+            for assignment in assignments.iter_mut() {
+                if let Some(name) = assignment.user_id.as_ref().and_then(|uid| names.get(uid)) {
+                    assignment.display_name = Some(name.clone());
+                }
+            }
+        }
+    }
+    schedule
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+    use fake_portal::{ACCESS_KEY, Endpoint, FakePortal};
+
+    fn event() -> EventId {
+        EventId::from_full("events/1234-A").unwrap()
+    }
+
+    fn shared_client(portal: &FakePortal) -> Arc<Mutex<UwhPortalClient>> {
+        let client = UwhPortalClient::new(
+            &portal.url(),
+            Some(ACCESS_KEY),
+            false,
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        Arc::new(Mutex::new(client))
+    }
+
+    #[tokio::test]
+    async fn a_shared_portal_client_answers_as_a_source() {
+        let portal = FakePortal::start().unwrap();
+        let source: SharedSource = shared_client(&portal);
+
+        let events = source.list_events(false, true).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, event());
+
+        let teams = source.teams(&event()).await.unwrap();
+        assert_eq!(teams.len(), 2);
+        let team = TeamId::from_full("teams/5678-B").unwrap();
+        assert_eq!(source.roster(&team).await.unwrap().len(), 6);
+
+        let schedule = source.schedule(&event()).await.unwrap();
+        let names = source.referee_names(&event()).await.unwrap();
+        let schedule = with_referee_names(schedule, &names);
+        let game = &schedule.games[&"1".to_string()];
+        let assignments = game.referee_assignments.as_ref().unwrap();
+        assert_eq!(assignments[0].display_name.as_deref(), Some("Casey"));
+
+        let scores = BlackWhiteBundle { black: 3, white: 1 };
+        source
+            .submit_scores(&event(), &"1".to_string(), scores, false)
+            .await
+            .unwrap();
+        source
+            .submit_stats(&event(), &"1".to_string(), "[]".to_string())
+            .await
+            .unwrap();
+        assert_eq!(portal.posted_scores().len(), 1);
+        assert_eq!(portal.posted_stats().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_single_event_site_lists_its_own_event_without_asking() {
+        let portal = FakePortal::start().unwrap();
+        let site = SingleEventSite::new(event(), shared_client(&portal));
+
+        let events = site.list_events(false, true).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, event());
+        assert!(site.list_events(true, true).await.unwrap().is_empty());
+        assert_eq!(portal.count(Endpoint::EventList), 0);
+
+        // Everything else goes through to the site
+        assert_eq!(site.teams(&event()).await.unwrap().len(), 2);
+        assert_eq!(portal.count(Endpoint::EventTeams), 1);
+    }
+
+    #[tokio::test]
+    async fn an_unsupported_call_reports_what_was_refused() {
+        let refused: SourceFuture<()> = ready(Err(Unsupported {
+            source: "test source",
+            call: "accept results",
+        }
+        .into()));
+        let e = refused.await.unwrap_err();
+        assert_eq!(e.to_string(), "A test source cannot accept results");
+        assert!(matches!(e, SourceError::Unsupported(_)));
+    }
+}
@@ -15,7 +15,6 @@ use core::{cmp::min, time::Duration};
 use defmt::Format;
 use derivative::Derivative;
 use displaydoc::Display;
-use enum_iterator::Sequence;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use time::Duration as SignedDuration;
@@ -51,9 +50,19 @@ pub struct GameSnapshot {
     pub game_number: GameNumber,
     pub next_game_number: GameNumber,
     pub event_id: Option<EventId>,
+    /// Names of the teams playing this game when the refbox knows them, for displays that can't
+    /// reach the portal
+    #[serde(default)]
+    pub team_names: BlackWhiteBundle<Option<String>>,
     pub recent_goal: Option<(Color, u8)>,
+    /// Every goal recorded this game, in the order each team scored them
+    #[serde(default)]
+    pub goals: BlackWhiteBundle<Vec<GoalSnapshot>>,
+    /// The team and scorer of a goal that was just disallowed
+    pub recent_disallowed_goal: Option<(Color, u8)>,
     pub next_period_len_secs: Option<u32>,
     pub conf_pause_time: Option<u32>,
+    pub shootout: Option<ShootoutSnapshot>,
 }
 
 #[cfg(feature = "std")]
@@ -75,15 +84,25 @@ impl From<GameSnapshot> for GameSnapshotNoHeap {
             })
             .collect();
 
-        Self {
-            current_period: snapshot.current_period,
-            secs_in_period: min(
+        // The shootout has no clock, so the panels are sent its running tally in place of the
+        // time (see `GameSnapshotNoHeap::shootout_scores`)
+        let secs_in_period = match (snapshot.current_period, &snapshot.shootout) {
+            (GamePeriod::PenaltyShootout, Some(shootout)) => {
+                let tally = shootout.tally();
+                u16::from_be_bytes([tally.black, tally.white])
+            }
+            _ => min(
                 snapshot
                     .secs_in_period
                     .try_into()
                     .unwrap_or(MAX_STRINGABLE_SECS),
                 MAX_STRINGABLE_SECS,
             ),
+        };
+
+        Self {
+            current_period: snapshot.current_period,
+            secs_in_period,
             timeout: snapshot.timeout,
             scores: snapshot.scores,
             penalties,
@@ -111,6 +130,40 @@ impl GameSnapshot {
     }
 }
 
+/// The attempts taken so far in a penalty shootout, in the order each team took them
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Default, Clone, Serialize, Deserialize)]
+pub struct ShootoutSnapshot {
+    pub attempts: BlackWhiteBundle<Vec<ShootoutAttemptSnapshot>>,
+    /// The number of rounds in the best-of series, before sudden-death rounds begin
+    pub rounds: u16,
+    /// The team due to shoot next. `None` before the first attempt, when either team may start,
+    /// and once the shootout has been decided.
+    pub next_shooter: Option<Color>,
+    pub winner: Option<Color>,
+}
+
+#[cfg(feature = "std")]
+impl ShootoutSnapshot {
+    /// The number of successful attempts by each team
+    pub fn tally(&self) -> BlackWhiteBundle<u8> {
+        self.attempts
+            .iter()
+            .map(|(color, attempts)| {
+                let scored = attempts.iter().filter(|a| a.scored).count();
+                (color, scored.try_into().unwrap_or(u8::MAX))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct ShootoutAttemptSnapshot {
+    pub player_number: u8,
+    pub scored: bool,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct PenaltySnapshot {
     pub player_number: u8,
@@ -124,6 +177,28 @@ pub struct InfractionSnapshot {
     pub infraction: Infraction,
 }
 
+/// A goal, and why it was taken back off the score if it was
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct GoalSnapshot {
+    pub player_number: u8,
+    pub period: GamePeriod,
+    pub secs_in_period: u32,
+    pub disallowed: Option<GoalDisallowReason>,
+}
+
+/// Why a goal that had been awarded was disallowed
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum GoalDisallowReason {
+    /// The scoring team fouled in the play that led to the goal
+    Foul,
+    /// An infraction like an illegal advancement in the scoring play
+    Infraction,
+    /// The goal was given to the wrong team or player
+    ScorerCorrection,
+}
+
 #[derive(Derivative, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[derivative(Debug, Default, Clone, Copy)]
 pub enum GamePeriod {
@@ -138,27 +213,14 @@ pub enum GamePeriod {
     OvertimeSecondHalf,
     PreSuddenDeath,
     SuddenDeath,
+    PenaltyShootout,
 }
 
 impl GamePeriod {
-    #[cfg(feature = "std")]
-    pub fn penalties_run(self, config: &Game) -> bool {
-        match self {
-            Self::BetweenGames
-            | Self::HalfTime
-            | Self::PreOvertime
-            | Self::OvertimeHalfTime
-            | Self::PreSuddenDeath => false,
-            Self::FirstHalf | Self::SecondHalf => true,
-            Self::OvertimeFirstHalf | Self::OvertimeSecondHalf => config.overtime_allowed,
-            Self::SuddenDeath => config.sudden_death_allowed,
-        }
-    }
-
     #[cfg(feature = "std")]
     pub fn duration(self, config: &Game) -> Option<Duration> {
         match self {
-            Self::BetweenGames | Self::SuddenDeath => None,
+            Self::BetweenGames | Self::SuddenDeath | Self::PenaltyShootout => None,
             Self::FirstHalf | Self::SecondHalf => Some(config.half_play_duration),
             Self::HalfTime => Some(config.half_time_duration),
             Self::PreOvertime => Some(config.pre_overtime_break),
@@ -184,7 +246,7 @@ impl GamePeriod {
             | p @ Self::PreSuddenDeath => p
                 .duration(config)
                 .and_then(|d| d.try_into().ok().map(|sd: SignedDuration| sd - time)),
-            Self::SuddenDeath => time.try_into().ok(),
+            Self::SuddenDeath | Self::PenaltyShootout => time.try_into().ok(),
         }
     }
 
@@ -200,7 +262,7 @@ impl GamePeriod {
             | Self::OvertimeHalfTime
             | Self::OvertimeSecondHalf
             | Self::PreSuddenDeath => start - end,
-            Self::SuddenDeath => end - start,
+            Self::SuddenDeath | Self::PenaltyShootout => end - start,
         }
     }
 
@@ -215,7 +277,8 @@ impl GamePeriod {
             Self::OvertimeHalfTime => Some(Self::OvertimeSecondHalf),
             Self::OvertimeSecondHalf => Some(Self::PreSuddenDeath),
             Self::PreSuddenDeath => Some(Self::SuddenDeath),
-            Self::SuddenDeath => None,
+            Self::SuddenDeath => Some(Self::PenaltyShootout),
+            Self::PenaltyShootout => None,
         }
     }
 
@@ -263,7 +326,7 @@ impl GamePeriod {
                     None
                 }
             }
-            Self::SuddenDeath => None,
+            Self::SuddenDeath | Self::PenaltyShootout => None,
         }
     }
 }
@@ -281,6 +344,7 @@ impl core::fmt::Display for GamePeriod {
             GamePeriod::OvertimeSecondHalf => write!(f, "Overtime Second Half"),
             GamePeriod::PreSuddenDeath => write!(f, "Pre Sudden Death"),
             GamePeriod::SuddenDeath => write!(f, "Sudden Death"),
+            GamePeriod::PenaltyShootout => write!(f, "Penalty Shootout"),
         }
     }
 }
@@ -331,81 +395,185 @@ impl PartialOrd for PenaltyTime {
     }
 }
 
-#[derive(Derivative, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Sequence)]
-#[derivative(Default)]
-pub enum Infraction {
-    #[derivative(Default)]
-    Unknown,
-    StickInfringement,
-    IllegalAdvancement,
-    IllegalSubstitution,
-    IllegallyStoppingThePuck,
-    OutOfBounds,
-    GrabbingTheBarrier,
-    Obstruction,
-    DelayOfGame,
-    UnsportsmanlikeConduct,
-    FreeArm,
-    FalseStart,
+/// The longest id an infraction can have. Enough for every built-in id while keeping the
+/// penalties in `GameSnapshotNoHeap` small.
+pub const MAX_INFRACTION_ID_LEN: usize = 24;
+
+/// An infraction, identified by its id in the active rule profile. The id is what gets
+/// serialized, so the infractions of the built-in profile keep the names they had when they were
+/// a fixed list.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Infraction {
+    id: [u8; MAX_INFRACTION_ID_LEN],
+    len: u8,
 }
 
 impl Infraction {
-    pub fn short_name(self) -> &'static str {
-        match self {
-            Self::Unknown => "Unknown",
-            Self::StickInfringement => "Stick Foul",
-            Self::IllegalAdvancement => "Illegal Advance",
-            Self::IllegalSubstitution => "Sub Foul",
-            Self::IllegallyStoppingThePuck => "Illegal Stoppage",
-            Self::OutOfBounds => "Out Of Bounds",
-            Self::GrabbingTheBarrier => "Grabbing The Wall",
-            Self::Obstruction => "Obstruction",
-            Self::DelayOfGame => "Delay Of Game",
-            Self::UnsportsmanlikeConduct => "Unsportsmanlike",
-            Self::FreeArm => "Free Arm",
-            Self::FalseStart => "False Start",
+    pub const UNKNOWN: Self = Self::from_static("Unknown");
+    pub const STICK_INFRINGEMENT: Self = Self::from_static("StickInfringement");
+    pub const ILLEGAL_ADVANCEMENT: Self = Self::from_static("IllegalAdvancement");
+    pub const ILLEGAL_SUBSTITUTION: Self = Self::from_static("IllegalSubstitution");
+    pub const ILLEGALLY_STOPPING_THE_PUCK: Self = Self::from_static("IllegallyStoppingThePuck");
+    pub const OUT_OF_BOUNDS: Self = Self::from_static("OutOfBounds");
+    pub const GRABBING_THE_BARRIER: Self = Self::from_static("GrabbingTheBarrier");
+    pub const OBSTRUCTION: Self = Self::from_static("Obstruction");
+    pub const DELAY_OF_GAME: Self = Self::from_static("DelayOfGame");
+    pub const UNSPORTSMANLIKE_CONDUCT: Self = Self::from_static("UnsportsmanlikeConduct");
+    pub const FREE_ARM: Self = Self::from_static("FreeArm");
+    pub const FALSE_START: Self = Self::from_static("FalseStart");
+
+    /// The infractions of the built-in rule profile, in the order the refbox offers them. Doesn't
+    /// include `UNKNOWN`, which every profile has.
+    pub const BUILT_IN: [Self; 11] = [
+        Self::STICK_INFRINGEMENT,
+        Self::ILLEGAL_ADVANCEMENT,
+        Self::ILLEGAL_SUBSTITUTION,
+        Self::ILLEGALLY_STOPPING_THE_PUCK,
+        Self::OUT_OF_BOUNDS,
+        Self::GRABBING_THE_BARRIER,
+        Self::OBSTRUCTION,
+        Self::DELAY_OF_GAME,
+        Self::UNSPORTSMANLIKE_CONDUCT,
+        Self::FREE_ARM,
+        Self::FALSE_START,
+    ];
+
+    const fn from_static(id: &str) -> Self {
+        let bytes = id.as_bytes();
+        assert!(!bytes.is_empty() && bytes.len() <= MAX_INFRACTION_ID_LEN);
+        let mut buf = [0; MAX_INFRACTION_ID_LEN];
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self {
+            id: buf,
+            len: bytes.len() as u8,
         }
     }
 
-    pub fn get_image(&self) -> &'static [u8] {
-        match self {
-            Self::Unknown => include_bytes!(
+    /// Returns `None` if `id` is empty or longer than `MAX_INFRACTION_ID_LEN` bytes
+    pub fn new(id: &str) -> Option<Self> {
+        if id.is_empty() || id.len() > MAX_INFRACTION_ID_LEN {
+            return None;
+        }
+        let mut buf = [0; MAX_INFRACTION_ID_LEN];
+        buf[..id.len()].copy_from_slice(id.as_bytes());
+        Some(Self {
+            id: buf,
+            len: id.len() as u8,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        // The bytes always come from a whole `&str`, so they are valid UTF-8
+        core::str::from_utf8(&self.id[..self.len as usize]).unwrap_or_default()
+    }
+
+    pub fn builtin_short_name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::UNKNOWN => "Unknown",
+            Self::STICK_INFRINGEMENT => "Stick Foul",
+            Self::ILLEGAL_ADVANCEMENT => "Illegal Advance",
+            Self::ILLEGAL_SUBSTITUTION => "Sub Foul",
+            Self::ILLEGALLY_STOPPING_THE_PUCK => "Illegal Stoppage",
+            Self::OUT_OF_BOUNDS => "Out Of Bounds",
+            Self::GRABBING_THE_BARRIER => "Grabbing The Wall",
+            Self::OBSTRUCTION => "Obstruction",
+            Self::DELAY_OF_GAME => "Delay Of Game",
+            Self::UNSPORTSMANLIKE_CONDUCT => "Unsportsmanlike",
+            Self::FREE_ARM => "Free Arm",
+            Self::FALSE_START => "False Start",
+            _ => return None,
+        })
+    }
+
+    pub fn builtin_image(self) -> Option<&'static [u8]> {
+        Some(match self {
+            Self::UNKNOWN => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Black_question_mark.png"
             ),
-            Self::StickInfringement => include_bytes!(
+            Self::STICK_INFRINGEMENT => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Stick_Infringement_smaller.png"
             ),
-            Self::IllegalAdvancement => include_bytes!(
+            Self::ILLEGAL_ADVANCEMENT => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Illegal_Advancement_smaller.png"
             ),
-            Self::IllegalSubstitution => include_bytes!(
+            Self::ILLEGAL_SUBSTITUTION => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Illegal_Substitution_smaller.png"
             ),
-            Self::IllegallyStoppingThePuck => include_bytes!(
+            Self::ILLEGALLY_STOPPING_THE_PUCK => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Illegal_Knockdown_smaller.png"
             ),
-            Self::OutOfBounds => include_bytes!(
+            Self::OUT_OF_BOUNDS => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Out_of_Bounds_smaller.png"
             ),
-            Self::GrabbingTheBarrier => include_bytes!(
+            Self::GRABBING_THE_BARRIER => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Grabbing_Barrier_smaller.png"
             ),
-            Self::Obstruction => include_bytes!(
+            Self::OBSTRUCTION => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Obstruction_smaller.png"
             ),
-            Self::DelayOfGame => include_bytes!(
+            Self::DELAY_OF_GAME => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/Delay_of_Game_smaller.png"
             ),
-            Self::UnsportsmanlikeConduct => {
+            Self::UNSPORTSMANLIKE_CONDUCT => {
                 include_bytes!("../../refbox/resources/Atlantis_infractions/Unsporting_smaller.png")
             }
-            Self::FreeArm => {
+            Self::FREE_ARM => {
                 include_bytes!("../../refbox/resources/Atlantis_infractions/Free_Arm_smaller.png")
             }
-            Self::FalseStart => include_bytes!(
+            Self::FALSE_START => include_bytes!(
                 "../../refbox/resources/Atlantis_infractions/False_Start_smaller.png"
             ),
+            _ => return None,
+        })
+    }
+}
+
+impl Default for Infraction {
+    fn default() -> Self {
+        Self::UNKNOWN
+    }
+}
+
+impl core::fmt::Debug for Infraction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Infraction").field(&self.id()).finish()
+    }
+}
+
+impl core::fmt::Display for Infraction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl Serialize for Infraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Infraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdVisitor;
+
+        impl serde::de::Visitor<'_> for IdVisitor {
+            type Value = Infraction;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "an infraction id of 1 to {MAX_INFRACTION_ID_LEN} bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Infraction::new(v)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
         }
+
+        deserializer.deserialize_str(IdVisitor)
     }
 }
 
@@ -466,7 +634,7 @@ impl PenaltySnapshot {
                 0x01ff => PenaltyTime::TotalDismissal,
                 time => PenaltyTime::Seconds(time),
             },
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         })
     }
 }
@@ -524,6 +692,7 @@ impl GamePeriod {
             Self::OvertimeSecondHalf => 7,
             Self::PreSuddenDeath => 8,
             Self::SuddenDeath => 9,
+            Self::PenaltyShootout => 10,
         }
     }
 
@@ -539,6 +708,7 @@ impl GamePeriod {
             7 => Ok(Self::OvertimeSecondHalf),
             8 => Ok(Self::PreSuddenDeath),
             9 => Ok(Self::SuddenDeath),
+            10 => Ok(Self::PenaltyShootout),
             _ => Err(DecodingError::InvalidGamePeriod(val)),
         }
     }
@@ -547,6 +717,16 @@ impl GamePeriod {
 impl GameSnapshotNoHeap {
     pub const ENCODED_LEN: usize = 19;
 
+    /// The shootout tally, which is carried in `secs_in_period` during `PenaltyShootout`
+    pub fn shootout_scores(&self) -> Option<BlackWhiteBundle<u8>> {
+        if self.current_period == GamePeriod::PenaltyShootout {
+            let [black, white] = self.secs_in_period.to_be_bytes();
+            Some(BlackWhiteBundle { black, white })
+        } else {
+            None
+        }
+    }
+
     pub fn encode(&self) -> Result<[u8; Self::ENCODED_LEN], EncodingError> {
         let mut val = [0u8; Self::ENCODED_LEN];
         val[0] = self.current_period.encode();
@@ -634,58 +814,6 @@ mod test {
         assert_eq!(PenaltyTime::TotalDismissal, PenaltyTime::TotalDismissal);
     }
 
-    #[test]
-    fn test_period_penalties_run() {
-        let all_periods_config = Game {
-            overtime_allowed: true,
-            sudden_death_allowed: true,
-            ..Default::default()
-        };
-        let sd_only_config = Game {
-            overtime_allowed: false,
-            sudden_death_allowed: true,
-            ..Default::default()
-        };
-        let no_sd_no_ot_config = Game {
-            overtime_allowed: false,
-            sudden_death_allowed: false,
-            ..Default::default()
-        };
-
-        assert!(!GamePeriod::BetweenGames.penalties_run(&all_periods_config));
-        assert!(GamePeriod::FirstHalf.penalties_run(&all_periods_config));
-        assert!(!GamePeriod::HalfTime.penalties_run(&all_periods_config));
-        assert!(GamePeriod::SecondHalf.penalties_run(&all_periods_config));
-        assert!(!GamePeriod::PreOvertime.penalties_run(&all_periods_config));
-        assert!(GamePeriod::OvertimeFirstHalf.penalties_run(&all_periods_config));
-        assert!(!GamePeriod::OvertimeHalfTime.penalties_run(&all_periods_config));
-        assert!(GamePeriod::OvertimeSecondHalf.penalties_run(&all_periods_config));
-        assert!(!GamePeriod::PreSuddenDeath.penalties_run(&all_periods_config));
-        assert!(GamePeriod::SuddenDeath.penalties_run(&all_periods_config));
-
-        assert!(!GamePeriod::BetweenGames.penalties_run(&sd_only_config));
-        assert!(GamePeriod::FirstHalf.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::HalfTime.penalties_run(&sd_only_config));
-        assert!(GamePeriod::SecondHalf.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::PreOvertime.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::OvertimeFirstHalf.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::OvertimeHalfTime.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::OvertimeSecondHalf.penalties_run(&sd_only_config));
-        assert!(!GamePeriod::PreSuddenDeath.penalties_run(&sd_only_config));
-        assert!(GamePeriod::SuddenDeath.penalties_run(&sd_only_config));
-
-        assert!(!GamePeriod::BetweenGames.penalties_run(&no_sd_no_ot_config));
-        assert!(GamePeriod::FirstHalf.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::HalfTime.penalties_run(&no_sd_no_ot_config));
-        assert!(GamePeriod::SecondHalf.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::PreOvertime.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::OvertimeFirstHalf.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::OvertimeHalfTime.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::OvertimeSecondHalf.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::PreSuddenDeath.penalties_run(&no_sd_no_ot_config));
-        assert!(!GamePeriod::SuddenDeath.penalties_run(&no_sd_no_ot_config));
-    }
-
     #[test]
     fn test_period_duration() {
         let config = Game {
@@ -732,6 +860,7 @@ mod test {
             Some(Duration::from_secs(15))
         );
         assert_eq!(GamePeriod::SuddenDeath.duration(&config), None);
+        assert_eq!(GamePeriod::PenaltyShootout.duration(&config), None);
     }
 
     #[test]
@@ -991,7 +1120,11 @@ mod test {
             GamePeriod::PreSuddenDeath.next_period(),
             Some(GamePeriod::SuddenDeath)
         );
-        assert_eq!(GamePeriod::SuddenDeath.next_period(), None);
+        assert_eq!(
+            GamePeriod::SuddenDeath.next_period(),
+            Some(GamePeriod::PenaltyShootout)
+        );
+        assert_eq!(GamePeriod::PenaltyShootout.next_period(), None);
     }
 
     #[test]
@@ -1027,12 +1160,12 @@ mod test {
         state.penalties.black.push(PenaltySnapshot {
             player_number: 1,
             time: PenaltyTime::Seconds(48),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
         state.penalties.white.push(PenaltySnapshot {
             player_number: 12,
             time: PenaltyTime::Seconds(96),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
 
         test_state(&mut state)?;
@@ -1045,12 +1178,12 @@ mod test {
         state.penalties.black.push(PenaltySnapshot {
             player_number: 4,
             time: PenaltyTime::Seconds(245),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
         state.penalties.white.push(PenaltySnapshot {
             player_number: 14,
             time: PenaltyTime::Seconds(300),
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
 
         test_state(&mut state)?;
@@ -1063,12 +1196,12 @@ mod test {
         state.penalties.black.push(PenaltySnapshot {
             player_number: 7,
             time: PenaltyTime::TotalDismissal,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
         state.penalties.white.push(PenaltySnapshot {
             player_number: 15,
             time: PenaltyTime::TotalDismissal,
-            infraction: Infraction::Unknown,
+            infraction: Infraction::UNKNOWN,
         });
 
         test_state(&mut state)?;
@@ -1101,6 +1234,105 @@ mod test {
 
         test_state(&mut state)?;
 
+        state.current_period = GamePeriod::PenaltyShootout;
+        state.secs_in_period = u16::from_be_bytes([4, 3]);
+        state.timeout = None;
+
+        test_state(&mut state)?;
+
         Ok(())
     }
+
+    #[test]
+    fn test_shootout_tally_replaces_time_on_panels() {
+        let attempt = |player_number, scored| ShootoutAttemptSnapshot {
+            player_number,
+            scored,
+        };
+        let mut snapshot = GameSnapshot {
+            current_period: GamePeriod::PenaltyShootout,
+            secs_in_period: 0,
+            scores: BlackWhiteBundle { black: 2, white: 2 },
+            shootout: Some(ShootoutSnapshot {
+                attempts: BlackWhiteBundle {
+                    black: vec![attempt(4, true), attempt(7, false), attempt(9, true)],
+                    white: vec![attempt(1, false), attempt(3, true)],
+                },
+                rounds: 3,
+                next_shooter: Some(Color::White),
+                winner: None,
+            }),
+            ..Default::default()
+        };
+
+        let no_heap = GameSnapshotNoHeap::from(snapshot.clone());
+        assert_eq!(no_heap.scores, BlackWhiteBundle { black: 2, white: 2 });
+        assert_eq!(
+            no_heap.shootout_scores(),
+            Some(BlackWhiteBundle { black: 2, white: 1 })
+        );
+
+        let decoded = GameSnapshotNoHeap::decode(&no_heap.encode().unwrap()).unwrap();
+        assert_eq!(
+            decoded.shootout_scores(),
+            Some(BlackWhiteBundle { black: 2, white: 1 })
+        );
+
+        snapshot.current_period = GamePeriod::BetweenGames;
+        snapshot.secs_in_period = 100;
+        let no_heap = GameSnapshotNoHeap::from(snapshot);
+        assert_eq!(no_heap.secs_in_period, 100);
+        assert_eq!(no_heap.shootout_scores(), None);
+    }
+
+    #[test]
+    fn test_infraction_ids() {
+        assert_eq!(Infraction::default(), Infraction::UNKNOWN);
+        assert_eq!(Infraction::new("FreeArm"), Some(Infraction::FREE_ARM));
+        assert_eq!(Infraction::FREE_ARM.id(), "FreeArm");
+        assert_eq!(Infraction::new(""), None);
+        assert!(Infraction::new(&"x".repeat(MAX_INFRACTION_ID_LEN)).is_some());
+        assert_eq!(
+            Infraction::new(&"x".repeat(MAX_INFRACTION_ID_LEN + 1)),
+            None
+        );
+
+        let custom = Infraction::new("SnorkelGrab").unwrap();
+        assert_eq!(custom.builtin_short_name(), None);
+        assert_eq!(custom.builtin_image(), None);
+        for inf in Infraction::BUILT_IN {
+            assert!(inf.builtin_short_name().is_some());
+            assert!(inf.builtin_image().is_some());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_infraction_serializes_as_its_id() {
+        // The same strings as when `Infraction` was an enum
+        assert_eq!(
+            serde_json::to_string(&Infraction::ILLEGALLY_STOPPING_THE_PUCK).unwrap(),
+            "\"IllegallyStoppingThePuck\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Infraction>("\"DelayOfGame\"").unwrap(),
+            Infraction::DELAY_OF_GAME
+        );
+        assert!(serde_json::from_str::<Infraction>("\"\"").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_snapshot_from_an_older_refbox() {
+        // A snapshot as sent by a refbox from before goals, team names, disallowed goals and
+        // shootouts were added
+        let json = r#"{"current_period":"FirstHalf","secs_in_period":600,"timeout":null,"scores":{"black":2,"white":1},"penalties":{"black":[],"white":[]},"warnings":{"black":[],"white":[]},"fouls":{"black":[],"equal":[],"white":[]},"is_old_game":false,"game_number":"12","next_game_number":"13","event_id":null,"recent_goal":["Black",4],"next_period_len_secs":null,"conf_pause_time":null}"#;
+        let snapshot: GameSnapshot = serde_json::from_str(json).unwrap();
+        assert_eq!(snapshot.scores, BlackWhiteBundle { black: 2, white: 1 });
+        assert_eq!(snapshot.recent_goal, Some((Color::Black, 4)));
+        assert_eq!(snapshot.goals, BlackWhiteBundle::default());
+        assert_eq!(snapshot.team_names, BlackWhiteBundle::default());
+        assert_eq!(snapshot.recent_disallowed_goal, None);
+        assert_eq!(snapshot.shootout, None);
+    }
 }
@@ -0,0 +1,202 @@
+//! Printing an HTML page to PDF with a headless Chrome or Chromium
+//!
+//! Used for the scoresheets: schedule-processor's blank ones and the refbox's completed ones.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The environment variable that names a browser to try before any other
+pub const BROWSER_ENV_VAR: &str = "SCORESHEET_BROWSER";
+
+/// Print the HTML file at `html_path` to a PDF at `pdf_path`, trying each browser from
+/// [`browser_candidates`] in turn. Returns whether one of them succeeded.
+pub fn print_to_pdf(html_path: &Path, pdf_path: &Path) -> bool {
+    // Convert to file:/// URL for Chrome without introducing Windows \\?\ prefix
+    let html_abs = if html_path.is_absolute() {
+        html_path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(html_path)
+    };
+    let html_url = format!("file:///{}", html_abs.to_string_lossy().replace('\\', "/"));
+
+    // Browsers to try for HTML->PDF, across Linux/WSL, macOS and Windows.
+    // Override with the SCORESHEET_BROWSER env var (full path to a browser).
+    let candidates = browser_candidates(
+        std::env::var(BROWSER_ENV_VAR).ok().as_deref(),
+        std::env::var("LOCALAPPDATA").ok().as_deref(),
+    );
+    let try_arg_sets: [&[&str]; 4] = [
+        &[
+            "--headless=new",
+            "--disable-gpu",
+            "--allow-file-access-from-files",
+            "--virtual-time-budget=8000",
+            "--no-sandbox",
+            "--disable-extensions",
+            "--disable-dev-shm-usage",
+        ],
+        &[
+            "--headless",
+            "--disable-gpu",
+            "--allow-file-access-from-files",
+            "--virtual-time-budget=8000",
+            "--no-sandbox",
+            "--disable-extensions",
+            "--disable-dev-shm-usage",
+        ],
+        &[
+            "--headless=new",
+            "--disable-gpu",
+            "--allow-file-access-from-files",
+            "--run-all-compositor-stages-before-draw",
+            "--no-sandbox",
+        ],
+        &[
+            "--headless",
+            "--disable-gpu",
+            "--allow-file-access-from-files",
+            "--run-all-compositor-stages-before-draw",
+            "--no-sandbox",
+        ],
+    ];
+    'outer: for c in &candidates {
+        // If 'c' looks like a path, ensure it exists before trying
+        let is_path_like = c.contains('\\') || c.contains('/') || c.contains(':');
+        if is_path_like && !Path::new(c).exists() {
+            log::debug!("Browser not found at: {}", c);
+            continue;
+        }
+        for args in &try_arg_sets {
+            let mut cmd = Command::new(c);
+            let print_arg = format!("--print-to-pdf={}", pdf_path.display());
+            cmd.args(*args)
+                .arg("--no-first-run")
+                .arg("--no-default-browser-check")
+                .arg(print_arg)
+                .arg(&html_url);
+            log::debug!(
+                "Attempting to print via '{}' url={} pdf={}",
+                c,
+                &html_url,
+                pdf_path.display()
+            );
+            match cmd.output() {
+                Ok(out) if out.status.success() => {
+                    log::info!("Wrote PDF to {}", pdf_path.display());
+                    return true;
+                }
+                Ok(out) => {
+                    let so = String::from_utf8_lossy(&out.stdout);
+                    let se = String::from_utf8_lossy(&out.stderr);
+                    log::debug!(
+                        "Browser '{}' run failed (status {}). stdout: {} stderr: {}",
+                        c,
+                        out.status,
+                        so.trim(),
+                        se.trim()
+                    );
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    // Browser isn't installed; skip its remaining arg sets.
+                    log::debug!("Browser '{}' is not installed", c);
+                    continue 'outer;
+                }
+                Err(e) => {
+                    log::debug!("Failed to run browser '{}': {}", c, e);
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Build the ordered list of browser executables to try when converting
+/// HTML into a PDF via headless Chrome/Chromium.
+///
+/// Order: an explicit override (`SCORESHEET_BROWSER`) first, then common
+/// Linux/macOS names resolved via PATH, then the standard Windows Chrome
+/// install locations, then a bare `chrome` on PATH.
+///
+/// `explicit` is the value of the `SCORESHEET_BROWSER` env var (a full path to
+/// a browser executable); `localappdata` is the Windows `LOCALAPPDATA` env var.
+pub fn browser_candidates(explicit: Option<&str>, localappdata: Option<&str>) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+
+    // 1. Explicit override wins, when set and non-empty.
+    if let Some(path) = explicit {
+        if !path.is_empty() {
+            candidates.push(path.to_string());
+        }
+    }
+
+    // 2. Linux / macOS names, resolved via PATH.
+    for name in [
+        "chromium",
+        "chromium-browser",
+        "google-chrome",
+        "google-chrome-stable",
+        "microsoft-edge",
+    ] {
+        candidates.push(name.to_string());
+    }
+
+    // 3. Standard Windows Chrome install locations (kept for the Windows build).
+    candidates.push(r#"C:\\Program Files\\Google\\Chrome\\Application\\chrome.exe"#.to_string());
+    candidates
+        .push(r#"C:\\Program Files (x86)\\Google\\Chrome\\Application\\chrome.exe"#.to_string());
+    if let Some(local) = localappdata {
+        let mut p = PathBuf::from(local);
+        p.push("Google\\Chrome\\Application\\chrome.exe");
+        candidates.push(p.to_string_lossy().into_owned());
+    }
+
+    // 4. Bare `chrome` on PATH.
+    candidates.push("chrome".to_string());
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::browser_candidates;
+
+    #[test]
+    fn candidates_include_linux_names() {
+        let candidates = browser_candidates(None, None);
+        for name in [
+            "chromium",
+            "chromium-browser",
+            "google-chrome",
+            "google-chrome-stable",
+        ] {
+            assert!(
+                candidates.iter().any(|c| c == name),
+                "expected candidate list to include {name}, got {candidates:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn explicit_browser_is_tried_first() {
+        let candidates = browser_candidates(Some("/opt/custom/chrome"), None);
+        assert_eq!(
+            candidates.first().map(String::as_str),
+            Some("/opt/custom/chrome"),
+            "explicit SCORESHEET_BROWSER path should be first"
+        );
+    }
+
+    #[test]
+    fn empty_explicit_is_ignored() {
+        let candidates = browser_candidates(Some(""), None);
+        assert_eq!(
+            candidates.first().map(String::as_str),
+            Some("chromium"),
+            "empty override should be skipped, Linux names come first"
+        );
+    }
+}
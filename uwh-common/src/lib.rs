@@ -9,6 +9,12 @@ pub mod bundles;
 #[cfg(feature = "std")]
 pub mod config;
 
+#[cfg(feature = "std")]
+pub mod game_data;
+
+#[cfg(feature = "std")]
+pub mod html_pdf;
+
 #[cfg(feature = "std")]
 pub mod uwhportal;
 
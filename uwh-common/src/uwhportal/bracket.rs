@@ -0,0 +1,391 @@
+//! Offline resolution of knockout slots
+//!
+//! Fills in the winner-of and loser-of slots of a [`Schedule`] from locally recorded results, so
+//! knockout games can show their teams without the portal. A game that ended level has no winner
+//! here (the portal settles it with a coin flip), so slots that depend on it are left unresolved
+//! rather than guessed.
+//!
+//! Seeded-by slots are filled from a group's standings once every game in the group has a result,
+//! counting 2 points for a win and 1 for a draw. Only a team whose points no other team in the
+//! group shares has a seed here: the portal breaks ties on head-to-head results and goal
+//! difference, which this doesn't attempt. Groups whose standings start from earlier ranks
+//! (swap, slide or exclusion) are left to the portal.
+
+use super::schedule::{
+    GameNumber, Group, ResultOf, Schedule, ScheduledTeam, SeededBy, StandingsCalculation, TeamId,
+};
+use crate::bundles::BlackWhiteBundle;
+use core::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Final scores by game number. Black is the dark team and white is the light team.
+pub type GameResults = BTreeMap<GameNumber, BlackWhiteBundle<u8>>;
+
+/// A game or group being resolved, so a schedule whose references loop back on themselves can't
+/// recurse forever
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum InProgress<'a> {
+    Game(&'a str),
+    Group(&'a str),
+}
+
+pub struct Bracket<'a> {
+    schedule: &'a Schedule,
+    results: &'a GameResults,
+}
+
+impl<'a> Bracket<'a> {
+    pub fn new(schedule: &'a Schedule, results: &'a GameResults) -> Self {
+        Self { schedule, results }
+    }
+
+    /// The team that fills `team`, if the results decide it
+    pub fn resolve(&self, team: &ScheduledTeam) -> Option<TeamId> {
+        self.resolve_inner(team, &mut BTreeSet::new())
+    }
+
+    /// A copy of the schedule with every slot whose team is known assigned to that team
+    pub fn resolved_schedule(&self) -> Schedule {
+        let mut schedule = self.schedule.clone();
+        for game in schedule.games.values_mut() {
+            for team in [&mut game.dark, &mut game.light] {
+                if team.assigned().is_none()
+                    && let Some(id) = self.resolve(team)
+                {
+                    *team = ScheduledTeam::new_team_id(id);
+                }
+            }
+        }
+        schedule
+    }
+
+    fn resolve_inner(
+        &self,
+        team: &ScheduledTeam,
+        in_progress: &mut BTreeSet<InProgress<'a>>,
+    ) -> Option<TeamId> {
+        if let Some(id) = team.assigned() {
+            Some(id.clone())
+        } else if let Some(result_of) = team.result_of() {
+            self.result_of(result_of, in_progress)
+        } else if let Some(seeded_by) = team.seeded_by() {
+            self.seeded_by(seeded_by, in_progress)
+        } else {
+            None
+        }
+    }
+
+    fn result_of(
+        &self,
+        result_of: &ResultOf,
+        in_progress: &mut BTreeSet<InProgress<'a>>,
+    ) -> Option<TeamId> {
+        let game = self.schedule.games.get(result_of.game_number())?;
+        let score = self.results.get(&game.number)?;
+        let (winner, loser) = match score.black.cmp(&score.white) {
+            Ordering::Greater => (&game.dark, &game.light),
+            Ordering::Less => (&game.light, &game.dark),
+            Ordering::Equal => return None,
+        };
+
+        let node = InProgress::Game(&game.number);
+        if !in_progress.insert(node) {
+            return None;
+        }
+        let team = match result_of {
+            ResultOf::Winner { .. } => self.resolve_inner(winner, in_progress),
+            ResultOf::Loser { .. } => self.resolve_inner(loser, in_progress),
+        };
+        in_progress.remove(&node);
+        team
+    }
+
+    fn seeded_by(
+        &self,
+        seeded_by: &SeededBy,
+        in_progress: &mut BTreeSet<InProgress<'a>>,
+    ) -> Option<TeamId> {
+        let name = seeded_by.group.as_deref()?;
+        let group = self
+            .schedule
+            .groups
+            .iter()
+            .find(|group| group.name == name || group.short_name == name)?;
+        if !matches!(
+            group.standings_calculation,
+            None | Some(StandingsCalculation::Standard)
+        ) {
+            return None;
+        }
+
+        let node = InProgress::Group(&group.name);
+        if !in_progress.insert(node) {
+            return None;
+        }
+        let points = self.points(group, in_progress);
+        in_progress.remove(&node);
+        let points = points?;
+
+        let seed = usize::try_from(seeded_by.number).ok()?;
+        let (team, team_points) = points.iter().find(|(_, team_points)| {
+            points.values().filter(|other| other > team_points).count() + 1 == seed
+        })?;
+        let shared = points.values().filter(|&p| p == team_points).count() > 1;
+        (!shared).then(|| team.clone())
+    }
+
+    /// Each team's points in `group`, if every game in it has a result and both of its teams
+    fn points(
+        &self,
+        group: &'a Group,
+        in_progress: &mut BTreeSet<InProgress<'a>>,
+    ) -> Option<BTreeMap<TeamId, u32>> {
+        let mut points = BTreeMap::new();
+        for number in &group.game_numbers {
+            let game = self.schedule.games.get(number)?;
+            let score = self.results.get(number)?;
+            let dark = self.resolve_inner(&game.dark, in_progress)?;
+            let light = self.resolve_inner(&game.light, in_progress)?;
+            let (dark_points, light_points) = match score.black.cmp(&score.white) {
+                Ordering::Greater => (2, 0),
+                Ordering::Less => (0, 2),
+                Ordering::Equal => (1, 1),
+            };
+            *points.entry(dark).or_default() += dark_points;
+            *points.entry(light).or_default() += light_points;
+        }
+        Some(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uwhportal::schedule::{EventId, Game, GameList, Group};
+    use time::macros::datetime;
+
+    fn team(id: &str) -> TeamId {
+        TeamId::from_partial(id)
+    }
+
+    fn game(number: &str, dark: ScheduledTeam, light: ScheduledTeam) -> (GameNumber, Game) {
+        let game = Game {
+            number: number.to_string(),
+            dark,
+            light,
+            start_time: datetime!(2026-06-01 9:00 UTC),
+            court: "A".to_string(),
+            timing_rule: "Default".to_string(),
+            referee_assignments: None,
+            description: None,
+        };
+        (number.to_string(), game)
+    }
+
+    /// Two semi-finals, a final and a third place game
+    fn knockout() -> Schedule {
+        let games: GameList = [
+            game(
+                "1",
+                ScheduledTeam::new_team_id(team("1-A")),
+                ScheduledTeam::new_team_id(team("4-A")),
+            ),
+            game(
+                "2",
+                ScheduledTeam::new_team_id(team("2-A")),
+                ScheduledTeam::new_team_id(team("3-A")),
+            ),
+            game(
+                "3",
+                ScheduledTeam::new_loser_of("1"),
+                ScheduledTeam::new_loser_of("2"),
+            ),
+            game(
+                "4",
+                ScheduledTeam::new_winner_of("1"),
+                ScheduledTeam::new_winner_of("2"),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        Schedule {
+            event_id: EventId::from_partial("1-A"),
+            games,
+            non_game_entries: vec![],
+            groups: vec![],
+            timing_rules: vec![],
+            standings_order: None,
+            final_results_order: None,
+            referees_by_game_number: None,
+        }
+    }
+
+    fn score(black: u8, white: u8) -> BlackWhiteBundle<u8> {
+        BlackWhiteBundle { black, white }
+    }
+
+    #[test]
+    fn fills_in_decided_slots() {
+        let schedule = knockout();
+        let results = GameResults::from([("1".to_string(), score(3, 1))]);
+        let bracket = Bracket::new(&schedule, &results);
+
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_winner_of("1")),
+            Some(team("1-A"))
+        );
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_loser_of("1")),
+            Some(team("4-A"))
+        );
+        assert_eq!(bracket.resolve(&ScheduledTeam::new_winner_of("2")), None);
+
+        let resolved = bracket.resolved_schedule();
+        assert_eq!(resolved.games["3"].dark.assigned(), Some(&team("4-A")));
+        assert_eq!(resolved.games["3"].light, ScheduledTeam::new_loser_of("2"));
+        assert_eq!(resolved.games["4"].dark.assigned(), Some(&team("1-A")));
+    }
+
+    #[test]
+    fn follows_a_slot_back_through_earlier_games() {
+        let schedule = knockout();
+        let results = GameResults::from([
+            ("1".to_string(), score(3, 1)),
+            ("2".to_string(), score(0, 2)),
+            ("4".to_string(), score(1, 2)),
+        ]);
+        let bracket = Bracket::new(&schedule, &results);
+
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_winner_of("4")),
+            Some(team("3-A"))
+        );
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_loser_of("4")),
+            Some(team("1-A"))
+        );
+    }
+
+    #[test]
+    fn leaves_a_tied_game_and_seeds_unresolved() {
+        let schedule = knockout();
+        let results = GameResults::from([("1".to_string(), score(2, 2))]);
+        let bracket = Bracket::new(&schedule, &results);
+
+        // Only a coin flip could settle this, so it's not guessed
+        assert_eq!(bracket.resolve(&ScheduledTeam::new_winner_of("1")), None);
+        assert_eq!(bracket.resolve(&ScheduledTeam::new_loser_of("1")), None);
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_seeded_by(1, "Pool")),
+            None
+        );
+        assert_eq!(bracket.resolved_schedule(), schedule);
+    }
+
+    /// A round robin pool of three, then a final between its top two
+    fn pool() -> Schedule {
+        let mut schedule = knockout();
+        schedule.games = [
+            game(
+                "1",
+                ScheduledTeam::new_team_id(team("rays")),
+                ScheduledTeam::new_team_id(team("eels")),
+            ),
+            game(
+                "2",
+                ScheduledTeam::new_team_id(team("eels")),
+                ScheduledTeam::new_team_id(team("sharks")),
+            ),
+            game(
+                "3",
+                ScheduledTeam::new_team_id(team("sharks")),
+                ScheduledTeam::new_team_id(team("rays")),
+            ),
+            game(
+                "4",
+                ScheduledTeam::new_seeded_by(1, "Pool A"),
+                ScheduledTeam::new_seeded_by(2, "Pool A"),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        schedule.groups = vec![Group {
+            name: "Pool A".to_string(),
+            short_name: "A".to_string(),
+            group_type: None,
+            game_numbers: vec!["1".to_string(), "2".to_string(), "3".to_string()],
+            standings_calculation: Some(StandingsCalculation::Standard),
+            final_results: None,
+        }];
+        schedule
+    }
+
+    #[test]
+    fn seeds_a_finished_pool_by_points() {
+        let schedule = pool();
+        let results = GameResults::from([
+            ("1".to_string(), score(3, 1)),
+            ("2".to_string(), score(2, 1)),
+            ("3".to_string(), score(0, 1)),
+        ]);
+        let bracket = Bracket::new(&schedule, &results);
+
+        let resolved = bracket.resolved_schedule();
+        assert_eq!(resolved.games["4"].dark.assigned(), Some(&team("rays")));
+        assert_eq!(resolved.games["4"].light.assigned(), Some(&team("eels")));
+        assert_eq!(
+            bracket.resolve(&ScheduledTeam::new_seeded_by(3, "A")),
+            Some(team("sharks"))
+        );
+    }
+
+    #[test]
+    fn leaves_an_unfinished_pool_and_tied_points_unseeded() {
+        let schedule = pool();
+        let results = GameResults::from([
+            ("1".to_string(), score(3, 1)),
+            ("2".to_string(), score(2, 2)),
+        ]);
+        let bracket = Bracket::new(&schedule, &results);
+        assert_eq!(bracket.resolved_schedule(), schedule);
+
+        // Every team wins once, so only a tie-break the portal applies could seed them
+        let results = GameResults::from([
+            ("1".to_string(), score(3, 1)),
+            ("2".to_string(), score(2, 0)),
+            ("3".to_string(), score(2, 0)),
+        ]);
+        let bracket = Bracket::new(&schedule, &results);
+        assert_eq!(bracket.resolved_schedule(), schedule);
+    }
+
+    #[test]
+    fn leaves_standings_from_starting_ranks_to_the_portal() {
+        let mut schedule = pool();
+        schedule.groups[0].standings_calculation = Some(StandingsCalculation::SwapIfUpset {
+            starting_ranks: vec![],
+        });
+        let results = GameResults::from([
+            ("1".to_string(), score(3, 1)),
+            ("2".to_string(), score(2, 1)),
+            ("3".to_string(), score(0, 1)),
+        ]);
+        let bracket = Bracket::new(&schedule, &results);
+        assert_eq!(bracket.resolved_schedule(), schedule);
+    }
+
+    #[test]
+    fn a_schedule_that_refers_to_itself_stays_unresolved() {
+        let mut schedule = knockout();
+        let (number, looped) = game(
+            "5",
+            ScheduledTeam::new_winner_of("5"),
+            ScheduledTeam::new_team_id(team("1-A")),
+        );
+        schedule.games.insert(number, looped);
+        let results = GameResults::from([("5".to_string(), score(1, 0))]);
+        let bracket = Bracket::new(&schedule, &results);
+
+        assert_eq!(bracket.resolve(&ScheduledTeam::new_winner_of("5")), None);
+    }
+}
@@ -0,0 +1,332 @@
+//! Why a portal call failed, and when the client tries it again.
+//!
+//! Every `UwhPortalClient` call fails with a `PortalError`, so a caller can tell an expired
+//! login from an outage from a refusal by matching on it rather than on the message. The client
+//! itself retries the failures that are worth retrying, as its `RetryPolicy` allows, before the
+//! error reaches the caller.
+
+use core::time::Duration;
+use log::warn;
+use reqwest::{RequestBuilder, Response, StatusCode, header::RETRY_AFTER};
+use std::{error::Error, fmt};
+use time::{OffsetDateTime, format_description::well_known::Rfc2822};
+
+/// Why a call to the portal failed
+#[derive(Debug)]
+pub enum PortalError {
+    /// The request was refused before it left the machine: the address cannot be used as
+    /// written, such as a plain-http address when https is required, or one with no host
+    NotSent(reqwest::Error),
+    /// The exchange never completed: DNS, connect, TLS, or a dropped response
+    Network(reqwest::Error),
+    /// No answer within the client's timeout
+    Timeout(reqwest::Error),
+    /// `401`: the access key was refused, or the call needed one and none was held
+    AuthExpired(String),
+    /// `403`: the key was accepted but does not cover this call
+    Forbidden(String),
+    /// `429`. `retry_after` is what the portal asked for, if it said.
+    RateLimited {
+        retry_after: Option<Duration>,
+        body: String,
+    },
+    /// `404`
+    NotFound(String),
+    /// A success whose body did not have the shape the client reads
+    SchemaMismatch(String),
+    /// `5xx`
+    ServerError { status: u16, body: String },
+    /// Any other refusal, such as stats the event will not take or a score already in. The body
+    /// is the portal's reason.
+    Rejected { status: u16, body: String },
+}
+
+impl PortalError {
+    /// The error for a response that was not a success, with the body the portal sent
+    pub(super) fn from_status(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: String,
+    ) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => Self::AuthExpired(body),
+            StatusCode::FORBIDDEN => Self::Forbidden(body),
+            StatusCode::NOT_FOUND => Self::NotFound(body),
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited { retry_after, body },
+            status if status.is_server_error() => Self::ServerError {
+                status: status.as_u16(),
+                body,
+            },
+            status => Self::Rejected {
+                status: status.as_u16(),
+                body,
+            },
+        }
+    }
+
+    /// The access key was the problem, and logging in again is what fixes it
+    pub fn is_auth(&self) -> bool {
+        matches!(self, Self::AuthExpired(_) | Self::Forbidden(_))
+    }
+
+    /// Whether the same call may succeed if sent again. `idempotent` is whether sending it twice
+    /// does no harm: a call that changes something is only sent again when the portal said it
+    /// did not handle it.
+    fn is_retryable(&self, idempotent: bool) -> bool {
+        match self {
+            Self::RateLimited { .. } => true,
+            Self::ServerError { status, .. } if *status == 503 => true,
+            Self::Network(_) | Self::Timeout(_) | Self::ServerError { .. } => idempotent,
+            Self::NotSent(_)
+            | Self::AuthExpired(_)
+            | Self::Forbidden(_)
+            | Self::NotFound(_)
+            | Self::SchemaMismatch(_)
+            | Self::Rejected { .. } => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for PortalError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_builder() {
+            Self::NotSent(e)
+        } else if e.is_timeout() {
+            Self::Timeout(e)
+        } else if e.is_decode() {
+            Self::SchemaMismatch(e.to_string())
+        } else {
+            Self::Network(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for PortalError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SchemaMismatch(e.to_string())
+    }
+}
+
+impl fmt::Display for PortalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSent(e) => write!(f, "request was never sent: {e}"),
+            Self::Network(e) => write!(f, "could not reach the site: {e}"),
+            Self::Timeout(e) => write!(f, "the site did not answer in time: {e}"),
+            Self::AuthExpired(body) => write!(f, "access key refused (401): {body}"),
+            Self::Forbidden(body) => write!(f, "not allowed (403): {body}"),
+            Self::RateLimited {
+                retry_after: Some(wait),
+                body,
+            } => write!(f, "too many requests, retry after {wait:?} (429): {body}"),
+            Self::RateLimited {
+                retry_after: None,
+                body,
+            } => write!(f, "too many requests (429): {body}"),
+            Self::NotFound(body) => write!(f, "not found (404): {body}"),
+            Self::SchemaMismatch(detail) => write!(f, "unexpected response: {detail}"),
+            Self::ServerError { status, body } => write!(f, "server error ({status}): {body}"),
+            Self::Rejected { status, body } => write!(f, "refused ({status}): {body}"),
+        }
+    }
+}
+
+impl Error for PortalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotSent(e) | Self::Network(e) | Self::Timeout(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// How often, and how patiently, the client tries a call again before giving up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Tries in all, counting the first. `1` never tries again.
+    pub max_attempts: u32,
+    /// The wait before the first retry, doubled before each one after it
+    pub initial_backoff: Duration,
+    /// The longest the client waits between tries of its own accord. A `Retry-After` is only
+    /// waited out when it is no longer than the wait the client would have used anyway; a portal
+    /// asking for longer gets its `RateLimited` error handed back, so a caller with a queue can
+    /// try again on the queue's own schedule instead of stalling it.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Every call is tried once
+    pub const NONE: Self = Self {
+        max_attempts: 1,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    /// How long to wait before sending again after `error` on try number `attempt` (from 1), or
+    /// `None` if it should not be sent again
+    fn wait_before_retry(
+        &self,
+        attempt: u32,
+        error: &PortalError,
+        idempotent: bool,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !error.is_retryable(idempotent) {
+            return None;
+        }
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(self.max_backoff);
+        match error {
+            PortalError::RateLimited {
+                retry_after: Some(wait),
+                ..
+            } => (*wait <= backoff).then_some(*wait),
+            _ => Some(backoff),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(4),
+        }
+    }
+}
+
+/// What a call does, which decides how freely it is sent again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Call {
+    /// Reads something, so sending it twice does no harm
+    Read,
+    /// Changes something, so it is only sent again when the portal said it did not handle it
+    Write,
+    /// Checks whether the portal is there. Never retried: the caller's own schedule of checks
+    /// is the retry, and a retried check only delays the answer.
+    Probe,
+}
+
+/// Send `request` under `policy`, and hand back the first successful response. Any other
+/// response is read and turned into the matching `PortalError`.
+pub(super) async fn send(
+    request: RequestBuilder,
+    policy: RetryPolicy,
+    call: Call,
+) -> Result<Response, PortalError> {
+    let policy = if call == Call::Probe {
+        RetryPolicy::NONE
+    } else {
+        policy
+    };
+    let mut attempt = 1;
+    loop {
+        let Some(this_try) = request.try_clone() else {
+            // Only a streamed body cannot be copied, and none of the client's are streamed
+            return check(request.send().await).await;
+        };
+        let error = match check(this_try.send().await).await {
+            Ok(response) => return Ok(response),
+            Err(e) => e,
+        };
+        let Some(wait) = policy.wait_before_retry(attempt, &error, call == Call::Read) else {
+            return Err(error);
+        };
+        warn!("portal call failed ({error}), trying again in {wait:?}");
+        tokio::time::sleep(wait).await;
+        attempt += 1;
+    }
+}
+
+/// The response if it is a `200`, otherwise the matching error. Only `200` has ever counted as
+/// success from the portal, so any other `2xx` is handed back as `Rejected`.
+async fn check(result: reqwest::Result<Response>) -> Result<Response, PortalError> {
+    let response = result?;
+    let status = response.status();
+    if status == StatusCode::OK {
+        return Ok(response);
+    }
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_retry_after);
+    let body = response.text().await.unwrap_or_default();
+    Err(PortalError::from_status(status, retry_after, body))
+}
+
+/// A `Retry-After` value: a number of seconds, or an HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    let wait = at - OffsetDateTime::now_utc();
+    Some(wait.try_into().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after_is_read_as_seconds_or_a_date() {
+        assert_eq!(parse_retry_after(" 12 "), Some(Duration::from_secs(12)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_its_cap_and_stops_at_the_last_attempt() {
+        let policy = RetryPolicy::default();
+        let busy = PortalError::ServerError {
+            status: 502,
+            body: String::new(),
+        };
+        assert_eq!(
+            policy.wait_before_retry(1, &busy, true),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            policy.wait_before_retry(2, &busy, true),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(policy.wait_before_retry(3, &busy, true), None);
+        assert_eq!(policy.wait_before_retry(1, &busy, false), None);
+        assert_eq!(RetryPolicy::NONE.wait_before_retry(1, &busy, true), None);
+
+        let capped = RetryPolicy {
+            max_attempts: 10,
+            ..policy
+        };
+        assert_eq!(
+            capped.wait_before_retry(8, &busy, true),
+            Some(Duration::from_secs(4))
+        );
+    }
+
+    #[test]
+    fn a_retry_after_longer_than_the_backoff_is_handed_back() {
+        let policy = RetryPolicy::default();
+        let limited = |secs| PortalError::RateLimited {
+            retry_after: Some(Duration::from_millis(secs)),
+            body: String::new(),
+        };
+        assert_eq!(
+            policy.wait_before_retry(1, &limited(300), false),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(policy.wait_before_retry(1, &limited(800), false), None);
+        assert_eq!(
+            policy.wait_before_retry(2, &limited(800), false),
+            Some(Duration::from_millis(800))
+        );
+        assert_eq!(policy.wait_before_retry(2, &limited(30_000), true), None);
+    }
+}
@@ -3,7 +3,7 @@ use core::{cell::OnceCell, time::Duration};
 use log::{debug, info, warn};
 use rand::{Rng, SeedableRng};
 use reqwest::{
-    Client, ClientBuilder, Method, RequestBuilder, StatusCode,
+    Client, ClientBuilder, Method, RequestBuilder,
     header::{AUTHORIZATION, HeaderValue},
 };
 use schedule::{EventId, GameNumber, TeamId, TeamList};
@@ -13,8 +13,14 @@ use std::{
     error::Error,
 };
 
+pub mod bracket;
+mod error;
+pub mod player_stats;
 pub mod schedule;
 
+use error::{Call, send};
+pub use error::{PortalError, RetryPolicy};
+
 // --- Coin-flip portal types (used by scoresheet generation / coin-flip resolution) ---
 
 #[derive(Debug, Clone, Deserialize)]
@@ -162,6 +168,7 @@ pub struct UwhPortalClient {
     auth_header: Option<HeaderValue>,
     client: Client,
     id: OnceCell<u32>,
+    retry: RetryPolicy,
 }
 
 impl UwhPortalClient {
@@ -188,9 +195,15 @@ impl UwhPortalClient {
             auth_header,
             client,
             id: OnceCell::new(),
+            retry: RetryPolicy::default(),
         })
     }
 
+    /// How calls that fail are tried again. Applies to requests built after the change.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
     /// Replace the access key.
     ///
     /// The key is checked and converted before it is stored, so a key that
@@ -220,8 +233,7 @@ impl UwhPortalClient {
         &self,
         event_id: &EventId,
         code: u32,
-    ) -> impl std::future::Future<Output = Result<PortalTokenResponse, Box<dyn Error>>> + use<>
-    {
+    ) -> impl std::future::Future<Output = Result<PortalTokenResponse, PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{}/access-keys/ref-box",
             self.base_url,
@@ -235,41 +247,39 @@ impl UwhPortalClient {
                 "refBoxId": self.id().to_string(),
                 "code": code.to_string()
             }));
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let response = request.send().await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal login successful");
-                let body = response.json::<serde_json::Value>().await?;
-                if let Some(token) = body["accessKey"].as_str() {
-                    Ok(PortalTokenResponse::Success(token.to_string()))
-                } else {
-                    Err(Box::new(ApiError::new(
-                        "Token not found in response".to_string(),
-                    )))?
+            match response.await {
+                Ok(response) => {
+                    info!("portal login successful");
+                    let body = response.json::<serde_json::Value>().await?;
+                    match body["accessKey"].as_str() {
+                        Some(token) => Ok(PortalTokenResponse::Success(token.to_string())),
+                        None => Err(PortalError::SchemaMismatch(
+                            "Token not found in response".to_string(),
+                        )),
+                    }
                 }
-            } else if response.status() == StatusCode::BAD_REQUEST {
-                warn!("portal login failed, response: {:?}", response);
-                let body = response.json::<serde_json::Value>().await?;
-                if let Some(reason) = body["reason"].as_str() {
-                    match reason {
-                        "NoPendingLink" => Ok(PortalTokenResponse::NoPendingLink),
-                        "InvalidCode" => Ok(PortalTokenResponse::InvalidCode),
-                        _ => Err(Box::new(ApiError::new(format!(
-                            "Unknown reason: {}",
-                            reason
-                        ))))?,
+                // A refused code is an answer, not a failure
+                Err(PortalError::Rejected { status: 400, body }) => {
+                    warn!("portal login failed: {body}");
+                    let body: serde_json::Value = serde_json::from_str(&body)?;
+                    match body["reason"].as_str() {
+                        Some("NoPendingLink") => Ok(PortalTokenResponse::NoPendingLink),
+                        Some("InvalidCode") => Ok(PortalTokenResponse::InvalidCode),
+                        Some(reason) => Err(PortalError::SchemaMismatch(format!(
+                            "Unknown reason: {reason}"
+                        ))),
+                        None => Err(PortalError::SchemaMismatch(
+                            "Reason not found in response".to_string(),
+                        )),
                     }
-                } else {
-                    Err(Box::new(ApiError::new(
-                        "Reason not found in response".to_string(),
-                    )))?
                 }
-            } else {
-                warn!("portal login failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
+                Err(e) => {
+                    warn!("portal login failed: {e}");
+                    Err(e)
+                }
             }
         }
     }
@@ -278,7 +288,7 @@ impl UwhPortalClient {
         &self,
         email: &str,
         password: &str,
-    ) -> impl std::future::Future<Output = Result<String, Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<String, PortalError>> + use<> {
         let url = format!("{}/api/authentication", self.base_url,);
 
         let request = self
@@ -288,24 +298,19 @@ impl UwhPortalClient {
                 "email": email,
                 "password": password
             }));
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let response = request.send().await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal login successful");
-                let body = response.json::<serde_json::Value>().await?;
-                if let Some(token) = body["accessToken"].as_str() {
-                    Ok(token.to_string())
-                } else {
-                    Err(Box::new(ApiError::new(
-                        "Token not found in response".to_string(),
-                    )))?
-                }
-            } else {
-                warn!("portal login failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
+            let response = response
+                .await
+                .inspect_err(|e| warn!("portal login failed: {e}"))?;
+            info!("portal login successful");
+            let body = response.json::<serde_json::Value>().await?;
+            match body["accessToken"].as_str() {
+                Some(token) => Ok(token.to_string()),
+                None => Err(PortalError::SchemaMismatch(
+                    "Token not found in response".to_string(),
+                )),
             }
         }
     }
@@ -313,25 +318,21 @@ impl UwhPortalClient {
     pub fn verify_token(
         &self,
         event: &EventId,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{}/access-keys/verify",
             self.base_url,
             event.partial()
         );
         let request = authenticated_request(&self.client, Method::GET, &url, &self.auth_header);
+        let response = send(request, self.retry, Call::Probe);
 
         async move {
-            let response = request.send().await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal token validation successful");
-                Ok(())
-            } else {
-                warn!("portal token validation failed, response: {response:?}");
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response
+                .await
+                .inspect_err(|e| warn!("portal token validation failed: {e}"))?;
+            info!("portal token validation successful");
+            Ok(())
         }
     }
 
@@ -340,26 +341,21 @@ impl UwhPortalClient {
         event_id: &EventId,
         game_number: &GameNumber,
         stats_json: String,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!("{}/api/admin/events/stats", self.base_url);
 
         let request = authenticated_request(&self.client, Method::POST, &url, &self.auth_header)
             .query(&[("eventId", event_id.full()), ("gameNumber", game_number)])
-            .body(stats_json.clone())
-            .header("Content-Type", "application/json")
-            .send();
+            .body(stats_json)
+            .header("Content-Type", "application/json");
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let response = request.await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal post game stats successful");
-                Ok(())
-            } else {
-                warn!("portal post game stats failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response
+                .await
+                .inspect_err(|e| warn!("portal post game stats failed: {e}"))?;
+            info!("portal post game stats successful");
+            Ok(())
         }
     }
 
@@ -369,71 +365,56 @@ impl UwhPortalClient {
         game_number: &GameNumber,
         scores: BlackWhiteBundle<u8>,
         force: bool,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{}/schedule/games/{game_number}/scores",
             self.base_url,
             event_id.partial(),
         );
 
-        let request = authenticated_request(&self.client, Method::POST, &url, &self.auth_header)
-            .query(&[("force", force)])
-            .json(&serde_json::json!({
+        let body = serde_json::json!({
             "dark": {
                 "value": scores.black
             },
             "light": {
                 "value": scores.white
             }
-            }));
-
-        let client_ = self.client.clone();
+        });
+        debug!("Posting game scores to portal: {url} force={force} body={body}");
+        let request = authenticated_request(&self.client, Method::POST, &url, &self.auth_header)
+            .query(&[("force", force)])
+            .json(&body);
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let request = request.build()?;
-            debug!("Posting game scores to portal: {request:?}");
-            debug!(
-                "Post body: {:?}",
-                std::str::from_utf8(request.body().unwrap().as_bytes().unwrap())
-            );
-            let response = client_.execute(request).await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal post game scores successful");
-                Ok(())
-            } else {
-                warn!("portal post game scores failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response
+                .await
+                .inspect_err(|e| warn!("portal post game scores failed: {e}"))?;
+            info!("portal post game scores successful");
+            Ok(())
         }
     }
 
     pub fn get_event_schedule_privileged(
         &self,
         event_id: &EventId,
-    ) -> impl std::future::Future<Output = Result<schedule::Schedule, Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<schedule::Schedule, PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{}/schedule/privileged",
             self.base_url,
             event_id.partial()
         );
 
-        let request =
-            authenticated_request(&self.client, Method::GET, &url, &self.auth_header).send();
+        let request = authenticated_request(&self.client, Method::GET, &url, &self.auth_header);
+        let response = send(request, self.retry, Call::Read);
 
         async move {
-            let response = request.await?;
-
-            if response.status() == StatusCode::OK {
-                let body = response.text().await?; // TODO: Can we just call response.json()?
-                let schedule: schedule::Schedule = serde_json::from_str(&body)?;
-                Ok(schedule)
-            } else {
-                warn!("portal get event schedule failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            let response = response
+                .await
+                .inspect_err(|e| warn!("portal get event schedule failed: {e}"))?;
+            let body = response.text().await?; // TODO: Can we just call response.json()?
+            let schedule: schedule::Schedule = serde_json::from_str(&body)?;
+            Ok(schedule)
         }
     }
 
@@ -462,22 +443,19 @@ impl UwhPortalClient {
     pub fn get_event_referee_name_map_from_referees(
         &self,
         event_id: &EventId,
-    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, Box<dyn Error>>> + use<>
+    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, PortalError>> + use<>
     {
         let url = format!(
             "{}/api/events/{}/referees",
             self.base_url,
             event_id.partial()
         );
-        let request = self.client.get(&url).send();
+        let response = send(self.client.get(&url), self.retry, Call::Read);
 
         async move {
-            let response = request.await?;
-            if response.status() != StatusCode::OK {
-                warn!("portal /referees failed, response: {:?}", response);
-                let body = response.text().await?;
-                return Err(Box::new(ApiError::new(body)).into());
-            }
+            let response = response
+                .await
+                .inspect_err(|e| warn!("portal /referees failed: {e}"))?;
             let body = response.json::<serde_json::Value>().await?;
             let mut map = HashMap::new();
 
@@ -514,36 +492,36 @@ impl UwhPortalClient {
     pub fn get_event_teams(
         &self,
         event_id: &EventId,
-    ) -> impl std::future::Future<Output = Result<TeamList, Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<TeamList, PortalError>> + use<> {
         let url = format!("{}/api/events/{}/teams", self.base_url, event_id.partial());
 
-        let request = self.client.get(&url).send();
+        let response = send(self.client.get(&url), self.retry, Call::Read);
 
         async move {
-            let response = request.await?;
-
-            if response.status() == StatusCode::OK {
-                let body = response.json::<serde_json::Value>().await?;
-                let teams = body["teams"]
-                    .as_array()
-                    .ok_or(format!("Invalid response format. Response: {:?}", body))?;
-                let mut team_map = BTreeMap::new();
-                for team_entry in teams {
-                    let team_info = &team_entry["team"];
-                    let team_id = team_info["id"]
-                        .as_str()
-                        .ok_or(format!("Missing team id in response: {:?}", team_info))?;
-                    let name = team_info["name"]
-                        .as_str()
-                        .ok_or(format!("Missing team name in response: {:?}", team_info))?;
-                    team_map.insert(TeamId::from_full(team_id)?, name.to_string());
-                }
-                Ok(team_map)
-            } else {
-                warn!("portal get event schedule failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
+            let response = response
+                .await
+                .inspect_err(|e| warn!("portal get event teams failed: {e}"))?;
+            let body = response.json::<serde_json::Value>().await?;
+            let mismatch = |what: &str, value: &serde_json::Value| {
+                PortalError::SchemaMismatch(format!("{what} in response: {value:?}"))
+            };
+            let teams = body["teams"]
+                .as_array()
+                .ok_or_else(|| mismatch("Invalid response format", &body))?;
+            let mut team_map = BTreeMap::new();
+            for team_entry in teams {
+                let team_info = &team_entry["team"];
+                let team_id = team_info["id"]
+                    .as_str()
+                    .ok_or_else(|| mismatch("Missing team id", team_info))?;
+                let name = team_info["name"]
+                    .as_str()
+                    .ok_or_else(|| mismatch("Missing team name", team_info))?;
+                let team_id = TeamId::from_full(team_id)
+                    .map_err(|e| PortalError::SchemaMismatch(e.to_string()))?;
+                team_map.insert(team_id, name.to_string());
             }
+            Ok(team_map)
         }
     }
 
@@ -551,22 +529,18 @@ impl UwhPortalClient {
         &self,
         past: bool,
         schedule_published: bool,
-    ) -> impl std::future::Future<Output = Result<Vec<schedule::Event>, Box<dyn Error>>> + use<>
-    {
+    ) -> impl std::future::Future<Output = Result<Vec<schedule::Event>, PortalError>> + use<> {
         let url = format!("{}/api/events", self.base_url);
 
         let filter = if past { "Past" } else { "InProgressOrUpcoming" };
         let schedule_published = if schedule_published { "true" } else { "false" };
 
-        let request = self
-            .client
-            .get(&url)
-            .query(&[
-                ("limit", "100"),
-                ("filter", filter),
-                ("isSchedulePublished", schedule_published),
-            ])
-            .send();
+        let request = self.client.get(&url).query(&[
+            ("limit", "100"),
+            ("filter", filter),
+            ("isSchedulePublished", schedule_published),
+        ]);
+        let response = send(request, self.retry, Call::Read);
 
         #[derive(Debug, Serialize, Deserialize)]
         struct ResponseWrapper {
@@ -576,17 +550,12 @@ impl UwhPortalClient {
         }
 
         async move {
-            let response = request.await?;
-
-            if response.status() == StatusCode::OK {
-                let body = response.text().await?;
-                let parsed_response: ResponseWrapper = serde_json::from_str(&body)?;
-                Ok(parsed_response.items)
-            } else {
-                warn!("portal get events list failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            let response = response
+                .await
+                .inspect_err(|e| warn!("portal get events list failed: {e}"))?;
+            let body = response.text().await?;
+            let parsed_response: ResponseWrapper = serde_json::from_str(&body)?;
+            Ok(parsed_response.items)
         }
     }
 
@@ -595,7 +564,7 @@ impl UwhPortalClient {
         event_slug: &str,
         schedule: &schedule::SendableSchedule,
         force: bool,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!("{}/api/events/{event_slug}/schedule", self.base_url);
 
         let mut request =
@@ -605,21 +574,14 @@ impl UwhPortalClient {
         if force {
             request = request.query(&[("force", "true")]);
         }
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let response = request.send().await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal push event schedule successful");
-                Ok(())
-            } else {
-                warn!(
-                    "portal push event schedule failed, response: {:?}",
-                    response
-                );
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response
+                .await
+                .inspect_err(|e| warn!("portal push event schedule failed: {e}"))?;
+            info!("portal push event schedule successful");
+            Ok(())
         }
     }
 
@@ -628,7 +590,7 @@ impl UwhPortalClient {
         &self,
         event_slug: &str,
         team_map: &BTreeMap<&str, &str>,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{event_slug}/schedule/map-teams",
             self.base_url
@@ -636,18 +598,14 @@ impl UwhPortalClient {
 
         let request = authenticated_request(&self.client, Method::POST, &url, &self.auth_header)
             .json(&team_map);
+        let response = send(request, self.retry, Call::Write);
 
         async move {
-            let response = request.send().await?;
-
-            if response.status() == StatusCode::OK {
-                info!("portal push team map successful");
-                Ok(())
-            } else {
-                warn!("portal push team map failed, response: {:?}", response);
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response
+                .await
+                .inspect_err(|e| warn!("portal push team map failed: {e}"))?;
+            info!("portal push team map successful");
+            Ok(())
         }
     }
 
@@ -656,53 +614,33 @@ impl UwhPortalClient {
     pub fn get_team_roster(
         &self,
         team_id: &TeamId,
-    ) -> impl std::future::Future<Output = Result<Vec<RosterPlayer>, Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<Vec<RosterPlayer>, PortalError>> + use<> {
         let url = format!("{}/api/admin/get-event-team", self.base_url);
         let team_id_full = team_id.full().to_string();
-        let request = self
-            .client
-            .get(&url)
-            .query(&[("teamId", &team_id_full)])
-            .send();
+        let request = self.client.get(&url).query(&[("teamId", &team_id_full)]);
+        let response = send(request, self.retry, Call::Read);
         async move {
-            let response = request.await?;
-            if response.status() == StatusCode::OK {
-                let body = response.json::<serde_json::Value>().await?;
-                Ok(parse_roster_json(&body))
-            } else {
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            let body = response.await?.json::<serde_json::Value>().await?;
+            Ok(parse_roster_json(&body))
         }
     }
 
     pub fn get_coin_flips(
         &self,
         event_slug: &str,
-    ) -> impl std::future::Future<Output = Result<CoinFlipDetails, Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<CoinFlipDetails, PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{event_slug}/schedule/coin-flips",
             self.base_url
         );
-        let request =
-            authenticated_request(&self.client, Method::GET, &url, &self.auth_header).send();
+        let request = authenticated_request(&self.client, Method::GET, &url, &self.auth_header);
+        let response = send(request, self.retry, Call::Read);
         async move {
-            let response = request.await?;
-            let status = response.status();
-            let body = response.text().await?;
-            if status == StatusCode::OK {
-                match serde_json::from_str::<CoinFlipDetails>(&body) {
-                    Ok(parsed) => Ok(parsed),
-                    Err(e) => {
-                        debug!("get_coin_flips: failed to decode body: {e}; body: {body}");
-                        Err(Box::new(ApiError::new(format!(
-                            "error decoding response body: {e}"
-                        ))))?
-                    }
-                }
-            } else {
-                Err(Box::new(ApiError::new(body)))?
-            }
+            let body = response.await?.text().await?;
+            serde_json::from_str::<CoinFlipDetails>(&body).map_err(|e| {
+                debug!("get_coin_flips: failed to decode body: {e}; body: {body}");
+                PortalError::SchemaMismatch(format!("error decoding response body: {e}"))
+            })
         }
     }
 
@@ -711,22 +649,17 @@ impl UwhPortalClient {
     pub fn get_event_referee_name_map(
         &self,
         event_id: &EventId,
-    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, Box<dyn Error>>> + use<>
+    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, PortalError>> + use<>
     {
         let url = format!(
             "{}/api/events/{}/participants",
             self.base_url,
             event_id.partial()
         );
-        let request =
-            authenticated_request(&self.client, Method::GET, &url, &self.auth_header).send();
+        let request = authenticated_request(&self.client, Method::GET, &url, &self.auth_header);
+        let response = send(request, self.retry, Call::Read);
         async move {
-            let response = request.await?;
-            if response.status() != StatusCode::OK {
-                let body = response.text().await?;
-                return Err(Box::new(ApiError::new(body)) as Box<dyn Error>);
-            }
-            let body = response.json::<serde_json::Value>().await?;
+            let body = response.await?.json::<serde_json::Value>().await?;
             let mut map = HashMap::new();
             let items = body
                 .as_array()
@@ -758,21 +691,16 @@ impl UwhPortalClient {
         &self,
         event_id: &EventId,
         game_number: &GameNumber,
-    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, Box<dyn Error>>> + use<>
+    ) -> impl std::future::Future<Output = Result<HashMap<String, String>, PortalError>> + use<>
     {
         let url = format!("{}/api/admin/events/game-referees", self.base_url);
         let event_id_full = event_id.full().to_string();
         let game_number = game_number.clone();
         let request = authenticated_request(&self.client, Method::GET, &url, &self.auth_header)
-            .query(&[("eventId", &event_id_full), ("gameNumber", &game_number)])
-            .send();
+            .query(&[("eventId", &event_id_full), ("gameNumber", &game_number)]);
+        let response = send(request, self.retry, Call::Read);
         async move {
-            let response = request.await?;
-            if response.status() != StatusCode::OK {
-                let body = response.text().await?;
-                return Err(Box::new(ApiError::new(body)) as Box<dyn Error>);
-            }
-            let body = response.json::<serde_json::Value>().await?;
+            let body = response.await?.json::<serde_json::Value>().await?;
             let mut map = HashMap::new();
             // Response: { referees: [ { user: { id, name, username } } ] }
             // name may be null; username is the fallback.
@@ -803,23 +731,18 @@ impl UwhPortalClient {
         event_slug: &str,
         model: &SetCoinFlipModel,
         force: bool,
-    ) -> impl std::future::Future<Output = Result<(), Box<dyn Error>>> + use<> {
+    ) -> impl std::future::Future<Output = Result<(), PortalError>> + use<> {
         let url = format!(
             "{}/api/events/{event_slug}/schedule/coin-flips",
             self.base_url
         );
         let request = authenticated_request(&self.client, Method::POST, &url, &self.auth_header)
             .query(&[("force", force)])
-            .json(model)
-            .send();
+            .json(model);
+        let response = send(request, self.retry, Call::Write);
         async move {
-            let response = request.await?;
-            if response.status() == StatusCode::OK {
-                Ok(())
-            } else {
-                let body = response.text().await?;
-                Err(Box::new(ApiError::new(body)))?
-            }
+            response.await?;
+            Ok(())
         }
     }
 }
@@ -853,25 +776,6 @@ fn build_auth_header(token: &str) -> Result<HeaderValue, UnsendableAccessKey> {
         .expect("a printable-ASCII key always makes a valid header value"))
 }
 
-#[derive(Debug)]
-struct ApiError {
-    message: String,
-}
-
-impl ApiError {
-    fn new(message: String) -> Self {
-        Self { message }
-    }
-}
-
-impl std::fmt::Display for ApiError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
-    }
-}
-
-impl Error for ApiError {}
-
 /// A character an access key must not contain, because an HTTP header cannot
 /// carry it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1151,3 +1055,342 @@ mod access_key_tests {
         assert!(!client.has_token());
     }
 }
+
+#[cfg(test)]
+mod fake_portal_tests {
+    use super::*;
+    use fake_portal::{ACCESS_KEY, EMAIL, Endpoint, Failure, FakePortal, PASSWORD};
+
+    fn event() -> EventId {
+        EventId::from_full("events/1234-A").unwrap()
+    }
+
+    fn client(portal: &FakePortal, key: Option<&str>) -> UwhPortalClient {
+        UwhPortalClient::new(&portal.url(), key, false, Duration::from_secs(5)).unwrap()
+    }
+
+    /// A client that tries each call up to three times, with waits short enough for a test
+    fn patient_client(portal: &FakePortal) -> UwhPortalClient {
+        let mut client = client(portal, Some(ACCESS_KEY));
+        client.set_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(20),
+        });
+        client
+    }
+
+    #[tokio::test]
+    async fn linking_needs_a_pending_link_and_its_code() {
+        let portal = FakePortal::start().unwrap();
+        let client = client(&portal, None);
+
+        assert_eq!(
+            client.login_to_portal(&event(), 1234).await.unwrap(),
+            PortalTokenResponse::NoPendingLink
+        );
+        portal.expect_link(1234);
+        assert_eq!(
+            client.login_to_portal(&event(), 4321).await.unwrap(),
+            PortalTokenResponse::InvalidCode
+        );
+        assert_eq!(
+            client.login_to_portal(&event(), 1234).await.unwrap(),
+            PortalTokenResponse::Success(ACCESS_KEY.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn an_expired_key_is_refused_until_the_new_one_is_fetched() {
+        let portal = FakePortal::start().unwrap();
+        assert!(client(&portal, None).verify_token(&event()).await.is_err());
+
+        let mut client = client(&portal, Some(ACCESS_KEY));
+        client.verify_token(&event()).await.unwrap();
+
+        portal.expire_tokens();
+        assert!(client.verify_token(&event()).await.is_err());
+        assert!(
+            client
+                .login_with_email_and_password(EMAIL, "wrong")
+                .await
+                .is_err()
+        );
+        let key = client
+            .login_with_email_and_password(EMAIL, PASSWORD)
+            .await
+            .unwrap();
+        assert_ne!(key, ACCESS_KEY);
+        client.set_token(&key).unwrap();
+        client.verify_token(&event()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reads_the_event_and_everything_in_it() {
+        let portal = FakePortal::start().unwrap();
+        let client = client(&portal, Some(ACCESS_KEY));
+
+        let events = client.get_event_list(false, true).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, event());
+
+        let teams = client.get_event_teams(&event()).await.unwrap();
+        assert_eq!(
+            teams.values().collect::<Vec<_>>(),
+            ["Black Sheep", "White Knights"]
+        );
+
+        let schedule = client
+            .get_event_schedule_privileged(&event())
+            .await
+            .unwrap();
+        assert_eq!(schedule.games.len(), 2);
+        assert_eq!(
+            schedule.get_game_timing(&"1".to_string()).unwrap().name,
+            "RR"
+        );
+
+        let referees = client
+            .get_event_referee_name_map_from_referees(&event())
+            .await
+            .unwrap();
+        assert_eq!(referees["user-abc123"], "Casey");
+        let participants = client.get_event_referee_name_map(&event()).await.unwrap();
+        assert_eq!(participants["user-abc123"], "Casey");
+        let game_referees = client
+            .get_game_referee_name_map(&event(), &"1".to_string())
+            .await
+            .unwrap();
+        assert_eq!(game_referees["user-abc123"], "reef_ref");
+        assert!(
+            client
+                .get_game_referee_name_map(&event(), &"2".to_string())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // The playing coach stays on the grid and the coach who only coaches does not.
+        let roster = client
+            .get_team_roster(&TeamId::from_full("teams/5678-B").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(roster.len(), 6);
+        assert_eq!(roster[5].name, "Frankie");
+        assert!(
+            client
+                .get_team_roster(&TeamId::from_full("teams/0000-Z").unwrap())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn a_coin_flip_result_is_recorded_and_then_served() {
+        let portal = FakePortal::start().unwrap();
+        let client = client(&portal, Some(ACCESS_KEY));
+        let slug = portal.data().event_slug;
+
+        let flips = client.get_coin_flips(&slug).await.unwrap();
+        assert!(flips.games[0].result.is_none());
+
+        let model = SetCoinFlipModel {
+            group_identifier: None,
+            coin_flip_identifier: flips.games[0].identifier.clone(),
+            team_id_or_pending_assignment_name: "teams/1234-A".to_string(),
+            kind: "Won".to_string(),
+        };
+        client
+            .set_coin_flip_result(&slug, &model, false)
+            .await
+            .unwrap();
+
+        assert_eq!(portal.coin_flip_results().len(), 1);
+        let flips = client.get_coin_flips(&slug).await.unwrap();
+        let result = flips.games[0].result.as_ref().unwrap();
+        assert_eq!(result.kind, "Won");
+        assert_eq!(result.team.team_id.as_deref(), Some("teams/1234-A"));
+    }
+
+    #[tokio::test]
+    async fn posted_scores_and_stats_reach_the_portal_as_sent() {
+        let portal = FakePortal::start().unwrap();
+        let client = client(&portal, Some(ACCESS_KEY));
+        let game = "2".to_string();
+
+        client
+            .post_game_scores(
+                &event(),
+                &game,
+                BlackWhiteBundle { black: 3, white: 1 },
+                true,
+            )
+            .await
+            .unwrap();
+        client
+            .post_game_stats(&event(), &game, r#"[{"$type":"goal"}]"#.to_string())
+            .await
+            .unwrap();
+
+        let scores = portal.posted_scores();
+        assert_eq!(scores.len(), 1);
+        assert_eq!((scores[0].dark, scores[0].light), (3, 1));
+        assert_eq!(scores[0].game_number, "2");
+        assert!(scores[0].force);
+
+        let stats = portal.posted_stats();
+        assert_eq!(stats[0].event_id, "events/1234-A");
+        assert_eq!(stats[0].events[0]["$type"], "goal");
+    }
+
+    #[tokio::test]
+    async fn scripted_failures_fail_only_the_calls_they_are_scripted_for() {
+        let portal = FakePortal::start().unwrap();
+        let mut client = client(&portal, Some(ACCESS_KEY));
+        client.set_retry_policy(RetryPolicy::NONE);
+        let scores = BlackWhiteBundle { black: 1, white: 1 };
+        let game = "1".to_string();
+
+        portal.fail_next(Endpoint::PostScores, Failure::Status(503));
+        portal.fail_next(
+            Endpoint::PostScores,
+            Failure::RateLimited {
+                retry_after: Duration::from_secs(30),
+            },
+        );
+        portal.fail_next(Endpoint::PostScores, Failure::ExpiredToken);
+        assert!(matches!(
+            client
+                .post_game_scores(&event(), &game, scores, false)
+                .await,
+            Err(PortalError::ServerError { status: 503, .. })
+        ));
+        assert!(matches!(
+            client.post_game_scores(&event(), &game, scores, false).await,
+            Err(PortalError::RateLimited {
+                retry_after: Some(wait),
+                ..
+            }) if wait == Duration::from_secs(30)
+        ));
+        let err = client
+            .post_game_scores(&event(), &game, scores, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PortalError::AuthExpired(_)));
+        assert!(err.is_auth());
+        client.verify_token(&event()).await.unwrap();
+        client
+            .post_game_scores(&event(), &game, scores, false)
+            .await
+            .unwrap();
+
+        assert_eq!(portal.count(Endpoint::PostScores), 4);
+        assert_eq!(portal.posted_scores().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_response_held_past_the_timeout_is_a_transport_error() {
+        let portal = FakePortal::start().unwrap();
+        let client = UwhPortalClient::new(
+            &portal.url(),
+            Some(ACCESS_KEY),
+            false,
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        portal.fail_next(
+            Endpoint::VerifyToken,
+            Failure::Delay(Duration::from_secs(2)),
+        );
+
+        let err = client.verify_token(&event()).await.unwrap_err();
+        assert!(matches!(err, PortalError::Timeout(_)));
+        // A check is never sent twice; its caller's next check is the retry
+        assert_eq!(portal.count(Endpoint::VerifyToken), 1);
+    }
+
+    #[tokio::test]
+    async fn a_busy_portal_is_asked_again_until_it_answers() {
+        let portal = FakePortal::start().unwrap();
+        let client = patient_client(&portal);
+        portal.fail_next(Endpoint::EventList, Failure::Status(503));
+        portal.fail_next(Endpoint::EventList, Failure::Status(502));
+
+        assert_eq!(client.get_event_list(false, true).await.unwrap().len(), 1);
+        assert_eq!(portal.count(Endpoint::EventList), 3);
+
+        portal.fail_always(Endpoint::EventList, Failure::Status(500));
+        assert!(matches!(
+            client.get_event_list(false, true).await,
+            Err(PortalError::ServerError { status: 500, .. })
+        ));
+        assert_eq!(portal.count(Endpoint::EventList), 6);
+    }
+
+    #[tokio::test]
+    async fn a_change_is_only_sent_again_when_the_portal_did_not_handle_it() {
+        let portal = FakePortal::start().unwrap();
+        let client = patient_client(&portal);
+        let scores = BlackWhiteBundle { black: 2, white: 0 };
+        let game = "1".to_string();
+
+        // A 500 may have come after the score was stored, so it is not sent twice
+        portal.fail_next(Endpoint::PostScores, Failure::Status(500));
+        assert!(matches!(
+            client
+                .post_game_scores(&event(), &game, scores, false)
+                .await,
+            Err(PortalError::ServerError { status: 500, .. })
+        ));
+        assert_eq!(portal.count(Endpoint::PostScores), 1);
+
+        // A 503 says it was not handled at all
+        portal.fail_next(Endpoint::PostScores, Failure::Status(503));
+        client
+            .post_game_scores(&event(), &game, scores, false)
+            .await
+            .unwrap();
+        assert_eq!(portal.count(Endpoint::PostScores), 3);
+        assert_eq!(portal.posted_scores().len(), 1);
+
+        // Nor is anything the portal refused outright
+        portal.fail_next(Endpoint::PostStats, Failure::Status(400));
+        assert!(matches!(
+            client
+                .post_game_stats(&event(), &game, "[]".to_string())
+                .await,
+            Err(PortalError::Rejected { status: 400, .. })
+        ));
+        assert_eq!(portal.count(Endpoint::PostStats), 1);
+    }
+
+    #[tokio::test]
+    async fn a_rate_limit_is_waited_out_only_when_the_wait_is_within_the_backoff() {
+        let portal = FakePortal::start().unwrap();
+        let client = patient_client(&portal);
+
+        portal.fail_next(
+            Endpoint::EventTeams,
+            Failure::RateLimited {
+                retry_after: Duration::ZERO,
+            },
+        );
+        client.get_event_teams(&event()).await.unwrap();
+        assert_eq!(portal.count(Endpoint::EventTeams), 2);
+
+        // Longer than the client would wait of its own accord, so it is the caller's to schedule
+        portal.fail_next(
+            Endpoint::EventTeams,
+            Failure::RateLimited {
+                retry_after: Duration::from_secs(1),
+            },
+        );
+        let started = std::time::Instant::now();
+        assert!(matches!(
+            client.get_event_teams(&event()).await,
+            Err(PortalError::RateLimited { .. })
+        ));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(portal.count(Endpoint::EventTeams), 3);
+    }
+}
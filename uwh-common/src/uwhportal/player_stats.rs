@@ -0,0 +1,474 @@
+//! Player statistics for an event ("top scorers" and "discipline" tables)
+//!
+//! Adds up the goal, penalty and foul events of every finished game, as the refbox sends them to
+//! the portal and keeps them in its results archive, by team and cap number. Goals that were
+//! later disallowed do not count. Player names are joined in afterwards from the team rosters,
+//! so a player missing from a roster is still listed by cap number.
+
+use super::{
+    RosterPlayer,
+    schedule::{EventId, GameNumber, TeamId},
+};
+use crate::{bundles::BlackWhiteBundle, color::Color};
+use core::cmp::Reverse;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlayerTotals {
+    pub team: TeamId,
+    pub team_name: Option<String>,
+    pub cap_number: u8,
+    /// From the team's roster, when it lists this cap number
+    pub name: Option<String>,
+    pub goals: u32,
+    /// Timed penalties, not counting total dismissals
+    pub penalties: u32,
+    /// The total length of the timed penalties
+    pub penalty_secs: u64,
+    pub total_dismissals: u32,
+    pub fouls: u32,
+}
+
+impl PlayerTotals {
+    pub const CSV_HEADERS: [&'static str; 8] = [
+        "Team",
+        "Cap",
+        "Player",
+        "Goals",
+        "Penalties",
+        "Penalty Time",
+        "Total Dismissals",
+        "Fouls",
+    ];
+
+    fn new(team: TeamId, cap_number: u8) -> Self {
+        Self {
+            team,
+            team_name: None,
+            cap_number,
+            name: None,
+            goals: 0,
+            penalties: 0,
+            penalty_secs: 0,
+            total_dismissals: 0,
+            fouls: 0,
+        }
+    }
+
+    /// The team's name, falling back to its id
+    pub fn team_display(&self) -> &str {
+        self.team_name.as_deref().unwrap_or(self.team.partial())
+    }
+
+    /// The player's name, falling back to their cap number
+    pub fn player_display(&self) -> String {
+        match &self.name {
+            Some(name) => format!("#{} {name}", self.cap_number),
+            None => format!("#{}", self.cap_number),
+        }
+    }
+
+    /// The penalty time as `m:ss`
+    pub fn penalty_time(&self) -> String {
+        format!("{}:{:02}", self.penalty_secs / 60, self.penalty_secs % 60)
+    }
+
+    /// One row of the CSV export, in the order of [`Self::CSV_HEADERS`]
+    pub fn csv_record(&self) -> [String; 8] {
+        [
+            self.team_display().to_string(),
+            self.cap_number.to_string(),
+            self.name.clone().unwrap_or_default(),
+            self.goals.to_string(),
+            self.penalties.to_string(),
+            self.penalty_time(),
+            self.total_dismissals.to_string(),
+            self.fouls.to_string(),
+        ]
+    }
+
+    fn has_discipline(&self) -> bool {
+        self.penalties > 0 || self.total_dismissals > 0 || self.fouls > 0
+    }
+}
+
+/// Totals for every player with at least one goal, penalty or foul
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+    /// The number of games added
+    pub games: u32,
+    players: BTreeMap<(TeamId, u8), PlayerTotals>,
+}
+
+impl PlayerStats {
+    /// Adds one game's `GameStats` events. A side without a team id (a manual game, or a game
+    /// played before the slot was filled) can't be tied to a team across games and is skipped.
+    pub fn add_game(
+        &mut self,
+        team_ids: &BlackWhiteBundle<Option<TeamId>>,
+        team_names: &BlackWhiteBundle<Option<String>>,
+        events: &serde_json::Value,
+    ) -> Result<(), serde_json::Error> {
+        let events = Vec::<StatsEvent>::deserialize(events)?;
+        self.games += 1;
+
+        let disallowed: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                StatsEvent::GoalDisallowed {
+                    side,
+                    goal_occurred_on,
+                } => Some((*side, goal_occurred_on.as_str())),
+                _ => None,
+            })
+            .collect();
+
+        for event in &events {
+            let (side, cap) = match event {
+                StatsEvent::Goal { side, cap, .. } | StatsEvent::Penalty { side, cap, .. } => {
+                    (*side, *cap)
+                }
+                // A foul on nobody in particular isn't anyone's
+                StatsEvent::Foul {
+                    side: Some(side),
+                    cap: Some(cap),
+                } => (*side, *cap),
+                _ => continue,
+            };
+            let color = side.color();
+            let Some(team) = &team_ids[color] else {
+                continue;
+            };
+            let totals = self
+                .players
+                .entry((team.clone(), cap))
+                .or_insert_with(|| PlayerTotals::new(team.clone(), cap));
+            if let Some(name) = &team_names[color] {
+                totals.team_name = Some(name.clone());
+            }
+
+            match event {
+                StatsEvent::Goal { occurred_on, .. } => {
+                    if !disallowed.contains(&(side, occurred_on.as_str())) {
+                        totals.goals += 1;
+                    }
+                }
+                StatsEvent::Penalty {
+                    is_total_dismissal: true,
+                    ..
+                } => totals.total_dismissals += 1,
+                StatsEvent::Penalty { duration, .. } => {
+                    totals.penalties += 1;
+                    totals.penalty_secs += duration.unwrap_or(0);
+                }
+                StatsEvent::Foul { .. } => totals.fouls += 1,
+                StatsEvent::GoalDisallowed { .. } | StatsEvent::Other => {}
+            }
+        }
+
+        // A goal that was disallowed may have been a player's only entry
+        self.players
+            .retain(|_, totals| totals.goals > 0 || totals.has_discipline());
+        Ok(())
+    }
+
+    /// Fills in player names from the team rosters
+    pub fn add_names(&mut self, rosters: &BTreeMap<TeamId, Vec<RosterPlayer>>) {
+        for ((team, cap), totals) in self.players.iter_mut() {
+            if let Some(player) = rosters
+                .get(team)
+                .and_then(|roster| roster.iter().find(|p| p.number == Some(*cap)))
+                .filter(|p| !p.name.is_empty())
+            {
+                totals.name = Some(player.name.clone());
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Every player, by team then cap number
+    pub fn players(&self) -> impl Iterator<Item = &PlayerTotals> {
+        self.players.values()
+    }
+
+    /// Players with at least one goal, most goals first
+    pub fn top_scorers(&self) -> Vec<&PlayerTotals> {
+        let mut scorers: Vec<_> = self.players().filter(|p| p.goals > 0).collect();
+        scorers.sort_by_key(|p| (Reverse(p.goals), p.team_display(), p.cap_number));
+        scorers
+    }
+
+    /// Players with a penalty or foul against them, most serious first: total dismissals, then
+    /// penalty time, then the number of penalties, then fouls
+    pub fn discipline(&self) -> Vec<&PlayerTotals> {
+        let mut players: Vec<_> = self.players().filter(|p| p.has_discipline()).collect();
+        players.sort_by_key(|p| {
+            (
+                Reverse(p.total_dismissals),
+                Reverse(p.penalty_secs),
+                Reverse(p.penalties),
+                Reverse(p.fouls),
+                p.team_display(),
+                p.cap_number,
+            )
+        });
+        players
+    }
+}
+
+/// The fields of a refbox results archive entry that the player statistics are made from. The
+/// refbox's `results.json` holds a list of these under `games`, and its per-day JSON export is a
+/// bare list of them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchivedGameStats {
+    pub event_id: Option<EventId>,
+    pub game_number: GameNumber,
+    /// The event day, which together with the game number tells one game from another
+    pub day: time::Date,
+    /// Only recorded by newer refboxes, older archives can't be tied to teams
+    #[serde(default)]
+    pub team_ids: BlackWhiteBundle<Option<TeamId>>,
+    pub teams: BlackWhiteBundle<Option<String>>,
+    pub stats: serde_json::Value,
+}
+
+/// Only the parts of a `GameStats` event used here, everything else is ignored
+#[derive(Debug, Deserialize)]
+#[serde(tag = "$type")]
+enum StatsEvent {
+    #[serde(rename = "goal")]
+    Goal {
+        #[serde(rename = "playerCapNumber")]
+        cap: u8,
+        side: Side,
+        #[serde(rename = "occurredOn")]
+        occurred_on: String,
+    },
+    #[serde(rename = "penalty")]
+    Penalty {
+        #[serde(rename = "playerCapNumber")]
+        cap: u8,
+        side: Side,
+        duration: Option<u64>,
+        #[serde(rename = "isTotalDismissal", default)]
+        is_total_dismissal: bool,
+    },
+    #[serde(rename = "foul")]
+    Foul {
+        #[serde(rename = "playerCapNumber")]
+        cap: Option<u8>,
+        side: Option<Side>,
+    },
+    #[serde(rename = "goalDisallowed")]
+    GoalDisallowed {
+        side: Side,
+        #[serde(rename = "goalOccurredOn")]
+        goal_occurred_on: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Side {
+    Dark,
+    Light,
+}
+
+impl Side {
+    fn color(self) -> Color {
+        match self {
+            Self::Dark => Color::Black,
+            Self::Light => Color::White,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn teams(black: &str, white: &str) -> BlackWhiteBundle<Option<TeamId>> {
+        BlackWhiteBundle {
+            black: Some(TeamId::from_partial(black)),
+            white: Some(TeamId::from_partial(white)),
+        }
+    }
+
+    fn names(black: &str, white: &str) -> BlackWhiteBundle<Option<String>> {
+        BlackWhiteBundle {
+            black: Some(black.to_string()),
+            white: Some(white.to_string()),
+        }
+    }
+
+    fn goal(cap: u8, side: &str, at: &str) -> serde_json::Value {
+        json!({"$type": "goal", "playerCapNumber": cap, "side": side, "gamePeriod": "FirstHalf",
+            "periodTime": 100.0, "occurredOn": at})
+    }
+
+    fn player(number: u8, name: &str) -> RosterPlayer {
+        RosterPlayer {
+            number: Some(number),
+            name: name.to_string(),
+            is_captain: false,
+            is_vice_captain: false,
+        }
+    }
+
+    #[test]
+    fn goals_add_up_across_games_by_team_and_cap() {
+        let mut stats = PlayerStats::default();
+        stats
+            .add_game(
+                &teams("1-A", "2-A"),
+                &names("Sharks", "Eels"),
+                &json!([
+                    goal(7, "dark", "2026-06-27T09:01:00Z"),
+                    goal(7, "light", "2026-06-27T09:02:00Z"),
+                    goal(7, "dark", "2026-06-27T09:03:00Z"),
+                ]),
+            )
+            .unwrap();
+        // The Sharks play white this time
+        stats
+            .add_game(
+                &teams("2-A", "1-A"),
+                &names("Eels", "Sharks"),
+                &json!([goal(7, "light", "2026-06-27T10:01:00Z")]),
+            )
+            .unwrap();
+
+        assert_eq!(stats.games, 2);
+        let scorers: Vec<_> = stats
+            .top_scorers()
+            .into_iter()
+            .map(|p| (p.team_display(), p.cap_number, p.goals))
+            .collect();
+        assert_eq!(scorers, [("Sharks", 7, 3), ("Eels", 7, 1)]);
+    }
+
+    #[test]
+    fn disallowed_goals_do_not_count() {
+        let mut stats = PlayerStats::default();
+        stats
+            .add_game(
+                &teams("1-A", "2-A"),
+                &names("Sharks", "Eels"),
+                &json!([
+                    goal(4, "dark", "2026-06-27T09:01:00Z"),
+                    goal(5, "dark", "2026-06-27T09:02:00Z"),
+                    {"$type": "goalDisallowed", "playerCapNumber": 5, "side": "dark",
+                        "goalOccurredOn": "2026-06-27T09:02:00Z", "reason": "Foul",
+                        "occurredOn": "2026-06-27T09:02:30Z"},
+                ]),
+            )
+            .unwrap();
+
+        let scorers: Vec<_> = stats.top_scorers().iter().map(|p| p.cap_number).collect();
+        assert_eq!(scorers, [4]);
+        assert_eq!(stats.players().count(), 1);
+    }
+
+    #[test]
+    fn discipline_puts_the_most_serious_first() {
+        let mut stats = PlayerStats::default();
+        stats
+            .add_game(
+                &teams("1-A", "2-A"),
+                &names("Sharks", "Eels"),
+                &json!([
+                    {"$type": "penalty", "playerCapNumber": 2, "side": "dark", "duration": 60,
+                        "isTotalDismissal": false},
+                    {"$type": "penalty", "playerCapNumber": 2, "side": "dark", "duration": 120,
+                        "isTotalDismissal": false},
+                    {"$type": "penalty", "playerCapNumber": 9, "side": "light", "duration": null,
+                        "isTotalDismissal": true},
+                    {"$type": "foul", "playerCapNumber": 3, "side": "light", "called": "Obstruction"},
+                    {"$type": "foul", "playerCapNumber": null, "side": null, "called": "Obstruction"},
+                    {"$type": "shootoutAttempt", "playerCapNumber": 1, "side": "dark",
+                        "round": 1, "scored": true},
+                ]),
+            )
+            .unwrap();
+
+        let discipline: Vec<_> = stats
+            .discipline()
+            .into_iter()
+            .map(|p| (p.cap_number, p.total_dismissals, p.penalties, p.fouls))
+            .collect();
+        assert_eq!(discipline, [(9, 1, 0, 0), (2, 0, 2, 0), (3, 0, 0, 1)]);
+        assert_eq!(stats.discipline()[1].penalty_time(), "3:00");
+        assert!(stats.top_scorers().is_empty());
+    }
+
+    #[test]
+    fn a_side_without_a_team_id_is_skipped() {
+        let mut stats = PlayerStats::default();
+        let team_ids = BlackWhiteBundle {
+            black: Some(TeamId::from_partial("1-A")),
+            white: None,
+        };
+        stats
+            .add_game(
+                &team_ids,
+                &Default::default(),
+                &json!([
+                    goal(1, "dark", "2026-06-27T09:01:00Z"),
+                    goal(2, "light", "2026-06-27T09:02:00Z"),
+                ]),
+            )
+            .unwrap();
+
+        let scorers: Vec<_> = stats
+            .top_scorers()
+            .into_iter()
+            .map(|p| (p.team_display(), p.cap_number))
+            .collect();
+        assert_eq!(scorers, [("1-A", 1)]);
+    }
+
+    #[test]
+    fn names_come_from_the_rosters() {
+        let mut stats = PlayerStats::default();
+        stats
+            .add_game(
+                &teams("1-A", "2-A"),
+                &names("Sharks", "Eels"),
+                &json!([
+                    goal(7, "dark", "2026-06-27T09:01:00Z"),
+                    goal(8, "dark", "2026-06-27T09:02:00Z"),
+                ]),
+            )
+            .unwrap();
+        let rosters = BTreeMap::from([(TeamId::from_partial("1-A"), vec![player(7, "Sam")])]);
+        stats.add_names(&rosters);
+
+        let shown: Vec<_> = stats.players().map(PlayerTotals::player_display).collect();
+        assert_eq!(shown, ["#7 Sam", "#8"]);
+        assert_eq!(
+            stats.players().next().unwrap().csv_record(),
+            ["Sharks", "7", "Sam", "1", "0", "0:00", "0", "0"]
+        );
+    }
+
+    #[test]
+    fn reads_archive_entries_without_team_ids() {
+        let game: ArchivedGameStats = serde_json::from_value(json!({
+            "event_id": null,
+            "game_number": "1",
+            "day": "2026-06-22",
+            "teams": {"black": "Sharks", "white": null},
+            "stats": []
+        }))
+        .unwrap();
+        assert_eq!(game.team_ids, BlackWhiteBundle::default());
+        assert_eq!(game.teams.black.as_deref(), Some("Sharks"));
+    }
+}
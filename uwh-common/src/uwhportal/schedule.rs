@@ -275,6 +275,20 @@ pub struct TimingRule {
     pub game_block: Option<Duration>,
 }
 
+impl TimingRule {
+    /// The config for a game played under this rule. The portal has no shootout or stop-time
+    /// settings, so those come from `local`, the refbox's own config.
+    pub fn game_config(self, local: &GameConfig) -> GameConfig {
+        GameConfig {
+            penalty_shootout_allowed: local.penalty_shootout_allowed,
+            penalty_shootout_rounds: local.penalty_shootout_rounds,
+            stop_time_allowed: local.stop_time_allowed,
+            stop_time_window: local.stop_time_window,
+            ..self.into()
+        }
+    }
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<GameConfig> for TimingRule {
     fn into(self) -> GameConfig {
@@ -308,7 +322,13 @@ impl Into<GameConfig> for TimingRule {
             game_block,
         } = self;
 
+        // The portal's timing rules have no shootout or stop-time settings, so both are left
+        // off here. `TimingRule::game_config` carries the refbox's own over instead.
         let GameConfig {
+            penalty_shootout_allowed,
+            penalty_shootout_rounds,
+            stop_time_allowed,
+            stop_time_window,
             penalty_shot_duration,
             nominal_break,
             post_game_duration,
@@ -320,11 +340,15 @@ impl Into<GameConfig> for TimingRule {
             timeouts_counted_per_half: team_timeouts_counted_per_half,
             overtime_allowed,
             sudden_death_allowed,
+            penalty_shootout_allowed,
+            penalty_shootout_rounds,
+            stop_time_allowed,
             single_half: half_time_duration == Duration::ZERO,
             half_play_duration,
             half_time_duration,
             team_timeout_duration,
             penalty_shot_duration,
+            stop_time_window,
             ot_half_play_duration,
             ot_half_time_duration,
             pre_overtime_break,
@@ -1051,6 +1075,32 @@ mod tests {
         assert_eq!(config.game_block, Duration::from_secs(2220));
     }
 
+    #[test]
+    fn a_rule_takes_the_local_shootout_and_stop_time_settings() {
+        let json = r#"{"name":"RR","teamTimeoutCount":1,"teamTimeoutsCountedPerHalf":true,"overtimeAllowed":false,"suddenDeathAllowed":false,"halfPlayDuration":900,"halfTimeDuration":180,"teamTimeoutDuration":60,"overtimeHalfPlayDuration":0,"overtimeHalfTimeDuration":0,"preOvertimeBreak":0,"preSuddenDeathDuration":0,"minimumBreak":240}"#;
+        let rule: TimingRule = serde_json::from_str(json).unwrap();
+        let local = GameConfig {
+            penalty_shootout_allowed: true,
+            penalty_shootout_rounds: 5,
+            stop_time_allowed: true,
+            stop_time_window: Duration::from_secs(90),
+            half_play_duration: Duration::from_secs(1),
+            ..Default::default()
+        };
+        let config = rule.clone().game_config(&local);
+        assert!(config.penalty_shootout_allowed);
+        assert_eq!(config.penalty_shootout_rounds, 5);
+        assert!(config.stop_time_allowed);
+        assert_eq!(config.stop_time_window, Duration::from_secs(90));
+        // Everything the rule does set still comes from the rule
+        assert_eq!(config.half_play_duration, Duration::from_secs(900));
+        assert!(!config.overtime_allowed);
+
+        let config: GameConfig = rule.into();
+        assert!(!config.penalty_shootout_allowed);
+        assert!(!config.stop_time_allowed);
+    }
+
     #[test]
     fn test_timing_rule_game_block_present_is_authoritative() {
         let json = r#"{"name":"RR","teamTimeoutCount":1,"teamTimeoutsCountedPerHalf":true,"overtimeAllowed":true,"suddenDeathAllowed":true,"halfPlayDuration":900,"halfTimeDuration":180,"teamTimeoutDuration":60,"overtimeHalfPlayDuration":300,"overtimeHalfTimeDuration":180,"preOvertimeBreak":180,"preSuddenDeathDuration":60,"minimumBreak":240,"gameBlock":1500}"#;